@@ -109,6 +109,7 @@ fn builtin_structs(path: &Path) -> anyhow::Result<()> {
     );
     writeln!(structs_pub, "#pragma once")?;
     writeln!(structs_pub, "// This file is auto-generated from {}", file!())?;
+    writeln!(structs_pub, "#include \"slint_color.h\"")?;
     writeln!(structs_pub, "namespace slint {{")?;
 
     let mut structs_priv = BufWriter::new(
@@ -119,6 +120,7 @@ fn builtin_structs(path: &Path) -> anyhow::Result<()> {
     writeln!(structs_priv, "// This file is auto-generated from {}", file!())?;
     writeln!(structs_priv, "#include \"slint_builtin_structs.h\"")?;
     writeln!(structs_priv, "#include \"slint_enums_internal.h\"")?;
+    writeln!(structs_priv, "#include \"slint_point.h\"")?;
     writeln!(structs_priv, "namespace slint::cbindgen_private {{")?;
     writeln!(structs_priv, "enum class KeyEventType : uint8_t;")?;
     macro_rules! struct_file {
@@ -126,6 +128,10 @@ macro_rules! struct_file {
             writeln!(structs_priv, "using slint::StandardListViewItem;")?;
             &mut structs_pub
         }};
+        (TextSpan) => {{
+            writeln!(structs_priv, "using slint::TextSpan;")?;
+            &mut structs_pub
+        }};
         ($_:ident) => {
             &mut structs_priv
         };
@@ -219,12 +225,15 @@ fn default_config() -> cbindgen::Config {
             ("PointerEventArg".into(), "PointerEvent".into()),
             ("PointerScrollEventArg".into(), "PointerScrollEvent".into()),
             ("PointArg".into(), "slint::LogicalPosition".into()),
+            ("StringArg".into(), "slint::SharedString".into()),
             ("FloatArg".into(), "float".into()),
             ("IntArg".into(), "int".into()),
             ("MenuEntryArg".into(), "MenuEntry".into()),
+            ("DropEventArg".into(), "DropEvent".into()),
             // Note: these types are not the same, but they are only used in callback return types that are only used in C++ (set and called)
             // therefore it is ok to reinterpret_cast
             ("MenuEntryModel".into(), "std::shared_ptr<slint::Model<MenuEntry>>".into()),
+            ("TextSpanModel".into(), "slint::private_api::TextSpanModel".into()),
             ("Coord".into(), "float".into()),
         ]
         .iter()
@@ -283,12 +292,19 @@ fn gen_corelib(
         "BorderRectangle",
         "ImageItem",
         "ClippedImage",
+        "VideoItem",
+        "LiveFrameItem",
         "TouchArea",
         "FocusScope",
         "SwipeGestureHandler",
+        "GestureArea",
+        "DragArea",
+        "DropArea",
+        "WindowControlArea",
         "Flickable",
         "SimpleText",
         "ComplexText",
+        "RichText",
         "Path",
         "WindowItem",
         "TextInput",
@@ -297,6 +313,8 @@ fn gen_corelib(
         "Rotate",
         "Opacity",
         "Layer",
+        "ShaderEffect",
+        "BackdropBlur",
         "ContextMenu",
     ];
 
@@ -374,9 +392,12 @@ fn gen_corelib(
         "PointerEventArg",
         "PointerScrollEventArg",
         "PointArg",
+        "StringArg",
         "Point",
         "MenuEntryModel",
         "MenuEntryArg",
+        "DropEventArg",
+        "TextSpanModel",
         "slint_color_brighter",
         "slint_color_darker",
         "slint_color_transparentize",
@@ -391,6 +412,9 @@ fn gen_corelib(
         "slint_image_from_embedded_textures",
         "slint_image_compare_equal",
         "slint_image_set_nine_slice_edges",
+        "slint_image_with_svg_fill",
+        "slint_image_with_svg_stroke",
+        "slint_image_with_svg_variable",
         "slint_image_to_rgb8",
         "slint_image_to_rgba8",
         "slint_image_to_rgba8_premultiplied",
@@ -455,7 +479,7 @@ fn gen_corelib(
         .with_src(crate_dir.join("properties.rs"))
         .with_src(crate_dir.join("properties/ffi.rs"))
         .with_src(crate_dir.join("callbacks.rs"))
-        .with_after_include("namespace slint { class Color; class Brush; }")
+        .with_after_include("namespace slint { class Color; class Brush; namespace private_api { struct PathData; } namespace cbindgen_private { using private_api::PathData; } }")
         .generate()
         .context("Unable to generate bindings for slint_properties_internal.h")?
         .write_to_file(include_dir.join("slint_properties_internal.h"));
@@ -505,6 +529,9 @@ fn gen_corelib(
                 "slint_image_from_embedded_textures",
                 "slint_image_compare_equal",
                 "slint_image_set_nine_slice_edges",
+                "slint_image_with_svg_fill",
+                "slint_image_with_svg_stroke",
+                "slint_image_with_svg_variable",
                 "slint_image_to_rgb8",
                 "slint_image_to_rgba8",
                 "slint_image_to_rgba8_premultiplied",
@@ -515,7 +542,7 @@ fn gen_corelib(
             ],
             vec!["Color"],
             "slint_image_internal.h",
-            "namespace slint::cbindgen_private { struct ParsedSVG{}; struct HTMLImage{}; using namespace vtable; namespace types{ struct NineSliceImage{}; } }",
+            "namespace slint::cbindgen_private { struct ParsedSVG{}; struct HTMLImage{}; using namespace vtable; namespace types{ struct NineSliceImage{}; struct AnimatedImage{}; struct VideoSourceInner{}; } }",
         ),
         (
             vec!["Color", "slint_color_brighter", "slint_color_darker",
@@ -535,7 +562,14 @@ fn gen_corelib(
             "",
         ),
         (
-            vec!["Brush", "LinearGradient", "GradientStop", "RadialGradient"],
+            vec![
+                "Brush",
+                "LinearGradient",
+                "GradientStop",
+                "RadialGradient",
+                "ConicGradient",
+                "FourCornerGradient",
+            ],
             vec!["Color"],
             "slint_brush_internal.h",
             "",
@@ -597,6 +631,9 @@ fn gen_corelib(
             "slint_image_load_from_path",
             "slint_image_load_from_embedded_data",
             "slint_image_set_nine_slice_edges",
+            "slint_image_with_svg_fill",
+            "slint_image_with_svg_stroke",
+            "slint_image_with_svg_variable",
             "slint_image_to_rgb8",
             "slint_image_to_rgba8",
             "slint_image_to_rgba8_premultiplied",
@@ -732,6 +769,26 @@ fn gen_corelib(
         .body
         .insert("Flickable".to_owned(), "    inline Flickable(); inline ~Flickable();".into());
     config.export.pre_body.insert("FlickableDataBox".to_owned(), "struct FlickableData;".into());
+    config
+        .export
+        .body
+        .insert("ImageItem".to_owned(), "    inline ImageItem(); inline ~ImageItem();".into());
+    config.export.body.insert(
+        "ClippedImage".to_owned(),
+        "    inline ClippedImage(); inline ~ClippedImage();".into(),
+    );
+    config
+        .export
+        .pre_body
+        .insert("ImagePlaybackDataBox".to_owned(), "struct ImagePlaybackData;".into());
+    config
+        .export
+        .body
+        .insert("VideoItem".to_owned(), "    inline VideoItem(); inline ~VideoItem();".into());
+    config
+        .export
+        .pre_body
+        .insert("VideoPlaybackDataBox".to_owned(), "struct VideoPlaybackData;".into());
 
     cbindgen::Builder::new()
         .with_config(config)
@@ -771,6 +828,27 @@ fn gen_corelib(
         using types::IntRect;
     }
     template<typename ModelData> class Model;
+    namespace private_api {
+    /// \private
+    /// Holds the model of the highlight-spans property of TextInput, the members are
+    /// defined in slint.h
+    class TextSpanModel
+    {
+    public:
+        inline TextSpanModel();
+        template<typename M>
+        inline TextSpanModel(const std::shared_ptr<M> &model);
+        inline TextSpanModel(const TextSpanModel &other);
+        inline TextSpanModel &operator=(const TextSpanModel &other);
+        inline ~TextSpanModel();
+        inline operator std::shared_ptr<Model<TextSpan>>() const;
+        inline friend bool operator==(const TextSpanModel &a, const TextSpanModel &b);
+        inline friend bool operator!=(const TextSpanModel &a, const TextSpanModel &b);
+
+    private:
+        uintptr_t inner[2];
+    };
+    }
 }",
         )
         .with_trailer(gen_item_declarations(&items))
@@ -488,6 +488,18 @@ struct UIntModel : Model<int>
         return static_cast<int>(value);
     }
 };
+
+/// Copies the rows of a model of float, for the functions implemented in Rust that need a slice.
+inline SharedVector<float> model_to_shared_vector(const std::shared_ptr<Model<float>> &model)
+{
+    SharedVector<float> result;
+    if (model) {
+        for (size_t i = 0; i < model->row_count(); ++i) {
+            result.push_back(model->row_data(i).value_or(0));
+        }
+    }
+    return result;
+}
 } // namespace private_api
 
 /// A Model backed by a SharedVector
@@ -554,6 +566,66 @@ public:
     }
 };
 
+namespace private_api {
+TextSpanModel::TextSpanModel()
+{
+    cbindgen_private::slint_text_span_model_init(this);
+}
+
+template<typename M>
+TextSpanModel::TextSpanModel(const std::shared_ptr<M> &model)
+{
+    // The rows are copied, tracking the model so that a binding is re-evaluated when it changes
+    SharedVector<TextSpan> spans;
+    if (model) {
+        model->track_row_count_changes();
+        for (size_t i = 0; i < model->row_count(); ++i) {
+            if (auto span = model->row_data_tracked(i)) {
+                spans.push_back(*span);
+            }
+        }
+    }
+    cbindgen_private::slint_text_span_model_from_vector(&spans, this);
+}
+
+TextSpanModel::TextSpanModel(const TextSpanModel &other)
+{
+    cbindgen_private::slint_text_span_model_clone(&other, this);
+}
+
+TextSpanModel &TextSpanModel::operator=(const TextSpanModel &other)
+{
+    if (this != &other) {
+        cbindgen_private::slint_text_span_model_drop(this);
+        cbindgen_private::slint_text_span_model_clone(&other, this);
+    }
+    return *this;
+}
+
+TextSpanModel::~TextSpanModel()
+{
+    cbindgen_private::slint_text_span_model_drop(this);
+}
+
+TextSpanModel::operator std::shared_ptr<Model<TextSpan>>() const
+{
+    SharedVector<TextSpan> spans;
+    cbindgen_private::slint_text_span_model_to_vector(this, &spans);
+    return std::make_shared<VectorModel<TextSpan>>(
+            std::vector<TextSpan>(spans.begin(), spans.end()));
+}
+
+bool operator==(const TextSpanModel &a, const TextSpanModel &b)
+{
+    return cbindgen_private::slint_text_span_model_eq(&a, &b);
+}
+
+bool operator!=(const TextSpanModel &a, const TextSpanModel &b)
+{
+    return !(a == b);
+}
+} // namespace private_api
+
 template<typename ModelData>
 class FilterModel;
 
@@ -1132,6 +1204,9 @@ class Repeater
         }
     };
 
+    // The row passed to scroll_to_row(), or -1
+    mutable int scroll_target_row = -1;
+
 public:
     // FIXME: should be private, but layouting code uses it.
     mutable std::shared_ptr<RepeaterInner> inner;
@@ -1186,14 +1261,28 @@ public:
     void ensure_updated_listview(const Parent *parent,
                                  const private_api::Property<float> *viewport_width,
                                  const private_api::Property<float> *viewport_height,
-                                 [[maybe_unused]] const private_api::Property<float> *viewport_y,
-                                 float listview_width, [[maybe_unused]] float listview_height) const
+                                 const private_api::Property<float> *viewport_y,
+                                 float listview_width, float listview_height) const
     {
         // TODO: the rust code in model.rs try to only allocate as many items as visible items
         ensure_updated(parent);
 
-        float h = compute_layout_listview(viewport_width, listview_width);
+        int row = std::exchange(scroll_target_row, -1);
+        float row_y = 0;
+        float h = compute_layout_listview(viewport_width, listview_width, row, &row_y);
         viewport_height->set(h);
+        if (row >= 0 && inner && std::size_t(row) < inner->data.size()) {
+            viewport_y->set(-std::min(row_y, std::max(0.f, h - listview_height)));
+        }
+    }
+
+    /// Scrolls the ListView so that the given row is at the top, the next time it's laid out
+    void scroll_to_row(int row) const
+    {
+        scroll_target_row = row;
+        if (inner) {
+            inner->is_dirty.set(true);
+        }
     }
 
     uint64_t visit(TraversalOrder order, private_api::ItemVisitorRefMut visitor) const
@@ -1230,14 +1319,17 @@ public:
     }
 
     float compute_layout_listview(const private_api::Property<float> *viewport_width,
-                                  float listview_width) const
+                                  float listview_width, int row, float *row_y) const
     {
         float offset = 0;
         viewport_width->set(listview_width);
         if (!inner)
             return offset;
-        for (auto &x : inner->data) {
-            (*x.ptr)->listview_layout(&offset, viewport_width);
+        for (std::size_t i = 0; i < inner->data.size(); ++i) {
+            if (int(i) == row) {
+                *row_y = offset;
+            }
+            (*inner->data[i].ptr)->listview_layout(&offset, viewport_width);
         }
         return offset;
     }
@@ -1338,6 +1430,33 @@ cbindgen_private::Flickable::~Flickable()
     slint_flickable_data_free(&data);
 }
 
+cbindgen_private::ImageItem::ImageItem()
+{
+    slint_image_playback_data_init(&data);
+}
+cbindgen_private::ImageItem::~ImageItem()
+{
+    slint_image_playback_data_free(&data);
+}
+
+cbindgen_private::ClippedImage::ClippedImage()
+{
+    slint_image_playback_data_init(&data);
+}
+cbindgen_private::ClippedImage::~ClippedImage()
+{
+    slint_image_playback_data_free(&data);
+}
+
+cbindgen_private::VideoItem::VideoItem()
+{
+    slint_video_playback_data_init(&data);
+}
+cbindgen_private::VideoItem::~VideoItem()
+{
+    slint_video_playback_data_free(&data);
+}
+
 cbindgen_private::NativeStyleMetrics::NativeStyleMetrics(void *)
 {
     slint_native_style_metrics_init(this);
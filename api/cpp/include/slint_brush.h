@@ -98,6 +98,74 @@ private:
     }
 };
 
+/// \private
+/// ConicGradientBrush represents a gradient whose color stops are placed around the center,
+/// clockwise, starting at a specific angle.
+class ConicGradientBrush
+{
+public:
+    /// Constructs an empty conic gradient with no color stops.
+    ConicGradientBrush() = default;
+    /// Constructs a new conic gradient starting at the specified \a from_angle. The color stops
+    /// will be constructed from the stops array pointed to be \a firstStop, with the length \a
+    /// stopCount.
+    ConicGradientBrush(float from_angle, const GradientStop *firstStop, int stopCount)
+        : inner(make_conic_gradient(from_angle, firstStop, stopCount))
+    {
+    }
+
+    /// Returns the angle in degrees at which the conic gradient starts.
+    float from_angle() const
+    {
+        // The gradient's first stop is a fake stop to store the angle
+        return inner[0].position;
+    }
+
+    /// Returns the number of gradient stops.
+    int stopCount() const { return int(inner.size()) - 1; }
+
+    /// Returns a pointer to the first gradient stop; undefined if the gradient has not stops.
+    const GradientStop *stopsBegin() const { return inner.begin() + 1; }
+    /// Returns a pointer past the last gradient stop. The returned pointer cannot be dereferenced,
+    /// it can only be used for comparison.
+    const GradientStop *stopsEnd() const { return inner.end(); }
+
+private:
+    cbindgen_private::types::ConicGradientBrush inner;
+
+    friend class slint::Brush;
+
+    static SharedVector<private_api::GradientStop>
+    make_conic_gradient(float from_angle, const GradientStop *firstStop, int stopCount)
+    {
+        SharedVector<private_api::GradientStop> gradient;
+        gradient.push_back({ Color::from_argb_encoded(0).inner, from_angle });
+        for (int i = 0; i < stopCount; ++i, ++firstStop)
+            gradient.push_back(*firstStop);
+        return gradient;
+    }
+};
+
+/// \private
+/// FourCornerGradientBrush represents a gradient that interpolates between the colors of the
+/// four corners.
+class FourCornerGradientBrush
+{
+public:
+    /// Constructs a new four corner gradient with the colors of the \a top_left, \a top_right,
+    /// \a bottom_right, and \a bottom_left corners.
+    FourCornerGradientBrush(const Color &top_left, const Color &top_right,
+                            const Color &bottom_right, const Color &bottom_left)
+        : inner { top_left.inner, top_right.inner, bottom_right.inner, bottom_left.inner }
+    {
+    }
+
+private:
+    cbindgen_private::types::FourCornerGradientBrush inner;
+
+    friend class slint::Brush;
+};
+
 }
 
 /// Brush is used to declare how to fill or outline shapes, such as rectangles, paths or text. A
@@ -123,6 +191,20 @@ public:
     {
     }
 
+    /// \private
+    /// Constructs a new brush that is the gradient \a gradient.
+    Brush(const private_api::ConicGradientBrush &gradient)
+        : data(Inner::ConicGradient(gradient.inner))
+    {
+    }
+
+    /// \private
+    /// Constructs a new brush that is the gradient \a gradient.
+    Brush(const private_api::FourCornerGradientBrush &gradient)
+        : data(Inner::FourCornerGradient(gradient.inner))
+    {
+    }
+
     /// Returns the color of the brush. If the brush is a gradient, this function returns the color
     /// of the first stop.
     inline Color color() const;
@@ -177,6 +259,14 @@ Color Brush::color() const
             result.inner = data.radial_gradient._0[0].color;
         }
         break;
+    case Tag::ConicGradient:
+        if (data.conic_gradient._0.size() > 1) {
+            result.inner = data.conic_gradient._0[1].color;
+        }
+        break;
+    case Tag::FourCornerGradient:
+        result.inner = data.four_corner_gradient._0.top_left;
+        break;
     }
     return result;
 }
@@ -201,6 +291,22 @@ inline Brush Brush::brighter(float factor) const
                                                           &result.data.radial_gradient._0[i].color);
         }
         break;
+    case Tag::ConicGradient:
+        for (std::size_t i = 1; i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_brighter(
+                    &data.conic_gradient._0[i].color, factor,
+                    &result.data.conic_gradient._0[i].color);
+        }
+        break;
+    case Tag::FourCornerGradient: {
+        const auto &g = data.four_corner_gradient._0;
+        auto &r = result.data.four_corner_gradient._0;
+        cbindgen_private::types::slint_color_brighter(&g.top_left, factor, &r.top_left);
+        cbindgen_private::types::slint_color_brighter(&g.top_right, factor, &r.top_right);
+        cbindgen_private::types::slint_color_brighter(&g.bottom_right, factor, &r.bottom_right);
+        cbindgen_private::types::slint_color_brighter(&g.bottom_left, factor, &r.bottom_left);
+        break;
+    }
     }
     return result;
 }
@@ -225,6 +331,22 @@ inline Brush Brush::darker(float factor) const
                                                         &result.data.radial_gradient._0[i].color);
         }
         break;
+    case Tag::ConicGradient:
+        for (std::size_t i = 1; i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_darker(
+                    &data.conic_gradient._0[i].color, factor,
+                    &result.data.conic_gradient._0[i].color);
+        }
+        break;
+    case Tag::FourCornerGradient: {
+        const auto &g = data.four_corner_gradient._0;
+        auto &r = result.data.four_corner_gradient._0;
+        cbindgen_private::types::slint_color_darker(&g.top_left, factor, &r.top_left);
+        cbindgen_private::types::slint_color_darker(&g.top_right, factor, &r.top_right);
+        cbindgen_private::types::slint_color_darker(&g.bottom_right, factor, &r.bottom_right);
+        cbindgen_private::types::slint_color_darker(&g.bottom_left, factor, &r.bottom_left);
+        break;
+    }
     }
     return result;
 }
@@ -251,6 +373,22 @@ inline Brush Brush::transparentize(float factor) const
                     &result.data.radial_gradient._0[i].color);
         }
         break;
+    case Tag::ConicGradient:
+        for (std::size_t i = 1; i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_transparentize(
+                    &data.conic_gradient._0[i].color, factor,
+                    &result.data.conic_gradient._0[i].color);
+        }
+        break;
+    case Tag::FourCornerGradient: {
+        const auto &g = data.four_corner_gradient._0;
+        auto &r = result.data.four_corner_gradient._0;
+        cbindgen_private::types::slint_color_transparentize(&g.top_left, factor, &r.top_left);
+        cbindgen_private::types::slint_color_transparentize(&g.top_right, factor, &r.top_right);
+        cbindgen_private::types::slint_color_transparentize(&g.bottom_right, factor, &r.bottom_right);
+        cbindgen_private::types::slint_color_transparentize(&g.bottom_left, factor, &r.bottom_left);
+        break;
+    }
     }
     return result;
 }
@@ -277,6 +415,22 @@ inline Brush Brush::with_alpha(float alpha) const
                     &result.data.radial_gradient._0[i].color);
         }
         break;
+    case Tag::ConicGradient:
+        for (std::size_t i = 1; i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_with_alpha(
+                    &data.conic_gradient._0[i].color, alpha,
+                    &result.data.conic_gradient._0[i].color);
+        }
+        break;
+    case Tag::FourCornerGradient: {
+        const auto &g = data.four_corner_gradient._0;
+        auto &r = result.data.four_corner_gradient._0;
+        cbindgen_private::types::slint_color_with_alpha(&g.top_left, alpha, &r.top_left);
+        cbindgen_private::types::slint_color_with_alpha(&g.top_right, alpha, &r.top_right);
+        cbindgen_private::types::slint_color_with_alpha(&g.bottom_right, alpha, &r.bottom_right);
+        cbindgen_private::types::slint_color_with_alpha(&g.bottom_left, alpha, &r.bottom_left);
+        break;
+    }
     }
     return result;
 }
@@ -12,6 +12,8 @@ namespace slint {
 
 namespace private_api {
 class LinearGradientBrush;
+class ConicGradientBrush;
+class FourCornerGradientBrush;
 }
 
 class Color;
@@ -217,6 +219,8 @@ public:
 private:
     cbindgen_private::types::Color inner;
     friend class private_api::LinearGradientBrush;
+    friend class private_api::ConicGradientBrush;
+    friend class private_api::FourCornerGradientBrush;
     friend class Brush;
 };
 
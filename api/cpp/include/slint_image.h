@@ -7,6 +7,7 @@
 #include "slint_generated_public.h"
 #include "slint_size.h"
 #include "slint_image_internal.h"
+#include "slint_color.h"
 #include "slint_string.h"
 #include "slint_sharedvector.h"
 
@@ -219,6 +220,47 @@ public:
         cbindgen_private::types::slint_image_set_nine_slice_edges(&data, top, right, bottom, left);
     }
 
+    /// Returns a copy of this image where the elements of the SVG that match the CSS \a selector,
+    /// such as `.accent` or `#outline`, are filled with the given \a color.
+    ///
+    /// Unlike the `colorize` property of the `Image` element, this keeps the other colors of the
+    /// SVG, so that one icon can follow the theme of the application.
+    /// Images that are not SVGs are returned unchanged.
+    Image with_svg_fill(const SharedString &selector, const Color &color) const
+    {
+        Image img;
+        cbindgen_private::types::slint_image_with_svg_fill(
+                &data, &selector, &static_cast<const cbindgen_private::types::Color &>(color),
+                &img.data);
+        return img;
+    }
+
+    /// Returns a copy of this image where the elements of the SVG that match the CSS \a selector,
+    /// such as `.accent` or `#outline`, are stroked with the given \a color.
+    ///
+    /// Images that are not SVGs are returned unchanged.
+    Image with_svg_stroke(const SharedString &selector, const Color &color) const
+    {
+        Image img;
+        cbindgen_private::types::slint_image_with_svg_stroke(
+                &data, &selector, &static_cast<const cbindgen_private::types::Color &>(color),
+                &img.data);
+        return img;
+    }
+
+    /// Returns a copy of this image where the CSS variable \a name, such as `--accent`, is
+    /// replaced by the given \a color wherever the SVG uses it with `var(--accent)`.
+    ///
+    /// Images that are not SVGs are returned unchanged.
+    Image with_svg_variable(const SharedString &name, const Color &color) const
+    {
+        Image img;
+        cbindgen_private::types::slint_image_with_svg_variable(
+                &data, &name, &static_cast<const cbindgen_private::types::Color &>(color),
+                &img.data);
+        return img;
+    }
+
     /// Returns the pixel buffer for the Image if available in RGB format without alpha.
     /// Returns nullopt if the pixels cannot be obtained, for example when the image was created
     /// from borrowed OpenGL textures.
@@ -5,6 +5,7 @@
 #include <initializer_list>
 #include <string_view>
 #include "slint_pathdata_internal.h"
+#include "slint_properties.h"
 
 namespace slint::private_api {
 using cbindgen_private::PathEvent;
@@ -65,4 +66,12 @@ private:
     Data data;
 };
 
+template<>
+inline void Property<PathData>::set_animated_value(
+        const PathData &new_value, const cbindgen_private::PropertyAnimation &animation_data) const
+{
+    cbindgen_private::slint_property_set_animated_value_path_data(&inner, &value, &new_value,
+                                                                  &animation_data);
+}
+
 }
@@ -11,10 +11,10 @@ struct ChangeTracker
 {
     void *inner;
 };
+struct StateInfo;
 }
 
 #include "slint_properties_internal.h"
-#include "slint_builtin_structs_internal.h"
 
 namespace slint::private_api {
 
@@ -60,6 +60,16 @@ inline void slint_property_set_animated_binding_helper(
             handle, binding, user_data, drop_user_data, animation_data, transition_data);
 }
 
+inline void slint_property_set_animated_binding_helper(
+        const cbindgen_private::PropertyHandleOpaque *handle,
+        void (*binding)(void *, PathData *), void *user_data, void (*drop_user_data)(void *),
+        const cbindgen_private::PropertyAnimation *animation_data,
+        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *))
+{
+    cbindgen_private::slint_property_set_animated_binding_path_data(
+            handle, binding, user_data, drop_user_data, animation_data, transition_data);
+}
+
 template<typename T>
 struct Property
 {
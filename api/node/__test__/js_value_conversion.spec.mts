@@ -546,6 +546,36 @@ test("get/set enum properties", (t) => {
     );
 });
 
+test("get/set hyphenated enum values", (t) => {
+    const compiler = new private_api.ComponentCompiler();
+    const definition = compiler.buildFromSource(
+        `export enum Filter { all, not-done }
+         export component App {
+            in-out property <Filter> filter: not-done;
+            out property <bool> is-all: filter == Filter.all;
+         }`,
+        "",
+    );
+    t.not(definition.App, null);
+
+    const instance = definition.App!.create();
+    t.not(instance, null);
+
+    t.is(instance!.getProperty("filter"), "not-done");
+    const Filter = compiler.enums.Filter as Record<string, string>;
+    t.deepEqual(Filter, { all: "all", not_done: "not-done" });
+
+    instance!.setProperty("filter", Filter.all);
+    t.is(instance!.getProperty("is-all"), true);
+
+    instance!.setProperty("filter", instance!.getProperty("filter"));
+    t.is(instance!.getProperty("is-all"), true);
+
+    instance!.setProperty("filter", Filter.not_done);
+    t.is(instance!.getProperty("filter"), "not-done");
+    t.is(instance!.getProperty("is-all"), false);
+});
+
 test("ArrayModel", (t) => {
     const compiler = new private_api.ComponentCompiler();
     const definition = compiler.buildFromSource(
@@ -148,11 +148,12 @@ fn convert_type(env: &Env, ty: &Type) -> Option<(String, JsUnknown)> {
                 Type::Enumeration(en) => {
                     let mut o = env.create_object().ok()?;
 
+                    // The keys use `_` so that they can be used as identifiers, but the values
+                    // keep the `-` like the strings accepted and returned for enum properties
                     for value in en.values.iter() {
-                        let value = value.replace_smolstr("-", "_");
                         o.set_property(
-                            env.create_string(&value).ok()?,
-                            env.create_string(&value).ok()?.into_unknown(),
+                            env.create_string(&value.replace_smolstr("-", "_")).ok()?,
+                            env.create_string(value).ok()?.into_unknown(),
                         )
                         .ok()?;
                     }
@@ -52,14 +52,21 @@ fn main() {
 
 use std::collections::HashMap;
 use std::env;
-use std::io::{BufWriter, Write};
+use std::io::Write;
 use std::path::Path;
 
 use i_slint_compiler::diagnostics::BuildDiagnostics;
 
+pub mod visitor;
+
 /// The structure for configuring aspects of the compilation of `.slint` markup files to Rust.
 pub struct CompilerConfiguration {
     config: i_slint_compiler::CompilerConfiguration,
+    typescript_definitions: Option<std::path::PathBuf>,
+    interface_description: Option<std::path::PathBuf>,
+    runtime_styles: Vec<String>,
+    constants: Vec<(String, Constant)>,
+    file_per_component: bool,
 }
 
 /// How should the slint compiler embed images and fonts
@@ -83,6 +90,11 @@ fn default() -> Self {
             config: i_slint_compiler::CompilerConfiguration::new(
                 i_slint_compiler::generator::OutputFormat::Rust,
             ),
+            typescript_definitions: None,
+            interface_description: None,
+            runtime_styles: Vec::new(),
+            constants: Vec::new(),
+            file_per_component: false,
         }
     }
 }
@@ -99,7 +111,7 @@ pub fn new() -> Self {
     pub fn with_include_paths(self, include_paths: Vec<std::path::PathBuf>) -> Self {
         let mut config = self.config;
         config.include_paths = include_paths;
-        Self { config }
+        Self { config, ..self }
     }
 
     /// Create a new configuration that sets the library paths used for looking up
@@ -132,7 +144,7 @@ pub fn with_include_paths(self, include_paths: Vec<std::path::PathBuf>) -> Self
     pub fn with_library_paths(self, library_paths: HashMap<String, std::path::PathBuf>) -> Self {
         let mut config = self.config;
         config.library_paths = library_paths;
-        Self { config }
+        Self { config, ..self }
     }
 
     /// Create a new configuration that selects the style to be used for widgets.
@@ -140,7 +152,7 @@ pub fn with_library_paths(self, library_paths: HashMap<String, std::path::PathBu
     pub fn with_style(self, style: String) -> Self {
         let mut config = self.config;
         config.style = Some(style);
-        Self { config }
+        Self { config, ..self }
     }
 
     /// Selects how the resources such as images and font are processed.
@@ -160,7 +172,235 @@ pub fn embed_resources(self, kind: EmbedResourcesKind) -> Self {
                 i_slint_compiler::EmbedResourcesKind::EmbedTextures
             }
         };
-        Self { config }
+        Self { config, ..self }
+    }
+
+    /// Restricts the fonts that are embedded to the given font families.
+    ///
+    /// This only has an effect if the resources are embedded with [`Self::embed_resources`].
+    /// Fonts imported in .slint files from other families are loaded from their path at run-time,
+    /// and the default font is left to the system if it's not part of these families.
+    /// An empty list, the default, embeds all the fonts.
+    #[must_use]
+    pub fn with_embedded_font_families(self, families: Vec<String>) -> Self {
+        let mut config = self.config;
+        config.font_embedding.families = families;
+        Self { config, ..self }
+    }
+
+    /// Restricts the fonts that are embedded to the font faces of the given weights,
+    /// for example `vec![400, 700]` for regular and bold.
+    ///
+    /// The fonts that are not embedded are handled like with [`Self::with_embedded_font_families`].
+    /// An empty list, the default, embeds all the weights.
+    #[must_use]
+    pub fn with_embedded_font_weights(self, weights: Vec<u16>) -> Self {
+        let mut config = self.config;
+        config.font_embedding.weights = weights;
+        Self { config, ..self }
+    }
+
+    /// When embedding resources for the software renderer, only embed the glyphs of the
+    /// characters that appear in the string literals of the .slint files and in their
+    /// bundled translations.
+    ///
+    /// By default, the glyphs of the ASCII characters are always embedded so that text
+    /// computed at run-time, such as numbers, can be displayed. Enabling this reduces the
+    /// size of the binary, but characters that are not in the .slint files are not rendered.
+    #[must_use]
+    pub fn with_glyph_subsetting(self, enabled: bool) -> Self {
+        let mut config = self.config;
+        config.font_embedding.subset_glyphs = enabled;
+        Self { config, ..self }
+    }
+
+    /// Configures the compiler to write a source map next to the generated Rust file.
+    ///
+    /// The generated code contains a call to `slint::private_unstable_api::source_location` at the
+    /// beginning of the code of each binding, which shows where the binding is in the .slint file when
+    /// stepping through the code in a debugger. The source map is written in a file with the same path
+    /// as the generated file, with `.map` appended. Each line of the source map contains, separated by
+    /// tabs, the line in the generated file where the code of a binding starts, followed by the path,
+    /// the line, and the column of that binding in the .slint file. The location of a line from a panic
+    /// message is the one of the closest entry before that line.
+    #[must_use]
+    pub fn with_source_map(self, enabled: bool) -> Self {
+        let mut config = self.config;
+        config.emit_source_locations = enabled;
+        Self { config, ..self }
+    }
+
+    /// Configures the compiler to report, as warnings, the exported components that are never used,
+    /// the properties that are never read, and the callbacks that are never invoked in the project.
+    ///
+    /// The public properties and callbacks of the components exported from the main .slint file
+    /// are part of the API used by the Rust code and are not reported.
+    #[must_use]
+    pub fn with_unused_items_report(self, enabled: bool) -> Self {
+        let mut config = self.config;
+        config.report_unused_items = enabled;
+        Self { config, ..self }
+    }
+
+    /// Configures the compiler to generate smaller code, at the cost of run-time speed.
+    ///
+    /// This is meant for microcontrollers, where the size of the flash is limited and the binary
+    /// is dominated by the generated code. The property bindings that have the same code in a
+    /// component, for example `color: Palette.foreground;` on many elements, are then compiled to
+    /// a single function. This can also be enabled with the `SLINT_OPTIMIZE_FOR_SIZE` environment variable.
+    ///
+    /// Sharing the bindings is the only optimization done by this option: the item trees and the
+    /// layout code are generated the same way as without it, so components with many elements
+    /// still generate a lot of code.
+    #[must_use]
+    pub fn with_size_optimization(self, enabled: bool) -> Self {
+        let mut config = self.config;
+        config.optimize_for_size = enabled;
+        Self { config, ..self }
+    }
+
+    /// Configures the compiler to convert the simple SVG images to native paths at compile time.
+    ///
+    /// An `Image` element with a `width` and a `height`, whose `source` is an SVG made of paths
+    /// filled with a single color, is replaced by a `Path` element, so that the icon scales
+    /// without loss and no SVG decoder is needed at run-time. The other images are left as is.
+    /// This has no effect when the images are embedded as textures for the software renderer.
+    /// This can also be enabled with the `SLINT_SVG_TO_PATH` environment variable.
+    #[must_use]
+    pub fn with_svg_to_path(self, enabled: bool) -> Self {
+        let mut config = self.config;
+        config.svg_to_path = enabled;
+        Self { config, ..self }
+    }
+
+    /// Configures the compiler to add a layer on top of the content of the exported windows that
+    /// shows the toasts of [`slint::Toast`](https://docs.rs/slint/latest/slint/struct.Toast.html).
+    ///
+    /// This is disabled by default, as it adds elements to every window. Without it,
+    /// `Toast::show()` doesn't show anything, and neither do the notifications that fall back to a toast.
+    #[must_use]
+    pub fn with_toasts(self, enabled: bool) -> Self {
+        let mut config = self.config;
+        config.toasts = enabled;
+        Self { config, ..self }
+    }
+
+    /// Registers a visitor that is called for each element of the main .slint file before the code generation.
+    ///
+    /// The visitor is called once the elements and bindings are resolved and type checked, and can
+    /// inspect and modify them, for example to inject identifiers or to enforce design-system rules.
+    /// Errors reported to the diagnostics make the compilation fail. Visitors are run in the order
+    /// in which they are registered.
+    /// ```rust,no_run
+    /// let config = slint_build::CompilerConfiguration::new().with_custom_pass(
+    ///     |element: &mut slint_build::visitor::v1::Element,
+    ///      diag: &mut slint_build::visitor::v1::Diagnostics| {
+    ///         if element.type_name().starts_with("Legacy") {
+    ///             diag.error(element, "Legacy components are not allowed");
+    ///         }
+    ///     },
+    /// );
+    /// slint_build::compile_with_config("ui/hello.slint", config).unwrap();
+    /// ```
+    #[must_use]
+    pub fn with_custom_pass(self, visitor: impl visitor::v1::Visitor + 'static) -> Self {
+        let mut config = self.config;
+        config.custom_passes.push(visitor::v1::into_custom_pass(visitor));
+        Self { config, ..self }
+    }
+
+    /// Sets the defines that are matched by the `@cfg(...)` conditions in the .slint files.
+    ///
+    /// Each define is either a name, like `"debug"`, or a name and a value separated by `=`,
+    /// like `"brand=acme"`. An element annotated with `@cfg(debug)` or `@cfg(brand = "acme")`
+    /// is only compiled if its condition matches, so debug panels, brand variants, or screens that
+    /// are specific to a target can be left out of the binary:
+    /// ```rust,no_run
+    /// let config = slint_build::CompilerConfiguration::new()
+    ///     .with_defines(["brand=acme", if cfg!(debug_assertions) { "debug" } else { "release" }]);
+    /// slint_build::compile_with_config("ui/hello.slint", config).unwrap();
+    /// ```
+    #[must_use]
+    pub fn with_defines(self, defines: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let mut config = self.config;
+        config.defines = defines
+            .into_iter()
+            .map(|define| {
+                let define = define.into();
+                match define.split_once('=') {
+                    Some((name, value)) => (name.trim().into(), Some(value.trim().into())),
+                    None => (define, None),
+                }
+            })
+            .collect();
+        Self { config, ..self }
+    }
+
+    /// Adds constants that can be used from all the .slint files as properties of the `Constants` global,
+    /// without import.
+    ///
+    /// The properties are declared with the type of the value and can't be changed, so the compiler
+    /// inlines their value like for any constant binding:
+    /// ```rust,no_run
+    /// use slint_build::Constant;
+    /// let config = slint_build::CompilerConfiguration::new().with_constants([
+    ///     ("app-version", Constant::from(env!("CARGO_PKG_VERSION"))),
+    ///     ("button-height", Constant::Length(32.)),
+    ///     ("brand-color", Constant::Color(0xff0080ff)),
+    /// ]);
+    /// slint_build::compile_with_config("ui/hello.slint", config).unwrap();
+    /// ```
+    /// ```slint,ignore
+    /// Text { text: "Version " + Constants.app-version; color: Constants.brand-color; }
+    /// ```
+    #[must_use]
+    pub fn with_constants(
+        mut self,
+        constants: impl IntoIterator<Item = (impl Into<String>, impl Into<Constant>)>,
+    ) -> Self {
+        self.constants
+            .extend(constants.into_iter().map(|(name, value)| (name.into(), value.into())));
+        self
+    }
+
+    /// Configures the compiler to also write TypeScript definitions for the exported components,
+    /// globals, structs, and enums to the given `.d.ts` file.
+    ///
+    /// The definitions describe the object returned by `loadFile` in the Node.js API of Slint,
+    /// as the `Module` interface, so that the same .slint files can be used from TypeScript with
+    /// the same type safety as from Rust:
+    /// ```typescript,ignore
+    /// import * as slint from "slint-ui";
+    /// import type { Module } from "./app";
+    /// const ui = slint.loadFile("ui/app.slint") as Module;
+    /// ```
+    #[must_use]
+    pub fn with_typescript_definitions(self, path: impl Into<std::path::PathBuf>) -> Self {
+        Self { typescript_definitions: Some(path.into()), ..self }
+    }
+
+    /// Also writes a JSON description of the public API of the exported components and globals,
+    /// and of the structs and enums that they use, to the given path.
+    ///
+    /// This makes the interface of the UI available to other tools, for example to generate
+    /// bindings for other languages, without having to parse .slint files. Each component and
+    /// global lists its `properties` (with their `type` and `visibility`), `callbacks`, and
+    /// `functions` (with their `arguments` and `return-type`).
+    #[must_use]
+    pub fn with_interface_description(self, path: impl Into<std::path::PathBuf>) -> Self {
+        Self { interface_description: Some(path.into()), ..self }
+    }
+
+    /// Configures the compiler to write the code of each component and global to its own file,
+    /// next to the main generated file.
+    ///
+    /// The generated files are only written when their content changes. Together with the stable
+    /// output of the compiler, this lets tools that cache on file contents, such as `sccache`, or
+    /// that look at the generated code, reuse their work for the components that didn't change.
+    /// This has no effect when several styles are selected with [`Self::with_runtime_styles`].
+    #[must_use]
+    pub fn with_file_per_component(self, enabled: bool) -> Self {
+        Self { file_per_component: enabled, ..self }
     }
 
     /// Sets the scale factor to be applied to all `px` to `phx` conversions
@@ -171,7 +411,27 @@ pub fn embed_resources(self, kind: EmbedResourcesKind) -> Self {
     pub fn with_scale_factor(self, factor: f32) -> Self {
         let mut config = self.config;
         config.const_scale_factor = factor as f64;
-        Self { config }
+        Self { config, ..self }
+    }
+
+    /// Compiles the .slint files with each of the given styles, so that the style can be selected
+    /// at run-time with [`slint::set_style()`](https://docs.rs/slint/latest/slint/fn.set_style.html)
+    /// before creating a component. The first style is used if no style is selected.
+    ///
+    /// This overrides the style set with [`Self::with_style`]. The generated code contains the components
+    /// once per style, which makes the binary bigger. The generated components are not [`ComponentHandle`]s,
+    /// but they have the same functions, and `as_weak()` returns a type with the same name as the
+    /// component with the `Weak` suffix:
+    /// ```rust,no_run
+    /// let config = slint_build::CompilerConfiguration::new()
+    ///     .with_runtime_styles(vec!["fluent".into(), "material".into()]);
+    /// slint_build::compile_with_config("ui/hello.slint", config).unwrap();
+    /// ```
+    ///
+    /// [`ComponentHandle`]: https://docs.rs/slint/latest/slint/trait.ComponentHandle.html
+    #[must_use]
+    pub fn with_runtime_styles(self, styles: Vec<String>) -> Self {
+        Self { runtime_styles: styles, ..self }
     }
 
     /// Configures the compiler to bundle translations when compiling Slint code.
@@ -187,10 +447,138 @@ pub fn with_bundled_translations(
     ) -> CompilerConfiguration {
         let mut config = self.config;
         config.translation_path_bundle = Some(path.into());
-        Self { config }
+        Self { config, ..self }
+    }
+}
+
+/// The value of a constant passed to [`CompilerConfiguration::with_constants`]
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Constant {
+    /// A `bool`
+    Bool(bool),
+    /// An `int`
+    Int(i32),
+    /// A `float`
+    Float(f64),
+    /// A `string`
+    String(String),
+    /// A `length`, in logical pixels
+    Length(f32),
+    /// A `color`, encoded as `0xAARRGGBB`
+    Color(u32),
+}
+
+impl From<bool> for Constant {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<i32> for Constant {
+    fn from(value: i32) -> Self {
+        Self::Int(value)
     }
 }
 
+impl From<f64> for Constant {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<f32> for Constant {
+    fn from(value: f32) -> Self {
+        Self::Float(value as f64)
+    }
+}
+
+impl From<&str> for Constant {
+    fn from(value: &str) -> Self {
+        Self::String(value.into())
+    }
+}
+
+impl From<String> for Constant {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+/// The path of the document generated for the constants, see [`CompilerConfiguration::with_constants`]
+const CONSTANTS_PATH: &str = "<constants>";
+
+/// Generate the source code of the `Constants` global
+fn constants_source(constants: &[(String, Constant)]) -> Result<String, CompileError> {
+    let mut source = String::from("export global Constants {\n");
+    for (name, value) in constants {
+        let is_valid_name = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !is_valid_name {
+            return Err(CompileError::CompileError(vec![format!(
+                "Invalid name for a constant: '{name}'"
+            )]));
+        }
+        let (ty, value) = match value {
+            Constant::Bool(b) => ("bool", b.to_string()),
+            Constant::Int(i) => ("int", i.to_string()),
+            Constant::Float(f) if f.is_finite() => ("float", f.to_string()),
+            Constant::Length(l) if l.is_finite() => ("length", format!("{l}px")),
+            Constant::Float(_) | Constant::Length(_) => {
+                return Err(CompileError::CompileError(vec![format!(
+                    "The value of the constant '{name}' is not a finite number"
+                )]))
+            }
+            Constant::String(s) => {
+                let mut escaped = String::from('"');
+                for c in s.chars() {
+                    match c {
+                        '"' => escaped += "\\\"",
+                        '\\' => escaped += "\\\\",
+                        '\n' => escaped += "\\n",
+                        c => escaped.push(c),
+                    }
+                }
+                escaped.push('"');
+                ("string", escaped)
+            }
+            Constant::Color(argb) => {
+                ("color", format!("#{:06x}{:02x}", argb & 0xffffff, argb >> 24))
+            }
+        };
+        source += &format!("    out property <{ty}> {name}: {value};\n");
+    }
+    source += "}\n";
+    Ok(source)
+}
+
+#[test]
+fn constants_source_test() {
+    let source = constants_source(&[
+        ("flag".into(), true.into()),
+        ("count".into(), 42.into()),
+        ("ratio".into(), Constant::Float(0.5)),
+        ("title".into(), "Say \"Hi\" \\o/\n".into()),
+        ("button-height".into(), Constant::Length(32.)),
+        ("brand".into(), Constant::Color(0x800080ff)),
+    ])
+    .unwrap();
+    assert_eq!(
+        source,
+        r#"export global Constants {
+    out property <bool> flag: true;
+    out property <int> count: 42;
+    out property <float> ratio: 0.5;
+    out property <string> title: "Say \"Hi\" \\o/\n";
+    out property <length> button-height: 32px;
+    out property <color> brand: #0080ff80;
+}
+"#
+    );
+    assert!(constants_source(&[("not valid".into(), true.into())]).is_err());
+    assert!(constants_source(&[("nan".into(), f64::NAN.into())]).is_err());
+}
+
 /// Error returned by the `compile` function
 #[derive(derive_more::Error, derive_more::Display, Debug)]
 #[non_exhaustive]
@@ -346,6 +734,56 @@ fn format_code(code: &str) -> String {
     );
 }
 
+/// Extract the source map from the formatted generated code, see [`CompilerConfiguration::with_source_map`]
+fn source_map(code: &str) -> String {
+    let mut map = String::new();
+    for (index, line) in code.lines().enumerate() {
+        let Some(pos) = line.find("source_location") else { continue };
+        let Some(args) = line[pos + "source_location".len()..].trim_start().strip_prefix('(')
+        else {
+            continue;
+        };
+        let Some(args) = args.trim_start().strip_prefix('"') else { continue };
+        let mut path = String::new();
+        let mut chars = args.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => break,
+                '\\' => match chars.next() {
+                    Some('n') => path.push('\n'),
+                    Some('t') => path.push('\t'),
+                    Some(c) => path.push(c),
+                    None => break,
+                },
+                c => path.push(c),
+            }
+        }
+        let mut numbers = chars
+            .as_str()
+            .split(',')
+            .skip(1)
+            .map(|n| n.trim_matches(|c: char| !c.is_ascii_digit()).parse::<usize>());
+        if let (Some(Ok(source_line)), Some(Ok(column))) = (numbers.next(), numbers.next()) {
+            map += &format!("{}\t{path}\t{source_line}\t{column}\n", index + 1);
+        }
+    }
+    map
+}
+
+#[test]
+fn source_map_test() {
+    let mut code = Vec::new();
+    CodeFormatter::new(&mut code)
+        .write_all(br#"fn main() { let x = move || { slint :: private_unstable_api :: source_location ("C:\\ui\\app.slint" , 12usize , 5usize) ; 42 } ; }"#)
+        .unwrap();
+    let code = String::from_utf8(code).unwrap();
+    assert_eq!(
+        code.lines().nth(2).unwrap().trim(),
+        r#"slint :: private_unstable_api :: source_location ("C:\\ui\\app.slint" , 12usize , 5usize) ;"#
+    );
+    assert_eq!(source_map(&code), "3\tC:\\ui\\app.slint\t12\t5\n");
+}
+
 /// Compile the `.slint` file and generate rust code for it.
 ///
 /// The generated code code will be created in the directory specified by
@@ -441,11 +879,39 @@ pub fn compile_with_output_path(
         return Err(CompileError::CompileError(vec));
     }
 
+    let typescript_definitions = config.typescript_definitions;
+    let interface_description = config.interface_description;
     let mut compiler_config = config.config;
     compiler_config.translation_domain = std::env::var("CARGO_PKG_NAME").ok();
+    if !config.constants.is_empty() {
+        compiler_config.implicit_globals =
+            Some((CONSTANTS_PATH.into(), constants_source(&config.constants)?));
+    }
 
     let syntax_node = syntax_node.expect("diags contained no compilation errors");
 
+    let mut dependencies: Vec<std::path::PathBuf> = Vec::new();
+    let mut other_styles = Vec::new();
+    if let Some((first_style, styles)) = config.runtime_styles.split_first() {
+        compiler_config.style = Some(first_style.clone());
+        for style in styles {
+            let mut compiler_config = compiler_config.clone();
+            compiler_config.style = Some(style.clone());
+            let (doc, diag, _) = spin_on::spin_on(i_slint_compiler::compile_syntax_node(
+                syntax_node.clone(),
+                BuildDiagnostics::default(),
+                compiler_config,
+            ));
+            if diag.has_errors() {
+                let vec = diag.to_string_vec();
+                diag.print();
+                return Err(CompileError::CompileError(vec));
+            }
+            dependencies.extend(diag.all_loaded_files.iter().filter(|x| x.is_absolute()).cloned());
+            other_styles.push((style.as_str(), doc));
+        }
+    }
+
     // 'spin_on' is ok here because the compiler in single threaded and does not block if there is no blocking future
     let (doc, diag, loader) =
         spin_on::spin_on(i_slint_compiler::compile_syntax_node(syntax_node, diag, compiler_config));
@@ -456,13 +922,33 @@ pub fn compile_with_output_path(
         return Err(CompileError::CompileError(vec));
     }
 
-    let output_file =
-        std::fs::File::create(&output_rust_file_path).map_err(CompileError::SaveError)?;
-    let mut code_formatter = CodeFormatter::new(BufWriter::new(output_file));
-    let generated = i_slint_compiler::generator::rust::generate(&doc, &loader.compiler_config)
-        .map_err(|e| CompileError::CompileError(vec![e.to_string()]))?;
-
-    let mut dependencies: Vec<std::path::PathBuf> = Vec::new();
+    let emit_source_map = loader.compiler_config.emit_source_locations;
+    let output_rust_file_path = output_rust_file_path.as_ref();
+    let mut split_files = Vec::new();
+    let generated = if !other_styles.is_empty() {
+        let first_style = loader.compiler_config.style.as_deref().unwrap_or_default();
+        let docs = std::iter::once((first_style, &doc))
+            .chain(other_styles.iter().map(|(style, doc)| (*style, doc)))
+            .collect::<Vec<_>>();
+        i_slint_compiler::generator::rust::generate_with_runtime_styles(
+            &docs,
+            &loader.compiler_config,
+        )
+    } else if config.file_per_component {
+        let file_prefix = output_rust_file_path.file_stem().unwrap_or_default().to_string_lossy();
+        i_slint_compiler::generator::rust::generate_split(
+            &doc,
+            &loader.compiler_config,
+            &file_prefix,
+        )
+        .map(|(main, files)| {
+            split_files = files;
+            main
+        })
+    } else {
+        i_slint_compiler::generator::rust::generate(&doc, &loader.compiler_config)
+    }
+    .map_err(|e| CompileError::CompileError(vec![e.to_string()]))?;
 
     for x in &diag.all_loaded_files {
         if x.is_absolute() {
@@ -477,9 +963,24 @@ pub fn compile_with_output_path(
         }
     });
 
-    write!(code_formatter, "{}", generated).map_err(CompileError::SaveError)?;
+    if config.file_per_component {
+        let directory = output_rust_file_path.parent().unwrap_or(Path::new(""));
+        for (file_name, code) in &split_files {
+            write_generated_code(&directory.join(file_name), code, emit_source_map)?;
+        }
+    }
+    write_generated_code(output_rust_file_path, &generated, emit_source_map)?;
     dependencies.push(input_slint_file_path.as_ref().to_path_buf());
 
+    if let Some(path) = typescript_definitions {
+        std::fs::write(path, i_slint_compiler::generator::typescript::generate(&doc))
+            .map_err(CompileError::SaveError)?;
+    }
+    if let Some(path) = interface_description {
+        std::fs::write(path, i_slint_compiler::generator::interface::generate(&doc))
+            .map_err(CompileError::SaveError)?;
+    }
+
     for resource in doc.embedded_file_resources.borrow().keys() {
         if !resource.starts_with("builtin:") {
             dependencies.push(Path::new(resource).to_path_buf());
@@ -489,6 +990,31 @@ pub fn compile_with_output_path(
     Ok(dependencies)
 }
 
+/// Formats the generated code and writes it to `path`, along with its source map if
+/// `emit_source_map` is set. The files are left untouched if their content didn't change, so
+/// that their modification time is preserved.
+fn write_generated_code(
+    path: &Path,
+    generated: &impl std::fmt::Display,
+    emit_source_map: bool,
+) -> Result<(), CompileError> {
+    let mut code = Vec::new();
+    write!(CodeFormatter::new(&mut code), "{}", generated).map_err(CompileError::SaveError)?;
+    if emit_source_map {
+        let mut map_path = path.as_os_str().to_owned();
+        map_path.push(".map");
+        write_if_changed(Path::new(&map_path), source_map(&String::from_utf8_lossy(&code)))?;
+    }
+    write_if_changed(path, code)
+}
+
+fn write_if_changed(path: &Path, content: impl AsRef<[u8]>) -> Result<(), CompileError> {
+    if std::fs::read(path).is_ok_and(|old| old == content.as_ref()) {
+        return Ok(());
+    }
+    std::fs::write(path, content).map_err(CompileError::SaveError)
+}
+
 /// This function is for use the application's build script, in order to print any device specific
 /// build flags reported by the backend
 pub fn print_rustc_flags() -> std::io::Result<()> {
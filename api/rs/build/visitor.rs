@@ -0,0 +1,211 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+Visitors that inspect and modify the elements of the .slint files before the code generation.
+
+A visitor is registered with [`CompilerConfiguration::with_custom_pass`](crate::CompilerConfiguration::with_custom_pass).
+The API is versioned: the types of a version module, like [`v1`], stay source compatible, and
+incompatible changes are only made in a new version module.
+*/
+
+/// Version 1 of the visitor API.
+pub mod v1 {
+    use i_slint_compiler::diagnostics::{BuildDiagnostics, Spanned};
+    use i_slint_compiler::expression_tree::{BindingExpression, Expression, Unit};
+    use i_slint_compiler::langtype::Type;
+    use i_slint_compiler::object_tree::ElementRc;
+    use i_slint_compiler::parser::normalize_identifier;
+
+    /// A visitor that is called for each element of the components of the main .slint file.
+    ///
+    /// This is implemented for closures that take an [`Element`] and [`Diagnostics`].
+    pub trait Visitor {
+        /// Called for each element, parents before their children.
+        fn visit_element(&mut self, element: &mut Element, diagnostics: &mut Diagnostics);
+    }
+
+    impl<F: FnMut(&mut Element, &mut Diagnostics)> Visitor for F {
+        fn visit_element(&mut self, element: &mut Element, diagnostics: &mut Diagnostics) {
+            self(element, diagnostics)
+        }
+    }
+
+    /// The constant value of a binding.
+    #[derive(Debug, Clone, PartialEq)]
+    #[non_exhaustive]
+    pub enum Value {
+        /// A `bool`
+        Bool(bool),
+        /// An `int` or a `float` without unit
+        Number(f64),
+        /// A `string`
+        String(String),
+    }
+
+    /// An element of a component, as passed to [`Visitor::visit_element`].
+    pub struct Element<'a> {
+        element: &'a ElementRc,
+        component_name: &'a str,
+    }
+
+    impl Element<'_> {
+        /// Returns the id of the element, or an empty string if it doesn't have one.
+        pub fn id(&self) -> String {
+            self.element.borrow().id.to_string()
+        }
+
+        /// Returns the name of the type of the element, like `Rectangle` or the name of a component.
+        pub fn type_name(&self) -> String {
+            self.element.borrow().base_type.to_string()
+        }
+
+        /// Returns the name of the component that contains the element.
+        pub fn component_name(&self) -> &str {
+            self.component_name
+        }
+
+        /// Returns the names of the properties and callbacks that have a binding in this element.
+        pub fn bindings(&self) -> Vec<String> {
+            self.element.borrow().bindings.keys().map(|name| name.to_string()).collect()
+        }
+
+        /// Returns true if the property or callback has a binding in this element.
+        pub fn has_binding(&self, name: &str) -> bool {
+            self.element.borrow().bindings.contains_key(&normalize_identifier(name))
+        }
+
+        /// Returns the value of the binding of the property if it is a literal.
+        pub fn binding_value(&self, name: &str) -> Option<Value> {
+            let element = self.element.borrow();
+            let binding = element.bindings.get(&normalize_identifier(name))?.borrow();
+            let mut expression = &binding.expression;
+            while let Expression::Cast { from, .. } = expression {
+                expression = from;
+            }
+            match expression {
+                Expression::BoolLiteral(value) => Some(Value::Bool(*value)),
+                Expression::NumberLiteral(value, Unit::None) => Some(Value::Number(*value)),
+                Expression::StringLiteral(value) => Some(Value::String(value.to_string())),
+                _ => None,
+            }
+        }
+
+        /// Sets the binding of the property to the value, replacing any existing binding.
+        ///
+        /// Returns an error if the element has no such property, or if the value doesn't match
+        /// the type of the property.
+        pub fn set_binding(&mut self, name: &str, value: Value) -> Result<(), String> {
+            let name = normalize_identifier(name);
+            let mut element = self.element.borrow_mut();
+            let property_type = element.lookup_property(&name).property_type;
+            let expression = match (value, &property_type) {
+                (Value::Bool(value), Type::Bool) => Expression::BoolLiteral(value),
+                (Value::Number(value), Type::Int32 | Type::Float32) => {
+                    Expression::NumberLiteral(value, Unit::None)
+                }
+                (Value::String(value), Type::String) => Expression::StringLiteral(value.into()),
+                (_, Type::Invalid) => return Err(format!("Unknown property {name}")),
+                (value, _) => {
+                    return Err(format!(
+                        "Cannot set the property {name} of type {property_type} to {value:?}"
+                    ))
+                }
+            };
+            let span = element.to_source_location();
+            element
+                .bindings
+                .insert(name, BindingExpression::new_with_span(expression, span).into());
+            Ok(())
+        }
+    }
+
+    /// Collects the errors and warnings reported by a [`Visitor`].
+    ///
+    /// Errors make the compilation fail.
+    pub struct Diagnostics<'a> {
+        diag: &'a mut BuildDiagnostics,
+    }
+
+    impl Diagnostics<'_> {
+        /// Reports an error at the location of the element.
+        pub fn error(&mut self, element: &Element, message: impl Into<String>) {
+            self.diag.push_error(message.into(), &*element.element.borrow());
+        }
+
+        /// Reports a warning at the location of the element.
+        pub fn warning(&mut self, element: &Element, message: impl Into<String>) {
+            self.diag.push_warning(message.into(), &*element.element.borrow());
+        }
+    }
+
+    pub(crate) fn into_custom_pass(
+        visitor: impl Visitor + 'static,
+    ) -> i_slint_compiler::CustomPass {
+        let visitor = std::cell::RefCell::new(visitor);
+        std::rc::Rc::new(move |doc, diag| {
+            let mut visitor = visitor.borrow_mut();
+            let mut diagnostics = Diagnostics { diag };
+            for component in &doc.inner_components {
+                i_slint_compiler::object_tree::recurse_elem(
+                    &component.root_element,
+                    &(),
+                    &mut |element, _| {
+                        let mut element = Element { element, component_name: &component.id };
+                        visitor.visit_element(&mut element, &mut diagnostics);
+                    },
+                );
+            }
+        })
+    }
+}
+
+#[test]
+fn visitor_test() {
+    use i_slint_compiler::diagnostics::BuildDiagnostics;
+    use v1::{Diagnostics, Element, Value};
+    let mut compiler_config = i_slint_compiler::CompilerConfiguration::new(
+        i_slint_compiler::generator::OutputFormat::Rust,
+    );
+    compiler_config.style = Some("fluent".into());
+    let seen = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+    let seen_ = seen.clone();
+    compiler_config.custom_passes.push(v1::into_custom_pass(
+        move |element: &mut Element, diag: &mut Diagnostics| {
+            seen_.borrow_mut().push((element.component_name().to_string(), element.type_name()));
+            if element.id() == "forbidden" {
+                diag.error(element, "'forbidden' is not allowed");
+            }
+            if element.has_binding("analytics_id") {
+                assert_eq!(element.binding_value("analytics-id"), Some(Value::Number(1.)));
+                element.set_binding("analytics-id", Value::Number(42.)).unwrap();
+                assert!(element.set_binding("analytics-id", Value::Bool(true)).is_err());
+                assert!(element.set_binding("unknown", Value::Bool(true)).is_err());
+            }
+        },
+    ));
+    let mut diag = BuildDiagnostics::default();
+    let node = i_slint_compiler::parser::parse(
+        r#"
+        export component Main inherits Window {
+            out property <int> analytics-id: 1;
+            forbidden := Rectangle {}
+        }
+        "#
+        .into(),
+        Some(std::path::Path::new("main.slint")),
+        &mut diag,
+    );
+    let (doc, diag, _) =
+        spin_on::spin_on(i_slint_compiler::compile_syntax_node(node, diag, compiler_config));
+    assert_eq!(diag.to_string_vec(), ["main.slint:4: 'forbidden' is not allowed"]);
+    assert_eq!(
+        *seen.borrow(),
+        [("Main".to_string(), "Window".to_string()), ("Main".to_string(), "Rectangle".to_string())]
+    );
+    let root = doc.inner_components[0].root_element.borrow();
+    assert!(matches!(
+        root.bindings["analytics-id"].borrow().expression,
+        i_slint_compiler::expression_tree::Expression::NumberLiteral(x, _) if x == 42.
+    ));
+}
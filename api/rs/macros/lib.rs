@@ -359,6 +359,11 @@ fn extract_compiler_config(
                             _ => break,
                         }
                     }
+                    Some(TokenTree::Ident(toasts_ident))
+                        if toasts_ident.to_string() == "toasts" && attr_stream.next().is_none() =>
+                    {
+                        compiler_config.toasts = true;
+                    }
                     _ => break,
                 }
             }
@@ -377,6 +382,8 @@ fn extract_compiler_config(
 /// When `import`ing `.slint` files or loading images with `@image-url`, the specified paths are relative to the
 /// the directory that contains Cargo.toml.
 ///
+/// Start the macro with `#[toasts]` to show the toasts of `slint::Toast` in the exported windows.
+///
 /// ### Limitations
 ///
 /// Within `.slint` files, you can interpolate string literals using `\{...}` syntax.
@@ -77,6 +77,20 @@
 //!
 //!
 //! Note Slint does not require a specific build tool and can work with others, such as [xbuild](https://github.com/rust-mobile/xbuild).
+//!
+//! ## Assets
+//!
+//! Images and fonts that aren't embedded in the binary can be shipped in the `assets/` directory of the APK, and
+//! loaded with an `asset://` URL, which is relative to that directory:
+//!
+//! ```slint,no-preview
+//! export component Example inherits Window {
+//!     Image { source: @image-url("asset://images/logo.png"); }
+//! }
+//! ```
+//!
+//! The same URLs can be passed to [`Image::load_from_path()`](crate::Image::load_from_path). They're always loaded at run-time,
+//! even when the compiler embeds the other resources.
 
 /// Re-export of the android-activity crate.
 #[cfg(all(
@@ -149,3 +163,78 @@ pub fn init_with_event_listener(
         i_slint_backend_android_activity::AndroidPlatform::new_with_event_listener(app, listener),
     ))
 }
+
+/// Returns the intent that started the application.
+///
+/// **Note:** This function is only available on Android with the "backend-android-activity-06" feature
+///
+/// Use it to handle deep links or the content shared by other applications: declare an `intent-filter`
+/// for the activity in the `AndroidManifest.xml`, and read the [`Intent::data`] or [`Intent::text`] after
+/// calling [`init()`].
+///
+/// ```rust,no_run
+/// # #[cfg(target_os = "android")]
+/// # fn example() -> Result<(), slint::PlatformError> {
+/// if let Some(intent) = slint::android::launch_intent()? {
+///     if let Some(url) = intent.data {
+///         eprintln!("Opened with the deep link {url}");
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub use i_slint_backend_android_activity::launch_intent;
+
+/// Opens a URL with the application that handles it, such as the web browser.
+///
+/// **Note:** This function is only available on Android with the "backend-android-activity-06" feature
+pub use i_slint_backend_android_activity::open_url;
+
+/// Shows the share sheet of the system to send a text to another application.
+///
+/// **Note:** This function is only available on Android with the "backend-android-activity-06" feature
+///
+/// The `subject` is used by applications such as email clients.
+pub use i_slint_backend_android_activity::share_text;
+
+/// Shows the share sheet of the system to send an image, and optionally a text, to another application.
+///
+/// **Note:** This function is only available on Android with the "backend-android-activity-06" feature
+///
+/// The image is stored as a PNG file in the pictures of the device, so that the other application can read it.
+/// Before Android 10, this requires the `WRITE_EXTERNAL_STORAGE` permission.
+pub use i_slint_backend_android_activity::share_image;
+
+/// Sets the function that receives the text and the files that another application shares with this one.
+///
+/// **Note:** This function is only available on Android with the "backend-android-activity-06" feature
+///
+/// Declare an `intent-filter` for the `android.intent.action.SEND` action in the `AndroidManifest.xml`
+/// so that the application appears in the share sheet of the system.
+pub use i_slint_backend_android_activity::on_shared_content;
+
+pub use i_slint_backend_android_activity::SharedContent;
+
+pub use i_slint_backend_android_activity::Intent;
+
+/// Sets the function that is called when the activity is started, stopped, or needs to save its state.
+///
+/// **Note:** This function is only available on Android with the "backend-android-activity-06" feature
+///
+/// Slint pauses rendering and timers while the activity is stopped.
+///
+/// ```rust,no_run
+/// # #[cfg(target_os = "android")]
+/// # fn example() -> Result<(), slint::PlatformError> {
+/// use slint::android::LifecycleEvent;
+/// slint::android::on_lifecycle_event(|event| match event {
+///     LifecycleEvent::SaveState { state } => state.extend_from_slice(b"current page"),
+///     LifecycleEvent::Resume { saved_state: Some(state) } => eprintln!("Restore {state:?}"),
+///     _ => {}
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+pub use i_slint_backend_android_activity::on_lifecycle_event;
+
+pub use i_slint_backend_android_activity::LifecycleEvent;
@@ -214,18 +214,26 @@ struct MyComponent { /*...*/ }
 #[doc(hidden)]
 #[deprecated(note = "Experimental type was made public by mistake")]
 pub use i_slint_core::component_factory::ComponentFactory;
+#[cfg(feature = "std")]
+pub use i_slint_core::dialogs;
 #[cfg(not(target_arch = "wasm32"))]
 pub use i_slint_core::graphics::{BorrowedOpenGLTextureBuilder, BorrowedOpenGLTextureOrigin};
 // keep in sync with internal/interpreter/api.rs
+#[cfg(feature = "std")]
+pub use i_slint_core::graphics::VideoFrameSender;
 pub use i_slint_core::graphics::{
     Brush, Color, Image, LoadImageError, Rgb8Pixel, Rgba8Pixel, RgbaColor, SharedPixelBuffer,
+    VideoFrame, VideoFrameProvider, VideoSource,
 };
+pub use i_slint_core::items::TextSpan;
 pub use i_slint_core::model::{
     FilterModel, MapModel, Model, ModelExt, ModelNotify, ModelPeer, ModelRc, ModelTracker,
     ReverseModel, SortModel, StandardListViewItem, TableColumn, VecModel,
 };
 pub use i_slint_core::sharedvector::SharedVector;
 pub use i_slint_core::timers::{Timer, TimerMode};
+#[cfg(feature = "fluent")]
+pub use i_slint_core::translations::{load_fluent_translations, FluentSyntaxError};
 pub use i_slint_core::translations::{select_bundled_translation, SelectBundledTranslationError};
 pub use i_slint_core::{
     format,
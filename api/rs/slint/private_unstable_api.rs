@@ -127,6 +127,10 @@ pub fn set_callback_handler<
     })
 }
 
+/// Marks the location in the .slint file of the code that follows in the generated code
+#[inline(always)]
+pub fn source_location(_path: &'static str, _line: usize, _column: usize) {}
+
 pub fn debug(s: SharedString) {
     #[cfg(feature = "log")]
     log::debug!("{s}");
@@ -144,6 +148,9 @@ pub fn ensure_backend() -> Result<(), crate::PlatformError> {
 /// Creates a new window to render components in.
 pub fn create_window_adapter(
 ) -> Result<alloc::rc::Rc<dyn i_slint_core::window::WindowAdapter>, crate::PlatformError> {
+    if let Some(adapter) = i_slint_core::offscreen::take_pending_window_adapter() {
+        return Ok(adapter);
+    }
     i_slint_backend_selector::with_platform(|b| b.create_window_adapter())
 }
 
@@ -187,13 +194,14 @@ pub mod re_exports {
         AccessibilityAction, AccessibleStringProperty, SupportedAccessibilityAction,
     };
     pub use i_slint_core::animations::{animation_tick, EasingCurve};
-    pub use i_slint_core::api::LogicalPosition;
+    pub use i_slint_core::api::{LogicalPosition, SystemClipboard};
     pub use i_slint_core::callbacks::Callback;
+    pub use i_slint_core::charts::*;
     pub use i_slint_core::date_time::*;
     pub use i_slint_core::graphics::*;
     pub use i_slint_core::input::{
-        key_codes::Key, FocusEvent, InputEventResult, KeyEvent, KeyEventResult, KeyboardModifiers,
-        MouseEvent,
+        key_codes::Key, FocusEvent, InputEventResult, KeyEvent, KeyEventResult, KeyEventType,
+        KeyboardModifiers, MouseEvent,
     };
     pub use i_slint_core::item_tree::{
         register_item_tree, unregister_item_tree, IndexRange, ItemTree, ItemTreeRefPin,
@@ -209,6 +217,7 @@ pub mod re_exports {
         logical_position_to_api, LogicalLength, LogicalPoint, LogicalRect,
     };
     pub use i_slint_core::model::*;
+    pub use i_slint_core::number_format::*;
     pub use i_slint_core::properties::{
         set_state_binding, ChangeTracker, Property, PropertyTracker, StateInfo,
     };
@@ -217,6 +226,7 @@ pub mod re_exports {
     pub use i_slint_core::translations::{
         set_bundled_languages, translate_from_bundle, translate_from_bundle_with_plural,
     };
+    pub use i_slint_core::window::window_manager::{close_secondary_window, show_secondary_window};
     pub use i_slint_core::window::{
         InputMethodRequest, Menu, MenuVTable, WindowAdapter, WindowAdapterRc, WindowInner,
     };
@@ -0,0 +1,94 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::platform::software_renderer::SoftwareRenderer;
+use slint::platform::{
+    OffscreenWindowAdapter, Platform, PlatformError, Renderer, WindowAdapter, WindowEvent,
+};
+use slint::{Color, ComponentHandle, PhysicalSize, Rgba8Pixel};
+use std::cell::Cell;
+use std::rc::Rc;
+
+struct OffscreenPlatform;
+
+impl Platform for OffscreenPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(OffscreenWindowAdapter::new())
+    }
+}
+
+thread_local! {
+    static OFFSCREEN_RENDERERS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// A platform with windows, which renders offscreen windows with its own renderer
+struct WindowingPlatform;
+
+impl Platform for WindowingPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Err("The test doesn't create any window".into())
+    }
+
+    fn create_offscreen_renderer(&self) -> Result<Box<dyn Renderer>, PlatformError> {
+        OFFSCREEN_RENDERERS.with(|count| count.set(count.get() + 1));
+        Ok(Box::new(SoftwareRenderer::new()))
+    }
+}
+
+slint::slint! {
+    export component Thumbnail inherits Window {
+        in property <color> left-color: red;
+        Rectangle {
+            x: 0;
+            width: parent.width / 2;
+            background: left-color;
+        }
+        Rectangle {
+            x: parent.width / 2;
+            width: parent.width / 2;
+            background: blue;
+        }
+    }
+}
+
+#[test]
+fn render_offscreen() {
+    slint::platform::set_platform(Box::new(OffscreenPlatform)).unwrap();
+    let thumbnail = Thumbnail::new().unwrap();
+    thumbnail.window().dispatch_event(WindowEvent::ScaleFactorChanged { scale_factor: 2.0 });
+    thumbnail.window().set_size(PhysicalSize::new(40, 20));
+    assert_eq!(thumbnail.window().size(), PhysicalSize::new(40, 20));
+    assert_eq!(thumbnail.window().scale_factor(), 2.0);
+
+    let image = thumbnail.window().take_snapshot().unwrap();
+    assert_eq!((image.width(), image.height()), (40, 20));
+    assert_eq!(pixel(&image, 5, 10), Rgba8Pixel::new(255, 0, 0, 255));
+    assert_eq!(pixel(&image, 35, 10), Rgba8Pixel::new(0, 0, 255, 255));
+
+    thumbnail.set_left_color(Color::from_rgb_u8(0, 255, 0));
+    thumbnail.window().set_size(PhysicalSize::new(80, 20));
+    let image = thumbnail.window().take_snapshot().unwrap();
+    assert_eq!((image.width(), image.height()), (80, 20));
+    assert_eq!(pixel(&image, 35, 10), Rgba8Pixel::new(0, 255, 0, 255));
+    assert_eq!(pixel(&image, 45, 10), Rgba8Pixel::new(0, 0, 255, 255));
+}
+
+#[test]
+fn create_component_next_to_windows() {
+    slint::platform::set_platform(Box::new(WindowingPlatform)).unwrap();
+    let adapter = OffscreenWindowAdapter::new();
+    let thumbnail = adapter.create_component(Thumbnail::new).unwrap();
+    thumbnail.window().set_size(PhysicalSize::new(40, 20));
+    let image = thumbnail.window().take_snapshot().unwrap();
+    assert_eq!((image.width(), image.height()), (40, 20));
+    assert_eq!(pixel(&image, 5, 10), Rgba8Pixel::new(255, 0, 0, 255));
+    assert_eq!(pixel(&image, 35, 10), Rgba8Pixel::new(0, 0, 255, 255));
+    assert_eq!(OFFSCREEN_RENDERERS.with(Cell::get), 1);
+
+    // The window adapter is only used by one component
+    assert!(adapter.create_component(Thumbnail::new).is_err());
+}
+
+fn pixel(image: &slint::SharedPixelBuffer<Rgba8Pixel>, x: usize, y: usize) -> Rgba8Pixel {
+    image.as_slice()[y * image.width() as usize + x]
+}
@@ -281,3 +281,58 @@ fn scale_factor() {
         do_test_render_region(renderer, 0, 0, 500, 500);
     }));
 }
+
+#[test]
+fn frame_statistics() {
+    slint::slint! {
+        export component Ui inherits Window {
+            in property <color> c: yellow;
+            background: black;
+            Rectangle {
+                x: 10phx;
+                y: 20phx;
+                width: 30phx;
+                height: 40phx;
+                background: c;
+            }
+            Rectangle {
+                x: 100phx;
+                y: 20phx;
+                width: 30phx;
+                height: 40phx;
+                background: red;
+            }
+        }
+    }
+
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = Ui::new().unwrap();
+    let window = WINDOW.with(|x| x.clone());
+    window.set_size(slint::PhysicalSize::new(180, 260));
+    ui.show().unwrap();
+    assert_eq!(ui.window().last_frame_statistics(), None);
+
+    assert!(window.draw_if_needed(|renderer| {
+        do_test_render_region(renderer, 0, 0, 180, 260);
+    }));
+    let statistics = ui.window().last_frame_statistics().unwrap();
+    // The window and both rectangles
+    assert_eq!(statistics.items_rendered, 3);
+    assert_eq!(
+        statistics.dirty_region,
+        Some(vec![(PhysicalPosition::new(0, 0), PhysicalSize::new(180, 260))])
+    );
+    assert_eq!(statistics.layers_created, None);
+
+    ui.set_c(slint::Color::from_rgb_u8(45, 12, 13));
+    assert!(window.draw_if_needed(|renderer| {
+        do_test_render_region(renderer, 10, 20, 40, 60);
+    }));
+    let statistics = ui.window().last_frame_statistics().unwrap();
+    // The second rectangle is outside of the dirty region
+    assert_eq!(statistics.items_rendered, 2);
+    assert_eq!(
+        statistics.dirty_region,
+        Some(vec![(PhysicalPosition::new(10, 20), PhysicalSize::new(30, 40))])
+    );
+}
@@ -279,6 +279,10 @@ export default defineConfig({
                                                 label: "Math",
                                                 slug: "reference/global-functions/math",
                                             },
+                                            {
+                                                label: "Clipboard",
+                                                slug: "reference/global-functions/clipboard",
+                                            },
                                             {
                                                 label: "animation-tick() / debug()",
                                                 slug: "reference/global-functions/builtinfunctions",
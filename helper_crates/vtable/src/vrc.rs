@@ -208,6 +208,19 @@ pub fn map_dyn<MappedType: ?Sized>(
     ) -> VRcMapped<VTable, MappedType> {
         VRcMapped { parent_strong: this.clone(), object: map_fn(Self::borrow_pin(&this)).get_ref() }
     }
+    /// Convert a VRc of a Dyn instance back to a VRc of the real instance, if the instance is of
+    /// type `X`. Otherwise, `this` is returned as the error.
+    pub fn downcast<X: HasStaticVTable<VTable> + 'static>(
+        this: Self,
+    ) -> Result<VRc<VTable, X>, Self> {
+        let vtable = unsafe { *core::ptr::addr_of!((*this.inner.as_ptr()).vtable) };
+        if core::ptr::eq(vtable, X::static_vtable()) {
+            // Safety: the vtable is the one of X, so this VRc was created with VRc::<VTable, X>::new
+            Ok(unsafe { core::mem::transmute::<Self, VRc<VTable, X>>(this) })
+        } else {
+            Err(this)
+        }
+    }
 }
 impl<VTable: VTableMetaDropInPlace, X> VRc<VTable, X> {
     /// Create a Pinned reference to the inner.
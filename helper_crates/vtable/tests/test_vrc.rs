@@ -105,6 +105,19 @@ fn rc_dyn_test() {
     assert_eq!(Rc::strong_count(&string), 1);
 }
 
+#[test]
+fn rc_downcast_test() {
+    let string = Rc::new("hello".to_string());
+    let rc: VRc<FooVTable> =
+        VRc::into_dyn(VRc::new(SomeStruct { e: 42, x: "44".into(), foo: string.clone() }));
+    let rc = VRc::downcast::<SyncStruct>(rc).unwrap_err();
+    let rc = VRc::downcast::<SomeStruct>(rc).ok().unwrap();
+    assert_eq!(rc.e, 42);
+    assert_eq!(VRc::strong_count(&rc), 1);
+    drop(rc);
+    assert_eq!(Rc::strong_count(&string), 1);
+}
+
 #[derive(Debug, const_field_offset::FieldOffsets)]
 #[repr(C)]
 struct SyncStruct {
@@ -3,15 +3,18 @@
 
 use super::*;
 use crate::javahelper::{print_jni_error, JavaHelper};
+use crate::lifecycle::{LifecycleEvent, LifecycleHandler};
 use android_activity::input::{
     ButtonState, InputEvent, KeyAction, Keycode, MotionAction, MotionEvent,
 };
 use android_activity::{InputStatus, MainEvent, PollEvent};
 use i_slint_core::api::{LogicalPosition, PhysicalPosition, PhysicalSize, PlatformError, Window};
+use i_slint_core::callbacks::CallbackSlot;
 use i_slint_core::items::ColorScheme;
 use i_slint_core::platform::{
     Key, PointerEventButton, WindowAdapter, WindowEvent, WindowProperties,
 };
+use i_slint_core::renderer::RendererSealed;
 use i_slint_core::timers::{Timer, TimerMode};
 use i_slint_core::window::{InputMethodRequest, WindowInner};
 use i_slint_core::{Property, SharedString};
@@ -25,7 +28,7 @@ struct LongPressDetection {
 }
 
 pub struct AndroidWindowAdapter {
-    app: AndroidApp,
+    pub(crate) app: AndroidApp,
     pub(crate) window: Window,
     pub(crate) renderer: i_slint_renderer_skia::SkiaRenderer,
     pub(crate) event_queue: EventQueue,
@@ -33,6 +36,7 @@ pub struct AndroidWindowAdapter {
     pub(super) java_helper: JavaHelper,
     pub(crate) color_scheme: core::pin::Pin<Box<Property<ColorScheme>>>,
     pub(crate) fullscreen: Cell<bool>,
+    keep_screen_on: Cell<bool>,
     /// The offset at which the Slint view is drawn in the native window (account for status bar)
     pub offset: Cell<PhysicalPosition>,
 
@@ -42,6 +46,13 @@ pub struct AndroidWindowAdapter {
 
     long_press: RefCell<Option<LongPressDetection>>,
     last_pressed_state: Cell<ButtonState>,
+    /// Whether the back key that is pressed was handled by the window, so that its release is handled as well
+    back_key_handled: Cell<bool>,
+    /// Whether the back gesture of Android 13 and later is intercepted, see [`WindowInner::handles_back_request`]
+    back_callback_enabled: Cell<bool>,
+    /// Whether the activity is stopped, and rendering and timers are paused
+    pub(crate) stopped: Cell<bool>,
+    pub(crate) lifecycle_handler: CallbackSlot<LifecycleHandler>,
 }
 
 impl WindowAdapter for AndroidWindowAdapter {
@@ -71,6 +82,16 @@ fn update_window_properties(&self, properties: WindowProperties<'_>) {
         if self.fullscreen.replace(f) != f {
             self.resize().unwrap();
         }
+        let keep_screen_on = properties.inhibits_screensaver();
+        if self.keep_screen_on.replace(keep_screen_on) != keep_screen_on {
+            use android_activity::WindowManagerFlags;
+            let flag = WindowManagerFlags::KEEP_SCREEN_ON;
+            if keep_screen_on {
+                self.app.set_window_flags(flag, WindowManagerFlags::empty());
+            } else {
+                self.app.set_window_flags(WindowManagerFlags::empty(), flag);
+            }
+        }
     }
 
     fn internal(
@@ -186,10 +207,15 @@ pub fn new(app: AndroidApp) -> Rc<Self> {
             color_scheme,
             java_helper,
             fullscreen: Cell::new(false),
+            keep_screen_on: Cell::new(false),
             offset: Default::default(),
             show_cursor_handles: Cell::new(false),
             long_press: RefCell::default(),
             last_pressed_state: Cell::new(ButtonState(0)),
+            back_key_handled: Cell::new(false),
+            back_callback_enabled: Cell::new(false),
+            stopped: Cell::new(false),
+            lifecycle_handler: CallbackSlot::default(),
         })
     }
 
@@ -231,7 +257,9 @@ pub fn process_event(&self, event: &PollEvent<'_>) -> Result<ControlFlow<()>, Pl
                 }
             }
             PollEvent::Main(
-                MainEvent::WindowResized { .. } | MainEvent::ContentRectChanged { .. },
+                MainEvent::WindowResized { .. }
+                | MainEvent::ContentRectChanged { .. }
+                | MainEvent::InsetsChanged { .. },
             ) => self.resize()?,
             PollEvent::Main(MainEvent::RedrawNeeded { .. }) => {
                 self.pending_redraw.set(false);
@@ -250,10 +278,44 @@ pub fn process_event(&self, event: &PollEvent<'_>) -> Result<ControlFlow<()>, Pl
                 if (scale_factor - self.window.scale_factor()).abs() > f32::EPSILON {
                     self.window
                         .try_dispatch_event(WindowEvent::ScaleFactorChanged { scale_factor })?;
-                    self.window.try_dispatch_event(WindowEvent::Resized {
-                        size: self.size().to_logical(scale_factor),
-                    })?;
                 }
+                // The activity isn't recreated on rotation or when a foldable is unfolded if it handles
+                // these configuration changes, so relayout into the existing surface
+                self.resize()?;
+            }
+            PollEvent::Main(MainEvent::Start) => {
+                self.stopped.set(false);
+                self.pending_redraw.set(true);
+                self.java_helper
+                    .set_hinge_sensor_enabled(true)
+                    .unwrap_or_else(|e| print_jni_error(&self.app, e));
+                self.dispatch_lifecycle_event(LifecycleEvent::Start);
+            }
+            PollEvent::Main(MainEvent::Resume { loader, .. }) => {
+                let saved_state = loader.load();
+                self.dispatch_lifecycle_event(LifecycleEvent::Resume {
+                    saved_state: saved_state.as_deref(),
+                });
+            }
+            PollEvent::Main(MainEvent::Pause) => {
+                self.dispatch_lifecycle_event(LifecycleEvent::Pause)
+            }
+            PollEvent::Main(MainEvent::Stop) => {
+                self.stopped.set(true);
+                self.java_helper
+                    .set_hinge_sensor_enabled(false)
+                    .unwrap_or_else(|e| print_jni_error(&self.app, e));
+                self.dispatch_lifecycle_event(LifecycleEvent::Stop);
+            }
+            PollEvent::Main(MainEvent::SaveState { saver, .. }) => {
+                let mut state = Vec::new();
+                self.dispatch_lifecycle_event(LifecycleEvent::SaveState { state: &mut state });
+                if !state.is_empty() {
+                    saver.store(&state);
+                }
+            }
+            PollEvent::Main(MainEvent::LowMemory) => {
+                self.dispatch_lifecycle_event(LifecycleEvent::LowMemory)
             }
             PollEvent::Main(MainEvent::Destroy) => {
                 return Ok(ControlFlow::Break(()));
@@ -263,12 +325,28 @@ pub fn process_event(&self, event: &PollEvent<'_>) -> Result<ControlFlow<()>, Pl
         Ok(ControlFlow::Continue(()))
     }
 
+    fn dispatch_lifecycle_event(&self, event: LifecycleEvent<'_>) {
+        self.lifecycle_handler.call(|handler| handler(event));
+    }
+
     fn process_inputs(&self) -> Result<(), PlatformError> {
         let mut iter =
             self.app.input_events_iter().map_err(|e| PlatformError::Other(e.to_string()))?;
         loop {
             let mut result = Ok(());
             let read_input = iter.next(|event| match event {
+                InputEvent::KeyEvent(key_event) if key_event.key_code() == Keycode::Back => {
+                    // The default action of Android (closing the activity) happens if the key isn't handled
+                    if key_event.action() == KeyAction::Down && key_event.repeat_count() == 0 {
+                        self.back_key_handled
+                            .set(WindowInner::from_pub(&self.window).request_back());
+                    }
+                    if self.back_key_handled.get() {
+                        InputStatus::Handled
+                    } else {
+                        InputStatus::Unhandled
+                    }
+                }
                 InputEvent::KeyEvent(key_event) => match map_key_event(key_event) {
                     Some(ev) => {
                         result = self.window.try_dispatch_event(ev);
@@ -410,11 +488,45 @@ fn resize(&self) -> Result<(), PlatformError> {
         self.window.try_dispatch_event(WindowEvent::Resized {
             size: size.to_logical(self.window.scale_factor()),
         })?;
+        // The Slint view is drawn at an offset in the surface, which must keep the size of the native window
+        self.renderer
+            .resize(PhysicalSize { width: win.width() as u32, height: win.height() as u32 })?;
         self.offset.set(offset);
+
+        // The insets are relative to the native window, but only the part that overlaps the Slint view matters
+        let [left, top, right, bottom] = self
+            .java_helper
+            .get_safe_area_insets()
+            .unwrap_or_else(|e| print_jni_error(&self.app, e));
+        let scale_factor = self.window.scale_factor();
+        let inset = |v: i32| v.max(0) as f32 / scale_factor;
+        self.window.try_dispatch_event(WindowEvent::SafeAreaInsetsChanged {
+            left: inset(left - offset.x),
+            top: inset(top - offset.y),
+            right: inset(right - (win.width() - offset.x - size.width as i32)),
+            bottom: inset(bottom - (win.height() - offset.y - size.height as i32)),
+        })?;
+
+        // In full screen, the keyboard covers the bottom of the Slint view, otherwise the view is above it
+        let keyboard_height = self
+            .java_helper
+            .get_keyboard_height()
+            .unwrap_or_else(|e| print_jni_error(&self.app, e));
+        self.window.try_dispatch_event(WindowEvent::VirtualKeyboardHeightChanged {
+            height: inset(keyboard_height - (win.height() - offset.y - size.height as i32)),
+        })?;
         Ok(())
     }
 
     pub fn do_render(&self) -> Result<(), PlatformError> {
+        // Only intercept the predictive back gesture when the window handles it, so that Android can
+        // otherwise animate the return to the home screen
+        let handles_back = WindowInner::from_pub(&self.window).handles_back_request();
+        if self.back_callback_enabled.replace(handles_back) != handles_back {
+            self.java_helper
+                .set_back_callback_enabled(handles_back)
+                .unwrap_or_else(|e| print_jni_error(&self.app, e));
+        }
         if let Some(win) = self.app.native_window() {
             let o = self.offset.get();
             self.renderer.render_transformed_with_post_callback(
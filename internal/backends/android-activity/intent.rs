@@ -0,0 +1,209 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Interoperation with the other applications of the system through Android intents
+
+use super::*;
+use crate::javahelper::JavaHelper;
+use i_slint_core::dialogs::{FileDialogFuture, FileDialogOptions, FileHandle};
+use i_slint_core::graphics::Image;
+use jni::objects::{JClass, JString};
+use jni::sys::jint;
+use jni::JNIEnv;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::task::{Poll, Waker};
+
+/// The intent that started the application, as returned by [`launch_intent()`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Intent {
+    /// The action of the intent, for example `android.intent.action.VIEW` for a deep link,
+    /// or `android.intent.action.MAIN` when the application is started from the launcher
+    pub action: Option<String>,
+    /// The data URI of the intent, for example the URL of a deep link
+    pub data: Option<String>,
+    /// The mime type of the data of the intent, for example when another application shares a file
+    pub mime_type: Option<String>,
+    /// The text shared by another application with `android.intent.action.SEND`
+    pub text: Option<String>,
+}
+
+fn with_java_helper<R>(
+    f: impl FnOnce(&JavaHelper) -> Result<R, jni::errors::Error>,
+) -> Result<R, PlatformError> {
+    let window = CURRENT_WINDOW.with_borrow(|x| x.upgrade()).ok_or(PlatformError::NoPlatform)?;
+    f(&window.java_helper).map_err(|e| {
+        // Unlike the other calls, these can fail because of the system (e.g. no application handles
+        // the intent), so report the error instead of panicking
+        crate::javahelper::clear_jni_error(&window.app);
+        PlatformError::Other(format!("JNI error: {e:?}"))
+    })
+}
+
+/// Returns the intent that started the application, for example to open the page of a deep link.
+pub fn launch_intent() -> Result<Option<Intent>, PlatformError> {
+    Ok(with_java_helper(|helper| helper.launch_intent())?.map(|values| {
+        let mut values = values.into_iter();
+        Intent {
+            action: values.next().flatten(),
+            data: values.next().flatten(),
+            mime_type: values.next().flatten(),
+            text: values.next().flatten(),
+        }
+    }))
+}
+
+/// Opens the URL with the application that handles it, such as the web browser.
+pub fn open_url(url: &str) -> Result<(), PlatformError> {
+    with_java_helper(|helper| helper.open_url(url))
+}
+
+/// Shows the share sheet of Android to send the text to another application.
+pub fn share_text(text: &str, subject: Option<&str>) -> Result<(), PlatformError> {
+    with_java_helper(|helper| helper.share_text(text, subject))
+}
+
+/// Shows the share sheet of Android to send the image, and optionally a text, to another application.
+///
+/// The image is written as a PNG file in the cache directory of the application. Other applications can only
+/// read it through a content provider, so declare the `FileProvider` of androidx in the `AndroidManifest.xml`
+/// with the `<package name>.slint.fileprovider` authority:
+///
+/// ```xml
+/// <provider android:name="androidx.core.content.FileProvider"
+///     android:authorities="${applicationId}.slint.fileprovider"
+///     android:exported="false"
+///     android:grantUriPermissions="true">
+///     <meta-data android:name="android.support.FILE_PROVIDER_PATHS"
+///         android:resource="@xml/slint_file_paths" />
+/// </provider>
+/// ```
+///
+/// with the `res/xml/slint_file_paths.xml` resource giving access to the `slint-shared` directory of the cache:
+///
+/// ```xml
+/// <paths><cache-path name="slint-shared" path="slint-shared/" /></paths>
+/// ```
+///
+/// Without it, the image is stored in the pictures of the device until the next image is shared, which before
+/// Android 10 requires the `WRITE_EXTERNAL_STORAGE` permission. The same applies to the images put into the
+/// clipboard.
+pub fn share_image(image: &Image, text: Option<&str>) -> Result<(), PlatformError> {
+    let buffer = image
+        .to_rgba8()
+        .ok_or_else(|| PlatformError::Other("The image to share has no pixel data".into()))?;
+    let pixels = crate::javahelper::to_argb_pixels(&buffer);
+    with_java_helper(|helper| helper.share_image(&pixels, buffer.width(), buffer.height(), text))
+}
+
+/// The content that another application shared with this application, as passed to the handler of
+/// [`on_shared_content()`].
+#[non_exhaustive]
+pub struct SharedContent {
+    /// The mime type of the content, for example `text/plain` or `image/*`
+    pub mime_type: Option<String>,
+    /// The shared text, such as a URL
+    pub text: Option<String>,
+    /// The subject of the shared text, for example from an email client
+    pub subject: Option<String>,
+    /// The shared files, such as images
+    pub files: Vec<FileHandle>,
+}
+
+/// Sets the function that is called with the content that another application shares with this application.
+///
+/// To appear in the share sheet of the system, the activity must declare an `intent-filter` for the
+/// `android.intent.action.SEND` action, and optionally for `android.intent.action.SEND_MULTIPLE`, in the
+/// `AndroidManifest.xml`. When the application is started this way, the handler is called once the event
+/// loop runs.
+pub fn on_shared_content(
+    handler: impl FnOnce(SharedContent) + 'static,
+) -> Result<(), PlatformError> {
+    let Some(values) = with_java_helper(|helper| helper.shared_content())? else { return Ok(()) };
+    let mut values = values.into_iter();
+    let mime_type = values.next().flatten();
+    let text = values.next().flatten();
+    let subject = values.next().flatten();
+    let mut files = Vec::new();
+    while let (Some(uri), Some(name)) = (values.next(), values.next()) {
+        let Some(uri) = uri else { continue };
+        files.push(FileHandle::from_reader(name.unwrap_or_default(), move || {
+            let uri = uri.clone();
+            async move { with_java_helper(|helper| helper.read_uri(&uri)).unwrap_or_default() }
+        }));
+    }
+    let content = SharedContent { mime_type, text, subject, files };
+    i_slint_core::timers::Timer::single_shot(Default::default(), move || handler(content));
+    Ok(())
+}
+
+#[derive(Default)]
+struct PendingDocument {
+    result: Option<Option<(String, String)>>,
+    waker: Option<Waker>,
+}
+
+thread_local! {
+    static PENDING_DOCUMENTS: RefCell<HashMap<i32, Rc<RefCell<PendingDocument>>>> = Default::default();
+    static NEXT_DOCUMENT_ID: Cell<i32> = const { Cell::new(0) };
+}
+
+/// Shows the document picker of Android, for [`i_slint_core::dialogs::open_file()`]
+pub(crate) fn pick_document(options: FileDialogOptions) -> Result<FileDialogFuture, PlatformError> {
+    let id = NEXT_DOCUMENT_ID.replace(NEXT_DOCUMENT_ID.get().wrapping_add(1));
+    let pending = Rc::new(RefCell::new(PendingDocument::default()));
+    PENDING_DOCUMENTS.with_borrow_mut(|p| p.insert(id, pending.clone()));
+    let extensions: Vec<&str> =
+        options.filters.iter().flat_map(|f| f.extensions.iter().map(|e| e.as_str())).collect();
+    if let Err(e) = with_java_helper(|helper| helper.pick_document(id, &extensions)) {
+        PENDING_DOCUMENTS.with_borrow_mut(|p| p.remove(&id));
+        return Err(e);
+    }
+    Ok(Box::pin(async move {
+        let (uri, name) = core::future::poll_fn(|cx| {
+            let mut pending = pending.borrow_mut();
+            match pending.result.take() {
+                Some(result) => Poll::Ready(result),
+                None => {
+                    pending.waker = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            }
+        })
+        .await?;
+        Some(FileHandle::from_reader(name, move || {
+            let uri = uri.clone();
+            async move { with_java_helper(|helper| helper.read_uri(&uri)).unwrap_or_default() }
+        }))
+    }))
+}
+
+#[no_mangle]
+pub(crate) extern "system" fn Java_SlintAndroidJavaHelper_documentPicked(
+    mut env: JNIEnv,
+    _class: JClass,
+    id: jint,
+    uri: JString,
+    name: JString,
+) {
+    let mut get_string = |s: &JString| -> Option<String> {
+        if s.is_null() {
+            return None;
+        }
+        let java_str = env.get_string(s).ok()?;
+        let decoded: std::borrow::Cow<str> = (&java_str).into();
+        Some(decoded.into_owned())
+    };
+    let result = get_string(&uri).map(|uri| (uri, get_string(&name).unwrap_or_default()));
+    i_slint_core::api::invoke_from_event_loop(move || {
+        if let Some(pending) = PENDING_DOCUMENTS.with_borrow_mut(|p| p.remove(&id)) {
+            let mut pending = pending.borrow_mut();
+            pending.result = Some(result);
+            if let Some(waker) = pending.waker.take() {
+                waker.wake();
+            }
+        }
+    })
+    .unwrap()
+}
@@ -14,6 +14,7 @@ import android.view.inputmethod.InputConnection;
 import android.content.ClipData;
 import android.content.ClipboardManager;
 import android.content.Context;
+import android.content.Intent;
 import android.content.res.Configuration;
 import android.content.res.TypedArray;
 import android.graphics.BlendMode;
@@ -122,6 +123,7 @@ class SlintInputView extends View {
     private int mPreeditStart = 0;
     private int mPreeditEnd = 0;
     private int mInputType = EditorInfo.TYPE_CLASS_TEXT;
+    private int mImeAction = EditorInfo.IME_ACTION_UNSPECIFIED;
     private int mInBatch = 0;
     private boolean mPending = false;
     private SlintEditable mEditable;
@@ -169,7 +171,7 @@ class SlintInputView extends View {
     @Override
     public InputConnection onCreateInputConnection(EditorInfo outAttrs) {
         outAttrs.inputType = mInputType;
-        outAttrs.imeOptions = EditorInfo.IME_FLAG_NO_EXTRACT_UI;
+        outAttrs.imeOptions = EditorInfo.IME_FLAG_NO_EXTRACT_UI | mImeAction;
         outAttrs.initialSelStart = mCursorPosition;
         outAttrs.initialSelEnd = mAnchorPosition;
         return new BaseInputConnection(this, true) {
@@ -196,15 +198,16 @@ class SlintInputView extends View {
     }
 
     public void setText(String text, int cursorPosition, int anchorPosition, int preeditStart, int preeditEnd,
-            int inputType) {
-        boolean restart = mInputType != inputType || !mText.equals(text) || mCursorPosition != cursorPosition
-                || mAnchorPosition != anchorPosition;
+            int inputType, int imeAction) {
+        boolean restart = mInputType != inputType || mImeAction != imeAction || !mText.equals(text)
+                || mCursorPosition != cursorPosition || mAnchorPosition != anchorPosition;
         mText = text;
         mCursorPosition = cursorPosition;
         mAnchorPosition = anchorPosition;
         mPreeditStart = preeditStart;
         mPreeditEnd = preeditEnd;
         mInputType = inputType;
+        mImeAction = imeAction;
 
         if (restart) {
             mEditable = new SlintEditable();
@@ -384,9 +387,37 @@ class SlintInputView extends View {
     }
 }
 
+// Forwards the predictive back gesture of Android 14 and later, so that the application can animate its progress
+class SlintBackAnimationCallback implements android.window.OnBackAnimationCallback {
+    @Override
+    public void onBackStarted(android.window.BackEvent backEvent) {
+        SlintAndroidJavaHelper.backEvent(0, backEvent.getProgress());
+    }
+
+    @Override
+    public void onBackProgressed(android.window.BackEvent backEvent) {
+        SlintAndroidJavaHelper.backEvent(1, backEvent.getProgress());
+    }
+
+    @Override
+    public void onBackInvoked() {
+        SlintAndroidJavaHelper.backEvent(2, 1);
+    }
+
+    @Override
+    public void onBackCancelled() {
+        SlintAndroidJavaHelper.backEvent(3, 0);
+    }
+}
+
 public class SlintAndroidJavaHelper {
     Activity mActivity;
     SlintInputView mInputView;
+    // The android.window.OnBackInvokedCallback registered while the Slint window handles the back gesture
+    Object mBackCallback;
+    boolean mBackCallbackEnabled = false;
+    // The android.hardware.SensorEventListener of the hinge angle, while the activity is started
+    Object mHingeListener;
 
     public SlintAndroidJavaHelper(Activity activity) {
         this.mActivity = activity;
@@ -434,8 +465,333 @@ public class SlintAndroidJavaHelper {
 
     static public native void popupMenuAction(int id);
 
+    static public native void backEvent(int kind, float progress);
+
+    static public native void documentPicked(int id, String uri, String name);
+
+    static public native void hingeAngleChanged(float angle);
+
+    // Reports the angle of the hinge of foldable devices, or does nothing if the device has no hinge sensor
+    public void set_hinge_sensor_enabled(boolean enabled) {
+        if (android.os.Build.VERSION.SDK_INT < 30) {
+            return;
+        }
+        android.hardware.SensorManager sensorManager = (android.hardware.SensorManager) mActivity
+                .getSystemService(Context.SENSOR_SERVICE);
+        if (!enabled) {
+            if (mHingeListener != null) {
+                sensorManager.unregisterListener((android.hardware.SensorEventListener) mHingeListener);
+                mHingeListener = null;
+            }
+            return;
+        }
+        android.hardware.Sensor sensor = sensorManager.getDefaultSensor(android.hardware.Sensor.TYPE_HINGE_ANGLE);
+        if (sensor == null || mHingeListener != null) {
+            return;
+        }
+        android.hardware.SensorEventListener listener = new android.hardware.SensorEventListener() {
+            @Override
+            public void onSensorChanged(android.hardware.SensorEvent event) {
+                hingeAngleChanged(event.values[0]);
+            }
+
+            @Override
+            public void onAccuracyChanged(android.hardware.Sensor sensor, int accuracy) {
+            }
+        };
+        mHingeListener = listener;
+        sensorManager.registerListener(listener, sensor, android.hardware.SensorManager.SENSOR_DELAY_NORMAL);
+    }
+
+    // Receives the result of the activity started by pick_document. It must be public and static so that
+    // Android can instantiate it, and is retained so that it is not re-created when the activity is.
+    public static class SlintResultFragment extends android.app.Fragment {
+        int mRequestId;
+        Intent mIntent;
+
+        @Override
+        public void onCreate(android.os.Bundle savedInstanceState) {
+            super.onCreate(savedInstanceState);
+            setRetainInstance(true);
+            if (mIntent != null) {
+                startActivityForResult(mIntent, 1);
+                mIntent = null;
+            }
+        }
+
+        @Override
+        public void onActivityResult(int requestCode, int resultCode, Intent data) {
+            String uri = null;
+            String name = null;
+            if (resultCode == Activity.RESULT_OK && data != null && data.getData() != null) {
+                uri = data.getData().toString();
+                name = data.getData().getLastPathSegment();
+                android.database.Cursor cursor = getActivity().getContentResolver().query(data.getData(),
+                        new String[] { android.provider.OpenableColumns.DISPLAY_NAME }, null, null, null);
+                if (cursor != null) {
+                    if (cursor.moveToFirst()) {
+                        name = cursor.getString(0);
+                    }
+                    cursor.close();
+                }
+            }
+            documentPicked(mRequestId, uri, name);
+            getFragmentManager().beginTransaction().remove(this).commitAllowingStateLoss();
+        }
+    }
+
+    public void open_url(String url) {
+        mActivity.startActivity(new Intent(Intent.ACTION_VIEW, android.net.Uri.parse(url)));
+    }
+
+    public void share_text(String text, String subject) {
+        Intent intent = new Intent(Intent.ACTION_SEND);
+        intent.setType("text/plain");
+        intent.putExtra(Intent.EXTRA_TEXT, text);
+        if (subject != null) {
+            intent.putExtra(Intent.EXTRA_SUBJECT, subject);
+        }
+        mActivity.startActivity(Intent.createChooser(intent, null));
+    }
+
+    public void share_image(int[] pixels, int width, int height, String text) throws java.io.IOException {
+        android.net.Uri uri = store_shared_image(pixels, width, height, "share");
+        Intent intent = new Intent(Intent.ACTION_SEND);
+        intent.setType("image/png");
+        intent.putExtra(Intent.EXTRA_STREAM, uri);
+        if (text != null) {
+            intent.putExtra(Intent.EXTRA_TEXT, text);
+        }
+        intent.addFlags(Intent.FLAG_GRANT_READ_URI_PERMISSION);
+        mActivity.startActivity(Intent.createChooser(intent, null));
+    }
+
+    // Authority of the androidx FileProvider that the application can declare to share the images from its
+    // cache directory
+    String fileProviderAuthority() {
+        return mActivity.getPackageName() + ".slint.fileprovider";
+    }
+
+    // Stores the image as a PNG file that other applications can read through the returned content URI, until
+    // an image is stored again with the same slot. Other applications can only read the files of the application
+    // through a content provider. NativeActivity can't declare one, so the FileProvider of the application is
+    // used if it declares one, and otherwise the image is stored in the media store until the next call.
+    android.net.Uri store_shared_image(int[] pixels, int width, int height, String slot) throws java.io.IOException {
+        android.graphics.Bitmap bitmap = android.graphics.Bitmap.createBitmap(pixels, width, height,
+                android.graphics.Bitmap.Config.ARGB_8888);
+        android.content.ContentResolver resolver = mActivity.getContentResolver();
+        android.content.SharedPreferences preferences = mActivity.getSharedPreferences("slint-shared",
+                Context.MODE_PRIVATE);
+        String previous = preferences.getString(slot, null);
+        if (previous != null) {
+            try {
+                resolver.delete(android.net.Uri.parse(previous), null, null);
+            } catch (Exception e) {
+                // Already deleted by the user
+            }
+            preferences.edit().remove(slot).apply();
+        }
+
+        java.io.File directory = new java.io.File(mActivity.getCacheDir(), "slint-shared");
+        directory.mkdirs();
+        java.io.File file = new java.io.File(directory, slot + ".png");
+        java.io.OutputStream output = new java.io.FileOutputStream(file);
+        try {
+            bitmap.compress(android.graphics.Bitmap.CompressFormat.PNG, 100, output);
+        } finally {
+            output.close();
+        }
+        try {
+            // Through reflection, as androidx is only available if the application depends on it
+            Class<?> provider = Class.forName("androidx.core.content.FileProvider", true,
+                    mActivity.getClassLoader());
+            return (android.net.Uri) provider
+                    .getMethod("getUriForFile", Context.class, String.class, java.io.File.class)
+                    .invoke(null, mActivity, fileProviderAuthority(), file);
+        } catch (Exception e) {
+            // No FileProvider, or it doesn't give access to the file
+        }
+        file.delete();
+
+        android.net.Uri uri;
+        String name = "slint-shared-" + System.currentTimeMillis() + ".png";
+        if (android.os.Build.VERSION.SDK_INT >= 29) {
+            android.content.ContentValues values = new android.content.ContentValues();
+            values.put(android.provider.MediaStore.MediaColumns.DISPLAY_NAME, name);
+            values.put(android.provider.MediaStore.MediaColumns.MIME_TYPE, "image/png");
+            uri = resolver.insert(android.provider.MediaStore.Images.Media.EXTERNAL_CONTENT_URI, values);
+            if (uri == null) {
+                throw new java.io.IOException("Cannot store the image to share");
+            }
+            java.io.OutputStream stream = resolver.openOutputStream(uri);
+            try {
+                bitmap.compress(android.graphics.Bitmap.CompressFormat.PNG, 100, stream);
+            } finally {
+                stream.close();
+            }
+        } else {
+            String path = android.provider.MediaStore.Images.Media.insertImage(resolver, bitmap, name, null);
+            if (path == null) {
+                throw new java.io.IOException("Cannot store the image to share");
+            }
+            uri = android.net.Uri.parse(path);
+        }
+        preferences.edit().putString(slot, uri.toString()).apply();
+        return uri;
+    }
+
+    // Returns the mime type, the text, and the subject of the content shared with the activity, followed
+    // by the URI and the name of each shared file, or null if the activity wasn't started to receive content
+    public String[] shared_content() {
+        Intent intent = mActivity.getIntent();
+        if (intent == null || !(Intent.ACTION_SEND.equals(intent.getAction())
+                || Intent.ACTION_SEND_MULTIPLE.equals(intent.getAction()))) {
+            return null;
+        }
+        java.util.ArrayList<android.net.Uri> uris = new java.util.ArrayList<>();
+        if (Intent.ACTION_SEND_MULTIPLE.equals(intent.getAction())) {
+            java.util.ArrayList<android.os.Parcelable> streams = intent
+                    .getParcelableArrayListExtra(Intent.EXTRA_STREAM);
+            if (streams != null) {
+                for (android.os.Parcelable stream : streams) {
+                    uris.add((android.net.Uri) stream);
+                }
+            }
+        } else {
+            android.os.Parcelable stream = intent.getParcelableExtra(Intent.EXTRA_STREAM);
+            if (stream != null) {
+                uris.add((android.net.Uri) stream);
+            }
+        }
+        CharSequence text = intent.getCharSequenceExtra(Intent.EXTRA_TEXT);
+        String[] result = new String[3 + 2 * uris.size()];
+        result[0] = intent.getType();
+        result[1] = text != null ? text.toString() : null;
+        result[2] = intent.getStringExtra(Intent.EXTRA_SUBJECT);
+        for (int i = 0; i < uris.size(); ++i) {
+            android.net.Uri uri = uris.get(i);
+            String name = uri.getLastPathSegment();
+            android.database.Cursor cursor = mActivity.getContentResolver().query(uri,
+                    new String[] { android.provider.OpenableColumns.DISPLAY_NAME }, null, null, null);
+            if (cursor != null) {
+                if (cursor.moveToFirst()) {
+                    name = cursor.getString(0);
+                }
+                cursor.close();
+            }
+            result[3 + 2 * i] = uri.toString();
+            result[4 + 2 * i] = name;
+        }
+        return result;
+    }
+
+    // Returns the action, the data URI, the mime type, and the text of the intent that started the activity
+    public String[] launch_intent() {
+        Intent intent = mActivity.getIntent();
+        if (intent == null) {
+            return null;
+        }
+        return new String[] { intent.getAction(), intent.getDataString(), intent.getType(),
+                intent.getStringExtra(Intent.EXTRA_TEXT) };
+    }
+
+    public void pick_document(int id, String[] extensions) {
+        Intent intent = new Intent(Intent.ACTION_OPEN_DOCUMENT);
+        intent.addCategory(Intent.CATEGORY_OPENABLE);
+        intent.setType("*/*");
+        java.util.ArrayList<String> mimeTypes = new java.util.ArrayList<>();
+        for (String extension : extensions) {
+            String mimeType = android.webkit.MimeTypeMap.getSingleton().getMimeTypeFromExtension(extension);
+            if (mimeType != null && !mimeTypes.contains(mimeType)) {
+                mimeTypes.add(mimeType);
+            }
+        }
+        if (!mimeTypes.isEmpty()) {
+            intent.putExtra(Intent.EXTRA_MIME_TYPES, mimeTypes.toArray(new String[0]));
+        }
+        mActivity.runOnUiThread(new Runnable() {
+            @Override
+            public void run() {
+                SlintResultFragment fragment = new SlintResultFragment();
+                fragment.mRequestId = id;
+                fragment.mIntent = intent;
+                mActivity.getFragmentManager().beginTransaction().add(fragment, "slint_pick_document_" + id)
+                        .commitAllowingStateLoss();
+            }
+        });
+    }
+
+    public byte[] read_uri(String uri) throws java.io.IOException {
+        java.io.InputStream input = mActivity.getContentResolver().openInputStream(android.net.Uri.parse(uri));
+        if (input == null) {
+            throw new java.io.FileNotFoundException(uri);
+        }
+        java.io.ByteArrayOutputStream output = new java.io.ByteArrayOutputStream();
+        byte[] buffer = new byte[16384];
+        int read;
+        while ((read = input.read(buffer)) != -1) {
+            output.write(buffer, 0, read);
+        }
+        input.close();
+        return output.toByteArray();
+    }
+
+    // With Android 13 and later, the back gesture must be intercepted before it starts, so it is only
+    // intercepted when the Slint window handles it
+    public void set_back_callback_enabled(boolean enabled) {
+        if (android.os.Build.VERSION.SDK_INT < 33) {
+            return;
+        }
+        mActivity.runOnUiThread(new Runnable() {
+            @Override
+            public void run() {
+                mBackCallbackEnabled = enabled;
+                register_back_callback(enabled);
+            }
+        });
+    }
+
+    private void register_back_callback(boolean register) {
+        android.window.OnBackInvokedDispatcher dispatcher = mActivity.getOnBackInvokedDispatcher();
+        if (register && mBackCallback == null) {
+            android.window.OnBackInvokedCallback callback;
+            if (android.os.Build.VERSION.SDK_INT >= 34) {
+                callback = new SlintBackAnimationCallback();
+            } else {
+                callback = new android.window.OnBackInvokedCallback() {
+                    @Override
+                    public void onBackInvoked() {
+                        backEvent(2, 1);
+                    }
+                };
+            }
+            dispatcher.registerOnBackInvokedCallback(android.window.OnBackInvokedDispatcher.PRIORITY_DEFAULT,
+                    callback);
+            mBackCallback = callback;
+        } else if (!register && mBackCallback != null) {
+            dispatcher.unregisterOnBackInvokedCallback((android.window.OnBackInvokedCallback) mBackCallback);
+            mBackCallback = null;
+        }
+    }
+
+    // Called when the Slint window rejected the back gesture
+    public void default_back() {
+        mActivity.runOnUiThread(new Runnable() {
+            @Override
+            public void run() {
+                if (android.os.Build.VERSION.SDK_INT >= 33) {
+                    register_back_callback(false);
+                }
+                mActivity.onBackPressed();
+                if (android.os.Build.VERSION.SDK_INT >= 33) {
+                    register_back_callback(mBackCallbackEnabled);
+                }
+            }
+        });
+    }
+
     public void set_imm_data(String text, int cursor_position, int anchor_position, int preedit_start, int preedit_end,
-            int cur_x, int cur_y, int anchor_x, int anchor_y, int cursor_height, int input_type,
+            int cur_x, int cur_y, int anchor_x, int anchor_y, int cursor_height, int input_type, int ime_action,
             boolean show_cursor_handles) {
 
         mActivity.runOnUiThread(new Runnable() {
@@ -443,7 +799,7 @@ public class SlintAndroidJavaHelper {
             public void run() {
                 int selStart = Math.min(cursor_position, anchor_position);
                 int selEnd = Math.max(cursor_position, anchor_position);
-                mInputView.setText(text, selStart, selEnd, preedit_start, preedit_end, input_type);
+                mInputView.setText(text, selStart, selEnd, preedit_start, preedit_end, input_type, ime_action);
                 int num_handles = 0;
                 if (show_cursor_handles) {
                     num_handles = cursor_position == anchor_position ? 1 : 2;
@@ -490,6 +846,46 @@ public class SlintAndroidJavaHelper {
         return rect;
     }
 
+    // Get the space taken by the system bars and the display cutout on each side of the window, excluding the keyboard
+    public Rect get_safe_area_insets() {
+        Rect rect = new Rect();
+        WindowInsets insets = mActivity.getWindow().getDecorView().getRootView().getRootWindowInsets();
+        if (insets == null) {
+            return rect;
+        }
+        if (android.os.Build.VERSION.SDK_INT >= 30) {
+            android.graphics.Insets i = insets.getInsets(WindowInsets.Type.systemBars() | WindowInsets.Type.displayCutout());
+            rect.set(i.left, i.top, i.right, i.bottom);
+        } else {
+            rect.set(insets.getStableInsetLeft(), insets.getStableInsetTop(), insets.getStableInsetRight(),
+                    insets.getStableInsetBottom());
+            if (android.os.Build.VERSION.SDK_INT >= 28) {
+                android.view.DisplayCutout cutout = insets.getDisplayCutout();
+                if (cutout != null) {
+                    rect.left = Math.max(rect.left, cutout.getSafeInsetLeft());
+                    rect.top = Math.max(rect.top, cutout.getSafeInsetTop());
+                    rect.right = Math.max(rect.right, cutout.getSafeInsetRight());
+                    rect.bottom = Math.max(rect.bottom, cutout.getSafeInsetBottom());
+                }
+            }
+        }
+        return rect;
+    }
+
+    // Get the height of the virtual keyboard from the bottom of the window, or 0 if it is hidden
+    public int get_keyboard_height() {
+        View rootView = mActivity.getWindow().getDecorView().getRootView();
+        WindowInsets insets = rootView.getRootWindowInsets();
+        if (insets == null) {
+            return 0;
+        }
+        if (android.os.Build.VERSION.SDK_INT >= 30) {
+            return insets.getInsets(WindowInsets.Type.ime()).bottom;
+        }
+        // The system window insets include the keyboard, unlike the stable insets
+        return Math.max(0, insets.getSystemWindowInsetBottom() - insets.getStableInsetBottom());
+    }
+
     public void show_action_menu() {
         mActivity.runOnUiThread(new Runnable() {
             @Override
@@ -506,7 +902,9 @@ public class SlintAndroidJavaHelper {
                 ClipboardManager clipboard = (ClipboardManager) mActivity.getSystemService(Context.CLIPBOARD_SERVICE);
                 if (clipboard.hasPrimaryClip()) {
                     ClipData.Item item = clipboard.getPrimaryClip().getItemAt(0);
-                    return item.getText().toString();
+                    // Also converts the URIs and the HTML copied by other applications
+                    CharSequence text = item.coerceToText(mActivity);
+                    return text != null ? text.toString() : "";
                 }
                 return "";
             }
@@ -531,4 +929,97 @@ public class SlintAndroidJavaHelper {
             }
         });
     }
+
+    public void set_clipboard_html(String html, String altText) {
+        mActivity.runOnUiThread(new Runnable() {
+            @Override
+            public void run() {
+                ClipboardManager clipboard = (ClipboardManager) mActivity.getSystemService(Context.CLIPBOARD_SERVICE);
+                clipboard.setPrimaryClip(ClipData.newHtmlText(null, altText, html));
+            }
+        });
+    }
+
+    // Returns the HTML in the clipboard, or null if it doesn't contain HTML
+    public String get_clipboard_html() {
+        FutureTask<String> future = new FutureTask<>(new Callable<String>() {
+            @Override
+            public String call() throws Exception {
+                ClipboardManager clipboard = (ClipboardManager) mActivity.getSystemService(Context.CLIPBOARD_SERVICE);
+                if (clipboard.hasPrimaryClip()) {
+                    return clipboard.getPrimaryClip().getItemAt(0).getHtmlText();
+                }
+                return null;
+            }
+        });
+
+        mActivity.runOnUiThread(future);
+        try {
+            return future.get();
+        } catch (Exception e) {
+            e.printStackTrace();
+            return null;
+        }
+    }
+
+    public void set_clipboard_image(int[] pixels, int width, int height) throws java.io.IOException {
+        android.net.Uri uri = store_shared_image(pixels, width, height, "clipboard");
+        mActivity.runOnUiThread(new Runnable() {
+            @Override
+            public void run() {
+                ClipboardManager clipboard = (ClipboardManager) mActivity.getSystemService(Context.CLIPBOARD_SERVICE);
+                clipboard.setPrimaryClip(ClipData.newUri(mActivity.getContentResolver(), null, uri));
+            }
+        });
+    }
+
+    // Returns the width and the height of the image in the clipboard followed by its ARGB pixels, or null if
+    // the clipboard doesn't contain an image
+    public int[] get_clipboard_image() throws java.io.IOException {
+        FutureTask<android.net.Uri> future = new FutureTask<>(new Callable<android.net.Uri>() {
+            @Override
+            public android.net.Uri call() throws Exception {
+                ClipboardManager clipboard = (ClipboardManager) mActivity.getSystemService(Context.CLIPBOARD_SERVICE);
+                if (clipboard.hasPrimaryClip()) {
+                    return clipboard.getPrimaryClip().getItemAt(0).getUri();
+                }
+                return null;
+            }
+        });
+        mActivity.runOnUiThread(future);
+        android.net.Uri uri;
+        try {
+            uri = future.get();
+        } catch (Exception e) {
+            e.printStackTrace();
+            return null;
+        }
+        if (uri == null) {
+            return null;
+        }
+        String type = mActivity.getContentResolver().getType(uri);
+        if (type == null || !type.startsWith("image/")) {
+            return null;
+        }
+        java.io.InputStream input = mActivity.getContentResolver().openInputStream(uri);
+        if (input == null) {
+            return null;
+        }
+        android.graphics.Bitmap bitmap;
+        try {
+            bitmap = android.graphics.BitmapFactory.decodeStream(input);
+        } finally {
+            input.close();
+        }
+        if (bitmap == null) {
+            return null;
+        }
+        int width = bitmap.getWidth();
+        int height = bitmap.getHeight();
+        int[] result = new int[2 + width * height];
+        result[0] = width;
+        result[1] = height;
+        bitmap.getPixels(result, 2, width, 0, 0, width, height);
+        return result;
+    }
 }
@@ -3,12 +3,12 @@
 
 use super::*;
 use i_slint_core::api::{PhysicalPosition, PhysicalSize};
-use i_slint_core::graphics::{euclid, Color};
-use i_slint_core::items::{ColorScheme, InputType};
-use i_slint_core::platform::WindowAdapter;
+use i_slint_core::graphics::{euclid, Color, Rgba8Pixel, SharedPixelBuffer};
+use i_slint_core::items::{ColorScheme, DevicePosture, ImeAction, InputType};
+use i_slint_core::platform::{WindowAdapter, WindowEvent};
 use i_slint_core::SharedString;
 use jni::objects::{JClass, JObject, JString, JValue};
-use jni::sys::{jboolean, jint};
+use jni::sys::{jboolean, jfloat, jint};
 use jni::JNIEnv;
 use std::time::Duration;
 
@@ -20,6 +20,33 @@ pub fn print_jni_error(app: &AndroidApp, e: jni::errors::Error) -> ! {
     panic!("JNI error: {e:?}")
 }
 
+/// Prints and clears the pending Java exception, for the calls that can fail because of the system
+/// (e.g. no application handles an intent, or the storage is full) and must not panic.
+pub fn clear_jni_error(app: &AndroidApp) {
+    // Safety: as documented in android-activity to obtain a jni::JavaVM
+    if let Ok(vm) = unsafe { jni::JavaVM::from_raw(app.vm_as_ptr() as *mut _) } {
+        if let Ok(env) = vm.attach_current_thread() {
+            let _ = env.exception_describe();
+            let _ = env.exception_clear();
+        }
+    }
+}
+
+/// Converts the pixels to the ARGB integers of an `android.graphics.Bitmap`
+pub fn to_argb_pixels(buffer: &SharedPixelBuffer<Rgba8Pixel>) -> Vec<i32> {
+    buffer.as_slice().iter().map(|p| i32::from_be_bytes([p.a, p.r, p.g, p.b])).collect()
+}
+
+/// Converts the ARGB integers of an `android.graphics.Bitmap` to pixels
+pub fn from_argb_pixels(width: u32, height: u32, pixels: &[i32]) -> SharedPixelBuffer<Rgba8Pixel> {
+    let mut buffer = SharedPixelBuffer::<Rgba8Pixel>::new(width, height);
+    for (pixel, argb) in buffer.make_mut_slice().iter_mut().zip(pixels) {
+        let [a, r, g, b] = argb.to_be_bytes();
+        *pixel = Rgba8Pixel { r, g, b, a };
+    }
+    buffer
+}
+
 pub struct JavaHelper(jni::objects::GlobalRef, AndroidApp);
 
 fn load_java_helper(app: &AndroidApp) -> Result<jni::objects::GlobalRef, jni::errors::Error> {
@@ -148,6 +175,21 @@ fn load_java_helper(app: &AndroidApp) -> Result<jni::objects::GlobalRef, jni::er
             sig: "(I)V".into(),
             fn_ptr: Java_SlintAndroidJavaHelper_popupMenuAction as *mut _,
         },
+        jni::NativeMethod {
+            name: "backEvent".into(),
+            sig: "(IF)V".into(),
+            fn_ptr: Java_SlintAndroidJavaHelper_backEvent as *mut _,
+        },
+        jni::NativeMethod {
+            name: "documentPicked".into(),
+            sig: "(ILjava/lang/String;Ljava/lang/String;)V".into(),
+            fn_ptr: crate::intent::Java_SlintAndroidJavaHelper_documentPicked as *mut _,
+        },
+        jni::NativeMethod {
+            name: "hingeAngleChanged".into(),
+            sig: "(F)V".into(),
+            fn_ptr: Java_SlintAndroidJavaHelper_hingeAngleChanged as *mut _,
+        },
     ];
     env.register_native_methods(&helper_class, &methods)?;
 
@@ -226,10 +268,34 @@ pub fn set_imm_data(
                     env.get_static_field(&class_it, "TYPE_CLASS_NUMBER", "I")?.i()?
                         | env.get_static_field(&class_it, "TYPE_NUMBER_FLAG_DECIMAL", "I")?.i()?
                 }
+                InputType::Email => {
+                    env.get_static_field(&class_it, "TYPE_CLASS_TEXT", "I")?.i()?
+                        | env
+                            .get_static_field(&class_it, "TYPE_TEXT_VARIATION_EMAIL_ADDRESS", "I")?
+                            .i()?
+                }
+                InputType::Phone => {
+                    env.get_static_field(&class_it, "TYPE_CLASS_PHONE", "I")?.i()?
+                }
                 _ => 0 as jint,
             };
             env.delete_local_ref(class_it)?;
 
+            let class_ei = env.find_class("android/view/inputmethod/EditorInfo")?;
+            let ime_action = match data.ime_action {
+                ImeAction::Done => Some("IME_ACTION_DONE"),
+                ImeAction::Go => Some("IME_ACTION_GO"),
+                ImeAction::Next => Some("IME_ACTION_NEXT"),
+                ImeAction::Search => Some("IME_ACTION_SEARCH"),
+                ImeAction::Send => Some("IME_ACTION_SEND"),
+                _ => None,
+            };
+            let ime_action = match ime_action {
+                Some(name) => env.get_static_field(&class_ei, name, "I")?.i()?,
+                None => env.get_static_field(&class_ei, "IME_ACTION_UNSPECIFIED", "I")?.i()?,
+            };
+            env.delete_local_ref(class_ei)?;
+
             let cur_origin = data.cursor_rect_origin.to_physical(scale_factor);
             let anchor_origin = data.anchor_point.to_physical(scale_factor);
             let cur_size = data.cursor_rect_size.to_physical(scale_factor);
@@ -244,7 +310,7 @@ pub fn set_imm_data(
             env.call_method(
                 helper,
                 "set_imm_data",
-                "(Ljava/lang/String;IIIIIIIIIIZ)V",
+                "(Ljava/lang/String;IIIIIIIIIIIZ)V",
                 &[
                     JValue::Object(&text),
                     JValue::from(to_utf16(cursor_position) as jint),
@@ -257,6 +323,7 @@ pub fn set_imm_data(
                     JValue::from(anchor_y as jint),
                     JValue::from(cursor_height as jint),
                     JValue::from(input_type),
+                    JValue::from(ime_action),
                     JValue::from(show_cursor_handles as jboolean),
                 ],
             )?;
@@ -284,6 +351,168 @@ pub fn get_view_rect(&self) -> Result<(PhysicalPosition, PhysicalSize), jni::err
         })
     }
 
+    /// Returns the insets of the system bars and display cutout as (left, top, right, bottom) physical pixels
+    pub fn get_safe_area_insets(&self) -> Result<[i32; 4], jni::errors::Error> {
+        self.with_jni_env(|env, helper| {
+            let rect = env
+                .call_method(helper, "get_safe_area_insets", "()Landroid/graphics/Rect;", &[])?
+                .l()?;
+            let rect = env.auto_local(rect);
+            Ok([
+                env.get_field(&rect, "left", "I")?.i()?,
+                env.get_field(&rect, "top", "I")?.i()?,
+                env.get_field(&rect, "right", "I")?.i()?,
+                env.get_field(&rect, "bottom", "I")?.i()?,
+            ])
+        })
+    }
+
+    /// Returns the height of the virtual keyboard from the bottom of the native window, in physical pixels
+    pub fn get_keyboard_height(&self) -> Result<i32, jni::errors::Error> {
+        self.with_jni_env(|env, helper| {
+            Ok(env.call_method(helper, "get_keyboard_height", "()I", &[])?.i()?)
+        })
+    }
+
+    pub fn open_url(&self, url: &str) -> Result<(), jni::errors::Error> {
+        self.with_jni_env(|env, helper| {
+            let url = env.auto_local(env.new_string(url)?);
+            env.call_method(helper, "open_url", "(Ljava/lang/String;)V", &[JValue::Object(&url)])?;
+            Ok(())
+        })
+    }
+
+    pub fn share_text(&self, text: &str, subject: Option<&str>) -> Result<(), jni::errors::Error> {
+        self.with_jni_env(|env, helper| {
+            let text = env.auto_local(env.new_string(text)?);
+            let subject = match subject {
+                Some(subject) => env.new_string(subject)?.into(),
+                None => JObject::null(),
+            };
+            let subject = env.auto_local(subject);
+            env.call_method(
+                helper,
+                "share_text",
+                "(Ljava/lang/String;Ljava/lang/String;)V",
+                &[JValue::Object(&text), JValue::Object(&subject)],
+            )?;
+            Ok(())
+        })
+    }
+
+    /// Returns the action, data, mime type, and text of the intent that started the activity
+    pub fn launch_intent(&self) -> Result<Option<Vec<Option<String>>>, jni::errors::Error> {
+        self.with_jni_env(|env, helper| {
+            let array =
+                env.call_method(helper, "launch_intent", "()[Ljava/lang/String;", &[])?.l()?;
+            jni_get_string_array(array, env)
+        })
+    }
+
+    /// Returns the mime type, text, and subject of the content shared with the activity,
+    /// followed by the URI and the name of each shared file
+    pub fn shared_content(&self) -> Result<Option<Vec<Option<String>>>, jni::errors::Error> {
+        self.with_jni_env(|env, helper| {
+            let array =
+                env.call_method(helper, "shared_content", "()[Ljava/lang/String;", &[])?.l()?;
+            jni_get_string_array(array, env)
+        })
+    }
+
+    /// Shares an image, given as ARGB pixels
+    pub fn share_image(
+        &self,
+        pixels: &[i32],
+        width: u32,
+        height: u32,
+        text: Option<&str>,
+    ) -> Result<(), jni::errors::Error> {
+        self.with_jni_env(|env, helper| {
+            let array = env.auto_local(env.new_int_array(pixels.len() as _)?);
+            env.set_int_array_region(&*array, 0, pixels)?;
+            let text = match text {
+                Some(text) => env.new_string(text)?.into(),
+                None => JObject::null(),
+            };
+            let text = env.auto_local(text);
+            env.call_method(
+                helper,
+                "share_image",
+                "([IIILjava/lang/String;)V",
+                &[
+                    JValue::Object(&array),
+                    JValue::from(width as jint),
+                    JValue::from(height as jint),
+                    JValue::Object(&text),
+                ],
+            )?;
+            Ok(())
+        })
+    }
+
+    pub fn pick_document(&self, id: i32, extensions: &[&str]) -> Result<(), jni::errors::Error> {
+        self.with_jni_env(|env, helper| {
+            let array =
+                env.new_object_array(extensions.len() as _, "java/lang/String", JObject::null())?;
+            let array = env.auto_local(array);
+            for (i, extension) in extensions.iter().enumerate() {
+                let extension = env.auto_local(env.new_string(extension)?);
+                env.set_object_array_element(&*array, i as _, &*extension)?;
+            }
+            env.call_method(
+                helper,
+                "pick_document",
+                "(I[Ljava/lang/String;)V",
+                &[JValue::from(id as jint), JValue::Object(&array)],
+            )?;
+            Ok(())
+        })
+    }
+
+    /// Reads the content of a `content://` URI
+    pub fn read_uri(&self, uri: &str) -> Result<Vec<u8>, jni::errors::Error> {
+        self.with_jni_env(|env, helper| {
+            let uri = env.auto_local(env.new_string(uri)?);
+            let bytes = env
+                .call_method(helper, "read_uri", "(Ljava/lang/String;)[B", &[JValue::Object(&uri)])?
+                .l()?;
+            let bytes = env.auto_local(jni::objects::JByteArray::from(bytes));
+            env.convert_byte_array(&*bytes)
+        })
+    }
+
+    pub fn set_back_callback_enabled(&self, enabled: bool) -> Result<(), jni::errors::Error> {
+        self.with_jni_env(|env, helper| {
+            env.call_method(
+                helper,
+                "set_back_callback_enabled",
+                "(Z)V",
+                &[JValue::from(enabled as jboolean)],
+            )?;
+            Ok(())
+        })
+    }
+
+    pub fn set_hinge_sensor_enabled(&self, enabled: bool) -> Result<(), jni::errors::Error> {
+        self.with_jni_env(|env, helper| {
+            env.call_method(
+                helper,
+                "set_hinge_sensor_enabled",
+                "(Z)V",
+                &[JValue::from(enabled as jboolean)],
+            )?;
+            Ok(())
+        })
+    }
+
+    /// Performs the back navigation of Android, when the window didn't handle the back gesture
+    pub fn default_back(&self) -> Result<(), jni::errors::Error> {
+        self.with_jni_env(|env, helper| {
+            env.call_method(helper, "default_back", "()V", &[])?;
+            Ok(())
+        })
+    }
+
     pub fn set_handle_color(&self, color: Color) -> Result<(), jni::errors::Error> {
         self.with_jni_env(|env, helper| {
             env.call_method(
@@ -330,6 +559,75 @@ pub fn set_clipboard(&self, text: &str) -> Result<(), jni::errors::Error> {
         })
     }
 
+    pub fn set_clipboard_html(&self, html: &str, alt_text: &str) -> Result<(), jni::errors::Error> {
+        self.with_jni_env(|env, helper| {
+            let html = env.auto_local(env.new_string(html)?);
+            let alt_text = env.auto_local(env.new_string(alt_text)?);
+            env.call_method(
+                helper,
+                "set_clipboard_html",
+                "(Ljava/lang/String;Ljava/lang/String;)V",
+                &[JValue::Object(&html), JValue::Object(&alt_text)],
+            )?;
+            Ok(())
+        })
+    }
+
+    pub fn get_clipboard_html(&self) -> Result<Option<String>, jni::errors::Error> {
+        self.with_jni_env(|env, helper| {
+            let j_string = env
+                .call_method(helper, "get_clipboard_html", "()Ljava/lang/String;", &[])?
+                .l()
+                .map(|l| env.auto_local(l))?;
+            if j_string.is_null() {
+                return Ok(None);
+            }
+            Ok(Some(jni_get_string(j_string.as_ref(), env)?.into()))
+        })
+    }
+
+    /// Puts an image, given as ARGB pixels, into the clipboard
+    pub fn set_clipboard_image(
+        &self,
+        pixels: &[i32],
+        width: u32,
+        height: u32,
+    ) -> Result<(), jni::errors::Error> {
+        self.with_jni_env(|env, helper| {
+            let array = env.auto_local(env.new_int_array(pixels.len() as _)?);
+            env.set_int_array_region(&*array, 0, pixels)?;
+            env.call_method(
+                helper,
+                "set_clipboard_image",
+                "([III)V",
+                &[
+                    JValue::Object(&array),
+                    JValue::from(width as jint),
+                    JValue::from(height as jint),
+                ],
+            )?;
+            Ok(())
+        })
+    }
+
+    /// Returns the width, the height, and the ARGB pixels of the image in the clipboard
+    pub fn get_clipboard_image(&self) -> Result<Option<(u32, u32, Vec<i32>)>, jni::errors::Error> {
+        self.with_jni_env(|env, helper| {
+            let array = env.call_method(helper, "get_clipboard_image", "()[I", &[])?.l()?;
+            if array.is_null() {
+                return Ok(None);
+            }
+            let array = env.auto_local(jni::objects::JIntArray::from(array));
+            let mut data = vec![0; env.get_array_length(&*array)? as usize];
+            env.get_int_array_region(&*array, 0, &mut data)?;
+            if data.len() < 2 {
+                return Ok(None);
+            }
+            let pixels = data.split_off(2);
+            Ok(Some((data[0] as u32, data[1] as u32, pixels)))
+        })
+    }
+
     pub fn get_clipboard(&self) -> Result<String, jni::errors::Error> {
         self.with_jni_env(|env, helper| {
             let j_string = env
@@ -525,6 +823,85 @@ extern "system" fn Java_SlintAndroidJavaHelper_popupMenuAction(
     .unwrap()
 }
 
+#[no_mangle]
+extern "system" fn Java_SlintAndroidJavaHelper_backEvent(
+    _env: JNIEnv,
+    _class: JClass,
+    kind: jint,
+    progress: jfloat,
+) {
+    i_slint_core::api::invoke_from_event_loop(move || {
+        if let Some(adaptor) = CURRENT_WINDOW.with_borrow(|x| x.upgrade()) {
+            let window = i_slint_core::window::WindowInner::from_pub(&adaptor.window);
+            match kind {
+                // started or progressed
+                0 | 1 => window.set_back_progress(progress),
+                // invoked
+                2 => {
+                    if !window.request_back() {
+                        adaptor
+                            .java_helper
+                            .default_back()
+                            .unwrap_or_else(|e| print_jni_error(&adaptor.app, e));
+                    }
+                }
+                // cancelled
+                _ => window.set_back_progress(0.),
+            }
+        }
+    })
+    .unwrap()
+}
+
+#[no_mangle]
+extern "system" fn Java_SlintAndroidJavaHelper_hingeAngleChanged(
+    _env: JNIEnv,
+    _class: JClass,
+    angle: jfloat,
+) {
+    // The angle is 0 when the device is closed, and 180 when it's flat
+    let posture = if angle < 30. {
+        DevicePosture::Folded
+    } else if angle < 160. {
+        DevicePosture::HalfOpened
+    } else {
+        DevicePosture::Flat
+    };
+    i_slint_core::api::invoke_from_event_loop(move || {
+        if let Some(adaptor) = CURRENT_WINDOW.with_borrow(|x| x.upgrade()) {
+            adaptor
+                .window
+                .try_dispatch_event(WindowEvent::DevicePostureChanged { posture })
+                .unwrap_or_else(|e| eprintln!("Error dispatching the device posture: {e}"));
+        }
+    })
+    .unwrap()
+}
+
+/// Converts a Java `String[]`, which may be null and contain null elements
+fn jni_get_string_array(
+    array: JObject,
+    env: &mut JNIEnv,
+) -> Result<Option<Vec<Option<String>>>, jni::errors::Error> {
+    if array.is_null() {
+        return Ok(None);
+    }
+    let array = env.auto_local(jni::objects::JObjectArray::from(array));
+    let len = env.get_array_length(&*array)?;
+    let mut result = Vec::with_capacity(len as usize);
+    for i in 0..len {
+        let element = env.auto_local(env.get_object_array_element(&*array, i)?);
+        if element.is_null() {
+            result.push(None);
+        } else {
+            let java_str = jni_get_string(&element, env)?;
+            let decoded: std::borrow::Cow<str> = (&java_str).into();
+            result.push(Some(decoded.into_owned()));
+        }
+    }
+    Ok(Some(result))
+}
+
 /// Workaround before <https://github.com/jni-rs/jni-rs/pull/557> is merged.
 fn jni_get_string<'e, 'a>(
     obj: &'a JObject<'a>,
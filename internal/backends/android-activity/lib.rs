@@ -7,7 +7,9 @@
 #![cfg(target_os = "android")]
 
 mod androidwindowadapter;
+mod intent;
 mod javahelper;
+mod lifecycle;
 
 #[cfg(all(not(feature = "aa-06"), feature = "aa-05"))]
 pub use android_activity_05 as android_activity;
@@ -19,8 +21,13 @@
 use androidwindowadapter::AndroidWindowAdapter;
 use core::ops::ControlFlow;
 use i_slint_core::api::{EventLoopError, PlatformError};
+use i_slint_core::graphics::{Rgba8Pixel, SharedPixelBuffer};
 use i_slint_core::platform::{Clipboard, WindowAdapter};
 use i_slint_renderer_skia::SkiaRendererExt;
+pub use intent::{
+    launch_intent, on_shared_content, open_url, share_image, share_text, Intent, SharedContent,
+};
+pub use lifecycle::{on_lifecycle_event, LifecycleEvent};
 use std::cell::RefCell;
 use std::rc::{Rc, Weak};
 use std::sync::{Arc, Mutex};
@@ -97,8 +104,14 @@ fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError>
     }
     fn run_event_loop(&self) -> Result<(), PlatformError> {
         loop {
-            let mut timeout = i_slint_core::platform::duration_until_next_timer_update();
-            if self.window.window.has_active_animations() {
+            // While the activity is stopped, only wake up for events
+            let stopped = self.window.stopped.get();
+            let mut timeout = if stopped {
+                None
+            } else {
+                i_slint_core::platform::duration_until_next_timer_update()
+            };
+            if !stopped && self.window.window.has_active_animations() {
                 // FIXME: we should not hardcode a value here
                 let frame_duration = std::time::Duration::from_millis(10);
                 timeout = Some(match timeout {
@@ -108,7 +121,9 @@ fn run_event_loop(&self) -> Result<(), PlatformError> {
             }
             let mut r = Ok(ControlFlow::Continue(()));
             self.app.poll_events(timeout, |e| {
-                i_slint_core::platform::update_timers_and_animations();
+                if !self.window.stopped.get() {
+                    i_slint_core::platform::update_timers_and_animations();
+                }
                 r = self.window.process_event(&e);
                 if let Some(event_listener) = &self.event_listener {
                     event_listener(&e)
@@ -117,7 +132,7 @@ fn run_event_loop(&self) -> Result<(), PlatformError> {
             if r?.is_break() {
                 break;
             }
-            if self.window.pending_redraw.take() {
+            if !self.window.stopped.get() && self.window.pending_redraw.take() {
                 self.window.do_render()?;
             }
         }
@@ -152,6 +167,86 @@ fn clipboard_text(&self, clipboard: Clipboard) -> Option<String> {
             None
         }
     }
+
+    fn set_clipboard_image(&self, image: &SharedPixelBuffer<Rgba8Pixel>, clipboard: Clipboard) {
+        if clipboard == Clipboard::DefaultClipboard {
+            let pixels = javahelper::to_argb_pixels(image);
+            // Writing the image can fail, for example if the storage is full
+            if let Err(e) =
+                self.window.java_helper.set_clipboard_image(&pixels, image.width(), image.height())
+            {
+                javahelper::clear_jni_error(&self.app);
+                eprintln!("Error putting the image into the clipboard: {e}");
+            }
+        }
+    }
+
+    fn clipboard_image(&self, clipboard: Clipboard) -> Option<SharedPixelBuffer<Rgba8Pixel>> {
+        if clipboard != Clipboard::DefaultClipboard {
+            return None;
+        }
+        match self.window.java_helper.get_clipboard_image() {
+            Ok(image) => image.map(|(width, height, pixels)| {
+                javahelper::from_argb_pixels(width, height, &pixels)
+            }),
+            Err(e) => {
+                // Reading the image can fail, for example if the other application removed it
+                javahelper::clear_jni_error(&self.app);
+                eprintln!("Error reading the image of the clipboard: {e}");
+                None
+            }
+        }
+    }
+
+    fn set_clipboard_html(&self, html: &str, alt_text: &str, clipboard: Clipboard) {
+        if clipboard == Clipboard::DefaultClipboard {
+            self.window
+                .java_helper
+                .set_clipboard_html(html, alt_text)
+                .unwrap_or_else(|e| javahelper::print_jni_error(&self.app, e));
+        }
+    }
+
+    fn clipboard_html(&self, clipboard: Clipboard) -> Option<String> {
+        if clipboard == Clipboard::DefaultClipboard {
+            self.window
+                .java_helper
+                .get_clipboard_html()
+                .unwrap_or_else(|e| javahelper::print_jni_error(&self.app, e))
+        } else {
+            None
+        }
+    }
+
+    fn read_resource(&self, path: &str) -> Option<std::io::Result<Vec<u8>>> {
+        // Assets are packaged in the APK, and can only be read through the AssetManager
+        let asset_path = path.strip_prefix("asset://")?;
+        Some((|| {
+            use std::io::Read;
+            let asset_path = std::ffi::CString::new(asset_path)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
+            let mut asset = self.app.asset_manager().open(&asset_path).ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    format!("No asset {asset_path:?} in the application"),
+                )
+            })?;
+            let mut data = Vec::new();
+            asset.read_to_end(&mut data)?;
+            Ok(data)
+        })())
+    }
+
+    fn show_file_dialog(
+        &self,
+        kind: i_slint_core::dialogs::FileDialogKind,
+        options: i_slint_core::dialogs::FileDialogOptions,
+    ) -> Result<i_slint_core::dialogs::FileDialogFuture, PlatformError> {
+        match kind {
+            i_slint_core::dialogs::FileDialogKind::OpenFile => intent::pick_document(options),
+            _ => Err("Only the dialog to open a file is supported on Android".into()),
+        }
+    }
 }
 
 enum Event {
@@ -0,0 +1,51 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! The lifecycle events of the Android activity
+
+use super::*;
+
+/// The changes of the state of the activity, as passed to the handler set with [`on_lifecycle_event()`].
+///
+/// See the [Android documentation](https://developer.android.com/guide/components/activities/activity-lifecycle)
+/// for when they happen.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum LifecycleEvent<'a> {
+    /// The activity became visible (`onStart`). Rendering and timers are resumed.
+    Start,
+    /// The activity is in the foreground and interacts with the user (`onResume`).
+    Resume {
+        /// The state stored during the last [`LifecycleEvent::SaveState`], if the system restarted the
+        /// application after it was stopped.
+        saved_state: Option<&'a [u8]>,
+    },
+    /// The activity is no longer in the foreground, but may still be visible (`onPause`).
+    Pause,
+    /// The activity is no longer visible (`onStop`). Rendering and timers are paused until the next
+    /// [`LifecycleEvent::Start`], so that the application doesn't drain the battery in the background.
+    Stop,
+    /// The system may kill the application. Write the state to restore to `state`, it's passed to the
+    /// next [`LifecycleEvent::Resume`].
+    SaveState {
+        /// The buffer for the state to save, initially empty
+        state: &'a mut Vec<u8>,
+    },
+    /// The system is running low on memory. Release the caches that can be recreated.
+    LowMemory,
+}
+
+pub(crate) type LifecycleHandler = dyn FnMut(LifecycleEvent<'_>);
+
+/// Sets the function that is called when the state of the activity changes, replacing the previous one.
+///
+/// This must be called after the backend is initialized. Unlike the event listener of
+/// [`AndroidPlatform::new_with_event_listener()`], the handler can store the state of the
+/// application to restore it when the system restarts it.
+pub fn on_lifecycle_event(
+    handler: impl FnMut(LifecycleEvent<'_>) + 'static,
+) -> Result<(), PlatformError> {
+    let window = CURRENT_WINDOW.with_borrow(|x| x.upgrade()).ok_or(PlatformError::NoPlatform)?;
+    window.lifecycle_handler.set(Box::new(handler));
+    Ok(())
+}
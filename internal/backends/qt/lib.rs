@@ -139,6 +139,27 @@ pub fn new() -> Self {
             cpp! {unsafe[] {
                 ensure_initialized(true);
             }}
+            // Forward the changes of the screens to Screen::on_changed
+            cpp! {unsafe[] {
+                static bool connected = false;
+                if (std::exchange(connected, true))
+                    return;
+                auto notify = [] {
+                    rust!(Slint_screens_changed [] { i_slint_core::platform::screens_changed() });
+                };
+                auto watch = [notify](QScreen *screen) {
+                    QObject::connect(screen, &QScreen::geometryChanged, qApp, notify);
+                    QObject::connect(screen, &QScreen::logicalDotsPerInchChanged, qApp, notify);
+                };
+                for (QScreen *screen : QGuiApplication::screens())
+                    watch(screen);
+                QObject::connect(qApp, &QGuiApplication::screenAdded, qApp, [=](QScreen *screen) {
+                    watch(screen);
+                    notify();
+                });
+                QObject::connect(qApp, &QGuiApplication::screenRemoved, qApp, notify);
+                QObject::connect(qApp, &QGuiApplication::primaryScreenChanged, qApp, notify);
+            }}
         }
         Self {}
     }
@@ -300,6 +321,47 @@ fn clipboard_text(&self, _clipboard: i_slint_core::platform::Clipboard) -> Optio
         None
     }
 
+    #[cfg(not(no_qt))]
+    fn screens(&self) -> Vec<i_slint_core::api::Screen> {
+        use cpp::cpp;
+        let mut screens = Vec::<i_slint_core::api::Screen>::new();
+        let screens_ptr = &mut screens;
+        cpp! {unsafe [screens_ptr as "void*"] {
+            ensure_initialized();
+            for (QScreen *screen : QGuiApplication::screens()) {
+                // The geometry is in device independent pixels
+                qreal dpr = screen->devicePixelRatio();
+                QRect geometry = screen->geometry();
+                QString name = screen->name();
+                int x = qRound(geometry.x() * dpr);
+                int y = qRound(geometry.y() * dpr);
+                int width = qRound(geometry.width() * dpr);
+                int height = qRound(geometry.height() * dpr);
+                float scale_factor = dpr;
+                bool is_primary = screen == QGuiApplication::primaryScreen();
+                rust!(Slint_push_screen [
+                    screens_ptr: &mut Vec<i_slint_core::api::Screen> as "void*",
+                    name: qttypes::QString as "QString",
+                    x: i32 as "int",
+                    y: i32 as "int",
+                    width: i32 as "int",
+                    height: i32 as "int",
+                    scale_factor: f32 as "float",
+                    is_primary: bool as "bool"
+                ] {
+                    screens_ptr.push(i_slint_core::api::Screen::new(
+                        name.to_string(),
+                        i_slint_core::api::PhysicalPosition::new(x, y),
+                        i_slint_core::api::PhysicalSize::new(width as u32, height as u32),
+                        scale_factor,
+                        is_primary,
+                    ));
+                });
+            }
+        }}
+        screens
+    }
+
     #[cfg(not(no_qt))]
     fn click_interval(&self) -> core::time::Duration {
         let duration_ms = unsafe {
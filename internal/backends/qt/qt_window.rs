@@ -84,6 +84,26 @@ struct TimerHandler : QObject {
 
     };
 
+    // Returns the mime type and the data of a drag and drop, in the format of slint's DropEvent
+    static std::pair<QString, QString> slint_drop_data(const QMimeData *mime) {
+        if (mime->hasUrls()) {
+            QString list;
+            for (const auto &url : mime->urls()) {
+                list += QString::fromUtf8(url.toEncoded()) + QStringLiteral("\r\n");
+            }
+            return { QStringLiteral("text/uri-list"), list };
+        }
+        if (mime->hasHtml())
+            return { QStringLiteral("text/html"), mime->html() };
+        if (mime->hasText())
+            return { QStringLiteral("text/plain"), mime->text() };
+        if (!mime->formats().isEmpty()) {
+            auto format = mime->formats().first();
+            return { format, QString::fromUtf8(mime->data(format)) };
+        }
+        return {};
+    }
+
     struct SlintWidget : QWidget {
         void *rust_window = nullptr;
         bool isMouseButtonDown = false;
@@ -94,6 +114,7 @@ struct SlintWidget : QWidget {
 
         SlintWidget() {
             setMouseTracking(true);
+            setAcceptDrops(true);
             setFocusPolicy(Qt::StrongFocus);
             setAttribute(Qt::WA_TranslucentBackground);
             // WA_TranslucentBackground sets WA_NoSystemBackground, but we actually need WA_NoSystemBackground
@@ -222,6 +243,61 @@ struct SlintWidget : QWidget {
             });
         }
 
+        void dragEnterEvent(QDragEnterEvent *event) override {
+            // Accept the drag in any case to get the move events, the DropArea might be elsewhere
+            dragMoveEvent(event);
+            event->acceptProposedAction();
+        }
+        void dragMoveEvent(QDragMoveEvent *event) override {
+            if (!rust_window)
+                return;
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+            QPoint pos = event->position().toPoint();
+            #else
+            QPoint pos = event->pos();
+            #endif
+            auto drop_data = slint_drop_data(event->mimeData());
+            QString mime_type = drop_data.first;
+            QString data = drop_data.second;
+            bool accepted = rust!(Slint_dragMoveEvent [rust_window: &QtWindow as "void*", pos: qttypes::QPoint as "QPoint", mime_type: qttypes::QString as "QString", data: qttypes::QString as "QString"] -> bool as "bool" {
+                let position = LogicalPoint::new(pos.x as _, pos.y as _);
+                rust_window.drag_event(MouseEvent::DragMove{position}, Some((mime_type.clone(), data.clone())))
+            });
+            if (accepted) {
+                event->acceptProposedAction();
+            } else {
+                event->ignore();
+            }
+        }
+        void dragLeaveEvent(QDragLeaveEvent *) override {
+            if (!rust_window)
+                return;
+            rust!(Slint_dragLeaveEvent [rust_window: &QtWindow as "void*"] {
+                rust_window.drag_event(MouseEvent::Exit, None);
+            });
+        }
+        void dropEvent(QDropEvent *event) override {
+            if (!rust_window)
+                return;
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+            QPoint pos = event->position().toPoint();
+            #else
+            QPoint pos = event->pos();
+            #endif
+            auto drop_data = slint_drop_data(event->mimeData());
+            QString mime_type = drop_data.first;
+            QString data = drop_data.second;
+            bool accepted = rust!(Slint_dropEvent [rust_window: &QtWindow as "void*", pos: qttypes::QPoint as "QPoint", mime_type: qttypes::QString as "QString", data: qttypes::QString as "QString"] -> bool as "bool" {
+                let position = LogicalPoint::new(pos.x as _, pos.y as _);
+                rust_window.drag_event(MouseEvent::Drop{position}, Some((mime_type.clone(), data.clone())))
+            });
+            if (accepted) {
+                event->acceptProposedAction();
+            } else {
+                event->ignore();
+            }
+        }
+
         void keyPressEvent(QKeyEvent *event) override {
             if (!rust_window)
                 return;
@@ -594,6 +670,37 @@ fn mangle_position(position: f32, idx: usize, count: usize) -> f32 {
                 return QBrush(qrg);
             }}
         }
+        i_slint_core::Brush::ConicGradient(g) => {
+            // Qt's conical gradient goes counter-clockwise from 3 o'clock, while ours goes
+            // clockwise from the top, so the angle and the stops need to be mirrored.
+            let start_angle = (90. - g.from_angle()) as qttypes::qreal;
+            cpp_class!(unsafe struct QConicalGradient as "QConicalGradient");
+            let mut qcg = cpp! {
+                unsafe [width as "qreal", height as "qreal", start_angle as "qreal"] -> QConicalGradient as "QConicalGradient" {
+                    QConicalGradient qcg(width / 2, height / 2, start_angle);
+                    return qcg;
+                }
+            };
+            let stops = g.stops().collect::<Vec<_>>();
+            let count = stops.len();
+            for (idx, s) in stops.iter().rev().enumerate() {
+                let pos: f32 = mangle_position(1. - s.position, idx, count);
+                let color: u32 = s.color.as_argb_encoded();
+                cpp! {unsafe [mut qcg as "QConicalGradient", pos as "float", color as "QRgb"] {
+                    qcg.setColorAt(pos, QColor::fromRgba(color));
+                }};
+            }
+            cpp! {unsafe [qcg as "QConicalGradient"] -> qttypes::QBrush as "QBrush" {
+                return QBrush(qcg);
+            }}
+        }
+        i_slint_core::Brush::FourCornerGradient(g) => {
+            // Qt has no equivalent, so fall back to the average of the corners
+            let color: u32 = g.color_at(0.5, 0.5).as_argb_encoded();
+            cpp!(unsafe [color as "QRgb"] -> qttypes::QBrush as "QBrush" {
+                return QBrush(QColor::fromRgba(color));
+            })
+        }
         _ => qttypes::QBrush::default(),
     }
 }
@@ -931,6 +1038,24 @@ fn draw_text_input(
                 (0., 0)
             };
 
+        // Each highlight is passed as start and length in UTF-16 units, followed by the foreground and background colors
+        let highlights: Vec<u32> = visual_representation
+            .highlights
+            .iter()
+            .flat_map(|highlight| {
+                let start = utf8_byte_offset_to_utf16_units(text.as_str(), highlight.range.start);
+                let end = utf8_byte_offset_to_utf16_units(text.as_str(), highlight.range.end);
+                [
+                    start as u32,
+                    (end - start) as u32,
+                    highlight.color.unwrap_or_default().as_argb_encoded(),
+                    highlight.background.unwrap_or_default().as_argb_encoded(),
+                ]
+            })
+            .collect();
+        let highlights_ptr = highlights.as_ptr();
+        let highlights_len = highlights.len();
+
         let single_line: bool = text_input.single_line();
 
         let painter: &mut QPainterPtr = &mut self.painter;
@@ -938,6 +1063,8 @@ fn draw_text_input(
                 painter as "QPainterPtr*",
                 rect as "QRectF",
                 fill_brush as "QBrush",
+                highlights_ptr as "const uint32_t*",
+                highlights_len as "size_t",
                 selection_foreground_color as "QRgb",
                 selection_background_color as "QRgb",
                 underline_selection as "bool",
@@ -956,6 +1083,18 @@ fn draw_text_input(
             do_text_layout(layout, flags, rect);
             (*painter)->setPen(QPen(fill_brush, 0));
             QVector<QTextLayout::FormatRange> selections;
+            for (size_t i = 0; i + 3 < highlights_len; i += 4) {
+                QTextCharFormat fmt;
+                if (qAlpha(highlights_ptr[i + 2]) != 0) {
+                    fmt.setForeground(QColor::fromRgba(highlights_ptr[i + 2]));
+                }
+                if (qAlpha(highlights_ptr[i + 3]) != 0) {
+                    fmt.setBackground(QColor::fromRgba(highlights_ptr[i + 3]));
+                }
+                selections << QTextLayout::FormatRange{
+                    int(highlights_ptr[i]), int(highlights_ptr[i + 1]), fmt
+                };
+            }
             if (selection_end_position != selection_start_position) {
                 QTextCharFormat fmt;
                 if (qAlpha(selection_background_color) != 0) {
@@ -1256,8 +1395,21 @@ fn draw_string(&mut self, string: &str, color: Color) {
         }}
     }
 
-    fn draw_image_direct(&mut self, _image: i_slint_core::graphics::Image) {
-        todo!()
+    fn draw_image_direct(&mut self, image: i_slint_core::graphics::Image) {
+        let size = image.size();
+        let Some(pixmap) = image_to_pixmap((&image).into(), None) else {
+            return;
+        };
+        let dest_rect =
+            qttypes::QRectF { x: 0., y: 0., width: size.width as f64, height: size.height as f64 };
+        let painter: &mut QPainterPtr = &mut self.painter;
+        cpp! { unsafe [
+                painter as "QPainterPtr*",
+                pixmap as "QPixmap",
+                dest_rect as "QRectF"
+            ] {
+            (*painter)->drawPixmap(dest_rect, pixmap, QRectF(pixmap.rect()));
+        }}
     }
 
     fn window(&self) -> &i_slint_core::window::WindowInner {
@@ -1539,7 +1691,9 @@ fn render_layer(
         item_rc: &ItemRc,
         layer_size_fn: &dyn Fn() -> LogicalSize,
     ) -> qttypes::QPixmap {
-        self.cache.get_or_update_cache_entry(item_rc,  || {
+        let mut layer_rendered = false;
+        let layer = self.cache.get_or_update_cache_entry(item_rc,  || {
+            layer_rendered = true;
             let painter: &mut QPainterPtr = &mut self.painter;
             let dpr = cpp! { unsafe [painter as "QPainterPtr*"] -> f32 as "float" {
                 return (*painter)->paintEngine()->paintDevice()->devicePixelRatioF();
@@ -1577,7 +1731,11 @@ fn render_layer(
             drop(layer_painter);
 
             qttypes::QPixmap::from(layer_image)
-        })
+        });
+        if !layer_rendered {
+            *self.metrics.layer_cache_hits.as_mut().unwrap() += 1;
+        }
+        layer
     }
 
     fn render_and_blend_layer(&mut self, alpha_tint: f32, self_rc: &ItemRc) -> RenderingResult {
@@ -1695,7 +1853,7 @@ fn paint_event(&self, painter: QPainterPtr) {
                 painter,
                 cache: &self.cache,
                 window: &self.window,
-                metrics: RenderingMetrics { layers_created: Some(0) },
+                metrics: RenderingMetrics { layers_created: Some(0), layer_cache_hits: Some(0) },
             };
 
             for (component, origin) in components {
@@ -1738,6 +1896,25 @@ fn mouse_event(&self, event: MouseEvent) {
         timer_event();
     }
 
+    /// Sends a drag and drop event of the windowing system, and returns true if a `DropArea` accepted it
+    fn drag_event(
+        &self,
+        event: MouseEvent,
+        data: Option<(qttypes::QString, qttypes::QString)>,
+    ) -> bool {
+        let data = data.map(|(mime_type, data)| items::DropEvent {
+            mime_type: String::from(mime_type).into(),
+            data: String::from(data).into(),
+            position: event
+                .position()
+                .map(i_slint_core::lengths::logical_position_to_api)
+                .unwrap_or_default(),
+        });
+        let accepted = WindowInner::from_pub(&self.window).process_external_drag(event, data);
+        timer_event();
+        accepted
+    }
+
     fn key_event(&self, key: i32, text: qttypes::QString, released: bool, repeat: bool) {
         i_slint_core::animations::update_animations();
         let text: String = text.into();
@@ -1943,9 +2120,41 @@ fn update_window_properties(&self, properties: i_slint_core::window::WindowPrope
         let fullscreen: bool = properties.is_fullscreen();
         let minimized: bool = properties.is_minimized();
         let maximized: bool = properties.is_maximized();
+        let transparent: bool = properties.is_transparent();
+        let keyboard_grabbed: bool = properties.is_keyboard_grabbed();
+
+        // The input region is set as the mask of the widget, with a QRectF and whether it's an ellipse per part
+        let input_region = properties.input_region();
+        let has_input_region = input_region.is_some();
+        let (region_rects, region_ellipses): (Vec<qttypes::QRectF>, Vec<bool>) = input_region
+            .iter()
+            .flat_map(|region| region.parts())
+            .filter_map(|part| {
+                let (position, size, ellipse) = match *part {
+                    i_slint_core::api::WindowRegionPart::Rectangle { position, size } => {
+                        (position, size, false)
+                    }
+                    i_slint_core::api::WindowRegionPart::Ellipse { position, size } => {
+                        (position, size, true)
+                    }
+                    _ => return None,
+                };
+                let rect = qttypes::QRectF {
+                    x: position.x as _,
+                    y: position.y as _,
+                    width: size.width as _,
+                    height: size.height as _,
+                };
+                Some((rect, ellipse))
+            })
+            .unzip();
+        let region_len = region_rects.len();
+        let region_rects_ptr = region_rects.as_ptr();
+        let region_ellipses_ptr = region_ellipses.as_ptr();
 
         cpp! {unsafe [widget_ptr as "QWidget*",  title as "QString", size as "QSize", background as "QBrush", no_frame as "bool", always_on_top as "bool",
-                      fullscreen as "bool", minimized as "bool", maximized as "bool"] {
+                      fullscreen as "bool", minimized as "bool", maximized as "bool", transparent as "bool", keyboard_grabbed as "bool",
+                      has_input_region as "bool", region_rects_ptr as "const QRectF*", region_ellipses_ptr as "const bool*", region_len as "size_t"] {
 
             if (size != widget_ptr->size()) {
                 widget_ptr->resize(size.expandedTo({1, 1}));
@@ -1972,6 +2181,27 @@ fn update_window_properties(&self, properties: i_slint_core::window::WindowPrope
                 widget_ptr->setWindowState(state);
             }
 
+            if (transparent != widget_ptr->testAttribute(Qt::WA_TranslucentBackground)) {
+                widget_ptr->setAttribute(Qt::WA_TranslucentBackground, transparent);
+                // See the constructor of SlintWidget
+                widget_ptr->setAttribute(Qt::WA_NoSystemBackground, false);
+            }
+
+            // The window handle only exists once the window is shown
+            if (auto window = widget_ptr->windowHandle()) {
+                window->setKeyboardGrabEnabled(keyboard_grabbed);
+            }
+
+            if (has_input_region) {
+                QRegion mask;
+                for (size_t i = 0; i < region_len; ++i) {
+                    mask += QRegion(region_rects_ptr[i].toAlignedRect(), region_ellipses_ptr[i] ? QRegion::Ellipse : QRegion::Rectangle);
+                }
+                widget_ptr->setMask(mask);
+            } else if (!widget_ptr->mask().isEmpty()) {
+                widget_ptr->clearMask();
+            }
+
             widget_ptr->setWindowTitle(title);
             auto pal = widget_ptr->palette();
 
@@ -2113,10 +2343,10 @@ fn input_method_request(&self, request: i_slint_core::window::InputMethodRequest
         };
 
         let rect = qttypes::QRectF {
-            x: props.cursor_rect_origin.x as _,
-            y: props.cursor_rect_origin.y as _,
-            width: props.cursor_rect_size.width as _,
-            height: props.cursor_rect_size.height as _,
+            x: props.candidate_rect_origin.x as _,
+            y: props.candidate_rect_origin.y as _,
+            width: props.candidate_rect_size.width as _,
+            height: props.candidate_rect_size.height as _,
         };
         let cursor: i32 = props.text[..props.cursor_position].encode_utf16().count() as _;
         let anchor: i32 =
@@ -2131,6 +2361,27 @@ fn input_method_request(&self, request: i_slint_core::window::InputMethodRequest
         }};
     }
 
+    fn start_drag(&self, data: &items::DropEvent) -> bool {
+        let widget_ptr = self.widget_ptr();
+        let mime_type: qttypes::QString = data.mime_type.as_str().into();
+        let data: qttypes::QString = data.data.as_str().into();
+        cpp! {unsafe [widget_ptr as "QWidget*", mime_type as "QString", data as "QString"] {
+            // QDrag::exec runs a nested event loop, so don't start it while the mouse event is processed
+            QTimer::singleShot(0, widget_ptr, [widget_ptr, mime_type, data] {
+                auto drag = new QDrag(widget_ptr);
+                auto mime = new QMimeData;
+                if (mime_type == QStringLiteral("text/plain")) {
+                    mime->setText(data);
+                } else {
+                    mime->setData(mime_type, data.toUtf8());
+                }
+                drag->setMimeData(mime);
+                drag->exec(Qt::CopyAction | Qt::MoveAction | Qt::LinkAction, Qt::CopyAction);
+            });
+        }};
+        true
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 use alloc::boxed::Box;
+use alloc::vec::Vec;
 use alloc::{format, string::String};
 
 use i_slint_core::api::PlatformError;
@@ -21,13 +22,47 @@
 ///     eprintln!("Error selecting backend with OpenGL ES support: {err}");
 /// }
 /// ```
+///
+/// Use [`Self::or_fallback()`] to specify an ordered list of preferences. The first combination of backend
+/// and renderer that's available at run-time is selected, and [`Self::on_selected()`] reports which one that is:
+/// ```rust,no_run
+/// # use i_slint_backend_selector::api::BackendSelector;
+/// BackendSelector::new()
+///     .renderer_name("skia-opengl".into())
+///     .or_fallback(BackendSelector::new().renderer_name("software".into()))
+///     .on_selected(|selected| {
+///         eprintln!("Selected the {} backend with renderer {:?}", selected.backend_name, selected.renderer_name)
+///     })
+///     .select()
+///     .unwrap();
+/// ```
 pub struct BackendSelector {
     requested_graphics_api: Option<RequestedGraphicsAPI>,
     backend: Option<String>,
     renderer: Option<String>,
+    fallbacks: Vec<BackendRequirements>,
+    selected_callback: Option<Box<dyn FnOnce(&SelectedBackend)>>,
     selected: bool,
 }
 
+/// The requirements of one of the alternatives of a [`BackendSelector`].
+struct BackendRequirements {
+    requested_graphics_api: Option<RequestedGraphicsAPI>,
+    backend: Option<String>,
+    renderer: Option<String>,
+}
+
+/// Describes the backend and renderer that a [`BackendSelector`] selected.
+/// This is passed to the callback set with [`BackendSelector::on_selected()`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct SelectedBackend {
+    /// The name of the selected backend, for example `winit`.
+    pub backend_name: String,
+    /// The name of the selected renderer, or None if the backend chooses its default renderer.
+    pub renderer_name: Option<String>,
+}
+
 impl BackendSelector {
     /// Creates a new BackendSelector.
     #[must_use]
@@ -36,6 +71,8 @@ pub fn new() -> BackendSelector {
             requested_graphics_api: None,
             backend: None,
             renderer: None,
+            fallbacks: Vec::new(),
+            selected_callback: None,
             selected: false,
         }
     }
@@ -113,9 +150,35 @@ pub fn backend_name(mut self, name: String) -> Self {
         self
     }
 
+    /// Adds an alternative to try if the requirements of this selector can't be met, for example
+    /// because the requested backend fails to initialize or the requested renderer isn't available.
+    /// Alternatives are tried in the order they were added. The callback set with [`Self::on_selected()`]
+    /// on the `fallback` is ignored.
+    #[must_use]
+    pub fn or_fallback(mut self, mut fallback: BackendSelector) -> Self {
+        // The fallback is selected as part of this selector, not when it's dropped.
+        fallback.selected = true;
+        self.fallbacks.push(BackendRequirements {
+            requested_graphics_api: fallback.requested_graphics_api.take(),
+            backend: fallback.backend.take(),
+            renderer: fallback.renderer.take(),
+        });
+        self.fallbacks.append(&mut fallback.fallbacks);
+        self
+    }
+
+    /// Sets a callback that's invoked with the backend and renderer that were selected, after
+    /// trying this selector's requirements and its fallbacks.
+    #[must_use]
+    pub fn on_selected(mut self, callback: impl FnOnce(&SelectedBackend) + 'static) -> Self {
+        self.selected_callback = Some(Box::new(callback));
+        self
+    }
+
     /// Completes the backend selection process and tries to combine with specified requirements
     /// with the different backends and renderers enabled at compile time. On success, the selected
-    /// backend is automatically set to be active. Returns an error if the requirements could not be met.
+    /// backend is automatically set to be active. Returns an error if the requirements could not be met,
+    /// neither by this selector nor by any of its fallbacks.
     pub fn select(mut self) -> Result<(), PlatformError> {
         self.select_internal()
     }
@@ -123,9 +186,52 @@ pub fn select(mut self) -> Result<(), PlatformError> {
     fn select_internal(&mut self) -> Result<(), PlatformError> {
         self.selected = true;
 
-        let backend_name = self.backend.as_deref().unwrap_or(super::DEFAULT_BACKEND_NAME);
+        let primary = BackendRequirements {
+            requested_graphics_api: self.requested_graphics_api.take(),
+            backend: self.backend.take(),
+            renderer: self.renderer.take(),
+        };
+        let fallbacks = core::mem::take(&mut self.fallbacks);
+        let has_fallbacks = !fallbacks.is_empty();
+
+        let mut errors: Vec<String> = Vec::new();
+        for candidate in core::iter::once(primary).chain(fallbacks) {
+            match candidate.create_backend() {
+                Ok(backend) => {
+                    i_slint_core::platform::set_platform(backend)
+                        .map_err(PlatformError::SetPlatformError)?;
+                    if let Some(callback) = self.selected_callback.take() {
+                        callback(&SelectedBackend {
+                            backend_name: candidate.backend_name().into(),
+                            renderer_name: candidate.renderer,
+                        });
+                    }
+                    return Ok(());
+                }
+                // Without fallbacks, report the error as is.
+                Err(err) if !has_fallbacks => return Err(err),
+                Err(err) => errors.push(format!(
+                    "Error from {} backend with renderer {}: {err}",
+                    candidate.backend_name(),
+                    candidate.renderer.as_deref().unwrap_or("default")
+                )),
+            }
+        }
 
-        let backend: Box<dyn i_slint_core::platform::Platform> = match backend_name {
+        Err(PlatformError::Other(format!(
+            "None of the requested backends could be selected.\n{}",
+            errors.join("\n")
+        )))
+    }
+}
+
+impl BackendRequirements {
+    fn backend_name(&self) -> &str {
+        self.backend.as_deref().unwrap_or(super::DEFAULT_BACKEND_NAME)
+    }
+
+    fn create_backend(&self) -> Result<Box<dyn i_slint_core::platform::Platform>, PlatformError> {
+        Ok(match self.backend_name() {
             #[cfg(all(feature = "i-slint-backend-linuxkms", target_os = "linux"))]
             "linuxkms" => {
                 if self.requested_graphics_api.is_some() {
@@ -173,10 +279,7 @@ fn select_internal(&mut self) -> Result<(), PlatformError> {
                 )
                 .into());
             }
-        };
-
-        i_slint_core::platform::set_platform(backend)
-            .map_err(|set_platform_error| PlatformError::SetPlatformError(set_platform_error))
+        })
     }
 }
 
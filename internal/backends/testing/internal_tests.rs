@@ -80,3 +80,13 @@ pub fn access_testing_window<R>(
         .map(callback)
         .expect("access_testing_window called without testing backend/adapter")
 }
+
+/// Simulate the back button or the back gesture of the platform, and return whether the window handled it
+pub fn send_back_request<
+    X: vtable::HasStaticVTable<i_slint_core::item_tree::ItemTreeVTable>,
+    Component: Into<vtable::VRc<i_slint_core::item_tree::ItemTreeVTable, X>> + ComponentHandle,
+>(
+    component: &Component,
+) -> bool {
+    WindowInner::from_pub(component.window()).request_back()
+}
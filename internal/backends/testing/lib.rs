@@ -15,6 +15,8 @@
 pub use testing_backend::*;
 #[cfg(all(feature = "ffi", not(test)))]
 mod ffi;
+#[cfg(feature = "screenshot")]
+pub mod screenshot;
 #[cfg(feature = "system-testing")]
 pub mod systest;
 
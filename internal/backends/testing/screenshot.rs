@@ -0,0 +1,165 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Render components with the software renderer, without a windowing system, and compare the
+//! result with reference images.
+//!
+//! Call [`init()`] before creating any component, then render the window of a component with
+//! [`render()`] and compare it with a reference image using [`compare()`].
+
+use i_slint_core::api::Window;
+use i_slint_core::graphics::{Rgb8Pixel, SharedPixelBuffer};
+use i_slint_core::platform::{OffscreenWindowAdapter, PlatformError};
+use i_slint_core::window::WindowAdapter;
+use std::rc::Rc;
+
+struct ScreenshotPlatform;
+
+impl i_slint_core::platform::Platform for ScreenshotPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(OffscreenWindowAdapter::new())
+    }
+
+    fn duration_since_start(&self) -> core::time::Duration {
+        // Animations don't advance unless the time is mocked, so that the rendering is reproducible
+        core::time::Duration::from_millis(i_slint_core::animations::current_tick().0)
+    }
+}
+
+/// Initialize a platform whose windows are rendered with the software renderer by [`render()`].
+/// The windows have the preferred size of their component unless [`Window::set_size()`] is called,
+/// and [`Window::dispatch_event()`] with a `ScaleFactorChanged` event sets their scale factor.
+/// Must be called before any call that would otherwise initialize the rendering backend.
+pub fn init() {
+    i_slint_core::platform::set_platform(Box::new(ScreenshotPlatform))
+        .expect("platform already initialized");
+}
+
+/// Render the `window` of a component that was created after calling [`init()`].
+pub fn render(window: &Window) -> Result<SharedPixelBuffer<Rgb8Pixel>, String> {
+    let size = window.size();
+    if size.width == 0 || size.height == 0 {
+        return Err(format!("Cannot render a window of size {}x{}", size.width, size.height));
+    }
+    let snapshot = window.take_snapshot().map_err(|e| e.to_string())?;
+    let mut buffer = SharedPixelBuffer::<Rgb8Pixel>::new(snapshot.width(), snapshot.height());
+    for (target, source) in buffer.make_mut_slice().iter_mut().zip(snapshot.as_slice()) {
+        *target = source.rgb();
+    }
+    Ok(buffer)
+}
+
+/// How different a rendering may be from the reference image
+#[derive(Clone, Copy, Debug)]
+pub struct Tolerance {
+    /// The [`perceptual_difference()`] above which a pixel counts as different
+    pub pixel_threshold: f32,
+    /// The percentage of the pixels that may be different
+    pub max_different_pixels: f32,
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Self { pixel_threshold: 0.1, max_different_pixels: 0. }
+    }
+}
+
+/// The result of [`compare()`]
+pub struct Comparison {
+    /// The number of pixels that are different according to the tolerance
+    pub different_pixels: usize,
+    /// The percentage of the pixels that are different
+    pub different_percentage: f32,
+    /// The largest [`perceptual_difference()`] of all the pixels
+    pub max_difference: f32,
+    /// The reference image in shades of gray, with the pixels that are different in red
+    pub diff: SharedPixelBuffer<Rgb8Pixel>,
+    /// Whether the number of different pixels is within the tolerance
+    pub matches: bool,
+}
+
+/// How different two colors look, between 0 (same color) and 1 (as different as black and white).
+/// This compares the brightness and the chrominance of the colors in the YIQ color space,
+/// so that changes that are hard to see count less.
+pub fn perceptual_difference(a: Rgb8Pixel, b: Rgb8Pixel) -> f32 {
+    let yiq = |p: Rgb8Pixel| {
+        let (r, g, b) = (p.r as f32, p.g as f32, p.b as f32);
+        (
+            r * 0.298_895_3 + g * 0.586_622_5 + b * 0.114_482_23,
+            r * 0.595_977_99 - g * 0.274_176_1 - b * 0.321_801_9,
+            r * 0.211_470_17 - g * 0.522_617_1 + b * 0.311_146_94,
+        )
+    };
+    let (ya, ia, qa) = yiq(a);
+    let (yb, ib, qb) = yiq(b);
+    let delta = 0.5053 * (ya - yb).powi(2) + 0.299 * (ia - ib).powi(2) + 0.1957 * (qa - qb).powi(2);
+    // Only the brightness differs between black and white
+    (delta / (0.5053 * 255. * 255.)).sqrt().min(1.)
+}
+
+/// Compare a `rendering` with the `reference` image. Returns an error if the sizes are different.
+pub fn compare(
+    reference: &SharedPixelBuffer<Rgb8Pixel>,
+    rendering: &SharedPixelBuffer<Rgb8Pixel>,
+    tolerance: &Tolerance,
+) -> Result<Comparison, String> {
+    if reference.size() != rendering.size() {
+        return Err(format!(
+            "The size of the rendering is {}x{} instead of {}x{}",
+            rendering.width(),
+            rendering.height(),
+            reference.width(),
+            reference.height()
+        ));
+    }
+    let mut diff = SharedPixelBuffer::<Rgb8Pixel>::new(reference.width(), reference.height());
+    let mut different_pixels = 0;
+    let mut max_difference = 0f32;
+    for ((r, s), d) in
+        reference.as_slice().iter().zip(rendering.as_slice()).zip(diff.make_mut_slice())
+    {
+        let difference = perceptual_difference(*r, *s);
+        max_difference = max_difference.max(difference);
+        *d = if difference > tolerance.pixel_threshold {
+            different_pixels += 1;
+            Rgb8Pixel::new(255, 0, 0)
+        } else {
+            // A faded gray version of the reference, so that the differences stand out
+            let luminance = r.r as f32 * 0.3 + r.g as f32 * 0.59 + r.b as f32 * 0.11;
+            let gray = (255. - (255. - luminance) * 0.25) as u8;
+            Rgb8Pixel::new(gray, gray, gray)
+        };
+    }
+    let total = reference.as_slice().len().max(1);
+    let different_percentage = different_pixels as f32 * 100. / total as f32;
+    Ok(Comparison {
+        different_pixels,
+        different_percentage,
+        max_difference,
+        diff,
+        matches: different_percentage <= tolerance.max_different_pixels,
+    })
+}
+
+/// Load a PNG file, or any other format supported by the `image` crate
+pub fn load_image(path: &std::path::Path) -> Result<SharedPixelBuffer<Rgb8Pixel>, String> {
+    let image = image::open(path).map_err(|e| format!("Cannot load {}: {e}", path.display()))?;
+    let image = image.into_rgb8();
+    Ok(SharedPixelBuffer::clone_from_slice(image.as_raw(), image.width(), image.height()))
+}
+
+/// Save the `buffer` to a PNG file
+pub fn save_image(
+    path: &std::path::Path,
+    buffer: &SharedPixelBuffer<Rgb8Pixel>,
+) -> Result<(), String> {
+    image::save_buffer_with_format(
+        path,
+        buffer.as_bytes(),
+        buffer.width(),
+        buffer.height(),
+        image::ColorType::Rgb8,
+        image::ImageFormat::Png,
+    )
+    .map_err(|e| format!("Cannot save {}: {e}", path.display()))
+}
@@ -1,9 +1,9 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-use i_slint_core::api::PhysicalSize;
+use i_slint_core::api::{PhysicalPosition, PhysicalSize};
 use i_slint_core::graphics::euclid::{Point2D, Size2D};
-use i_slint_core::graphics::FontRequest;
+use i_slint_core::graphics::{FontRequest, Rgba8Pixel, SharedPixelBuffer};
 use i_slint_core::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, ScaleFactor};
 use i_slint_core::platform::PlatformError;
 use i_slint_core::renderer::{Renderer, RendererSealed};
@@ -21,8 +21,16 @@ pub struct TestingBackendOptions {
     pub threading: bool,
 }
 
+/// The content of the clipboard. Setting one kind of content replaces the others.
+#[derive(Default)]
+struct ClipboardContent {
+    text: Option<String>,
+    html: Option<String>,
+    image: Option<SharedPixelBuffer<Rgba8Pixel>>,
+}
+
 pub struct TestingBackend {
-    clipboard: Mutex<Option<String>>,
+    clipboard: Mutex<ClipboardContent>,
     queue: Option<Queue>,
     mock_time: bool,
 }
@@ -44,6 +52,7 @@ fn create_window_adapter(
         Ok(Rc::new_cyclic(|self_weak| TestingWindow {
             window: i_slint_core::api::Window::new(self_weak.clone() as _),
             size: Default::default(),
+            position: Default::default(),
             ime_requests: Default::default(),
             mouse_cursor: Default::default(),
         }))
@@ -63,18 +72,93 @@ fn duration_since_start(&self) -> core::time::Duration {
 
     fn set_clipboard_text(&self, text: &str, clipboard: i_slint_core::platform::Clipboard) {
         if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
-            *self.clipboard.lock().unwrap() = Some(text.into());
+            *self.clipboard.lock().unwrap() =
+                ClipboardContent { text: Some(text.into()), ..Default::default() };
         }
     }
 
     fn clipboard_text(&self, clipboard: i_slint_core::platform::Clipboard) -> Option<String> {
         if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
-            self.clipboard.lock().unwrap().clone()
+            self.clipboard.lock().unwrap().text.clone()
+        } else {
+            None
+        }
+    }
+
+    fn set_clipboard_image(
+        &self,
+        image: &SharedPixelBuffer<Rgba8Pixel>,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) {
+        if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
+            *self.clipboard.lock().unwrap() =
+                ClipboardContent { image: Some(image.clone()), ..Default::default() };
+        }
+    }
+
+    fn clipboard_image(
+        &self,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) -> Option<SharedPixelBuffer<Rgba8Pixel>> {
+        if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
+            self.clipboard.lock().unwrap().image.clone()
+        } else {
+            None
+        }
+    }
+
+    fn set_clipboard_html(
+        &self,
+        html: &str,
+        alt_text: &str,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) {
+        if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
+            *self.clipboard.lock().unwrap() = ClipboardContent {
+                text: Some(alt_text.into()),
+                html: Some(html.into()),
+                image: None,
+            };
+        }
+    }
+
+    fn clipboard_html(&self, clipboard: i_slint_core::platform::Clipboard) -> Option<String> {
+        if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
+            self.clipboard.lock().unwrap().html.clone()
         } else {
             None
         }
     }
 
+    fn screens(&self) -> Vec<i_slint_core::api::Screen> {
+        // Two screens side by side
+        vec![
+            i_slint_core::api::Screen::new(
+                "Testing Screen 1",
+                PhysicalPosition::new(0, 0),
+                PhysicalSize::new(1920, 1080),
+                1.,
+                true,
+            ),
+            i_slint_core::api::Screen::new(
+                "Testing Screen 2",
+                PhysicalPosition::new(1920, 0),
+                PhysicalSize::new(2560, 1440),
+                2.,
+                false,
+            ),
+        ]
+    }
+
+    fn video_modes(&self, screen: &i_slint_core::api::Screen) -> Vec<i_slint_core::api::VideoMode> {
+        [(screen.size(), 60000), (screen.size(), 144000), (PhysicalSize::new(1280, 720), 60000)]
+            .into_iter()
+            .map(|(size, refresh_rate)| {
+                i_slint_core::api::VideoMode::new(screen.clone(), size, refresh_rate, 32)
+            })
+            .collect()
+    }
+
     fn run_event_loop(&self) -> Result<(), PlatformError> {
         let queue = match self.queue.as_ref() {
             Some(queue) => queue.clone(),
@@ -107,6 +191,7 @@ fn new_event_loop_proxy(&self) -> Option<Box<dyn i_slint_core::platform::EventLo
 pub struct TestingWindow {
     window: i_slint_core::api::Window,
     size: Cell<PhysicalSize>,
+    position: Cell<PhysicalPosition>,
     pub ime_requests: RefCell<Vec<InputMethodRequest>>,
     pub mouse_cursor: Cell<i_slint_core::items::MouseCursor>,
 }
@@ -138,6 +223,14 @@ fn size(&self) -> PhysicalSize {
         }
     }
 
+    fn position(&self) -> Option<PhysicalPosition> {
+        Some(self.position.get())
+    }
+
+    fn set_position(&self, position: i_slint_core::api::WindowPosition) {
+        self.position.set(position.to_physical(1.))
+    }
+
     fn set_size(&self, size: i_slint_core::api::WindowSize) {
         self.window.dispatch_event(i_slint_core::platform::WindowEvent::Resized {
             size: size.to_logical(1.),
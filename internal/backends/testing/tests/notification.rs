@@ -0,0 +1,60 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::platform::{PointerEventButton, WindowEvent};
+use slint::{ComponentHandle, LogicalPosition, Notification, SharedString};
+use std::cell::Cell;
+use std::rc::Rc;
+
+slint::slint! {
+    #[toasts]
+    export component App inherits Window {
+        width: 400px;
+        height: 300px;
+        in-out property <int> click-count;
+        TouchArea {
+            clicked => { root.click-count += 1; }
+        }
+    }
+}
+
+fn click(app: &App, x: f32, y: f32) {
+    let position = LogicalPosition::new(x, y);
+    let button = PointerEventButton::Left;
+    app.window().dispatch_event(WindowEvent::PointerPressed { position, button });
+    app.window().dispatch_event(WindowEvent::PointerReleased { position, button });
+}
+
+#[test]
+fn test_notification_fallback_toast() {
+    i_slint_backend_testing::init_no_event_loop();
+    let app = App::new().unwrap();
+
+    let open_count = Rc::new(Cell::new(0));
+    let notification = Notification::new("Saved");
+    notification.add_action("Open", {
+        let open_count = open_count.clone();
+        move || open_count.set(open_count.get() + 1)
+    });
+    assert_eq!(notification.title(), "Saved");
+    assert_eq!(notification.action_labels(), vec![SharedString::from("Open")]);
+
+    // The testing backend has no notification service, and there is no window to show a toast in
+    assert!(notification.show().is_err());
+
+    app.show().unwrap();
+    notification.show().unwrap();
+
+    // The toast is 16 + 50 + 16 + 40 + 16 pixels wide and 34 pixels high, 16 pixels above
+    // the bottom: the action is between x = 213 and x = 253
+    click(&app, 230., 265.);
+    assert_eq!(open_count.get(), 1);
+    assert_eq!(app.get_click_count(), 0);
+
+    // Dropping the notification closes the toast
+    notification.show().unwrap();
+    drop(notification);
+    click(&app, 230., 265.);
+    assert_eq!(open_count.get(), 1);
+    assert_eq!(app.get_click_count(), 1);
+}
@@ -0,0 +1,71 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+#![cfg(feature = "screenshot")]
+
+use i_slint_backend_testing::screenshot::{self, Tolerance};
+use i_slint_core::graphics::{Rgb8Pixel, SharedPixelBuffer};
+
+#[test]
+fn test_render_with_scale_factor() {
+    screenshot::init();
+
+    slint::slint! {
+        export component App inherits Window {
+            background: white;
+            Rectangle { x: 10px; y: 0px; width: 10px; height: 10px; background: #ff0000; }
+        }
+    }
+
+    let app = App::new().unwrap();
+    app.window()
+        .dispatch_event(slint::platform::WindowEvent::ScaleFactorChanged { scale_factor: 2. });
+    app.window().set_size(slint::LogicalSize::new(30., 20.));
+    app.show().unwrap();
+
+    let rendering = screenshot::render(app.window()).unwrap();
+    assert_eq!((rendering.width(), rendering.height()), (60, 40));
+    let pixel = |x: u32, y: u32| rendering.as_slice()[(y * rendering.width() + x) as usize];
+    assert_eq!(pixel(5, 5), Rgb8Pixel::new(255, 255, 255));
+    assert_eq!(pixel(25, 5), Rgb8Pixel::new(255, 0, 0));
+    assert_eq!(pixel(25, 25), Rgb8Pixel::new(255, 255, 255));
+}
+
+#[test]
+fn test_compare() {
+    let image = |pixels: &[Rgb8Pixel]| {
+        let mut buffer = SharedPixelBuffer::new(2, pixels.len() as u32 / 2);
+        buffer.make_mut_slice().copy_from_slice(pixels);
+        buffer
+    };
+    let white = Rgb8Pixel::new(255, 255, 255);
+    let black = Rgb8Pixel::new(0, 0, 0);
+    let almost_white = Rgb8Pixel::new(250, 252, 255);
+
+    assert_eq!(screenshot::perceptual_difference(white, white), 0.);
+    assert!(screenshot::perceptual_difference(white, black) > 0.999);
+    assert!(screenshot::perceptual_difference(white, almost_white) < 0.1);
+
+    let reference = image(&[white, white, white, black]);
+    let comparison = screenshot::compare(
+        &reference,
+        &image(&[almost_white, white, white, black]),
+        &Tolerance::default(),
+    )
+    .unwrap();
+    assert!(comparison.matches);
+    assert_eq!(comparison.different_pixels, 0);
+
+    let rendering = image(&[black, white, white, black]);
+    let comparison = screenshot::compare(&reference, &rendering, &Tolerance::default()).unwrap();
+    assert!(!comparison.matches);
+    assert_eq!(comparison.different_pixels, 1);
+    assert_eq!(comparison.different_percentage, 25.);
+    assert_eq!(comparison.diff.as_slice()[0], Rgb8Pixel::new(255, 0, 0));
+    assert_ne!(comparison.diff.as_slice()[1], Rgb8Pixel::new(255, 0, 0));
+
+    let tolerance = Tolerance { max_different_pixels: 30., ..Default::default() };
+    assert!(screenshot::compare(&reference, &rendering, &tolerance).unwrap().matches);
+
+    assert!(screenshot::compare(&reference, &image(&[white, white]), &tolerance).is_err());
+}
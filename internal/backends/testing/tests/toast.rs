@@ -0,0 +1,148 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::platform::{PointerEventButton, WindowEvent};
+use slint::{ComponentHandle, LogicalPosition, Toast, ToastOptions};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+slint::slint! {
+    #[toasts]
+    export component App inherits Window {
+        width: 400px;
+        height: 300px;
+        in-out property <int> click-count;
+        TouchArea {
+            clicked => { root.click-count += 1; }
+        }
+    }
+
+    component BaseWindow inherits Window {
+        VerticalLayout {
+            Rectangle { height: 50px; }
+            @children
+        }
+    }
+
+    export component AppWithBase inherits BaseWindow {
+        width: 400px;
+        height: 300px;
+        in-out property <int> click-count;
+        TouchArea {
+            clicked => { root.click-count += 1; }
+        }
+    }
+}
+
+fn click(app: &impl ComponentHandle, x: f32, y: f32) {
+    let position = LogicalPosition::new(x, y);
+    let button = PointerEventButton::Left;
+    app.window().dispatch_event(WindowEvent::PointerPressed { position, button });
+    app.window().dispatch_event(WindowEvent::PointerReleased { position, button });
+}
+
+#[test]
+fn test_toast() {
+    i_slint_backend_testing::init_no_event_loop();
+    let app = App::new().unwrap();
+    app.show().unwrap();
+
+    // The testing backend measures every character as 10x10 pixels: the toast is
+    // 16 + 70 + 16 + 40 + 16 pixels wide and 12 + 10 + 12 pixels high, 16 pixels above the bottom.
+    let undo_count = Rc::new(Cell::new(0));
+    let toast = Toast::show(
+        app.window(),
+        ToastOptions::new("Deleted").with_action("Undo", {
+            let undo_count = undo_count.clone();
+            move || undo_count.set(undo_count.get() + 1)
+        }),
+    );
+    assert!(toast.is_visible());
+
+    // A click on the message doesn't reach the content below
+    click(&app, 150., 265.);
+    assert_eq!(app.get_click_count(), 0);
+    assert!(toast.is_visible());
+
+    click(&app, 150., 200.);
+    assert_eq!(app.get_click_count(), 1);
+
+    // The action closes the toast
+    click(&app, 240., 265.);
+    assert_eq!(undo_count.get(), 1);
+    assert!(!toast.is_visible());
+    assert_eq!(app.get_click_count(), 1);
+
+    click(&app, 240., 265.);
+    assert_eq!(undo_count.get(), 1);
+    assert_eq!(app.get_click_count(), 2);
+}
+
+#[test]
+fn test_toast_duration() {
+    i_slint_backend_testing::init_no_event_loop();
+    let app = App::new().unwrap();
+    app.show().unwrap();
+
+    let short = Toast::show(app.window(), ToastOptions::new("Saved"));
+    let long = Toast::show(
+        app.window(),
+        ToastOptions::new("Uploaded").with_duration(Duration::from_secs(10)),
+    );
+    let forever =
+        Toast::show(app.window(), ToastOptions::new("Disconnected").with_duration(Duration::ZERO));
+
+    i_slint_backend_testing::mock_elapsed_time(Duration::from_secs(5));
+    assert!(!short.is_visible());
+    assert!(long.is_visible());
+    assert!(forever.is_visible());
+
+    i_slint_backend_testing::mock_elapsed_time(Duration::from_secs(5));
+    assert!(!long.is_visible());
+    assert!(forever.is_visible());
+
+    forever.close();
+    assert!(!forever.is_visible());
+}
+
+#[test]
+fn test_toast_stacking() {
+    i_slint_backend_testing::init_no_event_loop();
+    let app = App::new().unwrap();
+    app.show().unwrap();
+
+    let toasts = ["One", "Two", "Three"]
+        .map(|message| Toast::show(app.window(), ToastOptions::new(message)));
+    assert!(toasts.iter().all(Toast::is_visible));
+
+    // The toasts are 34 pixels high and 8 pixels apart, the oldest at the top
+    click(&app, 200., 300. - 16. - 34. * 3. - 8. * 2. + 5.);
+    click(&app, 200., 300. - 16. - 5.);
+    assert_eq!(app.get_click_count(), 0);
+    click(&app, 200., 300. - 16. - 34. * 3. - 8. * 2. - 5.);
+    assert_eq!(app.get_click_count(), 1);
+
+    // Showing a fourth toast closes the oldest
+    let fourth = Toast::show(app.window(), ToastOptions::new("Four"));
+    assert!(!toasts[0].is_visible());
+    assert!(toasts[1].is_visible() && toasts[2].is_visible() && fourth.is_visible());
+}
+
+#[test]
+fn test_toast_in_window_with_children_in_layout() {
+    i_slint_backend_testing::init_no_event_loop();
+    let app = AppWithBase::new().unwrap();
+    app.show().unwrap();
+
+    // The toast layer isn't in the layout of the base window
+    let toast = Toast::show(app.window(), ToastOptions::new("Saved"));
+    click(&app, 200., 265.);
+    assert_eq!(app.get_click_count(), 0);
+    click(&app, 200., 200.);
+    assert_eq!(app.get_click_count(), 1);
+
+    toast.close();
+    click(&app, 200., 265.);
+    assert_eq!(app.get_click_count(), 2);
+}
@@ -0,0 +1,58 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use i_slint_core::items::MenuEntry;
+use i_slint_core::window::{MenuVTable, WindowInner};
+use i_slint_core::SharedVector;
+use slint::ComponentHandle;
+
+slint::slint! {
+    export component App inherits Window {
+        in property <string> open-title: "Open";
+        in-out property <string> log;
+        Menu {
+            MenuItem { title: root.open-title; activated => { root.log += "open;"; } }
+            Menu {
+                title: "Recent";
+                MenuItem { title: "first"; activated => { root.log += "first;"; } }
+                MenuItem { title: "second"; }
+            }
+            MenuItem { title: "Quit"; activated => { root.log += "quit;"; } }
+        }
+        Text { text: "Hello"; }
+    }
+}
+
+fn sub_menu(menu: &vtable::VBox<MenuVTable>, parent: Option<&MenuEntry>) -> Vec<MenuEntry> {
+    let mut result = SharedVector::default();
+    menu.sub_menu(parent, &mut result);
+    result.iter().cloned().collect()
+}
+
+#[test]
+fn test_window_menu() {
+    i_slint_backend_testing::init_no_event_loop();
+    let app = App::new().unwrap();
+    let menu = WindowInner::from_pub(app.window()).menu().expect("the window has a menu");
+
+    let entries = sub_menu(&menu, None);
+    let titles = entries.iter().map(|e| e.title.as_str()).collect::<Vec<_>>();
+    assert_eq!(titles, ["Open", "Recent", "Quit"]);
+    assert!(!entries[0].has_sub_menu);
+    assert!(entries[1].has_sub_menu);
+
+    let recent = sub_menu(&menu, Some(&entries[1]));
+    let titles = recent.iter().map(|e| e.title.as_str()).collect::<Vec<_>>();
+    assert_eq!(titles, ["first", "second"]);
+    assert!(sub_menu(&menu, Some(&entries[0])).is_empty());
+
+    menu.activate(&entries[2]);
+    menu.activate(&recent[1]);
+    menu.activate(&recent[0]);
+    menu.activate(&entries[0]);
+    assert_eq!(app.get_log(), "quit;first;open;");
+
+    // The titles are bindings that are evaluated when the menu is shown
+    app.set_open_title("Open...".into());
+    assert_eq!(sub_menu(&menu, None)[0].title, "Open...");
+}
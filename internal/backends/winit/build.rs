@@ -11,6 +11,13 @@ fn main() {
        supports_opengl: { all(any(enable_skia_renderer, feature = "renderer-femtovg"), not(target_os = "ios")) },
        use_winit_theme: { any(target_family = "windows", target_os = "macos", target_os = "ios", target_arch = "wasm32") },
        muda: { all(feature = "muda", any(target_os = "windows", target_os = "macos")) },
+       native_tray_icon: { all(feature = "tray-icon", any(target_os = "windows", target_os = "macos")) },
+       sni_tray_icon: { all(feature = "tray-icon", any(target_os = "linux", target_os = "freebsd", target_os = "dragonfly", target_os = "netbsd", target_os = "openbsd")) },
+       enable_tray_icon: { any(native_tray_icon, sni_tray_icon) },
+       enable_file_dialogs: { all(feature = "file-dialogs", not(any(target_os = "android", target_os = "ios"))) },
+       enable_global_shortcuts: { all(feature = "global-shortcuts", any(target_os = "windows", target_os = "macos", target_os = "linux", target_os = "freebsd", target_os = "dragonfly", target_os = "netbsd", target_os = "openbsd")) },
+       enable_notifications: { all(feature = "notifications", any(target_os = "windows", target_os = "macos", target_os = "linux", target_os = "freebsd", target_os = "dragonfly", target_os = "netbsd", target_os = "openbsd")) },
+       enable_rich_clipboard: { all(feature = "rich-clipboard", not(any(target_os = "android", target_os = "ios", target_arch = "wasm32"))) },
     }
     // This uses `web_sys_unstable_api`, which is typically set via `RUST_FLAGS`
     println!("cargo:rustc-check-cfg=cfg(web_sys_unstable_apis)");
@@ -17,6 +17,57 @@ pub fn select_clipboard(
     }
 }
 
+/// Images and HTML in the default clipboard, with arboard, as copypasta only supports text
+#[cfg(enable_rich_clipboard)]
+pub mod rich {
+    use i_slint_core::graphics::{Rgba8Pixel, SharedPixelBuffer};
+    use std::cell::RefCell;
+
+    thread_local! {
+        // On X11 and Wayland, the content of the clipboard is served by the arboard::Clipboard,
+        // so it must be kept alive.
+        static CLIPBOARD: RefCell<Option<arboard::Clipboard>> = const { RefCell::new(None) };
+    }
+
+    fn with_clipboard<R>(f: impl FnOnce(&mut arboard::Clipboard) -> Option<R>) -> Option<R> {
+        CLIPBOARD.with(|clipboard| {
+            let mut clipboard = clipboard.borrow_mut();
+            if clipboard.is_none() {
+                *clipboard = arboard::Clipboard::new()
+                    .map_err(|e| i_slint_core::debug_log!("Error opening the clipboard: {e}"))
+                    .ok();
+            }
+            f(clipboard.as_mut()?)
+        })
+    }
+
+    pub fn set_image(image: &SharedPixelBuffer<Rgba8Pixel>) {
+        let image = arboard::ImageData {
+            width: image.width() as usize,
+            height: image.height() as usize,
+            bytes: image.as_bytes().into(),
+        };
+        with_clipboard(|clipboard| clipboard.set_image(image).ok());
+    }
+
+    pub fn image() -> Option<SharedPixelBuffer<Rgba8Pixel>> {
+        let image = with_clipboard(|clipboard| clipboard.get_image().ok())?;
+        Some(SharedPixelBuffer::clone_from_slice(
+            &image.bytes,
+            image.width as u32,
+            image.height as u32,
+        ))
+    }
+
+    pub fn set_html(html: &str, alt_text: &str) {
+        with_clipboard(|clipboard| clipboard.set_html(html, Some(alt_text)).ok());
+    }
+
+    pub fn html() -> Option<String> {
+        with_clipboard(|clipboard| clipboard.get().html().ok())
+    }
+}
+
 pub fn create_clipboard(
     _display_handle: &winit::raw_window_handle::DisplayHandle<'_>,
 ) -> ClipboardPair {
@@ -233,6 +233,90 @@ pub fn unregister_window(id: winit::window::WindowId) {
     });
 }
 
+type Monitors = (Vec<winit::monitor::MonitorHandle>, Option<winit::monitor::MonitorHandle>);
+
+thread_local! {
+    /// The monitors queried the last time the event loop was active
+    static CACHED_MONITORS: RefCell<Monitors> = Default::default();
+}
+
+/// Calls the function with the monitors known to winit, and the primary monitor.
+///
+/// winit can only enumerate the monitors while the event loop is active, so this
+/// uses the monitors from the last time the event loop was active otherwise.
+fn with_monitors<R: Default>(
+    f: impl FnOnce(Vec<winit::monitor::MonitorHandle>, Option<winit::monitor::MonitorHandle>) -> R,
+) -> R {
+    with_window_target(|event_loop| {
+        if let ActiveOrInactiveEventLoop::Active(event_loop) = event_loop.event_loop() {
+            CACHED_MONITORS
+                .set((event_loop.available_monitors().collect(), event_loop.primary_monitor()));
+        }
+        let (monitors, primary) = CACHED_MONITORS.with_borrow(Clone::clone);
+        Ok(f(monitors, primary))
+    })
+    .unwrap_or_default()
+}
+
+fn to_screen(
+    monitor: &winit::monitor::MonitorHandle,
+    primary: Option<&winit::monitor::MonitorHandle>,
+) -> corelib::api::Screen {
+    let position = monitor.position();
+    let size = monitor.size();
+    corelib::api::Screen::new(
+        monitor.name().unwrap_or_default(),
+        corelib::api::PhysicalPosition::new(position.x, position.y),
+        corelib::api::PhysicalSize::new(size.width, size.height),
+        monitor.scale_factor() as f32,
+        primary == Some(monitor),
+    )
+}
+
+/// Returns the monitors known to winit as [`corelib::api::Screen`]s.
+pub(crate) fn screens() -> Vec<corelib::api::Screen> {
+    with_monitors(|monitors, primary| {
+        monitors.iter().map(|monitor| to_screen(monitor, primary.as_ref())).collect()
+    })
+}
+
+/// Returns the video modes of the monitor of the screen.
+pub(crate) fn video_modes(screen: &corelib::api::Screen) -> Vec<corelib::api::VideoMode> {
+    with_monitors(|monitors, primary| {
+        let Some(monitor) = monitors.iter().find(|m| to_screen(m, primary.as_ref()) == *screen)
+        else {
+            return Vec::new();
+        };
+        monitor
+            .video_modes()
+            .map(|mode| {
+                let size = mode.size();
+                corelib::api::VideoMode::new(
+                    screen.clone(),
+                    corelib::api::PhysicalSize::new(size.width, size.height),
+                    mode.refresh_rate_millihertz(),
+                    mode.bit_depth(),
+                )
+            })
+            .collect()
+    })
+}
+
+/// Returns the winit video mode for the [`corelib::api::VideoMode`], if its monitor is still connected.
+pub(crate) fn find_video_mode(
+    mode: &corelib::api::VideoMode,
+) -> Option<winit::monitor::VideoModeHandle> {
+    with_monitors(|monitors, primary| {
+        let monitor = monitors.iter().find(|m| to_screen(m, primary.as_ref()) == *mode.screen())?;
+        monitor.video_modes().find(|m| {
+            let size = m.size();
+            corelib::api::PhysicalSize::new(size.width, size.height) == mode.size()
+                && m.refresh_rate_millihertz() == mode.refresh_rate_millihertz()
+                && m.bit_depth() == mode.bit_depth()
+        })
+    })
+}
+
 pub fn window_by_id(id: winit::window::WindowId) -> Option<Rc<WinitWindowAdapter>> {
     ALL_WINDOWS.with(|windows| windows.borrow().get(&id).and_then(|weakref| weakref.upgrade()))
 }
@@ -273,14 +357,89 @@ pub struct EventLoopState {
     // last seen cursor position
     cursor_pos: LogicalPoint,
     pressed: bool,
-    current_touch_id: Option<u64>,
+    /// The number of fingers on the touchscreen
+    touch_points: usize,
+    /// The number of pinch, rotation, and pan gestures of the touchpad in progress, that are
+    /// merged into one gesture
+    active_gestures: usize,
 
     loop_error: Option<PlatformError>,
     current_resize_direction: Option<ResizeDirection>,
+
+    /// The files dragged over a window by another application
+    hovered_files: Vec<std::path::PathBuf>,
+    /// The files dropped on a window, sent together once all the `DroppedFile` events are received
+    dropped_files: Option<(winit::window::WindowId, Vec<std::path::PathBuf>)>,
+
+    /// The screens last reported by winit. winit has no event for monitor changes, so they are
+    /// compared when a window moves or changes its scale factor.
+    screens: Option<Vec<corelib::api::Screen>>,
+}
+
+impl EventLoopState {
+    /// Dispatches a gesture of the touchpad. winit reports the pinch, rotation, and pan with
+    /// separate phases, while Slint sees a single gesture that starts with the first one and ends
+    /// with the last one.
+    fn dispatch_gesture(
+        &mut self,
+        window: &WinitWindowAdapter,
+        phase: winit::event::TouchPhase,
+        scale: f32,
+        rotation: f32,
+        delta_x: f32,
+        delta_y: f32,
+    ) {
+        use corelib::platform::GesturePhase;
+        let phase = match phase {
+            winit::event::TouchPhase::Started => {
+                self.active_gestures += 1;
+                if self.active_gestures == 1 {
+                    GesturePhase::Started
+                } else {
+                    GesturePhase::Updated
+                }
+            }
+            winit::event::TouchPhase::Moved => GesturePhase::Updated,
+            winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled
+                if self.active_gestures > 1 =>
+            {
+                self.active_gestures -= 1;
+                GesturePhase::Updated
+            }
+            winit::event::TouchPhase::Ended => {
+                self.active_gestures = 0;
+                GesturePhase::Ended
+            }
+            winit::event::TouchPhase::Cancelled => {
+                self.active_gestures = 0;
+                GesturePhase::Cancelled
+            }
+        };
+        self.loop_error = window
+            .window()
+            .try_dispatch_event(corelib::platform::WindowEvent::Gesture {
+                position: corelib::lengths::logical_position_to_api(self.cursor_pos),
+                scale,
+                rotation,
+                delta_x,
+                delta_y,
+                phase,
+            })
+            .err();
+    }
+
+    fn check_screens_changed(&mut self) {
+        let screens = screens();
+        if self.screens.as_ref().is_some_and(|old| *old != screens) {
+            corelib::platform::screens_changed();
+        }
+        self.screens = Some(screens);
+    }
 }
 
 impl winit::application::ApplicationHandler<SlintUserEvent> for EventLoopState {
     fn resumed(&mut self, _event_loop: &ActiveEventLoop) {
+        self.screens.get_or_insert_with(screens);
         ALL_WINDOWS.with(|ws| {
             for (_, window_weak) in ws.borrow().iter() {
                 if let Some(w) = window_weak.upgrade() {
@@ -348,6 +507,7 @@ fn window_event(
             WindowEvent::Focused(have_focus) => {
                 self.loop_error = window.activation_changed(have_focus).err();
             }
+            WindowEvent::Moved(_) => self.check_screens_changed(),
 
             WindowEvent::KeyboardInput { event, is_synthetic, .. } => {
                 let key_code = event.logical_key;
@@ -436,6 +596,28 @@ macro_rules! winit_key_to_char {
                 self.cursor_pos = euclid::point2(position.x, position.y);
                 runtime_window.process_mouse_input(MouseEvent::Moved { position: self.cursor_pos });
             }
+            WindowEvent::HoveredFile(path) => {
+                self.hovered_files.push(path);
+                self.loop_error = window
+                    .window()
+                    .try_dispatch_event(corelib::platform::WindowEvent::DragMoved {
+                        position: corelib::lengths::logical_position_to_api(self.cursor_pos),
+                        mime_type: "text/uri-list".into(),
+                        data: file_uri_list(&self.hovered_files),
+                    })
+                    .err();
+            }
+            WindowEvent::HoveredFileCancelled => {
+                self.hovered_files.clear();
+                self.loop_error = window
+                    .window()
+                    .try_dispatch_event(corelib::platform::WindowEvent::DragExited)
+                    .err();
+            }
+            WindowEvent::DroppedFile(path) => {
+                self.hovered_files.clear();
+                self.dropped_files.get_or_insert_with(|| (window_id, Vec::new())).1.push(path);
+            }
             WindowEvent::CursorLeft { .. } => {
                 // On the html canvas, we don't get the mouse move or release event when outside the canvas. So we have no choice but canceling the event
                 if cfg!(target_arch = "wasm32") || !self.pressed {
@@ -489,36 +671,68 @@ macro_rules! winit_key_to_char {
                 runtime_window.process_mouse_input(ev);
             }
             WindowEvent::Touch(touch) => {
-                if Some(touch.id) == self.current_touch_id || self.current_touch_id.is_none() {
-                    let location = touch.location.to_logical(runtime_window.scale_factor() as f64);
-                    let position = euclid::point2(location.x, location.y);
-                    let ev = match touch.phase {
-                        winit::event::TouchPhase::Started => {
-                            self.pressed = true;
-                            if self.current_touch_id.is_none() {
-                                self.current_touch_id = Some(touch.id);
-                            }
-                            MouseEvent::Pressed {
-                                position,
-                                button: PointerEventButton::Left,
-                                click_count: 0,
-                            }
-                        }
-                        winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled => {
-                            self.pressed = false;
-                            self.current_touch_id = None;
-                            MouseEvent::Released {
-                                position,
-                                button: PointerEventButton::Left,
-                                click_count: 0,
-                            }
-                        }
-                        winit::event::TouchPhase::Moved => MouseEvent::Moved { position },
-                    };
-                    runtime_window.process_mouse_input(ev);
-                }
+                let location = touch.location.to_logical(runtime_window.scale_factor() as f64);
+                let position = corelib::api::LogicalPosition::new(location.x, location.y);
+                let id = touch.id;
+                // winit reports the Apple Pencil as a touch with an altitude angle, and doesn't know its azimuth
+                let pen = match touch.force {
+                    Some(winit::event::Force::Calibrated {
+                        force,
+                        max_possible_force,
+                        altitude_angle: Some(_),
+                    }) => Some(corelib::platform::PenState {
+                        pressure: (force / max_possible_force) as f32,
+                        ..Default::default()
+                    }),
+                    _ => None,
+                };
+                let event = match (touch.phase, pen) {
+                    (winit::event::TouchPhase::Started, Some(state)) => {
+                        self.pressed = true;
+                        corelib::platform::WindowEvent::PenPressed { position, state }
+                    }
+                    (
+                        winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled,
+                        Some(state),
+                    ) => {
+                        self.pressed = self.touch_points > 0;
+                        corelib::platform::WindowEvent::PenReleased { position, state }
+                    }
+                    (winit::event::TouchPhase::Moved, Some(state)) => {
+                        corelib::platform::WindowEvent::PenMoved { position, state }
+                    }
+                    (winit::event::TouchPhase::Started, None) => {
+                        self.pressed = true;
+                        self.touch_points += 1;
+                        corelib::platform::WindowEvent::TouchPressed { id, position }
+                    }
+                    (
+                        winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled,
+                        None,
+                    ) => {
+                        self.touch_points = self.touch_points.saturating_sub(1);
+                        self.pressed = self.touch_points > 0;
+                        corelib::platform::WindowEvent::TouchReleased { id, position }
+                    }
+                    (winit::event::TouchPhase::Moved, None) => {
+                        corelib::platform::WindowEvent::TouchMoved { id, position }
+                    }
+                };
+                self.loop_error = window.window().try_dispatch_event(event).err();
+            }
+            WindowEvent::PinchGesture { delta, phase, .. } => {
+                self.dispatch_gesture(&window, phase, 1. + delta as f32, 0., 0., 0.);
+            }
+            WindowEvent::RotationGesture { delta, phase, .. } => {
+                // winit reports the rotation counterclockwise
+                self.dispatch_gesture(&window, phase, 1., -delta, 0., 0.);
+            }
+            WindowEvent::PanGesture { delta, phase, .. } => {
+                let delta = delta.to_logical::<f32>(runtime_window.scale_factor() as f64);
+                self.dispatch_gesture(&window, phase, 1., 0., delta.x, delta.y);
             }
             WindowEvent::ScaleFactorChanged { scale_factor, inner_size_writer: _ } => {
+                self.check_screens_changed();
                 if std::env::var("SLINT_SCALE_FACTOR").is_err() {
                     self.loop_error = window
                         .window()
@@ -572,6 +786,18 @@ fn user_event(&mut self, event_loop: &ActiveEventLoop, event: SlintUserEvent) {
             }
             #[cfg(muda)]
             CustomEvent::Muda(event) => {
+                #[cfg(native_tray_icon)]
+                if let Some((tray_id, index)) = event
+                    .id()
+                    .0
+                    .strip_prefix(crate::tray_icon::MENU_ID_PREFIX)
+                    .and_then(|id| id.split_once('|'))
+                {
+                    if let (Ok(tray_id), Ok(index)) = (tray_id.parse(), index.parse()) {
+                        crate::tray_icon::activate_menu_entry(tray_id, index);
+                    }
+                    return;
+                }
                 if let Some((window, eid)) = event.id().0.split_once('|').and_then(|(w, e)| {
                     Some((
                         window_by_id(winit::window::WindowId::from(w.parse::<u64>().ok()?))?,
@@ -593,6 +819,20 @@ fn new_events(&mut self, event_loop: &ActiveEventLoop, _cause: winit::event::Sta
     }
 
     fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        // winit sends one event per dropped file
+        if let Some((window_id, paths)) = self.dropped_files.take() {
+            if let Some(window) = window_by_id(window_id) {
+                self.loop_error = window
+                    .window()
+                    .try_dispatch_event(corelib::platform::WindowEvent::Dropped {
+                        position: corelib::lengths::logical_position_to_api(self.cursor_pos),
+                        mime_type: "text/uri-list".into(),
+                        data: file_uri_list(&paths),
+                    })
+                    .err();
+            }
+        }
+
         if !event_loop.exiting() {
             ALL_WINDOWS.with(|windows| {
                 for w in windows.borrow().iter().filter_map(|(_, w)| w.upgrade()) {
@@ -611,6 +851,33 @@ fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
     }
 }
 
+/// Returns the `text/uri-list` with the `file://` URI of each path
+fn file_uri_list(paths: &[std::path::PathBuf]) -> corelib::SharedString {
+    use std::fmt::Write;
+    let mut list = String::new();
+    for path in paths {
+        list.push_str("file://");
+        let path = path.to_string_lossy();
+        if !path.starts_with('/') {
+            // Windows paths start with the drive letter
+            list.push('/');
+        }
+        for c in path.chars() {
+            match c {
+                '\\' if cfg!(windows) => list.push('/'),
+                c if c.is_ascii_alphanumeric() || "/-_.~:".contains(c) => list.push(c),
+                c => {
+                    for b in c.encode_utf8(&mut [0; 4]).bytes() {
+                        write!(list, "%{b:02X}").unwrap();
+                    }
+                }
+            }
+        }
+        list.push_str("\r\n");
+    }
+    list.into()
+}
+
 /// Wrapper around a Handler that implements the winit::application::ApplicationHandler
 /// but make sure to call every function with CURRENT_WINDOW_TARGET set
 struct ActiveEventLoopSetterDuringEventProcessing<Handler>(Handler);
@@ -0,0 +1,71 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Native file dialogs, implemented with rfd. On Linux, they are shown by the XDG desktop portal,
+//! and in the browser, with an `<input type=file>` element.
+
+use i_slint_core::dialogs::{FileDialogFuture, FileDialogKind, FileDialogOptions, FileHandle};
+use i_slint_core::platform::PlatformError;
+
+pub fn show_file_dialog(
+    kind: FileDialogKind,
+    options: FileDialogOptions,
+) -> Result<FileDialogFuture, PlatformError> {
+    let mut dialog = rfd::AsyncFileDialog::new();
+    if let Some(title) = &options.title {
+        dialog = dialog.set_title(title.as_str());
+    }
+    for filter in &options.filters {
+        let extensions: Vec<&str> = filter.extensions.iter().map(|e| e.as_str()).collect();
+        dialog = dialog.add_filter(filter.name.as_str(), &extensions);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        if let Some(directory) = &options.directory {
+            dialog = dialog.set_directory(directory);
+        }
+        if let Some(file_name) = &options.file_name {
+            dialog = dialog.set_file_name(file_name.as_str());
+        }
+        let parent = options.parent().and_then(|window_adapter| {
+            window_adapter
+                .internal(i_slint_core::InternalToken)
+                .and_then(|wa| wa.as_any().downcast_ref::<crate::WinitWindowAdapter>())
+                .and_then(|adapter| adapter.winit_window())
+        });
+        if let Some(parent) = parent {
+            dialog = dialog.set_parent(&*parent);
+        }
+    }
+
+    match kind {
+        FileDialogKind::OpenFile => {
+            Ok(Box::pin(async move { dialog.pick_file().await.map(file_handle) }))
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        FileDialogKind::SaveFile => {
+            Ok(Box::pin(async move { dialog.save_file().await.map(file_handle) }))
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        FileDialogKind::PickFolder => {
+            Ok(Box::pin(async move { dialog.pick_folder().await.map(file_handle) }))
+        }
+        _ => Err(format!("{kind:?} dialogs are not supported in the browser").into()),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn file_handle(handle: rfd::FileHandle) -> FileHandle {
+    FileHandle::from_path(handle.path())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn file_handle(handle: rfd::FileHandle) -> FileHandle {
+    let name = handle.file_name();
+    let handle = std::rc::Rc::new(handle);
+    FileHandle::from_reader(name, move || {
+        let handle = handle.clone();
+        async move { handle.read().await }
+    })
+}
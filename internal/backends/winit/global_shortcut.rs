@@ -0,0 +1,144 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Global shortcuts, implemented with the global-hotkey crate on Windows, macOS, and X11.
+
+use global_hotkey::hotkey::{Code, HotKey, Modifiers};
+use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState};
+use i_slint_core::api::{GlobalShortcutError, KeyCombination};
+use i_slint_core::input::key_codes;
+use i_slint_core::platform::{GlobalShortcutAdapter, GlobalShortcutHandle};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+thread_local! {
+    static MANAGER: RefCell<Option<GlobalHotKeyManager>> = Default::default();
+    static SHORTCUTS: RefCell<HashMap<u32, GlobalShortcutHandle>> = Default::default();
+}
+
+/// Returns the name of the [`Code`] of the key, for the US keyboard layout
+fn code_name(key: char) -> Option<String> {
+    Some(match key {
+        'a'..='z' => format!("Key{}", key.to_ascii_uppercase()),
+        '0'..='9' => format!("Digit{key}"),
+        key_codes::F1..=key_codes::F24 => format!("F{}", key as u32 - key_codes::F1 as u32 + 1),
+        _ => match key {
+            ' ' => "Space",
+            '-' => "Minus",
+            '=' | '+' => "Equal",
+            ',' => "Comma",
+            '.' => "Period",
+            '/' => "Slash",
+            ';' => "Semicolon",
+            '\'' => "Quote",
+            '[' => "BracketLeft",
+            ']' => "BracketRight",
+            '\\' => "Backslash",
+            '`' => "Backquote",
+            key_codes::Backspace => "Backspace",
+            key_codes::Tab => "Tab",
+            key_codes::Return => "Enter",
+            key_codes::Escape => "Escape",
+            key_codes::Delete => "Delete",
+            key_codes::Insert => "Insert",
+            key_codes::Home => "Home",
+            key_codes::End => "End",
+            key_codes::PageUp => "PageUp",
+            key_codes::PageDown => "PageDown",
+            key_codes::UpArrow => "ArrowUp",
+            key_codes::DownArrow => "ArrowDown",
+            key_codes::LeftArrow => "ArrowLeft",
+            key_codes::RightArrow => "ArrowRight",
+            _ => return None,
+        }
+        .into(),
+    })
+}
+
+fn hot_key(key_combination: KeyCombination) -> Result<HotKey, GlobalShortcutError> {
+    let code: Code =
+        code_name(key_combination.key).and_then(|name| name.parse().ok()).ok_or_else(|| {
+            format!("The key {:?} can't be used in a global shortcut", key_combination.key)
+        })?;
+
+    // Slint maps the Command key to control, and the Control key to meta, on macOS
+    let (control, meta) = if cfg!(target_os = "macos") {
+        (Modifiers::SUPER, Modifiers::CONTROL)
+    } else {
+        (Modifiers::CONTROL, Modifiers::SUPER)
+    };
+    let mut modifiers = Modifiers::empty();
+    modifiers.set(Modifiers::ALT, key_combination.alt);
+    modifiers.set(control, key_combination.control);
+    modifiers.set(Modifiers::SHIFT, key_combination.shift);
+    modifiers.set(meta, key_combination.meta);
+    Ok(HotKey::new(Some(modifiers), code))
+}
+
+fn with_manager<R>(
+    f: impl FnOnce(&GlobalHotKeyManager) -> Result<R, GlobalShortcutError>,
+) -> Result<R, GlobalShortcutError> {
+    MANAGER.with(|manager| {
+        let mut manager = manager.borrow_mut();
+        if manager.is_none() {
+            *manager = Some(GlobalHotKeyManager::new().map_err(|e| e.to_string())?);
+            GlobalHotKeyEvent::set_event_handler(Some(|event: GlobalHotKeyEvent| {
+                if event.state() == HotKeyState::Pressed {
+                    let id = event.id();
+                    let _ = i_slint_core::api::invoke_from_event_loop(move || activate(id));
+                }
+            }));
+        }
+        f(manager.as_ref().unwrap())
+    })
+}
+
+fn activate(id: u32) {
+    let handle = SHORTCUTS.with(|shortcuts| shortcuts.borrow().get(&id).cloned());
+    if let Some(handle) = handle {
+        handle.activate();
+    }
+}
+
+pub fn register(
+    key_combination: KeyCombination,
+    handle: GlobalShortcutHandle,
+) -> Result<WinitGlobalShortcutAdapter, GlobalShortcutError> {
+    // Wayland compositors don't let applications grab keys
+    if crate::event_loop::with_window_target(|event_loop| Ok(event_loop.is_wayland()))
+        .unwrap_or_default()
+    {
+        return Err(GlobalShortcutError::Unsupported);
+    }
+
+    let hot_key = hot_key(key_combination)?;
+    if SHORTCUTS.with(|shortcuts| shortcuts.borrow().contains_key(&hot_key.id())) {
+        return Err(GlobalShortcutError::AlreadyRegistered(key_combination));
+    }
+    with_manager(|manager| {
+        manager.register(hot_key).map_err(|e| match e {
+            global_hotkey::Error::AlreadyRegistered(_) => {
+                GlobalShortcutError::AlreadyRegistered(key_combination)
+            }
+            e => e.to_string().into(),
+        })
+    })?;
+    SHORTCUTS.with(|shortcuts| shortcuts.borrow_mut().insert(hot_key.id(), handle));
+    Ok(WinitGlobalShortcutAdapter(hot_key))
+}
+
+/// Unregisters the hot key when dropped
+pub struct WinitGlobalShortcutAdapter(HotKey);
+
+impl GlobalShortcutAdapter for WinitGlobalShortcutAdapter {}
+
+impl Drop for WinitGlobalShortcutAdapter {
+    fn drop(&mut self) {
+        let _ = SHORTCUTS.try_with(|shortcuts| shortcuts.borrow_mut().remove(&self.0.id()));
+        let _ = MANAGER.try_with(|manager| {
+            if let Some(manager) = manager.borrow().as_ref() {
+                let _ = manager.unregister(self.0);
+            }
+        });
+    }
+}
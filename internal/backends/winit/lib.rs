@@ -19,6 +19,9 @@
 #[cfg(not(target_arch = "wasm32"))]
 mod clipboard;
 mod drag_resize_window;
+mod screensaver;
+#[cfg(target_os = "windows")]
+mod snap_layouts;
 mod winitwindowadapter;
 
 use i_slint_core::platform::PlatformError;
@@ -64,6 +67,11 @@ fn resume(
         ) -> Result<Rc<winit::window::Window>, PlatformError>;
 
         fn is_suspended(&self) -> bool;
+
+        // Creates a renderer of the same kind for rendering into images, without a window.
+        fn create_offscreen_renderer(
+            &self,
+        ) -> Result<Box<dyn i_slint_core::renderer::Renderer>, PlatformError>;
     }
 
     #[cfg(feature = "renderer-femtovg")]
@@ -77,8 +85,16 @@ fn resume(
 
 #[cfg(enable_accesskit)]
 mod accesskit;
+#[cfg(enable_file_dialogs)]
+mod file_dialog;
+#[cfg(enable_global_shortcuts)]
+mod global_shortcut;
 #[cfg(muda)]
 mod muda;
+#[cfg(enable_notifications)]
+mod notification;
+#[cfg(enable_tray_icon)]
+mod tray_icon;
 
 #[cfg(target_arch = "wasm32")]
 pub(crate) mod wasm_input_helper;
@@ -235,29 +251,8 @@ pub fn with_event_loop_builder(
     /// slint::platform::set_platform(Box::new(backend));
     /// ```
     pub fn build(self) -> Result<Backend, PlatformError> {
-        #[allow(unused_mut)]
-        let mut event_loop_builder =
-            self.event_loop_builder.unwrap_or_else(winit::event_loop::EventLoop::with_user_event);
-
-        #[cfg(all(feature = "muda", target_os = "macos"))]
-        winit::platform::macos::EventLoopBuilderExtMacOS::with_default_menu(
-            &mut event_loop_builder,
-            false,
-        );
-
-        // Initialize the winit event loop and propagate errors if for example `DISPLAY` or `WAYLAND_DISPLAY` isn't set.
-
-        let nre = crate::event_loop::NotRunningEventLoop::new(Some(event_loop_builder))?;
-
-        let proxy = nre.instance.create_proxy();
-
-        #[cfg(not(target_arch = "wasm32"))]
-        let clipboard = Rc::downgrade(&nre.clipboard);
-
-        crate::event_loop::MAYBE_LOOP_INSTANCE.with(|loop_instance| {
-            *loop_instance.borrow_mut() = Some(nre);
-        });
-
+        // Resolve the renderer before creating the event loop, as winit doesn't allow creating
+        // a second event loop when the backend selector falls back to another renderer.
         let renderer_factory_fn = match (
             self.renderer_name.as_deref(),
             self.requested_graphics_api.as_ref(),
@@ -317,6 +312,29 @@ pub fn build(self) -> Result<Backend, PlatformError> {
             }
         };
 
+        #[allow(unused_mut)]
+        let mut event_loop_builder =
+            self.event_loop_builder.unwrap_or_else(winit::event_loop::EventLoop::with_user_event);
+
+        #[cfg(all(feature = "muda", target_os = "macos"))]
+        winit::platform::macos::EventLoopBuilderExtMacOS::with_default_menu(
+            &mut event_loop_builder,
+            false,
+        );
+
+        // Initialize the winit event loop and propagate errors if for example `DISPLAY` or `WAYLAND_DISPLAY` isn't set.
+
+        let nre = crate::event_loop::NotRunningEventLoop::new(Some(event_loop_builder))?;
+
+        let proxy = nre.instance.create_proxy();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let clipboard = Rc::downgrade(&nre.clipboard);
+
+        crate::event_loop::MAYBE_LOOP_INSTANCE.with(|loop_instance| {
+            *loop_instance.borrow_mut() = Some(nre);
+        });
+
         Ok(Backend {
             requested_graphics_api: self.requested_graphics_api,
             renderer_factory_fn,
@@ -456,6 +474,12 @@ fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError>
         Ok(adapter)
     }
 
+    fn create_offscreen_renderer(
+        &self,
+    ) -> Result<Box<dyn i_slint_core::renderer::Renderer>, PlatformError> {
+        (self.renderer_factory_fn)().create_offscreen_renderer()
+    }
+
     fn run_event_loop(&self) -> Result<(), PlatformError> {
         let loop_state = self.event_loop_state.borrow_mut().take().unwrap_or_default();
         let new_state = loop_state.run()?;
@@ -504,6 +528,44 @@ fn invoke_from_event_loop(
         Some(Box::new(Proxy))
     }
 
+    #[cfg(enable_file_dialogs)]
+    fn show_file_dialog(
+        &self,
+        kind: i_slint_core::dialogs::FileDialogKind,
+        options: i_slint_core::dialogs::FileDialogOptions,
+    ) -> Result<i_slint_core::dialogs::FileDialogFuture, PlatformError> {
+        crate::file_dialog::show_file_dialog(kind, options)
+    }
+
+    #[cfg(enable_tray_icon)]
+    fn create_tray_icon_adapter(
+        &self,
+        handle: i_slint_core::platform::TrayIconHandle,
+    ) -> Result<Box<dyn i_slint_core::platform::TrayIconAdapter>, PlatformError> {
+        Ok(Box::new(crate::tray_icon::WinitTrayIconAdapter::new(handle)))
+    }
+
+    #[cfg(enable_global_shortcuts)]
+    fn register_global_shortcut(
+        &self,
+        key_combination: i_slint_core::api::KeyCombination,
+        handle: i_slint_core::platform::GlobalShortcutHandle,
+    ) -> Result<
+        Box<dyn i_slint_core::platform::GlobalShortcutAdapter>,
+        i_slint_core::api::GlobalShortcutError,
+    > {
+        Ok(Box::new(crate::global_shortcut::register(key_combination, handle)?))
+    }
+
+    #[cfg(enable_notifications)]
+    fn show_notification(
+        &self,
+        notification: &i_slint_core::api::Notification,
+        handle: i_slint_core::platform::NotificationHandle,
+    ) -> Result<Box<dyn i_slint_core::platform::NotificationAdapter>, PlatformError> {
+        Ok(Box::new(crate::notification::show(notification, handle)?))
+    }
+
     #[cfg(target_arch = "wasm32")]
     fn set_clipboard_text(&self, text: &str, clipboard: i_slint_core::platform::Clipboard) {
         crate::wasm_input_helper::set_clipboard_text(text.into(), clipboard);
@@ -529,6 +591,54 @@ fn clipboard_text(&self, clipboard: i_slint_core::platform::Clipboard) -> Option
         let mut pair = clipboard_pair.borrow_mut();
         clipboard::select_clipboard(&mut pair, clipboard).and_then(|c| c.get_contents().ok())
     }
+
+    #[cfg(enable_rich_clipboard)]
+    fn set_clipboard_image(
+        &self,
+        image: &i_slint_core::graphics::SharedPixelBuffer<i_slint_core::graphics::Rgba8Pixel>,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) {
+        if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
+            clipboard::rich::set_image(image);
+        }
+    }
+
+    #[cfg(enable_rich_clipboard)]
+    fn clipboard_image(
+        &self,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) -> Option<i_slint_core::graphics::SharedPixelBuffer<i_slint_core::graphics::Rgba8Pixel>> {
+        (clipboard == i_slint_core::platform::Clipboard::DefaultClipboard)
+            .then(clipboard::rich::image)
+            .flatten()
+    }
+
+    #[cfg(enable_rich_clipboard)]
+    fn set_clipboard_html(
+        &self,
+        html: &str,
+        alt_text: &str,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) {
+        if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
+            clipboard::rich::set_html(html, alt_text);
+        }
+    }
+
+    #[cfg(enable_rich_clipboard)]
+    fn clipboard_html(&self, clipboard: i_slint_core::platform::Clipboard) -> Option<String> {
+        (clipboard == i_slint_core::platform::Clipboard::DefaultClipboard)
+            .then(clipboard::rich::html)
+            .flatten()
+    }
+
+    fn screens(&self) -> Vec<i_slint_core::api::Screen> {
+        event_loop::screens()
+    }
+
+    fn video_modes(&self, screen: &i_slint_core::api::Screen) -> Vec<i_slint_core::api::VideoMode> {
+        event_loop::video_modes(screen)
+    }
 }
 
 /// Spawn the event loop, using [`winit::platform::web::EventLoopExtWebSys::spawn()`]
@@ -0,0 +1,129 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Desktop notifications, shown with the notification portal on Linux, and with notify-rust on Windows and macOS.
+
+use i_slint_core::api::{Notification, PlatformError};
+use i_slint_core::platform::{NotificationAdapter, NotificationHandle};
+
+cfg_if::cfg_if! {
+    if #[cfg(any(target_os = "windows", target_os = "macos"))] {
+        /// notify-rust can't close the notifications on these platforms
+        pub struct WinitNotificationAdapter;
+
+        pub fn show(
+            notification: &Notification,
+            _handle: NotificationHandle,
+        ) -> Result<WinitNotificationAdapter, PlatformError> {
+            // Return an error, so that the notification is shown as a toast with its action
+            if !notification.action_labels().is_empty() {
+                return Err("Notification actions are not supported by this platform".into());
+            }
+            let mut native = notify_rust::Notification::new();
+            native.summary(&notification.title()).body(&notification.body());
+            #[cfg(target_os = "windows")]
+            if let Some(path) = notification.icon().as_ref().and_then(|icon| icon.path()) {
+                native.image_path(&path.to_string_lossy());
+            }
+            // Showing the notification blocks on macOS until it's delivered
+            std::thread::spawn(move || {
+                let _ = native.show();
+            });
+            Ok(WinitNotificationAdapter)
+        }
+    } else {
+        use ashpd::desktop::notification::{Button, NotificationProxy};
+        use i_slint_core::future::JoinHandle;
+        use i_slint_core::platform::NotificationEvent;
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        /// The name of the action that the portal reports when the notification is clicked
+        const DEFAULT_ACTION: &str = "default";
+
+        /// Listens to the actions of the notification, and removes it when dropped
+        pub struct WinitNotificationAdapter {
+            id: String,
+            listener: Option<JoinHandle<()>>,
+        }
+
+        pub fn show(
+            notification: &Notification,
+            handle: NotificationHandle,
+        ) -> Result<WinitNotificationAdapter, PlatformError> {
+            static NEXT_ID: AtomicU32 = AtomicU32::new(1);
+            let id = format!("slint-notification-{}", NEXT_ID.fetch_add(1, Ordering::Relaxed));
+
+            // The portal only accepts the icon as data
+            let icon = notification
+                .icon()
+                .as_ref()
+                .and_then(|icon| icon.path())
+                .and_then(|path| std::fs::read(path).ok());
+            let mut portal_notification =
+                ashpd::desktop::notification::Notification::new(&notification.title())
+                    .body(notification.body().as_str())
+                    .default_action(DEFAULT_ACTION);
+            if let Some(icon) = icon {
+                portal_notification = portal_notification.icon(ashpd::desktop::Icon::Bytes(icon));
+            }
+            // The actions are named after their index
+            for (index, label) in notification.action_labels().iter().enumerate() {
+                portal_notification =
+                    portal_notification.button(Button::new(label, &index.to_string()));
+            }
+
+            let listener_id = id.clone();
+            let listener = spawn_local(async move {
+                use futures::stream::StreamExt;
+
+                let Ok(proxy) = NotificationProxy::new().await else { return };
+                let Ok(mut actions) = proxy.receive_action_invoked().await else { return };
+                if let Err(err) = proxy.add_notification(&listener_id, portal_notification).await {
+                    i_slint_core::debug_log!("Error showing the notification: {err}");
+                    return;
+                }
+                while let Some(action) = actions.next().await {
+                    if action.id() != listener_id {
+                        continue;
+                    }
+                    let event = match action.name() {
+                        DEFAULT_ACTION => NotificationEvent::Activated,
+                        name => match name.parse() {
+                            Ok(index) => NotificationEvent::ActionInvoked(index),
+                            Err(_) => continue,
+                        },
+                    };
+                    handle.dispatch_event(event);
+                }
+            })?;
+
+            Ok(WinitNotificationAdapter { id, listener: Some(listener) })
+        }
+
+        fn spawn_local(
+            future: impl std::future::Future<Output = ()> + 'static,
+        ) -> Result<JoinHandle<()>, PlatformError> {
+            i_slint_core::with_global_context(
+                || Err(PlatformError::NoPlatform),
+                |ctx| ctx.spawn_local(future),
+            )?
+            .map_err(|err| err.to_string().into())
+        }
+
+        impl Drop for WinitNotificationAdapter {
+            fn drop(&mut self) {
+                if let Some(listener) = self.listener.take() {
+                    listener.abort();
+                }
+                let id = std::mem::take(&mut self.id);
+                let _ = spawn_local(async move {
+                    if let Ok(proxy) = NotificationProxy::new().await {
+                        let _ = proxy.remove_notification(&id).await;
+                    }
+                });
+            }
+        }
+    }
+}
+
+impl NotificationAdapter for WinitNotificationAdapter {}
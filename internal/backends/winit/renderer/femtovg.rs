@@ -87,4 +87,16 @@ fn suspend(&self) -> Result<(), PlatformError> {
     fn is_suspended(&self) -> bool {
         self.suspended.get()
     }
+
+    fn create_offscreen_renderer(&self) -> Result<Box<dyn Renderer>, PlatformError> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let opengl_context = crate::event_loop::with_window_target(|event_loop| {
+                Ok(glcontext::OffscreenOpenGLContext::new_context(event_loop.event_loop())?)
+            })?;
+            Ok(Box::new(FemtoVGRenderer::new(opengl_context)?))
+        }
+        #[cfg(target_arch = "wasm32")]
+        Err("FemtoVG Renderer: Offscreen rendering is not supported in the browser".into())
+    }
 }
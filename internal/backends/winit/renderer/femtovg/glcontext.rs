@@ -1,14 +1,14 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-use std::{num::NonZeroU32, rc::Rc};
+use std::{cell::RefCell, num::NonZeroU32, rc::Rc};
 
 use glutin::{
     config::GlConfig,
     context::{ContextApi, ContextAttributesBuilder},
     display::GetGlDisplay,
     prelude::*,
-    surface::{SurfaceAttributesBuilder, WindowSurface},
+    surface::{PbufferSurface, SurfaceAttributesBuilder, WindowSurface},
 };
 use i_slint_core::{graphics::RequestedOpenGLVersion, platform::PlatformError};
 use raw_window_handle::{HasDisplayHandle, HasWindowHandle};
@@ -252,3 +252,132 @@ pub(crate) fn new_context(
         Ok((window.clone(), Self { context, surface, winit_window: window }))
     }
 }
+
+/// An OpenGL context that renders into a pbuffer instead of a window, for offscreen rendering.
+pub struct OffscreenOpenGLContext {
+    context: glutin::context::PossiblyCurrentContext,
+    config: glutin::config::Config,
+    // pbuffers can't be resized, so a new one is created when the size changes
+    surface: RefCell<glutin::surface::Surface<PbufferSurface>>,
+}
+
+unsafe impl i_slint_renderer_femtovg::OpenGLInterface for OffscreenOpenGLContext {
+    fn ensure_current(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if !self.context.is_current() {
+            self.context.make_current(&*self.surface.borrow()).map_err(
+                |glutin_error| -> PlatformError {
+                    format!("FemtoVG: Error making context current: {glutin_error}").into()
+                },
+            )?;
+        }
+        Ok(())
+    }
+
+    fn swap_buffers(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        // Nothing to present, the rendering is read back from the pbuffer
+        Ok(())
+    }
+
+    fn resize(
+        &self,
+        width: NonZeroU32,
+        height: NonZeroU32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let surface = Self::create_surface(&self.config, width, height)?;
+        self.context.make_current(&surface).map_err(|glutin_error| -> PlatformError {
+            format!("FemtoVG: Error making context current: {glutin_error}").into()
+        })?;
+        *self.surface.borrow_mut() = surface;
+        Ok(())
+    }
+
+    fn get_proc_address(&self, name: &std::ffi::CStr) -> *const std::ffi::c_void {
+        self.context.display().get_proc_address(name)
+    }
+}
+
+impl OffscreenOpenGLContext {
+    pub(crate) fn new_context(
+        event_loop: crate::event_loop::ActiveOrInactiveEventLoop<'_>,
+    ) -> Result<Self, PlatformError> {
+        let config_template_builder = glutin::config::ConfigTemplateBuilder::new()
+            .with_surface_type(glutin::config::ConfigSurfaceTypes::PBUFFER);
+
+        let display_builder = glutin_winit::DisplayBuilder::new()
+            .with_preference(glutin_winit::ApiPreference::FallbackEgl);
+        let config_picker = |it: Box<dyn Iterator<Item = glutin::config::Config> + '_>| {
+            it.reduce(
+                |accum, config| {
+                    if config.num_samples() < accum.num_samples() {
+                        config
+                    } else {
+                        accum
+                    }
+                },
+            )
+            .expect("internal error: Could not find any matching GL configuration")
+        };
+        let (_, config) = match event_loop {
+            crate::event_loop::ActiveOrInactiveEventLoop::Active(l) => {
+                display_builder.build(l, config_template_builder, config_picker)
+            }
+            crate::event_loop::ActiveOrInactiveEventLoop::Inactive(l) => {
+                display_builder.build(l, config_template_builder, config_picker)
+            }
+        }
+        .map_err(|glutin_err| {
+            format!(
+                "Error creating OpenGL display for offscreen rendering with glutin: {glutin_err}"
+            )
+        })?;
+
+        let gl_display = config.display();
+
+        let not_current_gl_context = unsafe {
+            gl_display
+                .create_context(&config, &ContextAttributesBuilder::new().build(None))
+                .map_err(|glutin_err| format!("Cannot create OpenGL context: {}", glutin_err))?
+        };
+
+        // The window adapter resizes the surface to the size of the window before rendering
+        let surface = Self::create_surface(
+            &config,
+            NonZeroU32::new(1).unwrap(),
+            NonZeroU32::new(1).unwrap(),
+        )?;
+
+        let context = not_current_gl_context.make_current(&surface)
+            .map_err(|glutin_error: glutin::error::Error| -> PlatformError {
+                format!("FemtoVG Renderer: Failed to make newly created OpenGL context current: {glutin_error}")
+            .into()
+        })?;
+
+        // Sanity check, as all this might succeed on Windows without working GL drivers, but this will fail:
+        if context
+            .display()
+            .get_proc_address(&std::ffi::CString::new("glCreateShader").unwrap())
+            .is_null()
+        {
+            return Err(
+                "Failed to initialize OpenGL driver: Could not locate glCreateShader symbol"
+                    .to_string()
+                    .into(),
+            );
+        }
+
+        Ok(Self { context, config, surface: RefCell::new(surface) })
+    }
+
+    fn create_surface(
+        config: &glutin::config::Config,
+        width: NonZeroU32,
+        height: NonZeroU32,
+    ) -> Result<glutin::surface::Surface<PbufferSurface>, PlatformError> {
+        let attrs = SurfaceAttributesBuilder::<PbufferSurface>::new().build(width, height);
+        unsafe {
+            config.display().create_pbuffer_surface(config, &attrs).map_err(|glutin_err| {
+                format!("Error creating OpenGL pbuffer surface: {}", glutin_err).into()
+            })
+        }
+    }
+}
@@ -158,4 +158,11 @@ fn resume(
     fn is_suspended(&self) -> bool {
         self.suspended.get()
     }
+
+    fn create_offscreen_renderer(
+        &self,
+    ) -> Result<Box<dyn i_slint_core::renderer::Renderer>, PlatformError> {
+        // Without a surface, Skia renders snapshots into a raster canvas
+        Ok(Box::new(i_slint_renderer_skia::SkiaRenderer::default()))
+    }
 }
@@ -205,4 +205,10 @@ fn suspend(&self) -> Result<(), PlatformError> {
     fn is_suspended(&self) -> bool {
         self._context.borrow().is_none()
     }
+
+    fn create_offscreen_renderer(
+        &self,
+    ) -> Result<Box<dyn i_slint_core::renderer::Renderer>, PlatformError> {
+        Ok(Box::new(SoftwareRenderer::new()))
+    }
 }
@@ -0,0 +1,155 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Prevents the screensaver and the sleep of the display while a window with
+//! `Window::set_inhibit_screensaver(true)` is shown.
+
+use i_slint_core::SlintContext;
+
+#[cfg(any(target_os = "macos", not(use_winit_theme)))]
+const REASON: &str = "The application is presenting content";
+
+/// Inhibits the screensaver until dropped
+pub struct ScreensaverInhibitor {
+    #[cfg(target_os = "windows")]
+    _private: (),
+    #[cfg(target_os = "macos")]
+    assertion_id: u32,
+    #[cfg(not(use_winit_theme))]
+    _release: futures::channel::oneshot::Sender<()>,
+}
+
+#[cfg(target_os = "windows")]
+mod windows {
+    pub const ES_CONTINUOUS: u32 = 0x80000000;
+    pub const ES_DISPLAY_REQUIRED: u32 = 0x00000002;
+    pub const ES_SYSTEM_REQUIRED: u32 = 0x00000001;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        pub fn SetThreadExecutionState(flags: u32) -> u32;
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod macos {
+    use std::ffi::c_void;
+
+    pub type CFStringRef = *const c_void;
+    pub const K_CF_STRING_ENCODING_UTF8: u32 = 0x08000100;
+    pub const K_IOPM_ASSERTION_LEVEL_ON: u32 = 255;
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    extern "C" {
+        pub fn CFStringCreateWithBytes(
+            alloc: *const c_void,
+            bytes: *const u8,
+            num_bytes: isize,
+            encoding: u32,
+            is_external_representation: u8,
+        ) -> CFStringRef;
+        pub fn CFRelease(cf: *const c_void);
+    }
+
+    #[link(name = "IOKit", kind = "framework")]
+    extern "C" {
+        pub fn IOPMAssertionCreateWithName(
+            assertion_type: CFStringRef,
+            level: u32,
+            name: CFStringRef,
+            assertion_id: *mut u32,
+        ) -> i32;
+        pub fn IOPMAssertionRelease(assertion_id: u32) -> i32;
+    }
+
+    pub fn cf_string(s: &str) -> CFStringRef {
+        // Safety: the bytes are valid UTF-8 for the given length
+        unsafe {
+            CFStringCreateWithBytes(
+                std::ptr::null(),
+                s.as_ptr(),
+                s.len() as isize,
+                K_CF_STRING_ENCODING_UTF8,
+                0,
+            )
+        }
+    }
+}
+
+impl ScreensaverInhibitor {
+    /// Returns None if the platform doesn't support inhibiting the screensaver
+    #[allow(unused_variables)]
+    pub fn new(context: &SlintContext) -> Option<Self> {
+        cfg_if::cfg_if! {
+            if #[cfg(target_os = "windows")] {
+                // Safety: SetThreadExecutionState has no preconditions. The state lasts until it's
+                // reset from the same thread, which is the thread of the event loop.
+                let previous = unsafe {
+                    windows::SetThreadExecutionState(
+                        windows::ES_CONTINUOUS
+                            | windows::ES_DISPLAY_REQUIRED
+                            | windows::ES_SYSTEM_REQUIRED,
+                    )
+                };
+                (previous != 0).then_some(Self { _private: () })
+            } else if #[cfg(target_os = "macos")] {
+                let assertion_type = macos::cf_string("PreventUserIdleDisplaySleep");
+                let name = macos::cf_string(REASON);
+                let mut assertion_id = 0;
+                // Safety: the strings are valid CFStrings, released after the call
+                let result = unsafe {
+                    let result = macos::IOPMAssertionCreateWithName(
+                        assertion_type,
+                        macos::K_IOPM_ASSERTION_LEVEL_ON,
+                        name,
+                        &mut assertion_id,
+                    );
+                    macos::CFRelease(assertion_type);
+                    macos::CFRelease(name);
+                    result
+                };
+                (result == 0).then_some(Self { assertion_id })
+            } else if #[cfg(not(use_winit_theme))] {
+                // The inhibit portal forwards to org.freedesktop.ScreenSaver, and also works in a sandbox.
+                // The inhibition lasts until the request is closed, when the sender is dropped.
+                let (release, released) = futures::channel::oneshot::channel::<()>();
+                context
+                    .spawn_local(async move {
+                        use ashpd::desktop::inhibit::{InhibitFlags, InhibitProxy};
+                        let Ok(proxy) = InhibitProxy::new().await else { return };
+                        let Ok(request) = proxy
+                            .inhibit(
+                                &ashpd::WindowIdentifier::default(),
+                                InhibitFlags::Idle.into(),
+                                REASON,
+                            )
+                            .await
+                        else {
+                            return;
+                        };
+                        let _ = released.await;
+                        let _ = request.close().await;
+                    })
+                    .ok()?;
+                Some(Self { _release: release })
+            } else {
+                None
+            }
+        }
+    }
+}
+
+impl Drop for ScreensaverInhibitor {
+    fn drop(&mut self) {
+        #[cfg(target_os = "windows")]
+        // Safety: see new()
+        unsafe {
+            windows::SetThreadExecutionState(windows::ES_CONTINUOUS);
+        }
+        #[cfg(target_os = "macos")]
+        // Safety: the assertion was created in new()
+        unsafe {
+            macos::IOPMAssertionRelease(self.assertion_id);
+        }
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Reports the `WindowControlArea` with the `maximize-button` control to Windows as the maximize
+//! button of the window, so that Windows 11 shows the snap layouts when it's hovered.
+//!
+//! While Windows handles the pointer over that area, the window doesn't receive the mouse events,
+//! so the `has-hover` and `pressed` properties of the area are not updated.
+
+use crate::winitwindowadapter::WinitWindowAdapter;
+use i_slint_core::items::WindowControl;
+use i_slint_core::lengths::LogicalPoint;
+use i_slint_core::window::WindowInner;
+use std::rc::Weak;
+use winit::raw_window_handle::{HasWindowHandle, RawWindowHandle};
+
+type Hwnd = isize;
+
+const WM_NCDESTROY: u32 = 0x0082;
+const WM_NCHITTEST: u32 = 0x0084;
+const WM_NCLBUTTONDOWN: u32 = 0x00A1;
+const WM_NCLBUTTONUP: u32 = 0x00A2;
+const HTMAXBUTTON: isize = 9;
+
+/// The id of the subclass, unique to this module
+const SUBCLASS_ID: usize = 0x534c4e54;
+
+#[repr(C)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+type SubclassProc = unsafe extern "system" fn(Hwnd, u32, usize, isize, usize, usize) -> isize;
+
+#[link(name = "comctl32")]
+extern "system" {
+    fn SetWindowSubclass(hwnd: Hwnd, proc: SubclassProc, id: usize, data: usize) -> i32;
+    fn RemoveWindowSubclass(hwnd: Hwnd, proc: SubclassProc, id: usize) -> i32;
+    fn DefSubclassProc(hwnd: Hwnd, msg: u32, wparam: usize, lparam: isize) -> isize;
+}
+
+#[link(name = "user32")]
+extern "system" {
+    fn ScreenToClient(hwnd: Hwnd, point: *mut Point) -> i32;
+}
+
+/// Installs the window procedure that handles the maximize button on the window
+pub fn install(winit_window: &winit::window::Window, adapter: Weak<WinitWindowAdapter>) {
+    let Ok(handle) = winit_window.window_handle() else { return };
+    let RawWindowHandle::Win32(handle) = handle.as_raw() else { return };
+    let data = Box::into_raw(Box::new(adapter));
+    // Safety: the window is valid, and the data is freed when the window is destroyed
+    if unsafe { SetWindowSubclass(handle.hwnd.get(), subclass_proc, SUBCLASS_ID, data as usize) }
+        == 0
+    {
+        // Safety: the data was not passed to the window
+        drop(unsafe { Box::from_raw(data) });
+    }
+}
+
+unsafe extern "system" fn subclass_proc(
+    hwnd: Hwnd,
+    msg: u32,
+    wparam: usize,
+    lparam: isize,
+    _id: usize,
+    data: usize,
+) -> isize {
+    let adapter = &*(data as *const Weak<WinitWindowAdapter>);
+    match msg {
+        WM_NCHITTEST => {
+            let result = DefSubclassProc(hwnd, msg, wparam, lparam);
+            if is_maximize_button(hwnd, lparam, adapter) {
+                HTMAXBUTTON
+            } else {
+                result
+            }
+        }
+        // Windows would run its own maximize button, that doesn't exist in a window without frame
+        WM_NCLBUTTONDOWN if wparam as isize == HTMAXBUTTON => 0,
+        WM_NCLBUTTONUP if wparam as isize == HTMAXBUTTON => {
+            if let Some(adapter) = adapter.upgrade() {
+                let window = i_slint_core::window::WindowAdapter::window(&*adapter);
+                window.set_maximized(!window.is_maximized());
+            }
+            0
+        }
+        WM_NCDESTROY => {
+            RemoveWindowSubclass(hwnd, subclass_proc, SUBCLASS_ID);
+            drop(Box::from_raw(data as *mut Weak<WinitWindowAdapter>));
+            DefSubclassProc(hwnd, msg, wparam, lparam)
+        }
+        _ => DefSubclassProc(hwnd, msg, wparam, lparam),
+    }
+}
+
+/// Returns true if the point of the message, in screen coordinates, is in a `maximize-button` area
+fn is_maximize_button(hwnd: Hwnd, lparam: isize, adapter: &Weak<WinitWindowAdapter>) -> bool {
+    let Some(adapter) = adapter.upgrade() else { return false };
+    let mut point = Point { x: (lparam & 0xffff) as i16 as i32, y: (lparam >> 16) as i16 as i32 };
+    // Safety: the window is valid while it receives messages
+    if unsafe { ScreenToClient(hwnd, &mut point) } == 0 {
+        return false;
+    }
+    let window = i_slint_core::window::WindowAdapter::window(&*adapter);
+    let scale_factor = window.scale_factor();
+    let position = LogicalPoint::new(point.x as f32 / scale_factor, point.y as f32 / scale_factor);
+    WindowInner::from_pub(window).window_control_at(position) == Some(WindowControl::MaximizeButton)
+}
@@ -0,0 +1,436 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! System tray icons: implemented with the tray-icon crate on Windows and macOS, and as a
+//! StatusNotifierItem over D-Bus with ksni on Linux and BSDs.
+
+use i_slint_core::graphics::Image;
+use i_slint_core::items::MenuEntry;
+use i_slint_core::platform::{TrayIconEvent, TrayIconHandle};
+use i_slint_core::window::MenuVTable;
+use i_slint_core::SharedString;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::{Rc, Weak};
+
+/// The prefix of the id of the native menu entries that belong to a tray icon
+#[cfg(native_tray_icon)]
+pub const MENU_ID_PREFIX: &str = "tray-";
+
+/// Limit the depth of the menus, as infinite menu depth is possible
+const MAX_MENU_DEPTH: usize = 15;
+
+thread_local! {
+    static TRAY_ICONS: RefCell<HashMap<u32, Weak<TrayIconState>>> = Default::default();
+    static NEXT_ID: Cell<u32> = const { Cell::new(1) };
+}
+
+/// A menu entry collected from the Slint menu, with `index` pointing into [`TrayIconState::entries`]
+#[derive(Clone, Debug)]
+struct MenuNode {
+    title: SharedString,
+    index: usize,
+    sub_menu: Option<Vec<MenuNode>>,
+}
+
+fn collect_menu(
+    menu: vtable::VRef<MenuVTable>,
+    parent: Option<&MenuEntry>,
+    depth: usize,
+    entries: &mut Vec<MenuEntry>,
+) -> Vec<MenuNode> {
+    let mut sub_entries = Default::default();
+    menu.sub_menu(parent, &mut sub_entries);
+    sub_entries
+        .into_iter()
+        .map(|entry| {
+            let sub_menu = (entry.has_sub_menu && depth < MAX_MENU_DEPTH)
+                .then(|| collect_menu(menu, Some(&entry), depth + 1, entries));
+            let index = entries.len();
+            let title = entry.title.clone();
+            entries.push(entry);
+            MenuNode { title, index, sub_menu }
+        })
+        .collect()
+}
+
+pub struct TrayIconState {
+    id: u32,
+    handle: TrayIconHandle,
+    menu: RefCell<Option<vtable::VBox<MenuVTable>>>,
+    /// The entries of the menu currently shown by the platform, indexed by their id
+    entries: RefCell<Vec<MenuEntry>>,
+    #[cfg(native_tray_icon)]
+    native: native::NativeTrayIcon,
+    #[cfg(sni_tray_icon)]
+    sni: sni::SniTrayIcon,
+}
+
+impl TrayIconState {
+    /// Queries the entries of the Slint menu again and updates the menu of the platform
+    fn update_menu(&self) {
+        let mut entries = Vec::new();
+        let nodes = self
+            .menu
+            .borrow()
+            .as_ref()
+            .map(|menu| collect_menu(menu.borrow(), None, 0, &mut entries));
+        *self.entries.borrow_mut() = entries;
+        #[cfg(native_tray_icon)]
+        self.native.set_menu(self.id, nodes);
+        #[cfg(sni_tray_icon)]
+        self.sni.set_menu(nodes);
+    }
+}
+
+/// Called in the event loop when the entry with the given index of the menu of a tray icon was activated
+pub fn activate_menu_entry(tray_id: u32, index: usize) {
+    let Some(state) = TRAY_ICONS.with(|icons| icons.borrow().get(&tray_id).and_then(Weak::upgrade))
+    else {
+        return;
+    };
+    let entry = state.entries.borrow().get(index).cloned();
+    if let Some(entry) = entry {
+        if let Some(menu) = state.menu.borrow().as_ref() {
+            menu.activate(&entry);
+        }
+        // The activation might have changed the entries
+        state.update_menu();
+    }
+}
+
+/// Called in the event loop to dispatch the event to the tray icon with the given id
+fn dispatch_event(tray_id: u32, event: TrayIconEvent) {
+    let Some(state) = TRAY_ICONS.with(|icons| icons.borrow().get(&tray_id).and_then(Weak::upgrade))
+    else {
+        return;
+    };
+    state.handle.dispatch_event(event);
+}
+
+pub struct WinitTrayIconAdapter(Rc<TrayIconState>);
+
+impl WinitTrayIconAdapter {
+    pub fn new(handle: TrayIconHandle) -> Self {
+        let id = NEXT_ID.with(|next| next.replace(next.get() + 1));
+        let state = Rc::new(TrayIconState {
+            id,
+            handle,
+            menu: Default::default(),
+            entries: Default::default(),
+            #[cfg(native_tray_icon)]
+            native: native::NativeTrayIcon::new(id),
+            #[cfg(sni_tray_icon)]
+            sni: sni::SniTrayIcon::new(id),
+        });
+        TRAY_ICONS.with(|icons| icons.borrow_mut().insert(id, Rc::downgrade(&state)));
+        Self(state)
+    }
+}
+
+impl Drop for WinitTrayIconAdapter {
+    fn drop(&mut self) {
+        let _ = TRAY_ICONS.try_with(|icons| icons.borrow_mut().remove(&self.0.id));
+    }
+}
+
+impl i_slint_core::platform::TrayIconAdapter for WinitTrayIconAdapter {
+    fn set_icon(&self, icon: &Image) {
+        let rgba = crate::winitwindowadapter::image_to_rgba8(icon);
+        #[cfg(native_tray_icon)]
+        self.0.native.set_icon(rgba);
+        #[cfg(sni_tray_icon)]
+        self.0.sni.set_icon(rgba);
+    }
+
+    fn set_tooltip(&self, tooltip: &str) {
+        #[cfg(native_tray_icon)]
+        self.0.native.set_tooltip(tooltip);
+        #[cfg(sni_tray_icon)]
+        self.0.sni.set_tooltip(tooltip);
+    }
+
+    fn set_menu(&self, menu: Option<vtable::VBox<MenuVTable>>) {
+        *self.0.menu.borrow_mut() = menu;
+        self.0.update_menu();
+    }
+
+    fn set_visible(&self, visible: bool) {
+        #[cfg(native_tray_icon)]
+        self.0.native.set_visible(visible);
+        #[cfg(sni_tray_icon)]
+        self.0.sni.set_visible(visible);
+    }
+}
+
+#[cfg(native_tray_icon)]
+mod native {
+    use super::{dispatch_event, MenuNode, MENU_ID_PREFIX};
+    use i_slint_core::platform::TrayIconEvent;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use tray_icon::menu::{IsMenuItem, Menu, MenuId, MenuItem, Submenu};
+    use tray_icon::{MouseButton, MouseButtonState, TrayIconBuilder};
+
+    /// The attributes of the tray icon, applied when the native icon is created
+    #[derive(Default)]
+    struct Attributes {
+        icon: Option<tray_icon::Icon>,
+        tooltip: String,
+        menu: Option<Menu>,
+        hidden: bool,
+    }
+
+    /// The native tray icon is created once the event loop runs, because on macOS it
+    /// requires the application to be initialized.
+    pub struct NativeTrayIcon {
+        tray_icon: Rc<RefCell<Option<tray_icon::TrayIcon>>>,
+        attributes: Rc<RefCell<Attributes>>,
+    }
+
+    impl NativeTrayIcon {
+        pub fn new(id: u32) -> Self {
+            tray_icon::TrayIconEvent::set_event_handler(Some(|event: tray_icon::TrayIconEvent| {
+                let event_kind = match event {
+                    tray_icon::TrayIconEvent::Click {
+                        button: MouseButton::Left,
+                        button_state: MouseButtonState::Up,
+                        ..
+                    } => TrayIconEvent::Activated,
+                    tray_icon::TrayIconEvent::Click {
+                        button: MouseButton::Right,
+                        button_state: MouseButtonState::Up,
+                        ..
+                    } => TrayIconEvent::ContextMenuRequested,
+                    _ => return,
+                };
+                let Ok(tray_id) = event.id().0.parse::<u32>() else { return };
+                let _ = i_slint_core::api::invoke_from_event_loop(move || {
+                    dispatch_event(tray_id, event_kind)
+                });
+            }));
+            tray_icon::menu::MenuEvent::set_event_handler(Some(|event| {
+                let _ = crate::send_event_via_global_event_loop_proxy(crate::SlintUserEvent(
+                    crate::event_loop::CustomEvent::Muda(event),
+                ));
+            }));
+
+            let tray_icon: Rc<RefCell<Option<tray_icon::TrayIcon>>> = Default::default();
+            let attributes: Rc<RefCell<Attributes>> = Default::default();
+            let (weak_icon, weak_attributes) =
+                (Rc::downgrade(&tray_icon), Rc::downgrade(&attributes));
+            i_slint_core::timers::Timer::single_shot(Default::default(), move || {
+                let (Some(tray_icon), Some(attributes)) =
+                    (weak_icon.upgrade(), weak_attributes.upgrade())
+                else {
+                    return;
+                };
+                let attributes = std::mem::take(&mut *attributes.borrow_mut());
+                let mut builder = TrayIconBuilder::new()
+                    .with_id(id.to_string())
+                    .with_tooltip(attributes.tooltip)
+                    .with_menu_on_left_click(false);
+                if let Some(icon) = attributes.icon {
+                    builder = builder.with_icon(icon);
+                }
+                if let Some(menu) = attributes.menu {
+                    builder = builder.with_menu(Box::new(menu));
+                }
+                match builder.build() {
+                    Ok(icon) => {
+                        if attributes.hidden {
+                            let _ = icon.set_visible(false);
+                        }
+                        *tray_icon.borrow_mut() = Some(icon);
+                    }
+                    Err(err) => i_slint_core::debug_log!("Error creating the tray icon: {err}"),
+                }
+            });
+            Self { tray_icon, attributes }
+        }
+
+        pub fn set_icon(&self, rgba: Option<(Vec<u8>, u32, u32)>) {
+            let icon =
+                rgba.and_then(|(pixels, w, h)| tray_icon::Icon::from_rgba(pixels, w, h).ok());
+            match self.tray_icon.borrow().as_ref() {
+                Some(tray_icon) => {
+                    let _ = tray_icon.set_icon(icon);
+                }
+                None => self.attributes.borrow_mut().icon = icon,
+            }
+        }
+
+        pub fn set_tooltip(&self, tooltip: &str) {
+            match self.tray_icon.borrow().as_ref() {
+                Some(tray_icon) => {
+                    let _ = tray_icon.set_tooltip(Some(tooltip));
+                }
+                None => self.attributes.borrow_mut().tooltip = tooltip.into(),
+            }
+        }
+
+        pub fn set_visible(&self, visible: bool) {
+            match self.tray_icon.borrow().as_ref() {
+                Some(tray_icon) => {
+                    let _ = tray_icon.set_visible(visible);
+                }
+                None => self.attributes.borrow_mut().hidden = !visible,
+            }
+        }
+
+        pub fn set_menu(&self, tray_id: u32, nodes: Option<Vec<MenuNode>>) {
+            let menu = nodes.map(|nodes| {
+                let menu = Menu::new();
+                for node in nodes {
+                    let _ = menu.append(&*create_menu_item(tray_id, node));
+                }
+                menu
+            });
+            match self.tray_icon.borrow().as_ref() {
+                Some(tray_icon) => {
+                    tray_icon.set_menu(menu.map(|m| Box::new(m) as Box<_>));
+                }
+                None => self.attributes.borrow_mut().menu = menu,
+            }
+        }
+    }
+
+    fn create_menu_item(tray_id: u32, node: MenuNode) -> Box<dyn IsMenuItem> {
+        let id = MenuId(format!("{MENU_ID_PREFIX}{tray_id}|{}", node.index));
+        match node.sub_menu {
+            Some(children) => {
+                let sub_menu = Submenu::with_id(id, &node.title, true);
+                for child in children {
+                    let _ = sub_menu.append(&*create_menu_item(tray_id, child));
+                }
+                Box::new(sub_menu)
+            }
+            None => Box::new(MenuItem::with_id(id, &node.title, true, None)),
+        }
+    }
+}
+
+#[cfg(sni_tray_icon)]
+mod sni {
+    use super::{dispatch_event, MenuNode};
+    use i_slint_core::platform::TrayIconEvent;
+
+    /// The state of the tray icon that is shared with the thread of the D-Bus service
+    struct SniTray {
+        id: u32,
+        icon: Vec<ksni::Icon>,
+        tooltip: String,
+        menu: Vec<MenuNode>,
+        visible: bool,
+    }
+
+    impl ksni::Tray for SniTray {
+        fn id(&self) -> String {
+            format!("slint-tray-{}-{}", std::process::id(), self.id)
+        }
+
+        fn category(&self) -> ksni::Category {
+            ksni::Category::ApplicationStatus
+        }
+
+        fn status(&self) -> ksni::Status {
+            if self.visible {
+                ksni::Status::Active
+            } else {
+                ksni::Status::Passive
+            }
+        }
+
+        fn icon_pixmap(&self) -> Vec<ksni::Icon> {
+            self.icon.clone()
+        }
+
+        fn tool_tip(&self) -> ksni::ToolTip {
+            ksni::ToolTip { title: self.tooltip.clone(), ..Default::default() }
+        }
+
+        fn activate(&mut self, _x: i32, _y: i32) {
+            let tray_id = self.id;
+            let _ = i_slint_core::api::invoke_from_event_loop(move || {
+                dispatch_event(tray_id, TrayIconEvent::Activated)
+            });
+        }
+
+        fn menu(&self) -> Vec<ksni::MenuItem<Self>> {
+            self.menu.iter().map(|node| create_menu_item(self.id, node)).collect()
+        }
+    }
+
+    fn create_menu_item(tray_id: u32, node: &MenuNode) -> ksni::MenuItem<SniTray> {
+        match &node.sub_menu {
+            Some(children) => ksni::menu::SubMenu {
+                label: node.title.to_string(),
+                submenu: children.iter().map(|child| create_menu_item(tray_id, child)).collect(),
+                ..Default::default()
+            }
+            .into(),
+            None => {
+                let index = node.index;
+                ksni::menu::StandardItem {
+                    label: node.title.to_string(),
+                    activate: Box::new(move |_| {
+                        let _ = i_slint_core::api::invoke_from_event_loop(move || {
+                            super::activate_menu_entry(tray_id, index)
+                        });
+                    }),
+                    ..Default::default()
+                }
+                .into()
+            }
+        }
+    }
+
+    pub struct SniTrayIcon {
+        handle: ksni::Handle<SniTray>,
+    }
+
+    impl SniTrayIcon {
+        pub fn new(id: u32) -> Self {
+            let service = ksni::TrayService::new(SniTray {
+                id,
+                icon: Vec::new(),
+                tooltip: String::new(),
+                menu: Vec::new(),
+                visible: true,
+            });
+            let handle = service.handle();
+            service.spawn();
+            Self { handle }
+        }
+
+        pub fn set_icon(&self, rgba: Option<(Vec<u8>, u32, u32)>) {
+            let icon = rgba.map(|(pixels, width, height)| ksni::Icon {
+                width: width as i32,
+                height: height as i32,
+                // StatusNotifierItem uses ARGB32 in network byte order
+                data: pixels.chunks(4).flat_map(|p| [p[3], p[0], p[1], p[2]]).collect(),
+            });
+            self.handle.update(move |tray| tray.icon = icon.into_iter().collect());
+        }
+
+        pub fn set_tooltip(&self, tooltip: &str) {
+            let tooltip = tooltip.to_string();
+            self.handle.update(move |tray| tray.tooltip = tooltip);
+        }
+
+        pub fn set_visible(&self, visible: bool) {
+            self.handle.update(move |tray| tray.visible = visible);
+        }
+
+        pub fn set_menu(&self, nodes: Option<Vec<MenuNode>>) {
+            let nodes = nodes.unwrap_or_default();
+            self.handle.update(move |tray| tray.menu = nodes);
+        }
+    }
+
+    impl Drop for SniTrayIcon {
+        fn drop(&mut self) {
+            self.handle.shutdown();
+        }
+    }
+}
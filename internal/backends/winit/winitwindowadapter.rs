@@ -77,7 +77,13 @@ fn physical_size_to_winit(size: PhysicalSize) -> winit::dpi::PhysicalSize<u32> {
 }
 
 fn icon_to_winit(icon: corelib::graphics::Image) -> Option<winit::window::Icon> {
-    let image_inner: &ImageInner = (&icon).into();
+    let (rgba_pixels, width, height) = image_to_rgba8(&icon)?;
+    winit::window::Icon::from_rgba(rgba_pixels, width, height).ok()
+}
+
+/// Returns the non-premultiplied RGBA pixels of an embedded image, with its width and height
+pub(crate) fn image_to_rgba8(image: &corelib::graphics::Image) -> Option<(Vec<u8>, u32, u32)> {
+    let image_inner: &ImageInner = image.into();
 
     let pixel_buffer = match image_inner {
         ImageInner::EmbeddedImage { buffer, .. } => buffer.clone(),
@@ -105,7 +111,7 @@ fn icon_to_winit(icon: corelib::graphics::Image) -> Option<winit::window::Icon>
             .collect(),
     };
 
-    winit::window::Icon::from_rgba(rgba_pixels, pixel_buffer.width(), pixel_buffer.height()).ok()
+    Some((rgba_pixels, pixel_buffer.width(), pixel_buffer.height()))
 }
 
 fn window_is_resizable(
@@ -208,6 +214,79 @@ fn set_minimized(&self, minimized: bool) {
         }
     }
 
+    fn set_transparent(&self, transparent: bool) {
+        match self {
+            Self::HasWindow { window, .. } => window.set_transparent(transparent),
+            Self::None(attributes) => attributes.borrow_mut().transparent = transparent,
+        }
+    }
+
+    fn set_backdrop(&self, backdrop: corelib::api::WindowBackdrop) {
+        use corelib::api::WindowBackdrop;
+        let blur = backdrop != WindowBackdrop::None;
+        #[cfg(target_family = "windows")]
+        let backdrop_type = match backdrop {
+            WindowBackdrop::Mica => winit::platform::windows::BackdropType::MainWindow,
+            WindowBackdrop::Blur | WindowBackdrop::Acrylic => {
+                winit::platform::windows::BackdropType::TransientWindow
+            }
+            _ => winit::platform::windows::BackdropType::None,
+        };
+        match self {
+            Self::HasWindow { window, .. } => {
+                window.set_blur(blur);
+                #[cfg(target_family = "windows")]
+                {
+                    use winit::platform::windows::WindowExtWindows;
+                    window.set_system_backdrop(backdrop_type);
+                }
+            }
+            Self::None(attributes) => {
+                let mut attributes = attributes.borrow_mut();
+                attributes.blur = blur;
+                #[cfg(target_family = "windows")]
+                {
+                    use winit::platform::windows::WindowAttributesExtWindows;
+                    *attributes =
+                        std::mem::take(&mut *attributes).with_system_backdrop(backdrop_type);
+                }
+            }
+        }
+    }
+
+    #[cfg_attr(not(target_family = "windows"), allow(unused))]
+    fn set_skip_taskbar(&self, skip_taskbar: bool) {
+        #[cfg(target_family = "windows")]
+        match self {
+            Self::HasWindow { window, .. } => {
+                use winit::platform::windows::WindowExtWindows;
+                window.set_skip_taskbar(skip_taskbar);
+            }
+            Self::None(attributes) => {
+                use winit::platform::windows::WindowAttributesExtWindows;
+                let mut attributes = attributes.borrow_mut();
+                *attributes = std::mem::take(&mut *attributes).with_skip_taskbar(skip_taskbar);
+            }
+        }
+    }
+
+    /// The cursor grab needs a window: it's applied again when the window is created
+    fn set_pointer_confined(&self, confined: bool) {
+        use winit::window::CursorGrabMode;
+        let Self::HasWindow { window, .. } = self else { return };
+        let result = if confined {
+            // macOS only supports locking the pointer, and X11 only confining it
+            window
+                .set_cursor_grab(CursorGrabMode::Confined)
+                .or_else(|_| window.set_cursor_grab(CursorGrabMode::Locked))
+        } else {
+            window.set_cursor_grab(CursorGrabMode::None)
+        };
+        if let Err(err) = result {
+            i_slint_core::debug_log!("Could not confine the pointer to the window: {err}");
+        }
+    }
+
     fn set_resizable(&self, resizable: bool) {
         match self {
             Self::HasWindow { window, .. } => {
@@ -256,6 +335,15 @@ pub struct WinitWindowAdapter {
     maximized: Cell<bool>,
     minimized: Cell<bool>,
     fullscreen: Cell<bool>,
+    skip_taskbar: Cell<bool>,
+    pointer_confined: Cell<bool>,
+    screensaver_inhibitor: RefCell<Option<crate::screensaver::ScreensaverInhibitor>>,
+    /// The native window of another application that this window is embedded in
+    #[cfg(feature = "raw-window-handle-06")]
+    parent_window: Cell<Option<raw_window_handle::RawWindowHandle>>,
+    exclusive_fullscreen: RefCell<Option<corelib::api::VideoMode>>,
+    transparent: Cell<bool>,
+    backdrop: Cell<corelib::api::WindowBackdrop>,
 
     pub(crate) renderer: Box<dyn WinitCompatibleRenderer>,
     requested_graphics_api: Option<RequestedGraphicsAPI>,
@@ -317,6 +405,15 @@ pub(crate) fn new(
             maximized: Cell::default(),
             minimized: Cell::default(),
             fullscreen: Cell::default(),
+            skip_taskbar: Cell::default(),
+            pointer_confined: Cell::default(),
+            screensaver_inhibitor: Default::default(),
+            #[cfg(feature = "raw-window-handle-06")]
+            parent_window: Default::default(),
+            exclusive_fullscreen: Default::default(),
+            // The windows are created with `with_transparent(true)`
+            transparent: Cell::new(true),
+            backdrop: Cell::default(),
             winit_window_or_none: RefCell::new(WinitWindowOrNone::None(window_attributes.into())),
             size: Cell::default(),
             pending_requested_size: Cell::new(None),
@@ -379,6 +476,13 @@ pub fn ensure_window(&self) -> Result<Rc<winit::window::Window>, PlatformError>
             }
         }
 
+        #[cfg(feature = "raw-window-handle-06")]
+        if let Some(parent) = self.parent_window.get() {
+            // Safety: the caller of Window::set_parent_window_handle guarantees that the handle is valid
+            window_attributes = unsafe { window_attributes.with_parent_window(Some(parent)) }
+                .with_decorations(false);
+        }
+
         let mut winit_window_or_none = self.winit_window_or_none.borrow_mut();
 
         let winit_window =
@@ -395,11 +499,18 @@ pub fn ensure_window(&self) -> Result<Rc<winit::window::Window>, PlatformError>
             .into(),
         };
 
+        if self.pointer_confined.get() {
+            winit_window_or_none.set_pointer_confined(true);
+        }
+
         crate::event_loop::register_window(
             winit_window.id(),
             (self.self_weak.upgrade().unwrap()) as _,
         );
 
+        #[cfg(target_os = "windows")]
+        crate::snap_layouts::install(&winit_window, self.self_weak.clone());
+
         Ok(winit_window)
     }
 
@@ -648,6 +759,17 @@ pub(crate) fn with_access_kit_adapter_from_weak_window_adapter(
         }
     }
 
+    fn update_screensaver_inhibitor(&self, inhibit: bool) {
+        let mut inhibitor = self.screensaver_inhibitor.borrow_mut();
+        if inhibit && inhibitor.is_none() {
+            *inhibitor = crate::screensaver::ScreensaverInhibitor::new(
+                WindowInner::from_pub(self.window()).context(),
+            );
+        } else if !inhibit {
+            *inhibitor = None;
+        }
+    }
+
     #[cfg(not(use_winit_theme))]
     fn spawn_xdg_settings_watcher(&self) -> Option<i_slint_core::future::JoinHandle<()>> {
         let window_inner = WindowInner::from_pub(self.window());
@@ -783,6 +905,10 @@ fn set_visible(&self, visible: bool) -> Result<(), PlatformError> {
 
             winit_window.set_visible(true);
 
+            self.update_screensaver_inhibitor(
+                WindowInner::from_pub(self.window()).inhibits_screensaver(),
+            );
+
             // Make sure the dark color scheme property is up-to-date, as it may have been queried earlier when
             // the window wasn't mapped yet.
             if let Some(color_scheme_prop) = self.color_scheme.get() {
@@ -804,6 +930,8 @@ fn set_visible(&self, visible: bool) -> Result<(), PlatformError> {
 
             Ok(())
         } else {
+            self.update_screensaver_inhibitor(false);
+
             crate::event_loop::with_window_target(|event_loop| {
                 // Wayland doesn't support hiding a window, only destroying it entirely.
                 if event_loop.is_wayland()
@@ -958,16 +1086,28 @@ fn update_window_properties(&self, properties: corelib::window::WindowProperties
         }
 
         let m = properties.is_fullscreen();
-        if m != self.fullscreen.get() {
+        let exclusive = properties.exclusive_fullscreen();
+        if m != self.fullscreen.get() || exclusive != *self.exclusive_fullscreen.borrow() {
             if m {
-                if winit_window_or_none.fullscreen().is_none() {
-                    winit_window_or_none
-                        .set_fullscreen(Some(winit::window::Fullscreen::Borderless(None)));
+                // Fall back to borderless fullscreen if the mode is no longer available
+                match exclusive.as_ref().and_then(crate::event_loop::find_video_mode) {
+                    Some(mode) => winit_window_or_none
+                        .set_fullscreen(Some(winit::window::Fullscreen::Exclusive(mode))),
+                    None => {
+                        if !matches!(
+                            winit_window_or_none.fullscreen(),
+                            Some(winit::window::Fullscreen::Borderless(_))
+                        ) {
+                            winit_window_or_none
+                                .set_fullscreen(Some(winit::window::Fullscreen::Borderless(None)));
+                        }
+                    }
                 }
             } else {
                 winit_window_or_none.set_fullscreen(None);
             }
             self.fullscreen.set(m);
+            self.exclusive_fullscreen.replace(exclusive);
         }
 
         let m = properties.is_maximized();
@@ -982,6 +1122,32 @@ fn update_window_properties(&self, properties: corelib::window::WindowProperties
             winit_window_or_none.set_minimized(m);
         }
 
+        let transparent = properties.is_transparent();
+        if transparent != self.transparent.get() {
+            self.transparent.set(transparent);
+            winit_window_or_none.set_transparent(transparent);
+        }
+
+        let backdrop = properties.backdrop();
+        if backdrop != self.backdrop.get() {
+            self.backdrop.set(backdrop);
+            winit_window_or_none.set_backdrop(backdrop);
+        }
+
+        let skip_taskbar = properties.skips_taskbar();
+        if skip_taskbar != self.skip_taskbar.get() {
+            self.skip_taskbar.set(skip_taskbar);
+            winit_window_or_none.set_skip_taskbar(skip_taskbar);
+        }
+
+        let confined = properties.is_pointer_confined();
+        if confined != self.pointer_confined.get() {
+            self.pointer_confined.set(confined);
+            winit_window_or_none.set_pointer_confined(confined);
+        }
+
+        self.update_screensaver_inhibitor(properties.inhibits_screensaver() && self.shown.get());
+
         // If we're in fullscreen, don't try to resize the window but
         // maintain the surface size we've been assigned to from the
         // windowing system. Weston/Wayland don't like it when we create a
@@ -1094,8 +1260,8 @@ fn input_method_request(&self, request: corelib::window::InputMethodRequest) {
                 _ => winit::window::ImePurpose::Normal,
             });
             winit_window.set_ime_cursor_area(
-                position_to_winit(&props.cursor_rect_origin.into()),
-                window_size_to_winit(&props.cursor_rect_size.into()),
+                position_to_winit(&props.candidate_rect_origin.into()),
+                window_size_to_winit(&props.candidate_rect_size.into()),
             );
         }
 
@@ -1215,6 +1381,37 @@ fn display_handle_06_rc(
             .map_or(Err(raw_window_handle::HandleError::Unavailable), |window| Ok(window))
     }
 
+    #[cfg(feature = "raw-window-handle-06")]
+    fn set_parent_window_handle_06(
+        &self,
+        parent: raw_window_handle::RawWindowHandle,
+    ) -> Result<(), PlatformError> {
+        use raw_window_handle::RawWindowHandle;
+        // winit ignores the parent window on the other platforms, including Wayland
+        let is_wayland =
+            crate::event_loop::with_window_target(|event_loop| Ok(event_loop.is_wayland()))
+                .unwrap_or_default();
+        if is_wayland
+            || !matches!(
+                parent,
+                RawWindowHandle::Win32(_)
+                    | RawWindowHandle::AppKit(_)
+                    | RawWindowHandle::Xlib(_)
+                    | RawWindowHandle::Xcb(_)
+            )
+        {
+            return Err(
+                "Embedding a window in a native window is only supported on Windows, macOS, and X11"
+                    .into(),
+            );
+        }
+        if self.winit_window_or_none.borrow().as_window().is_some() {
+            return Err("The parent window handle must be set before the window is shown".into());
+        }
+        self.parent_window.set(Some(parent));
+        Ok(())
+    }
+
     fn bring_to_front(&self) -> Result<(), PlatformError> {
         if let Some(winit_window) = self.winit_window_or_none.borrow().as_window() {
             winit_window.set_minimized(false);
@@ -1222,6 +1419,39 @@ fn bring_to_front(&self) -> Result<(), PlatformError> {
         }
         Ok(())
     }
+
+    fn drag_window(&self) -> bool {
+        self.winit_window_or_none
+            .borrow()
+            .as_window()
+            .is_some_and(|winit_window| winit_window.drag_window().is_ok())
+    }
+
+    fn drag_resize_window(&self, edge: corelib::items::WindowControl) -> bool {
+        use corelib::items::WindowControl;
+        use winit::window::ResizeDirection;
+        let direction = match edge {
+            WindowControl::ResizeTop => ResizeDirection::North,
+            WindowControl::ResizeBottom => ResizeDirection::South,
+            WindowControl::ResizeLeft => ResizeDirection::West,
+            WindowControl::ResizeRight => ResizeDirection::East,
+            WindowControl::ResizeTopLeft => ResizeDirection::NorthWest,
+            WindowControl::ResizeTopRight => ResizeDirection::NorthEast,
+            WindowControl::ResizeBottomLeft => ResizeDirection::SouthWest,
+            WindowControl::ResizeBottomRight => ResizeDirection::SouthEast,
+            _ => return false,
+        };
+        self.winit_window_or_none
+            .borrow()
+            .as_window()
+            .is_some_and(|winit_window| winit_window.drag_resize_window(direction).is_ok())
+    }
+
+    fn show_window_menu(&self, position: corelib::lengths::LogicalPoint) {
+        if let Some(winit_window) = self.winit_window_or_none.borrow().as_window() {
+            winit_window.show_window_menu(winit::dpi::LogicalPosition::new(position.x, position.y));
+        }
+    }
 }
 
 impl Drop for WinitWindowAdapter {
@@ -65,6 +65,16 @@ struct PointerEvent {
                     kind: PointerEventKind,
                     /// The keyboard modifiers pressed during the event
                     modifiers: KeyboardModifiers,
+                    /// The device that sent the event
+                    device: PointerDevice,
+                    /// The pressure of the pen, from 0 to 1. For the other devices, 0.5 while a button is pressed, and 0 otherwise
+                    pressure: f32,
+                    /// The angle in degrees between the pen and the screen in the plane of the X axis, from -90 to 90. Positive towards the right
+                    tilt_x: f32,
+                    /// The angle in degrees between the pen and the screen in the plane of the Y axis, from -90 to 90. Positive towards the bottom
+                    tilt_y: f32,
+                    /// True while the barrel button on the side of the pen is pressed
+                    barrel_button: bool,
                 }
                 private {
                 }
@@ -201,6 +211,69 @@ struct MenuEntry {
                 }
                 private {}
             }
+
+            /// A toast shown by the toast layer of a window
+            struct ToastEntry {
+                @name = "slint::private_api::ToastEntry"
+                export {
+                    /// The id of the toast, passed to the `ActivateToast` builtin function
+                    id: i32,
+                    /// The message of the toast
+                    message: SharedString,
+                    /// The label of the action of the toast, or empty if it has no action
+                    action: SharedString,
+                }
+                private {}
+            }
+
+            /// A range of the text of a `TextInput` or `TextEdit` that is drawn with a different style.
+            /// This is used by the `highlight-spans` property for example for syntax highlighting.
+            struct TextSpan {
+                @name = "slint::TextSpan"
+                export {
+                    /// The byte offset in the text where the span starts
+                    start: i32,
+                    /// The byte offset in the text where the span ends (exclusive)
+                    end: i32,
+                    /// The color of the text, or transparent to use the color of the element
+                    color: Color,
+                    /// The background color of the text, or transparent for no background
+                    background: Color,
+                    /// The weight of the font, or 0 to use the weight of the element
+                    font_weight: i32,
+                }
+                private {}
+            }
+
+            /// The data dragged over a `DropArea`, from a `DragArea` or from another application.
+            /// This structure is passed to the `can-drop` and `dropped` callbacks of the `DropArea` element.
+            struct DropEvent {
+                @name = "slint::private_api::DropEvent"
+                export {
+                    /// The mime type of the data, for example `text/plain`, or `text/uri-list` for files
+                    mime_type: SharedString,
+                    /// The data. Files are represented as a list of `file://` URIs, one per line.
+                    data: SharedString,
+                    /// The position of the pointer, relative to the `DropArea`
+                    position: LogicalPosition,
+                }
+                private {}
+            }
+
+            /// The offset and rotation applied to a single character of a `Text` element.
+            /// This structure is returned by the `glyph-transform` callback of the `Text` element.
+            struct GlyphTransform {
+                @name = "slint::private_api::GlyphTransform"
+                export {
+                    /// The horizontal offset of the character
+                    x: Coord,
+                    /// The vertical offset of the character
+                    y: Coord,
+                    /// The rotation of the character around its center, in degrees
+                    rotation: f32,
+                }
+                private {}
+            }
         ];
     };
 }
@@ -66,6 +66,19 @@ enum TextStrokeStyle {
                 Center,
             }
 
+            /// This enum describes the base direction of the paragraphs in a `Text`, which determines the order of
+            /// text that mixes left-to-right and right-to-left scripts, and the side that neutral characters such as
+            /// punctuation are placed on.
+            /// Currently only supported by the Skia renderer.
+            enum TextDirection {
+                /// The renderer chooses the direction. Currently this is left-to-right.
+                Auto,
+                /// The paragraphs are left-to-right, like in English.
+                LeftToRight,
+                /// The paragraphs are right-to-left, like in Arabic or Hebrew.
+                RightToLeft,
+            }
+
             /// This enum describes whether an event was rejected or accepted by an event handler.
             enum EventResult {
                 /// The event is rejected by this event handler and may then be handled by the parent item
@@ -162,6 +175,19 @@ enum PointerEventButton {
                 Forward,
             }
 
+            /// This enum describes the device that sent a pointer event.
+            #[non_exhaustive]
+            enum PointerDevice {
+                /// A mouse, or a touchpad.
+                Mouse,
+                /// A finger on a touchscreen.
+                Touch,
+                /// The tip of a pen or stylus.
+                Pen,
+                /// The eraser end of a pen or stylus.
+                Eraser,
+            }
+
             /// This enum represents different types of mouse cursors. It's a subset of the mouse cursors available in CSS.
             /// For details and pictograms see the [MDN Documentation for cursor](https://developer.mozilla.org/en-US/docs/Web/CSS/cursor#values).
             /// Depending on the backend and used OS unidirectional resize cursors may be replaced with bidirectional ones.
@@ -245,6 +271,10 @@ enum ImageFit {
                 /// The source image will still be scaled by the scale factor that applies to all elements in the window.
                 /// Any extra space will be left blank.
                 Preserve,
+                /// The source image is cut into nine slices by the `nine-slice-*` insets of the `Image` element.
+                /// The corners keep their size, the edges are stretched along the border, and the center is
+                /// stretched to fill the rest.
+                NineSlice,
             }
 
             /// This enum specifies the horizontal alignment of the source image.
@@ -296,6 +326,28 @@ enum InputType {
                 Number,
                 /// This will accept and render characters if it's valid part of a decimal
                 Decimal,
+                /// This renders all characters normally, and asks for a virtual keyboard suited to enter an email address
+                Email,
+                /// This renders all characters normally, and asks for a virtual keyboard suited to enter a phone number
+                Phone,
+            }
+
+            /// This enum describes the action of the enter key of the virtual keyboard, for a `TextInput`.
+            /// Pressing the enter key then invokes the `ime-action-triggered` callback with that action.
+            #[non_exhaustive]
+            enum ImeAction {
+                /// The virtual keyboard shows its default enter key, which inserts a new line in a multi-line `TextInput`
+                Default,
+                /// The enter key completes the input, for example to close the virtual keyboard
+                Done,
+                /// The enter key goes to the target of the text, such as a URL
+                Go,
+                /// The enter key moves to the next field
+                Next,
+                /// The enter key starts a search with the text
+                Search,
+                /// The enter key sends the text, such as a message
+                Send,
             }
 
             /// Enum representing the `alignment` property of a
@@ -368,7 +420,7 @@ enum AccessibleRole {
                 Text,
                 /// The role for a `TableView` or behaves like one.
                 Table,
-                /// The role for a TreeView or behaves like one. (Not provided yet)
+                /// The role for a `TreeView` or behaves like one.
                 Tree,
                 /// The element is a `ProgressIndicator` or behaves like one.
                 ProgressIndicator,
@@ -402,6 +454,24 @@ enum Orientation {
                 Vertical,
             }
 
+            /// This enum describes the orientation of the window, as used by the `screen-orientation` property of the `Window`.
+            enum ScreenOrientation {
+                /// The window is at least as high as it is wide.
+                Portrait,
+                /// The window is wider than it is high.
+                Landscape,
+            }
+
+            /// This enum describes the posture of a foldable device, as used by the `device-posture` property of the `Window`.
+            enum DevicePosture {
+                /// The device isn't foldable, or it's fully opened.
+                Flat,
+                /// The device is partially folded, like a book or a laptop.
+                HalfOpened,
+                /// The device is folded shut, and the application is shown on the outer display.
+                Folded,
+            }
+
             /// This enum indicates the color scheme used by the widget style. Use this to explicitly switch
             /// between dark and light schemes, or choose Unknown to fall back to the system default.
             enum ColorScheme {
@@ -447,6 +517,37 @@ enum PopupClosePolicy {
                 /// Does not close the `PopupWindow` automatically when user clicks.
                 NoAutoClose,
             }
+
+            /// This enum describes the part of a custom title bar or window frame that a `WindowControlArea`
+            /// represents, for windows with `no-frame: true`.
+            enum WindowControl {
+                /// The title bar: dragging it moves the window, double-clicking it maximizes or restores the
+                /// window, and right-clicking it shows the system menu of the window.
+                Caption,
+                /// The button that minimizes the window.
+                MinimizeButton,
+                /// The button that maximizes the window, or restores it when it's maximized.
+                /// On Windows 11, hovering it shows the snap layouts.
+                MaximizeButton,
+                /// The button that closes the window.
+                CloseButton,
+                /// The top edge of the window: dragging it resizes the window.
+                ResizeTop,
+                /// The bottom edge of the window: dragging it resizes the window.
+                ResizeBottom,
+                /// The left edge of the window: dragging it resizes the window.
+                ResizeLeft,
+                /// The right edge of the window: dragging it resizes the window.
+                ResizeRight,
+                /// The top left corner of the window: dragging it resizes the window.
+                ResizeTopLeft,
+                /// The top right corner of the window: dragging it resizes the window.
+                ResizeTopRight,
+                /// The bottom left corner of the window: dragging it resizes the window.
+                ResizeBottomLeft,
+                /// The bottom right corner of the window: dragging it resizes the window.
+                ResizeBottomRight,
+            }
         ];
     };
 }
@@ -5,9 +5,12 @@
 #![doc(html_logo_url = "https://slint.dev/logo/slint-logo-square-light.svg")]
 #![cfg_attr(not(feature = "shared-fontdb"), no_std)]
 
+extern crate alloc;
+
 pub mod builtin_structs;
 pub mod enums;
 pub mod key_codes;
+pub mod translations;
 
 #[cfg(feature = "shared-fontdb")]
 pub mod sharedfontdb;
@@ -0,0 +1,69 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Helpers for the translations, shared between the runtime and the slint-tr-extractor tool
+
+use alloc::string::String;
+use core::fmt::Write;
+
+/// Returns the identifier of the Fluent message of a `@tr` string with the given context
+///
+/// The identifier is the context and the string, separated by `--`, where the characters
+/// that are not ASCII letters or digits are replaced by `-`.
+/// For example, `@tr("Hello, World!")` in `component MyDialog` becomes `MyDialog--Hello-World`.
+/// If the string contains other characters than ASCII, or if it is long, the identifier is
+/// shortened and ends with a hash of the string so that it is still unique.
+pub fn fluent_message_id(context: &str, original: &str) -> String {
+    const MAX_LEN: usize = 48;
+    fn push_slug(id: &mut String, s: &str) {
+        let mut pending_dash = false;
+        for c in s.chars() {
+            if c.is_ascii_alphanumeric() {
+                if pending_dash && !id.is_empty() {
+                    id.push('-');
+                }
+                pending_dash = false;
+                id.push(c);
+            } else {
+                pending_dash = true;
+            }
+        }
+    }
+
+    let mut slug = String::new();
+    push_slug(&mut slug, original);
+    if !original.is_ascii() || slug.len() > MAX_LEN || slug.is_empty() {
+        // FNV-1a, which is stable across platforms and versions
+        let hash =
+            original.bytes().fold(0x811c9dc5_u32, |h, b| (h ^ b as u32).wrapping_mul(0x01000193));
+        slug.truncate(MAX_LEN);
+        if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+        write!(slug, "{hash:08x}").unwrap();
+    }
+
+    let mut id = String::new();
+    push_slug(&mut id, context);
+    if !id.is_empty() {
+        id.push_str("--");
+    }
+    id.push_str(&slug);
+    if !id.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        id.insert_str(0, "tr-");
+    }
+    id
+}
+
+#[test]
+fn test_fluent_message_id() {
+    assert_eq!(fluent_message_id("MyDialog", "Hello, World!"), "MyDialog--Hello-World");
+    assert_eq!(fluent_message_id("", "{n} files"), "n-files");
+    assert_eq!(fluent_message_id("Foo", "42"), "Foo--42");
+    assert_eq!(fluent_message_id("", "42"), "tr-42");
+    assert_eq!(fluent_message_id("ctx", "Grüße"), "ctx--Gr-e-b8226e38");
+    assert_ne!(fluent_message_id("ctx", "Grüße"), fluent_message_id("ctx", "Grøße"));
+    assert_eq!(fluent_message_id("ctx", "…").len(), "ctx--".len() + 8);
+    let long = fluent_message_id("", &"a".repeat(100));
+    assert!(long.starts_with(&"a".repeat(48)) && long.len() == 48 + 1 + 8);
+}
@@ -6,15 +6,13 @@
 
 use crate::diagnostics::{BuildDiagnostics, Spanned};
 use crate::expression_tree::{
-    BuiltinFunction, BuiltinMacroFunction, EasingCurve, Expression, MinMaxOp, Unit,
+    unique_local_variable_name, BuiltinFunction, BuiltinMacroFunction, EasingCurve, Expression,
+    MinMaxOp, Unit,
 };
 use crate::langtype::{EnumerationValue, Type};
 use crate::parser::NodeOrToken;
 use smol_str::{format_smolstr, ToSmolStr};
 
-/// Used for uniquely name some variables
-static COUNTER: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(1);
-
 /// "Expand" the macro `mac` (at location `n`) with the arguments `sub_expr`
 pub fn lower_macro(
     mac: BuiltinMacroFunction,
@@ -66,6 +64,7 @@ pub fn lower_macro(
         }
         BuiltinMacroFunction::Rgb => rgb_macro(n, sub_expr.collect(), diag),
         BuiltinMacroFunction::Hsv => hsv_macro(n, sub_expr.collect(), diag),
+        BuiltinMacroFunction::DisplayP3 => display_p3_macro(n, sub_expr.collect(), diag),
     }
 }
 
@@ -280,6 +279,66 @@ fn hsv_macro(
     }
 }
 
+/// Converts the Display P3 color to sRGB at compile time, so that no run-time support is needed.
+/// Colors outside of the sRGB gamut are clipped.
+fn display_p3_macro(
+    node: Option<NodeOrToken>,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    if args.len() < 3 || args.len() > 4 {
+        diag.push_error(
+            format!("This function needs 3 or 4 arguments, but {} were provided", args.len()),
+            &node,
+        );
+        return Expression::Invalid;
+    }
+    let mut values = [0., 0., 0., 1.];
+    for ((expr, n), value) in args.into_iter().zip(values.iter_mut()) {
+        *value = match expr {
+            Expression::NumberLiteral(val, Unit::None) => val,
+            Expression::NumberLiteral(val, Unit::Percent) => val / 100.,
+            _ => {
+                diag.push_error(
+                    "Arguments to display-p3 must be number literals or percentages".into(),
+                    &n,
+                );
+                return Expression::Invalid;
+            }
+        }
+        .clamp(0., 1.);
+    }
+
+    // The transfer function of Display P3 is the same as the one of sRGB
+    let to_linear = |v: f64| if v <= 0.04045 { v / 12.92 } else { ((v + 0.055) / 1.055).powf(2.4) };
+    let from_linear = |v: f64| {
+        let v = v.clamp(0., 1.);
+        if v <= 0.0031308 {
+            v * 12.92
+        } else {
+            1.055 * v.powf(1. / 2.4) - 0.055
+        }
+    };
+    const DISPLAY_P3_TO_SRGB: [[f64; 3]; 3] = [
+        [1.2249401762805598, -0.22494017628055996, 0.],
+        [-0.04205695470968816, 1.042056954709688, 0.],
+        [-0.019637554590334432, -0.07863604555063188, 1.0982736001409663],
+    ];
+    let linear = [values[0], values[1], values[2]].map(to_linear);
+    let [r, g, b] = DISPLAY_P3_TO_SRGB.map(|row| {
+        let value: f64 = row.iter().zip(&linear).map(|(m, c)| m * c).sum();
+        (from_linear(value) * 255.).round() as u32
+    });
+    let a = (values[3] * 255.).round() as u32;
+    Expression::Cast {
+        from: Box::new(Expression::NumberLiteral(
+            ((a << 24) | (r << 16) | (g << 8) | b) as f64,
+            Unit::None,
+        )),
+        to: Type::Color,
+    }
+}
+
 fn debug_macro(
     node: Option<NodeOrToken>,
     args: Vec<(Expression, Option<NodeOrToken>)>,
@@ -364,10 +423,7 @@ fn to_debug_string(
             false_expr: Box::new(Expression::StringLiteral("false".into())),
         },
         Type::Struct(s) => {
-            let local_object = format_smolstr!(
-                "debug_struct{}",
-                COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
-            );
+            let local_object = unique_local_variable_name("debug_struct");
             let mut string = None;
             for k in s.fields.keys() {
                 let field_name = if string.is_some() {
@@ -171,6 +171,7 @@ pub fn load_from_path(path: &Path) -> Result<String, Diagnostic> {
             span: Default::default(),
         },
         level: DiagnosticLevel::Error,
+        fix_its: Vec::new(),
     })?;
 
     if path.extension().map_or(false, |e| e == "rs") {
@@ -181,6 +182,7 @@ pub fn load_from_path(path: &Path) -> Result<String, Diagnostic> {
                 span: Default::default(),
             },
             level: DiagnosticLevel::Error,
+            fix_its: Vec::new(),
         });
     }
 
@@ -234,6 +236,38 @@ fn from(l: DiagnosticLevel) -> Self {
     }
 }
 
+/// A suggestion to fix a diagnostic, that can be applied without user interaction:
+/// the text in `range` of the source file of the diagnostic is replaced by `replacement`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixIt {
+    /// A short description of the change, such as "Replace with 'self.width'"
+    pub title: String,
+    /// The range in bytes in the source file. It is empty when the replacement is an insertion.
+    pub range: std::ops::Range<usize>,
+    /// The new text. It is empty when the fix-it removes the text in the range.
+    pub replacement: String,
+}
+
+impl FixIt {
+    /// Creates a fix-it that replaces the text in the given range of the source file
+    pub fn replace(
+        title: impl Into<String>,
+        range: crate::parser::TextRange,
+        replacement: impl Into<String>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            range: range.start().into()..range.end().into(),
+            replacement: replacement.into(),
+        }
+    }
+
+    /// Creates a fix-it that inserts a text at the given offset of the source file
+    pub fn insert(title: impl Into<String>, offset: TextSize, text: impl Into<String>) -> Self {
+        Self::replace(title, crate::parser::TextRange::empty(offset), text)
+    }
+}
+
 /// This structure represent a diagnostic emitted while compiling .slint code.
 ///
 /// It is basically a message, a level (warning or error), attached to a
@@ -243,6 +277,7 @@ pub struct Diagnostic {
     message: String,
     span: SourceLocation,
     level: DiagnosticLevel,
+    fix_its: Vec<FixIt>,
 }
 
 //NOTE! Diagnostic is re-exported in the public API of the interpreter
@@ -276,6 +311,20 @@ pub fn line_column(&self) -> (usize, usize) {
     pub fn source_file(&self) -> Option<&Path> {
         self.span.source_file().map(|sf| sf.path())
     }
+
+    /// Returns the suggestions to fix this diagnostic. Their ranges are in the source file
+    /// of the diagnostic, and can be converted with [`Self::offset_to_line_column`].
+    pub fn fix_its(&self) -> &[FixIt] {
+        &self.fix_its
+    }
+
+    /// Returns a tuple with the line (starting at 1) and column number (starting at 1) of
+    /// an offset in the source file of this diagnostic
+    ///
+    /// Returns (0, 0) if the source file is unknown
+    pub fn offset_to_line_column(&self, offset: usize) -> (usize, usize) {
+        self.span.source_file.as_ref().map_or((0, 0), |sf| sf.line_column(offset))
+    }
 }
 
 impl std::fmt::Display for Diagnostic {
@@ -323,7 +372,19 @@ pub fn push_diagnostic_with_span(
             "Error message should not end with a period: ({:?})",
             message
         );
-        self.inner.push(Diagnostic { message, span, level });
+        self.inner.push(Diagnostic { message, span, level, fix_its: Vec::new() });
+    }
+
+    /// Pushes a diagnostic with suggestions to fix it
+    pub fn push_diagnostic_with_fix_its(
+        &mut self,
+        message: String,
+        source: &dyn Spanned,
+        level: DiagnosticLevel,
+        fix_its: Vec<FixIt>,
+    ) {
+        self.push_diagnostic(message, source, level);
+        self.inner.last_mut().unwrap().fix_its = fix_its;
     }
     pub fn push_error_with_span(&mut self, message: String, span: SourceLocation) {
         self.push_diagnostic_with_span(message, span, DiagnosticLevel::Error)
@@ -357,6 +418,22 @@ pub fn push_property_deprecation_warning(
         )
     }
 
+    /// Same as [`Self::push_property_deprecation_warning`] for a token that is the name of the
+    /// deprecated property, with a fix-it to rename it
+    pub fn push_property_deprecation_warning_for_token(
+        &mut self,
+        old_property: &str,
+        new_property: &str,
+        token: &crate::parser::SyntaxToken,
+    ) {
+        self.push_property_deprecation_warning(old_property, new_property, token);
+        self.inner.last_mut().unwrap().fix_its.push(FixIt::replace(
+            format!("Rename to '{new_property}'"),
+            token.text_range(),
+            new_property,
+        ));
+    }
+
     /// Return true if there is at least one compilation error for this file
     pub fn has_errors(&self) -> bool {
         self.inner.iter().any(|diag| diag.level == DiagnosticLevel::Error)
@@ -388,6 +465,7 @@ fn call_diagnostics<Output>(
             .inner
             .into_iter()
             .filter_map(|d| {
+                let mut file_span = None;
                 let spans = if !d.span.span.is_valid() {
                     vec![]
                 } else if let Some(sf) = &d.span.source_file {
@@ -404,9 +482,10 @@ fn call_diagnostics<Output>(
                             sf.source.clone().unwrap_or_default(),
                         )
                     });
-                    let file_span = file.span;
+                    file_span = Some(file.span);
                     let s = codemap_diagnostic::SpanLabel {
-                        span: file_span
+                        span: file
+                            .span
                             .subspan(d.span.span.offset as u64, d.span.span.offset as u64),
                         style: codemap_diagnostic::SpanStyle::Primary,
                         label: None,
@@ -415,13 +494,33 @@ fn call_diagnostics<Output>(
                 } else {
                     vec![]
                 };
-                Some(codemap_diagnostic::Diagnostic {
+                // Each fix-it is shown as a help message pointing to the text to replace
+                let fix_its = d.fix_its.into_iter().map(move |fix_it| {
+                    let spans = file_span
+                        .map(|file_span| codemap_diagnostic::SpanLabel {
+                            span: file_span
+                                .subspan(fix_it.range.start as u64, fix_it.range.end as u64),
+                            style: codemap_diagnostic::SpanStyle::Primary,
+                            label: None,
+                        })
+                        .into_iter()
+                        .collect();
+                    codemap_diagnostic::Diagnostic {
+                        level: codemap_diagnostic::Level::Help,
+                        message: fix_it.title,
+                        code: None,
+                        spans,
+                    }
+                });
+                let diag = codemap_diagnostic::Diagnostic {
                     level: d.level.into(),
                     message: d.message,
                     code: None,
                     spans,
-                })
+                };
+                Some(std::iter::once(diag).chain(fix_its))
             })
+            .flatten()
             .collect();
 
         if !diags.is_empty() {
@@ -599,4 +698,50 @@ fn test_source_file_offset_line_column_mapping() {
             }
         }
     }
+
+    #[test]
+    fn fix_its() {
+        let source = r#"
+export component Foo inherits Window {
+    color: red;
+    function f() -> int { 42 }
+    property <int> a: f;
+    property <string> b: title;
+}
+"#;
+        let mut compiler_config =
+            crate::CompilerConfiguration::new(crate::generator::OutputFormat::Interpreter);
+        compiler_config.style = Some("fluent".into());
+        let mut diag = BuildDiagnostics::default();
+        let node = crate::parser::parse(
+            source.into(),
+            Some(std::path::Path::new("main.slint")),
+            &mut diag,
+        );
+        let (_, diag, _) =
+            spin_on::spin_on(crate::compile_syntax_node(node, diag, compiler_config));
+
+        let mut fixed = source.to_string();
+        let mut fix_its = diag.iter().flat_map(|d| d.fix_its()).collect::<Vec<_>>();
+        assert_eq!(
+            fix_its.iter().map(|f| f.title.as_str()).collect::<Vec<_>>(),
+            ["Rename to 'background'", "Add '()'", "Replace with 'self.title'"]
+        );
+        fix_its.sort_by_key(|f| std::cmp::Reverse(f.range.start));
+        for fix_it in fix_its {
+            fixed.replace_range(fix_it.range.clone(), &fix_it.replacement);
+        }
+        assert!(fixed.contains("    background: red;\n"), "{fixed}");
+        assert!(fixed.contains("property <int> a: f();"), "{fixed}");
+        assert!(fixed.contains("property <string> b: self.title;"), "{fixed}");
+
+        let d = diag.iter().find(|d| !d.fix_its().is_empty()).unwrap();
+        assert_eq!(d.offset_to_line_column(d.fix_its()[0].range.start), (3, 5));
+
+        #[cfg(feature = "display-diagnostics")]
+        {
+            let printed = diag.diagnostics_as_string();
+            assert!(printed.contains("help: Rename to 'background'"), "{printed}");
+        }
+    }
 }
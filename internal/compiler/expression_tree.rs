@@ -11,13 +11,30 @@
 use core::cell::RefCell;
 use smol_str::{format_smolstr, SmolStr};
 use std::cell::Cell;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::rc::{Rc, Weak};
 
 // FIXME remove the pub
 pub use crate::namedreference::NamedReference;
 pub use crate::passes::resolving;
 
+thread_local! {
+    static LOCAL_VARIABLE_COUNTER: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Returns a name for a local variable introduced by the compiler, made of the prefix and a
+/// number that is unique within the current compilation
+pub(crate) fn unique_local_variable_name(prefix: &str) -> SmolStr {
+    let count = LOCAL_VARIABLE_COUNTER.with(|c| c.replace(c.get() + 1));
+    format_smolstr!("{prefix}{count}")
+}
+
+/// Restart the numbering of [`unique_local_variable_name`], so that the generated code
+/// doesn't depend on what was compiled before in the same process
+pub(crate) fn reset_local_variable_names() {
+    LOCAL_VARIABLE_COUNTER.with(|c| c.set(0));
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 /// A function built into the run-time
 pub enum BuiltinFunction {
@@ -61,21 +78,45 @@ pub enum BuiltinFunction {
     ColorMix,
     ColorWithAlpha,
     ImageSize,
+    ImageWithSvgFill,
+    ImageWithSvgStroke,
+    ImageWithSvgVariable,
     ArrayLength,
     Rgb,
     Hsv,
     ColorScheme,
     SupportsNativeMenuBar,
     SetupNativeMenuBar,
+    SetupWindowMenu,
     Use24HourFormat,
     MonthDayCount,
     MonthOffset,
+    FirstDayOfWeek,
     FormatDate,
     DateNow,
     ValidDate,
     ParseDate,
+    ParseHexColor,
+    ChartLineCommands,
+    ChartPieSliceCommands,
+    ChartPieSliceAt,
+    PathInterpolate,
+    PathUnion,
+    PathIntersection,
+    PathDifference,
+    FormatNumber,
+    ValidNumber,
+    ParseNumber,
+    ClipboardSetText,
+    ClipboardText,
+    ClipboardSetImage,
+    ClipboardImage,
+    ClipboardSetHtml,
+    ClipboardHtml,
     TextInputFocused,
     SetTextInputFocused,
+    WindowToasts,
+    ActivateToast,
     ImplicitLayoutInfo(Orientation),
     ItemAbsolutePosition,
     RegisterCustomFontByPath,
@@ -107,6 +148,9 @@ pub enum BuiltinMacroFunction {
     /// transform the argument so it is always rgb(r, g, b, a) with r, g, b between 0 and 255.
     Rgb,
     Hsv,
+    /// `display-p3(r, g, b, a)` with number literals between 0 and 1 or percentages.
+    /// Converted to a color literal in sRGB at compile time.
+    DisplayP3,
     /// transform `debug(a, b, c)` into debug `a + " " + b + " " + c`
     Debug,
 }
@@ -207,6 +251,9 @@ pub fn ty(&self, function: &BuiltinFunction) -> Rc<Function> {
         node: None,
         rust_attributes: None,
     })),
+    ImageWithSvgFill: (Type::Image, Type::String, Type::Color) -> Type::Image,
+    ImageWithSvgStroke: (Type::Image, Type::String, Type::Color) -> Type::Image,
+    ImageWithSvgVariable: (Type::Image, Type::String, Type::Color) -> Type::Image,
     ArrayLength: (Type::Model) -> Type::Int32,
     Rgb: (Type::Int32, Type::Int32, Type::Int32, Type::Float32) -> Type::Color,
     Hsv: (Type::Float32, Type::Float32, Type::Float32, Type::Float32) -> Type::Color,
@@ -216,14 +263,38 @@ pub fn ty(&self, function: &BuiltinFunction) -> Rc<Function> {
     SupportsNativeMenuBar: () -> Type::Bool,
     // entries, sub-menu, activate. But the types here are not accurate.
     SetupNativeMenuBar: (Type::Model, typeregister::noarg_callback_type(), typeregister::noarg_callback_type()) -> Type::Void,
+    // Same arguments as SetupNativeMenuBar
+    SetupWindowMenu: (Type::Model, typeregister::noarg_callback_type(), typeregister::noarg_callback_type()) -> Type::Void,
     MonthDayCount: (Type::Int32, Type::Int32) -> Type::Int32,
     MonthOffset: (Type::Int32, Type::Int32) -> Type::Int32,
+    FirstDayOfWeek: () -> Type::Int32,
     FormatDate: (Type::String, Type::Int32, Type::Int32, Type::Int32) -> Type::String,
     TextInputFocused: () -> Type::Bool,
     DateNow: () -> Type::Array(Rc::new(Type::Int32)),
     ValidDate: (Type::String, Type::String) -> Type::Bool,
     ParseDate: (Type::String, Type::String) -> Type::Array(Rc::new(Type::Int32)),
+    ParseHexColor: (Type::String, Type::Color) -> Type::Color,
+    // values, minimum, maximum, width, height, area
+    ChartLineCommands: (Type::Array(Rc::new(Type::Float32)), Type::Float32, Type::Float32, Type::Float32, Type::Float32, Type::Bool) -> Type::String,
+    ChartPieSliceCommands: (Type::Array(Rc::new(Type::Float32)), Type::Int32) -> Type::String,
+    ChartPieSliceAt: (Type::Array(Rc::new(Type::Float32)), Type::Float32) -> Type::Int32,
+    PathInterpolate: (Type::String, Type::String, Type::Float32) -> Type::String,
+    PathUnion: (Type::String, Type::String) -> Type::String,
+    PathIntersection: (Type::String, Type::String) -> Type::String,
+    PathDifference: (Type::String, Type::String) -> Type::String,
+    FormatNumber: (Type::Float32, Type::Int32, Type::Bool) -> Type::String,
+    ValidNumber: (Type::String) -> Type::Bool,
+    ParseNumber: (Type::String) -> Type::Float32,
+    ClipboardSetText: (Type::String) -> Type::Void,
+    ClipboardText: () -> Type::String,
+    ClipboardSetImage: (Type::Image) -> Type::Void,
+    ClipboardImage: () -> Type::Image,
+    // html, alt-text
+    ClipboardSetHtml: (Type::String, Type::String) -> Type::Void,
+    ClipboardHtml: () -> Type::String,
     SetTextInputFocused: (Type::Bool) -> Type::Void,
+    WindowToasts: () -> Type::Array(Rc::new(typeregister::toast_entry_type())),
+    ActivateToast: (Type::Int32) -> Type::Void,
     ItemAbsolutePosition: (Type::ElementReference) -> typeregister::logical_point_type(),
     RegisterCustomFontByPath: (Type::String) -> Type::Void,
     RegisterCustomFontByMemory: (Type::Int32) -> Type::Void,
@@ -251,8 +322,10 @@ fn is_const(&self) -> bool {
             BuiltinFunction::ColorScheme => false,
             BuiltinFunction::SupportsNativeMenuBar => false,
             BuiltinFunction::SetupNativeMenuBar => false,
+            BuiltinFunction::SetupWindowMenu => false,
             BuiltinFunction::MonthDayCount => false,
             BuiltinFunction::MonthOffset => false,
+            BuiltinFunction::FirstDayOfWeek => false,
             BuiltinFunction::FormatDate => false,
             BuiltinFunction::DateNow => false,
             BuiltinFunction::ValidDate => false,
@@ -297,11 +370,33 @@ fn is_const(&self) -> bool {
             BuiltinFunction::ImageSize => true,
             #[cfg(target_arch = "wasm32")]
             BuiltinFunction::ImageSize => false,
+            BuiltinFunction::ImageWithSvgFill
+            | BuiltinFunction::ImageWithSvgStroke
+            | BuiltinFunction::ImageWithSvgVariable => false,
             BuiltinFunction::ArrayLength => true,
             BuiltinFunction::Rgb => true,
             BuiltinFunction::Hsv => true,
+            BuiltinFunction::ParseHexColor => true,
+            BuiltinFunction::ChartLineCommands
+            | BuiltinFunction::ChartPieSliceCommands
+            | BuiltinFunction::ChartPieSliceAt => false,
+            BuiltinFunction::PathInterpolate
+            | BuiltinFunction::PathUnion
+            | BuiltinFunction::PathIntersection
+            | BuiltinFunction::PathDifference => false,
+            // They depend on the locale
+            BuiltinFunction::FormatNumber
+            | BuiltinFunction::ValidNumber
+            | BuiltinFunction::ParseNumber => false,
+            BuiltinFunction::ClipboardSetText
+            | BuiltinFunction::ClipboardText
+            | BuiltinFunction::ClipboardSetImage
+            | BuiltinFunction::ClipboardImage
+            | BuiltinFunction::ClipboardSetHtml
+            | BuiltinFunction::ClipboardHtml => false,
             BuiltinFunction::SetTextInputFocused => false,
             BuiltinFunction::TextInputFocused => false,
+            BuiltinFunction::WindowToasts | BuiltinFunction::ActivateToast => false,
             BuiltinFunction::ImplicitLayoutInfo(_) => false,
             BuiltinFunction::ItemAbsolutePosition => true,
             BuiltinFunction::RegisterCustomFontByPath
@@ -322,8 +417,10 @@ pub fn is_pure(&self) -> bool {
             BuiltinFunction::ColorScheme => true,
             BuiltinFunction::SupportsNativeMenuBar => true,
             BuiltinFunction::SetupNativeMenuBar => false,
+            BuiltinFunction::SetupWindowMenu => false,
             BuiltinFunction::MonthDayCount => true,
             BuiltinFunction::MonthOffset => true,
+            BuiltinFunction::FirstDayOfWeek => true,
             BuiltinFunction::FormatDate => true,
             BuiltinFunction::DateNow => true,
             BuiltinFunction::ValidDate => true,
@@ -361,13 +458,35 @@ pub fn is_pure(&self) -> bool {
             | BuiltinFunction::ColorMix
             | BuiltinFunction::ColorWithAlpha => true,
             BuiltinFunction::ImageSize => true,
+            BuiltinFunction::ImageWithSvgFill
+            | BuiltinFunction::ImageWithSvgStroke
+            | BuiltinFunction::ImageWithSvgVariable => true,
             BuiltinFunction::ArrayLength => true,
             BuiltinFunction::Rgb => true,
             BuiltinFunction::Hsv => true,
+            BuiltinFunction::ParseHexColor => true,
+            BuiltinFunction::ChartLineCommands
+            | BuiltinFunction::ChartPieSliceCommands
+            | BuiltinFunction::ChartPieSliceAt => true,
+            BuiltinFunction::PathInterpolate
+            | BuiltinFunction::PathUnion
+            | BuiltinFunction::PathIntersection
+            | BuiltinFunction::PathDifference => true,
+            BuiltinFunction::FormatNumber
+            | BuiltinFunction::ValidNumber
+            | BuiltinFunction::ParseNumber => true,
+            BuiltinFunction::ClipboardText
+            | BuiltinFunction::ClipboardImage
+            | BuiltinFunction::ClipboardHtml => true,
+            BuiltinFunction::ClipboardSetText
+            | BuiltinFunction::ClipboardSetImage
+            | BuiltinFunction::ClipboardSetHtml => false,
             BuiltinFunction::ImplicitLayoutInfo(_) => true,
             BuiltinFunction::ItemAbsolutePosition => true,
             BuiltinFunction::SetTextInputFocused => false,
             BuiltinFunction::TextInputFocused => true,
+            BuiltinFunction::WindowToasts => true,
+            BuiltinFunction::ActivateToast => false,
             BuiltinFunction::RegisterCustomFontByPath
             | BuiltinFunction::RegisterCustomFontByMemory
             | BuiltinFunction::RegisterBitmapFont => false,
@@ -643,7 +762,7 @@ pub enum Expression {
     },
     Struct {
         ty: Type,
-        values: HashMap<SmolStr, Expression>,
+        values: BTreeMap<SmolStr, Expression>,
     },
 
     PathData(Path),
@@ -661,6 +780,17 @@ pub enum Expression {
         stops: Vec<(Expression, Expression)>,
     },
 
+    ConicGradient {
+        from_angle: Box<Expression>,
+        /// First expression in the tuple is a color, second expression is the stop position as a fraction of a turn
+        stops: Vec<(Expression, Expression)>,
+    },
+
+    FourCornerGradient {
+        /// The colors of the top-left, top-right, bottom-right, and bottom-left corners
+        colors: Vec<Expression>,
+    },
+
     EnumerationValue(EnumerationValue),
 
     ReturnStatement(Option<Box<Expression>>),
@@ -802,6 +932,8 @@ pub fn ty(&self) -> Type {
             Expression::EasingCurve(_) => Type::Easing,
             Expression::LinearGradient { .. } => Type::Brush,
             Expression::RadialGradient { .. } => Type::Brush,
+            Expression::ConicGradient { .. } => Type::Brush,
+            Expression::FourCornerGradient { .. } => Type::Brush,
             Expression::EnumerationValue(value) => Type::Enumeration(value.enumeration.clone()),
             // invalid because the expression is unreachable
             Expression::ReturnStatement(_) => Type::Invalid,
@@ -899,6 +1031,18 @@ pub fn visit(&self, mut visitor: impl FnMut(&Self)) {
                     visitor(s);
                 }
             }
+            Expression::ConicGradient { from_angle, stops } => {
+                visitor(from_angle);
+                for (c, s) in stops {
+                    visitor(c);
+                    visitor(s);
+                }
+            }
+            Expression::FourCornerGradient { colors } => {
+                for c in colors {
+                    visitor(c);
+                }
+            }
             Expression::EnumerationValue(_) => {}
             Expression::ReturnStatement(expr) => {
                 expr.as_deref().map(visitor);
@@ -1004,6 +1148,18 @@ pub fn visit_mut(&mut self, mut visitor: impl FnMut(&mut Self)) {
                     visitor(s);
                 }
             }
+            Expression::ConicGradient { from_angle, stops } => {
+                visitor(from_angle);
+                for (c, s) in stops {
+                    visitor(c);
+                    visitor(s);
+                }
+            }
+            Expression::FourCornerGradient { colors } => {
+                for c in colors {
+                    visitor(c);
+                }
+            }
             Expression::EnumerationValue(_) => {}
             Expression::ReturnStatement(expr) => {
                 expr.as_deref_mut().map(visitor);
@@ -1087,6 +1243,11 @@ pub fn is_constant(&self) -> bool {
             Expression::RadialGradient { stops } => {
                 stops.iter().all(|(c, s)| c.is_constant() && s.is_constant())
             }
+            Expression::ConicGradient { from_angle, stops } => {
+                from_angle.is_constant()
+                    && stops.iter().all(|(c, s)| c.is_constant() && s.is_constant())
+            }
+            Expression::FourCornerGradient { colors } => colors.iter().all(|c| c.is_constant()),
             Expression::EnumerationValue(_) => true,
             Expression::ReturnStatement(expr) => {
                 expr.as_ref().map_or(true, |expr| expr.is_constant())
@@ -1118,7 +1279,10 @@ pub fn maybe_convert_to(
         } else if ty.can_convert(&target_type) {
             let from = match (ty, &target_type) {
                 (Type::Brush, Type::Color) => match self {
-                    Expression::LinearGradient { .. } | Expression::RadialGradient { .. } => {
+                    Expression::LinearGradient { .. }
+                    | Expression::RadialGradient { .. }
+                    | Expression::ConicGradient { .. }
+                    | Expression::FourCornerGradient { .. } => {
                         let message = format!("Narrowing conversion from {0} to {1}. This can lead to unexpected behavior because the {0} is a gradient", Type::Brush, Type::Color);
                         diag.push_warning(message, node);
                         self
@@ -1134,7 +1298,7 @@ pub fn maybe_convert_to(
                     if left.fields != right.fields =>
                 {
                     if let Expression::Struct { mut values, .. } = self {
-                        let mut new_values = HashMap::new();
+                        let mut new_values = BTreeMap::new();
                         for (key, ty) in &right.fields {
                             let (key, expression) = values.remove_entry(key).map_or_else(
                                 || (key.clone(), Expression::default_value_for_type(ty)),
@@ -1144,13 +1308,8 @@ pub fn maybe_convert_to(
                         }
                         return Expression::Struct { values: new_values, ty: target_type };
                     }
-                    static COUNT: std::sync::atomic::AtomicUsize =
-                        std::sync::atomic::AtomicUsize::new(0);
-                    let var_name = format_smolstr!(
-                        "tmpobj_conv_{}",
-                        COUNT.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
-                    );
-                    let mut new_values = HashMap::new();
+                    let var_name = unique_local_variable_name("tmpobj_conv_");
+                    let mut new_values = BTreeMap::new();
                     for (key, ty) in &right.fields {
                         let expression = if left.fields.contains_key(key) {
                             Expression::StructFieldAccess {
@@ -1245,7 +1404,7 @@ pub fn maybe_convert_to(
         {
             // Also special case struct literal in case they contain array literal
             let mut fields = struct_type.fields.clone();
-            let mut new_values = HashMap::new();
+            let mut new_values = BTreeMap::new();
             for (f, v) in values {
                 if let Some(t) = fields.remove(f) {
                     new_values.insert(f.clone(), v.clone().maybe_convert_to(t, node, diag));
@@ -1711,6 +1870,27 @@ pub fn pretty_print(f: &mut dyn std::fmt::Write, expression: &Expression) -> std
             }
             write!(f, ")")
         }
+        Expression::ConicGradient { from_angle, stops } => {
+            write!(f, "@conic-gradient(from ")?;
+            pretty_print(f, from_angle)?;
+            for (c, s) in stops {
+                write!(f, ", ")?;
+                pretty_print(f, c)?;
+                write!(f, "  ")?;
+                pretty_print(f, s)?;
+            }
+            write!(f, ")")
+        }
+        Expression::FourCornerGradient { colors } => {
+            write!(f, "@four-corner-gradient(")?;
+            for (i, c) in colors.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                pretty_print(f, c)?;
+            }
+            write!(f, ")")
+        }
         Expression::EnumerationValue(e) => match e.enumeration.values.get(e.value) {
             Some(val) => write!(f, "{}.{}", e.enumeration.name, val),
             None => write!(f, "{}.{}", e.enumeration.name, e.value),
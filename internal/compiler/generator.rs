@@ -25,6 +25,10 @@
 #[cfg(feature = "rust")]
 pub mod rust;
 
+pub mod docs;
+pub mod interface;
+pub mod typescript;
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum OutputFormat {
     #[cfg(feature = "cpp")]
@@ -33,6 +37,12 @@ pub enum OutputFormat {
     Rust,
     Interpreter,
     Llr,
+    /// TypeScript definitions for the Node.js API
+    TypeScript,
+    /// Documentation of the exported components as JSON
+    DocsJson,
+    /// Documentation of the exported components as a HTML page
+    DocsHtml,
 }
 
 impl OutputFormat {
@@ -44,6 +54,9 @@ pub fn guess_from_extension(path: &std::path::Path) -> Option<Self> {
             }
             #[cfg(feature = "rust")]
             Some("rs") => Some(Self::Rust),
+            Some("ts") => Some(Self::TypeScript),
+            Some("json") => Some(Self::DocsJson),
+            Some("html") | Some("htm") => Some(Self::DocsHtml),
             _ => None,
         }
     }
@@ -58,6 +71,9 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             #[cfg(feature = "rust")]
             "rust" => Ok(Self::Rust),
             "llr" => Ok(Self::Llr),
+            "typescript" => Ok(Self::TypeScript),
+            "docs-json" => Ok(Self::DocsJson),
+            "docs-html" => Ok(Self::DocsHtml),
             _ => Err(format!("Unknown output format {}", s)),
         }
     }
@@ -95,6 +111,15 @@ pub fn generate(
             crate::llr::pretty_print::pretty_print(&root, &mut output).unwrap();
             write!(destination, "{output}")?;
         }
+        OutputFormat::TypeScript => {
+            write!(destination, "{}", typescript::generate(doc))?;
+        }
+        OutputFormat::DocsJson => {
+            write!(destination, "{}", docs::generate_json(doc))?;
+        }
+        OutputFormat::DocsHtml => {
+            write!(destination, "{}", docs::generate_html(doc))?;
+        }
     }
     Ok(())
 }
@@ -548,3 +573,46 @@ fn case_conversions() {
     assert_eq!(to_kebab_case("HelloWorld"), "hello-world");
     assert_eq!(to_pascal_case("hello-world"), "HelloWorld");
 }
+
+#[cfg(feature = "rust")]
+#[test]
+fn deterministic_output() {
+    let source = r#"
+        struct Item { title: string, done: bool, count: int }
+        global Logic {
+            in-out property <Item> current: { title: "a", done: true, count: 42 };
+        }
+        component Row inherits Rectangle {
+            in property <Item> item;
+            Text { text: item.title; }
+        }
+        export component Main inherits Window {
+            in-out property <[Item]> items: [{ title: "x" }, { title: "y", done: true }];
+            for item in items: Row { item: item; }
+            if Logic.current.done: Row { item: { title: Logic.current.title, count: 1 }; }
+            VerticalLayout {
+                Row { item: Logic.current; }
+                Text { text: "\{Logic.current.count}"; }
+            }
+        }
+    "#;
+    let generate_rust = || {
+        let mut diag = crate::diagnostics::BuildDiagnostics::default();
+        let node = crate::parser::parse(
+            source.into(),
+            Some(std::path::Path::new("main.slint")),
+            &mut diag,
+        );
+        let mut config = crate::CompilerConfiguration::new(OutputFormat::Rust);
+        config.style = Some("fluent".into());
+        let (doc, diag, _) =
+            spin_on::spin_on(crate::compile_syntax_node(node, diag, config.clone()));
+        assert!(!diag.has_errors(), "{:?}", diag.to_string_vec());
+        let mut output = Vec::new();
+        generate(OutputFormat::Rust, &mut output, &doc, &config).unwrap();
+        String::from_utf8(output).unwrap()
+    };
+    let first = generate_rust();
+    assert_eq!(first, generate_rust());
+    assert_eq!(first, generate_rust());
+}
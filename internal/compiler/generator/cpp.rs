@@ -708,6 +708,15 @@ pub fn generate(
         }
     }
 
+    if let Some(window) = find_window_in_component(doc) {
+        let mut diag = crate::diagnostics::BuildDiagnostics::default();
+        diag.push_error(
+            "Windows declared inside a component are not supported in C++ yet".into(),
+            &*window.borrow(),
+        );
+        return Err(std::io::Error::other(diag.to_string_vec().join("\n")));
+    }
+
     let llr = llr::lower_to_item_tree::lower_to_item_tree(&doc, compiler_config)?;
 
     #[cfg(feature = "bundle-translations")]
@@ -874,6 +883,40 @@ pub fn generate(
     Ok(file)
 }
 
+/// Returns the first `Window` declared inside a component, which the C++ run-time library can't show yet
+fn find_window_in_component(doc: &Document) -> Option<crate::object_tree::ElementRc> {
+    fn find_in_component(
+        component: &crate::object_tree::Component,
+    ) -> Option<crate::object_tree::ElementRc> {
+        for popup in component.popup_windows.borrow().iter() {
+            if popup.is_window {
+                return Some(popup.component.root_element.clone());
+            }
+            if let Some(window) = find_in_component(&popup.component) {
+                return Some(window);
+            }
+        }
+        let mut found = None;
+        crate::object_tree::recurse_elem(&component.root_element, &(), &mut |elem, _| {
+            if found.is_some() || elem.borrow().repeated.is_none() {
+                return;
+            }
+            if let crate::langtype::ElementType::Component(base) = &elem.borrow().base_type {
+                found = find_in_component(base);
+            }
+        });
+        found
+    }
+
+    let mut found = None;
+    doc.visit_all_used_components(|component| {
+        if found.is_none() {
+            found = find_in_component(component);
+        }
+    });
+    found
+}
+
 fn embed_resource(
     resource: &crate::embedded_resources::EmbeddedResources,
     path: &SmolStr,
@@ -2086,6 +2129,13 @@ fn generate_sub_component(
             let vp_w = access_member(&listview.viewport_width, &ctx);
             let lv_w = access_member(&listview.listview_width, &ctx);
 
+            if let Some(scroll_to_row) = &listview.scroll_to_row {
+                properties_init_code.push(format!(
+                    "{}.set_handler([self](int row) {{ self->{repeater_id}.scroll_to_row(row); }});",
+                    access_member(scroll_to_row, &ctx)
+                ));
+            }
+
             format!(
                 "self->{}.ensure_updated_listview(self, &{}, &{}, &{}, {}.get(), {}.get());",
                 repeater_id, vp_w, vp_h, vp_y, lv_w, lv_h
@@ -3361,6 +3411,24 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 stops_it.join(", "), stops.len()
             )
         }
+        Expression::ConicGradient{ from_angle, stops } => {
+            let from_angle = compile_expression(from_angle, ctx);
+            let mut stops_it = stops.iter().map(|(color, stop)| {
+                let color = compile_expression(color, ctx);
+                let position = compile_expression(stop, ctx);
+                format!("slint::private_api::GradientStop{{ {}, float({}), }}", color, position)
+            });
+            format!(
+                "[&] {{ const slint::private_api::GradientStop stops[] = {{ {} }}; return slint::Brush(slint::private_api::ConicGradientBrush({}, stops, {})); }}()",
+                stops_it.join(", "), from_angle, stops.len()
+            )
+        }
+        Expression::FourCornerGradient{ colors } => {
+            format!(
+                "slint::Brush(slint::private_api::FourCornerGradientBrush({}))",
+                colors.iter().map(|color| compile_expression(color, ctx)).join(", ")
+            )
+        }
         Expression::EnumerationValue(value) => {
             let prefix = if value.enumeration.node.is_some() { "" } else {"slint::cbindgen_private::"};
             format!(
@@ -3577,6 +3645,15 @@ fn compile_builtin_function_call(
         BuiltinFunction::ImageSize => {
             format!("{}.size()", a.next().unwrap())
         }
+        BuiltinFunction::ImageWithSvgFill => {
+            format!("{}.with_svg_fill({}, {})", a.next().unwrap(), a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::ImageWithSvgStroke => {
+            format!("{}.with_svg_stroke({}, {})", a.next().unwrap(), a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::ImageWithSvgVariable => {
+            format!("{}.with_svg_variable({}, {})", a.next().unwrap(), a.next().unwrap(), a.next().unwrap())
+        }
         BuiltinFunction::ArrayLength => {
             format!("slint::private_api::model_length({})", a.next().unwrap())
         }
@@ -3616,6 +3693,10 @@ fn compile_builtin_function_call(
                 [](auto &self, const slint::cbindgen_private::MenuEntry *parent){{ return parent ? {sub_menu}.call(*parent) : {entries}; }},
                 [](auto &self, const slint::cbindgen_private::MenuEntry &entry){{ {activated}.call(entry); }})")
         }
+        BuiltinFunction::SetupWindowMenu => {
+            // The menu of the window is only used by the tray icon, which isn't available in C++
+            String::new()
+        }
         BuiltinFunction::Use24HourFormat => {
             format!("slint::cbindgen_private::slint_date_time_use_24_hour_format()")
         }
@@ -3625,6 +3706,9 @@ fn compile_builtin_function_call(
         BuiltinFunction::MonthOffset => {
             format!("slint::cbindgen_private::slint_date_time_month_offset({}, {})", a.next().unwrap(), a.next().unwrap())
         }
+        BuiltinFunction::FirstDayOfWeek => {
+            "slint::cbindgen_private::slint_date_time_first_day_of_week()".into()
+        }
         BuiltinFunction::FormatDate => {
             format!("[](const auto &format, int d, int m, int y) {{ slint::SharedString out; slint::cbindgen_private::slint_date_time_format_date(&format, d, m, y, &out); return out; }}({}, {}, {}, {})",
                 a.next().unwrap(), a.next().unwrap(), a.next().unwrap(), a.next().unwrap()
@@ -3645,12 +3729,96 @@ fn compile_builtin_function_call(
                 a.next().unwrap(), a.next().unwrap()
             )
         }
+        BuiltinFunction::ParseHexColor => {
+            format!(
+                "[](const auto &text, const slint::Color &fallback) {{ uint32_t argb = 0; return slint::cbindgen_private::slint_color_parse_hex(&text, &argb) ? slint::Color::from_argb_encoded(argb) : fallback; }}({}, {})",
+                a.next().unwrap(), a.next().unwrap()
+            )
+        }
+        BuiltinFunction::ChartLineCommands => {
+            format!(
+                "[](const auto &values, float min, float max, float w, float h, bool area) {{ auto v = slint::private_api::model_to_shared_vector(values); slint::SharedString out; slint::cbindgen_private::slint_chart_line_commands(&v, min, max, w, h, area, &out); return out; }}({}, {}, {}, {}, {}, {})",
+                a.next().unwrap(), a.next().unwrap(), a.next().unwrap(), a.next().unwrap(), a.next().unwrap(), a.next().unwrap()
+            )
+        }
+        BuiltinFunction::ChartPieSliceCommands => {
+            format!(
+                "[](const auto &values, int index) {{ auto v = slint::private_api::model_to_shared_vector(values); slint::SharedString out; slint::cbindgen_private::slint_chart_pie_slice_commands(&v, index, &out); return out; }}({}, {})",
+                a.next().unwrap(), a.next().unwrap()
+            )
+        }
+        BuiltinFunction::ChartPieSliceAt => {
+            format!(
+                "[](const auto &values, float angle) {{ auto v = slint::private_api::model_to_shared_vector(values); return slint::cbindgen_private::slint_chart_pie_slice_at(&v, angle); }}({}, {})",
+                a.next().unwrap(), a.next().unwrap()
+            )
+        }
+        BuiltinFunction::PathInterpolate => {
+            format!(
+                "[](const auto &from, const auto &to, float t) {{ slint::SharedString out; slint::cbindgen_private::slint_path_interpolate(&from, &to, t, &out); return out; }}({}, {}, {})",
+                a.next().unwrap(), a.next().unwrap(), a.next().unwrap()
+            )
+        }
+        BuiltinFunction::PathUnion | BuiltinFunction::PathIntersection | BuiltinFunction::PathDifference => {
+            let operation = match function {
+                BuiltinFunction::PathUnion => "union",
+                BuiltinFunction::PathIntersection => "intersection",
+                _ => "difference",
+            };
+            format!(
+                "[](const auto &lhs, const auto &rhs) {{ slint::SharedString out; slint::cbindgen_private::slint_path_{operation}(&lhs, &rhs, &out); return out; }}({}, {})",
+                a.next().unwrap(), a.next().unwrap()
+            )
+        }
+        BuiltinFunction::FormatNumber => {
+            format!(
+                "[](double value, int decimals, bool group) {{ slint::SharedString out; slint::cbindgen_private::slint_number_format(value, decimals, group, &out); return out; }}({}, {}, {})",
+                a.next().unwrap(), a.next().unwrap(), a.next().unwrap()
+            )
+        }
+        BuiltinFunction::ValidNumber => {
+            format!(
+                "[](const auto &text) {{ double value = 0; return slint::cbindgen_private::slint_number_parse(&text, &value); }}({})",
+                a.next().unwrap()
+            )
+        }
+        BuiltinFunction::ParseNumber => {
+            format!(
+                "[](const auto &text) {{ double value = 0; slint::cbindgen_private::slint_number_parse(&text, &value); return float(value); }}({})",
+                a.next().unwrap()
+            )
+        }
+        BuiltinFunction::ClipboardSetText => {
+            format!("[](const slint::SharedString &text) {{ slint::cbindgen_private::slint_clipboard_set_text(&text); }}({})", a.next().unwrap())
+        }
+        BuiltinFunction::ClipboardText => {
+            "[] { slint::SharedString out; slint::cbindgen_private::slint_clipboard_text(&out); return out; }()".into()
+        }
+        BuiltinFunction::ClipboardSetImage => {
+            format!("[](const slint::Image &image) {{ slint::cbindgen_private::slint_clipboard_set_image(&image); }}({})", a.next().unwrap())
+        }
+        BuiltinFunction::ClipboardImage => {
+            "[] { slint::Image out; slint::cbindgen_private::slint_clipboard_image(&out); return out; }()".into()
+        }
+        BuiltinFunction::ClipboardSetHtml => {
+            format!(
+                "[](const slint::SharedString &html, const slint::SharedString &alt_text) {{ slint::cbindgen_private::slint_clipboard_set_html(&html, &alt_text); }}({}, {})",
+                a.next().unwrap(), a.next().unwrap()
+            )
+        }
+        BuiltinFunction::ClipboardHtml => {
+            "[] { slint::SharedString out; slint::cbindgen_private::slint_clipboard_html(&out); return out; }()".into()
+        }
         BuiltinFunction::SetTextInputFocused => {
             format!("{}.set_text_input_focused({})", access_window_field(ctx), a.next().unwrap())
         }
         BuiltinFunction::TextInputFocused => {
             format!("{}.text_input_focused()", access_window_field(ctx))
         }
+        BuiltinFunction::WindowToasts | BuiltinFunction::ActivateToast => {
+            // The toast layer is only added for the Rust generator and the interpreter
+            panic!("internal error: toasts are not supported in C++")
+        }
         BuiltinFunction::ShowPopupWindow => {
             if let [llr::Expression::NumberLiteral(popup_index), close_policy, llr::Expression::PropertyReference(parent_ref)] =
                 arguments
@@ -3668,6 +3836,7 @@ fn compile_builtin_function_call(
                 let window = access_window_field(ctx);
                 let current_sub_component = parent_ctx.current_sub_component.unwrap();
                 let popup = &current_sub_component.popup_windows[*popup_index as usize];
+                assert!(!popup.is_window, "internal error: Windows declared inside a component are rejected before");
                 let popup_window_id =
                     ident(&popup.item_tree.root.name);
                 let parent_component = access_item_rc(parent_ref, ctx);
@@ -3697,6 +3866,10 @@ fn compile_builtin_function_call(
                         parent_ctx = parent_ctx.parent.as_ref().unwrap().ctx;
                     }
                 };
+                assert!(
+                    !parent_ctx.current_sub_component.unwrap().popup_windows[*popup_index as usize].is_window,
+                    "internal error: Windows declared inside a component are rejected before"
+                );
                 let window = access_window_field(ctx);
                 format!("{window}.close_popup({component_access}->popup_id_{popup_index})")
             } else {
@@ -0,0 +1,406 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*! module for the documentation generator
+
+Extracts the `///` comments, the properties with their type and default value, the callbacks,
+and the public functions of the components and globals exported from a .slint file, and writes
+them as JSON or as a standalone HTML page.
+*/
+
+use crate::langtype::{Function, Type};
+use crate::object_tree::{Component, Document, PropertyVisibility};
+use crate::parser::{syntax_nodes, SyntaxKind, SyntaxNode};
+use std::fmt::Write;
+
+struct PropertyDoc {
+    name: String,
+    ty: String,
+    visibility: &'static str,
+    default: Option<String>,
+    description: String,
+}
+
+struct CallableDoc {
+    name: String,
+    arguments: Vec<(String, String)>,
+    return_type: Option<String>,
+    description: String,
+}
+
+struct ComponentDoc {
+    name: String,
+    is_global: bool,
+    inherits: Option<String>,
+    description: String,
+    properties: Vec<PropertyDoc>,
+    callbacks: Vec<CallableDoc>,
+    functions: Vec<CallableDoc>,
+}
+
+/// Returns the text of the `///` comments right before the node, without the leading slashes.
+///
+/// An empty line or any other token ends the comment block.
+fn doc_comment(node: &SyntaxNode) -> String {
+    // For `export component Foo`, the comment is before the `export` keyword
+    let node = match node.parent() {
+        Some(parent) if parent.kind() == SyntaxKind::ExportsList => parent,
+        _ => node.clone(),
+    };
+    // Nodes started at a checkpoint contain the comments that precede them
+    let leading = node
+        .node
+        .children_with_tokens()
+        .take_while(|t| matches!(t.kind(), SyntaxKind::Whitespace | SyntaxKind::Comment))
+        .collect::<Vec<_>>();
+    let preceding =
+        std::iter::successors(node.node.prev_sibling_or_token(), |t| t.prev_sibling_or_token());
+
+    let mut lines = Vec::new();
+    for token in leading.into_iter().rev().chain(preceding) {
+        let Some(token) = token.as_token() else { break };
+        match token.kind() {
+            SyntaxKind::Whitespace if token.text().matches('\n').count() < 2 => {}
+            SyntaxKind::Comment => {
+                let Some(line) = token.text().strip_prefix("///") else { break };
+                if line.starts_with('/') {
+                    break;
+                }
+                lines.push(line.strip_prefix(' ').unwrap_or(line).trim_end().to_string());
+            }
+            _ => break,
+        }
+    }
+    lines.reverse();
+    lines.join("\n")
+}
+
+fn callable_doc(name: &str, function: &Function, node: &SyntaxNode) -> CallableDoc {
+    let arguments = function
+        .args
+        .iter()
+        .enumerate()
+        .map(|(i, ty)| {
+            let name = function.arg_names.get(i).map_or_else(String::new, |n| n.to_string());
+            (name, ty.to_string())
+        })
+        .collect();
+    let return_type =
+        (function.return_type != Type::Void).then(|| function.return_type.to_string());
+    CallableDoc { name: name.into(), arguments, return_type, description: doc_comment(node) }
+}
+
+fn component_doc(name: &str, component: &Component) -> ComponentDoc {
+    let component_node =
+        component.node.as_ref().and_then(|n| syntax_nodes::Component::new(n.clone()));
+    let inherits = component_node
+        .as_ref()
+        .and_then(|n| n.Element().QualifiedName())
+        .map(|q| q.text().to_string().trim().to_string());
+    let description = component.node.as_ref().map(doc_comment).unwrap_or_default();
+
+    let mut declarations = component
+        .root_element
+        .borrow()
+        .property_declarations
+        .iter()
+        .filter(|(_, decl)| {
+            !matches!(
+                decl.visibility,
+                PropertyVisibility::Private
+                    | PropertyVisibility::Fake
+                    | PropertyVisibility::Constexpr
+            )
+        })
+        // Only the declarations from the component itself, not the ones of its base
+        .filter_map(|(name, decl)| {
+            let node = decl.node.clone()?;
+            let element = node.parent().filter(|p| p.kind() == SyntaxKind::Element)?;
+            (element.parent()?.node == component_node.as_ref()?.node)
+                .then(|| (name.clone(), decl.clone(), node))
+        })
+        .collect::<Vec<_>>();
+    declarations.sort_by_key(|(_, _, node)| node.text_range().start());
+
+    let mut doc = ComponentDoc {
+        name: name.into(),
+        is_global: component.is_global(),
+        inherits,
+        description,
+        properties: Vec::new(),
+        callbacks: Vec::new(),
+        functions: Vec::new(),
+    };
+    for (name, decl, node) in declarations {
+        match &decl.property_type {
+            Type::Callback(f) => doc.callbacks.push(callable_doc(&name, f, &node)),
+            Type::Function(f) => doc.functions.push(callable_doc(&name, f, &node)),
+            ty => {
+                let default = node
+                    .child_node(SyntaxKind::BindingExpression)
+                    .or_else(|| node.child_node(SyntaxKind::TwoWayBinding))
+                    .map(|b| {
+                        b.text().to_string().trim().trim_end_matches(';').trim_end().to_string()
+                    });
+                doc.properties.push(PropertyDoc {
+                    name: name.to_string(),
+                    ty: ty.to_string(),
+                    visibility: match decl.visibility {
+                        PropertyVisibility::Input => "in",
+                        PropertyVisibility::Output => "out",
+                        _ => "in-out",
+                    },
+                    default,
+                    description: doc_comment(&node),
+                })
+            }
+        }
+    }
+    doc
+}
+
+fn collect(doc: &Document) -> Vec<ComponentDoc> {
+    doc.exports
+        .iter()
+        .filter_map(|(export, component)| {
+            Some(component_doc(&export.name, component.as_ref().left()?))
+        })
+        .collect()
+}
+
+pub(crate) fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn json_optional_string(s: &Option<String>) -> String {
+    s.as_deref().map_or_else(|| "null".into(), json_string)
+}
+
+fn json_callables(out: &mut String, key: &str, callables: &[CallableDoc]) {
+    write!(out, ",\n      {}: [", json_string(key)).unwrap();
+    for (i, c) in callables.iter().enumerate() {
+        out.push_str(if i == 0 { "\n" } else { ",\n" });
+        let arguments = c
+            .arguments
+            .iter()
+            .map(|(name, ty)| {
+                format!("{{ \"name\": {}, \"type\": {} }}", json_string(name), json_string(ty))
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(
+            out,
+            "        {{ \"name\": {}, \"arguments\": [{arguments}], \"return-type\": {}, \"description\": {} }}",
+            json_string(&c.name),
+            json_optional_string(&c.return_type),
+            json_string(&c.description)
+        )
+        .unwrap();
+    }
+    out.push_str(if callables.is_empty() { "]" } else { "\n      ]" });
+}
+
+/// Generate the documentation of the exported components and globals of the document as JSON
+pub fn generate_json(doc: &Document) -> String {
+    let mut out = String::from("{\n  \"components\": [");
+    for (i, c) in collect(doc).iter().enumerate() {
+        out.push_str(if i == 0 { "\n" } else { ",\n" });
+        write!(
+            out,
+            "    {{\n      \"name\": {},\n      \"kind\": {},\n      \"inherits\": {},\n      \"description\": {}",
+            json_string(&c.name),
+            json_string(if c.is_global { "global" } else { "component" }),
+            json_optional_string(&c.inherits),
+            json_string(&c.description)
+        )
+        .unwrap();
+        out.push_str(",\n      \"properties\": [");
+        for (i, p) in c.properties.iter().enumerate() {
+            out.push_str(if i == 0 { "\n" } else { ",\n" });
+            write!(
+                out,
+                "        {{ \"name\": {}, \"type\": {}, \"visibility\": {}, \"default\": {}, \"description\": {} }}",
+                json_string(&p.name),
+                json_string(&p.ty),
+                json_string(p.visibility),
+                json_optional_string(&p.default),
+                json_string(&p.description)
+            )
+            .unwrap();
+        }
+        out.push_str(if c.properties.is_empty() { "]" } else { "\n      ]" });
+        json_callables(&mut out, "callbacks", &c.callbacks);
+        json_callables(&mut out, "functions", &c.functions);
+        out.push_str("\n    }");
+    }
+    out.push_str("\n  ]\n}\n");
+    out
+}
+
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// The description as paragraphs: blank lines in the comment separate paragraphs
+fn html_description(description: &str) -> String {
+    description
+        .split("\n\n")
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(|p| format!("<p>{}</p>\n", html_escape(p)))
+        .collect()
+}
+
+fn html_callables(out: &mut String, title: &str, callables: &[CallableDoc]) {
+    if callables.is_empty() {
+        return;
+    }
+    writeln!(out, "<h3>{title}</h3>\n<table>").unwrap();
+    out.push_str(
+        "<tr><th>Name</th><th>Arguments</th><th>Return type</th><th>Description</th></tr>\n",
+    );
+    for c in callables {
+        let arguments = c
+            .arguments
+            .iter()
+            .map(|(name, ty)| {
+                if name.is_empty() {
+                    html_escape(ty)
+                } else {
+                    format!("{}: {}", html_escape(name), html_escape(ty))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(
+            out,
+            "<tr><td><code>{}</code></td><td><code>{arguments}</code></td><td><code>{}</code></td><td>{}</td></tr>",
+            html_escape(&c.name),
+            html_escape(c.return_type.as_deref().unwrap_or("")),
+            html_description(&c.description)
+        )
+        .unwrap();
+    }
+    out.push_str("</table>\n");
+}
+
+/// Generate the documentation of the exported components and globals of the document as a HTML page
+pub fn generate_html(doc: &Document) -> String {
+    let mut out = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Slint Components</title>\n\
+         <style>\nbody { font-family: sans-serif; max-width: 60em; margin: auto; }\n\
+         table { border-collapse: collapse; width: 100%; }\n\
+         th, td { border: 1px solid #ccc; padding: 0.3em; text-align: left; vertical-align: top; }\n\
+         td p { margin: 0; }\n</style>\n</head>\n<body>\n<!-- This file is generated by the Slint compiler. Do not edit. -->\n",
+    );
+    for c in collect(doc) {
+        let name = html_escape(&c.name);
+        let kind = if c.is_global { "global" } else { "component" };
+        writeln!(out, "<section id=\"{name}\">\n<h2>{kind} {name}</h2>").unwrap();
+        if let Some(inherits) = &c.inherits {
+            writeln!(out, "<p>Inherits <code>{}</code></p>", html_escape(inherits)).unwrap();
+        }
+        out += &html_description(&c.description);
+        if !c.properties.is_empty() {
+            out.push_str("<h3>Properties</h3>\n<table>\n");
+            out.push_str("<tr><th>Name</th><th>Type</th><th>Visibility</th><th>Default</th><th>Description</th></tr>\n");
+            for p in &c.properties {
+                writeln!(
+                    out,
+                    "<tr><td><code>{}</code></td><td><code>{}</code></td><td>{}</td><td><code>{}</code></td><td>{}</td></tr>",
+                    html_escape(&p.name),
+                    html_escape(&p.ty),
+                    p.visibility,
+                    html_escape(p.default.as_deref().unwrap_or("")),
+                    html_description(&p.description)
+                )
+                .unwrap();
+            }
+            out.push_str("</table>\n");
+        }
+        html_callables(&mut out, "Callbacks", &c.callbacks);
+        html_callables(&mut out, "Functions", &c.functions);
+        out.push_str("</section>\n");
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+#[test]
+fn documentation() {
+    let mut compiler_config =
+        crate::CompilerConfiguration::new(crate::generator::OutputFormat::Interpreter);
+    compiler_config.style = Some("fluent".into());
+    let mut diag = crate::diagnostics::BuildDiagnostics::default();
+    let node = crate::parser::parse(
+        r#"
+        // Not a doc comment
+
+        /// A button with a "label".
+        ///
+        /// Second paragraph.
+        export component MyButton inherits Rectangle {
+            /// The text of the button
+            in property <string> text: "Ok";
+            out property <bool> pressed <=> ta.pressed;
+            // Not part of the documentation
+            property <int> internal;
+            /// Invoked when clicked
+            callback clicked(position: length);
+            public function reset() -> bool { true }
+            ta := TouchArea {}
+        }
+        export global Palette {
+            /// Main color
+            in-out property <color> accent: #f00;
+        }
+        "#
+        .into(),
+        Some(std::path::Path::new("main.slint")),
+        &mut diag,
+    );
+    let (doc, diag, _) = spin_on::spin_on(crate::compile_syntax_node(node, diag, compiler_config));
+    assert!(!diag.has_errors(), "{:?}", diag.to_string_vec());
+
+    let json = generate_json(&doc);
+    for expected in [
+        r#""name": "MyButton",
+      "kind": "component",
+      "inherits": "Rectangle",
+      "description": "A button with a \"label\".\n\nSecond paragraph.""#,
+        r#"{ "name": "text", "type": "string", "visibility": "in", "default": "\"Ok\"", "description": "The text of the button" }"#,
+        r#"{ "name": "pressed", "type": "bool", "visibility": "out", "default": "<=> ta.pressed", "description": "" }"#,
+        r#"{ "name": "clicked", "arguments": [{ "name": "position", "type": "length" }], "return-type": null, "description": "Invoked when clicked" }"#,
+        r#"{ "name": "reset", "arguments": [], "return-type": "bool", "description": "" }"#,
+        r#""name": "Palette",
+      "kind": "global",
+      "inherits": null,"#,
+        r##"{ "name": "accent", "type": "color", "visibility": "in-out", "default": "#f00", "description": "Main color" }"##,
+    ] {
+        assert!(json.contains(expected), "{expected} not found in\n{json}");
+    }
+    assert!(!json.contains("internal"), "{json}");
+
+    let html = generate_html(&doc);
+    for expected in [
+        "<h2>component MyButton</h2>\n<p>Inherits <code>Rectangle</code></p>\n<p>A button with a &quot;label&quot;.</p>\n<p>Second paragraph.</p>",
+        "<tr><td><code>text</code></td><td><code>string</code></td><td>in</td><td><code>&quot;Ok&quot;</code></td><td><p>The text of the button</p>\n</td></tr>",
+        "<h2>global Palette</h2>",
+    ] {
+        assert!(html.contains(expected), "{expected} not found in\n{html}");
+    }
+}
@@ -0,0 +1,230 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*! module for the interface description generator
+
+Generates a JSON file that describes the public API of the components and globals exported
+from a .slint file, as well as the structs and enums that they use, so that other tools can
+generate bindings or drive the UI without parsing .slint files themselves.
+
+Types are described with a string for the primitive types (`"int"`, `"string"`, `"length"`, ...),
+or with an object: `{ "array": <type> }`, `{ "struct": "Name" }` for the structs listed in
+`structs`, `{ "fields": { ... } }` for the anonymous and builtin structs, and `{ "enum": "Name" }`
+for the enums (with the `values` for the builtin enums that are not listed in `enums`).
+*/
+
+use super::docs::json_string;
+use crate::langtype::{Function, Type};
+use crate::object_tree::{Component, Document, PropertyVisibility};
+use std::fmt::Write;
+
+fn json_type(ty: &Type) -> String {
+    match ty {
+        Type::Array(ty) => format!("{{ \"array\": {} }}", json_type(ty)),
+        Type::Struct(s) if s.name.is_some() && s.node.is_some() => {
+            format!("{{ \"struct\": {} }}", json_string(s.name.as_ref().unwrap()))
+        }
+        Type::Struct(s) => {
+            let fields = s
+                .fields
+                .iter()
+                .map(|(name, ty)| format!("{}: {}", json_string(name), json_type(ty)))
+                .collect::<Vec<_>>()
+                .join(", ");
+            match &s.name {
+                Some(name) => {
+                    format!("{{ \"name\": {}, \"fields\": {{ {fields} }} }}", json_string(name))
+                }
+                None => format!("{{ \"fields\": {{ {fields} }} }}"),
+            }
+        }
+        Type::Enumeration(e) if e.node.is_some() => {
+            format!("{{ \"enum\": {} }}", json_string(&e.name))
+        }
+        Type::Enumeration(e) => format!(
+            "{{ \"enum\": {}, \"values\": [{}] }}",
+            json_string(&e.name),
+            e.values.iter().map(|v| json_string(v)).collect::<Vec<_>>().join(", ")
+        ),
+        ty => json_string(&ty.to_string()),
+    }
+}
+
+fn json_function(name: &str, function: &Function, pure: Option<bool>) -> String {
+    let arguments = function
+        .args
+        .iter()
+        .enumerate()
+        .map(|(i, ty)| {
+            let name = function.arg_names.get(i).map_or("", |n| n.as_str());
+            format!("{{ \"name\": {}, \"type\": {} }}", json_string(name), json_type(ty))
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(
+        "{{ \"name\": {}, \"arguments\": [{arguments}], \"return-type\": {}, \"pure\": {} }}",
+        json_string(name),
+        json_type(&function.return_type),
+        pure.unwrap_or(false)
+    )
+}
+
+/// Writes a JSON array with one item per line, at the given indentation
+fn write_array(out: &mut String, indent: &str, items: Vec<String>) {
+    if items.is_empty() {
+        out.push_str("[]");
+        return;
+    }
+    out.push_str("[\n");
+    for (i, item) in items.iter().enumerate() {
+        let separator = if i + 1 < items.len() { "," } else { "" };
+        writeln!(out, "{indent}  {item}{separator}").unwrap();
+    }
+    write!(out, "{indent}]").unwrap();
+}
+
+fn component_json(name: &str, component: &Component) -> String {
+    let mut properties = Vec::new();
+    let mut callbacks = Vec::new();
+    let mut functions = Vec::new();
+    for (name, decl) in &component.root_element.borrow().property_declarations {
+        if !decl.expose_in_public_api || decl.visibility == PropertyVisibility::Private {
+            continue;
+        }
+        match &decl.property_type {
+            Type::Callback(f) => callbacks.push(json_function(name, f, decl.pure)),
+            Type::Function(f) => functions.push(json_function(name, f, decl.pure)),
+            ty => {
+                let visibility = match decl.visibility {
+                    PropertyVisibility::Input => "in",
+                    PropertyVisibility::Output => "out",
+                    _ => "in-out",
+                };
+                properties.push(format!(
+                    "{{ \"name\": {}, \"type\": {}, \"visibility\": \"{visibility}\" }}",
+                    json_string(name),
+                    json_type(ty)
+                ))
+            }
+        }
+    }
+    let mut out = format!("{{\n      \"name\": {},\n      \"properties\": ", json_string(name));
+    write_array(&mut out, "      ", properties);
+    out.push_str(",\n      \"callbacks\": ");
+    write_array(&mut out, "      ", callbacks);
+    out.push_str(",\n      \"functions\": ");
+    write_array(&mut out, "      ", functions);
+    out.push_str("\n    }");
+    out
+}
+
+/// Generate the JSON description of the public API of the exported items of the document
+pub fn generate(doc: &Document) -> String {
+    let mut structs = Vec::new();
+    let mut enums = Vec::new();
+    for ty in doc.used_types.borrow().structs_and_enums.iter() {
+        match ty {
+            Type::Struct(s) if s.name.is_some() && s.node.is_some() => {
+                let fields = s
+                    .fields
+                    .iter()
+                    .map(|(name, ty)| format!("{}: {}", json_string(name), json_type(ty)))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                structs.push(format!(
+                    "{{ \"name\": {}, \"fields\": {{ {fields} }} }}",
+                    json_string(s.name.as_ref().unwrap())
+                ));
+            }
+            Type::Enumeration(e) if e.node.is_some() => {
+                let values = e.values.iter().map(|v| json_string(v)).collect::<Vec<_>>();
+                enums.push(format!(
+                    "{{ \"name\": {}, \"values\": [{}] }}",
+                    json_string(&e.name),
+                    values.join(", ")
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    let mut components = Vec::new();
+    let mut globals = Vec::new();
+    for (export, component) in doc.exports.iter() {
+        let Some(component) = component.as_ref().left() else { continue };
+        let json = component_json(&export.name, component);
+        if component.is_global() { &mut globals } else { &mut components }.push(json);
+    }
+
+    let mut out = String::from("{\n  \"components\": ");
+    write_array(&mut out, "  ", components);
+    out.push_str(",\n  \"globals\": ");
+    write_array(&mut out, "  ", globals);
+    out.push_str(",\n  \"structs\": ");
+    write_array(&mut out, "  ", structs);
+    out.push_str(",\n  \"enums\": ");
+    write_array(&mut out, "  ", enums);
+    out.push_str("\n}\n");
+    out
+}
+
+#[test]
+fn interface_description() {
+    let mut compiler_config =
+        crate::CompilerConfiguration::new(crate::generator::OutputFormat::Interpreter);
+    compiler_config.style = Some("fluent".into());
+    let mut diag = crate::diagnostics::BuildDiagnostics::default();
+    let node = crate::parser::parse(
+        r#"
+        export struct TodoItem { title: string, done: bool }
+        export enum Filter { all, not-done }
+        export global Logic {
+            pure callback format-date(year: int) -> string;
+        }
+        export component Main inherits Window {
+            in-out property <[TodoItem]> todo-items;
+            out property <Filter> filter;
+            in property <TextHorizontalAlignment> alignment;
+            in property <{ x: length, y: length }> position;
+            property <int> private-counter;
+            callback add-item(title: string);
+            public function count() -> int { todo-items.length }
+        }
+        "#
+        .into(),
+        Some(std::path::Path::new("main.slint")),
+        &mut diag,
+    );
+    let (doc, diag, _) = spin_on::spin_on(crate::compile_syntax_node(node, diag, compiler_config));
+    assert!(!diag.has_errors(), "{:?}", diag.to_string_vec());
+    let output = generate(&doc);
+    for expected in [
+        r#"{ "name": "todo-items", "type": { "array": { "struct": "TodoItem" } }, "visibility": "in-out" }"#,
+        r#"{ "name": "filter", "type": { "enum": "Filter" }, "visibility": "out" }"#,
+        r#"{ "name": "alignment", "type": { "enum": "TextHorizontalAlignment", "values": ["left", "center", "right"] }, "visibility": "in" }"#,
+        r#"{ "name": "position", "type": { "fields": { "x": "length", "y": "length" } }, "visibility": "in" }"#,
+        r#""callbacks": [
+        { "name": "add-item", "arguments": [{ "name": "title", "type": "string" }], "return-type": "void", "pure": false }
+      ]"#,
+        r#"{ "name": "count", "arguments": [], "return-type": "int", "pure": false }"#,
+        r#""globals": [
+    {
+      "name": "Logic",
+      "properties": [],
+      "callbacks": [
+        { "name": "format-date", "arguments": [{ "name": "year", "type": "int" }], "return-type": "string", "pure": true }
+      ],
+      "functions": []
+    }
+  ]"#,
+        r#""structs": [
+    { "name": "TodoItem", "fields": { "done": "bool", "title": "string" } }
+  ]"#,
+        r#""enums": [
+    { "name": "Filter", "values": ["all", "not-done"] }
+  ]"#,
+    ] {
+        assert!(output.contains(expected), "{expected} not found in\n{output}");
+    }
+    assert!(!output.contains("private-counter"), "{output}");
+}
@@ -155,6 +155,49 @@ pub fn generate(
     doc: &Document,
     compiler_config: &CompilerConfiguration,
 ) -> std::io::Result<TokenStream> {
+    let llr = crate::llr::lower_to_item_tree::lower_to_item_tree(doc, compiler_config)?;
+    Ok(generate_from_llr(doc, &llr, compiler_config, &[], None))
+}
+
+/// Like [`generate`], but the code of each component and global is put in its own file, so that
+/// the files of the components that didn't change stay the same.
+///
+/// Returns the code of the main file, and the name and code of the other files. The main file
+/// includes the other files with `include!`, so they must be written in the same directory.
+/// Their name starts with `file_prefix`.
+pub fn generate_split(
+    doc: &Document,
+    compiler_config: &CompilerConfiguration,
+    file_prefix: &str,
+) -> std::io::Result<(TokenStream, Vec<(String, TokenStream)>)> {
+    let llr = crate::llr::lower_to_item_tree::lower_to_item_tree(doc, compiler_config)?;
+    let mut files = Vec::new();
+    let main = generate_from_llr(doc, &llr, compiler_config, &[], Some((file_prefix, &mut files)));
+    Ok((main, files))
+}
+
+/// Generate the code for the document, except for the structs and enums in `shared_types`,
+/// which are imported from the grand-parent module instead.
+///
+/// If `split_files` is set, the code of the components and globals is added to the list of files
+/// instead, and included from the returned code.
+fn generate_from_llr(
+    doc: &Document,
+    llr: &llr::CompilationUnit,
+    compiler_config: &CompilerConfiguration,
+    shared_types: &[SmolStr],
+    mut split_files: Option<(&str, &mut Vec<(String, TokenStream)>)>,
+) -> TokenStream {
+    let mut split = |kind: &str, name: &str, code: TokenStream| match &mut split_files {
+        Some((prefix, files)) => {
+            let name = name.replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+            let file_name = format!("{prefix}.{kind}.{name}.rs");
+            files.push((file_name.clone(), code));
+            quote!(include!(#file_name);)
+        }
+        None => code,
+    };
+
     let (structs_and_enums_ids, structs_and_enum_def): (Vec<_>, Vec<_>) = doc
         .used_types
         .borrow()
@@ -163,31 +206,47 @@ pub fn generate(
         .filter_map(|ty| match ty {
             Type::Struct(s) => match s.as_ref() {
                 Struct { fields, name: Some(name), node: Some(_), rust_attributes } => {
-                    Some((ident(name), generate_struct(name, fields, rust_attributes)))
+                    let def = if shared_types.contains(name) {
+                        let id = ident(name);
+                        quote!(pub use super::super::#id;)
+                    } else {
+                        generate_struct(name, fields, rust_attributes)
+                    };
+                    Some((ident(name), def))
                 }
                 _ => None,
             },
+            Type::Enumeration(en) if shared_types.contains(&en.name) => {
+                let id = ident(&en.name);
+                Some((id.clone(), quote!(pub use super::super::#id;)))
+            }
             Type::Enumeration(en) => Some((ident(&en.name), generate_enum(en))),
             _ => None,
         })
         .unzip();
 
-    let llr = crate::llr::lower_to_item_tree::lower_to_item_tree(doc, compiler_config)?;
-
     if llr.public_components.is_empty() {
-        return Ok(Default::default());
+        return Default::default();
     }
 
     let sub_compos = llr
         .sub_components
         .iter()
-        .map(|sub_compo| generate_sub_component(sub_compo, &llr, None, None, false))
+        .map(|sub_compo| {
+            let code = generate_sub_component(sub_compo, llr, None, None, false);
+            split("sub", &sub_compo.name, code)
+        })
+        .collect::<Vec<_>>();
+    let public_components = llr
+        .public_components
+        .iter()
+        .map(|p| split("component", &p.name, generate_public_component(p, llr)))
         .collect::<Vec<_>>();
-    let public_components =
-        llr.public_components.iter().map(|p| generate_public_component(p, &llr));
 
-    let popup_menu =
-        llr.popup_menu.as_ref().map(|p| generate_item_tree(&p.item_tree, &llr, None, None, true));
+    let popup_menu = llr
+        .popup_menu
+        .as_ref()
+        .map(|p| generate_item_tree(&p.item_tree, llr, None, None, true, false));
 
     let version_check = format_ident!(
         "VersionCheck_{}_{}_{}",
@@ -200,8 +259,9 @@ pub fn generate(
         .globals
         .iter()
         .filter(|glob| glob.must_generate())
-        .map(|glob| generate_global(glob, &llr));
-    let shared_globals = generate_shared_globals(&llr, &compiler_config);
+        .map(|glob| split("global", &glob.name, generate_global(glob, llr)))
+        .collect::<Vec<_>>();
+    let shared_globals = generate_shared_globals(llr, compiler_config);
     let globals_ids = llr.globals.iter().filter(|glob| glob.exported).flat_map(|glob| {
         std::iter::once(ident(&glob.name)).chain(glob.aliases.iter().map(|x| ident(x)))
     });
@@ -219,9 +279,9 @@ pub fn generate(
     #[cfg(not(feature = "bundle-translations"))]
     let translations = quote!();
     #[cfg(feature = "bundle-translations")]
-    let translations = llr.translations.as_ref().map(|t| (generate_translations(t, &llr)));
+    let translations = llr.translations.as_ref().map(|t| (generate_translations(t, llr)));
 
-    Ok(quote! {
+    quote! {
         #[allow(non_snake_case, non_camel_case_types)]
         #[allow(unused_braces, unused_parens)]
         #[allow(clippy::all)]
@@ -243,9 +303,256 @@ mod #generated_mod {
         pub use #generated_mod::{#(#compo_ids,)* #(#structs_and_enums_ids,)* #(#globals_ids,)* #(#named_exports,)*};
         #[allow(unused_imports)]
         pub use slint::{ComponentHandle as _, Global as _, ModelExt as _};
+    }
+}
+
+/// Generate the rust code for the same document compiled with different styles.
+///
+/// The code for each style is in its own module. The exported components and globals are types
+/// that forward to the ones of the style selected with `slint::set_style()` when the component
+/// is created. The structs and enums that are not defined by a style are shared between the styles.
+pub fn generate_with_runtime_styles(
+    docs: &[(&str, &Document)],
+    compiler_config: &CompilerConfiguration,
+) -> std::io::Result<TokenStream> {
+    use crate::diagnostics::Spanned;
+    let Some((_, first_doc)) = docs.first() else { return Ok(Default::default()) };
+
+    let is_from_style = |node: Option<&crate::parser::SyntaxNode>| {
+        node.and_then(|n| n.source_file()).is_some_and(|sf| sf.path().starts_with("builtin:"))
+    };
+    let mut shared_types = Vec::new();
+    let mut shared_types_def = Vec::new();
+    for ty in first_doc.used_types.borrow().structs_and_enums.iter() {
+        match ty {
+            Type::Struct(s) => match s.as_ref() {
+                Struct { fields, name: Some(name), node: Some(node), rust_attributes }
+                    if !is_from_style(Some(&**node)) =>
+                {
+                    shared_types.push(name.clone());
+                    shared_types_def.push(generate_struct(name, fields, rust_attributes));
+                }
+                _ => {}
+            },
+            Type::Enumeration(en) if !is_from_style(en.node.as_deref()) => {
+                shared_types.push(en.name.clone());
+                shared_types_def.push(generate_enum(en));
+            }
+            _ => {}
+        }
+    }
+
+    let mut llrs = Vec::new();
+    for (_, doc) in docs {
+        llrs.push(crate::llr::lower_to_item_tree::lower_to_item_tree(doc, compiler_config)?);
+    }
+    let first_llr = &llrs[0];
+    if first_llr.public_components.is_empty() {
+        return Ok(Default::default());
+    }
+
+    let styles = docs.iter().map(|(style, _)| ident(style)).collect::<Vec<_>>();
+    let style_names = docs.iter().map(|(style, _)| *style).collect::<Vec<_>>();
+    let style_modules = docs
+        .iter()
+        .zip(&llrs)
+        .map(|((_, doc), llr)| generate_from_llr(doc, llr, compiler_config, &shared_types, None));
+    let first_style = &styles[0];
+    let unknown_style_error = format!(
+        "The selected style was not compiled in the application. The available styles are: {}",
+        style_names.join(", ")
+    );
+
+    let mut facades = Vec::new();
+    for component in &first_llr.public_components {
+        let component_id = ident(&component.name);
+        let styles_enum = format_ident!("{}Style", component_id);
+        let accessors = forward_public_api(&component.public_properties, &styles_enum, &styles);
+        facades.push(quote! {
+            enum #styles_enum { #(#styles(#styles::#component_id),)* }
+
+            pub struct #component_id(#styles_enum);
+
+            impl #component_id {
+                pub fn new() -> core::result::Result<Self, slint::PlatformError> {
+                    core::result::Result::Ok(Self(match slint::selected_style().as_deref() {
+                        None => #styles_enum::#first_style(#first_style::#component_id::new()?),
+                        #(Some(#style_names) => #styles_enum::#styles(#styles::#component_id::new()?),)*
+                        Some(_) => return core::result::Result::Err(slint::PlatformError::Other(#unknown_style_error.into())),
+                    }))
+                }
+
+                #accessors
+            }
+
+            // The weak reference points to the item tree of the selected style, which is
+            // recognized by its vtable when upgrading
+            impl slint::ComponentHandle for #component_id {
+                type Inner = sp::Dyn;
+                fn as_weak(&self) -> slint::Weak<Self> {
+                    slint::Weak::new(&match &self.0 {
+                        #(#styles_enum::#styles(inner) => sp::VRc::into_dyn(
+                            sp::VRc::<sp::ItemTreeVTable, <#styles::#component_id as slint::ComponentHandle>::Inner>::from(
+                                slint::ComponentHandle::clone_strong(inner),
+                            ),
+                        ),)*
+                    })
+                }
+
+                fn clone_strong(&self) -> Self {
+                    Self(match &self.0 {
+                        #(#styles_enum::#styles(inner) => #styles_enum::#styles(slint::ComponentHandle::clone_strong(inner)),)*
+                    })
+                }
+
+                fn from_inner(inner: sp::VRc<sp::ItemTreeVTable, sp::Dyn>) -> Self {
+                    #(
+                        let inner = match sp::VRc::downcast::<<#styles::#component_id as slint::ComponentHandle>::Inner>(inner) {
+                            core::result::Result::Ok(inner) => return Self(#styles_enum::#styles(slint::ComponentHandle::from_inner(inner))),
+                            core::result::Result::Err(inner) => inner,
+                        };
+                    )*
+                    drop(inner);
+                    unreachable!("The item tree is not the one of a style of this component")
+                }
+
+                fn run(&self) -> core::result::Result<(), slint::PlatformError> {
+                    match &self.0 { #(#styles_enum::#styles(inner) => slint::ComponentHandle::run(inner),)* }
+                }
+
+                fn show(&self) -> core::result::Result<(), slint::PlatformError> {
+                    match &self.0 { #(#styles_enum::#styles(inner) => slint::ComponentHandle::show(inner),)* }
+                }
+
+                fn hide(&self) -> core::result::Result<(), slint::PlatformError> {
+                    match &self.0 { #(#styles_enum::#styles(inner) => slint::ComponentHandle::hide(inner),)* }
+                }
+
+                fn window(&self) -> &slint::Window {
+                    match &self.0 { #(#styles_enum::#styles(inner) => slint::ComponentHandle::window(inner),)* }
+                }
+
+                fn global<'a, T: slint::Global<'a, Self>>(&'a self) -> T {
+                    T::get(self)
+                }
+            }
+        });
+    }
+
+    for global in first_llr.globals.iter().filter(|g| g.exported) {
+        let global_id = ident(&global.name);
+        let styles_enum = format_ident!("{}Style", global_id);
+        let accessors = forward_public_api(&global.public_properties, &styles_enum, &styles);
+        let aliases = global.aliases.iter().map(|name| ident(name));
+        let getters = first_llr.public_components.iter().map(|c| {
+            let component_id = ident(&c.name);
+            let component_styles_enum = format_ident!("{}Style", component_id);
+            quote! {
+                impl<'a> slint::Global<'a, #component_id> for #global_id<'a> {
+                    fn get(component: &'a #component_id) -> Self {
+                        Self(match &component.0 {
+                            #(#component_styles_enum::#styles(inner) => #styles_enum::#styles(slint::ComponentHandle::global::<#styles::#global_id<'a>>(inner)),)*
+                        })
+                    }
+                }
+            }
+        });
+        facades.push(quote! {
+            enum #styles_enum<'a> { #(#styles(#styles::#global_id<'a>),)* }
+
+            pub struct #global_id<'a>(#styles_enum<'a>);
+
+            impl<'a> #global_id<'a> {
+                #accessors
+            }
+            #(pub type #aliases<'a> = #global_id<'a>;)*
+            #(#getters)*
+        });
+    }
+
+    let shared_types_ids = shared_types.iter().map(|name| ident(name));
+    let compo_ids = first_llr.public_components.iter().map(|c| ident(&c.name));
+    let globals_ids = first_llr.globals.iter().filter(|glob| glob.exported).flat_map(|glob| {
+        std::iter::once(ident(&glob.name)).chain(glob.aliases.iter().map(|x| ident(x)))
+    });
+    let named_exports = generate_named_exports(first_doc);
+    let generated_mod = first_doc
+        .last_exported_component()
+        .map(|c| format_ident!("slint_generated{}", ident(&c.id)))
+        .unwrap_or_else(|| format_ident!("slint_generated"));
+
+    Ok(quote! {
+        #[allow(non_snake_case, non_camel_case_types)]
+        #[allow(unused_braces, unused_parens)]
+        #[allow(clippy::all)]
+        mod #generated_mod {
+            use slint::private_unstable_api::re_exports as sp;
+            #(#shared_types_def)*
+            #(pub mod #styles { #style_modules })*
+            #(#facades)*
+        }
+        #[allow(unused_imports)]
+        pub use #generated_mod::{#(#compo_ids,)* #(#shared_types_ids,)* #(#globals_ids,)* #(#named_exports,)*};
+        #[allow(unused_imports)]
+        pub use slint::{ComponentHandle as _, Global as _, ModelExt as _};
     })
 }
 
+/// Generate the accessors of the public API of a component or global of [`generate_with_runtime_styles`]
+/// that forward to the same accessor of the component or global of the selected style.
+fn forward_public_api(
+    public_properties: &llr::PublicProperties,
+    styles_enum: &Ident,
+    styles: &[Ident],
+) -> TokenStream {
+    let mut accessors: Vec<TokenStream> = vec![];
+    for p in public_properties {
+        let prop_ident = ident(&p.name);
+        if let Type::Callback(function) | Type::Function(function) = &p.ty {
+            let args =
+                function.args.iter().map(|a| rust_primitive_type(a).unwrap()).collect::<Vec<_>>();
+            let return_type = rust_primitive_type(&function.return_type).unwrap();
+            let args_name = (0..args.len()).map(|i| format_ident!("arg_{}", i)).collect::<Vec<_>>();
+            let caller_ident = format_ident!("invoke_{}", prop_ident);
+            let call = quote!(#caller_ident(#(#args_name),*));
+            accessors.push(quote!(
+                #[allow(dead_code)]
+                pub fn #caller_ident(&self, #(#args_name : #args,)*) -> #return_type {
+                    match &self.0 { #(#styles_enum::#styles(inner) => inner.#call,)* }
+                }
+            ));
+            if matches!(p.ty, Type::Callback(_)) {
+                let on_ident = format_ident!("on_{}", prop_ident);
+                accessors.push(quote!(
+                    #[allow(dead_code)]
+                    pub fn #on_ident(&self, f: impl FnMut(#(#args),*) -> #return_type + 'static) {
+                        match &self.0 { #(#styles_enum::#styles(inner) => inner.#on_ident(f),)* }
+                    }
+                ));
+            }
+        } else {
+            let rust_property_type = rust_primitive_type(&p.ty).unwrap();
+            let getter_ident = format_ident!("get_{}", prop_ident);
+            accessors.push(quote!(
+                #[allow(dead_code)]
+                pub fn #getter_ident(&self) -> #rust_property_type {
+                    match &self.0 { #(#styles_enum::#styles(inner) => inner.#getter_ident(),)* }
+                }
+            ));
+            if !p.read_only {
+                let setter_ident = format_ident!("set_{}", prop_ident);
+                accessors.push(quote!(
+                    #[allow(dead_code)]
+                    pub fn #setter_ident(&self, value: #rust_property_type) {
+                        match &self.0 { #(#styles_enum::#styles(inner) => inner.#setter_ident(value),)* }
+                    }
+                ));
+            }
+        }
+    }
+    quote!(#(#accessors)*)
+}
+
 fn generate_public_component(
     llr: &llr::PublicComponent,
     unit: &llr::CompilationUnit,
@@ -253,7 +560,7 @@ fn generate_public_component(
     let public_component_id = ident(&llr.name);
     let inner_component_id = inner_component_id(&llr.item_tree.root);
 
-    let component = generate_item_tree(&llr.item_tree, unit, None, None, false);
+    let component = generate_item_tree(&llr.item_tree, unit, None, None, false, false);
 
     let ctx = EvaluationContext {
         compilation_unit: unit,
@@ -388,6 +695,16 @@ fn init(self: &sp::Rc<Self>) {
                 #(self.#global_names.clone().init(self);)*
             }
 
+            /// The globals of a `Window` declared inside another component: the globals are shared, but the window is not
+            #[allow(dead_code)]
+            fn new_for_window(&self, root_item_tree_weak : sp::VWeak<sp::ItemTreeVTable>) -> Self {
+                Self {
+                    #(#global_names : self.#global_names.clone(),)*
+                    window_adapter : ::core::default::Default::default(),
+                    root_item_tree_weak,
+                }
+            }
+
             fn window_adapter_impl(&self) -> sp::Rc<dyn sp::WindowAdapter> {
                 sp::Rc::clone(self.window_adapter_ref().unwrap())
             }
@@ -468,10 +785,17 @@ pub enum #enum_name {
     }
 }
 
+/// The closures of the bindings that are shared by several properties of a component when optimizing
+/// for size, indexed by the code of the binding and the type of the property. The closures don't
+/// capture anything, so they can be copied, and their declaration is only generated once before
+/// the initialization of the properties.
+type SharedBindings = BTreeMap<(String, String), (Ident, TokenStream)>;
+
 fn handle_property_init(
     prop: &llr::PropertyReference,
     binding_expression: &llr::BindingExpression,
     init: &mut Vec<TokenStream>,
+    shared_bindings: &mut SharedBindings,
     ctx: &EvaluationContext,
 ) {
     let rust_property = access_member(prop, ctx).unwrap();
@@ -483,6 +807,12 @@ fn handle_property_init(
         quote!(let _self = self_rc.as_pin_ref();)
     };
 
+    let source_location = binding_expression
+        .source_location
+        .as_ref()
+        .filter(|_| ctx.compilation_unit.has_source_locations)
+        .and_then(source_location_marker);
+
     if let Type::Callback(callback) = &prop_type {
         let mut ctx2 = ctx.clone();
         ctx2.argument_types = &callback.args;
@@ -493,6 +823,7 @@ fn handle_property_init(
             #[allow(unreachable_code, unused)]
             slint::private_unstable_api::set_callback_handler(#rust_property, &self_rc, {
                 move |self_rc, args| {
+                    #source_location
                     #init_self_pin_ref
                     (#tokens_for_expression) #as_
                 }
@@ -506,7 +837,7 @@ fn handle_property_init(
 
         init.push(if binding_expression.is_constant && !binding_expression.is_state_info {
             let t = rust_property_type(prop_type).unwrap_or(quote!(_));
-            quote! { #rust_property.set({ (#tokens_for_expression) as #t }); }
+            quote! { #rust_property.set({ #source_location (#tokens_for_expression) as #t }); }
         } else {
             let maybe_cast_to_property_type = if binding_expression.expression.borrow().ty(ctx) == Type::Invalid {
                 // Don't cast if the Rust code is the never type, as with return statements inside a block, the
@@ -517,6 +848,7 @@ fn handle_property_init(
             };
 
             let binding_tokens = quote!(move |self_rc| {
+                #source_location
                 #init_self_pin_ref
                 (#tokens_for_expression) #maybe_cast_to_property_type
             });
@@ -546,6 +878,31 @@ fn handle_property_init(
                         }
                     }
                     None => {
+                        let binding_tokens = rust_property_type(prop_type)
+                            .filter(|_| {
+                                ctx.compilation_unit.optimize_for_size && source_location.is_none()
+                            })
+                            .map_or(binding_tokens, |t| {
+                                let key = (tokens_for_expression.to_string(), t.to_string());
+                                let index = shared_bindings.len();
+                                let (ident, _) = shared_bindings.entry(key).or_insert_with(|| {
+                                    let ident = format_ident!("shared_binding_{index}");
+                                    let self_type = if ctx.current_global.is_some() {
+                                        quote!(::core::pin::Pin<sp::Rc<Self>>)
+                                    } else {
+                                        quote!(sp::VRcMapped<sp::ItemTreeVTable, Self>)
+                                    };
+                                    let declaration = quote! {
+                                        #[allow(unreachable_code, unused)]
+                                        let #ident = move |self_rc: #self_type| -> #t {
+                                            #init_self_pin_ref
+                                            (#tokens_for_expression) #maybe_cast_to_property_type
+                                        };
+                                    };
+                                    (ident, declaration)
+                                });
+                                quote!(#ident)
+                            });
                         quote! { {
                             slint::private_unstable_api::set_property_binding(#rust_property, &self_rc, #binding_tokens);
                         } }
@@ -556,6 +913,15 @@ fn handle_property_init(
     }
 }
 
+/// Returns a call to `source_location` with the location of the binding in the .slint file,
+/// so that the location can be found from the generated code
+fn source_location_marker(location: &crate::diagnostics::SourceLocation) -> Option<TokenStream> {
+    let source_file = location.source_file.as_ref()?;
+    let (line, column) = source_file.line_column(location.span.offset);
+    let path = source_file.path().to_string_lossy();
+    Some(quote!(slint::private_unstable_api::source_location(#path, #line, #column);))
+}
+
 /// Public API for Global and root component
 fn public_api(
     public_properties: &llr::PublicProperties,
@@ -690,6 +1056,7 @@ fn generate_sub_component(
                 Some(ParentCtx::new(&ctx, None)),
                 None,
                 false,
+                popup.is_window,
             )
         })
         .collect::<Vec<_>>();
@@ -797,6 +1164,22 @@ fn generate_sub_component(
             let vp_w = access_member(&listview.viewport_width, &ctx).unwrap();
             let lv_w = access_member(&listview.listview_width, &ctx).unwrap();
 
+            if let Some(scroll_to_row) = &listview.scroll_to_row {
+                let scroll_to_row = access_member(scroll_to_row, &ctx).unwrap();
+                init.push(quote! {
+                    #scroll_to_row.set_handler({
+                        let self_weak = sp::VRcMapped::downgrade(&self_rc);
+                        move |(row,)| {
+                            let Some(self_rc) = self_weak.upgrade() else { return };
+                            let _self = self_rc.as_pin_ref();
+                            if let Ok(row) = usize::try_from(*row) {
+                                #inner_component_id::FIELD_OFFSETS.#repeater_id.apply_pin(_self).scroll_to_row(row);
+                            }
+                        }
+                    });
+                });
+            }
+
             quote! {
                 #inner_component_id::FIELD_OFFSETS.#repeater_id.apply_pin(_self).ensure_updated_listview(
                     || { #rep_inner_component_id::new(_self.self_weak.get().unwrap().clone()).unwrap().into() },
@@ -1029,11 +1412,17 @@ fn generate_sub_component(
         init.push(quote!(#r;))
     }
 
+    let mut shared_bindings = SharedBindings::new();
+    let shared_bindings_position = init.len();
     for (prop, expression) in &component.property_init {
         if expression.use_count.get() > 0 && component.prop_used(prop) {
-            handle_property_init(prop, expression, &mut init, &ctx)
+            handle_property_init(prop, expression, &mut init, &mut shared_bindings, &ctx)
         }
     }
+    init.splice(
+        shared_bindings_position..shared_bindings_position,
+        shared_bindings.into_values().map(|(_, declaration)| declaration),
+    );
     for prop in &component.const_properties {
         if component.prop_used(prop) {
             let rust_property = access_member(prop, &ctx).unwrap();
@@ -1364,6 +1753,8 @@ fn generate_global(global: &llr::GlobalComponent, root: &llr::CompilationUnit) -
 
     let declared_functions = generate_functions(&global.functions, &ctx);
 
+    let mut shared_bindings = SharedBindings::new();
+    let shared_bindings_position = init.len();
     for (property_index, expression) in global.init_values.iter().enumerate() {
         if global.properties[property_index].use_count.get() == 0 {
             continue;
@@ -1373,10 +1764,15 @@ fn generate_global(global: &llr::GlobalComponent, root: &llr::CompilationUnit) -
                 &llr::PropertyReference::Local { sub_component_path: vec![], property_index },
                 expression,
                 &mut init,
+                &mut shared_bindings,
                 &ctx,
             )
         }
     }
+    init.splice(
+        shared_bindings_position..shared_bindings_position,
+        shared_bindings.into_values().map(|(_, declaration)| declaration),
+    );
     for (property_index, cst) in global.const_properties.iter().enumerate() {
         if global.properties[property_index].use_count.get() == 0 {
             continue;
@@ -1490,6 +1886,7 @@ fn generate_item_tree(
     parent_ctx: Option<ParentCtx>,
     index_property: Option<llr::PropertyIndex>,
     is_popup_menu: bool,
+    is_secondary_window: bool,
 ) -> TokenStream {
     let sub_comp = generate_sub_component(&sub_tree.root, root, parent_ctx, index_property, true);
     let inner_component_id = self::inner_component_id(&sub_tree.root);
@@ -1504,6 +1901,16 @@ fn generate_item_tree(
 
     let globals = if is_popup_menu {
         quote!(globals)
+    } else if is_secondary_window {
+        quote!(sp::Rc::new(
+            parent
+                .upgrade()
+                .unwrap()
+                .globals
+                .get()
+                .unwrap()
+                .new_for_window(sp::VRc::downgrade(&self_dyn_rc))
+        ))
     } else if parent_ctx.is_some() {
         quote!(parent.upgrade().unwrap().globals.get().unwrap().clone())
     } else {
@@ -1749,8 +2156,14 @@ fn generate_repeated_component(
     unit: &llr::CompilationUnit,
     parent_ctx: ParentCtx,
 ) -> TokenStream {
-    let component =
-        generate_item_tree(&repeated.sub_tree, unit, Some(parent_ctx), repeated.index_prop, false);
+    let component = generate_item_tree(
+        &repeated.sub_tree,
+        unit,
+        Some(parent_ctx),
+        repeated.index_prop,
+        false,
+        false,
+    );
 
     let ctx = EvaluationContext {
         compilation_unit: unit,
@@ -2548,6 +2961,23 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
                 sp::RadialGradientBrush::new_circle([#(#stops),*])
             ))
         }
+        Expression::ConicGradient { from_angle, stops } => {
+            let from_angle = compile_expression(from_angle, ctx);
+            let stops = stops.iter().map(|(color, stop)| {
+                let color = compile_expression(color, ctx);
+                let position = compile_expression(stop, ctx);
+                quote!(sp::GradientStop{ color: #color, position: #position as _ })
+            });
+            quote!(slint::Brush::ConicGradient(
+                sp::ConicGradientBrush::new(#from_angle as _, [#(#stops),*])
+            ))
+        }
+        Expression::FourCornerGradient { colors } => {
+            let colors = colors.iter().map(|color| compile_expression(color, ctx));
+            quote!(slint::Brush::FourCornerGradient(
+                sp::FourCornerGradientBrush::new(#(#colors),*)
+            ))
+        }
         Expression::EnumerationValue(value) => {
             let base_ident = ident(&value.enumeration.name);
             let value_ident = ident(&value.to_pascal_case());
@@ -2681,6 +3111,16 @@ fn compile_builtin_function_call(
                 let current_sub_component = parent_ctx.current_sub_component.unwrap();
                 let popup = &current_sub_component.popup_windows[*popup_index as usize];
                 let popup_window_id = inner_component_id(&popup.item_tree.root);
+                let popup_id_name = internal_popup_id(*popup_index as usize);
+                if popup.is_window {
+                    return quote!({
+                        let _ = sp::show_secondary_window(&#component_access_tokens.#popup_id_name, || {
+                            let window_instance = #popup_window_id::new(#component_access_tokens.self_weak.get().unwrap().clone())?;
+                            #popup_window_id::user_init(sp::VRc::map(window_instance.clone(), |x| x));
+                            core::result::Result::Ok(sp::VRc::into_dyn(window_instance))
+                        });
+                    });
+                }
                 let parent_component = access_item_rc(parent_ref, ctx);
 
                 let popup_ctx = EvaluationContext::new_sub_component(
@@ -2693,7 +3133,6 @@ fn compile_builtin_function_call(
 
                 let close_policy = compile_expression(close_policy, ctx);
                 let window_adapter_tokens = access_window_adapter_field(ctx);
-                let popup_id_name = internal_popup_id(*popup_index as usize);
                 quote!({
                     let popup_instance = #popup_window_id::new(#component_access_tokens.self_weak.get().unwrap().clone()).unwrap();
                     let popup_instance_vrc = sp::VRc::map(popup_instance.clone(), |x| x);
@@ -2728,8 +3167,12 @@ fn compile_builtin_function_call(
                         parent_ctx = parent_ctx.parent.as_ref().unwrap().ctx;
                     }
                 }
-                let window_adapter_tokens = access_window_adapter_field(ctx);
                 let popup_id_name = internal_popup_id(*popup_index as usize);
+                let current_sub_component = parent_ctx.current_sub_component.unwrap();
+                if current_sub_component.popup_windows[*popup_index as usize].is_window {
+                    return quote!(sp::close_secondary_window(&#component_access_tokens.#popup_id_name));
+                }
+                let window_adapter_tokens = access_window_adapter_field(ctx);
                 quote!(
                     if let Some(current_id) = #component_access_tokens.#popup_id_name.take() {
                         sp::WindowInner::from_pub(#window_adapter_tokens.window()).close_popup(current_id);
@@ -2958,6 +3401,20 @@ fn compile_builtin_function_call(
             quote!(#x.with_alpha(#alpha as f32))
         }
         BuiltinFunction::ImageSize => quote!( #(#a)*.size()),
+        BuiltinFunction::ImageWithSvgFill => {
+            let (image, selector, color) =
+                (a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(#image.with_svg_fill(&#selector, #color))
+        }
+        BuiltinFunction::ImageWithSvgStroke => {
+            let (image, selector, color) =
+                (a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(#image.with_svg_stroke(&#selector, #color))
+        }
+        BuiltinFunction::ImageWithSvgVariable => {
+            let (image, name, color) = (a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(#image.with_svg_variable(&#name, #color))
+        }
         BuiltinFunction::ArrayLength => {
             quote!(match &#(#a)* { x => {
                 x.model_tracker().track_row_count_changes();
@@ -2995,19 +3452,24 @@ fn compile_builtin_function_call(
             let window_adapter_tokens = access_window_adapter_field(ctx);
             quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).supports_native_menu_bar())
         }
-        BuiltinFunction::SetupNativeMenuBar => {
+        BuiltinFunction::SetupNativeMenuBar | BuiltinFunction::SetupWindowMenu => {
             let window_adapter_tokens = access_window_adapter_field(ctx);
             let [entries, Expression::PropertyReference(sub_menu), Expression::PropertyReference(activated)] =
                 arguments
             else {
-                panic!("internal error: incorrect arguments to SetupNativeMenuBar")
+                panic!("internal error: incorrect arguments to {function:?}")
+            };
+            let register = if matches!(function, BuiltinFunction::SetupNativeMenuBar) {
+                quote!(register_menubar)
+            } else {
+                quote!(register_menu)
             };
             let entries = compile_expression(entries, ctx);
             let sub_menu = access_member(sub_menu, ctx).unwrap();
             let activated = access_member(activated, ctx).unwrap();
             let inner_component_id = self::inner_component_id(ctx.current_sub_component.unwrap());
             quote! {
-                if sp::WindowInner::from_pub(#window_adapter_tokens.window()).supports_native_menu_bar() {
+                {
                     // May seem overkill to have an instance of the struct for each call, but there should only be one call per component anyway
                     struct MenuBarWrapper(sp::VWeakMapped<sp::ItemTreeVTable, #inner_component_id>);
                     const _ : () = {
@@ -3030,7 +3492,8 @@ fn activate(&self, entry: &sp::MenuEntry) {
                             #activated.call(&(entry.clone(),))
                         }
                     }
-                    sp::WindowInner::from_pub(#window_adapter_tokens.window()).setup_menubar(sp::VBox::new(MenuBarWrapper(_self.self_weak.get().unwrap().clone())));
+                    let self_weak = _self.self_weak.get().unwrap().clone();
+                    sp::WindowInner::from_pub(#window_adapter_tokens.window()).#register(move || sp::VBox::new(MenuBarWrapper(self_weak.clone())));
                 }
             }
         }
@@ -3042,6 +3505,9 @@ fn activate(&self, entry: &sp::MenuEntry) {
             let (m, y) = (a.next().unwrap(), a.next().unwrap());
             quote!(sp::month_offset(#m as u32, #y as i32))
         }
+        BuiltinFunction::FirstDayOfWeek => {
+            quote!(sp::first_day_of_week())
+        }
         BuiltinFunction::FormatDate => {
             let (f, d, m, y) =
                 (a.next().unwrap(), a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
@@ -3058,6 +3524,79 @@ fn activate(&self, entry: &sp::MenuEntry) {
         BuiltinFunction::DateNow => {
             quote!(sp::ModelRc::new(sp::VecModel::from_slice(&sp::date_now())))
         }
+        BuiltinFunction::ParseHexColor => {
+            let (text, fallback) = (a.next().unwrap(), a.next().unwrap());
+            quote!(sp::parse_hex_color(#text.as_str()).unwrap_or(#fallback))
+        }
+        BuiltinFunction::ChartLineCommands => {
+            let (values, min, max, w, h, area) = (
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+            );
+            quote!(sp::line_chart_commands(&#values.iter().collect::<sp::Vec<f32>>(), #min as f32, #max as f32, #w as f32, #h as f32, #area))
+        }
+        BuiltinFunction::ChartPieSliceCommands => {
+            let (values, index) = (a.next().unwrap(), a.next().unwrap());
+            quote!(usize::try_from(#index).map(|index| sp::pie_slice_commands(&#values.iter().collect::<sp::Vec<f32>>(), index)).unwrap_or_default())
+        }
+        BuiltinFunction::ChartPieSliceAt => {
+            let (values, angle) = (a.next().unwrap(), a.next().unwrap());
+            quote!(sp::pie_slice_at(&#values.iter().collect::<sp::Vec<f32>>(), #angle as f32))
+        }
+        BuiltinFunction::PathInterpolate => {
+            let (from, to, t) = (a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(sp::interpolate_path_commands(#from.as_str(), #to.as_str(), #t as f32).unwrap_or_default())
+        }
+        BuiltinFunction::PathUnion => {
+            let (lhs, rhs) = (a.next().unwrap(), a.next().unwrap());
+            quote!(sp::path_boolean_operation(#lhs.as_str(), #rhs.as_str(), sp::PathBooleanOperation::Union).unwrap_or_default())
+        }
+        BuiltinFunction::PathIntersection => {
+            let (lhs, rhs) = (a.next().unwrap(), a.next().unwrap());
+            quote!(sp::path_boolean_operation(#lhs.as_str(), #rhs.as_str(), sp::PathBooleanOperation::Intersection).unwrap_or_default())
+        }
+        BuiltinFunction::PathDifference => {
+            let (lhs, rhs) = (a.next().unwrap(), a.next().unwrap());
+            quote!(sp::path_boolean_operation(#lhs.as_str(), #rhs.as_str(), sp::PathBooleanOperation::Difference).unwrap_or_default())
+        }
+        BuiltinFunction::FormatNumber => {
+            let (value, decimals, group) =
+                (a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(sp::format_number(#value as f64, #decimals as i32, #group))
+        }
+        BuiltinFunction::ValidNumber => {
+            let text = a.next().unwrap();
+            quote!(sp::parse_number(#text.as_str()).is_some())
+        }
+        BuiltinFunction::ParseNumber => {
+            let text = a.next().unwrap();
+            quote!(sp::parse_number(#text.as_str()).unwrap_or_default() as f32)
+        }
+        BuiltinFunction::ClipboardSetText => {
+            let text = a.next().unwrap();
+            quote!(sp::SystemClipboard::set_text(#text.as_str()))
+        }
+        BuiltinFunction::ClipboardText => {
+            quote!(sp::SystemClipboard::text().unwrap_or_default())
+        }
+        BuiltinFunction::ClipboardSetImage => {
+            let image = a.next().unwrap();
+            quote!(sp::SystemClipboard::set_image(&#image))
+        }
+        BuiltinFunction::ClipboardImage => {
+            quote!(sp::SystemClipboard::image().unwrap_or_default())
+        }
+        BuiltinFunction::ClipboardSetHtml => {
+            let (html, alt_text) = (a.next().unwrap(), a.next().unwrap());
+            quote!(sp::SystemClipboard::set_html(#html.as_str(), #alt_text.as_str()))
+        }
+        BuiltinFunction::ClipboardHtml => {
+            quote!(sp::SystemClipboard::html().unwrap_or_default())
+        }
         BuiltinFunction::TextInputFocused => {
             let window_adapter_tokens = access_window_adapter_field(ctx);
             quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).text_input_focused())
@@ -3066,6 +3605,14 @@ fn activate(&self, entry: &sp::MenuEntry) {
             let window_adapter_tokens = access_window_adapter_field(ctx);
             quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).set_text_input_focused(#(#a)*))
         }
+        BuiltinFunction::WindowToasts => {
+            let window_adapter_tokens = access_window_adapter_field(ctx);
+            quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).toasts())
+        }
+        BuiltinFunction::ActivateToast => {
+            let window_adapter_tokens = access_window_adapter_field(ctx);
+            quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).activate_toast(#(#a)*))
+        }
         BuiltinFunction::Translate => {
             quote!(slint::private_unstable_api::translate(#((#a) as _),*))
         }
@@ -3380,3 +3927,118 @@ fn generate_translations(
         const _SLINT_BUNDLED_LANGUAGES: &[&str] = &[#(#lang),*];
     )
 }
+
+#[test]
+fn runtime_styles() {
+    let source = r#"
+        import { Button } from "std-widgets.slint";
+        export struct Item { title: string }
+        export global Logic { callback clicked(Item); }
+        export component Main inherits Window {
+            in-out property <string> text;
+            Button { text: root.text; clicked => { Logic.clicked({ title: root.text }); } }
+        }
+    "#;
+    let docs = ["fluent", "material"].map(|style| {
+        let mut compiler_config = CompilerConfiguration::new(crate::generator::OutputFormat::Rust);
+        compiler_config.style = Some(style.into());
+        let mut diag = crate::diagnostics::BuildDiagnostics::default();
+        let node = crate::parser::parse(
+            source.into(),
+            Some(std::path::Path::new("main.slint")),
+            &mut diag,
+        );
+        let (doc, diag, loader) =
+            spin_on::spin_on(crate::compile_syntax_node(node, diag, compiler_config));
+        assert!(!diag.has_errors(), "{:?}", diag.to_string_vec());
+        (style, doc, loader)
+    });
+    let compiler_config = docs[0].2.compiler_config.clone();
+    let docs = docs.iter().map(|(style, doc, _)| (*style, doc)).collect::<Vec<_>>();
+    let output = generate_with_runtime_styles(&docs, &compiler_config).unwrap().to_string();
+    for expected in [
+        "pub mod r#fluent",
+        "pub mod r#material",
+        "pub struct r#Item",
+        "pub use super :: super :: r#Item ;",
+        "pub struct r#Main (MainStyle) ;",
+        "Some (\"material\") => MainStyle :: r#material (r#material :: r#Main :: new () ?)",
+        "pub fn on_clicked (& self , f : impl FnMut (r#Item) -> () + 'static) { match & self . 0 { LogicStyle :: r#fluent (inner) => inner . on_clicked (f) ,",
+        "pub fn set_text (& self , value : sp :: SharedString)",
+        "impl < 'a > slint :: Global < 'a , r#Main > for r#Logic < 'a >",
+        "impl slint :: ComponentHandle for r#Main { type Inner = sp :: Dyn ;",
+    ] {
+        assert!(output.contains(expected), "{expected} not found in\n{output}");
+    }
+    assert_eq!(output.matches("pub struct r#Item").count(), 1);
+}
+
+#[test]
+fn optimize_for_size() {
+    let source = r#"
+        export component Main inherits Window {
+            in property <string> label;
+            in property <int> count;
+            Text { text: root.label + "!"; }
+            Text { text: root.label + "!"; }
+            Text { text: root.label + "!"; }
+            Text { text: root.count; }
+        }
+    "#;
+    let generate = |optimize_for_size| {
+        let mut compiler_config = CompilerConfiguration::new(crate::generator::OutputFormat::Rust);
+        compiler_config.style = Some("fluent".into());
+        compiler_config.optimize_for_size = optimize_for_size;
+        let mut diag = crate::diagnostics::BuildDiagnostics::default();
+        let node = crate::parser::parse(
+            source.into(),
+            Some(std::path::Path::new("main.slint")),
+            &mut diag,
+        );
+        let (doc, diag, loader) =
+            spin_on::spin_on(crate::compile_syntax_node(node, diag, compiler_config));
+        assert!(!diag.has_errors(), "{:?}", diag.to_string_vec());
+        generate(&doc, &loader.compiler_config).unwrap().to_string()
+    };
+
+    let output = generate(true);
+    let main = &output[output.find("impl InnerMain").unwrap()..];
+    let label_binding = main.find("\"!\"").unwrap();
+    let shared = &main[main[..label_binding].rfind("let shared_binding_").unwrap()..];
+    let ident = &shared[4..shared.find(" =").unwrap()];
+    assert_eq!(main.matches(&format!("let {ident} =")).count(), 1, "{main}");
+    assert_eq!(main.matches(&format!("& self_rc , {ident})")).count(), 3, "{main}");
+    assert_eq!(main.matches("\"!\"").count(), 1, "{main}");
+    assert!(!generate(false).contains("shared_binding"));
+}
+
+#[test]
+fn split_files() {
+    let source = r#"
+        export global Logic { in-out property <int> count; }
+        component Row inherits Rectangle { Text { text: Logic.count; } }
+        export component Main inherits Window {
+            for i in 3: Row {}
+        }
+    "#;
+    let mut compiler_config = CompilerConfiguration::new(crate::generator::OutputFormat::Rust);
+    compiler_config.style = Some("fluent".into());
+    let mut diag = crate::diagnostics::BuildDiagnostics::default();
+    let node =
+        crate::parser::parse(source.into(), Some(std::path::Path::new("main.slint")), &mut diag);
+    let (doc, diag, loader) =
+        spin_on::spin_on(crate::compile_syntax_node(node, diag, compiler_config));
+    assert!(!diag.has_errors(), "{:?}", diag.to_string_vec());
+    let (main, files) = generate_split(&doc, &loader.compiler_config, "main").unwrap();
+    let main = main.to_string();
+    let names = files.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>();
+    for expected in ["main.global.Logic.rs", "main.component.Main.rs"] {
+        assert!(names.contains(&expected), "{expected} not found in {names:?}");
+        assert!(main.contains(&format!("include ! (\"{expected}\")")), "{main}");
+    }
+    assert!(!main.contains("struct InnerMain"), "{main}");
+    let whole = generate(&doc, &loader.compiler_config).unwrap().to_string();
+    for (_, code) in &files {
+        assert!(whole.contains(&code.to_string()));
+    }
+}
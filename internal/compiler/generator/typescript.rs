@@ -0,0 +1,219 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*! module for the TypeScript definitions generator
+
+Generates a `.d.ts` file that describes the object returned by `loadFile` in the Node.js API
+of Slint (the `slint-ui` package) for a .slint file, so that the exported components, globals,
+structs, and enums can be used in a type safe way.
+*/
+
+use crate::langtype::{Function, Type};
+use crate::object_tree::{Component, Document, PropertyVisibility};
+use std::fmt::Write;
+
+/// The names of the properties are exposed with `_` instead of `-` in the Node.js API
+fn translate_name(name: &str) -> String {
+    name.replace('-', "_")
+}
+
+fn quote_name(name: &str) -> String {
+    let name = translate_name(name);
+    if name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        name
+    } else {
+        format!("\"{name}\"")
+    }
+}
+
+/// Returns the TypeScript type for a value of the given type in the Node.js API
+fn ts_type(ty: &Type) -> String {
+    match ty {
+        Type::Float32
+        | Type::Int32
+        | Type::Duration
+        | Type::PhysicalLength
+        | Type::LogicalLength
+        | Type::Rem
+        | Type::Angle
+        | Type::Percent
+        | Type::UnitProduct(_) => "number".into(),
+        Type::String => "string".into(),
+        Type::Bool => "boolean".into(),
+        Type::Color | Type::Brush => "Brush".into(),
+        Type::Image => "ImageData".into(),
+        Type::Array(ty) => format!("Model<{}>", ts_type(ty)),
+        Type::Struct(s) if s.name.is_some() && s.node.is_some() => {
+            translate_name(s.name.as_ref().unwrap())
+        }
+        Type::Struct(s) => {
+            let fields = s
+                .fields
+                .iter()
+                .map(|(name, ty)| format!(" {}: {};", quote_name(name), ts_type(ty)))
+                .collect::<String>();
+            format!("{{{fields} }}")
+        }
+        Type::Enumeration(e) if e.node.is_some() => translate_name(&e.name),
+        Type::Enumeration(e) => {
+            e.values.iter().map(|v| format!("\"{v}\"")).collect::<Vec<_>>().join(" | ")
+        }
+        Type::Callback(f) | Type::Function(f) => {
+            format!("({}) => {}", arguments(f), ts_type(&f.return_type))
+        }
+        Type::Void => "void".into(),
+        _ => "any".into(),
+    }
+}
+
+fn arguments(function: &Function) -> String {
+    function
+        .args
+        .iter()
+        .enumerate()
+        .map(|(i, ty)| {
+            let name = function.arg_names.get(i).filter(|n| !n.is_empty());
+            let name = name.map_or_else(|| format!("arg_{i}"), |n| translate_name(n));
+            format!("{name}: {}", ts_type(ty))
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Write the members for the public properties, callbacks, and functions of a component or global
+fn write_members(out: &mut String, component: &Component) {
+    let root = component.root_element.borrow();
+    for (name, decl) in &root.property_declarations {
+        if !decl.expose_in_public_api || decl.visibility == PropertyVisibility::Private {
+            continue;
+        }
+        let name = quote_name(name);
+        match &decl.property_type {
+            Type::Function(f) => {
+                writeln!(out, "    {name}({}): {};", arguments(f), ts_type(&f.return_type)).unwrap()
+            }
+            ty => {
+                let readonly =
+                    if decl.visibility == PropertyVisibility::Output { "readonly " } else { "" };
+                writeln!(out, "    {readonly}{name}: {};", ts_type(ty)).unwrap()
+            }
+        }
+    }
+}
+
+/// Generate the TypeScript definitions for the exported items of the document
+pub fn generate(doc: &Document) -> String {
+    let mut out = String::new();
+    let mut module = String::new();
+    out += "// This file is generated by the Slint compiler. Do not edit.\n\n";
+    out += "import type { Brush, ComponentHandle, ImageData, Model } from \"slint-ui\";\n";
+
+    for ty in doc.used_types.borrow().structs_and_enums.iter() {
+        match ty {
+            Type::Struct(s) if s.name.is_some() && s.node.is_some() => {
+                let name = translate_name(s.name.as_ref().unwrap());
+                writeln!(out, "\nexport interface {name} {{").unwrap();
+                for (field, ty) in &s.fields {
+                    writeln!(out, "    {}: {};", quote_name(field), ts_type(ty)).unwrap();
+                }
+                out += "}\n";
+                writeln!(module, "    {name}: (properties?: Partial<{name}>) => {name};").unwrap();
+            }
+            Type::Enumeration(e) if e.node.is_some() => {
+                let name = translate_name(&e.name);
+                let values = e.values.iter().map(|v| format!("\"{v}\"")).collect::<Vec<_>>();
+                writeln!(out, "\nexport type {name} = {};", values.join(" | ")).unwrap();
+                let members = e
+                    .values
+                    .iter()
+                    .map(|v| format!(" readonly {}: \"{v}\";", translate_name(v)))
+                    .collect::<String>();
+                writeln!(module, "    {name}: {{{members} }};").unwrap();
+            }
+            _ => {}
+        }
+    }
+
+    let mut globals = Vec::new();
+    for global in doc.used_types.borrow().globals.iter() {
+        let exported_names = global.exported_global_names.borrow();
+        let Some(first) = exported_names.first() else { continue };
+        writeln!(out, "\nexport interface {} {{", first.name).unwrap();
+        write_members(&mut out, global);
+        out += "}\n";
+        for name in exported_names.iter() {
+            if name.name != first.name {
+                writeln!(out, "\nexport type {} = {};", name.name, first.name).unwrap();
+            }
+            globals.push(name.name.clone());
+        }
+    }
+
+    for (export, component) in doc.exports.iter() {
+        let Some(component) = component.as_ref().left().filter(|c| !c.is_global()) else {
+            continue;
+        };
+        let name = &export.name;
+        writeln!(out, "\nexport interface {name} extends ComponentHandle {{").unwrap();
+        write_members(&mut out, component);
+        for global in &globals {
+            writeln!(out, "    readonly {global}: {global};").unwrap();
+        }
+        out += "}\n";
+        writeln!(
+            module,
+            "    {name}: new (properties?: Partial<Omit<{name}, keyof ComponentHandle>>) => {name};"
+        )
+        .unwrap();
+    }
+
+    out += "\n/** The object returned by `loadFile` for this .slint file */\n";
+    out += "export interface Module {\n";
+    out += &module;
+    out += "}\n";
+    out
+}
+
+#[test]
+fn typescript_definitions() {
+    let mut compiler_config =
+        crate::CompilerConfiguration::new(crate::generator::OutputFormat::Interpreter);
+    compiler_config.style = Some("fluent".into());
+    let mut diag = crate::diagnostics::BuildDiagnostics::default();
+    let node = crate::parser::parse(
+        r#"
+        export struct TodoItem { title: string, done: bool }
+        export enum Filter { all, not-done }
+        export global Logic {
+            pure callback format-date(year: int) -> string;
+        }
+        export component Main inherits Window {
+            in-out property <[TodoItem]> todo-items;
+            out property <Filter> filter;
+            callback add-item(string);
+            public function count() -> int { todo-items.length }
+        }
+        "#
+        .into(),
+        Some(std::path::Path::new("main.slint")),
+        &mut diag,
+    );
+    let (doc, diag, _) = spin_on::spin_on(crate::compile_syntax_node(node, diag, compiler_config));
+    assert!(!diag.has_errors(), "{:?}", diag.to_string_vec());
+    let output = generate(&doc);
+    for expected in [
+        "export interface TodoItem {\n    done: boolean;\n    title: string;\n}",
+        "export type Filter = \"all\" | \"not-done\";",
+        "    Filter: { readonly all: \"all\"; readonly not_done: \"not-done\"; };",
+        "export interface Logic {\n    format_date: (year: number) => string;\n}",
+        "    todo_items: Model<TodoItem>;",
+        "    readonly filter: Filter;",
+        "    add_item: (arg_0: string) => void;",
+        "    count(): number;",
+        "    readonly Logic: Logic;",
+        "    Main: new (properties?: Partial<Omit<Main, keyof ComponentHandle>>) => Main;",
+        "    TodoItem: (properties?: Partial<TodoItem>) => TodoItem;",
+    ] {
+        assert!(output.contains(expected), "{expected} not found in\n{output}");
+    }
+}
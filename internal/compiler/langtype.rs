@@ -363,6 +363,8 @@ pub struct BuiltinPropertyInfo {
     /// When != None, this is the initial value that we will have to set if no other binding were specified
     pub default_value: BuiltinPropertyDefault,
     pub property_visibility: PropertyVisibility,
+    /// For callbacks: whether it is declared as `pure`
+    pub declared_pure: Option<bool>,
 }
 
 impl BuiltinPropertyInfo {
@@ -371,6 +373,7 @@ pub fn new(ty: Type) -> Self {
             ty,
             default_value: BuiltinPropertyDefault::None,
             property_visibility: PropertyVisibility::InOut,
+            declared_pure: None,
         }
     }
 
@@ -436,7 +439,7 @@ pub fn lookup_property<'a>(&self, name: &'a str) -> PropertyLookupResult<'a> {
                         resolved_name,
                         property_type: p.ty.clone(),
                         property_visibility: p.property_visibility,
-                        declared_pure: None,
+                        declared_pure: p.declared_pure,
                         is_local_to_component: false,
                         is_in_direct_base: false,
                     },
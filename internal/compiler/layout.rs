@@ -518,6 +518,8 @@ pub fn implicit_layout_info_call(elem: &ElementRc, orientation: Orientation) ->
                         | "FocusScope"
                         | "Opacity"
                         | "Layer"
+                        | "ShaderEffect"
+                        | "BackdropBlur"
                         | "BoxShadow"
                         | "Clip"
                 ) =>
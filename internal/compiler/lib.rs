@@ -58,6 +58,35 @@ pub enum EmbedResourcesKind {
     EmbedTextures,
 }
 
+/// Select which fonts are embedded when the fonts are embedded in the generated code
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FontEmbedding {
+    /// When not empty, only the fonts of these families are embedded.
+    ///
+    /// The custom fonts imported in .slint files that are not embedded are loaded from
+    /// their path at run-time, and the other fonts are left to the system.
+    pub families: Vec<String>,
+    /// When not empty, only the font faces of these weights are embedded (for example 400 for
+    /// regular and 700 for bold)
+    pub weights: Vec<u16>,
+    /// When embedding textures, only embed the glyphs of the characters used in string literals
+    /// and in the bundled translations, instead of also embedding the glyphs of the ASCII characters.
+    pub subset_glyphs: bool,
+}
+
+impl FontEmbedding {
+    /// Returns true if a font face of the given family and weight must be embedded
+    pub fn embeds(&self, family: &str, weight: u16) -> bool {
+        (self.families.is_empty() || self.families.iter().any(|f| f.eq_ignore_ascii_case(family)))
+            && (self.weights.is_empty() || self.weights.contains(&weight))
+    }
+
+    /// Returns true if all the fonts are embedded
+    pub fn embeds_all(&self) -> bool {
+        self.families.is_empty() && self.weights.is_empty()
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Default)]
 #[non_exhaustive]
 pub enum ComponentSelection {
@@ -88,12 +117,21 @@ pub enum ComponentSelection {
     >,
 >;
 
+/// A pass registered with [`CompilerConfiguration::custom_passes`].
+///
+/// It is called with the document of the root file once the elements and expressions are resolved
+/// and type checked, and before the elements are lowered for the code generation.
+/// It can inspect and modify the elements and bindings of the components, and report diagnostics.
+pub type CustomPass = Rc<dyn Fn(&mut object_tree::Document, &mut diagnostics::BuildDiagnostics)>;
+
 /// CompilationConfiguration allows configuring different aspects of the compiler.
 #[derive(Clone)]
 pub struct CompilerConfiguration {
     /// Indicate whether to embed resources such as images in the generated output or whether
     /// to retain references to the resources on the file system.
     pub embed_resources: EmbedResourcesKind,
+    /// Select which fonts are embedded when [`Self::embed_resources`] embeds resources
+    pub font_embedding: FontEmbedding,
     /// The compiler will look in these paths for components used in the file to compile.
     pub include_paths: Vec<std::path::PathBuf>,
     /// The compiler will look in these paths for library imports.
@@ -142,8 +180,46 @@ pub struct CompilerConfiguration {
     /// Generate debug information for elements (ids, type names)
     pub debug_info: bool,
 
+    /// Emit the location in the .slint files of the bindings in the generated code.
+    /// (Only supported by the Rust generator)
+    pub emit_source_locations: bool,
+
+    /// Generate smaller code at the cost of run-time speed, for microcontrollers where the flash
+    /// size is limited, by sharing one function between the bindings with the same code in a
+    /// component. This is the only size optimization: the item trees and the layout code are
+    /// generated the same way with or without it.
+    /// (Only supported by the Rust generator)
+    pub optimize_for_size: bool,
+
+    /// Replace the `Image` elements that show a simple SVG, made of paths filled with a single
+    /// color, with a `Path` element, so that no SVG decoder is needed at run-time.
+    /// This has no effect when the images are embedded as textures for the software renderer.
+    /// (Only supported with the `software-renderer` feature)
+    pub svg_to_path: bool,
+
+    /// Add the toast layer of the style on top of the content of the exported windows, to show
+    /// the toasts of `slint::Toast`. Disabled by default, as it adds elements to every window.
+    /// (Only supported by the Rust generator and the interpreter)
+    pub toasts: bool,
+
+    /// Report the exported components that are never used, the properties that are never read,
+    /// and the callbacks that are never invoked, as warnings.
+    pub report_unused_items: bool,
+
+    /// Passes that are run, in order, on the typed object tree before the lowering passes
+    pub custom_passes: Vec<CustomPass>,
+
+    /// The names, with an optional value, that are set for the `@cfg(...)` conditions.
+    /// An element annotated with `@cfg(name)` or `@cfg(name = "value")` is only compiled if
+    /// its condition matches these defines.
+    pub defines: Vec<(String, Option<String>)>,
+
     pub components_to_generate: ComponentSelection,
 
+    /// Path and source code of a document that is loaded before the root file.
+    /// The globals and components it exports can be used from every document without import.
+    pub implicit_globals: Option<(std::path::PathBuf, String)>,
+
     #[cfg(feature = "software-renderer")]
     pub font_cache: FontCache,
 }
@@ -194,6 +270,10 @@ pub fn new(output_format: OutputFormat) -> Self {
 
         let debug_info = std::env::var_os("SLINT_EMIT_DEBUG_INFO").is_some();
 
+        let optimize_for_size = std::env::var_os("SLINT_OPTIMIZE_FOR_SIZE").is_some();
+
+        let svg_to_path = std::env::var_os("SLINT_SVG_TO_PATH").is_some();
+
         let cpp_namespace = match output_format {
             #[cfg(feature = "cpp")]
             OutputFormat::Cpp(config) => match config.namespace {
@@ -208,6 +288,7 @@ pub fn new(output_format: OutputFormat) -> Self {
 
         Self {
             embed_resources,
+            font_embedding: Default::default(),
             include_paths: Default::default(),
             library_paths: Default::default(),
             style: Default::default(),
@@ -220,7 +301,15 @@ pub fn new(output_format: OutputFormat) -> Self {
             translation_domain: None,
             cpp_namespace,
             debug_info,
+            emit_source_locations: false,
+            optimize_for_size,
+            svg_to_path,
+            toasts: false,
+            report_unused_items: false,
+            custom_passes: Vec::new(),
+            defines: Vec::new(),
             components_to_generate: ComponentSelection::ExportedWindows,
+            implicit_globals: None,
             #[cfg(feature = "software-renderer")]
             font_cache: Default::default(),
             #[cfg(feature = "bundle-translations")]
@@ -267,10 +356,10 @@ fn load_font_by_id(
     }
 }
 
-fn prepare_for_compile(
+fn prepare_config(
     diagnostics: &mut diagnostics::BuildDiagnostics,
     #[allow(unused_mut)] mut compiler_config: CompilerConfiguration,
-) -> typeloader::TypeLoader {
+) -> CompilerConfiguration {
     #[cfg(feature = "software-renderer")]
     if compiler_config.embed_resources == EmbedResourcesKind::EmbedTextures {
         // HACK: disable accessibility when compiling for the software renderer
@@ -279,6 +368,15 @@ fn prepare_for_compile(
     }
 
     diagnostics.enable_experimental = compiler_config.enable_experimental;
+    compiler_config
+}
+
+fn prepare_for_compile(
+    diagnostics: &mut diagnostics::BuildDiagnostics,
+    compiler_config: CompilerConfiguration,
+) -> typeloader::TypeLoader {
+    let compiler_config = prepare_config(diagnostics, compiler_config);
+    expression_tree::reset_local_variable_names();
 
     let global_type_registry = if compiler_config.enable_experimental {
         crate::typeregister::TypeRegister::builtin_experimental()
@@ -295,11 +393,13 @@ pub async fn compile_syntax_node(
     #[allow(unused_mut)] mut compiler_config: CompilerConfiguration,
 ) -> (object_tree::Document, diagnostics::BuildDiagnostics, typeloader::TypeLoader) {
     let mut loader = prepare_for_compile(&mut diagnostics, compiler_config);
+    loader.load_implicit_globals(&mut diagnostics).await;
 
     let doc_node: parser::syntax_nodes::Document = doc_node.into();
 
     let type_registry =
         Rc::new(RefCell::new(typeregister::TypeRegister::new(&loader.global_type_registry)));
+    type_registry.borrow_mut().defines = loader.compiler_config.defines.clone();
     let (foreign_imports, reexports) =
         loader.load_dependencies_recursively(&doc_node, &mut diagnostics, &type_registry).await;
 
@@ -333,6 +433,7 @@ pub async fn load_root_file(
     #[allow(unused_mut)] mut compiler_config: CompilerConfiguration,
 ) -> (std::path::PathBuf, diagnostics::BuildDiagnostics, typeloader::TypeLoader) {
     let mut loader = prepare_for_compile(&mut diagnostics, compiler_config);
+    loader.load_implicit_globals(&mut diagnostics).await;
 
     let (path, _) =
         loader.load_root_file(path, source_path, source_code, false, &mut diagnostics).await;
@@ -359,9 +460,54 @@ pub async fn load_root_file_with_raw_type_loader(
     Option<typeloader::TypeLoader>,
 ) {
     let mut loader = prepare_for_compile(&mut diagnostics, compiler_config);
+    loader.load_implicit_globals(&mut diagnostics).await;
+
+    let (path, raw_type_loader) =
+        loader.load_root_file(path, source_path, source_code, true, &mut diagnostics).await;
+
+    (path, diagnostics, loader, raw_type_loader)
+}
+
+/// Like `load_root_file_with_raw_type_loader`, but only load the files that changed since the
+/// previous call with the same `cache`, and the files that depend on them. The other files are
+/// reused resolved and type checked, and the passes run on the whole tree again.
+///
+/// The configuration must be the same as in the previous call. The cache is updated with the
+/// files loaded by this call, or cleared if there are errors. The raw `TypeLoader` is only
+/// returned if `keep_raw` is true.
+pub async fn load_root_file_incremental(
+    path: &Path,
+    source_path: &Path,
+    source_code: String,
+    mut diagnostics: diagnostics::BuildDiagnostics,
+    compiler_config: CompilerConfiguration,
+    keep_raw: bool,
+    cache: &mut typeloader::IncrementalCache,
+) -> (
+    std::path::PathBuf,
+    diagnostics::BuildDiagnostics,
+    typeloader::TypeLoader,
+    Option<typeloader::TypeLoader>,
+) {
+    let mut loader = match cache.0.take() {
+        Some(mut loader) => {
+            loader.compiler_config = prepare_config(&mut diagnostics, compiler_config);
+            loader.invalidate_changed_documents().await;
+            loader
+        }
+        None => {
+            let mut loader = prepare_for_compile(&mut diagnostics, compiler_config);
+            loader.load_implicit_globals(&mut diagnostics).await;
+            loader
+        }
+    };
 
+    // The passes lower the loaded documents in place, so the cache keeps their state before
     let (path, raw_type_loader) =
         loader.load_root_file(path, source_path, source_code, true, &mut diagnostics).await;
+    cache.0 = raw_type_loader;
+    let raw_type_loader =
+        if keep_raw { cache.0.as_ref().and_then(typeloader::snapshot) } else { None };
 
     (path, diagnostics, loader, raw_type_loader)
 }
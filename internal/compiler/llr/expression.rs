@@ -8,7 +8,7 @@
 use core::num::NonZeroUsize;
 use itertools::Either;
 use smol_str::SmolStr;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone)]
 pub enum Expression {
@@ -136,7 +136,7 @@ pub enum Expression {
     },
     Struct {
         ty: Type,
-        values: HashMap<SmolStr, Expression>,
+        values: BTreeMap<SmolStr, Expression>,
     },
 
     EasingCurve(crate::expression_tree::EasingCurve),
@@ -152,6 +152,17 @@ pub enum Expression {
         stops: Vec<(Expression, Expression)>,
     },
 
+    ConicGradient {
+        from_angle: Box<Expression>,
+        /// First expression in the tuple is a color, second expression is the stop position as a fraction of a turn
+        stops: Vec<(Expression, Expression)>,
+    },
+
+    FourCornerGradient {
+        /// The colors of the top-left, top-right, bottom-right, and bottom-left corners
+        colors: Vec<Expression>,
+    },
+
     EnumerationValue(crate::langtype::EnumerationValue),
 
     LayoutCacheAccess {
@@ -304,6 +315,8 @@ pub fn ty(&self, ctx: &dyn TypeResolutionContext) -> Type {
             Self::EasingCurve(_) => Type::Easing,
             Self::LinearGradient { .. } => Type::Brush,
             Self::RadialGradient { .. } => Type::Brush,
+            Self::ConicGradient { .. } => Type::Brush,
+            Self::FourCornerGradient { .. } => Type::Brush,
             Self::EnumerationValue(e) => Type::Enumeration(e.enumeration.clone()),
             Self::LayoutCacheAccess { .. } => Type::LogicalLength,
             Self::BoxLayoutFunction { sub_expression, .. } => sub_expression.ty(ctx),
@@ -376,6 +389,14 @@ macro_rules! visit_impl {
                     $visitor(b);
                 }
             }
+            Expression::ConicGradient { from_angle, stops } => {
+                $visitor(from_angle);
+                for (a, b) in stops {
+                    $visitor(a);
+                    $visitor(b);
+                }
+            }
+            Expression::FourCornerGradient { colors } => colors.$iter().for_each($visitor),
             Expression::EnumerationValue(_) => {}
             Expression::LayoutCacheAccess { repeater_index, .. } => {
                 if let Some(repeater_index) = repeater_index {
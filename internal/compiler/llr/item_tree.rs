@@ -47,6 +47,9 @@ pub struct BindingExpression {
     /// The amount of time this binding is used
     /// This property is only valid after the [`count_property_use`](super::optim_passes::count_property_use) pass
     pub use_count: Cell<usize>,
+
+    /// The location of the binding in the .slint file
+    pub source_location: Option<crate::diagnostics::SourceLocation>,
 }
 
 #[derive(Debug)]
@@ -128,6 +131,8 @@ pub struct ListViewInfo {
     pub listview_height: PropertyReference,
     /// The ListView's inner visible width (not counting eventual scrollbar)
     pub listview_width: PropertyReference,
+    /// The callback of the ListView to scroll a row to the top, whose handler calls the repeater
+    pub scroll_to_row: Option<PropertyReference>,
 
     // In the repeated component context
     pub prop_y: PropertyReference,
@@ -273,6 +278,8 @@ pub struct SubComponent {
 pub struct PopupWindow {
     pub item_tree: ItemTree,
     pub position: MutExpression,
+    /// The popup is a `Window` declared inside another component, shown in its own top-level window
+    pub is_window: bool,
 }
 
 #[derive(Debug)]
@@ -377,6 +384,10 @@ pub struct CompilationUnit {
     pub globals: Vec<GlobalComponent>,
     pub popup_menu: Option<PopupMenu>,
     pub has_debug_info: bool,
+    /// When true, the generators should emit the source location of the bindings
+    pub has_source_locations: bool,
+    /// When true, the generators should prefer smaller code over faster code
+    pub optimize_for_size: bool,
     #[cfg(feature = "bundle-translations")]
     pub translations: Option<super::translations::Translations>,
 }
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 use std::cell::RefCell;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::BTreeMap;
 use std::num::NonZeroUsize;
 use std::rc::{Rc, Weak};
 
@@ -202,6 +202,16 @@ pub fn lower_expression(
                 .map(|(a, b)| (lower_expression(a, ctx), lower_expression(b, ctx)))
                 .collect::<_>(),
         },
+        tree_Expression::ConicGradient { from_angle, stops } => llr_Expression::ConicGradient {
+            from_angle: Box::new(lower_expression(from_angle, ctx)),
+            stops: stops
+                .iter()
+                .map(|(a, b)| (lower_expression(a, ctx), lower_expression(b, ctx)))
+                .collect::<_>(),
+        },
+        tree_Expression::FourCornerGradient { colors } => llr_Expression::FourCornerGradient {
+            colors: colors.iter().map(|c| lower_expression(c, ctx)).collect::<_>(),
+        },
         tree_Expression::EnumerationValue(e) => llr_Expression::EnumerationValue(e.clone()),
         tree_Expression::ReturnStatement(..) => {
             panic!("The remove return pass should have removed all return")
@@ -250,18 +260,15 @@ fn lower_assignment(
         tree_Expression::StructFieldAccess { base, name } => {
             let ty = base.ty();
 
-            static COUNT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
-            let unique_name = format_smolstr!(
-                "struct_assignment{}",
-                COUNT.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
-            );
+            let unique_name =
+                crate::expression_tree::unique_local_variable_name("struct_assignment");
             let s = tree_Expression::StoreLocalVariable {
                 name: unique_name.clone(),
                 value: base.clone(),
             };
             let lower_base =
                 tree_Expression::ReadLocalVariable { name: unique_name, ty: ty.clone() };
-            let mut values = HashMap::new();
+            let mut values = BTreeMap::new();
             match &ty {
                 Type::Struct(s) => {
                     for field in s.fields.keys() {
@@ -880,7 +887,7 @@ pub fn get_layout_info(
                     },
                 )
             })
-            .collect::<HashMap<_, _>>();
+            .collect::<BTreeMap<_, _>>();
 
         for (nr, s) in constraints.for_each_restrictions(orientation) {
             values.insert(
@@ -1011,7 +1018,7 @@ pub fn make_struct(
     it: impl IntoIterator<Item = (&'static str, Type, llr_Expression)>,
 ) -> llr_Expression {
     let mut fields = BTreeMap::<SmolStr, Type>::new();
-    let mut values = HashMap::<SmolStr, llr_Expression>::new();
+    let mut values = BTreeMap::<SmolStr, llr_Expression>::new();
     for (name, ty, expr) in it {
         fields.insert(SmolStr::new(name), ty);
         values.insert(SmolStr::new(name), expr);
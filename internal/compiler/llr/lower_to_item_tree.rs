@@ -103,6 +103,8 @@ pub fn lower_to_item_tree(
             })
             .collect(),
         has_debug_info: compiler_config.debug_info,
+        has_source_locations: compiler_config.emit_source_locations,
+        optimize_for_size: compiler_config.optimize_for_size,
         popup_menu,
         #[cfg(feature = "bundle-translations")]
         translations: state.translation_builder.take().map(|x| x.into_inner().result()),
@@ -230,6 +232,19 @@ fn component_id(component: &Rc<Component>) -> SmolStr {
     if component.is_global() {
         component.root_element.borrow().id.clone()
     } else if component.id.is_empty() {
+        // The ids of the elements are only unique within a component, so the repeated and popup
+        // components are named after the component that contains them
+        let mut parent = component.parent_element.upgrade();
+        while let Some(enclosing) = parent.and_then(|p| p.borrow().enclosing_component.upgrade()) {
+            if !enclosing.id.is_empty() {
+                return format_smolstr!(
+                    "{}_Component_{}",
+                    enclosing.id,
+                    component.root_element.borrow().id
+                );
+            }
+            parent = enclosing.parent_element.upgrade();
+        }
         format_smolstr!("Component_{}", component.root_element.borrow().id)
     } else {
         format_smolstr!("{}_{}", component.id, component.root_element.borrow().id)
@@ -451,6 +466,7 @@ fn lower_sub_component(
                     is_constant,
                     is_state_info,
                     use_count: 0.into(),
+                    source_location: binding.span.clone(),
                 },
             ));
         }
@@ -591,7 +607,7 @@ fn lower_geometry(
     ctx: &ExpressionContext<'_>,
 ) -> super::Expression {
     let mut fields = BTreeMap::default();
-    let mut values = HashMap::with_capacity(4);
+    let mut values = BTreeMap::new();
     for (f, v) in [("x", &geom.x), ("y", &geom.y), ("width", &geom.width), ("height", &geom.height)]
     {
         fields.insert(f.into(), Type::LogicalLength);
@@ -654,6 +670,7 @@ fn lower_repeated_component(
         viewport_width: ctx.map_property_reference(&lv.viewport_width),
         listview_height: ctx.map_property_reference(&lv.listview_height),
         listview_width: ctx.map_property_reference(&lv.listview_width),
+        scroll_to_row: lv.scroll_to_row.as_ref().map(|nr| ctx.map_property_reference(nr)),
         prop_y: sc.mapping.map_property_reference(&geom.y, ctx.state),
         prop_width: sc.mapping.map_property_reference(&geom.width, ctx.state),
         prop_height: sc.mapping.map_property_reference(&geom.height, ctx.state),
@@ -727,7 +744,7 @@ fn lower_popup_component(
         ],
     );
 
-    PopupWindow { item_tree, position: position.into() }
+    PopupWindow { item_tree, position: position.into(), is_window: popup.is_window }
 }
 
 fn lower_timer(timer: &object_tree::Timer, ctx: &ExpressionContext) -> Timer {
@@ -879,6 +896,7 @@ fn lower_global_expressions(
             is_constant,
             is_state_info: false,
             use_count: 0.into(),
+            source_location: binding.borrow().span.clone(),
         });
     }
 
@@ -71,6 +71,9 @@ pub fn count_property_use(root: &CompilationUnit) {
                 visit_property(&lv.viewport_height, ctx);
                 visit_property(&lv.listview_width, ctx);
                 visit_property(&lv.listview_height, ctx);
+                if let Some(scroll_to_row) = &lv.scroll_to_row {
+                    visit_property(scroll_to_row, ctx);
+                }
 
                 let rep_ctx = EvaluationContext::new_sub_component(
                     root,
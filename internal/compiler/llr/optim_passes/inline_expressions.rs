@@ -62,6 +62,8 @@ fn expression_cost(exp: &Expression, ctx: &EvaluationContext) -> isize {
         Expression::EasingCurve(_) => 1,
         Expression::LinearGradient { .. } => ALLOC_COST,
         Expression::RadialGradient { .. } => ALLOC_COST,
+        Expression::ConicGradient { .. } => ALLOC_COST,
+        Expression::FourCornerGradient { .. } => 1,
         Expression::EnumerationValue(_) => 0,
         Expression::LayoutCacheAccess { .. } => PROPERTY_ACCESS_COST,
         Expression::BoxLayoutFunction { .. } => return isize::MAX,
@@ -119,6 +121,9 @@ fn builtin_function_cost(function: &BuiltinFunction) -> isize {
         BuiltinFunction::ColorMix => 50,
         BuiltinFunction::ColorWithAlpha => 50,
         BuiltinFunction::ImageSize => 50,
+        BuiltinFunction::ImageWithSvgFill
+        | BuiltinFunction::ImageWithSvgStroke
+        | BuiltinFunction::ImageWithSvgVariable => isize::MAX,
         BuiltinFunction::ArrayLength => 50,
         BuiltinFunction::Rgb => 50,
         BuiltinFunction::Hsv => 50,
@@ -130,14 +135,35 @@ fn builtin_function_cost(function: &BuiltinFunction) -> isize {
         BuiltinFunction::ColorScheme => PROPERTY_ACCESS_COST,
         BuiltinFunction::SupportsNativeMenuBar => 10,
         BuiltinFunction::SetupNativeMenuBar => isize::MAX,
+        BuiltinFunction::SetupWindowMenu => isize::MAX,
         BuiltinFunction::MonthDayCount => isize::MAX,
         BuiltinFunction::MonthOffset => isize::MAX,
+        BuiltinFunction::FirstDayOfWeek => isize::MAX,
         BuiltinFunction::FormatDate => isize::MAX,
         BuiltinFunction::DateNow => isize::MAX,
         BuiltinFunction::ValidDate => isize::MAX,
         BuiltinFunction::ParseDate => isize::MAX,
+        BuiltinFunction::ParseHexColor => isize::MAX,
+        BuiltinFunction::ChartLineCommands => isize::MAX,
+        BuiltinFunction::ChartPieSliceCommands => isize::MAX,
+        BuiltinFunction::ChartPieSliceAt => isize::MAX,
+        BuiltinFunction::PathInterpolate
+        | BuiltinFunction::PathUnion
+        | BuiltinFunction::PathIntersection
+        | BuiltinFunction::PathDifference => isize::MAX,
+        BuiltinFunction::FormatNumber => isize::MAX,
+        BuiltinFunction::ValidNumber => isize::MAX,
+        BuiltinFunction::ParseNumber => isize::MAX,
+        BuiltinFunction::ClipboardSetText
+        | BuiltinFunction::ClipboardText
+        | BuiltinFunction::ClipboardSetImage
+        | BuiltinFunction::ClipboardImage
+        | BuiltinFunction::ClipboardSetHtml
+        | BuiltinFunction::ClipboardHtml => isize::MAX,
         BuiltinFunction::SetTextInputFocused => PROPERTY_ACCESS_COST,
         BuiltinFunction::TextInputFocused => PROPERTY_ACCESS_COST,
+        BuiltinFunction::WindowToasts => PROPERTY_ACCESS_COST,
+        BuiltinFunction::ActivateToast => isize::MAX,
         BuiltinFunction::Translate => 2 * ALLOC_COST + PROPERTY_ACCESS_COST,
         BuiltinFunction::Use24HourFormat => 2 * ALLOC_COST + PROPERTY_ACCESS_COST,
         BuiltinFunction::UpdateTimers => 10,
@@ -329,6 +329,15 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
                 "@radial-gradient(circle, {})",
                 stops.iter().map(|(e1, e2)| format!("{} {}", e(e1), e(e2))).join(", ")
             ),
+            Expression::ConicGradient { from_angle, stops } => write!(
+                f,
+                "@conic-gradient(from {}, {})",
+                e(from_angle),
+                stops.iter().map(|(e1, e2)| format!("{} {}", e(e1), e(e2))).join(", ")
+            ),
+            Expression::FourCornerGradient { colors } => {
+                write!(f, "@four-corner-gradient({})", colors.iter().map(e).join(", "))
+            }
             Expression::EnumerationValue(x) => write!(f, "{}", x),
             Expression::LayoutCacheAccess { layout_cache_prop, index, repeater_index: None } => {
                 write!(f, "{}[{}]", DisplayPropertyRef(layout_cache_prop, ctx), index)
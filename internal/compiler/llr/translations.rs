@@ -146,6 +146,32 @@ pub fn lower_translate_call(&mut self, args: Vec<Expression>) -> Expression {
         }
     }
 
+    /// Add the characters used by all the translations of a message to `characters`
+    pub fn collect_characters(
+        &self,
+        original: &str,
+        contextid: &str,
+        plural: &str,
+        characters: &mut impl Extend<char>,
+    ) {
+        for catalog in &self.catalogs {
+            let Some(message) = catalog.find_message(
+                contextid.is_empty().not().then_some(contextid),
+                original,
+                plural.is_empty().not().then_some(plural),
+            ) else {
+                continue;
+            };
+            if let Ok(forms) = message.msgstr_plural() {
+                for form in forms {
+                    characters.extend(form.chars());
+                }
+            } else if let Ok(msgstr) = message.msgstr() {
+                characters.extend(msgstr.chars());
+            }
+        }
+    }
+
     pub fn result(self) -> Translations {
         self.result
     }
@@ -389,6 +415,8 @@ fn p(string: &str) -> String {
                     sub_components: Vec::new(),
                     globals: Vec::new(),
                     has_debug_info: false,
+                    has_source_locations: false,
+                    optimize_for_size: false,
                     translations: None,
                     popup_menu: None,
                 },
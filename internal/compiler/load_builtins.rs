@@ -101,28 +101,35 @@ pub(crate) fn load_builtins(register: &mut TypeRegister) {
                     (prop_name, info)
                 })
                 .chain(e.CallbackDeclaration().map(|s| {
-                    (
-                        identifier_text(&s.DeclaredIdentifier()).unwrap(),
-                        BuiltinPropertyInfo::new(Type::Callback(Rc::new(Function{
-                            args: s
-                                .CallbackDeclarationParameter()
-                                .map(|a| {
-                                    object_tree::type_from_node(a.Type(), *diag.borrow_mut(), register)
-                                })
-                                .collect(),
-                            return_type: s.ReturnType().map(|a| {
-                                object_tree::type_from_node(
-                                    a.Type(),
-                                    *diag.borrow_mut(),
-                                    register,
-                                )
-                            }).unwrap_or(Type::Void),
-                            arg_names: s
-                                .CallbackDeclarationParameter()
-                                .map(|a| a.DeclaredIdentifier().and_then(|x| identifier_text(&x)).unwrap_or_default())
-                                .collect()
-                        }))),
-                    )
+                    let pure =
+                        s.child_token(SyntaxKind::Identifier).is_some_and(|t| t.text() == "pure");
+                    let function = Function {
+                        args: s
+                            .CallbackDeclarationParameter()
+                            .map(|a| {
+                                object_tree::type_from_node(a.Type(), *diag.borrow_mut(), register)
+                            })
+                            .collect(),
+                        return_type: s
+                            .ReturnType()
+                            .map(|a| {
+                                object_tree::type_from_node(a.Type(), *diag.borrow_mut(), register)
+                            })
+                            .unwrap_or(Type::Void),
+                        arg_names: s
+                            .CallbackDeclarationParameter()
+                            .map(|a| {
+                                a.DeclaredIdentifier()
+                                    .and_then(|x| identifier_text(&x))
+                                    .unwrap_or_default()
+                            })
+                            .collect(),
+                    };
+                    let info = BuiltinPropertyInfo {
+                        declared_pure: Some(pure),
+                        ..BuiltinPropertyInfo::new(Type::Callback(Rc::new(function)))
+                    };
+                    (identifier_text(&s.DeclaredIdentifier()).unwrap(), info)
                 }))
         );
         n.deprecated_aliases = e
@@ -17,7 +17,7 @@
 use smol_str::{SmolStr, ToSmolStr};
 use std::cell::RefCell;
 
-mod named_colors;
+pub mod named_colors;
 
 /// Contains information which allow to lookup identifier in expressions
 pub struct LookupCtx<'a> {
@@ -100,6 +100,7 @@ pub enum BuiltinNamespace {
     Colors,
     Math,
     Key,
+    Clipboard,
     SlintInternal,
 }
 
@@ -163,6 +164,9 @@ fn for_each_entry<R>(
             }
             LookupResult::Namespace(BuiltinNamespace::Math) => MathFunctions.for_each_entry(ctx, f),
             LookupResult::Namespace(BuiltinNamespace::Key) => KeysLookup.for_each_entry(ctx, f),
+            LookupResult::Namespace(BuiltinNamespace::Clipboard) => {
+                ClipboardFunctions.for_each_entry(ctx, f)
+            }
             LookupResult::Namespace(BuiltinNamespace::SlintInternal) => {
                 SlintInternal.for_each_entry(ctx, f)
             }
@@ -178,6 +182,9 @@ fn lookup(&self, ctx: &LookupCtx, name: &SmolStr) -> Option<LookupResult> {
             }
             LookupResult::Namespace(BuiltinNamespace::Math) => MathFunctions.lookup(ctx, name),
             LookupResult::Namespace(BuiltinNamespace::Key) => KeysLookup.lookup(ctx, name),
+            LookupResult::Namespace(BuiltinNamespace::Clipboard) => {
+                ClipboardFunctions.lookup(ctx, name)
+            }
             LookupResult::Namespace(BuiltinNamespace::SlintInternal) => {
                 SlintInternal.lookup(ctx, name)
             }
@@ -759,6 +766,25 @@ fn for_each_entry<R>(
     }
 }
 
+struct ClipboardFunctions;
+impl LookupObject for ClipboardFunctions {
+    fn for_each_entry<R>(
+        &self,
+        ctx: &LookupCtx,
+        f: &mut impl FnMut(&SmolStr, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        use Expression::BuiltinFunctionReference as BFR;
+        let sl = || ctx.current_token.as_ref().map(|t| t.to_source_location());
+        let mut f = |n, e: Expression| f(&SmolStr::new_static(n), e.into());
+        None.or_else(|| f("set-text", BFR(BuiltinFunction::ClipboardSetText, sl())))
+            .or_else(|| f("text", BFR(BuiltinFunction::ClipboardText, sl())))
+            .or_else(|| f("set-image", BFR(BuiltinFunction::ClipboardSetImage, sl())))
+            .or_else(|| f("image", BFR(BuiltinFunction::ClipboardImage, sl())))
+            .or_else(|| f("set-html", BFR(BuiltinFunction::ClipboardSetHtml, sl())))
+            .or_else(|| f("html", BFR(BuiltinFunction::ClipboardHtml, sl())))
+    }
+}
+
 struct SlintInternal;
 impl LookupObject for SlintInternal {
     fn for_each_entry<R>(
@@ -800,10 +826,20 @@ fn for_each_entry<R>(
         })
         .or_else(|| f("month-day-count", BFR(BuiltinFunction::MonthDayCount, sl())))
         .or_else(|| f("month-offset", BFR(BuiltinFunction::MonthOffset, sl())))
+        .or_else(|| f("first-day-of-week", BFR(BuiltinFunction::FirstDayOfWeek, sl())))
         .or_else(|| f("format-date", BFR(BuiltinFunction::FormatDate, sl())))
         .or_else(|| f("date-now", BFR(BuiltinFunction::DateNow, sl())))
         .or_else(|| f("valid-date", BFR(BuiltinFunction::ValidDate, sl())))
         .or_else(|| f("parse-date", BFR(BuiltinFunction::ParseDate, sl())))
+        .or_else(|| f("parse-hex-color", BFR(BuiltinFunction::ParseHexColor, sl())))
+        .or_else(|| f("chart-line-commands", BFR(BuiltinFunction::ChartLineCommands, sl())))
+        .or_else(|| {
+            f("chart-pie-slice-commands", BFR(BuiltinFunction::ChartPieSliceCommands, sl()))
+        })
+        .or_else(|| f("chart-pie-slice-at", BFR(BuiltinFunction::ChartPieSliceAt, sl())))
+        .or_else(|| f("format-number", BFR(BuiltinFunction::FormatNumber, sl())))
+        .or_else(|| f("valid-number", BFR(BuiltinFunction::ValidNumber, sl())))
+        .or_else(|| f("parse-number", BFR(BuiltinFunction::ParseNumber, sl())))
     }
 }
 
@@ -820,6 +856,9 @@ fn for_each_entry<R>(
         None.or_else(|| f("rgb", BuiltinMacroReference(BuiltinMacroFunction::Rgb, t.clone())))
             .or_else(|| f("rgba", BuiltinMacroReference(BuiltinMacroFunction::Rgb, t.clone())))
             .or_else(|| f("hsv", BuiltinMacroReference(BuiltinMacroFunction::Hsv, t.clone())))
+            .or_else(|| {
+                f("display-p3", BuiltinMacroReference(BuiltinMacroFunction::DisplayP3, t.clone()))
+            })
     }
 }
 
@@ -852,6 +891,16 @@ fn for_each_entry<R>(
                     .into(),
                 )
             })
+            .or_else(|| {
+                let sl = || ctx.current_token.as_ref().map(|t| t.to_source_location());
+                let mut f = |n, b| {
+                    f(&SmolStr::new_static(n), Expression::BuiltinFunctionReference(b, sl()).into())
+                };
+                None.or_else(|| f("path-interpolate", BuiltinFunction::PathInterpolate))
+                    .or_else(|| f("path-union", BuiltinFunction::PathUnion))
+                    .or_else(|| f("path-intersection", BuiltinFunction::PathIntersection))
+                    .or_else(|| f("path-difference", BuiltinFunction::PathDifference))
+            })
     }
 }
 
@@ -866,6 +915,7 @@ fn for_each_entry<R>(
         None.or_else(|| f("Colors", LookupResult::Namespace(BuiltinNamespace::Colors)))
             .or_else(|| f("Math", LookupResult::Namespace(BuiltinNamespace::Math)))
             .or_else(|| f("Key", LookupResult::Namespace(BuiltinNamespace::Key)))
+            .or_else(|| f("Clipboard", LookupResult::Namespace(BuiltinNamespace::Clipboard)))
             .or_else(|| {
                 if ctx.type_register.expose_internal_types {
                     f("SlintInternal", LookupResult::Namespace(BuiltinNamespace::SlintInternal))
@@ -1057,9 +1107,24 @@ fn for_each_entry<R>(
                 name: f.into(),
             })
         };
+        let member_function = |f: BuiltinFunction| {
+            LookupResult::from(Expression::MemberFunction {
+                base: Box::new(self.0.clone()),
+                base_node: ctx.current_token.clone(), // Note that this is not the base_node, but the function's node
+                member: Box::new(Expression::BuiltinFunctionReference(
+                    f,
+                    ctx.current_token.as_ref().map(|t| t.to_source_location()),
+                )),
+            })
+        };
         let mut f = |s, res| f(&SmolStr::new_static(s), res);
         None.or_else(|| f("width", field_access("width")))
             .or_else(|| f("height", field_access("height")))
+            .or_else(|| f("with-svg-fill", member_function(BuiltinFunction::ImageWithSvgFill)))
+            .or_else(|| f("with-svg-stroke", member_function(BuiltinFunction::ImageWithSvgStroke)))
+            .or_else(|| {
+                f("with-svg-variable", member_function(BuiltinFunction::ImageWithSvgVariable))
+            })
     }
 }
 
@@ -43,7 +43,7 @@ pub fn new(element: &ElementRc, name: SmolStr) -> Self {
         Self(NamedReferenceInner::from_name(element, name))
     }
     pub(crate) fn snapshot(&self, snapshotter: &mut crate::typeloader::Snapshotter) -> Self {
-        NamedReference(Rc::new(self.0.snapshot(snapshotter)))
+        NamedReference(snapshotter.snapshot_named_reference(&self.0))
     }
     pub fn name(&self) -> &SmolStr {
         &self.0.name
@@ -151,7 +151,7 @@ fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
     }
 }
 
-struct NamedReferenceInner {
+pub(crate) struct NamedReferenceInner {
     /// The element.
     element: Weak<RefCell<Element>>,
     /// The property name
@@ -217,7 +217,7 @@ pub(crate) fn snapshot(
             .0
             .borrow()
             .iter()
-            .map(|(k, v)| (k.clone(), Rc::new(v.snapshot(snapshotter))))
+            .map(|(k, v)| (k.clone(), snapshotter.snapshot_named_reference(v)))
             .collect();
         NamedReferenceContainer(RefCell::new(inner))
     }
@@ -58,7 +58,7 @@ pub struct Document {
     /// Map of resources that should be embedded in the generated code, indexed by their absolute path on
     /// disk on the build system
     pub embedded_file_resources:
-        RefCell<HashMap<SmolStr, crate::embedded_resources::EmbeddedResources>>,
+        RefCell<BTreeMap<SmolStr, crate::embedded_resources::EmbeddedResources>>,
 
     /// The list of used extra types used recursively.
     pub used_types: RefCell<UsedSubTypes>,
@@ -304,6 +304,8 @@ pub struct PopupWindow {
     pub y: NamedReference,
     pub close_policy: EnumerationValue,
     pub parent_element: ElementRc,
+    /// True for a `Window` declared inside another component, that is shown in its own top-level window
+    pub is_window: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -892,6 +894,9 @@ pub struct ListViewInfo {
     pub listview_height: NamedReference,
     /// The ListView's inner visible width (not counting eventual scrollbar)
     pub listview_width: NamedReference,
+    /// The callback of the ListView to scroll a row to the top, handled by the repeater.
+    /// `None` for the ListViews that don't declare it, such as the internal one of the Qt style.
+    pub scroll_to_row: Option<NamedReference>,
 }
 
 #[derive(Debug, Clone)]
@@ -1350,10 +1355,13 @@ pub fn from_node(
                         };
                         let lookup_result = r.lookup_property(unresolved_prop_name);
                         let valid_assign = lookup_result.is_valid_for_assignment();
+                        let is_path_commands =
+                            is_path_commands(&r, lookup_result.resolved_name.as_ref());
                         if let Some(anim_element) = animation_element_from_node(
                             &anim,
                             &prop_name_token,
                             lookup_result.property_type,
+                            is_path_commands,
                             diag,
                             tr,
                         ) {
@@ -1450,7 +1458,31 @@ pub fn from_node(
         let mut children_placeholder = None;
         let r = r.make_rc();
 
-        for se in node.children() {
+        // The children, where the `@cfg(...)` elements are replaced by their element, or removed
+        // if their condition doesn't match the defines
+        let children = node
+            .children()
+            .filter_map(|se| {
+                if se.kind() != SyntaxKind::CfgElement {
+                    return Some(se);
+                }
+                let cfg: syntax_nodes::CfgElement = se.into();
+                cfg_condition_matches(&cfg.CfgCondition(), &tr.defines, diag)
+                    .then(|| {
+                        cfg.children().find(|n| {
+                            matches!(
+                                n.kind(),
+                                SyntaxKind::SubElement
+                                    | SyntaxKind::RepeatedElement
+                                    | SyntaxKind::ConditionalElement
+                            )
+                        })
+                    })
+                    .flatten()
+            })
+            .collect::<Vec<_>>();
+
+        for se in children.iter().cloned() {
             if se.kind() == SyntaxKind::SubElement {
                 let parent_type = r.borrow().base_type.clone();
                 r.borrow_mut().children.push(Element::from_sub_element_node(
@@ -1556,9 +1588,9 @@ pub fn from_node(
             }
         }
 
-        if r.borrow().base_type.to_smolstr() == "ListView" {
+        if matches!(r.borrow().base_type.to_smolstr().as_str(), "ListView" | "TreeView") {
             let mut seen_for = false;
-            for se in node.children() {
+            for se in children {
                 if se.kind() == SyntaxKind::RepeatedElement && !seen_for {
                     seen_for = true;
                 } else if matches!(
@@ -1611,7 +1643,8 @@ fn from_repeated_node(
         diag: &mut BuildDiagnostics,
         tr: &TypeRegister,
     ) -> ElementRc {
-        let is_listview = if parent.borrow().base_type.to_string() == "ListView" {
+        let parent_type = parent.borrow().base_type.to_smolstr();
+        let is_listview = if matches!(parent_type.as_str(), "ListView" | "TreeView") {
             Some(ListViewInfo {
                 viewport_y: NamedReference::new(parent, SmolStr::new_static("viewport-y")),
                 viewport_height: NamedReference::new(
@@ -1621,6 +1654,13 @@ fn from_repeated_node(
                 viewport_width: NamedReference::new(parent, SmolStr::new_static("viewport-width")),
                 listview_height: NamedReference::new(parent, SmolStr::new_static("visible-height")),
                 listview_width: NamedReference::new(parent, SmolStr::new_static("visible-width")),
+                scroll_to_row: matches!(
+                    parent.borrow().lookup_property("scroll-to-row-requested").property_type,
+                    Type::Callback(_)
+                )
+                .then(|| {
+                    NamedReference::new(parent, SmolStr::new_static("scroll-to-row-requested"))
+                }),
             })
         } else {
             None
@@ -1753,7 +1793,7 @@ fn parse_bindings(
             }
 
             if *lookup_result.resolved_name != *unresolved_name {
-                diag.push_property_deprecation_warning(
+                diag.push_property_deprecation_warning_for_token(
                     &unresolved_name,
                     &lookup_result.resolved_name,
                     &name_token,
@@ -1894,6 +1934,42 @@ pub fn element_infos(&self) -> String {
     }
 }
 
+/// Evaluate the condition of a `@cfg(...)` element against the defines of the compiler configuration
+fn cfg_condition_matches(
+    node: &syntax_nodes::CfgCondition,
+    defines: &[(String, Option<String>)],
+    diag: &mut BuildDiagnostics,
+) -> bool {
+    let Some(name) = parser::identifier_text(node) else { return false };
+    let sub_conditions = node.CfgCondition().collect::<Vec<_>>();
+    if node.child_token(SyntaxKind::LParent).is_some() {
+        let mut results = sub_conditions.iter().map(|c| cfg_condition_matches(c, defines, diag));
+        return match name.as_str() {
+            "all" => results.fold(true, |acc, r| acc & r),
+            "any" => results.fold(false, |acc, r| acc | r),
+            "not" if sub_conditions.len() == 1 => !results.next().unwrap(),
+            "not" => {
+                diag.push_error("'not' in @cfg expects exactly one condition".into(), node);
+                false
+            }
+            _ => {
+                diag.push_error(
+                    format!("Unknown @cfg predicate '{name}'. Expected 'all', 'any', or 'not'"),
+                    node,
+                );
+                false
+            }
+        };
+    }
+    let value = node
+        .child_token(SyntaxKind::StringLiteral)
+        .map(|t| crate::literals::unescape_string(t.text()).unwrap_or_default());
+    defines.iter().any(|(define, define_value)| {
+        parser::normalize_identifier(define) == name
+            && value.as_ref().map_or(true, |v| define_value.as_deref() == Some(v.as_str()))
+    })
+}
+
 /// Apply default property values defined in `builtins.slint` to the element.
 fn apply_default_type_properties(element: &mut Element) {
     // Apply default property values on top:
@@ -1960,14 +2036,25 @@ pub fn type_struct_from_node(
     Type::Struct(Rc::new(Struct { fields, name, node: Some(object_node), rust_attributes }))
 }
 
+/// The `commands` of a `Path` are a string, but can be animated by interpolating between two
+/// compatible paths
+fn is_path_commands(element: &Element, prop_name: &str) -> bool {
+    prop_name == "commands" && element.builtin_type().is_some_and(|b| b.name == "Path")
+}
+
 fn animation_element_from_node(
     anim: &syntax_nodes::PropertyAnimation,
     prop_name: &syntax_nodes::QualifiedName,
     prop_type: Type,
+    is_path_commands: bool,
     diag: &mut BuildDiagnostics,
     tr: &TypeRegister,
 ) -> Option<ElementRc> {
-    let anim_type = tr.property_animation_type_for_property(prop_type);
+    let anim_type = tr.property_animation_type_for_property(if is_path_commands {
+        Type::Float32
+    } else {
+        prop_type
+    });
     if !matches!(anim_type, ElementType::Builtin(..)) {
         diag.push_error(
             format!(
@@ -2333,6 +2420,9 @@ pub fn visit_all_named_references_in_element(
             vis(&mut lv.viewport_width);
             vis(&mut lv.listview_height);
             vis(&mut lv.listview_width);
+            if let Some(scroll_to_row) = &mut lv.scroll_to_row {
+                vis(scroll_to_row);
+            }
         }
     }
     elem.borrow_mut().repeated = repeated;
@@ -2453,7 +2543,7 @@ fn from_node(
                 .filter_map(|(pa, qn)| {
                     lookup_property_from_qualified_name_for_state(qn.clone(), r, diag).and_then(
                         |(ne, prop_type)| {
-                            animation_element_from_node(&pa, &qn, prop_type, diag, tr)
+                            animation_element_from_node(&pa, &qn, prop_type, false, diag, tr)
                                 .map(|anim_element| (ne, qn.to_source_location(), anim_element))
                         },
                     )
@@ -2815,3 +2905,36 @@ pub fn adjust_geometry_for_injected_parent(injected_parent: &ElementRc, old_elem
     old_elem_mut.geometry_props.as_mut().unwrap().y =
         NamedReference::new(injected_parent, SmolStr::new_static("dummy"));
 }
+
+#[test]
+fn cfg_elements() {
+    let mut compiler_config =
+        crate::CompilerConfiguration::new(crate::generator::OutputFormat::Interpreter);
+    compiler_config.style = Some("fluent".into());
+    compiler_config.defines = vec![("debug".into(), None), ("brand".into(), Some("acme".into()))];
+    let mut diag = BuildDiagnostics::default();
+    let node = crate::parser::parse(
+        r#"
+        export component Main inherits Window {
+            @cfg(debug) debug-panel := Text {}
+            @cfg(mcu) mcu-screen := Text { text: this-would-be-an-error; }
+            @cfg(brand = "acme") acme-logo := Text {}
+            @cfg(brand = "other") other-logo := Text {}
+            @cfg(all(debug, not(any(mcu, brand = "other")))) if true: conditional := Text {}
+        }
+        "#
+        .into(),
+        Some(std::path::Path::new("main.slint")),
+        &mut diag,
+    );
+    let (doc, diag, _) = spin_on::spin_on(crate::compile_syntax_node(node, diag, compiler_config));
+    assert!(!diag.has_errors(), "{:?}", diag.to_string_vec());
+    let mut ids = Vec::new();
+    recurse_elem_including_sub_components(&doc.inner_components[0], &(), &mut |elem, _| {
+        ids.push(elem.borrow().id.clone());
+    });
+    // The ids are made unique by adding a suffix
+    let has = |name: &str| ids.iter().any(|id| id.starts_with(name));
+    assert!(has("debug-panel") && has("acme-logo") && has("conditional"), "{ids:?}");
+    assert!(!has("mcu-screen") && !has("other-logo"), "{ids:?}");
+}
@@ -338,10 +338,14 @@ fn source_file(&self) -> Option<&SourceFile> {
         Element -> [ ?QualifiedName, *PropertyDeclaration, *Binding, *CallbackConnection,
                      *CallbackDeclaration, *ConditionalElement, *Function, *SubElement,
                      *RepeatedElement, *PropertyAnimation, *PropertyChangedCallback,
-                     *TwoWayBinding, *States, *Transitions, ?ChildrenPlaceholder ],
+                     *TwoWayBinding, *States, *Transitions, ?ChildrenPlaceholder, *CfgElement ],
         RepeatedElement -> [ ?DeclaredIdentifier, ?RepeatedIndex, Expression , SubElement],
         RepeatedIndex -> [],
         ConditionalElement -> [ Expression , SubElement],
+        /// `@cfg(condition) Element { ... }`, the element is only compiled if the condition matches the defines
+        CfgElement -> [ CfgCondition, ?SubElement, ?RepeatedElement, ?ConditionalElement ],
+        /// `name`, `name = "value"`, or `not(...)`, `all(...)`, `any(...)` with nested conditions
+        CfgCondition -> [ *CfgCondition ],
         CallbackDeclaration -> [ DeclaredIdentifier, *CallbackDeclarationParameter, ?ReturnType, ?TwoWayBinding ],
         // `foo: type` or just `type`
         CallbackDeclarationParameter -> [ ?DeclaredIdentifier, Type],
@@ -48,6 +48,7 @@ pub fn parse_element(p: &mut impl Parser) -> bool {
 /// animate someProp { }
 /// animate * { }
 /// @children
+/// @cfg(debug) DebugPanel {}
 /// double_binding <=> element.property;
 /// public pure function foo() {}
 /// changed foo => {}
@@ -125,6 +126,9 @@ pub fn parse_element_content(p: &mut impl Parser) {
                     }
                 }
             },
+            SyntaxKind::At if p.nth(1).as_str() == "cfg" => {
+                had_parse_error |= !parse_cfg_element(&mut *p);
+            }
             SyntaxKind::At => {
                 let checkpoint = p.checkpoint();
                 p.consume();
@@ -134,7 +138,7 @@ pub fn parse_element_content(p: &mut impl Parser) {
                     p.consume()
                 } else {
                     p.test(SyntaxKind::Identifier);
-                    p.error("Parse error: Expected @children")
+                    p.error("Parse error: Expected @children or @cfg")
                 }
             }
             _ => {
@@ -218,6 +222,73 @@ fn parse_if_element(p: &mut impl Parser) {
     parse_sub_element(&mut *p);
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,CfgElement
+/// @cfg(debug) Elem { }
+/// @cfg(brand = "acme") foo := Elem { }
+/// @cfg(not(any(mcu, all(debug, brand = "acme")))) if condition : Elem { }
+/// @cfg(debug) for xx in mm: Elem { }
+/// ```
+fn parse_cfg_element(p: &mut impl Parser) -> bool {
+    let mut p = p.start_node(SyntaxKind::CfgElement);
+    p.expect(SyntaxKind::At);
+    debug_assert_eq!(p.peek().as_str(), "cfg");
+    p.expect(SyntaxKind::Identifier); // "cfg"
+    if !p.expect(SyntaxKind::LParent) {
+        return false;
+    }
+    parse_cfg_condition(&mut *p);
+    if !p.expect(SyntaxKind::RParent) {
+        return false;
+    }
+    match p.nth(0).kind() {
+        SyntaxKind::Identifier if p.peek().as_str() == "for" => {
+            parse_repeated_element(&mut *p);
+            true
+        }
+        SyntaxKind::Identifier if p.peek().as_str() == "if" => {
+            parse_if_element(&mut *p);
+            true
+        }
+        SyntaxKind::Identifier => parse_sub_element(&mut *p),
+        _ => {
+            p.error("Expected an element after @cfg(...)");
+            false
+        }
+    }
+}
+
+#[cfg_attr(test, parser_test)]
+/// ```test,CfgCondition
+/// debug
+/// brand = "acme"
+/// not(debug)
+/// all(debug, any(mcu, brand = "acme"),)
+/// ```
+fn parse_cfg_condition(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::CfgCondition);
+    if !p.expect(SyntaxKind::Identifier) {
+        return;
+    }
+    match p.nth(0).kind() {
+        SyntaxKind::Equal => {
+            p.consume();
+            p.expect(SyntaxKind::StringLiteral);
+        }
+        SyntaxKind::LParent => {
+            p.consume();
+            while p.nth(0).kind() != SyntaxKind::RParent {
+                parse_cfg_condition(&mut *p);
+                if !p.test(SyntaxKind::Comma) {
+                    break;
+                }
+            }
+            p.expect(SyntaxKind::RParent);
+        }
+        _ => {}
+    }
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,Binding
 /// foo: bar;
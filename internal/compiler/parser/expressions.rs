@@ -232,13 +232,19 @@ fn parse_at_keyword(p: &mut impl Parser) {
         "radial-gradient" | "radial_gradient" => {
             parse_gradient(p);
         }
+        "conic-gradient" | "conic_gradient" => {
+            parse_gradient(p);
+        }
+        "four-corner-gradient" | "four_corner_gradient" => {
+            parse_gradient(p);
+        }
         "tr" => {
             parse_tr(p);
         }
         _ => {
             p.consume();
             p.test(SyntaxKind::Identifier); // consume the identifier, so that autocomplete works
-            p.error("Expected 'image-url', 'tr', 'linear-gradient' or 'radial-gradient' after '@'");
+            p.error("Expected 'image-url', 'tr', 'linear-gradient', 'radial-gradient', 'conic-gradient' or 'four-corner-gradient' after '@'");
         }
     }
 }
@@ -340,6 +346,8 @@ fn parse_template_string(p: &mut impl Parser) {
 /// @linear-gradient(217deg, rgba(255,0,0,0.8), rgba(255,0,0,0) 70.71%)
 /// @linear_gradient(217deg, rgba(255,0,0,0.8), rgba(255,0,0,0) 70.71%)
 /// @radial-gradient(circle, #e66465, blue 50%, #9198e5)
+/// @conic-gradient(from 90deg, #e66465, blue 180deg, #9198e5)
+/// @four-corner-gradient(red, green, blue, yellow)
 /// ```
 fn parse_gradient(p: &mut impl Parser) {
     let mut p = p.start_node(SyntaxKind::AtGradient);
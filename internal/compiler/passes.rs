@@ -28,16 +28,23 @@
 mod inlining;
 mod lower_absolute_coordinates;
 mod lower_accessibility;
+mod lower_accordion;
 mod lower_component_container;
 mod lower_layout;
 mod lower_menus;
 mod lower_popups;
 mod lower_property_to_element;
+mod lower_shader_effect;
 mod lower_shadows;
+mod lower_split_view;
 mod lower_states;
+mod lower_swipe_view;
 mod lower_tabwidget;
 mod lower_text_input_interface;
 mod lower_timers;
+mod lower_toasts;
+mod lower_toolbar;
+mod lower_wizard;
 pub mod materialize_fake_properties;
 pub mod move_declarations;
 mod optimize_useless_rectangles;
@@ -46,8 +53,11 @@
 mod remove_return;
 mod remove_unused_properties;
 mod repeater_component;
+mod report_unused;
 pub mod resolve_native_classes;
 pub mod resolving;
+#[cfg(feature = "software-renderer")]
+mod svg_to_path;
 mod unique_id;
 mod visible;
 mod z_order;
@@ -83,11 +93,26 @@ pub async fn run_passes(
     let global_type_registry = type_loader.global_type_registry.clone();
     run_import_passes(doc, type_loader, diag);
     check_public_api::check_public_api(doc, &type_loader.compiler_config, diag);
+    if type_loader.compiler_config.report_unused_items {
+        report_unused::report_unused(doc, type_loader.all_documents(), diag);
+    }
 
     let raw_type_loader =
         keep_raw.then(|| crate::typeloader::snapshot_with_extra_doc(type_loader, doc).unwrap());
 
+    for pass in type_loader.compiler_config.custom_passes.clone() {
+        pass(doc, diag);
+    }
+
     collect_subcomponents::collect_subcomponents(doc);
+    #[cfg(feature = "software-renderer")]
+    if type_loader.compiler_config.svg_to_path
+        && type_loader.compiler_config.embed_resources != crate::EmbedResourcesKind::EmbedTextures
+    {
+        doc.visit_all_used_components(|component| {
+            svg_to_path::svg_to_path(component, &doc.local_registry);
+        });
+    }
     doc.visit_all_used_components(|component| {
         compile_paths::compile_paths(
             component,
@@ -97,7 +122,13 @@ pub async fn run_passes(
         );
     });
     lower_tabwidget::lower_tabwidget(doc, type_loader, diag).await;
+    lower_split_view::lower_split_view(doc, type_loader, diag).await;
+    lower_swipe_view::lower_swipe_view(doc, type_loader, diag).await;
+    lower_accordion::lower_accordion(doc, type_loader, diag).await;
+    lower_wizard::lower_wizard(doc, type_loader, diag).await;
+    lower_toolbar::lower_toolbar(doc, type_loader, diag).await;
     lower_menus::lower_menus(doc, type_loader, diag).await;
+    lower_toasts::lower_toasts(doc, type_loader, diag).await;
     collect_subcomponents::collect_subcomponents(doc);
 
     doc.visit_all_used_components(|component| {
@@ -113,12 +144,14 @@ pub async fn run_passes(
         lower_popups::lower_popups(component, &doc.local_registry, diag);
         collect_init_code::collect_init_code(component);
         lower_timers::lower_timers(component, diag);
+        lower_shader_effect::lower_shader_effect(component, diag);
     });
 
     inlining::inline(doc, inlining::InlineSelection::InlineOnlyRequiredComponents, diag);
     collect_subcomponents::collect_subcomponents(doc);
 
     for root_component in doc.exported_roots() {
+        lower_toasts::move_toast_layer_to_window(&root_component);
         focus_handling::call_focus_on_init(&root_component);
         ensure_window::ensure_window(&root_component, &doc.local_registry, &style_metrics, diag);
     }
@@ -243,7 +276,7 @@ pub async fn run_passes(
     match type_loader.compiler_config.embed_resources {
         #[cfg(feature = "software-renderer")]
         crate::EmbedResourcesKind::EmbedTextures => {
-            let mut characters_seen = std::collections::HashSet::new();
+            let mut characters_seen = std::collections::BTreeSet::new();
 
             // Include at least the default font sizes used in the MCU backend
             let mut font_pixel_sizes =
@@ -257,6 +290,25 @@ pub async fn run_passes(
                 embed_glyphs::scan_string_literals(component, &mut characters_seen);
             });
 
+            #[cfg(feature = "bundle-translations")]
+            if let Some(path) = &type_loader.compiler_config.translation_path_bundle {
+                // Errors loading the translations are reported when lowering them
+                if let Ok(translations) =
+                    crate::llr::translations::TranslationsBuilder::load_translations(
+                        path,
+                        type_loader.compiler_config.translation_domain.as_deref().unwrap_or(""),
+                    )
+                {
+                    doc.visit_all_used_components(|component| {
+                        embed_glyphs::scan_translations(
+                            component,
+                            &translations,
+                            &mut characters_seen,
+                        );
+                    });
+                }
+            }
+
             embed_glyphs::embed_glyphs(
                 doc,
                 &type_loader.compiler_config,
@@ -274,6 +326,7 @@ pub async fn run_passes(
                 std::iter::once(&*doc).chain(type_loader.all_documents()),
                 type_loader.compiler_config.embed_resources
                     == crate::EmbedResourcesKind::EmbedAllResources,
+                &type_loader.compiler_config.font_embedding,
             );
         }
     };
@@ -295,3 +348,43 @@ pub fn run_import_passes(
     check_rotation::check_rotation(doc, diag);
     unique_id::check_unique_id(doc, diag);
 }
+
+#[test]
+fn custom_passes() {
+    use crate::expression_tree::Unit;
+    let mut compiler_config =
+        crate::CompilerConfiguration::new(crate::generator::OutputFormat::Interpreter);
+    compiler_config.style = Some("fluent".into());
+    compiler_config.custom_passes.push(std::rc::Rc::new(|doc, diag| {
+        for component in &doc.inner_components {
+            crate::object_tree::recurse_elem(&component.root_element, &(), &mut |elem, _| {
+                if elem.borrow().id == "forbidden" {
+                    diag.push_error("'forbidden' is not allowed".into(), &*elem.borrow());
+                }
+            });
+            component.root_element.borrow_mut().bindings.insert(
+                "analytics-id".into(),
+                std::cell::RefCell::new(Expression::NumberLiteral(42., Unit::None).into()),
+            );
+        }
+    }));
+    let mut diag = crate::diagnostics::BuildDiagnostics::default();
+    let node = crate::parser::parse(
+        r#"
+        export component Main inherits Window {
+            out property <int> analytics-id;
+            forbidden := Rectangle {}
+        }
+        "#
+        .into(),
+        Some(std::path::Path::new("main.slint")),
+        &mut diag,
+    );
+    let (doc, diag, _) = spin_on::spin_on(crate::compile_syntax_node(node, diag, compiler_config));
+    assert_eq!(diag.to_string_vec(), ["main.slint:4: 'forbidden' is not allowed"]);
+    let root = doc.inner_components[0].root_element.borrow();
+    assert!(matches!(
+        root.bindings["analytics-id"].borrow().expression,
+        Expression::NumberLiteral(x, _) if x == 42.
+    ));
+}
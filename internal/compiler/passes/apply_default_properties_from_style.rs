@@ -70,6 +70,24 @@ pub fn apply_default_properties_from_style(
                         to: Type::Brush,
                     });
                 }
+                "RichText" => {
+                    elem.set_binding_if_not_set("color".into(), || Expression::Cast {
+                        from: Expression::PropertyReference(NamedReference::new(
+                            &palette.root_element,
+                            SmolStr::new_static("foreground"),
+                        ))
+                        .into(),
+                        to: Type::Brush,
+                    });
+                    elem.set_binding_if_not_set("link-color".into(), || Expression::Cast {
+                        from: Expression::PropertyReference(NamedReference::new(
+                            &palette.root_element,
+                            SmolStr::new_static("accent-background"),
+                        ))
+                        .into(),
+                        to: Type::Brush,
+                    });
+                }
                 "Dialog" | "Window" => {
                     elem.set_binding_if_not_set("background".into(), || Expression::Cast {
                         from: Expression::PropertyReference(NamedReference::new(
@@ -539,6 +539,15 @@ fn visit_implicit_layout_info_dependencies(
                 vis(&NamedReference::new(item, SmolStr::new_static("overflow")).into(), N);
             }
         }
+        "RichText" => {
+            vis(&NamedReference::new(item, SmolStr::new_static("text")).into(), N);
+            vis(&NamedReference::new(item, SmolStr::new_static("font-family")).into(), N);
+            vis(&NamedReference::new(item, SmolStr::new_static("font-size")).into(), N);
+            vis(&NamedReference::new(item, SmolStr::new_static("font-weight")).into(), N);
+            if orientation == Orientation::Vertical {
+                vis(&NamedReference::new(item, SmolStr::new_static("width")).into(), N);
+            }
+        }
 
         _ => (),
     }
@@ -15,6 +15,7 @@ pub fn collect_custom_fonts<'a>(
     doc: &Document,
     all_docs: impl Iterator<Item = &'a Document> + 'a,
     embed_fonts: bool,
+    font_embedding: &crate::FontEmbedding,
 ) {
     let mut all_fonts = BTreeSet::new();
 
@@ -22,47 +23,72 @@ pub fn collect_custom_fonts<'a>(
         all_fonts.extend(doc.custom_fonts.iter().map(|(path, _)| path))
     }
 
-    let registration_function = if embed_fonts {
-        Expression::BuiltinFunctionReference(BuiltinFunction::RegisterCustomFontByMemory, None)
-    } else {
-        Expression::BuiltinFunctionReference(BuiltinFunction::RegisterCustomFontByPath, None)
+    let embed_font_by_memory = |font_path: &SmolStr| {
+        Expression::NumberLiteral(
+            {
+                let mut resources = doc.embedded_file_resources.borrow_mut();
+                let resource_id = match resources.get(font_path) {
+                    Some(r) => r.id,
+                    None => {
+                        let id = resources.len();
+                        resources.insert(
+                            font_path.clone(),
+                            crate::embedded_resources::EmbeddedResources {
+                                id,
+                                kind: crate::embedded_resources::EmbeddedResourcesKind::RawData,
+                            },
+                        );
+                        id
+                    }
+                };
+                resource_id as _
+            },
+            Unit::None,
+        )
     };
 
-    let prepare_font_registration_argument: Box<dyn Fn(&SmolStr) -> Expression> = if embed_fonts {
-        Box::new(|font_path| {
-            Expression::NumberLiteral(
-                {
-                    let mut resources = doc.embedded_file_resources.borrow_mut();
-                    let resource_id = match resources.get(font_path) {
-                        Some(r) => r.id,
-                        None => {
-                            let id = resources.len();
-                            resources.insert(
-                                font_path.clone(),
-                                crate::embedded_resources::EmbeddedResources {
-                                    id,
-                                    kind: crate::embedded_resources::EmbeddedResourcesKind::RawData,
-                                },
-                            );
-                            id
-                        }
-                    };
-                    resource_id as _
-                },
-                Unit::None,
+    let font_registration = |font_path: &SmolStr| {
+        let (function, argument) = if embed_fonts
+            && !crate::pathutils::is_asset_url(font_path)
+            && embeds_font_file(font_path, font_embedding)
+        {
+            (BuiltinFunction::RegisterCustomFontByMemory, embed_font_by_memory(font_path))
+        } else {
+            (
+                BuiltinFunction::RegisterCustomFontByPath,
+                Expression::StringLiteral(font_path.clone()),
             )
-        })
-    } else {
-        Box::new(|font_path| Expression::StringLiteral(font_path.clone()))
+        };
+        Expression::FunctionCall {
+            function: Box::new(Expression::BuiltinFunctionReference(function, None)),
+            arguments: vec![argument],
+            source_location: None,
+        }
     };
 
+    let registrations =
+        all_fonts.iter().map(|font_path| font_registration(font_path)).collect::<Vec<_>>();
     for c in doc.exported_roots() {
-        c.init_code.borrow_mut().font_registration_code.extend(all_fonts.iter().map(|font_path| {
-            Expression::FunctionCall {
-                function: Box::new(registration_function.clone()),
-                arguments: vec![prepare_font_registration_argument(font_path)],
-                source_location: None,
-            }
-        }));
+        c.init_code.borrow_mut().font_registration_code.extend(registrations.iter().cloned());
     }
 }
+
+/// Returns true if one of the faces in the font file matches the [`crate::FontEmbedding`] filter
+fn embeds_font_file(font_path: &str, font_embedding: &crate::FontEmbedding) -> bool {
+    if font_embedding.embeds_all() {
+        return true;
+    }
+    #[cfg(feature = "software-renderer")]
+    {
+        let mut db = i_slint_common::sharedfontdb::fontdb::Database::new();
+        if db.load_font_file(font_path).is_ok() {
+            return db.faces().any(|face| {
+                face.families.iter().any(|(family, _)| font_embedding.embeds(family, face.weight.0))
+            });
+        }
+    }
+    #[cfg(not(feature = "software-renderer"))]
+    let _ = font_path;
+    // Without the font database, the family of the font can't be known: embed it
+    true
+}
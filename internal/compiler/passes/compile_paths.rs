@@ -64,7 +64,7 @@ pub fn compile_paths(
                 return;
             }
 
-            match &commands_expr.expression {
+            let mut binding: BindingExpression = match &commands_expr.expression {
                 Expression::StringLiteral(commands) => {
                     match compile_path_from_string_literal(commands) {
                         Ok(binding) => binding,
@@ -88,7 +88,10 @@ pub fn compile_paths(
                     );
                     return;
                 }
-            }
+            };
+            // `animate commands` animates the path data by interpolating between the paths
+            binding.animation = commands_expr.animation;
+            binding
         } else {
             let mut elem = elem_.borrow_mut();
             let enclosing_component = elem.enclosing_component.upgrade().unwrap();
@@ -106,15 +106,23 @@ fn process_expression(expr: &mut Expression, old_state: &DedupPropState) {
     }
 
     if new_state.counts.borrow().has_duplicate {
-        let mut stores = vec![];
-        for (nr, c) in &new_state.counts.borrow().counts {
-            if c.has_been_mapped {
-                stores.push(Expression::StoreLocalVariable {
-                    name: map_nr(nr),
-                    value: Box::new(Expression::PropertyReference(nr.clone())),
-                });
-            }
-        }
+        let mut mapped = new_state
+            .counts
+            .borrow()
+            .counts
+            .iter()
+            .filter(|(_, c)| c.has_been_mapped)
+            .map(|(nr, _)| (map_nr(nr), nr.clone()))
+            .collect::<Vec<_>>();
+        // Sort to not depend on the order of the HashMap, so that the generated code is stable
+        mapped.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut stores = mapped
+            .into_iter()
+            .map(|(name, nr)| Expression::StoreLocalVariable {
+                name,
+                value: Box::new(Expression::PropertyReference(nr)),
+            })
+            .collect::<Vec<_>>();
         stores.push(std::mem::take(expr));
         *expr = Expression::CodeBlock(stores);
     }
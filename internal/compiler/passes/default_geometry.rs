@@ -14,13 +14,14 @@
 
 use crate::diagnostics::{BuildDiagnostics, DiagnosticLevel, Spanned};
 use crate::expression_tree::{
-    BindingExpression, BuiltinFunction, Expression, MinMaxOp, NamedReference, Unit,
+    unique_local_variable_name, BindingExpression, BuiltinFunction, Expression, MinMaxOp,
+    NamedReference, Unit,
 };
 use crate::langtype::{BuiltinElement, DefaultSizeBinding, Type};
 use crate::layout::{implicit_layout_info_call, LayoutConstraints, Orientation};
 use crate::object_tree::{Component, ElementRc};
 use smol_str::{format_smolstr, SmolStr};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 pub fn default_geometry(root_component: &Rc<Component>, diag: &mut BuildDiagnostics) {
     crate::object_tree::recurse_elem_including_sub_components(
@@ -261,11 +262,7 @@ fn merge_explicit_constraints(
     orientation: Orientation,
 ) {
     if constraints.has_explicit_restrictions(orientation) {
-        static COUNT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
-        let unique_name = format_smolstr!(
-            "layout_info_{}",
-            COUNT.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
-        );
+        let unique_name = unique_local_variable_name("layout_info_");
         let ty = expr.ty();
         let store = Expression::StoreLocalVariable {
             name: unique_name.clone(),
@@ -288,7 +285,7 @@ fn merge_explicit_constraints(
                     },
                 )
             })
-            .collect::<HashMap<_, _>>();
+            .collect::<BTreeMap<_, _>>();
 
         for (nr, s) in constraints.for_each_restrictions(orientation) {
             let e = nr
@@ -308,7 +305,7 @@ fn merge_explicit_constraints(
 }
 
 fn explicit_layout_info(e: &ElementRc, orientation: Orientation) -> Expression {
-    let mut values = HashMap::new();
+    let mut values = BTreeMap::new();
     let (size, orient) = match orientation {
         Orientation::Horizontal => ("width", "horizontal"),
         Orientation::Vertical => ("height", "vertical"),
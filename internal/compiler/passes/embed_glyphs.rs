@@ -10,7 +10,7 @@
 use crate::object_tree::*;
 use crate::CompilerConfiguration;
 use std::cell::RefCell;
-use std::collections::HashSet;
+use std::collections::BTreeSet;
 use std::rc::Rc;
 use std::sync::Arc;
 
@@ -42,7 +42,7 @@ pub fn embed_glyphs<'a>(
     _compiler_config: &CompilerConfiguration,
     _scale_factor: f64,
     _pixel_sizes: Vec<i16>,
-    _characters_seen: HashSet<char>,
+    _characters_seen: BTreeSet<char>,
     _all_docs: impl Iterator<Item = &'a crate::object_tree::Document> + 'a,
     _diag: &mut BuildDiagnostics,
 ) -> bool {
@@ -55,7 +55,7 @@ pub fn embed_glyphs<'a>(
     compiler_config: &CompilerConfiguration,
     scale_factor: f64,
     mut pixel_sizes: Vec<i16>,
-    mut characters_seen: HashSet<char>,
+    mut characters_seen: BTreeSet<char>,
     all_docs: impl Iterator<Item = &'a crate::object_tree::Document> + 'a,
     diag: &mut BuildDiagnostics,
 ) {
@@ -63,14 +63,16 @@ pub fn embed_glyphs<'a>(
 
     let generic_diag_location = doc.node.as_ref().map(|n| n.to_source_location());
 
-    characters_seen.extend(
-        ('a'..='z')
-            .chain('A'..='Z')
-            .chain('0'..='9')
-            .chain(" '!\"#$%&()*+,-./:;<=>?@\\[]{}^_|~".chars())
-            .chain(std::iter::once('●'))
-            .chain(std::iter::once('…')),
-    );
+    if !compiler_config.font_embedding.subset_glyphs {
+        characters_seen.extend(
+            ('a'..='z')
+                .chain('A'..='Z')
+                .chain('0'..='9')
+                .chain(" '!\"#$%&()*+,-./:;<=>?@\\[]{}^_|~".chars())
+                .chain(std::iter::once('●'))
+                .chain(std::iter::once('…')),
+        );
+    }
 
     if let Ok(sizes_str) = std::env::var("SLINT_FONT_SIZES") {
         for custom_size_str in sizes_str.split(',') {
@@ -115,7 +117,7 @@ fn embed_glyphs_with_fontdb<'a>(
     fontdb: &RefCell<sharedfontdb::FontDatabase>,
     doc: &Document,
     pixel_sizes: Vec<i16>,
-    characters_seen: HashSet<char>,
+    characters_seen: BTreeSet<char>,
     all_docs: impl Iterator<Item = &'a crate::object_tree::Document> + 'a,
     diag: &mut BuildDiagnostics,
     generic_diag_location: Option<crate::diagnostics::SourceLocation>,
@@ -129,6 +131,13 @@ fn embed_glyphs_with_fontdb<'a>(
         let mut fontdb_mut = fontdb.borrow_mut();
         for doc in all_docs {
             for (font_path, import_token) in doc.custom_fonts.iter() {
+                if crate::pathutils::is_asset_url(font_path) {
+                    diag.push_error(
+                        "Fonts loaded from asset:// URLs are only available at run-time and can't be embedded as glyphs".into(),
+                        import_token,
+                    );
+                    continue;
+                }
                 let face_count = fontdb_mut.faces().count();
                 if let Err(e) = fontdb_mut.make_mut().load_font_file(font_path) {
                     diag.push_error(format!("Error loading font: {}", e), import_token);
@@ -215,6 +224,36 @@ fn embed_glyphs_with_fontdb<'a>(
     }
 
     let mut embed_font_by_path_and_face_id = |path: &std::path::Path, face_id| {
+        let font_embedding = &compiler_config.font_embedding;
+        if !font_embedding.embeds_all() {
+            let face_info =
+                fontdb.face(face_id).expect("internal error: fontdb provided ids are not valid");
+            if !face_info
+                .families
+                .iter()
+                .any(|(family, _)| font_embedding.embeds(family, face_info.weight.0))
+            {
+                // Custom fonts that are not embedded are loaded from their path at run-time
+                if custom_fonts.contains(&face_id) {
+                    for c in doc.exported_roots() {
+                        c.init_code.borrow_mut().font_registration_code.push(
+                            Expression::FunctionCall {
+                                function: Box::new(Expression::BuiltinFunctionReference(
+                                    BuiltinFunction::RegisterCustomFontByPath,
+                                    None,
+                                )),
+                                arguments: vec![Expression::StringLiteral(
+                                    path.to_string_lossy().into(),
+                                )],
+                                source_location: None,
+                            },
+                        );
+                    }
+                }
+                return;
+            }
+        }
+
         let (fontdue_font, face_data, face_index) = match compiler_config.load_font_by_id(face_id) {
             Ok(font) => font,
             Err(msg) => {
@@ -591,7 +630,7 @@ pub fn collect_font_sizes_used(
         .to_string()
         .as_str()
     {
-        "TextInput" | "Text" | "SimpleText" | "ComplexText" => {
+        "TextInput" | "Text" | "SimpleText" | "ComplexText" | "RichText" => {
             if let Some(font_size) = try_extract_font_size_from_element(elem, "font-size") {
                 add_font_size(font_size)
             }
@@ -605,7 +644,7 @@ pub fn collect_font_sizes_used(
     });
 }
 
-pub fn scan_string_literals(component: &Rc<Component>, characters_seen: &mut HashSet<char>) {
+pub fn scan_string_literals(component: &Rc<Component>, characters_seen: &mut BTreeSet<char>) {
     visit_all_expressions(component, |expr, _| {
         expr.visit_recursive(&mut |expr| {
             if let Expression::StringLiteral(string) = expr {
@@ -614,3 +653,31 @@ pub fn scan_string_literals(component: &Rc<Component>, characters_seen: &mut Has
         })
     })
 }
+
+/// Add the characters of the bundled translations of the strings passed to `@tr`
+#[cfg(feature = "bundle-translations")]
+pub fn scan_translations(
+    component: &Rc<Component>,
+    translations: &crate::llr::translations::TranslationsBuilder,
+    characters_seen: &mut BTreeSet<char>,
+) {
+    visit_all_expressions(component, |expr, _| {
+        expr.visit_recursive(&mut |expr| {
+            let Expression::FunctionCall { function, arguments, .. } = expr else { return };
+            if !matches!(
+                **function,
+                Expression::BuiltinFunctionReference(
+                    crate::expression_tree::BuiltinFunction::Translate,
+                    _
+                )
+            ) {
+                return;
+            }
+            if let [Expression::StringLiteral(original), Expression::StringLiteral(context), _, _, _, Expression::StringLiteral(plural)] =
+                arguments.as_slice()
+            {
+                translations.collect_characters(original, context, plural, characters_seen);
+            }
+        })
+    })
+}
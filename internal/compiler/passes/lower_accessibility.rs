@@ -62,7 +62,7 @@ pub fn lower_accessibility_properties(component: &Rc<Component>, diag: &mut Buil
 
 fn apply_builtin(e: &ElementRc) {
     let bty = if let Some(bty) = e.borrow().builtin_type() { bty } else { return };
-    if bty.name == "Text" {
+    if bty.name == "Text" || bty.name == "RichText" {
         e.borrow_mut().set_binding_if_not_set("accessible-role".into(), || {
             let enum_ty = crate::typeregister::BUILTIN.with(|e| e.enums.AccessibleRole.clone());
             Expression::EnumerationValue(EnumerationValue {
@@ -0,0 +1,94 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Pass that lowers the Accordion so that only one of its Expanders is expanded at a time.
+//!
+//! Must be done before inlining and many other passes because the lowered code must
+//! be further inlined
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::{BindingExpression, Expression, NamedReference, Unit};
+use crate::langtype::ElementType;
+use crate::object_tree::*;
+use smol_str::SmolStr;
+use std::cell::RefCell;
+
+pub async fn lower_accordion(
+    doc: &Document,
+    type_loader: &mut crate::typeloader::TypeLoader,
+    diag: &mut BuildDiagnostics,
+) {
+    // Ignore import errors
+    let mut build_diags_to_ignore = BuildDiagnostics::default();
+    let accordion_impl = type_loader
+        .import_component("std-widgets.slint", "AccordionImpl", &mut build_diags_to_ignore)
+        .await
+        .expect("can't load AccordionImpl from std-widgets.slint");
+    let accordion_item_impl = type_loader
+        .import_component("std-widgets.slint", "AccordionItemImpl", &mut build_diags_to_ignore)
+        .await
+        .expect("can't load AccordionItemImpl from std-widgets.slint");
+
+    doc.visit_all_used_components(|component| {
+        recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
+            if matches!(&elem.borrow().builtin_type(), Some(b) if b.name == "Accordion") {
+                process_accordion(
+                    elem,
+                    ElementType::Component(accordion_impl.clone()),
+                    ElementType::Component(accordion_item_impl.clone()),
+                    diag,
+                );
+            }
+        })
+    });
+}
+
+fn process_accordion(
+    elem: &ElementRc,
+    accordion_impl: ElementType,
+    accordion_item_impl: ElementType,
+    diag: &mut BuildDiagnostics,
+) {
+    if matches!(&elem.borrow_mut().base_type, ElementType::Builtin(_)) {
+        // That's the Accordion re-exported from the style, it doesn't need to be processed
+        return;
+    }
+
+    elem.borrow_mut().base_type = accordion_impl;
+    let mut items = std::mem::take(&mut elem.borrow_mut().children);
+    items.retain(|item| {
+        if item.borrow().repeated.is_some() {
+            diag.push_error(
+                "dynamic expanders ('if' or 'for') are currently not supported".into(),
+                &*item.borrow(),
+            );
+            return false;
+        }
+        if item.borrow().base_type.to_string() != "Expander" {
+            assert!(diag.has_errors());
+            return false;
+        }
+        true
+    });
+
+    for (index, item) in items.iter().enumerate() {
+        item.borrow_mut().base_type = accordion_item_impl.clone();
+        item.borrow_mut().bindings.insert(
+            SmolStr::new_static("index"),
+            RefCell::new(BindingExpression::from(Expression::NumberLiteral(
+                index as _,
+                Unit::None,
+            ))),
+        );
+        item.borrow_mut().bindings.insert(
+            SmolStr::new_static("current-index"),
+            BindingExpression::new_two_way(NamedReference::new(
+                elem,
+                SmolStr::new_static("current-index"),
+            ))
+            .into(),
+        );
+    }
+
+    elem.borrow_mut().children = items;
+}
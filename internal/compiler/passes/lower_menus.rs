@@ -1,7 +1,7 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-//! Passe lower the `MenuBar` and `ContextMenu` as well as all their contents
+//! Passe lower the `MenuBar`, `Menu`, and `ContextMenu` as well as all their contents
 //!
 //! Must be done before inlining and many other passes because the lowered code must
 //! be further inlined as it may expends to native widget that needs inlining
@@ -12,6 +12,7 @@
 use crate::object_tree::*;
 use core::cell::RefCell;
 use smol_str::{format_smolstr, SmolStr};
+use std::rc::Rc;
 
 struct UsefulMenuComponents {
     menubar_impl: ElementType,
@@ -46,8 +47,13 @@ pub async fn lower_menus(
     doc.visit_all_used_components(|component| {
         recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
             if matches!(&elem.borrow().builtin_type(), Some(b) if b.name == "Window") {
+                // Before the MenuBar, which moves the other children of the window
+                process_window_menu(elem, &useful_menu_component, diag);
                 has_menu |= process_window(elem, &useful_menu_component, diag);
             }
+            if matches!(&elem.borrow().base_type, ElementType::Builtin(b) if b.name == "Menu") {
+                diag.push_error("Menu can only be within a Window element".into(), &*elem.borrow());
+            }
             if matches!(&elem.borrow().builtin_type(), Some(b) if b.name == "ContextMenu") {
                 has_menu |= process_context_menu(elem, &useful_menu_component, diag);
             }
@@ -254,3 +260,214 @@ fn process_window(
 
     true
 }
+
+/// Lower the `Menu` of a window to an element with the `entries`, `sub-menu`, and `activated`
+/// of its `Menu` and `MenuItem` tree, that is registered as the menu of the window.
+fn process_window_menu(
+    win: &ElementRc,
+    components: &UsefulMenuComponents,
+    diag: &mut BuildDiagnostics,
+) {
+    let mut menu = None;
+    win.borrow_mut().children.retain(|x| {
+        if matches!(&x.borrow().base_type, ElementType::Builtin(b) if b.name == "Menu") {
+            if menu.is_some() {
+                diag.push_error("Only one Menu is allowed in a Window".into(), &*x.borrow());
+            } else {
+                menu = Some(x.clone());
+            }
+            false
+        } else {
+            true
+        }
+    });
+    let Some(menu) = menu else { return };
+    if menu.borrow().repeated.is_some() {
+        diag.push_error(
+            "Menu cannot be in a conditional or repeated element".into(),
+            &*menu.borrow(),
+        );
+    }
+
+    const ENTRIES: &str = "entries";
+    const SUB_MENU: &str = "sub-menu";
+    const ACTIVATE: &str = "activated";
+
+    let entries_ty = components.menubar_impl.lookup_property(ENTRIES).property_type;
+    let Type::Array(entry_ty) = &entries_ty else { panic!("entries must be an array") };
+    let mut tree = MenuTree {
+        entry_ty: (**entry_ty).clone(),
+        sub_menus: Vec::new(),
+        activated: Vec::new(),
+        elements: Vec::new(),
+    };
+    let entries = tree.lower_children(&menu, diag);
+
+    // Every `if entry.id == "..."` test, chained from the last one to the first
+    let entry_id_is = |id: &SmolStr| Expression::BinaryExpression {
+        lhs: Expression::StructFieldAccess {
+            base: Expression::FunctionParameterReference { index: 0, ty: tree.entry_ty.clone() }
+                .into(),
+            name: SmolStr::new_static("id"),
+        }
+        .into(),
+        rhs: Expression::StringLiteral(id.clone()).into(),
+        op: '=',
+    };
+    let sub_menu = tree.sub_menus.iter().rev().fold(
+        Expression::Array { element_ty: tree.entry_ty.clone(), values: vec![] },
+        |else_expr, (id, entries)| Expression::Condition {
+            condition: entry_id_is(id).into(),
+            true_expr: entries.clone().into(),
+            false_expr: else_expr.into(),
+        },
+    );
+    let activated = tree.activated.iter().rev().fold(
+        Expression::CodeBlock(vec![]),
+        |else_expr, (id, handler)| Expression::Condition {
+            condition: entry_id_is(id).into(),
+            true_expr: Expression::CodeBlock(vec![handler.clone(), Expression::CodeBlock(vec![])])
+                .into(),
+            false_expr: else_expr.into(),
+        },
+    );
+
+    let source_location = Some(menu.borrow().to_source_location());
+    {
+        let mut menu = menu.borrow_mut();
+        for (prop, expr) in [(ENTRIES, entries), (SUB_MENU, sub_menu), (ACTIVATE, activated)] {
+            let ty = components.menubar_impl.lookup_property(prop).property_type;
+            assert_ne!(ty, Type::Invalid, "Can't lookup type for {prop}");
+            menu.property_declarations.insert(
+                prop.into(),
+                PropertyDeclaration { property_type: ty, ..Default::default() },
+            );
+            menu.bindings.insert(prop.into(), RefCell::new(expr.into()));
+        }
+        menu.bindings.remove("title");
+        menu.base_type = components.empty.clone();
+    }
+
+    let setup_menu = Expression::FunctionCall {
+        function: Expression::BuiltinFunctionReference(
+            BuiltinFunction::SetupWindowMenu,
+            source_location.clone(),
+        )
+        .into(),
+        arguments: vec![
+            Expression::PropertyReference(NamedReference::new(&menu, SmolStr::new_static(ENTRIES))),
+            Expression::CallbackReference(
+                NamedReference::new(&menu, SmolStr::new_static(SUB_MENU)),
+                None,
+            ),
+            Expression::CallbackReference(
+                NamedReference::new(&menu, SmolStr::new_static(ACTIVATE)),
+                None,
+            ),
+        ],
+        source_location,
+    };
+
+    win.borrow_mut().children.push(menu);
+    let component = win.borrow().enclosing_component.upgrade().unwrap();
+
+    // The Menu and MenuItem elements don't exist at run-time, so their properties can't be used
+    let mut invalid_references = Vec::new();
+    crate::object_tree::visit_all_named_references(&component, &mut |nr| {
+        let element = nr.element();
+        if tree.elements.iter().any(|e| Rc::ptr_eq(e, &element)) {
+            invalid_references.push(element);
+        }
+    });
+    if !invalid_references.is_empty() {
+        // Keep the referenced elements alive for the passes that run until the errors are reported
+        for element in &tree.elements {
+            let mut element = element.borrow_mut();
+            element.repeated = None;
+            element.children.clear();
+        }
+        component.optimized_elements.borrow_mut().extend(tree.elements.iter().cloned());
+    }
+    for element in invalid_references {
+        diag.push_error(
+            "The properties of a Menu or MenuItem cannot be accessed".into(),
+            &*element.borrow(),
+        );
+    }
+
+    component.init_code.borrow_mut().constructor_code.push(setup_menu);
+}
+
+/// The expressions computed from a tree of `Menu` and `MenuItem`.
+/// Each entry has an `id` that is its index in the tree.
+struct MenuTree {
+    entry_ty: Type,
+    /// The entries of each sub-menu, by id of its parent entry
+    sub_menus: Vec<(SmolStr, Expression)>,
+    /// The `activated` handler of each `MenuItem`, by id
+    activated: Vec<(SmolStr, Expression)>,
+    /// All the elements that are removed from the tree
+    elements: Vec<ElementRc>,
+}
+
+impl MenuTree {
+    /// Returns the array of the entries for the children of the `menu`
+    fn lower_children(&mut self, menu: &ElementRc, diag: &mut BuildDiagnostics) -> Expression {
+        let Type::Struct(entry_struct) = self.entry_ty.clone() else {
+            panic!("MenuEntry must be a struct")
+        };
+        let children = std::mem::take(&mut menu.borrow_mut().children);
+        let mut values = Vec::new();
+        for child in children {
+            let is_menu = match &child.borrow().base_type {
+                ElementType::Builtin(b) if b.name == "Menu" => true,
+                ElementType::Builtin(b) if b.name == "MenuItem" => false,
+                _ => {
+                    diag.push_error(
+                        "Only Menu and MenuItem are valid children of a Menu".into(),
+                        &*child.borrow(),
+                    );
+                    self.elements.push(child.clone());
+                    continue;
+                }
+            };
+            if child.borrow().repeated.is_some() {
+                diag.push_error(
+                    "Menu and MenuItem cannot be in a conditional or repeated element".into(),
+                    &*child.borrow(),
+                );
+            }
+            let id = format_smolstr!("{}", self.elements.len());
+            self.elements.push(child.clone());
+
+            let title = child
+                .borrow_mut()
+                .bindings
+                .remove("title")
+                .map(|b| b.into_inner().expression)
+                .unwrap_or_else(|| Expression::StringLiteral(SmolStr::default()));
+            if is_menu {
+                let sub_menu = self.lower_children(&child, diag);
+                self.sub_menus.push((id.clone(), sub_menu));
+            } else if let Some(activated) = child.borrow_mut().bindings.remove("activated") {
+                self.activated.push((id.clone(), activated.into_inner().expression));
+            }
+
+            let values_by_field = entry_struct
+                .fields
+                .iter()
+                .map(|(field, ty)| {
+                    let value = match field.as_str() {
+                        "title" => title.clone(),
+                        "id" => Expression::StringLiteral(id.clone()),
+                        "has-sub-menu" => Expression::BoolLiteral(is_menu),
+                        _ => Expression::default_value_for_type(ty),
+                    };
+                    (field.clone(), value)
+                })
+                .collect();
+            values.push(Expression::Struct { ty: self.entry_ty.clone(), values: values_by_field });
+        }
+        Expression::Array { element_ty: self.entry_ty.clone(), values }
+    }
+}
@@ -1,7 +1,8 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-//! Passe that transform the PopupWindow element into a component
+//! Passe that transform the PopupWindow element, and the Window elements declared inside another
+//! component, into a component
 
 use crate::diagnostics::{BuildDiagnostics, SourceLocation};
 use crate::expression_tree::{BindingExpression, Expression, NamedReference};
@@ -9,6 +10,7 @@
 use crate::object_tree::*;
 use crate::typeregister::TypeRegister;
 use smol_str::{format_smolstr, SmolStr};
+use std::collections::HashSet;
 use std::rc::{Rc, Weak};
 
 const CLOSE_ON_CLICK: &str = "close-on-click";
@@ -20,6 +22,9 @@ pub fn lower_popups(
     diag: &mut BuildDiagnostics,
 ) {
     let window_type = type_register.lookup_builtin_element("Window").unwrap();
+    // The lowered elements are visited again as the root of their component, with the Window base
+    // type for the popups, so they must not be mistaken for a Window declared by the user
+    let mut lowered = HashSet::new();
 
     recurse_elem_including_sub_components_no_borrow(
         component,
@@ -32,7 +37,20 @@ pub fn lower_popups(
             };
 
             if is_popup {
-                lower_popup_window(elem, parent_element.as_ref(), &window_type, diag);
+                if lowered.insert(Rc::as_ptr(elem)) {
+                    lower_popup_window(elem, parent_element.as_ref(), &window_type, diag);
+                }
+            } else if let Some(parent_element) = parent_element {
+                // Only the elements written as `Window`. The placeholder of a repeated Window is
+                // skipped: the error is reported on the Window itself
+                let is_window = matches!(
+                    &elem.borrow().base_type,
+                    ElementType::Builtin(b) if b.name == "Window"
+                );
+                if is_window && elem.borrow().repeated.is_none() && lowered.insert(Rc::as_ptr(elem))
+                {
+                    lower_secondary_window(elem, parent_element, diag);
+                }
             }
             Some(elem.clone())
         },
@@ -87,16 +105,6 @@ fn lower_popup_window(
         return;
     }
 
-    // Remove the popup_window_element from its parent
-    let old_size = parent_element.borrow().children.len();
-    parent_element.borrow_mut().children.retain(|child| !Rc::ptr_eq(child, popup_window_element));
-    debug_assert_eq!(
-        parent_element.borrow().children.len() + 1,
-        old_size,
-        "Exactly one child must be removed (the popup itself)"
-    );
-    parent_element.borrow_mut().has_popup_child = true;
-
     if matches!(popup_window_element.borrow().base_type, ElementType::Builtin(_)) {
         popup_window_element.borrow_mut().base_type = window_type.clone();
     }
@@ -163,10 +171,56 @@ fn lower_popup_window(
             }
             None
         })
-        .unwrap_or_else(|| EnumerationValue {
-            value: 0,
-            enumeration: crate::typeregister::BUILTIN.with(|e| e.enums.PopupClosePolicy.clone()),
-        });
+        .unwrap_or_else(default_close_policy);
+
+    move_into_component(popup_window_element, parent_element, close_policy, false, diag);
+}
+
+/// A `Window` declared inside another component is shown in its own top-level window with `show()`
+fn lower_secondary_window(
+    window_element: &ElementRc,
+    parent_element: &ElementRc,
+    diag: &mut BuildDiagnostics,
+) {
+    let parent_component = window_element.borrow().enclosing_component.upgrade().unwrap();
+    if Rc::ptr_eq(&parent_component.root_element, window_element) {
+        diag.push_error(
+            "A Window declared inside a component cannot be directly repeated or conditional"
+                .into(),
+            &*window_element.borrow(),
+        );
+        return;
+    }
+    move_into_component(window_element, parent_element, default_close_policy(), true, diag);
+}
+
+fn default_close_policy() -> EnumerationValue {
+    EnumerationValue {
+        value: 0,
+        enumeration: crate::typeregister::BUILTIN.with(|e| e.enums.PopupClosePolicy.clone()),
+    }
+}
+
+/// Moves the element out of its parent into its own component, and register it in the popup windows
+/// of the parent component
+fn move_into_component(
+    popup_window_element: &ElementRc,
+    parent_element: &ElementRc,
+    close_policy: EnumerationValue,
+    is_window: bool,
+    diag: &mut BuildDiagnostics,
+) {
+    let parent_component = popup_window_element.borrow().enclosing_component.upgrade().unwrap();
+
+    // Remove the popup_window_element from its parent
+    let old_size = parent_element.borrow().children.len();
+    parent_element.borrow_mut().children.retain(|child| !Rc::ptr_eq(child, popup_window_element));
+    debug_assert_eq!(
+        parent_element.borrow().children.len() + 1,
+        old_size,
+        "Exactly one child must be removed (the popup itself)"
+    );
+    parent_element.borrow_mut().has_popup_child = true;
 
     let popup_comp = Rc::new(Component {
         root_element: popup_window_element.clone(),
@@ -205,7 +259,7 @@ fn lower_popup_window(
     // - Maybe this should actually be allowed
     visit_all_named_references(&parent_component, &mut |nr| {
         let element = &nr.element();
-        if check_element(element, &weak, diag, popup_window_element) {
+        if check_element(element, &weak, diag, popup_window_element, is_window) {
             // just set it to whatever is a valid NamedReference, otherwise we'll panic later
             *nr = coord_x.clone();
         }
@@ -215,7 +269,7 @@ fn lower_popup_window(
             if let Expression::ElementReference(ref element) = exp {
                 let elem = element.upgrade().unwrap();
                 if !Rc::ptr_eq(&elem, popup_window_element) {
-                    check_element(&elem, &weak, diag, popup_window_element);
+                    check_element(&elem, &weak, diag, popup_window_element, is_window);
                 }
             }
         });
@@ -227,6 +281,7 @@ fn lower_popup_window(
         y: coord_y,
         close_policy,
         parent_element: parent_element.clone(),
+        is_window,
     });
 }
 
@@ -239,10 +294,14 @@ fn check_element(
     popup_comp: &Weak<Component>,
     diag: &mut BuildDiagnostics,
     popup_window_element: &ElementRc,
+    is_window: bool,
 ) -> bool {
     if Weak::ptr_eq(&element.borrow().enclosing_component, popup_comp) {
         diag.push_error(
-            "Cannot access the inside of a PopupWindow from enclosing component".into(),
+            format!(
+                "Cannot access the inside of a {} from enclosing component",
+                if is_window { "Window" } else { "PopupWindow" }
+            ),
             &*popup_window_element.borrow(),
         );
         true
@@ -0,0 +1,105 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Pass that passes the properties declared in a ShaderEffect element to its shader.
+//!
+//! Each property is converted to one or more floats that are set to the `uniform-N` properties of
+//! the native item, and their names and counts are set to its `uniform-names` property.
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::{BuiltinFunction, Expression, NamedReference, Unit};
+use crate::langtype::{ElementType, Type};
+use crate::object_tree::*;
+use smol_str::{format_smolstr, SmolStr};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Must be kept in sync with `ShaderEffect::MAX_UNIFORM_VALUES` in i-slint-core
+const MAX_UNIFORM_VALUES: usize = 16;
+
+pub fn lower_shader_effect(component: &Rc<Component>, diag: &mut BuildDiagnostics) {
+    recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
+        let is_shader_effect = matches!(&elem.borrow().base_type, ElementType::Builtin(base_type) if base_type.name == "ShaderEffect");
+        if is_shader_effect {
+            lower_element(elem, diag);
+        }
+    })
+}
+
+fn lower_element(elem: &ElementRc, diag: &mut BuildDiagnostics) {
+    let declarations = elem
+        .borrow()
+        .property_declarations
+        .iter()
+        .map(|(name, decl)| (name.clone(), decl.property_type.clone(), decl.node.clone()))
+        .collect::<Vec<_>>();
+
+    let mut names = Vec::new();
+    let mut values = Vec::new();
+    for (name, ty, node) in declarations {
+        let prop = || Expression::PropertyReference(NamedReference::new(elem, name.clone()));
+        let divide = |unit| Expression::BinaryExpression {
+            lhs: Box::new(prop()),
+            rhs: Box::new(Expression::NumberLiteral(1., unit)),
+            op: '/',
+        };
+        let converted = match ty {
+            Type::Float32 => vec![prop()],
+            Type::Int32 => vec![Expression::Cast { from: Box::new(prop()), to: Type::Float32 }],
+            Type::Bool => vec![Expression::Condition {
+                condition: Box::new(prop()),
+                true_expr: Box::new(Expression::NumberLiteral(1., Unit::None)),
+                false_expr: Box::new(Expression::NumberLiteral(0., Unit::None)),
+            }],
+            Type::LogicalLength => vec![divide(Unit::Px)],
+            Type::Duration => vec![divide(Unit::Ms)],
+            Type::Angle => vec![divide(Unit::Deg)],
+            Type::Color | Type::Brush => {
+                let color = if ty == Type::Brush {
+                    Expression::Cast { from: Box::new(prop()), to: Type::Color }
+                } else {
+                    prop()
+                };
+                ["red", "green", "blue", "alpha"]
+                    .into_iter()
+                    .map(|field| Expression::BinaryExpression {
+                        lhs: Box::new(Expression::StructFieldAccess {
+                            base: Box::new(Expression::FunctionCall {
+                                function: Box::new(Expression::BuiltinFunctionReference(
+                                    BuiltinFunction::ColorRgbaStruct,
+                                    None,
+                                )),
+                                arguments: vec![color.clone()],
+                                source_location: None,
+                            }),
+                            name: SmolStr::new_static(field),
+                        }),
+                        rhs: Box::new(Expression::NumberLiteral(255., Unit::None)),
+                        op: '/',
+                    })
+                    .collect()
+            }
+            // Callbacks, functions, and properties of other types are not passed to the shader
+            _ => continue,
+        };
+
+        if values.len() + converted.len() > MAX_UNIFORM_VALUES {
+            diag.push_error(
+                format!("A ShaderEffect can pass at most {MAX_UNIFORM_VALUES} values to its shader, where a color counts as four values"),
+                &node,
+            );
+            return;
+        }
+        names.push(format!("{}:{}", name.replace('-', "_"), converted.len()));
+        values.extend(converted);
+    }
+
+    let mut elem = elem.borrow_mut();
+    elem.bindings.insert(
+        SmolStr::new_static("uniform-names"),
+        RefCell::new(Expression::StringLiteral(names.join(",").into()).into()),
+    );
+    for (index, value) in values.into_iter().enumerate() {
+        elem.bindings.insert(format_smolstr!("uniform-{index}"), RefCell::new(value.into()));
+    }
+}
@@ -0,0 +1,163 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Pass that lowers the SplitView to position its panes and create the handles between them.
+//!
+//! Must be done before inlining and many other passes because the lowered code must
+//! be further inlined
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::{BindingExpression, Expression, NamedReference, Unit};
+use crate::langtype::ElementType;
+use crate::object_tree::*;
+use smol_str::{format_smolstr, SmolStr};
+use std::cell::RefCell;
+
+pub async fn lower_split_view(
+    doc: &Document,
+    type_loader: &mut crate::typeloader::TypeLoader,
+    diag: &mut BuildDiagnostics,
+) {
+    // Ignore import errors
+    let mut build_diags_to_ignore = BuildDiagnostics::default();
+    let split_view_impl = type_loader
+        .import_component("std-widgets.slint", "SplitViewImpl", &mut build_diags_to_ignore)
+        .await
+        .expect("can't load SplitViewImpl from std-widgets.slint");
+    let split_pane_impl = type_loader
+        .import_component("std-widgets.slint", "SplitPaneImpl", &mut build_diags_to_ignore)
+        .await
+        .expect("can't load SplitPaneImpl from std-widgets.slint");
+    let handle_impl = type_loader
+        .import_component("std-widgets.slint", "SplitViewHandleImpl", &mut build_diags_to_ignore)
+        .await
+        .expect("can't load SplitViewHandleImpl from std-widgets.slint");
+
+    doc.visit_all_used_components(|component| {
+        recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
+            if matches!(&elem.borrow().builtin_type(), Some(b) if b.name == "SplitView") {
+                process_split_view(
+                    elem,
+                    ElementType::Component(split_view_impl.clone()),
+                    ElementType::Component(split_pane_impl.clone()),
+                    ElementType::Component(handle_impl.clone()),
+                    diag,
+                );
+            }
+        })
+    });
+}
+
+fn process_split_view(
+    elem: &ElementRc,
+    split_view_impl: ElementType,
+    split_pane_impl: ElementType,
+    handle_impl: ElementType,
+    diag: &mut BuildDiagnostics,
+) {
+    if matches!(&elem.borrow_mut().base_type, ElementType::Builtin(_)) {
+        // That's the SplitView re-exported from the style, it doesn't need to be processed
+        return;
+    }
+
+    elem.borrow_mut().base_type = split_view_impl;
+    let mut panes = std::mem::take(&mut elem.borrow_mut().children);
+    panes.retain(|pane| {
+        if pane.borrow().repeated.is_some() {
+            diag.push_error(
+                "dynamic panes ('if' or 'for') are currently not supported".into(),
+                &*pane.borrow(),
+            );
+            return false;
+        }
+        if pane.borrow().base_type.to_string() != "SplitPane" {
+            assert!(diag.has_errors());
+            return false;
+        }
+        true
+    });
+    let num_panes = panes.len();
+
+    let handles = (0..num_panes.saturating_sub(1))
+        .map(|index| {
+            Element::make_rc(Element {
+                id: format_smolstr!("{}-handle{}", elem.borrow().id, index),
+                base_type: handle_impl.clone(),
+                enclosing_component: elem.borrow().enclosing_component.clone(),
+                ..Default::default()
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for (index, pane) in panes.iter().enumerate() {
+        for prop in ["x", "y", "width", "height"] {
+            if let Some(old) = pane.borrow().bindings.get(prop) {
+                diag.push_error(
+                    format!("The property '{prop}' cannot be set for panes inside a SplitView"),
+                    &*old.borrow(),
+                );
+            }
+        }
+        pane.borrow_mut().base_type = split_pane_impl.clone();
+        bind_to_view(elem, pane);
+        set_binding(pane, "default-size", reference(elem, "default-pane-size"));
+        set_binding(pane, "fill", Expression::BoolLiteral(index + 1 == num_panes));
+        let start = match index.checked_sub(1) {
+            Some(previous) => reference(&handles[previous], "end"),
+            None => Expression::NumberLiteral(0., Unit::Px),
+        };
+        set_binding(pane, "start", start);
+    }
+
+    for (index, handle) in handles.iter().enumerate() {
+        let pane = &panes[index];
+        let next = &panes[index + 1];
+        bind_to_view(elem, handle);
+        set_binding(handle, "handle-size", reference(elem, "handle-size"));
+        set_binding(handle, "position", reference(pane, "end"));
+        set_binding(handle, "pane-actual-size", reference(pane, "actual-size"));
+        set_binding(handle, "pane-expanded-size", reference(pane, "expanded-size"));
+        set_binding(handle, "min-size", reference(pane, "min-size"));
+        set_binding(handle, "max-size", reference(pane, "max-size"));
+        set_binding(handle, "collapsible", reference(pane, "collapsible"));
+        set_binding(handle, "next-actual-size", reference(next, "actual-size"));
+        set_binding(handle, "next-min-size", reference(next, "min-size"));
+        set_binding(handle, "next-fills", Expression::BoolLiteral(index + 2 == num_panes));
+        set_two_way_binding(handle, "pane-size", pane, "size");
+        set_two_way_binding(handle, "collapsed", pane, "collapsed");
+        set_two_way_binding(handle, "next-size", next, "size");
+    }
+
+    set_binding(elem, "pane-count", Expression::NumberLiteral(num_panes as _, Unit::None));
+    // The handles are on top of the panes, to receive the mouse events first
+    elem.borrow_mut().children = panes.into_iter().chain(handles).collect();
+}
+
+fn bind_to_view(split_view: &ElementRc, elem: &ElementRc) {
+    set_binding(elem, "orientation", reference(split_view, "orientation"));
+    set_binding(elem, "view-width", reference(split_view, "width"));
+    set_binding(elem, "view-height", reference(split_view, "height"));
+}
+
+fn reference(elem: &ElementRc, name: &'static str) -> Expression {
+    Expression::PropertyReference(NamedReference::new(elem, SmolStr::new_static(name)))
+}
+
+fn set_binding(elem: &ElementRc, name: &'static str, expression: Expression) {
+    elem.borrow_mut()
+        .bindings
+        .insert(SmolStr::new_static(name), RefCell::new(BindingExpression::from(expression)));
+}
+
+fn set_two_way_binding(
+    elem: &ElementRc,
+    name: &'static str,
+    other: &ElementRc,
+    other_name: &'static str,
+) {
+    elem.borrow_mut().bindings.insert(
+        SmolStr::new_static(name),
+        BindingExpression::new_two_way(NamedReference::new(other, SmolStr::new_static(other_name)))
+            .into(),
+    );
+}
@@ -0,0 +1,105 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Pass that lowers the SwipeView to position its pages relative to the current one.
+//!
+//! Must be done before inlining and many other passes because the lowered code must
+//! be further inlined
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::{BindingExpression, Expression, NamedReference, Unit};
+use crate::langtype::ElementType;
+use crate::object_tree::*;
+use smol_str::SmolStr;
+use std::cell::RefCell;
+
+pub async fn lower_swipe_view(
+    doc: &Document,
+    type_loader: &mut crate::typeloader::TypeLoader,
+    diag: &mut BuildDiagnostics,
+) {
+    // Ignore import errors
+    let mut build_diags_to_ignore = BuildDiagnostics::default();
+    let swipe_view_impl = type_loader
+        .import_component("std-widgets.slint", "SwipeViewImpl", &mut build_diags_to_ignore)
+        .await
+        .expect("can't load SwipeViewImpl from std-widgets.slint");
+    let swipe_page_impl = type_loader
+        .import_component("std-widgets.slint", "SwipePageImpl", &mut build_diags_to_ignore)
+        .await
+        .expect("can't load SwipePageImpl from std-widgets.slint");
+
+    doc.visit_all_used_components(|component| {
+        recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
+            if matches!(&elem.borrow().builtin_type(), Some(b) if b.name == "SwipeView") {
+                process_swipe_view(
+                    elem,
+                    ElementType::Component(swipe_view_impl.clone()),
+                    ElementType::Component(swipe_page_impl.clone()),
+                    diag,
+                );
+            }
+        })
+    });
+}
+
+fn process_swipe_view(
+    elem: &ElementRc,
+    swipe_view_impl: ElementType,
+    swipe_page_impl: ElementType,
+    diag: &mut BuildDiagnostics,
+) {
+    if matches!(&elem.borrow_mut().base_type, ElementType::Builtin(_)) {
+        // That's the SwipeView re-exported from the style, it doesn't need to be processed
+        return;
+    }
+
+    elem.borrow_mut().base_type = swipe_view_impl;
+    let mut pages = std::mem::take(&mut elem.borrow_mut().children);
+    pages.retain(|page| {
+        if page.borrow().repeated.is_some() {
+            diag.push_error(
+                "dynamic pages ('if' or 'for') are currently not supported".into(),
+                &*page.borrow(),
+            );
+            return false;
+        }
+        if page.borrow().base_type.to_string() != "SwipePage" {
+            assert!(diag.has_errors());
+            return false;
+        }
+        true
+    });
+
+    for (index, page) in pages.iter().enumerate() {
+        for prop in ["x", "y", "width", "height"] {
+            if let Some(old) = page.borrow().bindings.get(prop) {
+                diag.push_error(
+                    format!("The property '{prop}' cannot be set for pages inside a SwipeView"),
+                    &*old.borrow(),
+                );
+            }
+        }
+        page.borrow_mut().base_type = swipe_page_impl.clone();
+        set_binding(page, "index", Expression::NumberLiteral(index as _, Unit::None));
+        set_binding(page, "page-count", reference(elem, "page-count"));
+        set_binding(page, "view-position", reference(elem, "visual-position"));
+        set_binding(page, "orientation", reference(elem, "orientation"));
+        set_binding(page, "loop", reference(elem, "loop"));
+        set_binding(page, "view-width", reference(elem, "width"));
+        set_binding(page, "view-height", reference(elem, "height"));
+    }
+
+    set_binding(elem, "page-count", Expression::NumberLiteral(pages.len() as _, Unit::None));
+    elem.borrow_mut().children = pages;
+}
+
+fn reference(elem: &ElementRc, name: &'static str) -> Expression {
+    Expression::PropertyReference(NamedReference::new(elem, SmolStr::new_static(name)))
+}
+
+fn set_binding(elem: &ElementRc, name: &'static str, expression: Expression) {
+    elem.borrow_mut()
+        .bindings
+        .insert(SmolStr::new_static(name), RefCell::new(BindingExpression::from(expression)));
+}
@@ -0,0 +1,107 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Passes that add the `ToastLayerImpl` component of the style on top of the content of the
+//! exported windows, to show the toasts of `slint::Toast`.
+//!
+//! The layer is added before the repeaters are lowered, and moved back to be the last child of
+//! the window after inlining, as the base of the window may have placed it in a layout with `@children`.
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::{BuiltinFunction, Expression, NamedReference, Unit};
+use crate::langtype::{ElementType, Type};
+use crate::object_tree::*;
+use core::cell::RefCell;
+use smol_str::SmolStr;
+use std::rc::Rc;
+
+const TOAST_LAYER_IMPL: &str = "ToastLayerImpl";
+
+pub async fn lower_toasts(
+    doc: &Document,
+    type_loader: &mut crate::typeloader::TypeLoader,
+    diag: &mut BuildDiagnostics,
+) {
+    if !type_loader.compiler_config.toasts {
+        return;
+    }
+    let windows = doc
+        .exported_roots()
+        .filter(|c| matches!(c.root_element.borrow().builtin_type(), Some(b) if b.name == "Window"))
+        .collect::<Vec<_>>();
+    if windows.is_empty() {
+        return;
+    }
+
+    // Ignore import errors
+    let mut build_diags_to_ignore = BuildDiagnostics::default();
+    let Some(toast_layer_impl) = type_loader
+        .import_component("std-widgets.slint", TOAST_LAYER_IMPL, &mut build_diags_to_ignore)
+        .await
+    else {
+        diag.push_error(
+            "The style doesn't support toasts".into(),
+            &*windows[0].root_element.borrow(),
+        );
+        return;
+    };
+
+    for window in windows {
+        let layer = Element {
+            id: SmolStr::new_static("toast-layer"),
+            base_type: toast_layer_impl.clone().into(),
+            enclosing_component: Rc::downgrade(&window),
+            ..Default::default()
+        }
+        .make_rc();
+
+        let mut bindings = vec![
+            ("x", Expression::NumberLiteral(0., Unit::Px)),
+            ("y", Expression::NumberLiteral(0., Unit::Px)),
+            ("toasts", builtin_call(BuiltinFunction::WindowToasts, vec![])),
+            (
+                "activated",
+                builtin_call(
+                    BuiltinFunction::ActivateToast,
+                    vec![Expression::FunctionParameterReference { index: 0, ty: Type::Int32 }],
+                ),
+            ),
+        ];
+        for size in ["width", "height"] {
+            let nr = NamedReference::new(&window.root_element, SmolStr::new_static(size));
+            bindings.push((size, Expression::PropertyReference(nr)));
+        }
+        layer.borrow_mut().bindings.extend(
+            bindings
+                .into_iter()
+                .map(|(name, e)| (SmolStr::new_static(name), RefCell::new(e.into()))),
+        );
+
+        window.root_element.borrow_mut().children.push(layer);
+    }
+}
+
+/// Makes the toast layer the last child of the window again, after inlining.
+pub fn move_toast_layer_to_window(component: &Rc<Component>) {
+    fn take_toast_layer(elem: &ElementRc) -> Option<ElementRc> {
+        let mut elem = elem.borrow_mut();
+        let index = elem.children.iter().position(|child| {
+            matches!(&child.borrow().base_type, ElementType::Component(c) if c.id == TOAST_LAYER_IMPL)
+        });
+        match index {
+            Some(index) => Some(elem.children.remove(index)),
+            None => elem.children.iter().find_map(take_toast_layer),
+        }
+    }
+    if let Some(layer) = take_toast_layer(&component.root_element) {
+        component.root_element.borrow_mut().children.push(layer);
+    }
+}
+
+fn builtin_call(function: BuiltinFunction, arguments: Vec<Expression>) -> Expression {
+    Expression::FunctionCall {
+        function: Expression::BuiltinFunctionReference(function, None).into(),
+        arguments,
+        source_location: None,
+    }
+}
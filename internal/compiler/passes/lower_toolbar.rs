@@ -0,0 +1,187 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+// cSpell: ignore toolbar
+
+//! Pass that lowers the ToolBar to lay out its items and create the overflow menu with
+//! the items that don't fit.
+//!
+//! Must be done before inlining and many other passes because the lowered code must
+//! be further inlined
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::{BindingExpression, Expression, NamedReference, Unit};
+use crate::langtype::ElementType;
+use crate::object_tree::*;
+use smol_str::{format_smolstr, SmolStr};
+use std::cell::RefCell;
+
+struct ToolBarTypes {
+    toolbar: ElementType,
+    items: ElementType,
+    overflow: ElementType,
+    button: ElementType,
+    separator: ElementType,
+    menu_item: ElementType,
+    menu_separator: ElementType,
+}
+
+pub async fn lower_toolbar(
+    doc: &Document,
+    type_loader: &mut crate::typeloader::TypeLoader,
+    diag: &mut BuildDiagnostics,
+) {
+    let types = ToolBarTypes {
+        toolbar: import_impl(type_loader, "ToolBarImpl").await,
+        items: import_impl(type_loader, "ToolBarItemsImpl").await,
+        overflow: import_impl(type_loader, "ToolBarOverflowImpl").await,
+        button: import_impl(type_loader, "ToolButtonImpl").await,
+        separator: import_impl(type_loader, "ToolSeparatorImpl").await,
+        menu_item: import_impl(type_loader, "ToolBarMenuItemImpl").await,
+        menu_separator: import_impl(type_loader, "ToolBarMenuSeparatorImpl").await,
+    };
+
+    doc.visit_all_used_components(|component| {
+        recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
+            if matches!(&elem.borrow().builtin_type(), Some(b) if b.name == "ToolBar") {
+                process_toolbar(elem, &types, diag);
+            }
+        })
+    });
+}
+
+async fn import_impl(type_loader: &mut crate::typeloader::TypeLoader, name: &str) -> ElementType {
+    // Ignore import errors
+    let mut build_diags_to_ignore = BuildDiagnostics::default();
+    let component = type_loader
+        .import_component("std-widgets.slint", name, &mut build_diags_to_ignore)
+        .await
+        .unwrap_or_else(|| panic!("can't load {name} from std-widgets.slint"));
+    ElementType::Component(component)
+}
+
+fn process_toolbar(elem: &ElementRc, types: &ToolBarTypes, diag: &mut BuildDiagnostics) {
+    if matches!(&elem.borrow_mut().base_type, ElementType::Builtin(_)) {
+        // That's the ToolBar re-exported from the style, it doesn't need to be processed
+        return;
+    }
+
+    elem.borrow_mut().base_type = types.toolbar.clone();
+    let mut items = std::mem::take(&mut elem.borrow_mut().children);
+    items.retain(|item| {
+        if item.borrow().repeated.is_some() {
+            diag.push_error(
+                "dynamic items ('if' or 'for') are currently not supported".into(),
+                &*item.borrow(),
+            );
+            return false;
+        }
+        let base_type = item.borrow().base_type.to_string();
+        if base_type != "ToolButton" && base_type != "ToolSeparator" {
+            assert!(diag.has_errors());
+            return false;
+        }
+        true
+    });
+
+    let mut menu_items = Vec::with_capacity(items.len());
+    for (index, item) in items.iter().enumerate() {
+        // The position is set by the layout, which reports the error if it's set
+        if let Some(old) = item.borrow().bindings.get("visible") {
+            diag.push_error(
+                "The property 'visible' cannot be set for items inside a ToolBar".into(),
+                &*old.borrow(),
+            );
+        }
+        let is_button = item.borrow().base_type.to_string() == "ToolButton";
+        let menu_item = Element::make_rc(Element {
+            id: format_smolstr!("{}-menu-item{}", elem.borrow().id, index),
+            base_type: if is_button {
+                types.menu_item.clone()
+            } else {
+                types.menu_separator.clone()
+            },
+            enclosing_component: elem.borrow().enclosing_component.clone(),
+            ..Default::default()
+        });
+        set_binding(&menu_item, "overflowed", reference(item, "overflowed"));
+        if is_button {
+            for prop in ["text", "icon", "enabled", "checkable"] {
+                set_binding(&menu_item, prop, reference(item, prop));
+            }
+            menu_item.borrow_mut().bindings.insert(
+                SmolStr::new_static("checked"),
+                BindingExpression::new_two_way(NamedReference::new(
+                    item,
+                    SmolStr::new_static("checked"),
+                ))
+                .into(),
+            );
+            // Activating the entry of the menu is like clicking the item
+            let clicked = Expression::FunctionCall {
+                function: Box::new(Expression::CallbackReference(
+                    NamedReference::new(item, SmolStr::new_static("clicked")),
+                    None,
+                )),
+                arguments: vec![],
+                source_location: None,
+            };
+            set_binding(&menu_item, "clicked", clicked);
+        }
+        item.borrow_mut().base_type =
+            if is_button { types.button.clone() } else { types.separator.clone() };
+        set_binding(item, "available-width", reference(elem, "available-width"));
+        menu_items.push(menu_item);
+    }
+
+    let items = Element::make_rc(Element {
+        id: format_smolstr!("{}-items", elem.borrow().id),
+        base_type: types.items.clone(),
+        enclosing_component: elem.borrow().enclosing_component.clone(),
+        children: items,
+        ..Default::default()
+    });
+    // The items keep their preferred size, those that don't fit are hidden
+    set_binding(&items, "x", Expression::NumberLiteral(0., Unit::Px));
+    set_binding(&items, "y", Expression::NumberLiteral(0., Unit::Px));
+    set_binding(&items, "width", reference(&items, "preferred-width"));
+    set_binding(&items, "height", reference(elem, "height"));
+
+    let overflow = Element::make_rc(Element {
+        id: format_smolstr!("{}-overflow", elem.borrow().id),
+        base_type: types.overflow.clone(),
+        enclosing_component: elem.borrow().enclosing_component.clone(),
+        children: menu_items,
+        ..Default::default()
+    });
+    set_binding(&overflow, "x", reference(elem, "available-width"));
+    set_binding(&overflow, "y", Expression::NumberLiteral(0., Unit::Px));
+    set_binding(&overflow, "width", reference(elem, "overflow-button-width"));
+    set_binding(&overflow, "height", reference(elem, "height"));
+    set_binding(&overflow, "visible", reference(elem, "has-overflow"));
+
+    for (prop, items_prop) in
+        [("content-width", "preferred-width"), ("content-height", "preferred-height")]
+    {
+        elem.borrow_mut().bindings.insert(
+            SmolStr::new_static(prop),
+            BindingExpression::new_two_way(NamedReference::new(
+                &items,
+                SmolStr::new_static(items_prop),
+            ))
+            .into(),
+        );
+    }
+
+    elem.borrow_mut().children = vec![items, overflow];
+}
+
+fn reference(elem: &ElementRc, name: &'static str) -> Expression {
+    Expression::PropertyReference(NamedReference::new(elem, SmolStr::new_static(name)))
+}
+
+fn set_binding(elem: &ElementRc, name: &'static str, expression: Expression) {
+    elem.borrow_mut()
+        .bindings
+        .insert(SmolStr::new_static(name), RefCell::new(BindingExpression::from(expression)));
+}
@@ -0,0 +1,130 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Pass that lowers the Wizard to place its steps in the content area and only show the current one.
+//!
+//! Must be done before inlining and many other passes because the lowered code must
+//! be further inlined
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::{BindingExpression, Expression, MinMaxOp, NamedReference, Unit};
+use crate::langtype::{ElementType, Type};
+use crate::object_tree::*;
+use smol_str::{format_smolstr, SmolStr};
+use std::cell::RefCell;
+
+pub async fn lower_wizard(
+    doc: &Document,
+    type_loader: &mut crate::typeloader::TypeLoader,
+    diag: &mut BuildDiagnostics,
+) {
+    // Ignore import errors
+    let mut build_diags_to_ignore = BuildDiagnostics::default();
+    let wizard_impl = type_loader
+        .import_component("std-widgets.slint", "WizardImpl", &mut build_diags_to_ignore)
+        .await
+        .expect("can't load WizardImpl from std-widgets.slint");
+    let wizard_step_impl = type_loader
+        .import_component("std-widgets.slint", "WizardStepImpl", &mut build_diags_to_ignore)
+        .await
+        .expect("can't load WizardStepImpl from std-widgets.slint");
+
+    doc.visit_all_used_components(|component| {
+        recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
+            if matches!(&elem.borrow().builtin_type(), Some(b) if b.name == "Wizard") {
+                process_wizard(
+                    elem,
+                    ElementType::Component(wizard_impl.clone()),
+                    ElementType::Component(wizard_step_impl.clone()),
+                    diag,
+                );
+            }
+        })
+    });
+}
+
+fn process_wizard(
+    elem: &ElementRc,
+    wizard_impl: ElementType,
+    wizard_step_impl: ElementType,
+    diag: &mut BuildDiagnostics,
+) {
+    if matches!(&elem.borrow_mut().base_type, ElementType::Builtin(_)) {
+        // That's the Wizard re-exported from the style, it doesn't need to be processed
+        return;
+    }
+
+    elem.borrow_mut().base_type = wizard_impl;
+    let mut steps = std::mem::take(&mut elem.borrow_mut().children);
+    steps.retain(|step| {
+        if step.borrow().repeated.is_some() {
+            diag.push_error(
+                "dynamic steps ('if' or 'for') are currently not supported".into(),
+                &*step.borrow(),
+            );
+            return false;
+        }
+        if step.borrow().base_type.to_string() != "WizardStep" {
+            assert!(diag.has_errors());
+            return false;
+        }
+        true
+    });
+
+    for (index, step) in steps.iter().enumerate() {
+        for prop in ["x", "y", "width", "height", "visible"] {
+            if let Some(old) = step.borrow().bindings.get(prop) {
+                diag.push_error(
+                    format!("The property '{prop}' cannot be set for steps inside a Wizard"),
+                    &*old.borrow(),
+                );
+            }
+        }
+        step.borrow_mut().base_type = wizard_step_impl.clone();
+        set_binding(step, "index", Expression::NumberLiteral(index as _, Unit::None));
+        set_binding(step, "current-index", reference(elem, "current-index"));
+        for prop in ["x", "y", "width", "height"] {
+            set_binding(
+                step,
+                prop,
+                Expression::PropertyReference(NamedReference::new(
+                    elem,
+                    format_smolstr!("content-{prop}"),
+                )),
+            );
+        }
+    }
+
+    set_binding(elem, "step-count", Expression::NumberLiteral(steps.len() as _, Unit::None));
+    set_binding(
+        elem,
+        "step-titles",
+        Expression::Array {
+            element_ty: Type::String,
+            values: steps.iter().map(|step| reference(step, "title")).collect(),
+        },
+    );
+    for prop in ["min-width", "min-height"] {
+        if let Some(expr) = steps
+            .iter()
+            .map(|step| reference(step, prop))
+            .reduce(|lhs, rhs| crate::builtin_macros::min_max_expression(lhs, rhs, MinMaxOp::Max))
+        {
+            elem.borrow_mut()
+                .bindings
+                .insert(format_smolstr!("content-{prop}"), RefCell::new(expr.into()));
+        }
+    }
+
+    elem.borrow_mut().children = steps;
+}
+
+fn reference(elem: &ElementRc, name: &'static str) -> Expression {
+    Expression::PropertyReference(NamedReference::new(elem, SmolStr::new_static(name)))
+}
+
+fn set_binding(elem: &ElementRc, name: &'static str, expression: Expression) {
+    elem.borrow_mut()
+        .bindings
+        .insert(SmolStr::new_static(name), RefCell::new(BindingExpression::from(expression)));
+}
@@ -1,11 +1,11 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-use smol_str::{format_smolstr, SmolStr};
-use std::collections::{BTreeMap, HashMap};
+use smol_str::SmolStr;
+use std::collections::BTreeMap;
 use std::rc::Rc;
 
-use crate::expression_tree::Expression;
+use crate::expression_tree::{unique_local_variable_name, Expression};
 use crate::langtype::{Struct, Type};
 
 pub fn remove_return(doc: &crate::object_tree::Document) {
@@ -173,11 +173,7 @@ fn continue_codeblock(
     has_value: bool,
 ) -> ExpressionResult {
     let rest = process_codeblock(iter, ty, ctx).into_return_object(ty, &ctx.ret_ty);
-    static COUNT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
-    let unique_name = format_smolstr!(
-        "return_check_merge{}",
-        COUNT.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
-    );
+    let unique_name = unique_local_variable_name("return_check_merge");
     let load = Box::new(Expression::ReadLocalVariable {
         name: unique_name.clone(),
         ty: return_object.ty(),
@@ -258,12 +254,7 @@ fn to_expression(self, ty: &Type) -> Expression {
                 Expression::CodeBlock(pre_statements)
             }
             ExpressionResult::ReturnObject { value, has_value, has_return_value } => {
-                static COUNT: std::sync::atomic::AtomicUsize =
-                    std::sync::atomic::AtomicUsize::new(0);
-                let name = format_smolstr!(
-                    "returned_expression{}",
-                    COUNT.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
-                );
+                let name = unique_local_variable_name("returned_expression");
                 let load =
                     Box::new(Expression::ReadLocalVariable { name: name.clone(), ty: value.ty() });
                 Expression::CodeBlock(vec![
@@ -372,12 +363,7 @@ fn map_value(self, f: impl FnOnce(Expression) -> Expression) -> Self {
                 if !has_value {
                     return ExpressionResult::ReturnObject { value, has_value, has_return_value };
                 }
-                static COUNT: std::sync::atomic::AtomicUsize =
-                    std::sync::atomic::AtomicUsize::new(0);
-                let name = format_smolstr!(
-                    "mapped_expression{}",
-                    COUNT.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
-                );
+                let name = unique_local_variable_name("mapped_expression");
                 let value_ty = value.ty();
                 let load = |field: &str| Expression::StructFieldAccess {
                     base: Box::new(Expression::ReadLocalVariable {
@@ -417,7 +403,7 @@ fn codeblock_with_expr(mut pre_statements: Vec<Expression>, expr: Expression) ->
 
 fn make_struct(it: impl Iterator<Item = (&'static str, Type, Expression)>) -> Expression {
     let mut fields = BTreeMap::<SmolStr, Type>::new();
-    let mut values = HashMap::<SmolStr, Expression>::new();
+    let mut values = BTreeMap::<SmolStr, Expression>::new();
     let mut voids = Vec::new();
     for (name, ty, expr) in it {
         if matches!(ty, Type::Void | Type::Invalid) {
@@ -451,7 +437,7 @@ fn convert_struct(from: Expression, to: Type) -> Expression {
         return Expression::Invalid;
     };
     if let Expression::Struct { mut values, .. } = from {
-        let mut new_values = HashMap::new();
+        let mut new_values = BTreeMap::new();
         for (key, ty) in &s.fields {
             let (key, expression) = values
                 .remove_entry(key)
@@ -460,13 +446,9 @@ fn convert_struct(from: Expression, to: Type) -> Expression {
         }
         return Expression::Struct { values: new_values, ty: to };
     }
-    static COUNT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
-    let var_name = format_smolstr!(
-        "tmpobj_ret_conv_{}",
-        COUNT.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
-    );
+    let var_name = unique_local_variable_name("tmpobj_ret_conv_");
     let from_ty = from.ty();
-    let mut new_values = HashMap::new();
+    let mut new_values = BTreeMap::new();
     let Type::Struct(from_s) = &from_ty else {
         assert_eq!(from_ty, Type::Invalid);
         return Expression::Invalid;
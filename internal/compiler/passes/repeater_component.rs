@@ -15,6 +15,7 @@
 pub fn process_repeater_components(component: &Rc<Component>) {
     create_repeater_components(component);
     adjust_references(component);
+    mark_listview_callbacks_as_set(component);
 }
 
 fn create_repeater_components(component: &Rc<Component>) {
@@ -102,6 +103,21 @@ fn create_repeater_components(component: &Rc<Component>) {
     }
 }
 
+/// The handler of the callback of the ListView that scrolls to a row is set by the generated code.
+/// This is done once the references were adjusted, because the ListView may itself be repeated.
+fn mark_listview_callbacks_as_set(component: &Rc<Component>) {
+    recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
+        let scroll_to_row = elem
+            .borrow()
+            .repeated
+            .as_ref()
+            .and_then(|r| r.is_listview.as_ref()?.scroll_to_row.clone());
+        if let Some(scroll_to_row) = scroll_to_row {
+            scroll_to_row.mark_as_set();
+        }
+    });
+}
+
 /// Make sure that references to property within the repeated element actually point to the reference
 /// to the root of the newly created component
 fn adjust_references(comp: &Rc<Component>) {
@@ -0,0 +1,231 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Pass that reports the exported components that are never used, the properties that are never
+//! read, and the callbacks that are never invoked in all the documents of the project.
+//!
+//! This is only run when [`crate::CompilerConfiguration::report_unused_items`] is set.
+
+use crate::diagnostics::{BuildDiagnostics, Spanned};
+use crate::expression_tree::Expression;
+use crate::langtype::{ElementType, Type};
+use crate::namedreference::NamedReference;
+use crate::object_tree::{recurse_elem, visit_element_expressions, Document, Element, ElementRc};
+use crate::object_tree::{Component, PropertyVisibility};
+use smol_str::SmolStr;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+type DeclarationKey = (*const RefCell<Element>, SmolStr);
+
+#[derive(Default)]
+struct Usage {
+    components: HashSet<*const Component>,
+    read_properties: HashSet<DeclarationKey>,
+    invoked_callbacks: HashSet<DeclarationKey>,
+}
+
+/// Returns the element that declares the property, which can be the root element of a base component
+fn declaring_element(elem: &ElementRc, name: &str) -> Option<ElementRc> {
+    let mut elem = elem.clone();
+    loop {
+        if elem.borrow().property_declarations.contains_key(name) {
+            return Some(elem);
+        }
+        let base = match &elem.borrow().base_type {
+            ElementType::Component(c) => c.root_element.clone(),
+            _ => return None,
+        };
+        elem = base;
+    }
+}
+
+fn key(elem: &ElementRc, name: &str) -> Option<DeclarationKey> {
+    declaring_element(elem, name).map(|e| (Rc::as_ptr(&e), SmolStr::new(name)))
+}
+
+impl Usage {
+    fn mark_read(&mut self, nr: &NamedReference) {
+        if let Some(key) = key(&nr.element(), nr.name()) {
+            self.read_properties.insert(key);
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &Expression) {
+        match expr {
+            Expression::PropertyReference(nr) => self.mark_read(nr),
+            Expression::CallbackReference(nr, _) | Expression::FunctionReference(nr, _) => {
+                if let Some(key) = key(&nr.element(), nr.name()) {
+                    self.invoked_callbacks.insert(key);
+                }
+            }
+            // Assigning to a property (even with `+=`) doesn't count as reading it
+            Expression::SelfAssignment { lhs, rhs, .. } => {
+                if !matches!(**lhs, Expression::PropertyReference(_)) {
+                    self.visit_expression(lhs);
+                }
+                self.visit_expression(rhs);
+                return;
+            }
+            _ => {}
+        }
+        expr.visit(|sub| self.visit_expression(sub));
+    }
+
+    fn visit_element(&mut self, elem: &ElementRc) {
+        if let ElementType::Component(c) = &elem.borrow().base_type {
+            self.components.insert(Rc::as_ptr(c));
+        }
+        visit_element_expressions(elem, |expr, _, _| self.visit_expression(expr));
+
+        let elem_ref = elem.borrow();
+        for name in elem_ref.change_callbacks.keys() {
+            if let Some(key) = key(elem, name) {
+                self.read_properties.insert(key);
+            }
+        }
+        // Both sides of a two way binding are considered used, as they can be changed from either side
+        let mut mark_two_way = |name: &SmolStr, nr: &NamedReference| {
+            for (elem, name) in [(elem.clone(), name.as_str()), (nr.element(), nr.name())] {
+                if let Some(key) = key(&elem, name) {
+                    self.read_properties.insert(key.clone());
+                    self.invoked_callbacks.insert(key);
+                }
+            }
+        };
+        for (name, binding) in &elem_ref.bindings {
+            for nr in &binding.borrow().two_way_bindings {
+                mark_two_way(name, nr);
+            }
+        }
+        for (name, decl) in &elem_ref.property_declarations {
+            if let Some(nr) = &decl.is_alias {
+                mark_two_way(name, nr);
+            }
+        }
+    }
+}
+
+fn is_builtin(doc: &Document) -> bool {
+    doc.node
+        .as_ref()
+        .and_then(|n| n.source_file())
+        .map_or(true, |sf| sf.path().starts_with("builtin:"))
+}
+
+pub fn report_unused<'a>(
+    doc: &Document,
+    all_docs: impl Iterator<Item = &'a Document>,
+    diag: &mut BuildDiagnostics,
+) {
+    let mut docs = all_docs.filter(|d| !is_builtin(d)).collect::<Vec<_>>();
+    docs.sort_by_key(|d| d.node.as_ref().and_then(|n| n.source_file()).map(|sf| sf.path()));
+
+    let mut usage = Usage::default();
+    for d in std::iter::once(doc).chain(docs.iter().copied()) {
+        for component in &d.inner_components {
+            recurse_elem(&component.root_element, &(), &mut |elem, _| usage.visit_element(elem));
+        }
+    }
+
+    // The components and globals exported from the root document are the API of the project
+    let entry_points = doc
+        .exports
+        .iter()
+        .filter_map(|(_, c)| c.as_ref().left())
+        .map(Rc::as_ptr)
+        .collect::<HashSet<_>>();
+
+    for d in docs.iter().copied() {
+        for (export, component) in d.exports.iter() {
+            let Some(component) = component.as_ref().left() else { continue };
+            if !component.is_global()
+                && !entry_points.contains(&Rc::as_ptr(component))
+                && !usage.components.contains(&Rc::as_ptr(component))
+            {
+                diag.push_warning(
+                    format!("Component '{}' is exported but never used", export.name),
+                    &export.name_ident,
+                );
+            }
+        }
+    }
+
+    for d in std::iter::once(doc).chain(docs.iter().copied()) {
+        for component in &d.inner_components {
+            let is_entry_point = entry_points.contains(&Rc::as_ptr(component));
+            recurse_elem(&component.root_element, &(), &mut |elem, _| {
+                let is_api = is_entry_point && Rc::ptr_eq(elem, &component.root_element);
+                for (name, decl) in &elem.borrow().property_declarations {
+                    let Some(node) = &decl.node else { continue };
+                    if is_api && decl.visibility != PropertyVisibility::Private {
+                        continue;
+                    }
+                    let key = (Rc::as_ptr(elem), name.clone());
+                    match &decl.property_type {
+                        Type::Callback(_) => {
+                            if !usage.invoked_callbacks.contains(&key) {
+                                diag.push_warning(
+                                    format!("Callback '{name}' is never invoked"),
+                                    node,
+                                );
+                            }
+                        }
+                        Type::Function(_) => {}
+                        _ => {
+                            if !usage.read_properties.contains(&key) {
+                                diag.push_warning(format!("Property '{name}' is never read"), node);
+                            }
+                        }
+                    }
+                }
+            });
+        }
+    }
+}
+
+#[test]
+fn report_unused_items() {
+    let mut compiler_config =
+        crate::CompilerConfiguration::new(crate::generator::OutputFormat::Interpreter);
+    compiler_config.style = Some("fluent".into());
+    compiler_config.report_unused_items = true;
+    let mut diag = BuildDiagnostics::default();
+    let node = crate::parser::parse(
+        r#"
+        component Button {
+            in property <string> text;
+            in property <bool> unused-input;
+            callback clicked;
+            callback never-called;
+            Text { text: root.text; }
+            TouchArea { clicked => { root.clicked(); } }
+        }
+        export component NeverUsed {}
+        export component Main inherits Window {
+            in-out property <int> public-prop;
+            property <int> counter;
+            property <int> watched;
+            changed watched => { counter += 1; }
+            Button { text: "Hello"; clicked => { watched += 1; } }
+        }
+        "#
+        .into(),
+        Some(std::path::Path::new("main.slint")),
+        &mut diag,
+    );
+    let (_, diag, _) = spin_on::spin_on(crate::compile_syntax_node(node, diag, compiler_config));
+    assert!(!diag.has_errors(), "{:?}", diag.to_string_vec());
+    let mut warnings = diag.iter().map(|d| d.message().to_string()).collect::<Vec<_>>();
+    warnings.sort();
+    assert_eq!(
+        warnings,
+        [
+            "Callback 'never-called' is never invoked",
+            "Exported component 'NeverUsed' doesn't inherit Window. No code will be generated for it",
+            "Property 'counter' is never read",
+            "Property 'unused-input' is never read",
+        ]
+    );
+}
@@ -8,7 +8,7 @@
 //!
 //! Most of the code for the resolving actually lies in the expression_tree module
 
-use crate::diagnostics::{BuildDiagnostics, Spanned};
+use crate::diagnostics::{BuildDiagnostics, DiagnosticLevel, FixIt, Spanned};
 use crate::expression_tree::*;
 use crate::langtype::{ElementType, Struct, Type};
 use crate::lookup::{LookupCtx, LookupObject, LookupResult};
@@ -17,7 +17,7 @@
 use crate::typeregister::TypeRegister;
 use core::num::IntErrorKind;
 use smol_str::{SmolStr, ToSmolStr};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::rc::Rc;
 
 /// This represents a scope for the Component, where Component is the repeated component, but
@@ -267,7 +267,7 @@ fn from_function(node: syntax_nodes::Function, ctx: &mut LookupCtx) -> Expressio
         )
     }
 
-    fn from_expression_node(node: syntax_nodes::Expression, ctx: &mut LookupCtx) -> Self {
+    pub fn from_expression_node(node: syntax_nodes::Expression, ctx: &mut LookupCtx) -> Self {
         node.children_with_tokens()
             .find_map(|child| match child {
                 NodeOrToken::Node(node) => match node.kind() {
@@ -282,12 +282,14 @@ fn from_expression_node(node: syntax_nodes::Expression, ctx: &mut LookupCtx) ->
                             LookupPhase::default(),
                         );
                         if matches!(exp.ty(), Type::Function { .. } | Type::Callback { .. }) {
-                            ctx.diag.push_error(
+                            ctx.diag.push_diagnostic_with_fix_its(
                                 format!(
                                     "'{}' must be called. Did you forgot the '()'?",
                                     QualifiedTypeName::from_node(node.clone().into())
                                 ),
                                 &node,
+                                DiagnosticLevel::Error,
+                                vec![FixIt::insert("Add '()'", node.text_range().end(), "()")],
                             )
                         }
                         Some(exp)
@@ -442,11 +444,13 @@ fn from_at_gradient(node: syntax_nodes::AtGradient, ctx: &mut LookupCtx) -> Self
         enum GradKind {
             Linear { angle: Box<Expression> },
             Radial,
+            Conic { from_angle: Box<Expression> },
         }
 
         let mut subs = node
             .children_with_tokens()
-            .filter(|n| matches!(n.kind(), SyntaxKind::Comma | SyntaxKind::Expression));
+            .filter(|n| matches!(n.kind(), SyntaxKind::Comma | SyntaxKind::Expression))
+            .peekable();
 
         let grad_token = node.child_token(SyntaxKind::Identifier).unwrap();
         let grad_text = grad_token.text();
@@ -495,8 +499,39 @@ enum GradKind {
                 return Expression::Invalid;
             }
             GradKind::Radial
+        } else if grad_text.starts_with("conic") {
+            let from_angle = if matches!(subs.peek(), Some(NodeOrToken::Node(n)) if n.text().to_string().trim() == "from")
+            {
+                subs.next();
+                let angle_expr = match subs.next() {
+                    Some(e) if e.kind() == SyntaxKind::Expression => {
+                        syntax_nodes::Expression::from(e.into_node().unwrap())
+                    }
+                    _ => {
+                        ctx.diag.push_error("Expected angle expression after 'from'".into(), &node);
+                        return Expression::Invalid;
+                    }
+                };
+                if subs.next().is_some_and(|s| s.kind() != SyntaxKind::Comma) {
+                    ctx.diag.push_error(
+                        "'from' angle expression must be followed by a comma".into(),
+                        &node,
+                    );
+                    return Expression::Invalid;
+                }
+                Expression::from_expression_node(angle_expr.clone(), ctx).maybe_convert_to(
+                    Type::Angle,
+                    &angle_expr,
+                    ctx.diag,
+                )
+            } else {
+                Expression::NumberLiteral(0., Unit::Deg)
+            };
+            GradKind::Conic { from_angle: Box::new(from_angle) }
+        } else if grad_text.starts_with("four") {
+            return Self::from_four_corner_gradient(node, subs, ctx);
         } else {
-            // Parser should have ensured we have one of the linear or radial gradient
+            // Parser should have ensured we have one of the linear, radial, conic, or four-corner gradient
             panic!("Not a gradient {grad_text:?}");
         };
 
@@ -542,7 +577,19 @@ enum Stop {
                         break;
                     }
                     Stop::Color(col) => {
-                        stops.push((col, e.maybe_convert_to(Type::Float32, &n, ctx.diag)))
+                        let position = if matches!(grad_kind, GradKind::Conic { .. })
+                            && e.ty() == Type::Angle
+                        {
+                            // The positions of a conic gradient are stored as a fraction of a turn
+                            Expression::BinaryExpression {
+                                lhs: Box::new(e),
+                                rhs: Box::new(Expression::NumberLiteral(360., Unit::Deg)),
+                                op: '/',
+                            }
+                        } else {
+                            e.maybe_convert_to(Type::Float32, &n, ctx.diag)
+                        };
+                        stops.push((col, position))
                     }
                 }
             }
@@ -602,7 +649,47 @@ enum Stop {
         match grad_kind {
             GradKind::Linear { angle } => Expression::LinearGradient { angle, stops },
             GradKind::Radial => Expression::RadialGradient { stops },
+            GradKind::Conic { from_angle } => Expression::ConicGradient { from_angle, stops },
+        }
+    }
+
+    fn from_four_corner_gradient(
+        node: syntax_nodes::AtGradient,
+        subs: impl Iterator<Item = NodeOrToken>,
+        ctx: &mut LookupCtx,
+    ) -> Expression {
+        let mut colors = vec![];
+        let mut expect_comma = false;
+        for n in subs {
+            if n.kind() == SyntaxKind::Comma {
+                if !expect_comma {
+                    ctx.diag.push_error("Expected expression".into(), &n);
+                    return Expression::Invalid;
+                }
+                expect_comma = false;
+            } else if expect_comma {
+                ctx.diag.push_error(
+                    "Expected comma: the colors of a @four-corner-gradient don't have a position"
+                        .into(),
+                    &n,
+                );
+                return Expression::Invalid;
+            } else {
+                let old_property_type = std::mem::replace(&mut ctx.property_type, Type::Color);
+                let e = Expression::from_expression_node(n.as_node().unwrap().clone().into(), ctx);
+                ctx.property_type = old_property_type;
+                colors.push(e.maybe_convert_to(Type::Color, &n, ctx.diag));
+                expect_comma = true;
+            }
         }
+        if colors.len() != 4 {
+            ctx.diag.push_error(
+                "@four-corner-gradient expects four colors: top-left, top-right, bottom-right, and bottom-left".into(),
+                &node,
+            );
+            return Expression::Invalid;
+        }
+        Expression::FourCornerGradient { colors }
     }
 
     fn from_at_tr(node: syntax_nodes::AtTr, ctx: &mut LookupCtx) -> Expression {
@@ -805,7 +892,13 @@ fn from_qualified_name_node(
                 {
                     if let Some(e) = e {
                         if e.lookup(ctx, &first_str).is_some() {
-                            ctx.diag.push_error(format!("Unknown unqualified identifier '{0}'. Did you mean '{prefix}.{0}'?", first.text()), &node);
+                            let replacement = format!("{prefix}.{}", first.text());
+                            ctx.diag.push_diagnostic_with_fix_its(
+                                format!("Unknown unqualified identifier '{0}'. Did you mean '{replacement}'?", first.text()),
+                                &node,
+                                DiagnosticLevel::Error,
+                                vec![FixIt::replace(format!("Replace with '{replacement}'"), first.text_range(), replacement)],
+                            );
                             return Expression::Invalid;
                         }
                     }
@@ -1245,7 +1338,7 @@ fn from_object_literal_node(
         node: syntax_nodes::ObjectLiteral,
         ctx: &mut LookupCtx,
     ) -> Expression {
-        let values: HashMap<SmolStr, Expression> = node
+        let values: BTreeMap<SmolStr, Expression> = node
             .ObjectMember()
             .map(|n| {
                 (
@@ -1434,6 +1527,20 @@ fn continue_lookup_within_element(
     let lookup_result = elem.borrow().lookup_property(&prop_name);
     let local_to_component = lookup_result.is_local_to_component && ctx.is_local_element(elem);
 
+    if lookup_result.property_type == Type::Invalid {
+        if let Some(f) = secondary_window_member_function(elem, &prop_name) {
+            if let Some(x) = it.next() {
+                ctx.diag.push_error("Cannot access fields of a function".into(), &x)
+            }
+            return Expression::MemberFunction {
+                base: Box::new(Expression::ElementReference(Rc::downgrade(elem))),
+                base_node: Some(NodeOrToken::Node(node.into())),
+                member: Expression::BuiltinFunctionReference(f, Some(second.to_source_location()))
+                    .into(),
+            };
+        }
+    }
+
     if lookup_result.property_type.is_property_type() {
         if !local_to_component && lookup_result.property_visibility == PropertyVisibility::Private {
             ctx.diag.push_error(format!("The property '{}' is private. Annotate it with 'in', 'out' or 'in-out' to make it accessible from other components", second.text()), &second);
@@ -1442,7 +1549,7 @@ fn continue_lookup_within_element(
             ctx.diag.push_error(format!("This special property can only be used to make a binding and cannot be accessed"), &second);
             return Expression::Invalid;
         } else if lookup_result.resolved_name != prop_name.as_str() {
-            ctx.diag.push_property_deprecation_warning(
+            ctx.diag.push_property_deprecation_warning_for_token(
                 &prop_name,
                 &lookup_result.resolved_name,
                 &second,
@@ -1537,6 +1644,22 @@ fn continue_lookup_within_element(
     }
 }
 
+/// A `Window` declared inside another component (a secondary window) has the `show()` and `close()` functions.
+/// They are not declared in the `Window` builtin, so that they don't conflict with the properties of the root.
+fn secondary_window_member_function(elem: &ElementRc, name: &str) -> Option<BuiltinFunction> {
+    let function = match name {
+        "show" => BuiltinFunction::ShowPopupWindow,
+        "close" => BuiltinFunction::ClosePopupWindow,
+        _ => return None,
+    };
+    let e = elem.borrow();
+    if !e.builtin_type().is_some_and(|b| b.name == "Window") {
+        return None;
+    }
+    let component = e.enclosing_component.upgrade()?;
+    (!Rc::ptr_eq(&component.root_element, elem)).then_some(function)
+}
+
 fn maybe_lookup_object(
     mut base: Expression,
     it: impl Iterator<Item = crate::parser::SyntaxToken>,
@@ -0,0 +1,297 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This pass replaces the `Image` elements that show a simple SVG file with a `Path` element,
+//! so that the icon is drawn from native path commands instead of being decoded at run-time.
+//!
+//! Only the SVGs that are made of filled paths of a single solid color are lowered. The `Image`
+//! must have a `width` and a `height`, and must not use other image specific properties than
+//! `source` and `colorize`. The other images are left as is.
+
+use crate::expression_tree::{BindingExpression, Expression, ImageReference, Unit};
+use crate::langtype::{ElementType, Type};
+use crate::object_tree::*;
+use resvg::usvg;
+use smol_str::SmolStr;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::rc::Rc;
+
+/// The content of a SVG file, as path commands
+struct SvgPath {
+    commands: String,
+    size: (f32, f32),
+    /// The color as ARGB
+    color: u32,
+    even_odd: bool,
+}
+
+pub fn svg_to_path(component: &Rc<Component>, tr: &crate::typeregister::TypeRegister) {
+    let path_type = tr.lookup_element("Path").unwrap();
+    let image_type = tr.lookup_element("Image").unwrap();
+    let (ElementType::Builtin(path_builtin), ElementType::Builtin(image_builtin)) =
+        (&path_type, &image_type)
+    else {
+        return;
+    };
+    let image_properties = image_builtin
+        .properties
+        .keys()
+        .filter(|k| {
+            !path_builtin.properties.contains_key(*k)
+                && !crate::typeregister::reserved_property(k).is_valid()
+        })
+        .cloned()
+        .collect::<HashSet<SmolStr>>();
+
+    let mut candidates = Vec::new();
+    recurse_elem(&component.root_element, &(), &mut |elem, _| {
+        // The properties of the root element can be accessed from other components
+        if Rc::ptr_eq(elem, &component.root_element) {
+            return;
+        }
+        let e = elem.borrow();
+        if !matches!(&e.base_type, ElementType::Builtin(b) if b.name == "Image")
+            || !e.bindings.contains_key("width")
+            || !e.bindings.contains_key("height")
+            || e.bindings.keys().any(|k| {
+                k.starts_with("rotation-")
+                    || (image_properties.contains(k) && k != "source" && k != "colorize")
+            })
+        {
+            return;
+        }
+        let Some(source) = e.bindings.get("source") else { return };
+        let source = source.borrow();
+        let Expression::ImageReference {
+            resource_ref: ImageReference::AbsolutePath(path),
+            nine_slice: None,
+            ..
+        } = &source.expression
+        else {
+            return;
+        };
+        let extension = std::path::Path::new(path.as_str()).extension();
+        if extension.is_some_and(|ext| ext.eq_ignore_ascii_case("svg")) {
+            candidates.push((elem.clone(), path.clone()));
+        }
+    });
+    if candidates.is_empty() {
+        return;
+    }
+
+    // Path doesn't have the image properties, so they must not be read from anywhere
+    visit_all_named_references(component, &mut |nr| {
+        if image_properties.contains(nr.name()) {
+            let element = nr.element();
+            candidates.retain(|(elem, _)| !Rc::ptr_eq(elem, &element));
+        }
+    });
+
+    for (elem, path) in candidates {
+        let Some(svg) = load_svg(&path) else { continue };
+        let mut elem = elem.borrow_mut();
+        let span = elem.bindings.remove("source").and_then(|b| b.into_inner().span);
+        let binding = |expression: Expression| match &span {
+            Some(span) => BindingExpression::new_with_span(expression, span.clone()),
+            None => BindingExpression::from(expression),
+        };
+        elem.base_type = path_type.clone();
+        let fill = elem.bindings.remove("colorize").unwrap_or_else(|| {
+            RefCell::new(binding(Expression::Cast {
+                from: Box::new(Expression::Cast {
+                    from: Box::new(Expression::NumberLiteral(svg.color as _, Unit::None)),
+                    to: Type::Color,
+                }),
+                to: Type::Brush,
+            }))
+        });
+        elem.bindings.insert("fill".into(), fill);
+        if svg.even_odd {
+            let fill_rule = crate::typeregister::BUILTIN.with(|e| e.enums.FillRule.clone());
+            let value = fill_rule.try_value_from_string("evenodd").unwrap();
+            elem.bindings
+                .insert("fill-rule".into(), binding(Expression::EnumerationValue(value)).into());
+        }
+        for (name, value) in [
+            ("viewbox-x", 0.),
+            ("viewbox-y", 0.),
+            ("viewbox-width", svg.size.0),
+            ("viewbox-height", svg.size.1),
+        ] {
+            let value = Expression::NumberLiteral(value as _, Unit::None);
+            elem.bindings.insert(name.into(), binding(value).into());
+        }
+        let commands = Expression::StringLiteral(svg.commands.into());
+        elem.bindings.insert("commands".into(), binding(commands).into());
+    }
+}
+
+/// Returns None if the file can't be loaded or if it is not simple enough to be a Path
+fn load_svg(path: &str) -> Option<SvgPath> {
+    let file = crate::fileaccess::load_file(std::path::Path::new(path))?;
+    let data = match file.builtin_contents {
+        Some(data) => data.to_vec(),
+        None => std::fs::read(&file.canon_path).ok()?,
+    };
+    let tree = usvg::Tree::from_data(&data, &usvg::Options::default()).ok()?;
+
+    let mut commands = String::new();
+    let mut fill = None;
+    collect_paths(tree.root(), &mut commands, &mut fill)?;
+    let (color, opacity, rule) = fill?;
+    let alpha = (opacity * 255.).round() as u32;
+    Some(SvgPath {
+        commands,
+        size: (tree.size().width(), tree.size().height()),
+        color: alpha << 24
+            | (color.red as u32) << 16
+            | (color.green as u32) << 8
+            | color.blue as u32,
+        even_odd: rule == usvg::FillRule::EvenOdd,
+    })
+}
+
+fn collect_paths(
+    group: &usvg::Group,
+    commands: &mut String,
+    fill: &mut Option<(usvg::Color, f32, usvg::FillRule)>,
+) -> Option<()> {
+    if group.opacity().get() < 1.
+        || group.blend_mode() != usvg::BlendMode::Normal
+        || group.clip_path().is_some()
+        || group.mask().is_some()
+        || !group.filters().is_empty()
+    {
+        return None;
+    }
+    // Round to avoid long numbers in the generated code
+    let n = |v: f32| (v * 1000.).round() / 1000.;
+    for node in group.children() {
+        match node {
+            usvg::Node::Group(group) => collect_paths(group, commands, fill)?,
+            usvg::Node::Path(path) => {
+                if !path.is_visible() {
+                    continue;
+                }
+                if path.stroke().is_some() {
+                    return None;
+                }
+                let Some(path_fill) = path.fill() else { continue };
+                let usvg::Paint::Color(color) = path_fill.paint() else { return None };
+                let path_fill = (*color, path_fill.opacity().get(), path_fill.rule());
+                if *fill.get_or_insert(path_fill) != path_fill {
+                    return None;
+                }
+                let data = path.data().clone().transform(path.abs_transform())?;
+                for segment in data.segments() {
+                    use usvg::tiny_skia_path::PathSegment;
+                    match segment {
+                        PathSegment::MoveTo(p) => write!(commands, "M {} {} ", n(p.x), n(p.y)),
+                        PathSegment::LineTo(p) => write!(commands, "L {} {} ", n(p.x), n(p.y)),
+                        PathSegment::QuadTo(c, p) => {
+                            write!(commands, "Q {} {} {} {} ", n(c.x), n(c.y), n(p.x), n(p.y))
+                        }
+                        PathSegment::CubicTo(c1, c2, p) => write!(
+                            commands,
+                            "C {} {} {} {} {} {} ",
+                            n(c1.x),
+                            n(c1.y),
+                            n(c2.x),
+                            n(c2.y),
+                            n(p.x),
+                            n(p.y)
+                        ),
+                        PathSegment::Close => write!(commands, "Z "),
+                    }
+                    .unwrap();
+                }
+            }
+            _ => return None,
+        }
+    }
+    Some(())
+}
+
+#[test]
+fn svg_to_path_lowering() {
+    let mut compiler_config =
+        crate::CompilerConfiguration::new(crate::generator::OutputFormat::Interpreter);
+    compiler_config.style = Some("fluent".into());
+    compiler_config.svg_to_path = true;
+    let mut diag = crate::diagnostics::BuildDiagnostics::default();
+    let node = crate::parser::parse(
+        r#"
+        export component Main inherits Window {
+            simple := Image {
+                source: @image-url("builtin:/fluent/_arrow-up.svg");
+                width: 24px;
+                height: 24px;
+            }
+            colorized := Image {
+                source: @image-url("builtin:/fluent/_arrow-up.svg");
+                width: 24px;
+                height: 24px;
+                colorize: red;
+            }
+            // Not lowered: no explicit size
+            implicit-size := Image {
+                source: @image-url("builtin:/fluent/_arrow-up.svg");
+            }
+            // Not lowered: uses image-fit
+            fit := Image {
+                source: @image-url("builtin:/fluent/_arrow-up.svg");
+                width: 24px;
+                height: 24px;
+                image-fit: cover;
+            }
+            // Not lowered: the source is read
+            read := Image {
+                source: @image-url("builtin:/fluent/_arrow-up.svg");
+                width: 24px;
+                height: 24px;
+            }
+            out property <int> source-width: read.source.width;
+        }
+        "#
+        .into(),
+        Some(std::path::Path::new("main.slint")),
+        &mut diag,
+    );
+    let (doc, diag, _) = spin_on::spin_on(crate::compile_syntax_node(node, diag, compiler_config));
+    assert!(!diag.has_errors(), "{:?}", diag.to_string_vec());
+
+    let root = doc.exported_roots().next().unwrap().root_element.clone();
+    let element = |id: &str| {
+        let mut found = None;
+        recurse_elem(&root, &(), &mut |e, _| {
+            // The unique_id pass adds a suffix to the ids
+            if e.borrow().id.strip_prefix(id).is_some_and(|s| s.starts_with('-')) {
+                found = Some(e.clone());
+            }
+        });
+        found.unwrap_or_else(|| panic!("{id} not found"))
+    };
+    let is_path = |id: &str| element(id).borrow().base_type.to_string() == "Path";
+    assert!(is_path("simple"));
+    assert!(is_path("colorized"));
+    assert!(!is_path("implicit-size"));
+    assert!(!is_path("fit"));
+    assert!(!is_path("read"));
+
+    let simple = element("simple");
+    let simple = simple.borrow();
+    assert!(simple.bindings.contains_key("elements"));
+    assert!(!simple.bindings.contains_key("source"));
+    assert!(matches!(
+        simple.bindings["viewbox-width"].borrow().expression,
+        Expression::NumberLiteral(w, _) if w == 12.
+    ));
+    assert!(matches!(
+        &simple.bindings["fill"].borrow().expression,
+        Expression::Cast { from, to: Type::Brush }
+            if matches!(**from, Expression::Cast { ref from, .. }
+                if matches!(**from, Expression::NumberLiteral(c, _) if c == 0xffffffff_u32 as f64))
+    ));
+}
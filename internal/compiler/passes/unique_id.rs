@@ -10,43 +10,55 @@
 
 /// This pass make sure that the id of the elements are unique
 ///
-/// It currently does so by adding a number to the existing id
+/// It currently does so by adding a number to the existing id.
+/// The numbering restarts for each component, so that changing a component doesn't change the
+/// ids, and therefore the generated code, of the other components. The components that have the
+/// same name as a previous one also get their occurrence number in the ids of their elements.
 pub fn assign_unique_id(doc: &Document) {
-    let mut count = 0;
+    let mut seen_names = HashMap::<SmolStr, u32>::new();
     doc.visit_all_used_components(|component| {
         if !component.is_global() {
-            assign_unique_id_in_component(component, &mut count)
+            let occurrence = seen_names.entry(component.id.clone()).or_default();
+            let prefix = match *occurrence {
+                0 => SmolStr::default(),
+                n => format_smolstr!("{n}-"),
+            };
+            *occurrence += 1;
+            assign_unique_id_in_component(component, &prefix)
         }
     });
-    rename_globals(doc, count);
+    rename_globals(doc);
 }
 
-fn assign_unique_id_in_component(component: &Rc<Component>, count: &mut u32) {
+fn assign_unique_id_in_component(component: &Rc<Component>, prefix: &str) {
+    let mut count = 0;
     recurse_elem_including_sub_components(component, &(), &mut |elem, _| {
-        *count += 1;
+        count += 1;
         let mut elem_mut = elem.borrow_mut();
         let old_id = if !elem_mut.id.is_empty() {
             elem_mut.id.clone()
         } else {
             elem_mut.base_type.to_smolstr().to_ascii_lowercase().into()
         };
-        elem_mut.id = format_smolstr!("{}-{}", old_id, count);
+        elem_mut.id = format_smolstr!("{}-{}{}", old_id, prefix, count);
 
         let enclosing = elem_mut.enclosing_component.upgrade().unwrap();
         if Rc::ptr_eq(&elem, &enclosing.root_element) {
             for o in enclosing.optimized_elements.borrow().iter() {
-                *count += 1;
+                count += 1;
                 let mut elem_mut = o.borrow_mut();
-                elem_mut.id = format_smolstr!("optimized-{}-{}", elem_mut.id, count);
+                elem_mut.id = format_smolstr!("optimized-{}-{}{}", elem_mut.id, prefix, count);
             }
         }
     });
 }
 
 /// Give globals unique name
-fn rename_globals(doc: &Document, mut count: u32) {
+fn rename_globals(doc: &Document) {
+    let mut seen_names = HashMap::<SmolStr, u32>::new();
     for g in &doc.used_types.borrow().globals {
-        count += 1;
+        let occurrence = seen_names.entry(g.id.clone()).or_default();
+        *occurrence += 1;
         let mut root = g.root_element.borrow_mut();
         if matches!(&root.base_type, ElementType::Builtin(_)) {
             // builtin global keeps its name
@@ -54,7 +66,8 @@ fn rename_globals(doc: &Document, mut count: u32) {
         } else if let Some(s) = g.exported_global_names.borrow().first() {
             root.id = s.to_smolstr();
         } else {
-            root.id = format_smolstr!("{}-{}", g.id, count);
+            // Not just a number, so that it can't be the same as the id of an element
+            root.id = format_smolstr!("{}-global-{}", g.id, occurrence);
         }
     }
 }
@@ -19,6 +19,12 @@ pub fn is_url(path: &Path) -> bool {
     to_url(path).is_some()
 }
 
+/// Check whether the path is an `asset://` URL, which is only resolved at run-time by the platform
+/// (from the assets of the APK on Android), so that the file can't be embedded.
+pub fn is_asset_url(path: &str) -> bool {
+    path.starts_with("asset://")
+}
+
 /// Convert a `Path` to an `url::Url` if possible
 fn to_url(path: &str) -> Option<url::Url> {
     let Ok(url) = url::Url::parse(path) else {
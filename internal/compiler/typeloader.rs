@@ -8,6 +8,7 @@
 use std::rc::{Rc, Weak};
 
 use crate::diagnostics::{BuildDiagnostics, Spanned};
+use crate::namedreference::NamedReferenceInner;
 use crate::object_tree::{self, Document, ExportedName, Exports};
 use crate::parser::{syntax_nodes, NodeOrToken, SyntaxKind, SyntaxToken};
 use crate::typeregister::TypeRegister;
@@ -37,6 +38,12 @@ struct LoadedDocuments {
     dependencies: HashMap<PathBuf, HashSet<PathBuf>>,
 }
 
+/// The documents loaded by a previous compilation, resolved and type checked but before the passes
+/// that lower them, so that the next compilation only loads the files that changed and the files
+/// that depend on them. See [`crate::load_root_file_incremental`].
+#[derive(Default)]
+pub struct IncrementalCache(pub(crate) Option<TypeLoader>);
+
 #[derive(Debug, Clone)]
 pub enum ImportKind {
     /// `import {Foo, Bar} from "foo"`
@@ -97,6 +104,7 @@ pub fn snapshot(type_loader: &TypeLoader) -> Option<TypeLoader> {
         component_map: HashMap::new(),
         element_map: HashMap::new(),
         type_register_map: HashMap::new(),
+        named_reference_map: HashMap::new(),
         keep_alive: Vec::new(),
         keep_alive_elements: Vec::new(),
     };
@@ -123,6 +131,7 @@ pub(crate) fn snapshot_with_extra_doc(
         component_map: HashMap::new(),
         element_map: HashMap::new(),
         type_register_map: HashMap::new(),
+        named_reference_map: HashMap::new(),
         keep_alive: Vec::new(),
         keep_alive_elements: Vec::new(),
     };
@@ -136,7 +145,7 @@ pub(crate) fn snapshot_with_extra_doc(
     if let Some(doc_node) = &new_doc.node {
         let path = doc_node.source_file.path().to_path_buf();
         if let Some(r) = &mut result {
-            r.all_documents.docs.insert(path, LoadedDocument::Document(new_doc));
+            r.insert_document(path, new_doc);
         }
     }
 
@@ -150,6 +159,10 @@ pub(crate) struct Snapshotter {
         HashMap<by_address::ByAddress<object_tree::ElementRc>, Weak<RefCell<object_tree::Element>>>,
     type_register_map:
         HashMap<by_address::ByAddress<Rc<RefCell<TypeRegister>>>, Rc<RefCell<TypeRegister>>>,
+    /// The passes rely on the references to the same property sharing their inner value, for
+    /// example to redirect all of them to the aliased property
+    named_reference_map:
+        HashMap<by_address::ByAddress<Rc<NamedReferenceInner>>, Rc<NamedReferenceInner>>,
 
     keep_alive: Vec<(Rc<object_tree::Component>, Rc<object_tree::Component>)>,
     keep_alive_elements: Vec<(object_tree::ElementRc, object_tree::ElementRc)>,
@@ -253,7 +266,7 @@ fn snapshot_loaded_documents(
                 })
                 .collect(),
             currently_loading: Default::default(),
-            dependencies: Default::default(),
+            dependencies: loaded_documents.dependencies.clone(),
         })
     }
 
@@ -294,6 +307,19 @@ fn snapshot_document(&mut self, document: &object_tree::Document) -> object_tree
         }
     }
 
+    pub(crate) fn snapshot_named_reference(
+        &mut self,
+        named_reference: &Rc<NamedReferenceInner>,
+    ) -> Rc<NamedReferenceInner> {
+        let key = by_address::ByAddress(named_reference.clone());
+        if let Some(r) = self.named_reference_map.get(&key) {
+            return r.clone();
+        }
+        let r = Rc::new(named_reference.snapshot(self));
+        self.named_reference_map.insert(key, r.clone());
+        r
+    }
+
     pub(crate) fn create_component(
         &mut self,
         component: &Rc<object_tree::Component>,
@@ -466,7 +492,7 @@ fn snapshot_element(
             .iter()
             .map(|s| object_tree::State {
                 id: s.id.clone(),
-                condition: s.condition.clone(),
+                condition: s.condition.as_ref().map(|c| self.snapshot_expression(c)),
                 property_changes: s
                     .property_changes
                     .iter()
@@ -490,6 +516,7 @@ fn snapshot_element(
                     viewport_width: lv.viewport_width.snapshot(self),
                     listview_height: lv.listview_height.snapshot(self),
                     listview_width: lv.listview_width.snapshot(self),
+                    scroll_to_row: lv.scroll_to_row.as_ref().map(|nr| nr.snapshot(self)),
                 }),
             });
 
@@ -522,7 +549,14 @@ fn snapshot_element(
             elem.layout_info_prop.as_ref().map(|(n1, n2)| (n1.snapshot(self), n2.snapshot(self)));
         target_element.property_analysis = RefCell::new(elem.property_analysis.borrow().clone());
 
-        target_element.change_callbacks = elem.change_callbacks.clone();
+        target_element.change_callbacks = elem
+            .change_callbacks
+            .iter()
+            .map(|(k, v)| {
+                let callbacks = v.borrow().iter().map(|e| self.snapshot_expression(e)).collect();
+                (k.clone(), RefCell::new(callbacks))
+            })
+            .collect();
         target_element.child_of_layout = elem.child_of_layout;
         target_element.default_fill_parent = elem.default_fill_parent;
         target_element.has_popup_child = elem.has_popup_child;
@@ -628,6 +662,7 @@ fn snapshot_popup_window(
             y: popup_window.y.snapshot(self),
             close_policy: popup_window.close_policy.clone(),
             parent_element: self.use_element(&popup_window.parent_element),
+            is_window: popup_window.is_window,
         }
     }
 
@@ -689,21 +724,21 @@ fn snapshot_expression(
             },
             Expression::ElementReference(el) => {
                 Expression::ElementReference(if let Some(el) = el.upgrade() {
-                    Rc::downgrade(&el)
+                    Rc::downgrade(&self.use_element(&el))
                 } else {
                     Weak::default()
                 })
             }
             Expression::RepeaterIndexReference { element } => Expression::RepeaterIndexReference {
                 element: if let Some(el) = element.upgrade() {
-                    Rc::downgrade(&el)
+                    Rc::downgrade(&self.use_element(&el))
                 } else {
                     Weak::default()
                 },
             },
             Expression::RepeaterModelReference { element } => Expression::RepeaterModelReference {
                 element: if let Some(el) = element.upgrade() {
-                    Rc::downgrade(&el)
+                    Rc::downgrade(&self.use_element(&el))
                 } else {
                     Weak::default()
                 },
@@ -807,6 +842,16 @@ fn snapshot_expression(
                     .map(|(e1, e2)| (self.snapshot_expression(e1), self.snapshot_expression(e2)))
                     .collect(),
             },
+            Expression::ConicGradient { from_angle, stops } => Expression::ConicGradient {
+                from_angle: Box::new(self.snapshot_expression(from_angle)),
+                stops: stops
+                    .iter()
+                    .map(|(e1, e2)| (self.snapshot_expression(e1), self.snapshot_expression(e2)))
+                    .collect(),
+            },
+            Expression::FourCornerGradient { colors } => Expression::FourCornerGradient {
+                colors: colors.iter().map(|c| self.snapshot_expression(c)).collect(),
+            },
             Expression::ReturnStatement(expr) => Expression::ReturnStatement(
                 expr.as_ref().map(|e| Box::new(self.snapshot_expression(e))),
             ),
@@ -941,6 +986,46 @@ pub fn invalidate_document(&mut self, path: &Path) -> HashSet<PathBuf> {
         extra_deps
     }
 
+    /// Invalidates the documents whose file changed since they were loaded, and the documents that
+    /// depend on them, so that they are loaded again when they are imported. The documents whose
+    /// file didn't change keep their syntax tree and are type checked again.
+    ///
+    /// The files are read with the `open_import_fallback` or from the file system.
+    pub async fn invalidate_changed_documents(&mut self) {
+        let fallback = self.compiler_config.open_import_fallback.clone();
+        let implicit_globals =
+            self.compiler_config.implicit_globals.as_ref().map(|(p, _)| p.clone());
+        let mut changed = Vec::new();
+        for (path, doc) in &self.all_documents.docs {
+            // The builtin files never change, and the implicit globals are part of the configuration
+            if path.starts_with("builtin:/") || implicit_globals.as_ref() == Some(path) {
+                continue;
+            }
+            let doc_node = match doc {
+                LoadedDocument::Document(doc) => doc.node.as_ref(),
+                LoadedDocument::Invalidated(doc_node) => Some(doc_node),
+            };
+            let Some(old_source) = doc_node.and_then(|n| n.source_file.source()) else {
+                changed.push(path.clone());
+                continue;
+            };
+            let source = if let Some(fallback) = &fallback {
+                let result = fallback(path.to_string_lossy().into()).await;
+                result.unwrap_or_else(|| std::fs::read_to_string(path))
+            } else {
+                std::fs::read_to_string(path)
+            };
+            if source.ok().as_deref() != Some(old_source) {
+                changed.push(path.clone());
+            }
+        }
+        for path in changed {
+            self.invalidate_document(&path);
+            // Drop the syntax tree, so that the new content is read
+            self.drop_document(&path).ok();
+        }
+    }
+
     /// Imports of files that don't have the .slint extension are returned.
     pub async fn load_dependencies_recursively<'a>(
         &'a mut self,
@@ -1305,6 +1390,34 @@ pub async fn load_file(
         Self::load_file_impl(&state, path, doc_node, is_builtin, &Default::default()).await;
     }
 
+    /// Load the document from [`CompilerConfiguration::implicit_globals`], if any, and register
+    /// the globals and components it exports in the global type registry so that they can be
+    /// used without import.
+    pub async fn load_implicit_globals(&mut self, diag: &mut BuildDiagnostics) {
+        let Some((path, source_code)) = self.compiler_config.implicit_globals.clone() else {
+            return;
+        };
+        self.load_file(&path, &path, source_code, false, diag).await;
+        let Some(doc) = self.get_document(&path) else { return };
+        let exports = doc
+            .exports
+            .iter()
+            .filter_map(|(name, c)| Some((name.clone(), c.as_ref().left()?.clone())))
+            .collect::<Vec<_>>();
+        let mut registry = self.global_type_registry.borrow_mut();
+        for (name, c) in exports {
+            if matches!(registry.lookup_element(&name.name), Ok(langtype::ElementType::Builtin(_)))
+            {
+                diag.push_error(
+                    format!("'{}' is already the name of a builtin element", name.name),
+                    &name.name_ident,
+                );
+                continue;
+            }
+            registry.add_with_name(name.name, c);
+        }
+    }
+
     /// Reload a cached file
     ///
     /// The path must be canonical
@@ -1345,7 +1458,7 @@ pub async fn load_root_file(
         } else {
             None
         };
-        state.tl.all_documents.docs.insert(path.clone(), LoadedDocument::Document(doc));
+        state.tl.insert_document(path.clone(), doc);
         (path, raw_type_loader)
     }
 
@@ -1364,16 +1477,19 @@ async fn load_file_impl<'a>(
         if !state.diag.has_errors() {
             crate::passes::run_import_passes(&doc, state.tl, state.diag);
         }
+        state.tl.insert_document(path, doc);
+    }
+
+    /// Adds a loaded document and records that it depends on the files it imports.
+    fn insert_document(&mut self, path: PathBuf, doc: Document) {
         for dep in &doc.imports {
-            state
-                .tl
-                .all_documents
+            self.all_documents
                 .dependencies
                 .entry(Path::new(&dep.file).into())
                 .or_default()
                 .insert(path.clone());
         }
-        state.tl.all_documents.docs.insert(path, LoadedDocument::Document(doc));
+        self.all_documents.docs.insert(path, LoadedDocument::Document(doc));
     }
 
     async fn load_doc_no_pass<'a>(
@@ -1387,6 +1503,10 @@ async fn load_doc_no_pass<'a>(
             Rc::new(RefCell::new(TypeRegister::new(&state.borrow().tl.global_type_registry)));
         dependency_registry.borrow_mut().expose_internal_types =
             is_builtin || state.borrow().tl.compiler_config.enable_experimental;
+        if !is_builtin {
+            dependency_registry.borrow_mut().defines =
+                state.borrow().tl.compiler_config.defines.clone();
+        }
         let (imports, reexports) = Self::load_dependencies_recursively_impl(
             state,
             &dependency_doc,
@@ -84,6 +84,7 @@ pub struct BuiltinTypes {
     pub strarg_callback_type: Type,
     pub logical_point_type: Type,
     pub font_metrics_type: Type,
+    pub toast_entry_type: Type,
     pub layout_info_type: Type,
     pub path_element_type: Type,
     pub box_layout_cell_data_type: Type,
@@ -129,6 +130,17 @@ fn new() -> Self {
                 node: None,
                 rust_attributes: None,
             })),
+            toast_entry_type: Type::Struct(Rc::new(Struct {
+                fields: IntoIterator::into_iter([
+                    (SmolStr::new_static("id"), Type::Int32),
+                    (SmolStr::new_static("message"), Type::String),
+                    (SmolStr::new_static("action"), Type::String),
+                ])
+                .collect(),
+                name: Some("slint::private_api::ToastEntry".into()),
+                node: None,
+                rust_attributes: None,
+            })),
             noarg_callback_type: Type::Callback(Rc::new(Function {
                 return_type: Type::Void,
                 args: vec![],
@@ -333,6 +345,8 @@ pub struct TypeRegister {
     parent_registry: Option<Rc<RefCell<TypeRegister>>>,
     /// If the lookup function should return types that are marked as internal
     pub(crate) expose_internal_types: bool,
+    /// The defines used to evaluate the `@cfg(...)` conditions
+    pub(crate) defines: Vec<(String, Option<String>)>,
 }
 
 impl TypeRegister {
@@ -354,6 +368,7 @@ pub(crate) fn snapshot(&self, snapshotter: &mut typeloader::Snapshotter) -> Self
                 .as_ref()
                 .map(|tr| snapshotter.snapshot_type_register(tr)),
             expose_internal_types: self.expose_internal_types,
+            defines: self.defines.clone(),
         }
     }
 
@@ -408,8 +423,10 @@ macro_rules! map_type {
             ($pub_type:ident, f32) => { Type::Float32 };
             ($pub_type:ident, SharedString) => { Type::String };
             ($pub_type:ident, Image) => { Type::Image };
+            ($pub_type:ident, Color) => { Type::Color };
             ($pub_type:ident, Coord) => { Type::LogicalLength };
             ($pub_type:ident, KeyboardModifiers) => { $pub_type.clone() };
+            ($pub_type:ident, LogicalPosition) => { logical_point_type() };
             ($pub_type:ident, $_:ident) => {
                 BUILTIN.with(|e| Type::Enumeration(e.enums.$pub_type.clone()))
             };
@@ -498,6 +515,7 @@ struct $Name:ident {
         let font_metrics_prop = crate::langtype::BuiltinPropertyInfo {
             ty: font_metrics_type(),
             property_visibility: PropertyVisibility::Output,
+            declared_pure: None,
             default_value: BuiltinPropertyDefault::Fn(|elem| {
                 crate::expression_tree::Expression::FunctionCall {
                     function: Box::new(
@@ -584,6 +602,7 @@ pub fn new(parent: &Rc<RefCell<TypeRegister>>) -> Self {
         Self {
             parent_registry: Some(parent.clone()),
             expose_internal_types: parent.borrow().expose_internal_types,
+            defines: parent.borrow().defines.clone(),
             ..Default::default()
         }
     }
@@ -716,6 +735,11 @@ pub fn font_metrics_type() -> Type {
     BUILTIN.with(|types| types.font_metrics_type.clone())
 }
 
+/// The [`Type`] for a runtime ToastEntry structure
+pub fn toast_entry_type() -> Type {
+    BUILTIN.with(|types| types.toast_entry_type.clone())
+}
+
 /// The [`Type`] for a runtime LayoutInfo structure
 pub fn layout_info_type() -> Type {
     BUILTIN.with(|types| types.layout_info_type.clone())
@@ -216,7 +216,7 @@ pub fn now() -> Self {
         Self(Self::duration_since_start().as_millis() as u64)
     }
 
-    fn duration_since_start() -> core::time::Duration {
+    pub(crate) fn duration_since_start() -> core::time::Duration {
         crate::context::GLOBAL_CONTEXT
             .with(|p| p.get().map(|p| p.platform().duration_since_start()))
             .unwrap_or_default()
@@ -7,16 +7,25 @@
 
 #![warn(missing_docs)]
 
+pub use crate::clipboard::SystemClipboard;
 #[cfg(target_has_atomic = "ptr")]
 pub use crate::future::*;
+pub use crate::global_shortcut::{GlobalShortcut, GlobalShortcutError, KeyCombination};
 use crate::graphics::{Rgba8Pixel, SharedPixelBuffer};
-use crate::input::{KeyEventType, MouseEvent};
+use crate::input::{KeyEventType, MouseEvent, PointerEventButton};
 use crate::item_tree::ItemTreeVTable;
+pub use crate::notification::Notification;
+pub use crate::screen::{Screen, VideoMode};
+pub use crate::tray_icon::TrayIcon;
+pub use crate::window::toast::{Toast, ToastOptions};
+pub use crate::window::window_manager::{WindowId, WindowManager};
 use crate::window::{WindowAdapter, WindowInner};
 #[cfg(not(feature = "std"))]
 use alloc::boxed::Box;
 #[cfg(not(feature = "std"))]
 use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 
 /// A position represented in the coordinate space of logical pixels. That is the space before applying
 /// a display device specific scale factor.
@@ -346,6 +355,29 @@ fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 #[cfg(feature = "std")]
 impl std::error::Error for SetRenderingNotifierError {}
 
+/// This struct contains statistics about the rendering of a frame, as returned by
+/// [`Window::last_frame_statistics()`]. Use them to diagnose frames that take too long to render,
+/// for example by querying them when the callback set with [`Window::set_rendering_notifier()`] is
+/// invoked with [`RenderingState::AfterRendering`].
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct FrameStatistics {
+    /// The number of items that were drawn. Items outside of the redrawn region aren't counted.
+    pub items_rendered: usize,
+    /// The rectangles of the window that were redrawn, as position and size in physical pixels.
+    /// This is `None` if the renderer redrew the entire window.
+    pub dirty_region: Option<Vec<(PhysicalPosition, PhysicalSize)>>,
+    /// The number of layers that were created to cache the rendering of items. This is `None` if the
+    /// renderer doesn't use layers.
+    pub layers_created: Option<usize>,
+    /// The number of layers that were reused from the cache, without rendering their items again.
+    /// This is `None` if the renderer doesn't use layers.
+    pub layer_cache_hits: Option<usize>,
+    /// The time spent rendering the frame on the CPU. This doesn't include the time the GPU takes
+    /// to execute the rendering commands, as that happens asynchronously.
+    pub rendering_duration: core::time::Duration,
+}
+
 #[cfg(feature = "raw-window-handle-06")]
 #[derive(Clone)]
 enum WindowHandleInner {
@@ -412,6 +444,116 @@ pub enum CloseRequestResponse {
     KeepWindowShown = 1,
 }
 
+/// The effect that the windowing system applies to the content behind the transparent parts of a
+/// window, set with [`Window::set_backdrop()`].
+///
+/// The effect is only visible where the `background` of the `Window` element is transparent.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum WindowBackdrop {
+    /// No effect: the content behind the window is visible as is (default).
+    #[default]
+    None = 0,
+    /// The content behind the window is blurred. Supported on macOS, on Windows, and by some Wayland compositors.
+    Blur = 1,
+    /// The acrylic material of Windows 11. Falls back to [`WindowBackdrop::Blur`] on other platforms.
+    Acrylic = 2,
+    /// The mica material of Windows 11, which is tinted by the desktop wallpaper. Falls back to
+    /// [`WindowBackdrop::Blur`] on other platforms.
+    Mica = 3,
+}
+
+/// A part of a [`WindowRegion`], in logical coordinates relative to the top-left corner of the window.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum WindowRegionPart {
+    /// A rectangle
+    Rectangle {
+        /// The position of the top-left corner
+        position: LogicalPosition,
+        /// The size of the rectangle
+        size: LogicalSize,
+    },
+    /// The ellipse that fits in the rectangle
+    Ellipse {
+        /// The position of the top-left corner of the rectangle
+        position: LogicalPosition,
+        /// The size of the rectangle
+        size: LogicalSize,
+    },
+}
+
+impl WindowRegionPart {
+    fn contains(&self, point: LogicalPosition) -> bool {
+        match *self {
+            WindowRegionPart::Rectangle { position, size } => {
+                point.x >= position.x
+                    && point.y >= position.y
+                    && point.x < position.x + size.width
+                    && point.y < position.y + size.height
+            }
+            WindowRegionPart::Ellipse { position, size } => {
+                if size.width <= 0. || size.height <= 0. {
+                    return false;
+                }
+                let dx = (point.x - position.x) / size.width * 2. - 1.;
+                let dy = (point.y - position.y) / size.height * 2. - 1.;
+                dx * dx + dy * dy <= 1.
+            }
+        }
+    }
+}
+
+/// A region of a window, made of the union of rectangles and ellipses, to give a window a custom shape
+/// with [`Window::set_input_region()`].
+///
+/// # Example
+/// ```rust
+/// use slint::{LogicalPosition, LogicalSize, WindowRegion};
+/// // A round window with a title bar
+/// let region = WindowRegion::new()
+///     .with_rectangle(LogicalPosition::new(0., 0.), LogicalSize::new(200., 30.))
+///     .with_ellipse(LogicalPosition::new(0., 30.), LogicalSize::new(200., 200.));
+/// assert!(region.contains(LogicalPosition::new(100., 130.)));
+/// assert!(!region.contains(LogicalPosition::new(5., 225.)));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WindowRegion {
+    parts: Vec<WindowRegionPart>,
+}
+
+impl WindowRegion {
+    /// Creates an empty region.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rectangle to the region.
+    #[must_use]
+    pub fn with_rectangle(mut self, position: LogicalPosition, size: LogicalSize) -> Self {
+        self.parts.push(WindowRegionPart::Rectangle { position, size });
+        self
+    }
+
+    /// Adds the ellipse that fits in the rectangle at `position` of the given `size` to the region.
+    #[must_use]
+    pub fn with_ellipse(mut self, position: LogicalPosition, size: LogicalSize) -> Self {
+        self.parts.push(WindowRegionPart::Ellipse { position, size });
+        self
+    }
+
+    /// Returns the parts of the region.
+    pub fn parts(&self) -> &[WindowRegionPart] {
+        &self.parts
+    }
+
+    /// Returns true if the point, in logical coordinates relative to the window, is in the region.
+    pub fn contains(&self, point: LogicalPosition) -> bool {
+        self.parts.iter().any(|part| part.contains(point))
+    }
+}
+
 impl Window {
     /// Create a new window from a window adapter
     ///
@@ -474,6 +616,12 @@ pub fn set_rendering_notifier(
         self.0.window_adapter().renderer().set_rendering_notifier(Box::new(callback))
     }
 
+    /// Returns statistics about the last frame that was rendered in this window, or `None` if the window
+    /// wasn't rendered yet.
+    pub fn last_frame_statistics(&self) -> Option<FrameStatistics> {
+        self.0.last_frame_statistics()
+    }
+
     /// This function allows registering a callback that's invoked when the user tries to close a window.
     /// The callback has to return a [CloseRequestResponse].
     pub fn on_close_requested(&self, callback: impl FnMut() -> CloseRequestResponse + 'static) {
@@ -518,6 +666,36 @@ pub fn set_size(&self, size: impl Into<WindowSize>) {
         crate::window::WindowAdapter::set_size(&*self.0.window_adapter(), size);
     }
 
+    /// Returns the screen that contains the center of the window, or the primary screen
+    /// if the window isn't on any of the screens.
+    /// Returns `None` if the platform doesn't support the enumeration of the screens.
+    pub fn screen(&self) -> Option<Screen> {
+        let size = self.size();
+        let position = self.position();
+        let center = PhysicalPosition::new(
+            position.x + size.width as i32 / 2,
+            position.y + size.height as i32 / 2,
+        );
+        let mut screens = Screen::all();
+        match screens.iter().position(|s| s.contains(center)) {
+            Some(index) => Some(screens.swap_remove(index)),
+            None => Screen::primary(),
+        }
+    }
+
+    /// Moves the window to the specified screen, centered on it.
+    /// Note that on some windowing systems, such as Wayland, this functionality is not available.
+    pub fn move_to_screen(&self, screen: &Screen) {
+        self.set_position(screen.centered_position(self.size()));
+    }
+
+    /// Centers the window on the screen it's currently on. See [`Self::screen()`].
+    pub fn center_on_screen(&self) {
+        if let Some(screen) = self.screen() {
+            self.move_to_screen(&screen);
+        }
+    }
+
     /// Returns if the window is currently fullscreen
     pub fn is_fullscreen(&self) -> bool {
         self.0.is_fullscreen()
@@ -528,6 +706,22 @@ pub fn set_fullscreen(&self, fullscreen: bool) {
         self.0.set_fullscreen(fullscreen);
     }
 
+    /// Returns the video mode of the window, if it's in exclusive fullscreen.
+    pub fn exclusive_fullscreen(&self) -> Option<VideoMode> {
+        self.0.exclusive_fullscreen()
+    }
+
+    /// Shows the window in exclusive fullscreen on the screen of the video mode, switching the screen
+    /// to that resolution and refresh rate. Use one of the modes returned by [`Screen::video_modes()`].
+    /// If `mode` is `None`, or when calling [`Self::set_fullscreen()`] with `false`, the window leaves fullscreen.
+    ///
+    /// Contrary to [`Self::set_fullscreen()`], which shows a borderless window that covers the screen,
+    /// exclusive fullscreen is only supported by some platforms, such as Windows, macOS, and X11 with the
+    /// winit backend. Other platforms show the window in borderless fullscreen.
+    pub fn set_exclusive_fullscreen(&self, mode: Option<VideoMode>) {
+        self.0.set_exclusive_fullscreen(mode);
+    }
+
     /// Returns if the window is currently maximized
     pub fn is_maximized(&self) -> bool {
         self.0.is_maximized()
@@ -548,6 +742,105 @@ pub fn set_minimized(&self, minimized: bool) {
         self.0.set_minimized(minimized);
     }
 
+    /// Returns if the windowing system composites the window with the content behind it.
+    pub fn is_transparent(&self) -> bool {
+        self.0.is_transparent()
+    }
+
+    /// Sets whether the windowing system composites the window with the content behind it, so that
+    /// the transparent parts of the `background` of the `Window` element show what is behind the window.
+    ///
+    /// Windows are transparent by default. Making a window opaque can make its composition more efficient.
+    pub fn set_transparent(&self, transparent: bool) {
+        self.0.set_transparent(transparent);
+    }
+
+    /// Returns the effect applied to the content behind the window.
+    pub fn backdrop(&self) -> WindowBackdrop {
+        self.0.backdrop()
+    }
+
+    /// Sets the effect applied by the windowing system to the content behind the transparent parts
+    /// of the window, such as a blur. Platforms that don't support the effect ignore it.
+    pub fn set_backdrop(&self, backdrop: WindowBackdrop) {
+        self.0.set_backdrop(backdrop);
+    }
+
+    /// Returns the region of the window that receives pointer input, if one was set.
+    pub fn input_region(&self) -> Option<WindowRegion> {
+        self.0.input_region()
+    }
+
+    /// Restricts the window to a region, to make windows of any shape, for example for launchers or overlays.
+    /// `None` resets it to the whole window.
+    ///
+    /// Pointer events outside the region are never delivered to the elements of the window. When the
+    /// backend supports it, the windowing system also sends them to the windows below, and doesn't show
+    /// the parts of the window outside the region.
+    pub fn set_input_region(&self, region: Option<WindowRegion>) {
+        self.0.set_input_region(region);
+    }
+
+    /// Returns if the window is shown above the other windows.
+    pub fn is_always_on_top(&self) -> bool {
+        self.0.is_always_on_top()
+    }
+
+    /// Sets whether the window is shown above the other windows. This overrides the
+    /// `always-on-top` property of the `Window` element.
+    pub fn set_always_on_top(&self, always_on_top: bool) {
+        self.0.set_always_on_top(always_on_top);
+    }
+
+    /// Returns if the window is hidden from the task bar.
+    pub fn skips_taskbar(&self) -> bool {
+        self.0.skips_taskbar()
+    }
+
+    /// Sets whether the window is hidden from the task bar of the system.
+    /// This is only supported on Windows.
+    pub fn set_skip_taskbar(&self, skip_taskbar: bool) {
+        self.0.set_skip_taskbar(skip_taskbar);
+    }
+
+    /// Returns if the mouse pointer is confined to the window.
+    pub fn is_pointer_confined(&self) -> bool {
+        self.0.is_pointer_confined()
+    }
+
+    /// Sets whether the mouse pointer is confined to the window while it has the focus, so that it
+    /// can't reach other windows or the task bar. On macOS, the pointer is locked at its position instead.
+    pub fn set_pointer_confined(&self, confined: bool) {
+        self.0.set_pointer_confined(confined);
+    }
+
+    /// Returns if the window grabs the keyboard.
+    pub fn is_keyboard_grabbed(&self) -> bool {
+        self.0.is_keyboard_grabbed()
+    }
+
+    /// Sets whether the window grabs the keyboard, so that the key combinations usually handled by the
+    /// windowing system, such as switching between windows, are delivered to the window instead.
+    /// Whether the system permits it depends on the platform: the Qt backend supports it on X11.
+    pub fn set_keyboard_grab(&self, grab: bool) {
+        self.0.set_keyboard_grab(grab);
+    }
+
+    /// Returns if the window prevents the screensaver from starting.
+    pub fn inhibits_screensaver(&self) -> bool {
+        self.0.inhibits_screensaver()
+    }
+
+    /// Sets whether the screensaver and the automatic sleep of the display are prevented while the
+    /// window is shown, for example for media players or dashboards.
+    ///
+    /// The winit backend implements it on Windows, macOS, and on Linux with the inhibit portal of
+    /// the desktop, which forwards it to `org.freedesktop.ScreenSaver`. The Android backend keeps
+    /// the screen on with `FLAG_KEEP_SCREEN_ON`.
+    pub fn set_inhibit_screensaver(&self, inhibit: bool) {
+        self.0.set_inhibit_screensaver(inhibit);
+    }
+
     /// Dispatch a window event to the scene.
     ///
     /// Use this when you're implementing your own backend and want to forward user input events.
@@ -602,6 +895,81 @@ pub fn try_dispatch_event(
             crate::platform::WindowEvent::PointerExited => {
                 self.0.process_mouse_input(MouseEvent::Exit)
             }
+            crate::platform::WindowEvent::TouchPressed { id, position } => {
+                let events =
+                    self.0.touch_state.borrow_mut().pressed(id, position.to_euclid().cast());
+                self.0.process_touch_input(events);
+            }
+            crate::platform::WindowEvent::TouchMoved { id, position } => {
+                let events = self.0.touch_state.borrow_mut().moved(id, position.to_euclid().cast());
+                self.0.process_touch_input(events);
+            }
+            crate::platform::WindowEvent::TouchReleased { id, position } => {
+                let events =
+                    self.0.touch_state.borrow_mut().released(id, position.to_euclid().cast());
+                self.0.process_touch_input(events);
+            }
+            crate::platform::WindowEvent::PenPressed { position, state } => {
+                self.0.process_device_mouse_input(
+                    MouseEvent::Pressed {
+                        position: position.to_euclid().cast(),
+                        button: PointerEventButton::Left,
+                        click_count: 0,
+                    },
+                    state.device(),
+                    state,
+                );
+            }
+            crate::platform::WindowEvent::PenMoved { position, state } => {
+                self.0.process_device_mouse_input(
+                    MouseEvent::Moved { position: position.to_euclid().cast() },
+                    state.device(),
+                    state,
+                );
+            }
+            crate::platform::WindowEvent::PenReleased { position, state } => {
+                self.0.process_device_mouse_input(
+                    MouseEvent::Released {
+                        position: position.to_euclid().cast(),
+                        button: PointerEventButton::Left,
+                        click_count: 0,
+                    },
+                    state.device(),
+                    state,
+                );
+            }
+            crate::platform::WindowEvent::Gesture {
+                position,
+                scale,
+                rotation,
+                delta_x,
+                delta_y,
+                phase,
+            } => {
+                self.0.process_mouse_input(MouseEvent::Gesture {
+                    position: position.to_euclid().cast(),
+                    scale,
+                    rotation,
+                    delta_x: delta_x as _,
+                    delta_y: delta_y as _,
+                    phase,
+                });
+            }
+            crate::platform::WindowEvent::DragMoved { position, mime_type, data } => {
+                self.0.process_external_drag(
+                    MouseEvent::DragMove { position: position.to_euclid().cast() },
+                    Some(crate::items::DropEvent { mime_type, data, position }),
+                );
+            }
+            crate::platform::WindowEvent::DragExited => {
+                self.0.process_external_drag(MouseEvent::Exit, None);
+            }
+            crate::platform::WindowEvent::Dropped { position, mime_type, data } => {
+                self.0.process_external_drag(
+                    MouseEvent::Drop { position: position.to_euclid().cast() },
+                    Some(crate::items::DropEvent { mime_type, data, position }),
+                );
+            }
 
             crate::platform::WindowEvent::KeyPressed { text } => {
                 self.0.process_key_input(crate::input::KeyEvent {
@@ -633,6 +1001,20 @@ pub fn try_dispatch_event(
                 self.0.set_window_item_geometry(size.to_euclid());
                 self.0.window_adapter().renderer().resize(size.to_physical(self.scale_factor()))?;
             }
+            crate::platform::WindowEvent::SafeAreaInsetsChanged { top, bottom, left, right } => {
+                self.0.set_safe_area_insets(
+                    [top, bottom, left, right]
+                        .map(|v| crate::lengths::LogicalLength::new(v as crate::Coord)),
+                );
+            }
+            crate::platform::WindowEvent::VirtualKeyboardHeightChanged { height } => {
+                self.0.set_virtual_keyboard_height(crate::lengths::LogicalLength::new(
+                    height as crate::Coord,
+                ));
+            }
+            crate::platform::WindowEvent::DevicePostureChanged { posture } => {
+                self.0.set_device_posture(posture);
+            }
             crate::platform::WindowEvent::CloseRequested => {
                 if self.0.request_close() {
                     self.hide()?;
@@ -676,6 +1058,37 @@ pub fn window_handle(&self) -> WindowHandle {
         }
     }
 
+    /// Embeds the window in a native window of another toolkit, so that Slint views can be hosted in existing
+    /// applications: for example a `HWND` of a Win32 or MFC application, an `NSView` of a Cocoa application,
+    /// or an X11 window. This function is only accessible if you enable the `raw-window-handle-06` crate feature.
+    ///
+    /// Call this function before showing the window. The window is then shown without frame inside the parent,
+    /// and [`Self::set_position()`] is relative to the parent. The host application is responsible for resizing
+    /// the window with [`Self::set_size()`] when the parent is resized.
+    ///
+    /// Returns an error if the platform doesn't support embedding, or if the window is already shown.
+    /// It is implemented by the winit backend on Windows, macOS, and X11. Wayland doesn't let applications
+    /// embed windows of other applications, so an error is returned there, as well as for a handle of
+    /// another windowing system than the one used by Slint.
+    ///
+    /// # Safety
+    ///
+    /// The parent handle must be a valid window handle of the windowing system used by Slint, and stay
+    /// valid as long as the window is shown.
+    #[cfg(feature = "raw-window-handle-06")]
+    #[allow(unsafe_code)]
+    pub unsafe fn set_parent_window_handle(
+        &self,
+        parent: raw_window_handle_06::RawWindowHandle,
+    ) -> Result<(), PlatformError> {
+        match self.0.window_adapter().internal(crate::InternalToken) {
+            Some(internal) => internal.set_parent_window_handle_06(parent),
+            None => Err(PlatformError::Other(
+                "Embedding a window in a native window is not supported by this platform".into(),
+            )),
+        }
+    }
+
     /// Takes a snapshot of the window contents and returns it as RGBA8 encoded pixel buffer.
     ///
     /// Note that this function may be slow to call as it may need to re-render the scene.
@@ -1091,3 +1504,61 @@ pub fn set_xdg_app_id(app_id: impl Into<SharedString>) -> Result<(), PlatformErr
         |ctx| ctx.set_xdg_app_id(app_id.into()),
     )
 }
+
+#[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
+use crate::thread_local;
+
+thread_local! {
+    static SELECTED_STYLE: core::cell::RefCell<Option<SharedString>> = const { core::cell::RefCell::new(None) }
+}
+
+/// Selects the widget style of the components created after this call.
+///
+/// This only has an effect if the application's `.slint` files were compiled with several styles,
+/// with `slint_build::CompilerConfiguration::with_runtime_styles`. The style must be one of these styles,
+/// otherwise creating a component fails. By default, the first of these styles is used.
+pub fn set_style(style: impl Into<SharedString>) {
+    SELECTED_STYLE.with(|s| *s.borrow_mut() = Some(style.into()));
+}
+
+/// Returns the style selected with [`set_style()`], if any.
+pub fn selected_style() -> Option<SharedString> {
+    SELECTED_STYLE.with(|s| s.borrow().clone())
+}
+
+thread_local! {
+    static FONT_FALLBACK_FAMILIES: core::cell::RefCell<alloc::collections::BTreeMap<SharedString, Vec<SharedString>>> = Default::default()
+}
+
+/// Sets the font families that are used, in order, for the characters that the font family `family`
+/// doesn't have glyphs for. For example, a Latin font can fall back to a CJK font and then to an emoji font.
+/// An empty `family` sets the fallback chain for the default font, and for the families that don't
+/// have their own chain.
+///
+/// The fallback families are tried before the ones provided by the operating system.
+/// The software renderer doesn't fall back for individual characters, it uses the first family of the
+/// chain that is available if `family` isn't.
+///
+/// Call this before creating the components that show text; text that's already laid out isn't updated.
+///
+/// ```rust,no_run
+/// slint::set_font_fallback_families("", &["Noto Sans CJK JP", "Noto Color Emoji"]);
+/// ```
+pub fn set_font_fallback_families(family: &str, fallback_families: &[&str]) {
+    FONT_FALLBACK_FAMILIES.with(|chains| {
+        let mut chains = chains.borrow_mut();
+        if fallback_families.is_empty() {
+            chains.remove(family);
+        } else {
+            chains.insert(family.into(), fallback_families.iter().map(|f| (*f).into()).collect());
+        }
+    });
+}
+
+/// Returns the fallback chain set for `family` with [`set_font_fallback_families()`], or the one of the default font.
+pub(crate) fn font_fallback_families(family: &str) -> Vec<SharedString> {
+    FONT_FALLBACK_FAMILIES.with(|chains| {
+        let chains = chains.borrow();
+        chains.get(family).or_else(|| chains.get("")).cloned().unwrap_or_default()
+    })
+}
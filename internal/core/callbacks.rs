@@ -12,7 +12,7 @@
 
 #[cfg(not(feature = "std"))]
 use alloc::boxed::Box;
-use core::cell::Cell;
+use core::cell::{Cell, RefCell};
 
 /// A Callback that can be connected to a handler.
 ///
@@ -58,6 +58,46 @@ pub fn set_handler(&self, mut f: impl FnMut(&Arg) -> Ret + 'static) {
     }
 }
 
+/// Holds the handler that is set from Rust for an event of the platform integration, such as a change
+/// of the clipboard or the activation of a global shortcut.
+///
+/// Unlike [`Callback`], the handler can set another handler while it's invoked, which then replaces it.
+pub struct CallbackSlot<F: ?Sized>(RefCell<Option<Box<F>>>);
+
+impl<F: ?Sized> Default for CallbackSlot<F> {
+    fn default() -> Self {
+        Self(RefCell::new(None))
+    }
+}
+
+impl<F: ?Sized> CallbackSlot<F> {
+    /// Sets the handler, replacing the previous one.
+    pub fn set(&self, handler: Box<F>) {
+        *self.0.borrow_mut() = Some(handler);
+    }
+
+    /// Return whether a handler is set or not.
+    pub fn is_set(&self) -> bool {
+        self.0.borrow().is_some()
+    }
+
+    /// Calls `f` with the handler, and returns its result, or None if no handler is set.
+    ///
+    /// The handler is taken out of the slot while `f` runs, so that it can set another handler.
+    pub fn call<R>(&self, f: impl FnOnce(&mut F) -> R) -> Option<R> {
+        let mut handler = self.0.borrow_mut().take()?;
+        let result = f(&mut handler);
+        self.0.borrow_mut().get_or_insert(handler);
+        Some(result)
+    }
+}
+
+impl<F: ?Sized> core::fmt::Debug for CallbackSlot<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("CallbackSlot").field(&self.is_set()).finish()
+    }
+}
+
 #[test]
 fn callback_simple_test() {
     use std::rc::Rc;
@@ -73,6 +113,22 @@ struct Component {
     assert!(c.pressed.get());
 }
 
+#[test]
+fn callback_slot_test() {
+    use std::rc::Rc;
+    let slot = Rc::new(CallbackSlot::<dyn FnMut() -> i32>::default());
+    assert_eq!(slot.call(|f| f()), None);
+    let slot_ = slot.clone();
+    slot.set(Box::new(move || {
+        // Replace the handler while it runs
+        slot_.set(Box::new(|| 2));
+        1
+    }));
+    assert_eq!(slot.call(|f| f()), Some(1));
+    assert_eq!(slot.call(|f| f()), Some(2));
+    assert!(slot.is_set());
+}
+
 #[cfg(feature = "ffi")]
 pub(crate) mod ffi {
     #![allow(unsafe_code)]
@@ -0,0 +1,146 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Functions used by the chart widgets to turn a series of values into path commands.
+
+use crate::SharedString;
+use core::fmt::Write;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Returns the commands of a path that goes through all the values, distributed evenly over `width`.
+/// `minimum` is at the bottom (`height`), and `maximum` at the top (0).
+///
+/// When `area` is true, the path is closed through the bottom so it can be filled.
+pub fn line_chart_commands(
+    values: &[f32],
+    minimum: f32,
+    maximum: f32,
+    width: f32,
+    height: f32,
+    area: bool,
+) -> SharedString {
+    let mut commands = SharedString::default();
+    if values.is_empty() || width <= 0. || height <= 0. {
+        return commands;
+    }
+    let range = if maximum > minimum { maximum - minimum } else { 1. };
+    let step = if values.len() > 1 { width / (values.len() - 1) as f32 } else { 0. };
+    let y = |value: f32| {
+        height - (value.clamp(minimum, maximum.max(minimum)) - minimum) / range * height
+    };
+    for (i, value) in values.iter().enumerate() {
+        let command = if i == 0 { 'M' } else { 'L' };
+        write!(commands, "{command} {} {} ", i as f32 * step, y(*value)).unwrap();
+    }
+    if area {
+        write!(commands, "L {} {height} L 0 {height} Z", (values.len() - 1) as f32 * step).unwrap();
+    }
+    commands
+}
+
+fn slice_angles(values: &[f32], index: usize) -> Option<(f32, f32)> {
+    let total = values.iter().fold(0., |sum, v| sum + v.max(0.));
+    if total <= 0. || index >= values.len() {
+        return None;
+    }
+    let start = values[..index].iter().fold(0., |sum, v| sum + v.max(0.));
+    let end = start + values[index].max(0.);
+    Some((start / total * core::f32::consts::TAU, end / total * core::f32::consts::TAU))
+}
+
+/// Returns the commands of the path of the slice at `index` of a pie chart of the given values,
+/// in a circle of radius 1 centered at 0,0. The first slice starts at the top and they go clockwise.
+pub fn pie_slice_commands(values: &[f32], index: usize) -> SharedString {
+    let mut commands = SharedString::default();
+    let Some((start, end)) = slice_angles(values, index) else { return commands };
+    if end - start >= core::f32::consts::TAU - f32::EPSILON {
+        // A single arc can't be a full circle
+        commands.push_str("M 0 -1 A 1 1 0 1 1 0 1 A 1 1 0 1 1 0 -1 Z");
+    } else if end > start {
+        let large_arc = (end - start > core::f32::consts::PI) as i32;
+        write!(
+            commands,
+            "M 0 0 L {} {} A 1 1 0 {large_arc} 1 {} {} Z",
+            start.sin(),
+            -start.cos(),
+            end.sin(),
+            -end.cos()
+        )
+        .unwrap();
+    }
+    commands
+}
+
+/// Returns the index of the slice of a pie chart of the given values that is at the given angle
+/// (in degrees, clockwise from the top), or -1 if there is none.
+pub fn pie_slice_at(values: &[f32], angle: f32) -> i32 {
+    let angle = num_traits::Euclid::rem_euclid(&angle, &360.).to_radians();
+    (0..values.len())
+        .find(|i| {
+            slice_angles(values, *i).is_some_and(|(start, end)| angle >= start && angle < end)
+        })
+        .map_or(-1, |i| i as i32)
+}
+
+#[cfg(feature = "ffi")]
+mod ffi {
+    #![allow(unsafe_code)]
+
+    use super::*;
+    use crate::SharedVector;
+
+    #[no_mangle]
+    pub extern "C" fn slint_chart_line_commands(
+        values: &SharedVector<f32>,
+        minimum: f32,
+        maximum: f32,
+        width: f32,
+        height: f32,
+        area: bool,
+        out: &mut SharedString,
+    ) {
+        *out = line_chart_commands(values, minimum, maximum, width, height, area)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_chart_pie_slice_commands(
+        values: &SharedVector<f32>,
+        index: i32,
+        out: &mut SharedString,
+    ) {
+        *out = usize::try_from(index)
+            .map(|index| pie_slice_commands(values, index))
+            .unwrap_or_default()
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_chart_pie_slice_at(values: &SharedVector<f32>, angle: f32) -> i32 {
+        pie_slice_at(values, angle)
+    }
+}
+
+#[test]
+fn test_line_chart_commands() {
+    assert_eq!(line_chart_commands(&[], 0., 10., 100., 50., false), "");
+    assert_eq!(
+        line_chart_commands(&[0., 5., 10.], 0., 10., 100., 50., false),
+        "M 0 50 L 50 25 L 100 0 "
+    );
+    assert_eq!(
+        line_chart_commands(&[0., 20.], 0., 10., 100., 50., true),
+        "M 0 50 L 100 0 L 100 50 L 0 50 Z"
+    );
+}
+
+#[test]
+fn test_pie_slices() {
+    assert!(pie_slice_commands(&[1., 1.], 0).starts_with("M 0 0 L 0 -1 A 1 1 0 0 1 "));
+    assert_eq!(pie_slice_commands(&[3.], 0), "M 0 -1 A 1 1 0 1 1 0 1 A 1 1 0 1 1 0 -1 Z");
+    assert_eq!(pie_slice_commands(&[0., 0.], 1), "");
+    assert_eq!(pie_slice_at(&[1., 1., 2.], 10.), 0);
+    assert_eq!(pie_slice_at(&[1., 1., 2.], 100.), 1);
+    assert_eq!(pie_slice_at(&[1., 1., 2.], 270.), 2);
+    assert_eq!(pie_slice_at(&[1., 1., 2.], -10.), 2);
+    assert_eq!(pie_slice_at(&[], 10.), -1);
+}
@@ -0,0 +1,177 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Access to the content of the system clipboard
+
+#![warn(missing_docs)]
+
+use crate::callbacks::CallbackSlot;
+use crate::graphics::Image;
+use crate::platform::{Clipboard, Platform};
+use crate::SharedString;
+use alloc::boxed::Box;
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+use core::cell::RefCell;
+
+/// The system clipboard, to copy and paste text, images, and HTML between applications.
+///
+/// The same functions are available in `.slint` files in the `Clipboard` namespace, for example
+/// `Clipboard.set-text("Hello")` or `Clipboard.image()`.
+///
+/// The functions do nothing, or return `None`, if the platform doesn't support that kind of content.
+///
+/// # Example
+/// ```rust,no_run
+/// use slint::SystemClipboard;
+/// SystemClipboard::set_html("<b>Hello</b> World", "Hello World");
+/// SystemClipboard::on_changed(|| {
+///     if let Some(text) = SystemClipboard::text() {
+///         println!("The clipboard now contains {text}");
+///     }
+/// });
+/// ```
+pub struct SystemClipboard {
+    _private: (),
+}
+
+impl SystemClipboard {
+    /// Copies the text into the clipboard.
+    pub fn set_text(text: &str) {
+        with_platform(|platform| platform.set_clipboard_text(text, Clipboard::DefaultClipboard));
+        clipboard_changed(Clipboard::DefaultClipboard);
+    }
+
+    /// Returns the text in the clipboard, if any.
+    pub fn text() -> Option<SharedString> {
+        with_platform(|platform| platform.clipboard_text(Clipboard::DefaultClipboard))
+            .flatten()
+            .map(Into::into)
+    }
+
+    /// Copies the image into the clipboard. Does nothing if the pixels of the image can't be read,
+    /// for example if it is a texture.
+    pub fn set_image(image: &Image) {
+        let Some(pixels) = image.to_rgba8() else { return };
+        with_platform(|platform| {
+            platform.set_clipboard_image(&pixels, Clipboard::DefaultClipboard)
+        });
+        clipboard_changed(Clipboard::DefaultClipboard);
+    }
+
+    /// Returns the image in the clipboard, if any.
+    pub fn image() -> Option<Image> {
+        with_platform(|platform| platform.clipboard_image(Clipboard::DefaultClipboard))
+            .flatten()
+            .map(Image::from_rgba8)
+    }
+
+    /// Copies the HTML into the clipboard. `alt_text` is pasted by the applications that don't
+    /// support HTML.
+    pub fn set_html(html: &str, alt_text: &str) {
+        with_platform(|platform| {
+            platform.set_clipboard_html(html, alt_text, Clipboard::DefaultClipboard)
+        });
+        clipboard_changed(Clipboard::DefaultClipboard);
+    }
+
+    /// Returns the HTML in the clipboard, if any.
+    pub fn html() -> Option<SharedString> {
+        with_platform(|platform| platform.clipboard_html(Clipboard::DefaultClipboard))
+            .flatten()
+            .map(Into::into)
+    }
+
+    /// Sets a callback that is invoked when the content of the clipboard changes.
+    ///
+    /// It is invoked when the application changes the content of the clipboard, and when the
+    /// platform reports a change with [`clipboard_changed()`](crate::platform::clipboard_changed).
+    /// On platforms that don't report changes, the text of the clipboard is compared when a window
+    /// of the application becomes active, to detect changes done by other applications.
+    pub fn on_changed(callback: impl FnMut() + 'static) {
+        let text = Self::text();
+        crate::context::GLOBAL_CONTEXT.with(|ctx| {
+            if let Some(ctx) = ctx.get() {
+                ctx.0.clipboard.changed.set(Box::new(callback));
+                *ctx.0.clipboard.last_text.borrow_mut() = text.map(Into::into);
+            }
+        });
+    }
+}
+
+/// The state of the clipboard change notifications, stored in the [`SlintContext`](crate::SlintContext)
+#[derive(Default, Debug)]
+pub(crate) struct ClipboardState {
+    changed: CallbackSlot<dyn FnMut()>,
+    /// The text of the clipboard when it was last known, to detect the changes of the other applications
+    last_text: RefCell<Option<String>>,
+}
+
+fn with_platform<R>(f: impl FnOnce(&dyn Platform) -> R) -> Option<R> {
+    crate::context::GLOBAL_CONTEXT.with(|ctx| ctx.get().map(|ctx| f(ctx.platform())))
+}
+
+/// Call this function from the platform when the content of the clipboard changed, to invoke the
+/// callback set with [`SystemClipboard::on_changed()`].
+pub fn clipboard_changed(clipboard: Clipboard) {
+    if clipboard != Clipboard::DefaultClipboard {
+        return;
+    }
+    crate::context::GLOBAL_CONTEXT.with(|ctx| {
+        let Some(ctx) = ctx.get() else { return };
+        if !ctx.0.clipboard.changed.is_set() {
+            return;
+        }
+        let text = ctx.platform().clipboard_text(Clipboard::DefaultClipboard);
+        *ctx.0.clipboard.last_text.borrow_mut() = text;
+        ctx.0.clipboard.changed.call(|callback| callback());
+    });
+}
+
+/// Called when a window becomes active, to detect whether another application changed the text of the clipboard
+pub(crate) fn check_for_external_change(ctx: &crate::SlintContext) {
+    if !ctx.0.clipboard.changed.is_set() {
+        return;
+    }
+    let last_text = ctx.0.clipboard.last_text.borrow().clone();
+    if ctx.platform().clipboard_text(Clipboard::DefaultClipboard) != last_text {
+        clipboard_changed(Clipboard::DefaultClipboard);
+    }
+}
+
+#[cfg(feature = "ffi")]
+mod ffi {
+    #![allow(unsafe_code)]
+
+    use super::*;
+
+    #[no_mangle]
+    pub extern "C" fn slint_clipboard_set_text(text: &SharedString) {
+        SystemClipboard::set_text(text)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_clipboard_text(out: &mut SharedString) {
+        *out = SystemClipboard::text().unwrap_or_default()
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_clipboard_set_image(image: &Image) {
+        SystemClipboard::set_image(image)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_clipboard_image(out: &mut Image) {
+        *out = SystemClipboard::image().unwrap_or_default()
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_clipboard_set_html(html: &SharedString, alt_text: &SharedString) {
+        SystemClipboard::set_html(html, alt_text)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_clipboard_html(out: &mut SharedString) {
+        *out = SystemClipboard::html().unwrap_or_default()
+    }
+}
@@ -26,6 +26,9 @@ pub(crate) struct SlintContextInner {
         core::cell::RefCell<Option<alloc::vec::Vec<&'static str>>>,
     pub(crate) window_shown_hook:
         core::cell::RefCell<Option<Box<dyn FnMut(&Rc<dyn crate::platform::WindowAdapter>)>>>,
+    pub(crate) window_manager: crate::window::window_manager::WindowManagerState,
+    pub(crate) clipboard: crate::clipboard::ClipboardState,
+    pub(crate) screens_changed: crate::callbacks::CallbackSlot<dyn FnMut()>,
     #[cfg(all(unix, not(target_os = "macos")))]
     xdg_app_id: core::cell::RefCell<Option<crate::SharedString>>,
 }
@@ -45,6 +48,9 @@ pub fn new(platform: Box<dyn Platform + 'static>) -> Self {
             translations_dirty: Box::pin(Property::new_named(0, "SlintContext::translations")),
             translations_bundle_languages: Default::default(),
             window_shown_hook: Default::default(),
+            window_manager: Default::default(),
+            clipboard: Default::default(),
+            screens_changed: Default::default(),
             #[cfg(all(unix, not(target_os = "macos")))]
             xdg_app_id: Default::default(),
         }))
@@ -46,6 +46,43 @@ pub fn month_offset(month: u32, year: i32) -> i32 {
     0
 }
 
+/// Returns the first day of the week for the current locale, with 0 for Sunday and 6 for Saturday
+#[cfg(feature = "std")]
+pub fn first_day_of_week() -> i32 {
+    sys_locale::get_locale().map_or(1, |locale| first_day_of_week_for_locale(&locale))
+}
+
+/// Returns the first day of the week for the current locale, with 0 for Sunday and 6 for Saturday
+#[cfg(not(feature = "std"))]
+pub fn first_day_of_week() -> i32 {
+    1
+}
+
+/// Returns the first day of the week in the region of the given locale (such as `en-US`),
+/// with 0 for Sunday and 6 for Saturday. Defaults to Monday.
+pub fn first_day_of_week_for_locale(locale: &str) -> i32 {
+    // The region is the last part of the locale in upper case, e.g. "US" in "en-US" or "en_US.UTF-8"
+    let locale = locale.split(['.', '@']).next().unwrap_or_default();
+    let region = locale
+        .rsplit(['-', '_'])
+        .find(|part| part.len() == 2 && part.chars().all(|c| c.is_ascii_uppercase()));
+    match region {
+        Some(
+            "AG" | "AS" | "BD" | "BR" | "BS" | "BT" | "BW" | "BZ" | "CA" | "CN" | "CO" | "DM"
+            | "DO" | "ET" | "GT" | "GU" | "HK" | "HN" | "ID" | "IL" | "IN" | "JM" | "JP" | "KE"
+            | "KH" | "KR" | "LA" | "MH" | "MM" | "MO" | "MT" | "MX" | "MZ" | "NI" | "NP" | "PA"
+            | "PE" | "PH" | "PK" | "PR" | "PT" | "PY" | "SA" | "SG" | "SV" | "TH" | "TT" | "TW"
+            | "UM" | "US" | "VE" | "VI" | "WS" | "YE" | "ZA" | "ZW",
+        ) => 0,
+        Some(
+            "AE" | "AF" | "BH" | "DJ" | "DZ" | "EG" | "IQ" | "IR" | "JO" | "KW" | "LY" | "OM"
+            | "QA" | "SD" | "SY",
+        ) => 6,
+        Some("MV") => 5,
+        _ => 1,
+    }
+}
+
 pub fn format_date(format: &str, day: u32, month: u32, year: i32) -> SharedString {
     if let Some(date) = NaiveDate::from_ymd_opt(year, month, day) {
         return crate::format!("{}", date.format(format));
@@ -94,6 +131,11 @@ pub extern "C" fn slint_date_time_month_offset(month: u32, year: i32) -> i32 {
         month_offset(month, year)
     }
 
+    #[no_mangle]
+    pub extern "C" fn slint_date_time_first_day_of_week() -> i32 {
+        first_day_of_week()
+    }
+
     #[no_mangle]
     pub extern "C" fn slint_date_time_format_date(
         format: &SharedString,
@@ -126,3 +168,13 @@ pub extern "C" fn slint_date_time_parse_date(
         }
     }
 }
+
+#[test]
+fn test_first_day_of_week_for_locale() {
+    assert_eq!(first_day_of_week_for_locale("en-US"), 0);
+    assert_eq!(first_day_of_week_for_locale("en_US.UTF-8"), 0);
+    assert_eq!(first_day_of_week_for_locale("de-DE"), 1);
+    assert_eq!(first_day_of_week_for_locale("zh-Hant-TW"), 0);
+    assert_eq!(first_day_of_week_for_locale("ar-EG"), 6);
+    assert_eq!(first_day_of_week_for_locale("fr"), 1);
+}
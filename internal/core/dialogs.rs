@@ -0,0 +1,237 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+#![cfg(feature = "std")]
+#![warn(missing_docs)]
+
+//! Native dialogs to open and save files, and to pick folders.
+//!
+//! The functions of this module return futures that resolve once the user closes the dialog.
+//! Await them in a future started with `slint::spawn_local()`, so that the
+//! event loop keeps running while the dialog is shown.
+//!
+//! ```rust,no_run
+//! use slint::dialogs::{FileDialogOptions, FileFilter};
+//! slint::spawn_local(async {
+//!     let options = FileDialogOptions::new()
+//!         .with_title("Open an image")
+//!         .with_filter(FileFilter::new("Images", ["png", "jpg", "jpeg"]));
+//!     if let Ok(Some(file)) = slint::dialogs::open_file(options).await {
+//!         let data = file.read().await.unwrap();
+//!         println!("{} has {} bytes", file.file_name(), data.len());
+//!     }
+//! })
+//! .unwrap();
+//! ```
+
+use crate::api::{PlatformError, Window};
+use crate::window::{WindowAdapter, WindowInner};
+use crate::SharedString;
+use alloc::rc::{Rc, Weak};
+use core::future::Future;
+use core::pin::Pin;
+use std::path::{Path, PathBuf};
+
+/// A filter of the files shown in a file dialog, for example `FileFilter::new("Images", ["png", "jpg"])`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileFilter {
+    /// The name of the filter, as shown to the user
+    pub name: SharedString,
+    /// The extensions of the files that match the filter, without the leading dot
+    pub extensions: Vec<SharedString>,
+}
+
+impl FileFilter {
+    /// Creates a filter with the given name, that matches the files with one of the extensions.
+    pub fn new(
+        name: impl Into<SharedString>,
+        extensions: impl IntoIterator<Item = impl Into<SharedString>>,
+    ) -> Self {
+        Self { name: name.into(), extensions: extensions.into_iter().map(Into::into).collect() }
+    }
+}
+
+/// The options of a file dialog.
+#[derive(Clone, Default)]
+pub struct FileDialogOptions {
+    /// The title of the dialog
+    pub title: Option<SharedString>,
+    /// The directory shown when the dialog opens
+    pub directory: Option<PathBuf>,
+    /// The file name suggested by the save dialog
+    pub file_name: Option<SharedString>,
+    /// The filters the user can choose from. The first one is selected by default.
+    pub filters: Vec<FileFilter>,
+    parent: Option<Weak<dyn WindowAdapter>>,
+}
+
+impl FileDialogOptions {
+    /// Creates the default options: no title, no filters, and no parent window.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the title of the dialog.
+    #[must_use]
+    pub fn with_title(mut self, title: impl Into<SharedString>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the directory shown when the dialog opens.
+    #[must_use]
+    pub fn with_directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.directory = Some(directory.into());
+        self
+    }
+
+    /// Sets the file name suggested by [`save_file()`].
+    #[must_use]
+    pub fn with_file_name(mut self, file_name: impl Into<SharedString>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    /// Adds a filter of the files shown in the dialog.
+    #[must_use]
+    pub fn with_filter(mut self, filter: FileFilter) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    /// Makes the dialog modal for the given window, on platforms that support it.
+    #[must_use]
+    pub fn with_parent(mut self, window: &Window) -> Self {
+        self.parent = Some(Rc::downgrade(&WindowInner::from_pub(window).window_adapter()));
+        self
+    }
+
+    /// Returns the window the dialog is modal for, if it's still alive.
+    pub fn parent(&self) -> Option<Rc<dyn WindowAdapter>> {
+        self.parent.as_ref().and_then(Weak::upgrade)
+    }
+}
+
+impl core::fmt::Debug for FileDialogOptions {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FileDialogOptions")
+            .field("title", &self.title)
+            .field("directory", &self.directory)
+            .field("file_name", &self.file_name)
+            .field("filters", &self.filters)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The kind of file dialog passed to [`Platform::show_file_dialog()`](crate::platform::Platform::show_file_dialog).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FileDialogKind {
+    /// A dialog to choose an existing file
+    OpenFile,
+    /// A dialog to choose where to save a file
+    SaveFile,
+    /// A dialog to choose a folder
+    PickFolder,
+}
+
+/// A future returned by [`Platform::show_file_dialog()`](crate::platform::Platform::show_file_dialog),
+/// that resolves to the chosen file, or `None` if the dialog was cancelled.
+pub type FileDialogFuture = Pin<Box<dyn Future<Output = Option<FileHandle>>>>;
+
+enum FileSource {
+    Path(PathBuf),
+    /// The content of a file that has no path, for example in the browser
+    Reader(Box<dyn Fn() -> Pin<Box<dyn Future<Output = Vec<u8>>>>>),
+}
+
+/// A file or folder chosen in a dialog.
+pub struct FileHandle {
+    name: SharedString,
+    source: FileSource,
+}
+
+impl FileHandle {
+    /// Creates a handle for a file or folder on the file system.
+    pub fn from_path(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let name = path
+            .file_name()
+            .map_or_else(Default::default, |n| SharedString::from(n.to_string_lossy().as_ref()));
+        Self { name, source: FileSource::Path(path) }
+    }
+
+    /// Creates a handle for a file that has no path on the file system, such as a file chosen
+    /// in the browser. `reader` returns the content of the file.
+    pub fn from_reader<F: Future<Output = Vec<u8>> + 'static>(
+        name: impl Into<SharedString>,
+        reader: impl Fn() -> F + 'static,
+    ) -> Self {
+        Self { name: name.into(), source: FileSource::Reader(Box::new(move || Box::pin(reader()))) }
+    }
+
+    /// Returns the path of the file, or `None` if the file has no path, such as in the browser.
+    pub fn path(&self) -> Option<&Path> {
+        match &self.source {
+            FileSource::Path(path) => Some(path),
+            FileSource::Reader(_) => None,
+        }
+    }
+
+    /// Returns the name of the file, without its directory.
+    pub fn file_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Reads the content of the file.
+    pub async fn read(&self) -> std::io::Result<Vec<u8>> {
+        match &self.source {
+            FileSource::Path(path) => std::fs::read(path),
+            FileSource::Reader(reader) => Ok(reader().await),
+        }
+    }
+}
+
+impl core::fmt::Debug for FileHandle {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match &self.source {
+            FileSource::Path(path) => f.debug_tuple("FileHandle").field(path).finish(),
+            FileSource::Reader(_) => f.debug_tuple("FileHandle").field(&self.name).finish(),
+        }
+    }
+}
+
+async fn show_file_dialog(
+    kind: FileDialogKind,
+    options: FileDialogOptions,
+) -> Result<Option<FileHandle>, PlatformError> {
+    let future = crate::context::with_global_context(
+        || Err(PlatformError::NoPlatform),
+        |ctx| ctx.platform().show_file_dialog(kind, options),
+    )??;
+    Ok(future.await)
+}
+
+/// Shows a dialog to choose an existing file, and returns the chosen file, or `None` if the
+/// dialog was cancelled.
+///
+/// Returns an error if the platform doesn't support file dialogs.
+pub async fn open_file(options: FileDialogOptions) -> Result<Option<FileHandle>, PlatformError> {
+    show_file_dialog(FileDialogKind::OpenFile, options).await
+}
+
+/// Shows a dialog to choose where to save a file, and returns the chosen file, or `None` if the
+/// dialog was cancelled. The file is not created.
+///
+/// Returns an error if the platform doesn't support file dialogs.
+pub async fn save_file(options: FileDialogOptions) -> Result<Option<FileHandle>, PlatformError> {
+    show_file_dialog(FileDialogKind::SaveFile, options).await
+}
+
+/// Shows a dialog to choose a folder, and returns the chosen folder, or `None` if the dialog
+/// was cancelled.
+///
+/// Returns an error if the platform doesn't support folder dialogs.
+pub async fn pick_folder(options: FileDialogOptions) -> Result<Option<FileHandle>, PlatformError> {
+    show_file_dialog(FileDialogKind::PickFolder, options).await
+}
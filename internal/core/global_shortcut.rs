@@ -0,0 +1,235 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! System-wide keyboard shortcuts, that are activated even when no window of the application has the focus
+
+#![warn(missing_docs)]
+
+use crate::callbacks::CallbackSlot;
+use crate::input::key_codes;
+use alloc::boxed::Box;
+use alloc::rc::{Rc, Weak};
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+use core::cell::RefCell;
+
+/// The key combination of a [`GlobalShortcut`]: a key and the modifiers that must be pressed with it.
+///
+/// It can be parsed from a string with the modifiers and the key separated by `+`, such as `"Control+Shift+K"`.
+/// The modifiers are `Control` (or `Ctrl`), `Alt`, `Shift`, and `Meta`. The key is either a single character, or
+/// the name of a [`Key`](crate::input::key_codes::Key), such as `F1` or `Escape`, or `Space`.
+///
+/// As with the modifiers of key events, `Control` is the Command key (⌘) on macOS, and `Meta` is the Control
+/// key on macOS and the Windows key on Windows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct KeyCombination {
+    /// The key, as a character or one of the constants of [`Key`](crate::input::key_codes::Key).
+    /// Letters are always lower case.
+    pub key: char,
+    /// The Alt key
+    pub alt: bool,
+    /// The Control key, or the Command key on macOS
+    pub control: bool,
+    /// The Shift key
+    pub shift: bool,
+    /// The Meta key: the Control key on macOS, and the Windows key on Windows
+    pub meta: bool,
+}
+
+impl core::str::FromStr for KeyCombination {
+    type Err = GlobalShortcutError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || GlobalShortcutError::InvalidKeyCombination(s.into());
+        let mut combination =
+            Self { key: '\0', alt: false, control: false, shift: false, meta: false };
+        // "Control++" is the plus key with the control modifier
+        let (modifiers, key) = match s.strip_suffix("++") {
+            Some(modifiers) => (modifiers, "+"),
+            None => s.rsplit_once('+').unwrap_or(("", s)),
+        };
+        for modifier in modifiers.split('+').filter(|_| !modifiers.is_empty()) {
+            let flag = match modifier.trim().to_ascii_lowercase().as_str() {
+                "control" | "ctrl" => &mut combination.control,
+                "alt" => &mut combination.alt,
+                "shift" => &mut combination.shift,
+                "meta" => &mut combination.meta,
+                _ => return Err(invalid()),
+            };
+            *flag = true;
+        }
+
+        let key = if s.trim() == "+" { "+" } else { key.trim() };
+        let mut chars = key.chars();
+        combination.key = match (chars.next(), chars.next()) {
+            (Some(c), None) => c.to_lowercase().next().unwrap_or(c),
+            _ => key_from_name(key).ok_or_else(invalid)?,
+        };
+        if is_modifier(combination.key) {
+            return Err(invalid());
+        }
+        Ok(combination)
+    }
+}
+
+fn key_from_name(name: &str) -> Option<char> {
+    macro_rules! match_key_name {
+        ($($char:literal # $name:ident # $($_qt:ident)|* # $($_winit:ident $(($_pos:ident))?)|* # $($_xkb:ident)|*;)*) => {
+            match name {
+                n if n.eq_ignore_ascii_case("Space") => Some(' '),
+                $(n if n.eq_ignore_ascii_case(stringify!($name)) => Some(key_codes::$name),)*
+                _ => None,
+            }
+        };
+    }
+    i_slint_common::for_each_special_keys!(match_key_name)
+}
+
+fn is_modifier(key: char) -> bool {
+    matches!(
+        key,
+        key_codes::Alt
+            | key_codes::AltGr
+            | key_codes::Control
+            | key_codes::ControlR
+            | key_codes::Shift
+            | key_codes::ShiftR
+            | key_codes::Meta
+            | key_codes::MetaR
+    )
+}
+
+/// The error returned when a [`GlobalShortcut`] can't be registered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GlobalShortcutError {
+    /// The string doesn't describe a valid [`KeyCombination`].
+    InvalidKeyCombination(String),
+    /// The platform doesn't support global shortcuts, or the platform was not initialized yet.
+    Unsupported,
+    /// The key combination is already registered, by this or another application.
+    AlreadyRegistered(KeyCombination),
+    /// Another platform-specific error occurred
+    Other(String),
+}
+
+impl core::fmt::Display for GlobalShortcutError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            GlobalShortcutError::InvalidKeyCombination(s) => {
+                write!(f, "Invalid key combination for a global shortcut: {s:?}")
+            }
+            GlobalShortcutError::Unsupported => {
+                f.write_str("Global shortcuts are not supported by this platform")
+            }
+            GlobalShortcutError::AlreadyRegistered(_) => {
+                f.write_str("The key combination of the global shortcut is already registered")
+            }
+            GlobalShortcutError::Other(str) => f.write_str(str),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GlobalShortcutError {}
+
+impl From<String> for GlobalShortcutError {
+    fn from(value: String) -> Self {
+        Self::Other(value)
+    }
+}
+
+/// A keyboard shortcut that is activated even when no window of the application has the focus.
+///
+/// The shortcut is unregistered when the `GlobalShortcut` is dropped.
+///
+/// Global shortcuts are implemented by the winit backend on Windows, macOS, and X11, with the
+/// `global-shortcuts` cargo feature. On Wayland, compositors don't let applications grab keys, so
+/// [`GlobalShortcut::new()`] returns [`GlobalShortcutError::Unsupported`].
+///
+/// # Example
+/// ```rust,no_run
+/// let shortcut = slint::GlobalShortcut::new("Control+Shift+Space").unwrap();
+/// shortcut.on_activated(|| println!("The shortcut was pressed"));
+/// ```
+pub struct GlobalShortcut {
+    inner: Rc<GlobalShortcutInner>,
+}
+
+impl GlobalShortcut {
+    /// Registers the key combination, described as in [`KeyCombination`], as global shortcut.
+    ///
+    /// Returns an error if the key combination is invalid, if it's already registered by this or
+    /// another application, or if the platform doesn't support global shortcuts. The platform is
+    /// initialized when the first component is created.
+    pub fn new(key_combination: &str) -> Result<Self, GlobalShortcutError> {
+        let key_combination = key_combination.parse()?;
+        let inner = Rc::new(GlobalShortcutInner {
+            key_combination,
+            adapter: Default::default(),
+            activated: Default::default(),
+        });
+        let handle = GlobalShortcutHandle(Rc::downgrade(&inner));
+        let adapter = crate::context::with_global_context(
+            || Err(crate::api::PlatformError::NoPlatform),
+            |ctx| ctx.platform().register_global_shortcut(key_combination, handle),
+        )
+        .map_err(|_| GlobalShortcutError::Unsupported)??;
+        inner.adapter.replace(Some(adapter));
+        Ok(Self { inner })
+    }
+
+    /// Returns the key combination of the shortcut.
+    pub fn key_combination(&self) -> KeyCombination {
+        self.inner.key_combination
+    }
+
+    /// Sets a callback that is invoked when the user presses the key combination.
+    pub fn on_activated(&self, callback: impl FnMut() + 'static) {
+        self.inner.activated.set(Box::new(callback));
+    }
+}
+
+struct GlobalShortcutInner {
+    key_combination: KeyCombination,
+    adapter: RefCell<Option<Box<dyn GlobalShortcutAdapter>>>,
+    activated: CallbackSlot<dyn FnMut()>,
+}
+
+/// This trait represents the registration of a [`GlobalShortcut`] with the platform.
+///
+/// It is created by [`Platform::register_global_shortcut()`](crate::platform::Platform::register_global_shortcut),
+/// and dropped when the shortcut should be unregistered.
+pub trait GlobalShortcutAdapter {}
+
+/// A handle given to the platform when registering a [`GlobalShortcut`], to report its activation.
+#[derive(Clone)]
+pub struct GlobalShortcutHandle(Weak<GlobalShortcutInner>);
+
+impl GlobalShortcutHandle {
+    /// Invokes the `activated` callback of the [`GlobalShortcut`]. Does nothing if the shortcut was dropped.
+    pub fn activate(&self) {
+        let Some(inner) = self.0.upgrade() else { return };
+        inner.activated.call(|f| f());
+    }
+}
+
+#[test]
+fn parse_key_combination() {
+    let parse = |s: &str| s.parse::<KeyCombination>();
+    let k = parse("Control+Shift+K").unwrap();
+    assert_eq!((k.key, k.control, k.shift, k.alt, k.meta), ('k', true, true, false, false));
+    let k = parse("alt + F5").unwrap();
+    assert_eq!((k.key, k.control, k.alt), (key_codes::F5, false, true));
+    let k = parse("Ctrl++").unwrap();
+    assert_eq!((k.key, k.control), ('+', true));
+    assert_eq!(parse("+").unwrap().key, '+');
+    assert_eq!(parse("Meta+Escape").unwrap().key, key_codes::Escape);
+    assert_eq!(parse("Control+Space").unwrap().key, ' ');
+    assert!(matches!(parse(""), Err(GlobalShortcutError::InvalidKeyCombination(_))));
+    assert!(matches!(parse("Control+"), Err(GlobalShortcutError::InvalidKeyCombination(_))));
+    assert!(matches!(parse("Hyper+K"), Err(GlobalShortcutError::InvalidKeyCombination(_))));
+    assert!(matches!(parse("Control+Shift"), Err(GlobalShortcutError::InvalidKeyCombination(_))));
+    assert!(matches!(parse("Control+Foo"), Err(GlobalShortcutError::InvalidKeyCombination(_))));
+}
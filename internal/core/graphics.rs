@@ -42,6 +42,11 @@
 #[cfg(feature = "std")]
 pub use path::*;
 
+#[cfg(feature = "std")]
+mod path_operations;
+#[cfg(feature = "std")]
+pub use path_operations::*;
+
 mod brush;
 pub use brush::*;
 
@@ -51,6 +56,9 @@
 pub(crate) mod bitmapfont;
 pub use self::bitmapfont::*;
 
+mod video;
+pub use video::*;
+
 pub mod rendering_metrics_collector;
 
 #[cfg(feature = "box-shadow-cache")]
@@ -153,6 +161,94 @@ pub struct FontRequest {
     pub letter_spacing: Option<LogicalLength>,
     /// Whether to select an italic face of the font family.
     pub italic: bool,
+    /// The OpenType features to enable or disable, in the syntax of the `font-features` property.
+    /// Use [`Self::features()`] to parse them.
+    pub features: SharedString,
+    /// The values of the axes of a variable font, in the syntax of the `font-variations` property.
+    /// Use [`Self::variations()`] to parse them.
+    pub variations: SharedString,
+}
+
+impl FontRequest {
+    /// Returns the OpenType features of this request.
+    pub fn features(&self) -> impl Iterator<Item = FontFeature> + '_ {
+        parse_font_features(&self.features)
+    }
+
+    /// Returns the values of the axes to set on a variable font. The `wght` axis follows the weight of
+    /// the request, unless it's set explicitly.
+    pub fn variations(&self) -> impl Iterator<Item = FontVariation> + '_ {
+        let explicit = parse_font_variations(&self.variations);
+        let has_weight = explicit.clone().any(|variation| &variation.tag == b"wght");
+        self.weight
+            .filter(|_| !has_weight)
+            .map(|weight| FontVariation { tag: *b"wght", value: weight as f32 })
+            .into_iter()
+            .chain(explicit)
+    }
+
+    /// Returns the font families to fall back to, in order, for characters that the requested family
+    /// doesn't have glyphs for, as set with [`crate::api::set_font_fallback_families()`].
+    pub fn fallback_families(&self) -> alloc::vec::Vec<SharedString> {
+        crate::api::font_fallback_families(self.family.as_deref().unwrap_or_default())
+    }
+}
+
+/// An OpenType feature to enable or disable when shaping text, such as `liga` or `tnum`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FontFeature {
+    /// The four letter tag of the feature
+    pub tag: [u8; 4],
+    /// 0 disables the feature, 1 enables it, and larger values select an alternate.
+    pub value: u32,
+}
+
+/// Parses a comma separated list of OpenType features, where `tag` or `+tag` enables a feature,
+/// `-tag` disables it, and `tag=2` selects the alternate with that index. Invalid entries are skipped.
+pub fn parse_font_features(features: &str) -> impl Iterator<Item = FontFeature> + '_ {
+    features.split(',').filter_map(|entry| {
+        let entry = entry.trim();
+        let (tag, value) = if let Some(tag) = entry.strip_prefix('-') {
+            (tag, 0)
+        } else if let Some((tag, value)) = entry.split_once('=') {
+            (tag, value.trim().parse().ok()?)
+        } else {
+            (entry.strip_prefix('+').unwrap_or(entry), 1)
+        };
+        let tag: [u8; 4] = tag.trim().as_bytes().try_into().ok()?;
+        tag.iter().all(|c| c.is_ascii_graphic()).then_some(FontFeature { tag, value })
+    })
+}
+
+/// The value of an axis of a variable font, such as `wght` for the weight or `wdth` for the width.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FontVariation {
+    /// The four letter tag of the axis
+    pub tag: [u8; 4],
+    /// The value, in the units of the axis. For example, the weight is between 1 and 1000, and the width
+    /// is a percentage of the normal width.
+    pub value: f32,
+}
+
+/// Parses a comma separated list of `tag=value` entries, such as `wght=650, wdth=75`.
+/// Invalid entries are skipped.
+pub fn parse_font_variations(variations: &str) -> impl Iterator<Item = FontVariation> + Clone + '_ {
+    variations.split(',').filter_map(|entry| {
+        let (tag, value) = entry.split_once('=')?;
+        let tag: [u8; 4] = tag.trim().as_bytes().try_into().ok()?;
+        let value: f32 = value.trim().parse().ok()?;
+        (tag.iter().all(|c| c.is_ascii_graphic()) && value.is_finite())
+            .then_some(FontVariation { tag, value })
+    })
+}
+
+/// Reads the file at the given path, or the resource that the platform provides for it
+/// with [`Platform::read_resource()`](crate::platform::Platform::read_resource).
+#[cfg(feature = "std")]
+pub fn read_resource(path: &str) -> std::io::Result<Vec<u8>> {
+    crate::context::GLOBAL_CONTEXT
+        .with(|ctx| ctx.get().and_then(|ctx| ctx.platform().read_resource(path)))
+        .unwrap_or_else(|| std::fs::read(path))
 }
 
 #[cfg(feature = "shared-fontdb")]
@@ -288,3 +384,30 @@ pub fn physical_position_to_api(
         crate::api::PhysicalPosition::from_euclid(position)
     }
 }
+
+#[test]
+fn test_parse_font_features() {
+    let features = |s| parse_font_features(s).map(|f| (f.tag, f.value)).collect::<Vec<_>>();
+    assert_eq!(features(""), []);
+    assert_eq!(
+        features("tnum, -liga,+smcp , ss01=2"),
+        [(*b"tnum", 1), (*b"liga", 0), (*b"smcp", 1), (*b"ss01", 2)]
+    );
+    assert_eq!(features("toolong, abc, cv01=x, kern"), [(*b"kern", 1)]);
+}
+
+#[test]
+fn test_font_variations() {
+    let variations = |request: &FontRequest| {
+        request.variations().map(|v| (v.tag, v.value)).collect::<alloc::vec::Vec<_>>()
+    };
+    let mut request = FontRequest {
+        variations: "wdth=75, opsz = 14.5, bad, wdth=x".into(),
+        ..Default::default()
+    };
+    assert_eq!(variations(&request), [(*b"wdth", 75.), (*b"opsz", 14.5)]);
+    request.weight = Some(650);
+    assert_eq!(variations(&request), [(*b"wght", 650.), (*b"wdth", 75.), (*b"opsz", 14.5)]);
+    request.variations = "wght=300".into();
+    assert_eq!(variations(&request), [(*b"wght", 300.)]);
+}
@@ -29,6 +29,12 @@ pub enum Brush {
     /// The radial gradient variant of a brush describes a circle variant centered
     /// in the middle
     RadialGradient(RadialGradientBrush),
+    /// The conic gradient variant of a brush describes the gradient stops around
+    /// the center of the shape, starting at the specified angle.
+    ConicGradient(ConicGradientBrush),
+    /// The four corner gradient variant of a brush interpolates between the colors
+    /// of the four corners of the shape.
+    FourCornerGradient(FourCornerGradientBrush),
 }
 
 /// Construct a brush with transparent color
@@ -41,6 +47,7 @@ fn default() -> Self {
 impl Brush {
     /// If the brush is SolidColor, the contained color is returned.
     /// If the brush is a LinearGradient, the color of the first stop is returned.
+    /// If the brush is a FourCornerGradient, the color of the top-left corner is returned.
     pub fn color(&self) -> Color {
         match self {
             Brush::SolidColor(col) => *col,
@@ -50,6 +57,10 @@ pub fn color(&self) -> Color {
             Brush::RadialGradient(gradient) => {
                 gradient.stops().next().map(|stop| stop.color).unwrap_or_default()
             }
+            Brush::ConicGradient(gradient) => {
+                gradient.stops().next().map(|stop| stop.color).unwrap_or_default()
+            }
+            Brush::FourCornerGradient(gradient) => gradient.top_left,
         }
     }
 
@@ -66,6 +77,8 @@ pub fn is_transparent(&self) -> bool {
             Brush::SolidColor(c) => c.alpha() == 0,
             Brush::LinearGradient(_) => false,
             Brush::RadialGradient(_) => false,
+            Brush::ConicGradient(_) => false,
+            Brush::FourCornerGradient(_) => false,
         }
     }
 
@@ -82,6 +95,8 @@ pub fn is_opaque(&self) -> bool {
             Brush::SolidColor(c) => c.alpha() == 255,
             Brush::LinearGradient(g) => g.stops().all(|s| s.color.alpha() == 255),
             Brush::RadialGradient(g) => g.stops().all(|s| s.color.alpha() == 255),
+            Brush::ConicGradient(g) => g.stops().all(|s| s.color.alpha() == 255),
+            Brush::FourCornerGradient(g) => g.colors().all(|c| c.alpha() == 255),
         }
     }
 
@@ -104,6 +119,16 @@ pub fn brighter(&self, factor: f32) -> Self {
                     GradientStop { color: s.color.brighter(factor), position: s.position }
                 })))
             }
+            Brush::ConicGradient(g) => Brush::ConicGradient(ConicGradientBrush::new(
+                g.from_angle(),
+                g.stops().map(|s| GradientStop {
+                    color: s.color.brighter(factor),
+                    position: s.position,
+                }),
+            )),
+            Brush::FourCornerGradient(g) => {
+                Brush::FourCornerGradient(g.map_colors(|c| c.brighter(factor)))
+            }
         }
     }
 
@@ -123,6 +148,14 @@ pub fn darker(&self, factor: f32) -> Self {
                 g.stops()
                     .map(|s| GradientStop { color: s.color.darker(factor), position: s.position }),
             )),
+            Brush::ConicGradient(g) => Brush::ConicGradient(ConicGradientBrush::new(
+                g.from_angle(),
+                g.stops()
+                    .map(|s| GradientStop { color: s.color.darker(factor), position: s.position }),
+            )),
+            Brush::FourCornerGradient(g) => {
+                Brush::FourCornerGradient(g.map_colors(|c| c.darker(factor)))
+            }
         }
     }
 
@@ -147,6 +180,16 @@ pub fn transparentize(&self, amount: f32) -> Self {
                     GradientStop { color: s.color.transparentize(amount), position: s.position }
                 })))
             }
+            Brush::ConicGradient(g) => Brush::ConicGradient(ConicGradientBrush::new(
+                g.from_angle(),
+                g.stops().map(|s| GradientStop {
+                    color: s.color.transparentize(amount),
+                    position: s.position,
+                }),
+            )),
+            Brush::FourCornerGradient(g) => {
+                Brush::FourCornerGradient(g.map_colors(|c| c.transparentize(amount)))
+            }
         }
     }
 
@@ -168,6 +211,16 @@ pub fn with_alpha(&self, alpha: f32) -> Self {
                     GradientStop { color: s.color.with_alpha(alpha), position: s.position }
                 })))
             }
+            Brush::ConicGradient(g) => Brush::ConicGradient(ConicGradientBrush::new(
+                g.from_angle(),
+                g.stops().map(|s| GradientStop {
+                    color: s.color.with_alpha(alpha),
+                    position: s.position,
+                }),
+            )),
+            Brush::FourCornerGradient(g) => {
+                Brush::FourCornerGradient(g.map_colors(|c| c.with_alpha(alpha)))
+            }
         }
     }
 }
@@ -221,6 +274,95 @@ pub fn stops(&self) -> impl Iterator<Item = &GradientStop> {
     }
 }
 
+/// The ConicGradientBrush describes a way of filling a shape with a gradient whose colors
+/// are interpolated around the center of the shape, clockwise.
+#[derive(Clone, PartialEq, Debug)]
+#[repr(transparent)]
+pub struct ConicGradientBrush(SharedVector<GradientStop>);
+
+impl ConicGradientBrush {
+    /// Creates a new conic gradient, starting at the specified angle and described by the
+    /// provided color stops.
+    ///
+    /// The angle need to be specified in degrees, where 0 is the top of the shape. The position
+    /// of the stops is the fraction of a full turn from that angle.
+    pub fn new(from_angle: f32, stops: impl IntoIterator<Item = GradientStop>) -> Self {
+        let stop_iter = stops.into_iter();
+        let mut encoded_angle_and_stops = SharedVector::with_capacity(stop_iter.size_hint().0 + 1);
+        // The gradient's first stop is a fake stop to store the angle
+        encoded_angle_and_stops
+            .push(GradientStop { color: Default::default(), position: from_angle });
+        encoded_angle_and_stops.extend(stop_iter);
+        Self(encoded_angle_and_stops)
+    }
+    /// Returns the angle in degrees at which the conic gradient starts.
+    pub fn from_angle(&self) -> f32 {
+        self.0[0].position
+    }
+    /// Returns the color stops of the conic gradient.
+    pub fn stops(&self) -> impl Iterator<Item = &GradientStop> {
+        // skip the first fake stop that just contains the angle
+        self.0.iter().skip(1)
+    }
+    /// Returns the color at the given fraction of a turn from the start angle,
+    /// interpolated between the surrounding stops.
+    pub fn color_at(&self, position: f32) -> Color {
+        let mut previous: Option<&GradientStop> = None;
+        for stop in self.stops() {
+            if stop.position >= position {
+                return match previous {
+                    Some(previous) if stop.position > previous.position => {
+                        let t =
+                            (position - previous.position) / (stop.position - previous.position);
+                        previous.color.interpolate(&stop.color, t)
+                    }
+                    _ => stop.color,
+                };
+            }
+            previous = Some(stop);
+        }
+        previous.map(|stop| stop.color).unwrap_or_default()
+    }
+}
+
+/// The FourCornerGradientBrush describes a way of filling a shape with colors that are
+/// interpolated between the colors of its four corners.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+#[repr(C)]
+pub struct FourCornerGradientBrush {
+    /// The color of the top-left corner.
+    pub top_left: Color,
+    /// The color of the top-right corner.
+    pub top_right: Color,
+    /// The color of the bottom-right corner.
+    pub bottom_right: Color,
+    /// The color of the bottom-left corner.
+    pub bottom_left: Color,
+}
+
+impl FourCornerGradientBrush {
+    /// Creates a new four corner gradient from the colors of the top-left, top-right,
+    /// bottom-right, and bottom-left corners.
+    pub fn new(top_left: Color, top_right: Color, bottom_right: Color, bottom_left: Color) -> Self {
+        Self { top_left, top_right, bottom_right, bottom_left }
+    }
+    /// Returns the colors of the top-left, top-right, bottom-right, and bottom-left corners.
+    pub fn colors(&self) -> impl Iterator<Item = Color> {
+        [self.top_left, self.top_right, self.bottom_right, self.bottom_left].into_iter()
+    }
+    /// Returns the color at the given position, where (0, 0) is the top-left corner
+    /// and (1, 1) the bottom-right corner.
+    pub fn color_at(&self, x: f32, y: f32) -> Color {
+        let top = self.top_left.interpolate(&self.top_right, x);
+        let bottom = self.bottom_left.interpolate(&self.bottom_right, x);
+        top.interpolate(&bottom, y)
+    }
+
+    fn map_colors(&self, mut f: impl FnMut(Color) -> Color) -> Self {
+        Self::new(f(self.top_left), f(self.top_right), f(self.bottom_right), f(self.bottom_left))
+    }
+}
+
 /// GradientStop describes a single color stop in a gradient. The colors between multiple
 /// stops are interpolated.
 #[repr(C)]
@@ -330,8 +472,55 @@ fn interpolate(&self, target_value: &Self, t: f32) -> Self {
                     Brush::RadialGradient(new_grad)
                 }
             }
-            (a @ Brush::LinearGradient(_), b @ Brush::RadialGradient(_))
-            | (a @ Brush::RadialGradient(_), b @ Brush::LinearGradient(_)) => {
+            (Brush::SolidColor(col), Brush::ConicGradient(grad)) => {
+                let mut new_grad = grad.clone();
+                for x in new_grad.0.make_mut_slice().iter_mut().skip(1) {
+                    x.color = col.interpolate(&x.color, t);
+                }
+                Brush::ConicGradient(new_grad)
+            }
+            (a @ Brush::ConicGradient(_), b @ Brush::SolidColor(_)) => {
+                Self::interpolate(b, a, 1. - t)
+            }
+            (Brush::ConicGradient(lhs), Brush::ConicGradient(rhs)) => {
+                if lhs.0.len() < rhs.0.len() {
+                    Self::interpolate(target_value, self, 1. - t)
+                } else {
+                    let mut new_grad = lhs.clone();
+                    let mut iter = new_grad.0.make_mut_slice().iter_mut();
+                    {
+                        let angle = &mut iter.next().unwrap().position;
+                        *angle = angle.interpolate(&rhs.from_angle(), t);
+                    }
+                    let mut last_color = Color::default();
+                    for s2 in rhs.stops() {
+                        let s1 = iter.next().unwrap();
+                        last_color = s2.color;
+                        s1.color = s1.color.interpolate(&s2.color, t);
+                        s1.position = s1.position.interpolate(&s2.position, t);
+                    }
+                    for x in iter {
+                        x.position = x.position.interpolate(&1.0, t);
+                        x.color = x.color.interpolate(&last_color, t);
+                    }
+                    Brush::ConicGradient(new_grad)
+                }
+            }
+            (Brush::SolidColor(col), Brush::FourCornerGradient(grad)) => {
+                Brush::FourCornerGradient(grad.map_colors(|c| col.interpolate(&c, t)))
+            }
+            (a @ Brush::FourCornerGradient(_), b @ Brush::SolidColor(_)) => {
+                Self::interpolate(b, a, 1. - t)
+            }
+            (Brush::FourCornerGradient(lhs), Brush::FourCornerGradient(rhs)) => {
+                Brush::FourCornerGradient(FourCornerGradientBrush::new(
+                    lhs.top_left.interpolate(&rhs.top_left, t),
+                    lhs.top_right.interpolate(&rhs.top_right, t),
+                    lhs.bottom_right.interpolate(&rhs.bottom_right, t),
+                    lhs.bottom_left.interpolate(&rhs.bottom_left, t),
+                ))
+            }
+            (a, b) => {
                 // Just go to an intermediate color.
                 let color = Color::interpolate(&b.color(), &a.color(), t);
                 if t < 0.5 {
@@ -357,3 +546,42 @@ fn test_linear_gradient_encoding() {
     assert_eq!(grad.angle(), 256.);
     assert!(grad.stops().eq(stops.iter()));
 }
+
+#[test]
+#[allow(clippy::float_cmp)] // We want bit-wise equality here
+fn test_conic_gradient_encoding() {
+    let stops: SharedVector<GradientStop> = [
+        GradientStop { position: 0.0, color: Color::from_argb_u8(255, 255, 0, 0) },
+        GradientStop { position: 1.0, color: Color::from_argb_u8(255, 0, 0, 255) },
+    ]
+    .into();
+    let grad = ConicGradientBrush::new(90., stops.clone());
+    assert_eq!(grad.from_angle(), 90.);
+    assert!(grad.stops().eq(stops.iter()));
+    assert_eq!(grad.color_at(0.), Color::from_argb_u8(255, 255, 0, 0));
+    assert_eq!(grad.color_at(0.5), Color::from_argb_u8(255, 128, 0, 128));
+    assert_eq!(grad.color_at(1.), Color::from_argb_u8(255, 0, 0, 255));
+}
+
+#[test]
+fn test_four_corner_gradient() {
+    let red = Color::from_rgb_u8(255, 0, 0);
+    let blue = Color::from_rgb_u8(0, 0, 255);
+    let grad = FourCornerGradientBrush::new(red, red, blue, blue);
+    assert_eq!(grad.color_at(0.5, 0.), red);
+    assert_eq!(grad.color_at(0.5, 1.), blue);
+    assert_eq!(grad.color_at(0., 0.5), Color::from_rgb_u8(128, 0, 128));
+
+    let brush = Brush::FourCornerGradient(grad);
+    assert_eq!(brush.color(), red);
+    assert!(brush.is_opaque());
+    assert_eq!(
+        Brush::SolidColor(blue).interpolate(&brush, 0.5),
+        Brush::FourCornerGradient(FourCornerGradientBrush::new(
+            Color::from_rgb_u8(128, 0, 128),
+            Color::from_rgb_u8(128, 0, 128),
+            blue,
+            blue
+        ))
+    );
+}
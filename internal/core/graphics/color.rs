@@ -159,6 +159,18 @@ pub fn from_hsva(hue: f32, saturation: f32, value: f32, alpha: f32) -> Self {
         <RgbaColor<f32>>::from(hsva).into()
     }
 
+    /// Construct a color from red, green, blue and alpha channel parameters in the Display P3
+    /// color space, all between 0 and 1.
+    ///
+    /// The color is converted to sRGB, the color space that Slint renders in. Colors that are
+    /// outside of the sRGB gamut are clipped to its boundary.
+    pub fn from_display_p3(red: f32, green: f32, blue: f32, alpha: f32) -> Self {
+        let linear = [red, green, blue].map(srgb_to_linear);
+        let [red, green, blue] = DISPLAY_P3_TO_SRGB
+            .map(|row| linear_to_srgb(row.iter().zip(&linear).map(|(m, c)| m * c).sum()));
+        Self::from_argb_f32(alpha, red, green, blue)
+    }
+
     /// Returns the red channel of the color as u8 in the range 0..255.
     #[inline(always)]
     pub fn red(self) -> u8 {
@@ -329,6 +341,31 @@ fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
     }
 }
 
+/// Parses a color in the hexadecimal `#rgb`, `#rgba`, `#rrggbb`, or `#rrggbbaa` notation, where the `#`
+/// is optional. Returns `None` if the text is not such a color.
+pub fn parse_hex_color(text: &str) -> Option<Color> {
+    let hex = text.trim();
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+    if !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    // Expand the short notations by repeating each digit
+    let expanded: alloc::string::String;
+    let hex = if hex.len() <= 4 {
+        expanded = hex.chars().flat_map(|c| [c, c]).collect();
+        expanded.as_str()
+    } else {
+        hex
+    };
+    let value = u32::from_str_radix(hex, 16).ok()?;
+    match hex.len() {
+        6 => Some(Color::from_argb_encoded(0xff000000 | value)),
+        // 0xRRGGBBAA to 0xAARRGGBB
+        8 => Some(Color::from_argb_encoded(value.rotate_right(8))),
+        _ => None,
+    }
+}
+
 /// HsvaColor stores the hue, saturation, value and alpha components of a color
 /// in the HSV color space as `f32 ` fields.
 /// This is merely a helper struct for use with [`Color`].
@@ -411,6 +448,33 @@ fn from(value: Color) -> Self {
     }
 }
 
+/// Converts linear Display P3 to linear sRGB. Both use the D65 white point.
+const DISPLAY_P3_TO_SRGB: [[f32; 3]; 3] = [
+    [1.224_940_2, -0.224_940_4, 0.0],
+    [-0.042_056_955, 1.042_057_1, 0.0],
+    [-0.019_637_555, -0.078_636_04, 1.098_273_6],
+];
+
+/// Decodes a channel value between 0 and 1 with the sRGB transfer function, which Display P3
+/// uses as well.
+pub(crate) fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// The inverse of [`srgb_to_linear`]. Values outside of 0..1 are clamped.
+pub(crate) fn linear_to_srgb(value: f32) -> f32 {
+    let value = value.clamp(0., 1.);
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1. / 2.4) - 0.055
+    }
+}
+
 #[test]
 fn test_rgb_to_hsv() {
     // White
@@ -452,6 +516,31 @@ fn test_transparent_transition() {
     assert_eq!(interpolated, Color::from_argb_u8(191, 211, 211, 211));
 }
 
+#[test]
+fn test_display_p3() {
+    // Colors within the sRGB gamut keep their value
+    assert_eq!(Color::from_display_p3(1., 1., 1., 1.), Color::from_rgb_u8(255, 255, 255));
+    assert_eq!(Color::from_display_p3(0., 0., 0., 0.5), Color::from_argb_u8(128, 0, 0, 0));
+    assert_eq!(Color::from_display_p3(0.4, 0.4, 0.4, 1.), Color::from_rgb_u8(102, 102, 102));
+    // The pure P3 primaries are outside of sRGB and get clipped
+    assert_eq!(Color::from_display_p3(1., 0., 0., 1.), Color::from_rgb_u8(255, 0, 0));
+    assert_eq!(Color::from_display_p3(0., 1., 0., 1.), Color::from_rgb_u8(0, 255, 0));
+    // Same result as the conversion in CSS Color Module Level 4
+    assert_eq!(Color::from_display_p3(0.5, 0.3, 0.2, 1.), Color::from_rgb_u8(136, 73, 45));
+}
+
+#[test]
+fn test_parse_hex_color() {
+    assert_eq!(parse_hex_color("#3586f4"), Some(Color::from_rgb_u8(0x35, 0x86, 0xf4)));
+    assert_eq!(parse_hex_color("3586F480"), Some(Color::from_argb_u8(0x80, 0x35, 0x86, 0xf4)));
+    assert_eq!(parse_hex_color(" #abc "), Some(Color::from_rgb_u8(0xaa, 0xbb, 0xcc)));
+    assert_eq!(parse_hex_color("#abcd"), Some(Color::from_argb_u8(0xdd, 0xaa, 0xbb, 0xcc)));
+    assert_eq!(parse_hex_color("#12345"), None);
+    assert_eq!(parse_hex_color("#+12345"), None);
+    assert_eq!(parse_hex_color("red"), None);
+    assert_eq!(parse_hex_color(""), None);
+}
+
 #[cfg(feature = "ffi")]
 pub(crate) mod ffi {
     #![allow(unsafe_code)]
@@ -506,4 +595,14 @@ pub extern "C" fn slint_color_to_hsva(
     pub extern "C" fn slint_color_from_hsva(h: f32, s: f32, v: f32, a: f32) -> Color {
         Color::from_hsva(h, s, v, a)
     }
+
+    #[no_mangle]
+    pub extern "C" fn slint_color_parse_hex(text: &crate::SharedString, argb: &mut u32) -> bool {
+        if let Some(color) = parse_hex_color(text) {
+            *argb = color.as_argb_encoded();
+            true
+        } else {
+            false
+        }
+    }
 }
@@ -7,7 +7,7 @@
 
 use crate::lengths::{PhysicalPx, ScaleFactor};
 use crate::slice::Slice;
-use crate::{SharedString, SharedVector};
+use crate::{Color, SharedString, SharedVector};
 
 use super::{IntRect, IntSize};
 use crate::items::{ImageFit, ImageHorizontalAlignment, ImageTiling, ImageVerticalAlignment};
@@ -16,6 +16,8 @@
 pub mod cache;
 #[cfg(target_arch = "wasm32")]
 mod htmlimage;
+#[cfg(feature = "image-decoders")]
+mod icc;
 #[cfg(feature = "svg")]
 mod svg;
 
@@ -48,6 +50,16 @@ pub struct OpaqueImageVTable {
     pub static NINE_SLICE_VT for NineSliceImage
 }
 
+OpaqueImageVTable_static! {
+    /// VTable for RC wrapped animated image helper struct.
+    pub static ANIMATED_IMAGE_VT for AnimatedImage
+}
+
+OpaqueImageVTable_static! {
+    /// VTable for RC wrapped video source helper struct.
+    pub static VIDEO_SOURCE_VT for super::VideoSourceInner
+}
+
 /// SharedPixelBuffer is a container for storing image data as pixels. It is
 /// internally reference counted and cheap to clone.
 ///
@@ -343,6 +355,8 @@ pub fn new(resource: &ImageInner) -> Option<Self> {
             #[cfg(not(target_arch = "wasm32"))]
             ImageInner::BorrowedOpenGLTexture(..) => return None,
             ImageInner::NineSlice(nine) => vtable::VRc::borrow(nine).cache_key(),
+            ImageInner::AnimatedImage(animation) => animation.cache_key(),
+            ImageInner::Video(..) => return None,
         };
         if matches!(key, ImageCacheKey::Invalid) {
             None
@@ -376,6 +390,71 @@ fn cache_key(&self) -> ImageCacheKey {
     }
 }
 
+/// A frame of an [`AnimatedImage`]
+pub struct AnimationFrame {
+    /// The pixels of the whole frame
+    pub buffer: SharedImageBuffer,
+    /// How long the frame is shown
+    pub duration: core::time::Duration,
+}
+
+/// Represent an image made of several frames that are shown one after the other, such as an animated GIF
+pub struct AnimatedImage {
+    frames: alloc::vec::Vec<AnimationFrame>,
+    cache_key: ImageCacheKey,
+}
+
+impl AnimatedImage {
+    /// Creates an animated image from its frames. There must be at least one frame.
+    pub fn new(frames: alloc::vec::Vec<AnimationFrame>, cache_key: ImageCacheKey) -> Self {
+        assert!(!frames.is_empty());
+        Self { frames, cache_key }
+    }
+
+    /// Returns the frames of the animation
+    pub fn frames(&self) -> &[AnimationFrame] {
+        &self.frames
+    }
+
+    /// Returns the frame at the given index as an image
+    pub fn frame(&self, index: usize) -> Image {
+        let buffer = self.frames[index.min(self.frames.len() - 1)].buffer.clone();
+        Image(ImageInner::EmbeddedImage { cache_key: ImageCacheKey::Invalid, buffer })
+    }
+
+    /// Returns the index of the frame to show after the animation was played for `elapsed`, and how long
+    /// until the next frame is due. That duration is None when the last frame is reached and `looping` is false.
+    pub fn frame_at(
+        &self,
+        elapsed: core::time::Duration,
+        looping: bool,
+    ) -> (usize, Option<core::time::Duration>) {
+        let total: core::time::Duration = self.frames.iter().map(|frame| frame.duration).sum();
+        let last = self.frames.len() - 1;
+        if total.is_zero() || (!looping && elapsed >= total) {
+            return (last, None);
+        }
+        let mut position =
+            core::time::Duration::from_nanos((elapsed.as_nanos() % total.as_nanos()) as u64);
+        for (index, frame) in self.frames.iter().enumerate() {
+            if position < frame.duration {
+                return (index, Some(frame.duration - position));
+            }
+            position -= frame.duration;
+        }
+        (last, None)
+    }
+}
+
+impl OpaqueImage for AnimatedImage {
+    fn size(&self) -> IntSize {
+        self.frames[0].buffer.size()
+    }
+    fn cache_key(&self) -> ImageCacheKey {
+        self.cache_key.clone()
+    }
+}
+
 /// A resource is a reference to binary data, for example images. They can be accessible on the file
 /// system or embedded in the resulting binary. Or they might be URLs to a web server and a downloaded
 /// is necessary before they can be used.
@@ -400,6 +479,8 @@ pub enum ImageInner {
     #[cfg(not(target_arch = "wasm32"))]
     BorrowedOpenGLTexture(BorrowedOpenGLTexture) = 6,
     NineSlice(vtable::VRc<OpaqueImageVTable, NineSliceImage>) = 7,
+    AnimatedImage(vtable::VRc<OpaqueImageVTable, AnimatedImage>) = 8,
+    Video(vtable::VRc<OpaqueImageVTable, super::VideoSourceInner>) = 9,
 }
 
 impl ImageInner {
@@ -488,6 +569,8 @@ pub fn render_to_buffer(
                 Some(SharedImageBuffer::RGBA8Premultiplied(buffer))
             }
             ImageInner::NineSlice(nine) => nine.0.render_to_buffer(None),
+            ImageInner::AnimatedImage(animation) => Some(animation.frames[0].buffer.clone()),
+            ImageInner::Video(video) => video.current_frame().0.render_to_buffer(None),
             _ => None,
         }
     }
@@ -517,6 +600,8 @@ pub fn size(&self) -> IntSize {
             #[cfg(not(target_arch = "wasm32"))]
             ImageInner::BorrowedOpenGLTexture(BorrowedOpenGLTexture { size, .. }) => *size,
             ImageInner::NineSlice(nine) => nine.0.size(),
+            ImageInner::AnimatedImage(animation) => animation.size(),
+            ImageInner::Video(video) => video.size(),
         }
     }
 }
@@ -537,11 +622,19 @@ fn eq(&self, other: &Self) -> bool {
             #[cfg(not(target_arch = "wasm32"))]
             (Self::BorrowedOpenGLTexture(l0), Self::BorrowedOpenGLTexture(r0)) => l0 == r0,
             (Self::NineSlice(l), Self::NineSlice(r)) => l.0 == r.0 && l.1 == r.1,
+            (Self::AnimatedImage(l0), Self::AnimatedImage(r0)) => vtable::VRc::ptr_eq(l0, r0),
+            (Self::Video(l0), Self::Video(r0)) => vtable::VRc::ptr_eq(l0, r0),
             _ => false,
         }
     }
 }
 
+impl From<super::VideoSource> for Image {
+    fn from(source: super::VideoSource) -> Self {
+        Image(ImageInner::Video(source.0))
+    }
+}
+
 impl<'a> From<&'a Image> for &'a ImageInner {
     fn from(other: &'a Image) -> Self {
         &other.0
@@ -661,6 +754,18 @@ impl std::error::Error for LoadImageError {}
 #[derive(Default, Clone, Debug, PartialEq, derive_more::From)]
 pub struct Image(ImageInner);
 
+/// A change of the style of an SVG, applied when parsing it
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(not(feature = "svg"), allow(dead_code))]
+pub(crate) enum SvgOverride {
+    /// Sets the fill of the elements matching the CSS selector
+    Fill(SharedString, Color),
+    /// Sets the stroke of the elements matching the CSS selector
+    Stroke(SharedString, Color),
+    /// Replaces `var(--name)` with the color
+    Variable(SharedString, Color),
+}
+
 impl Image {
     #[cfg(feature = "image-decoders")]
     /// Load an Image from a path to a file containing an image
@@ -813,10 +918,51 @@ pub unsafe fn from_borrowed_gl_2d_rgba_texture(
     pub fn load_from_svg_data(buffer: &[u8]) -> Result<Self, LoadImageError> {
         let cache_key = ImageCacheKey::Invalid;
         Ok(Image(ImageInner::Svg(vtable::VRc::new(
-            svg::load_from_data(buffer, cache_key).map_err(|_| LoadImageError(()))?,
+            svg::load_from_data(buffer.to_vec().into(), cache_key)
+                .map_err(|_| LoadImageError(()))?,
         ))))
     }
 
+    /// Returns a copy of this image where the elements of the SVG that match the CSS `selector`,
+    /// such as `.accent` or `#outline`, are filled with the given color.
+    ///
+    /// Unlike the `colorize` property of the `Image` element, this keeps the other colors of the
+    /// SVG, so that one icon can follow the theme of the application.
+    /// Images that are not SVGs are returned unchanged.
+    pub fn with_svg_fill(&self, selector: &str, color: Color) -> Image {
+        self.with_svg_override(SvgOverride::Fill(selector.into(), color))
+    }
+
+    /// Returns a copy of this image where the elements of the SVG that match the CSS `selector`,
+    /// such as `.accent` or `#outline`, are stroked with the given color.
+    ///
+    /// Images that are not SVGs are returned unchanged.
+    pub fn with_svg_stroke(&self, selector: &str, color: Color) -> Image {
+        self.with_svg_override(SvgOverride::Stroke(selector.into(), color))
+    }
+
+    /// Returns a copy of this image where the CSS variable `name`, such as `--accent`, is replaced
+    /// by the given color wherever the SVG uses it with `var(--accent)`.
+    ///
+    /// Images that are not SVGs are returned unchanged.
+    pub fn with_svg_variable(&self, name: &str, color: Color) -> Image {
+        self.with_svg_override(SvgOverride::Variable(name.into(), color))
+    }
+
+    fn with_svg_override(&self, _style_override: SvgOverride) -> Image {
+        match &self.0 {
+            #[cfg(feature = "svg")]
+            ImageInner::Svg(svg) => match svg.with_override(_style_override) {
+                Ok(svg) => Image(ImageInner::Svg(vtable::VRc::new(svg))),
+                Err(err) => {
+                    crate::debug_log!("Error applying the style to the SVG: {err}");
+                    self.clone()
+                }
+            },
+            _ => self.clone(),
+        }
+    }
+
     /// Sets the nine-slice edges of the image.
     ///
     /// [Nine-slice scaling](https://en.wikipedia.org/wiki/9-slice_scaling) is a method for scaling
@@ -868,6 +1014,12 @@ pub fn path(&self) -> Option<&std::path::Path> {
                 } => Some(std::path::Path::new(path.as_str())),
                 _ => None,
             },
+            ImageInner::AnimatedImage(animation) => match &animation.cache_key {
+                ImageCacheKey::Path(CachedPath { path, .. }) => {
+                    Some(std::path::Path::new(path.as_str()))
+                }
+                _ => None,
+            },
             _ => None,
         }
     }
@@ -952,6 +1104,16 @@ pub fn load_image_from_embedded_data(data: Slice<'static, u8>, format: Slice<'_,
     })
 }
 
+/// Load an image from data in memory that doesn't live for the whole program.
+/// The `format` is the file extension, used as a hint for the decoder.
+/// Unlike [`load_image_from_embedded_data`], the result is not cached.
+#[cfg(feature = "image-decoders")]
+pub fn load_image_from_data(data: &[u8], format: &[u8]) -> Result<Image, LoadImageError> {
+    cache::decode_image_data(data.to_vec().into(), format, ImageCacheKey::Invalid)
+        .map(Image)
+        .ok_or(LoadImageError(()))
+}
+
 #[test]
 fn test_image_size_from_buffer_without_backend() {
     {
@@ -985,6 +1147,78 @@ fn test_image_invalid_svg() {
     assert!(result.is_err());
 }
 
+#[cfg(feature = "svg")]
+#[test]
+fn test_svg_overrides() {
+    let svg = r#"<svg width="2" height="1" xmlns="http://www.w3.org/2000/svg">
+        <style>.left { fill: #000000 }</style>
+        <rect class="left" width="1" height="1"/>
+        <rect x="1" width="1" height="1" style="fill: var(--right, #0000ff)"/>
+    </svg>"#;
+    let pixels = |image: &Image| image.to_rgba8_premultiplied().unwrap().as_slice().to_vec();
+    let image = Image::load_from_svg_data(svg.as_bytes()).unwrap();
+    let red = Color::from_rgb_u8(255, 0, 0);
+    let green = Color::from_rgb_u8(0, 255, 0);
+
+    let styled = image.with_svg_fill(".left", red);
+    assert_ne!(styled, image);
+    assert_eq!(pixels(&styled), [Rgba8Pixel::new(255, 0, 0, 255), Rgba8Pixel::new(0, 0, 255, 255)]);
+    let styled = styled.with_svg_variable("--right", green);
+    assert_eq!(pixels(&styled), [Rgba8Pixel::new(255, 0, 0, 255), Rgba8Pixel::new(0, 255, 0, 255)]);
+    assert_eq!(pixels(&image)[0], Rgba8Pixel::new(0, 0, 0, 255));
+
+    let not_svg = Image::from_rgba8(SharedPixelBuffer::new(1, 1));
+    assert_eq!(not_svg.with_svg_fill(".left", red), not_svg);
+}
+
+#[cfg(feature = "image-gif")]
+#[test]
+fn test_animated_gif() {
+    use core::time::Duration;
+    let mut data = Vec::new();
+    {
+        let mut encoder = image::codecs::gif::GifEncoder::new(&mut data);
+        let frames = [(255, 50), (0, 200)].map(|(red, delay)| {
+            image::Frame::from_parts(
+                image::RgbaImage::from_pixel(4, 3, image::Rgba([red, 0, 0, 255])),
+                0,
+                0,
+                image::Delay::from_numer_denom_ms(delay, 1),
+            )
+        });
+        encoder.encode_frames(frames).unwrap();
+    }
+    let image = load_image_from_data(&data, b"gif").unwrap();
+    assert_eq!(image.size(), [4, 3].into());
+    let ImageInner::AnimatedImage(animation) = &image.0 else { panic!("The GIF is not animated") };
+    assert_eq!(animation.frames().len(), 2);
+    assert_eq!(animation.frames()[1].duration, Duration::from_millis(200));
+    assert_eq!(
+        animation.frame(1).to_rgba8().unwrap().as_slice()[0],
+        Rgba8Pixel { r: 0, g: 0, b: 0, a: 255 }
+    );
+
+    assert_eq!(animation.frame_at(Duration::ZERO, true), (0, Some(Duration::from_millis(50))));
+    assert_eq!(
+        animation.frame_at(Duration::from_millis(60), true),
+        (1, Some(Duration::from_millis(190)))
+    );
+    assert_eq!(
+        animation.frame_at(Duration::from_millis(260), true),
+        (0, Some(Duration::from_millis(40)))
+    );
+    assert_eq!(animation.frame_at(Duration::from_millis(260), false), (1, None));
+}
+
+#[cfg(feature = "image-webp")]
+#[test]
+fn test_load_webp() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../docs/astro/src/assets/slint-logo-simple-dark.webp");
+    let image = Image::load_from_path(&path).unwrap();
+    assert_eq!(image.size(), [282, 150].into());
+}
+
 /// The result of the fit function
 #[derive(Debug)]
 pub struct FitResult {
@@ -1143,7 +1377,8 @@ pub fn fit(
     let ratio = match image_fit {
         // If there is any tiling, we ignore image_fit
         _ if has_tiling => scale_factor.get(),
-        ImageFit::Fill => {
+        // Nine-slice images are fitted with fit9slice(), so this only applies without insets
+        ImageFit::Fill | ImageFit::NineSlice => {
             return FitResult {
                 clip_rect: source_rect,
                 source_to_target_x: target.width / o.width,
@@ -1303,6 +1538,10 @@ pub extern "C" fn slint_image_path(image: &Image) -> Option<&SharedString> {
                 },
                 _ => None,
             },
+            ImageInner::AnimatedImage(animation) => match &animation.cache_key {
+                ImageCacheKey::Path(CachedPath { path, .. }) => Some(path),
+                _ => None,
+            },
             _ => None,
         }
     }
@@ -1332,6 +1571,36 @@ pub extern "C" fn slint_image_set_nine_slice_edges(
         image.set_nine_slice_edges(top, right, bottom, left);
     }
 
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_image_with_svg_fill(
+        image: &Image,
+        selector: &SharedString,
+        color: &Color,
+        out: *mut Image,
+    ) {
+        core::ptr::write(out, image.with_svg_fill(selector, *color))
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_image_with_svg_stroke(
+        image: &Image,
+        selector: &SharedString,
+        color: &Color,
+        out: *mut Image,
+    ) {
+        core::ptr::write(out, image.with_svg_stroke(selector, *color))
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_image_with_svg_variable(
+        image: &Image,
+        name: &SharedString,
+        color: &Color,
+        out: *mut Image,
+    ) {
+        core::ptr::write(out, image.with_svg_variable(name, *color))
+    }
+
     #[no_mangle]
     pub extern "C" fn slint_image_to_rgb8(
         image: &Image,
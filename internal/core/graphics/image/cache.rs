@@ -5,8 +5,12 @@
 This module contains image and caching related types for the run-time library.
 */
 
-use super::{CachedPath, Image, ImageCacheKey, ImageInner, SharedImageBuffer, SharedPixelBuffer};
+use super::{
+    AnimatedImage, AnimationFrame, CachedPath, Image, ImageCacheKey, ImageInner, SharedImageBuffer,
+    SharedPixelBuffer,
+};
 use crate::{slice::Slice, SharedString};
+use image::{AnimationDecoder, ImageDecoder};
 
 struct ImageWeightInBytes;
 
@@ -28,6 +32,10 @@ fn weight(&self, _key: &ImageCacheKey, value: &ImageInner) -> usize {
             #[cfg(not(target_arch = "wasm32"))]
             ImageInner::BorrowedOpenGLTexture(..) => 0, // Assume storage in GPU memory
             ImageInner::NineSlice(nine) => self.weight(_key, &nine.0),
+            ImageInner::AnimatedImage(animation) => {
+                animation.frames().iter().map(|frame| frame.buffer.size().area() as usize * 4).sum()
+            }
+            ImageInner::Video(..) => 0, // The frames change all the time and are not cached
         }
     }
 }
@@ -95,18 +103,17 @@ pub(crate) fn load_image_from_path(&mut self, path: &SharedString) -> Option<Ima
                 )));
             }
 
-            image::open(std::path::Path::new(&path.as_str())).map_or_else(
-                |decode_err| {
-                    eprintln!("Error loading image from {}: {}", &path, decode_err);
-                    None
-                },
-                |image| {
-                    Some(ImageInner::EmbeddedImage {
-                        cache_key,
-                        buffer: dynamic_image_to_shared_image_buffer(image),
-                    })
-                },
-            )
+            let format = image::ImageFormat::from_path(path.as_str()).ok();
+            crate::graphics::read_resource(path.as_str())
+                .map_err(image::ImageError::IoError)
+                .and_then(|data| decode_image(&data, format, cache_key))
+                .map_or_else(
+                    |decode_err| {
+                        eprintln!("Error loading image from {}: {}", &path, decode_err);
+                        None
+                    },
+                    Some,
+                )
         });
     }
 
@@ -117,40 +124,109 @@ pub(crate) fn load_image_from_embedded_data(
     ) -> Option<Image> {
         let cache_key = ImageCacheKey::from_embedded_image_data(data.as_slice());
         self.lookup_image_in_cache_or_create(cache_key, |cache_key| {
-            #[cfg(feature = "svg")]
-            if format.as_slice() == b"svg" || format.as_slice() == b"svgz" {
-                return Some(ImageInner::Svg(vtable::VRc::new(
-                    super::svg::load_from_data(data.as_slice(), cache_key).map_or_else(
-                        |svg_err| {
-                            eprintln!("Error loading SVG: {}", svg_err);
-                            None
-                        },
-                        Some,
-                    )?,
-                )));
-            }
+            decode_image_data(data.as_slice().into(), format.as_slice(), cache_key)
+        })
+    }
+}
 
-            let format = std::str::from_utf8(format.as_slice())
-                .ok()
-                .and_then(image::ImageFormat::from_extension);
-            let maybe_image = if let Some(format) = format {
-                image::load_from_memory_with_format(data.as_slice(), format)
-            } else {
-                image::load_from_memory(data.as_slice())
-            };
-
-            match maybe_image {
-                Ok(image) => Some(ImageInner::EmbeddedImage {
-                    cache_key,
-                    buffer: dynamic_image_to_shared_image_buffer(image),
-                }),
-                Err(decode_err) => {
-                    eprintln!("Error decoding embedded image: {}", decode_err);
+/// Decode the image in `data`, using `format` (the file extension) as a hint.
+pub(crate) fn decode_image_data(
+    data: alloc::borrow::Cow<'static, [u8]>,
+    format: &[u8],
+    cache_key: ImageCacheKey,
+) -> Option<ImageInner> {
+    #[cfg(feature = "svg")]
+    if format == b"svg" || format == b"svgz" {
+        return Some(ImageInner::Svg(vtable::VRc::new(
+            super::svg::load_from_data(data, cache_key).map_or_else(
+                |svg_err| {
+                    eprintln!("Error loading SVG: {}", svg_err);
                     None
+                },
+                Some,
+            )?,
+        )));
+    }
+
+    let format = std::str::from_utf8(format).ok().and_then(image::ImageFormat::from_extension);
+    match decode_image(&data, format, cache_key) {
+        Ok(image) => Some(image),
+        Err(decode_err) => {
+            eprintln!("Error decoding embedded image: {}", decode_err);
+            None
+        }
+    }
+}
+
+/// Decode the image in `data`, guessing the format from the data if `format` is None.
+/// Animated GIF and PNG (APNG) images are decoded into an [`AnimatedImage`].
+fn decode_image(
+    data: &[u8],
+    format: Option<image::ImageFormat>,
+    cache_key: ImageCacheKey,
+) -> image::ImageResult<ImageInner> {
+    let format = match format {
+        Some(format) => format,
+        None => image::guess_format(data)?,
+    };
+
+    let frames = match format {
+        #[cfg(feature = "image-gif")]
+        image::ImageFormat::Gif => Some(image::codecs::gif::GifDecoder::new(data)?.into_frames()),
+        image::ImageFormat::Png => {
+            let decoder = image::codecs::png::PngDecoder::new(data)?;
+            decoder.is_apng().then(|| decoder.apng().into_frames())
+        }
+        _ => None,
+    };
+
+    if let Some(frames) = frames {
+        let mut frames = frames
+            .map(|frame| {
+                let frame = frame?;
+                let (numerator, denominator) = frame.delay().numer_denom_ms();
+                let mut duration =
+                    core::time::Duration::from_millis((numerator / denominator.max(1)) as u64);
+                // Like web browsers, slow down GIFs that don't specify a delay or a very short one
+                if format == image::ImageFormat::Gif && duration.as_millis() <= 10 {
+                    duration = core::time::Duration::from_millis(100);
                 }
-            }
-        })
+                let buffer = frame.into_buffer();
+                Ok(AnimationFrame {
+                    buffer: SharedImageBuffer::RGBA8(SharedPixelBuffer::clone_from_slice(
+                        buffer.as_raw(),
+                        buffer.width(),
+                        buffer.height(),
+                    )),
+                    duration,
+                })
+            })
+            .collect::<image::ImageResult<Vec<_>>>()?;
+        if frames.len() > 1 {
+            return Ok(ImageInner::AnimatedImage(vtable::VRc::new(AnimatedImage::new(
+                frames, cache_key,
+            ))));
+        }
+        if let Some(frame) = frames.pop() {
+            return Ok(ImageInner::EmbeddedImage { cache_key, buffer: frame.buffer });
+        }
     }
+
+    // Images in other color spaces than sRGB, like photos in Display P3, embed an ICC profile
+    let icc_profile = match format {
+        image::ImageFormat::Png => image::codecs::png::PngDecoder::new(data)?.icc_profile(),
+        image::ImageFormat::Jpeg => image::codecs::jpeg::JpegDecoder::new(data)?.icc_profile(),
+        _ => None,
+    };
+
+    let buffer =
+        dynamic_image_to_shared_image_buffer(image::load_from_memory_with_format(data, format)?);
+    let buffer = match icc_profile.as_deref().and_then(super::icc::IccProfile::parse) {
+        Some(profile) => profile.convert_to_srgb(buffer),
+        None => buffer,
+    };
+
+    Ok(ImageInner::EmbeddedImage { cache_key, buffer })
 }
 
 fn dynamic_image_to_shared_image_buffer(dynamic_image: image::DynamicImage) -> SharedImageBuffer {
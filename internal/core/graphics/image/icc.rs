@@ -0,0 +1,313 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+Conversion of decoded images with an embedded ICC profile to sRGB.
+
+Only RGB matrix/TRC profiles are supported, which is what cameras, phones and image editors
+embed for Display P3, Adobe RGB and similar color spaces. Images with other profiles are
+left as they are.
+*/
+
+use super::SharedImageBuffer;
+use crate::graphics::color::{linear_to_srgb, srgb_to_linear};
+
+/// Converts PCS XYZ (D50) to linear sRGB, with the Bradford chromatic adaptation to D65.
+const XYZ_D50_TO_SRGB: [[f32; 3]; 3] = [
+    [3.133_856, -1.616_866_7, -0.490_614_6],
+    [-0.978_768_4, 1.916_141_5, 0.033_454],
+    [0.071_945_3, -0.228_991_4, 1.405_242_7],
+];
+
+/// Size of the table that maps linear values back to 8 bit sRGB values.
+const ENCODE_TABLE_SIZE: usize = 4096;
+
+/// A tone reproduction curve, converting an encoded channel value between 0 and 1 to linear.
+enum Curve {
+    Gamma(f32),
+    Table(Vec<u16>),
+    /// An ICC parametric curve: `g, a, b, c, d, e, f`
+    Parametric([f32; 7]),
+}
+
+impl Curve {
+    fn eval(&self, x: f32) -> f32 {
+        match self {
+            Curve::Gamma(gamma) => x.powf(*gamma),
+            Curve::Table(table) => {
+                let position = x * (table.len() - 1) as f32;
+                let index = (position as usize).min(table.len() - 2);
+                let fraction = position - index as f32;
+                let (a, b) = (table[index] as f32, table[index + 1] as f32);
+                (a + (b - a) * fraction) / 65535.
+            }
+            Curve::Parametric([g, a, b, c, d, e, f]) => {
+                if x >= *d {
+                    (a * x + b).max(0.).powf(*g) + e
+                } else {
+                    c * x + f
+                }
+            }
+        }
+    }
+}
+
+/// A parsed matrix/TRC ICC profile.
+pub struct IccProfile {
+    /// Converts linear RGB in the profile's color space to linear sRGB
+    matrix: [[f32; 3]; 3],
+    curves: [Curve; 3],
+}
+
+impl IccProfile {
+    /// Parses the profile, returns None if it is invalid or not an RGB matrix/TRC profile.
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        if data.get(16..20)? != b"RGB " || data.get(20..24)? != b"XYZ " {
+            return None;
+        }
+        let tag_count = read_u32(data, 128)? as usize;
+        let tag = |signature: &[u8; 4]| {
+            (0..tag_count.min(1024)).find_map(|i| {
+                let entry = 132 + i * 12;
+                if data.get(entry..entry + 4)? != signature {
+                    return None;
+                }
+                let offset = read_u32(data, entry + 4)? as usize;
+                let size = read_u32(data, entry + 8)? as usize;
+                data.get(offset..offset.checked_add(size)?)
+            })
+        };
+
+        let mut to_xyz = [[0.; 3]; 3];
+        for (column, signature) in [b"rXYZ", b"gXYZ", b"bXYZ"].into_iter().enumerate() {
+            let xyz = parse_xyz(tag(signature)?)?;
+            for row in 0..3 {
+                to_xyz[row][column] = xyz[row];
+            }
+        }
+        let matrix = XYZ_D50_TO_SRGB.map(|row| {
+            core::array::from_fn(|column| (0..3).map(|i| row[i] * to_xyz[i][column]).sum())
+        });
+
+        let curves =
+            [parse_curve(tag(b"rTRC")?)?, parse_curve(tag(b"gTRC")?)?, parse_curve(tag(b"bTRC")?)?];
+        Some(Self { matrix, curves })
+    }
+
+    /// Returns true if the conversion to sRGB doesn't change any 8 bit value noticeably,
+    /// for example for the sRGB profiles that many images embed.
+    fn is_srgb(&self) -> bool {
+        let identity = self.matrix.iter().enumerate().all(|(row, values)| {
+            values
+                .iter()
+                .enumerate()
+                .all(|(column, value)| (value - if row == column { 1. } else { 0. }).abs() < 0.01)
+        });
+        identity
+            && self.curves.iter().all(|curve| {
+                (0..=255).all(|i| {
+                    let x = i as f32 / 255.;
+                    (curve.eval(x) - srgb_to_linear(x)).abs() < 0.002
+                })
+            })
+    }
+
+    /// Converts the pixels of the buffer from the profile's color space to sRGB.
+    /// The buffer is returned unchanged if the profile is sRGB already.
+    pub fn convert_to_srgb(&self, buffer: SharedImageBuffer) -> SharedImageBuffer {
+        if self.is_srgb() {
+            return buffer;
+        }
+        let decode: [[f32; 256]; 3] = core::array::from_fn(|channel| {
+            core::array::from_fn(|i| self.curves[channel].eval(i as f32 / 255.))
+        });
+        let encode: Vec<u8> = (0..ENCODE_TABLE_SIZE)
+            .map(|i| {
+                (linear_to_srgb(i as f32 / (ENCODE_TABLE_SIZE - 1) as f32) * 255.).round() as u8
+            })
+            .collect();
+        let convert = |r: &mut u8, g: &mut u8, b: &mut u8| {
+            let linear = [decode[0][*r as usize], decode[1][*g as usize], decode[2][*b as usize]];
+            let [red, green, blue] = self.matrix.map(|row| {
+                let value: f32 = row.iter().zip(&linear).map(|(m, c)| m * c).sum();
+                encode[(value.clamp(0., 1.) * (ENCODE_TABLE_SIZE - 1) as f32).round() as usize]
+            });
+            (*r, *g, *b) = (red, green, blue);
+        };
+
+        match buffer {
+            SharedImageBuffer::RGB8(mut pixels) => {
+                pixels
+                    .make_mut_slice()
+                    .iter_mut()
+                    .for_each(|p| convert(&mut p.r, &mut p.g, &mut p.b));
+                SharedImageBuffer::RGB8(pixels)
+            }
+            SharedImageBuffer::RGBA8(mut pixels) => {
+                pixels
+                    .make_mut_slice()
+                    .iter_mut()
+                    .for_each(|p| convert(&mut p.r, &mut p.g, &mut p.b));
+                SharedImageBuffer::RGBA8(pixels)
+            }
+            // The decoders never produce premultiplied images, and converting them would require
+            // to unpremultiply first.
+            buffer @ SharedImageBuffer::RGBA8Premultiplied(_) => buffer,
+        }
+    }
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(data.get(offset..offset + 4)?.try_into().ok()?))
+}
+
+fn read_s15_fixed16(data: &[u8], offset: usize) -> Option<f32> {
+    Some(read_u32(data, offset)? as i32 as f32 / 65536.)
+}
+
+fn parse_xyz(data: &[u8]) -> Option<[f32; 3]> {
+    if data.get(0..4)? != b"XYZ " {
+        return None;
+    }
+    Some([read_s15_fixed16(data, 8)?, read_s15_fixed16(data, 12)?, read_s15_fixed16(data, 16)?])
+}
+
+fn parse_curve(data: &[u8]) -> Option<Curve> {
+    match data.get(0..4)? {
+        b"curv" => {
+            let count = read_u32(data, 8)? as usize;
+            let entries = data.get(12..12 + count.checked_mul(2)?)?;
+            let entry = |i: usize| u16::from_be_bytes([entries[i * 2], entries[i * 2 + 1]]);
+            match count {
+                0 => Some(Curve::Gamma(1.)),
+                1 => Some(Curve::Gamma(entry(0) as f32 / 256.)),
+                _ => Some(Curve::Table((0..count).map(entry).collect())),
+            }
+        }
+        b"para" => {
+            let function = u16::from_be_bytes(data.get(8..10)?.try_into().ok()?);
+            let parameter_count = match function {
+                0 => 1,
+                1 => 3,
+                2 => 4,
+                3 => 5,
+                4 => 7,
+                _ => return None,
+            };
+            let mut p = [0.; 7];
+            for (i, value) in p.iter_mut().take(parameter_count).enumerate() {
+                *value = read_s15_fixed16(data, 12 + i * 4)?;
+            }
+            let [g, a, b, c, d, e, f] = p;
+            // Express all function types as type 4: Y = (aX + b)^g + e if X >= d, else cX + f
+            Some(Curve::Parametric(match function {
+                0 => [g, 1., 0., 0., 0., 0., 0.],
+                1 => [g, a, b, 0., -b / a, 0., 0.],
+                2 => [g, a, b, 0., -b / a, c, c],
+                3 => [g, a, b, c, d, 0., 0.],
+                _ => [g, a, b, c, d, e, f],
+            }))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics::{Rgb8Pixel, SharedPixelBuffer};
+
+    /// Builds a minimal matrix/TRC profile with the given primaries (in PCS XYZ) and a
+    /// parametric sRGB curve.
+    fn make_profile(primaries: [[f32; 3]; 3]) -> Vec<u8> {
+        let mut tags: Vec<([u8; 4], Vec<u8>)> = Vec::new();
+        let fixed = |v: f32| ((v * 65536.).round() as i32).to_be_bytes();
+        for (signature, xyz) in [*b"rXYZ", *b"gXYZ", *b"bXYZ"].into_iter().zip(primaries) {
+            let mut data = b"XYZ \0\0\0\0".to_vec();
+            xyz.iter().for_each(|v| data.extend(fixed(*v)));
+            tags.push((signature, data));
+        }
+        let mut curve = b"para\0\0\0\0\0\x03\0\0".to_vec();
+        for v in [2.4, 1. / 1.055, 0.055 / 1.055, 1. / 12.92, 0.04045] {
+            curve.extend(fixed(v));
+        }
+        for signature in [*b"rTRC", *b"gTRC", *b"bTRC"] {
+            tags.push((signature, curve.clone()));
+        }
+
+        let mut profile = vec![0; 128];
+        profile[16..20].copy_from_slice(b"RGB ");
+        profile[20..24].copy_from_slice(b"XYZ ");
+        profile.extend((tags.len() as u32).to_be_bytes());
+        let mut offset = 132 + tags.len() * 12;
+        for (signature, data) in &tags {
+            profile.extend(signature);
+            profile.extend((offset as u32).to_be_bytes());
+            profile.extend((data.len() as u32).to_be_bytes());
+            offset += data.len();
+        }
+        tags.iter().for_each(|(_, data)| profile.extend(data));
+        profile
+    }
+
+    /// The sRGB primaries, adapted to D50
+    const SRGB: [[f32; 3]; 3] =
+        [[0.4361, 0.2225, 0.0139], [0.3851, 0.7169, 0.0971], [0.1431, 0.0606, 0.7141]];
+    /// The Display P3 primaries, adapted to D50
+    const DISPLAY_P3: [[f32; 3]; 3] =
+        [[0.5151, 0.2412, -0.0011], [0.2920, 0.6922, 0.0419], [0.1571, 0.0666, 0.7841]];
+
+    fn convert(profile: &IccProfile, pixels: &[Rgb8Pixel]) -> Vec<Rgb8Pixel> {
+        let mut buffer = SharedPixelBuffer::new(pixels.len() as u32, 1);
+        buffer.make_mut_slice().copy_from_slice(pixels);
+        let buffer = SharedImageBuffer::RGB8(buffer);
+        match profile.convert_to_srgb(buffer) {
+            SharedImageBuffer::RGB8(pixels) => pixels.as_slice().to_vec(),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn srgb_profile_is_unchanged() {
+        let profile = IccProfile::parse(&make_profile(SRGB)).unwrap();
+        assert!(profile.is_srgb());
+        let pixels = [Rgb8Pixel::new(255, 0, 0), Rgb8Pixel::new(12, 34, 56)];
+        assert_eq!(convert(&profile, &pixels), pixels);
+    }
+
+    #[test]
+    fn display_p3_profile() {
+        let profile = IccProfile::parse(&make_profile(DISPLAY_P3)).unwrap();
+        assert!(!profile.is_srgb());
+        let converted = convert(
+            &profile,
+            &[
+                Rgb8Pixel::new(255, 255, 255),
+                Rgb8Pixel::new(0, 255, 0),
+                Rgb8Pixel::new(128, 77, 51),
+            ],
+        );
+        // White stays white, the P3 green is clipped to the sRGB green
+        assert_eq!(converted[0], Rgb8Pixel::new(255, 255, 255));
+        assert_eq!(converted[1], Rgb8Pixel::new(0, 255, 0));
+        // Within the sRGB gamut, the result matches Color::from_display_p3
+        let expected = crate::Color::from_display_p3(128. / 255., 77. / 255., 51. / 255., 1.);
+        let pixel = converted[2];
+        for (actual, expected) in
+            [(pixel.r, expected.red()), (pixel.g, expected.green()), (pixel.b, expected.blue())]
+        {
+            assert!(actual.abs_diff(expected) <= 1, "{pixel:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn invalid_profiles() {
+        assert!(IccProfile::parse(&[]).is_none());
+        let mut profile = make_profile(DISPLAY_P3);
+        profile[16..20].copy_from_slice(b"GRAY");
+        assert!(IccProfile::parse(&profile).is_none());
+        let mut profile = make_profile(DISPLAY_P3);
+        profile.truncate(200);
+        assert!(IccProfile::parse(&profile).is_none());
+    }
+}
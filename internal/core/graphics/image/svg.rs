@@ -1,14 +1,22 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-use super::{ImageCacheKey, SharedImageBuffer, SharedPixelBuffer};
+use super::{ImageCacheKey, SharedImageBuffer, SharedPixelBuffer, SvgOverride};
+use crate::graphics::Color;
 use crate::lengths::PhysicalPx;
 #[cfg(not(target_arch = "wasm32"))]
 use crate::SharedString;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
 use resvg::{tiny_skia, usvg};
 
 pub struct ParsedSVG {
     svg_tree: usvg::Tree,
+    /// The source of the SVG, kept to parse it again when its style is overridden
+    data: Cow<'static, [u8]>,
+    overrides: Vec<SvgOverride>,
     cache_key: ImageCacheKey,
 }
 
@@ -72,6 +80,116 @@ pub fn render(
         resvg::render(tree, transform, &mut skia_buffer);
         Ok(SharedImageBuffer::RGBA8Premultiplied(buffer))
     }
+
+    /// Returns a copy of this SVG parsed again with the additional override
+    pub(crate) fn with_override(
+        &self,
+        style_override: SvgOverride,
+    ) -> Result<ParsedSVG, usvg::Error> {
+        let mut overrides = self.overrides.clone();
+        overrides.push(style_override);
+        let svg_tree = parse(&self.data, &overrides)?;
+        Ok(ParsedSVG {
+            svg_tree,
+            data: self.data.clone(),
+            overrides,
+            cache_key: ImageCacheKey::Invalid,
+        })
+    }
+}
+
+fn parse(data: &[u8], overrides: &[SvgOverride]) -> Result<usvg::Tree, usvg::Error> {
+    i_slint_common::sharedfontdb::FONT_DB.with_borrow(|db| {
+        let option = usvg::Options { fontdb: (*db).clone(), ..Default::default() };
+        if overrides.is_empty() {
+            return usvg::Tree::from_data(data, &option);
+        }
+        let decompressed;
+        let data = if data.starts_with(&[0x1f, 0x8b]) {
+            decompressed = usvg::decompress_svgz(data)?;
+            &decompressed
+        } else {
+            data
+        };
+        let text = core::str::from_utf8(data).map_err(|_| usvg::Error::NotAnUtf8Str)?;
+        usvg::Tree::from_str(&apply_overrides(text, overrides), &option)
+    })
+}
+
+/// Formats the color as `#rrggbbaa`
+fn css_color(color: Color) -> String {
+    alloc::format!(
+        "#{:02x}{:02x}{:02x}{:02x}",
+        color.red(),
+        color.green(),
+        color.blue(),
+        color.alpha()
+    )
+}
+
+/// Replaces the CSS variables of the overrides in the SVG source, and appends the fill and stroke
+/// overrides in a style sheet.
+fn apply_overrides(text: &str, overrides: &[SvgOverride]) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(pos) = rest.find("var(") {
+        result.push_str(&rest[..pos]);
+        let (replacement, len) = resolve_variable(&rest[pos..], overrides);
+        match replacement {
+            Some(replacement) => result.push_str(&replacement),
+            None => result.push_str(&rest[pos..pos + len]),
+        }
+        rest = &rest[pos + len..];
+    }
+    result.push_str(rest);
+
+    let mut style_sheet = String::new();
+    for style_override in overrides {
+        let _ = match style_override {
+            SvgOverride::Fill(selector, color) => {
+                writeln!(style_sheet, "{selector} {{ fill: {} }}", css_color(*color))
+            }
+            SvgOverride::Stroke(selector, color) => {
+                writeln!(style_sheet, "{selector} {{ stroke: {} }}", css_color(*color))
+            }
+            SvgOverride::Variable(..) => Ok(()),
+        };
+    }
+    // usvg applies the style sheet passed in its options before the ones of the document, so insert
+    // it at the end of the document instead for the overrides to take precedence.
+    if !style_sheet.is_empty() {
+        if let Some(pos) = result.rfind("</") {
+            result.insert_str(pos, &alloc::format!("<style><![CDATA[{style_sheet}]]></style>"));
+        }
+    }
+    result
+}
+
+/// Resolves the `var(--name)` or `var(--name, fallback)` at the start of `text`. Returns the
+/// replacement, if any, and the length of the expression.
+fn resolve_variable(text: &str, overrides: &[SvgOverride]) -> (Option<String>, usize) {
+    let mut depth = 0;
+    let Some(end) = text.char_indices().find_map(|(i, c)| {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => return None,
+        }
+        (depth == 0).then_some(i)
+    }) else {
+        return (None, "var(".len());
+    };
+    let (name, fallback) = match text["var(".len()..end].split_once(',') {
+        Some((name, fallback)) => (name.trim(), Some(fallback.trim())),
+        None => (text["var(".len()..end].trim(), None),
+    };
+    let value = overrides.iter().rev().find_map(|style_override| match style_override {
+        SvgOverride::Variable(variable, color) if variable.as_str() == name => {
+            Some(css_color(*color))
+        }
+        _ => None,
+    });
+    (value.or_else(|| fallback.map(Into::into)), end + 1)
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -79,19 +197,31 @@ pub fn load_from_path(
     path: &SharedString,
     cache_key: ImageCacheKey,
 ) -> Result<ParsedSVG, std::io::Error> {
-    let svg_data = std::fs::read(std::path::Path::new(&path.as_str()))?;
+    let svg_data = crate::graphics::read_resource(path.as_str())?;
+    load_from_data(svg_data.into(), cache_key).map_err(std::io::Error::other)
+}
 
-    i_slint_common::sharedfontdb::FONT_DB.with_borrow(|db| {
-        let option = usvg::Options { fontdb: (*db).clone(), ..Default::default() };
-        usvg::Tree::from_data(&svg_data, &option)
-            .map(|svg| ParsedSVG { svg_tree: svg, cache_key })
-            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))
-    })
+pub fn load_from_data(
+    data: Cow<'static, [u8]>,
+    cache_key: ImageCacheKey,
+) -> Result<ParsedSVG, usvg::Error> {
+    let svg_tree = parse(&data, &[])?;
+    Ok(ParsedSVG { svg_tree, data, overrides: Vec::new(), cache_key })
 }
 
-pub fn load_from_data(slice: &[u8], cache_key: ImageCacheKey) -> Result<ParsedSVG, usvg::Error> {
-    i_slint_common::sharedfontdb::FONT_DB.with_borrow(|db| {
-        let option = usvg::Options { fontdb: (*db).clone(), ..Default::default() };
-        usvg::Tree::from_data(slice, &option).map(|svg| ParsedSVG { svg_tree: svg, cache_key })
-    })
+#[test]
+fn test_apply_overrides() {
+    let red = Color::from_rgb_u8(255, 0, 0);
+    let svg = r#"<svg><path class="a" fill="var(--x)"/><path fill="var(--y, #00ff00)"/></svg>"#;
+    assert_eq!(
+        apply_overrides(
+            svg,
+            &[
+                SvgOverride::Variable("--x".into(), red),
+                SvgOverride::Fill(".a".into(), Color::from_argb_u8(128, 0, 0, 255)),
+            ]
+        ),
+        "<svg><path class=\"a\" fill=\"#ff0000ff\"/><path fill=\"#00ff00\"/>\
+         <style><![CDATA[.a { fill: #0000ff80 }\n]]></style></svg>"
+    );
 }
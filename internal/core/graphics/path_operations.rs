@@ -0,0 +1,506 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Interpolation between paths and boolean operations on paths, both working on SVG path commands
+//! such as the `commands` of the `Path` element.
+
+use super::PathData;
+use crate::SharedString;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use lyon_path::iterator::PathIterator;
+use lyon_path::math::{Point, Vector};
+use lyon_path::Event;
+use std::collections::{HashMap, HashSet};
+
+/// The tolerance used to approximate curves with line segments, in the units of the path
+const FLATTENING_TOLERANCE: f32 = 0.01;
+/// Distance under which two points, or a point and a line, are considered to be at the same place
+const EPSILON: f32 = 1e-4;
+
+fn parse_commands(commands: &str) -> Option<lyon_path::Path> {
+    let mut builder = lyon_path::Path::builder();
+    lyon_extra::parser::PathParser::new()
+        .parse(
+            &lyon_extra::parser::ParserOptions::DEFAULT,
+            &mut lyon_extra::parser::Source::new(commands.chars()),
+            &mut builder,
+        )
+        .ok()?;
+    Some(builder.build())
+}
+
+/// A segment of a path where the lines and the quadratic curves are converted to cubic curves,
+/// so that paths mixing them can be interpolated.
+#[derive(Clone, Copy, PartialEq)]
+enum Segment {
+    Begin(Point),
+    Cubic(Point, Point, Point),
+    End { close: bool },
+}
+
+fn segments(events: impl Iterator<Item = Event<Point, Point>>) -> Vec<Segment> {
+    events
+        .map(|event| match event {
+            Event::Begin { at } => Segment::Begin(at),
+            Event::Line { from, to } => {
+                Segment::Cubic(from.lerp(to, 1. / 3.), from.lerp(to, 2. / 3.), to)
+            }
+            Event::Quadratic { from, ctrl, to } => {
+                let cubic = lyon_geom::QuadraticBezierSegment { from, ctrl, to }.to_cubic();
+                Segment::Cubic(cubic.ctrl1, cubic.ctrl2, cubic.to)
+            }
+            Event::Cubic { ctrl1, ctrl2, to, .. } => Segment::Cubic(ctrl1, ctrl2, to),
+            Event::End { close, .. } => Segment::End { close },
+        })
+        .collect()
+}
+
+/// Returns the commands of the path in between the paths described by `from` and `to`, where
+/// `t` is the progress from 0 to 1.
+///
+/// The paths must be compatible: they must have the same number of sub-paths, made of the same number
+/// of segments, and be closed the same way. Lines, quadratic, and cubic curves can be mixed.
+/// Returns None if the paths are not compatible, or if one of them can't be parsed.
+pub fn interpolate_path_commands(from: &str, to: &str, t: f32) -> Option<SharedString> {
+    interpolate_segments(
+        segments(parse_commands(from)?.iter()),
+        segments(parse_commands(to)?.iter()),
+        t,
+    )
+}
+
+fn interpolate_segments(from: Vec<Segment>, to: Vec<Segment>, t: f32) -> Option<SharedString> {
+    if from.len() != to.len() {
+        return None;
+    }
+    let mut commands = SharedString::default();
+    for (from, to) in from.into_iter().zip(to) {
+        match (from, to) {
+            (Segment::Begin(p1), Segment::Begin(p2)) => {
+                let p = p1.lerp(p2, t);
+                write!(commands, "M {} {} ", p.x, p.y).unwrap();
+            }
+            (Segment::Cubic(c1, c2, p1), Segment::Cubic(d1, d2, p2)) => {
+                let (c, d, p) = (c1.lerp(d1, t), c2.lerp(d2, t), p1.lerp(p2, t));
+                write!(commands, "C {} {} {} {} {} {} ", c.x, c.y, d.x, d.y, p.x, p.y).unwrap();
+            }
+            (Segment::End { close: c1 }, Segment::End { close: c2 }) if c1 == c2 => {
+                if c1 {
+                    commands.push_str("Z ");
+                }
+            }
+            _ => return None,
+        }
+    }
+    Some(commands)
+}
+
+/// Interpolates the paths of the `Path` element when its `commands` are animated.
+///
+/// If the paths are not compatible (see [`interpolate_path_commands`]), the animation jumps to the target value.
+impl crate::properties::InterpolatedPropertyValue for PathData {
+    fn interpolate(&self, target_value: &Self, t: f32) -> Self {
+        let segments_of = |data: &PathData| Some(segments(data.clone().iter()?.iter()));
+        segments_of(self)
+            .zip(segments_of(target_value))
+            .and_then(|(from, to)| interpolate_segments(from, to, t))
+            .map(PathData::Commands)
+            .unwrap_or_else(|| target_value.clone())
+    }
+}
+
+/// The boolean operation done by [`path_boolean_operation`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PathBooleanOperation {
+    /// The area covered by either path
+    Union,
+    /// The area covered by both paths
+    Intersection,
+    /// The area covered by the first path but not by the second
+    Difference,
+}
+
+/// Returns the commands of the path covering the area that results from the boolean `operation` on
+/// the areas of the paths described by the `a` and `b` commands.
+///
+/// The curves are approximated with lines, and each sub-path of the result is closed. The areas are
+/// determined with the even-odd rule, so a sub-path within another one is a hole. The sub-paths of each
+/// path must not intersect each other. Returns None if one of the paths can't be parsed.
+pub fn path_boolean_operation(
+    a: &str,
+    b: &str,
+    operation: PathBooleanOperation,
+) -> Option<SharedString> {
+    let a = Shape::new(&parse_commands(a)?);
+    let b = Shape::new(&parse_commands(b)?);
+    let (a_edges, b_edges) = split_edges(&a, &b);
+
+    // Adding 0 turns -0 into 0, so that they have the same key
+    let key = |p: Point| ((p.x + 0.).to_bits(), (p.y + 0.).to_bits());
+    let a_keys = a_edges.iter().map(|(from, to)| (key(*from), key(*to))).collect::<HashSet<_>>();
+    let b_keys = b_edges.iter().map(|(from, to)| (key(*from), key(*to))).collect::<HashSet<_>>();
+
+    let mut result = Vec::new();
+    for &(from, to) in &a_edges {
+        let same = b_keys.contains(&(key(from), key(to)));
+        let opposite = b_keys.contains(&(key(to), key(from)));
+        let inside = || b.contains(from.lerp(to, 0.5));
+        let keep = match operation {
+            PathBooleanOperation::Union => same || (!opposite && !inside()),
+            PathBooleanOperation::Intersection => same || (!opposite && inside()),
+            PathBooleanOperation::Difference => opposite || (!same && !inside()),
+        };
+        if keep {
+            result.push((from, to));
+        }
+    }
+    for &(from, to) in &b_edges {
+        // Edges shared with the first path are handled above
+        if a_keys.contains(&(key(from), key(to))) || a_keys.contains(&(key(to), key(from))) {
+            continue;
+        }
+        let inside = a.contains(from.lerp(to, 0.5));
+        match operation {
+            PathBooleanOperation::Union if !inside => result.push((from, to)),
+            PathBooleanOperation::Intersection if inside => result.push((from, to)),
+            PathBooleanOperation::Difference if inside => result.push((to, from)),
+            _ => {}
+        }
+    }
+
+    // Chain the edges into closed sub-paths
+    let mut edges_from = HashMap::<_, Vec<usize>>::new();
+    for (index, (from, _)) in result.iter().enumerate() {
+        edges_from.entry(key(*from)).or_default().push(index);
+    }
+    let mut used = alloc::vec![false; result.len()];
+    let mut commands = SharedString::default();
+    for start in 0..result.len() {
+        if used[start] {
+            continue;
+        }
+        used[start] = true;
+        let (first, mut current) = result[start];
+        write!(commands, "M {} {} ", first.x, first.y).unwrap();
+        while key(current) != key(first) {
+            write!(commands, "L {} {} ", current.x, current.y).unwrap();
+            let Some(next) = edges_from
+                .get(&key(current))
+                .and_then(|edges| edges.iter().copied().find(|edge| !used[*edge]))
+            else {
+                break;
+            };
+            used[next] = true;
+            current = result[next].1;
+        }
+        commands.push_str("Z ");
+    }
+    Some(commands)
+}
+
+/// The polygons approximating a path, with the outer polygons oriented clockwise and the holes
+/// counter-clockwise (in a coordinate system where y goes down).
+struct Shape {
+    polygons: Vec<Vec<Point>>,
+}
+
+impl Shape {
+    fn new(path: &lyon_path::Path) -> Self {
+        let mut polygons = Vec::new();
+        let mut current = Vec::<Point>::new();
+        for event in path.iter().flattened(FLATTENING_TOLERANCE) {
+            match event {
+                Event::Begin { at } => current = alloc::vec![at],
+                Event::Line { to, .. } => {
+                    if current.last().map_or(true, |last| (*last - to).length() > EPSILON) {
+                        current.push(to);
+                    }
+                }
+                Event::End { .. } => {
+                    let mut polygon = core::mem::take(&mut current);
+                    while polygon.len() > 1
+                        && (polygon[0] - *polygon.last().unwrap()).length() <= EPSILON
+                    {
+                        polygon.pop();
+                    }
+                    if polygon.len() >= 3 {
+                        polygons.push(polygon);
+                    }
+                }
+                Event::Quadratic { .. } | Event::Cubic { .. } => {
+                    unreachable!("the path is flattened")
+                }
+            }
+        }
+
+        let depths = polygons
+            .iter()
+            .enumerate()
+            .map(|(index, polygon)| {
+                let sample = polygon[0].lerp(polygon[1], 0.5);
+                polygons
+                    .iter()
+                    .enumerate()
+                    .filter(|(other, other_polygon)| {
+                        *other != index && winding_number(other_polygon, sample) != 0
+                    })
+                    .count()
+            })
+            .collect::<Vec<_>>();
+        for (polygon, depth) in polygons.iter_mut().zip(depths) {
+            if (signed_area(polygon) > 0.) != (depth % 2 == 0) {
+                polygon.reverse();
+            }
+        }
+        Self { polygons }
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        self.polygons.iter().flat_map(|polygon| {
+            polygon.iter().zip(polygon.iter().cycle().skip(1)).map(|(from, to)| (*from, *to))
+        })
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        self.polygons.iter().map(|polygon| winding_number(polygon, point)).sum::<i32>() != 0
+    }
+}
+
+fn signed_area(polygon: &[Point]) -> f32 {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(p, q)| p.to_vector().cross(q.to_vector()))
+        .sum::<f32>()
+        / 2.
+}
+
+fn winding_number(polygon: &[Point], point: Point) -> i32 {
+    let mut winding = 0;
+    for (from, to) in polygon.iter().zip(polygon.iter().cycle().skip(1)) {
+        let side = (*to - *from).cross(point - *from);
+        if from.y <= point.y {
+            if to.y > point.y && side > 0. {
+                winding += 1;
+            }
+        } else if to.y <= point.y && side < 0. {
+            winding -= 1;
+        }
+    }
+    winding
+}
+
+/// Returns the edges of both shapes, split where they intersect or touch the other shape, so that
+/// each edge is either completely inside, outside, or on the boundary of the other shape.
+fn split_edges(a: &Shape, b: &Shape) -> (Vec<(Point, Point)>, Vec<(Point, Point)>) {
+    let a_edges = a.edges().collect::<Vec<_>>();
+    let b_edges = b.edges().collect::<Vec<_>>();
+    let mut a_splits = alloc::vec![Vec::new(); a_edges.len()];
+    let mut b_splits = alloc::vec![Vec::new(); b_edges.len()];
+
+    for (i, &(a0, a1)) in a_edges.iter().enumerate() {
+        for (j, &(b0, b1)) in b_edges.iter().enumerate() {
+            let (r, s) = (a1 - a0, b1 - b0);
+            let denominator = r.cross(s);
+            if denominator.abs() <= EPSILON * r.length() * s.length() {
+                // Parallel: if they are on the same line, split each edge at the ends of the other one
+                if distance_to_line(b0, a0, r) <= EPSILON {
+                    for p in [b0, b1] {
+                        if let Some(t) = parameter_within(p, a0, r) {
+                            a_splits[i].push((t, p));
+                        }
+                    }
+                    for p in [a0, a1] {
+                        if let Some(u) = parameter_within(p, b0, s) {
+                            b_splits[j].push((u, p));
+                        }
+                    }
+                }
+                continue;
+            }
+            let q = b0 - a0;
+            let t = q.cross(s) / denominator;
+            let u = q.cross(r) / denominator;
+            let (t_epsilon, u_epsilon) = (EPSILON / r.length(), EPSILON / s.length());
+            if !(-t_epsilon..=1. + t_epsilon).contains(&t)
+                || !(-u_epsilon..=1. + u_epsilon).contains(&u)
+            {
+                continue;
+            }
+            // Use the existing points when the intersection is at the end of an edge,
+            // so that the edges are connected at exactly the same points
+            let point = if u.abs() <= u_epsilon {
+                b0
+            } else if (1. - u).abs() <= u_epsilon {
+                b1
+            } else if t.abs() <= t_epsilon {
+                a0
+            } else if (1. - t).abs() <= t_epsilon {
+                a1
+            } else {
+                a0 + r * t
+            };
+            if t > t_epsilon && t < 1. - t_epsilon {
+                a_splits[i].push((t, point));
+            }
+            if u > u_epsilon && u < 1. - u_epsilon {
+                b_splits[j].push((u, point));
+            }
+        }
+    }
+
+    fn apply_splits(
+        edges: &[(Point, Point)],
+        splits: Vec<Vec<(f32, Point)>>,
+    ) -> Vec<(Point, Point)> {
+        let mut result = Vec::new();
+        for (&(from, to), mut splits) in edges.iter().zip(splits) {
+            splits.sort_by(|(t1, _), (t2, _)| t1.total_cmp(t2));
+            let mut previous = from;
+            for point in splits.into_iter().map(|(_, p)| p).chain(core::iter::once(to)) {
+                if point != previous {
+                    result.push((previous, point));
+                    previous = point;
+                }
+            }
+        }
+        result
+    }
+    (apply_splits(&a_edges, a_splits), apply_splits(&b_edges, b_splits))
+}
+
+fn distance_to_line(point: Point, origin: Point, direction: Vector) -> f32 {
+    (point - origin).cross(direction).abs() / direction.length()
+}
+
+/// Returns the parameter of the point on the segment starting at `origin`, if it is strictly within it.
+fn parameter_within(point: Point, origin: Point, direction: Vector) -> Option<f32> {
+    let t = (point - origin).dot(direction) / direction.square_length();
+    let epsilon = EPSILON / direction.length();
+    (t > epsilon && t < 1. - epsilon).then_some(t)
+}
+
+#[cfg(feature = "ffi")]
+mod ffi {
+    #![allow(unsafe_code)]
+
+    use super::*;
+
+    #[no_mangle]
+    pub extern "C" fn slint_path_interpolate(
+        from: &SharedString,
+        to: &SharedString,
+        t: f32,
+        out: &mut SharedString,
+    ) {
+        *out = interpolate_path_commands(from, to, t).unwrap_or_default();
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_path_union(a: &SharedString, b: &SharedString, out: &mut SharedString) {
+        *out = path_boolean_operation(a, b, PathBooleanOperation::Union).unwrap_or_default();
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_path_intersection(
+        a: &SharedString,
+        b: &SharedString,
+        out: &mut SharedString,
+    ) {
+        *out = path_boolean_operation(a, b, PathBooleanOperation::Intersection).unwrap_or_default();
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_path_difference(
+        a: &SharedString,
+        b: &SharedString,
+        out: &mut SharedString,
+    ) {
+        *out = path_boolean_operation(a, b, PathBooleanOperation::Difference).unwrap_or_default();
+    }
+}
+
+#[test]
+fn test_interpolate_path_commands() {
+    assert_eq!(
+        interpolate_path_commands("M 0 0 L 30 0 Z", "M 10 20 C 10 20 40 20 40 20 Z", 0.5).unwrap(),
+        "M 5 10 C 10 10 30 10 35 10 Z "
+    );
+    assert_eq!(
+        interpolate_path_commands("M 0 0 C 1 2 3 4 5 6", "M 0 0 Q 1 1 2 2", 0.).unwrap(),
+        "M 0 0 C 1 2 3 4 5 6 "
+    );
+    // Different number of segments
+    assert_eq!(interpolate_path_commands("M 0 0 L 10 0 L 10 10", "M 0 0 L 10 0", 0.5), None);
+    // Closed and open
+    assert_eq!(interpolate_path_commands("M 0 0 L 10 0 Z", "M 0 0 L 10 0", 0.5), None);
+    assert_eq!(interpolate_path_commands("M 0 0 L 10 0 Z", "not a path", 0.5), None);
+}
+
+#[test]
+fn test_interpolate_path_data() {
+    use crate::properties::InterpolatedPropertyValue;
+    let from = PathData::Commands("M 0 0 L 30 0 Z".into());
+    let to = PathData::Commands("M 10 20 C 10 20 40 20 40 20 Z".into());
+    assert_eq!(
+        from.interpolate(&to, 0.5),
+        PathData::Commands("M 5 10 C 10 10 30 10 35 10 Z ".into())
+    );
+    // Incompatible paths jump to the target
+    let to = PathData::Commands("M 0 0 L 10 0".into());
+    assert_eq!(from.interpolate(&to, 0.5), to);
+}
+
+#[cfg(test)]
+fn area_of_commands(commands: &str) -> f32 {
+    Shape::new(&parse_commands(commands).unwrap()).polygons.iter().map(|p| signed_area(p)).sum()
+}
+
+#[test]
+fn test_path_boolean_operation() {
+    use PathBooleanOperation::*;
+    let square = "M 0 0 L 10 0 L 10 10 L 0 10 Z";
+    let shifted = "M 5 5 L 15 5 L 15 15 L 5 15 Z";
+    let area =
+        |operation| area_of_commands(&path_boolean_operation(square, shifted, operation).unwrap());
+    assert!((area(Union) - 175.).abs() < 0.01);
+    assert!((area(Intersection) - 25.).abs() < 0.01);
+    assert!((area(Difference) - 75.).abs() < 0.01);
+
+    // Squares sharing an edge
+    let adjacent = "M 10 0 L 20 0 L 20 10 L 10 10 Z";
+    let area =
+        |operation| area_of_commands(&path_boolean_operation(square, adjacent, operation).unwrap());
+    assert!((area(Union) - 200.).abs() < 0.01);
+    assert!(area(Intersection).abs() < 0.01);
+    assert!((area(Difference) - 100.).abs() < 0.01);
+
+    // A hole punched in the middle, the inner sub-path is counted as a hole by Shape::new
+    let inner = "M 3 3 L 7 3 L 7 7 L 3 7 Z";
+    let punched = path_boolean_operation(square, inner, Difference).unwrap();
+    assert!((area_of_commands(&punched) - 84.).abs() < 0.01);
+    let filled = path_boolean_operation(&punched, inner, Union).unwrap();
+    assert!((area_of_commands(&filled) - 100.).abs() < 0.01);
+
+    // The same path
+    assert!(
+        (area_of_commands(&path_boolean_operation(square, square, Union).unwrap()) - 100.).abs()
+            < 0.01
+    );
+    assert!(
+        area_of_commands(&path_boolean_operation(square, square, Difference).unwrap()).abs() < 0.01
+    );
+
+    // Overlapping circles, of radius 5 and with their centers 5 apart
+    let circle =
+        |x: f32| format!("M {} 5 A 5 5 0 1 0 {} 5 A 5 5 0 1 0 {} 5 Z", x - 5., x + 5., x - 5.);
+    let area = |operation| {
+        area_of_commands(&path_boolean_operation(&circle(5.), &circle(10.), operation).unwrap())
+    };
+    assert!((area(Union) - 126.37).abs() < 0.3);
+    assert!((area(Intersection) - 30.71).abs() < 0.3);
+    assert!((area(Difference) - 47.83).abs() < 0.3);
+
+    assert_eq!(path_boolean_operation(square, "not a path", Union), None);
+}
@@ -31,15 +31,20 @@ pub enum RefreshMode {
 pub struct RenderingMetrics {
     /// The number of layers that were created. None if the renderer does not create layers.
     pub layers_created: Option<usize>,
+    /// The number of layers that were reused without rendering them again. None if the renderer
+    /// does not create layers.
+    pub layer_cache_hits: Option<usize>,
 }
 
 impl core::fmt::Display for RenderingMetrics {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if let Some(layer_count) = self.layers_created {
-            write!(f, "[{} layers created]", layer_count)
-        } else {
-            Ok(())
+            write!(f, "[{} layers created]", layer_count)?;
         }
+        if let Some(hit_count) = self.layer_cache_hits {
+            write!(f, "[{} layers reused]", hit_count)?;
+        }
+        Ok(())
     }
 }
 
@@ -0,0 +1,329 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+This module contains the types to show the frames of a video player or decoder in the `Video` element.
+*/
+
+use super::{
+    Image, ImageCacheKey, ImageInner, IntSize, OpaqueImage, OpaqueImageVTable, Rgb8Pixel,
+    Rgba8Pixel, SharedPixelBuffer,
+};
+use crate::Property;
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+use core::pin::Pin;
+use core::time::Duration;
+
+/// Implement this trait to let the `Video` element control the playback of a video player or
+/// decoder, such as a GStreamer pipeline, FFmpeg, or libmpv.
+///
+/// The functions are called when the `playing` and `position` properties of the `Video` element
+/// change. The decoded frames are passed to the [`VideoSource`] with [`VideoSource::push_frame()`].
+pub trait VideoFrameProvider {
+    /// Called when the `Video` element starts or resumes playing.
+    fn play(&self);
+    /// Called when the `Video` element is paused.
+    fn pause(&self);
+    /// Called when the `position` property of the `Video` element is set, to seek to the given position.
+    fn seek(&self, position: Duration);
+}
+
+/// A frame of a video, passed to [`VideoSource::push_frame()`].
+#[non_exhaustive]
+pub enum VideoFrame<'a> {
+    /// A frame made of RGBA pixels. The buffer is shown without copying the pixels.
+    Rgba8(SharedPixelBuffer<Rgba8Pixel>),
+    /// A frame in the planar YUV 4:2:0 format (I420), as produced by most video decoders.
+    /// The `u` and `v` planes have half the width and half the height of the `y` plane, rounded up.
+    /// The frame is converted to RGB using the BT.601 coefficients.
+    Yuv420 {
+        /// The width of the frame in pixels
+        width: u32,
+        /// The height of the frame in pixels
+        height: u32,
+        /// The luma plane
+        y: &'a [u8],
+        /// The number of bytes between the start of two rows of the luma plane
+        y_stride: usize,
+        /// The blue-difference chroma plane
+        u: &'a [u8],
+        /// The red-difference chroma plane
+        v: &'a [u8],
+        /// The number of bytes between the start of two rows of the chroma planes
+        uv_stride: usize,
+    },
+    /// Any image, for example a texture created with `BorrowedOpenGLTextureBuilder` or imported from
+    /// WGPU, to show frames that are decoded on the GPU without copying them.
+    Image(Image),
+}
+
+/// The data shared between the [`VideoSource`] handles and the images created from them
+#[doc(hidden)]
+pub struct VideoSourceInner {
+    provider: Box<dyn VideoFrameProvider>,
+    frame: Pin<Box<Property<Image>>>,
+    /// The size of the frames, in a separate property so that new frames of the same size don't
+    /// invalidate the layout.
+    size: Pin<Box<Property<IntSize>>>,
+    position: Pin<Box<Property<Duration>>>,
+    duration: Pin<Box<Property<Duration>>>,
+}
+
+impl VideoSourceInner {
+    pub(crate) fn current_frame(&self) -> Image {
+        self.frame.as_ref().get()
+    }
+}
+
+impl OpaqueImage for VideoSourceInner {
+    fn size(&self) -> IntSize {
+        self.size.as_ref().get()
+    }
+    fn cache_key(&self) -> ImageCacheKey {
+        ImageCacheKey::Invalid
+    }
+}
+
+/// A VideoSource connects a video player or decoder with the `Video` element.
+///
+/// Convert it into an [`Image`] and assign it to the `source` property of a `Video` element. The
+/// element then shows the frames passed to [`Self::push_frame()`], and calls the [`VideoFrameProvider`]
+/// when the video is played, paused, or when its position is changed.
+///
+/// All functions must be called from the thread that runs the event loop. Decoders and cameras that
+/// produce the frames in a different thread can send them with a [`VideoFrameSender`] instead.
+///
+/// Only the area of the elements that show the video is redrawn when a frame is pushed, as long as
+/// the renderer supports partial rendering.
+#[derive(Clone)]
+pub struct VideoSource(pub(crate) vtable::VRc<OpaqueImageVTable, VideoSourceInner>);
+
+impl VideoSource {
+    /// Creates a new VideoSource that's controlled by the given provider.
+    pub fn new(provider: impl VideoFrameProvider + 'static) -> Self {
+        Self(vtable::VRc::new(VideoSourceInner {
+            provider: Box::new(provider),
+            frame: Box::pin(Property::new(Image::default())),
+            size: Box::pin(Property::new(IntSize::default())),
+            position: Box::pin(Property::new(Duration::ZERO)),
+            duration: Box::pin(Property::new(Duration::ZERO)),
+        }))
+    }
+
+    /// Creates a new VideoSource for a stream of frames that can't be paused or seeked, such as
+    /// the preview of a camera. Show it with the `LiveFrame` element.
+    pub fn new_live() -> Self {
+        struct LiveStream;
+        impl VideoFrameProvider for LiveStream {
+            fn play(&self) {}
+            fn pause(&self) {}
+            fn seek(&self, _position: Duration) {}
+        }
+        Self::new(LiveStream)
+    }
+
+    /// Returns the VideoSource that the image was created from, if any.
+    pub fn from_image(image: &Image) -> Option<Self> {
+        match <&ImageInner>::from(image) {
+            ImageInner::Video(inner) => Some(Self(inner.clone())),
+            _ => None,
+        }
+    }
+
+    /// Shows the given frame in the `Video` elements that use this source.
+    pub fn push_frame(&self, frame: VideoFrame) {
+        let image = match frame {
+            VideoFrame::Rgba8(buffer) => Image::from_rgba8(buffer),
+            VideoFrame::Yuv420 { width, height, y, y_stride, u, v, uv_stride } => {
+                Image::from_rgb8(yuv420_to_rgb8(width, height, y, y_stride, u, v, uv_stride))
+            }
+            VideoFrame::Image(image) => image,
+        };
+        self.0.size.as_ref().set(image.size());
+        self.0.frame.as_ref().set(image);
+    }
+
+    /// Returns a sender to pass frames to this VideoSource from other threads.
+    #[cfg(feature = "std")]
+    pub fn frame_sender(&self) -> VideoFrameSender {
+        VideoFrameSender {
+            pending_frame: Default::default(),
+            source: SendWeak {
+                weak: vtable::VRc::downgrade(&self.0),
+                thread: std::thread::current().id(),
+            },
+        }
+    }
+
+    /// Returns the frame that was last passed to [`Self::push_frame()`].
+    pub fn current_frame(&self) -> Image {
+        self.0.current_frame()
+    }
+
+    /// Sets the current playback position, which the `Video` element exposes in its `position` property.
+    /// Call this when a new frame is shown or when seeking completed.
+    pub fn set_position(&self, position: Duration) {
+        self.0.position.as_ref().set(position);
+    }
+
+    /// Returns the current playback position.
+    pub fn position(&self) -> Duration {
+        self.0.position.as_ref().get()
+    }
+
+    /// Sets the duration of the video, which the `Video` element exposes in its `duration` property.
+    pub fn set_duration(&self, duration: Duration) {
+        self.0.duration.as_ref().set(duration);
+    }
+
+    /// Returns the duration of the video.
+    pub fn duration(&self) -> Duration {
+        self.0.duration.as_ref().get()
+    }
+
+    /// Returns the provider that controls the playback.
+    pub fn provider(&self) -> &dyn VideoFrameProvider {
+        &*self.0.provider
+    }
+}
+
+impl PartialEq for VideoSource {
+    fn eq(&self, other: &Self) -> bool {
+        vtable::VRc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// A VideoFrameSender passes frames to a [`VideoSource`] from any thread, for example from the thread
+/// that captures the frames of a camera. Create it with [`VideoSource::frame_sender()`].
+///
+/// Only the latest frame is shown: frames that are sent faster than the event loop processes them
+/// replace each other and never reach the user interface, so that a slow UI doesn't fall behind.
+#[cfg(feature = "std")]
+#[derive(Clone)]
+pub struct VideoFrameSender {
+    pending_frame: std::sync::Arc<std::sync::Mutex<Option<SharedPixelBuffer<Rgba8Pixel>>>>,
+    source: SendWeak,
+}
+
+#[cfg(feature = "std")]
+impl VideoFrameSender {
+    /// Sends a frame, which is shown by the next iteration of the event loop, unless a newer
+    /// frame is sent before.
+    pub fn send(
+        &self,
+        frame: SharedPixelBuffer<Rgba8Pixel>,
+    ) -> Result<(), crate::api::EventLoopError> {
+        let previous = self.pending_frame.lock().unwrap().replace(frame);
+        if previous.is_some() {
+            // The event loop wasn't woken up yet to show the previous frame, and will show this one instead
+            return Ok(());
+        }
+        let pending_frame = self.pending_frame.clone();
+        let source = self.source.clone();
+        crate::api::invoke_from_event_loop(move || {
+            let frame = pending_frame.lock().unwrap().take();
+            if let (Some(frame), Some(source)) = (frame, source.upgrade()) {
+                source.push_frame(VideoFrame::Rgba8(frame));
+            }
+        })
+    }
+}
+
+/// A weak reference to the data of a [`VideoSource`] that can be moved to other threads, but only
+/// upgraded in the thread that created it.
+#[cfg(feature = "std")]
+#[derive(Clone)]
+struct SendWeak {
+    weak: vtable::VWeak<OpaqueImageVTable, VideoSourceInner>,
+    thread: std::thread::ThreadId,
+}
+
+#[cfg(feature = "std")]
+impl SendWeak {
+    fn upgrade(&self) -> Option<VideoSource> {
+        if std::thread::current().id() != self.thread {
+            return None;
+        }
+        self.weak.upgrade().map(VideoSource)
+    }
+}
+
+// Safety: the weak reference is only upgraded in the thread that created it, and the VWeak only
+// uses atomic reference counts so it is safe to clone and drop in another thread.
+#[allow(unsafe_code)]
+#[cfg(feature = "std")]
+unsafe impl Send for SendWeak {}
+
+/// Converts a frame in the I420 format to RGB, using the BT.601 coefficients for limited range YUV.
+fn yuv420_to_rgb8(
+    width: u32,
+    height: u32,
+    y_plane: &[u8],
+    y_stride: usize,
+    u_plane: &[u8],
+    v_plane: &[u8],
+    uv_stride: usize,
+) -> SharedPixelBuffer<Rgb8Pixel> {
+    let mut buffer = SharedPixelBuffer::<Rgb8Pixel>::new(width, height);
+    let width = width as usize;
+    for (row, pixels) in buffer.make_mut_slice().chunks_exact_mut(width.max(1)).enumerate() {
+        let y_row = &y_plane[row * y_stride..];
+        let uv_offset = (row / 2) * uv_stride;
+        let (u_row, v_row) = (&u_plane[uv_offset..], &v_plane[uv_offset..]);
+        for (column, pixel) in pixels.iter_mut().enumerate() {
+            let c = (y_row[column] as i32 - 16) * 298;
+            let d = u_row[column / 2] as i32 - 128;
+            let e = v_row[column / 2] as i32 - 128;
+            let clamp = |value: i32| ((value + 128) >> 8).clamp(0, 255) as u8;
+            *pixel = Rgb8Pixel {
+                r: clamp(c + 409 * e),
+                g: clamp(c - 100 * d - 208 * e),
+                b: clamp(c + 516 * d),
+            };
+        }
+    }
+    buffer
+}
+
+#[test]
+fn test_yuv420_to_rgb8() {
+    let convert = |y: u8, u: u8, v: u8| yuv420_to_rgb8(1, 1, &[y], 1, &[u], &[v], 1).as_slice()[0];
+    assert_eq!(convert(235, 128, 128), Rgb8Pixel { r: 255, g: 255, b: 255 });
+    assert_eq!(convert(16, 128, 128), Rgb8Pixel { r: 0, g: 0, b: 0 });
+    assert_eq!(convert(81, 90, 240), Rgb8Pixel { r: 255, g: 0, b: 0 });
+
+    // The chroma planes are subsampled: the four pixels of a 2x2 block share their u and v values
+    let buffer =
+        yuv420_to_rgb8(3, 2, &[16, 235, 81, 0, 16, 235, 81, 0], 4, &[128, 90], &[128, 240], 2);
+    assert_eq!(
+        buffer.as_slice(),
+        [[0, 0, 0], [255, 255, 255], [255, 0, 0], [0, 0, 0], [255, 255, 255], [255, 0, 0]]
+            .map(|[r, g, b]| Rgb8Pixel { r, g, b })
+    );
+}
+
+#[test]
+fn test_frames_of_the_same_size_keep_the_layout() {
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+    let source = VideoSource::new_live();
+    let image = Image::from(source.clone());
+    let evaluations = Rc::new(Cell::new(0));
+    let size = Box::pin(Property::<IntSize>::default());
+    size.as_ref().set_binding({
+        let evaluations = evaluations.clone();
+        move || {
+            evaluations.set(evaluations.get() + 1);
+            image.size()
+        }
+    });
+    assert_eq!(size.as_ref().get(), IntSize::default());
+
+    source.push_frame(VideoFrame::Rgba8(SharedPixelBuffer::new(4, 3)));
+    assert_eq!(size.as_ref().get(), IntSize::new(4, 3));
+    source.push_frame(VideoFrame::Rgba8(SharedPixelBuffer::new(4, 3)));
+    assert_eq!(size.as_ref().get(), IntSize::new(4, 3));
+    assert_eq!(evaluations.get(), 2);
+    assert_eq!(source.current_frame().size(), IntSize::new(4, 3));
+}
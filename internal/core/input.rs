@@ -7,9 +7,9 @@
 
 use crate::item_tree::ItemTreeRc;
 use crate::item_tree::{ItemRc, ItemWeak, VisitChildrenResult};
-pub use crate::items::PointerEventButton;
 use crate::items::{ItemRef, TextCursorDirection};
 pub use crate::items::{KeyEvent, KeyboardModifiers};
+pub use crate::items::{PointerDevice, PointerEventButton};
 use crate::lengths::{LogicalPoint, LogicalVector};
 use crate::timers::Timer;
 use crate::window::{WindowAdapter, WindowInner};
@@ -21,6 +21,9 @@
 use core::cell::Cell;
 use core::pin::Pin;
 use core::time::Duration;
+#[cfg(not(feature = "std"))]
+#[allow(unused)]
+use num_traits::Float;
 
 /// A mouse or touch event
 ///
@@ -49,6 +52,25 @@ pub enum MouseEvent {
     Wheel { position: LogicalPoint, delta_x: Coord, delta_y: Coord },
     /// The mouse exited the item or component
     Exit,
+    /// Data is dragged over the item. The data can be queried with
+    /// [`WindowInner::drag_data()`](crate::window::WindowInner::drag_data)
+    DragMove { position: LogicalPoint },
+    /// Data was dropped on the item. The data can be queried with
+    /// [`WindowInner::drag_data()`](crate::window::WindowInner::drag_data)
+    Drop { position: LogicalPoint },
+    /// A pinch, rotation, or pan gesture of two fingers on a touchscreen or touchpad.
+    /// `position` is the center of the gesture. `scale` is the factor by which the distance
+    /// between the fingers changed, `rotation` is the angle in degrees by which the fingers rotated
+    /// clockwise, and `delta_x` and `delta_y` are the distance by which the center moved, since
+    /// the previous event of the gesture.
+    Gesture {
+        position: LogicalPoint,
+        scale: f32,
+        rotation: f32,
+        delta_x: Coord,
+        delta_y: Coord,
+        phase: GesturePhase,
+    },
 }
 
 impl MouseEvent {
@@ -59,6 +81,9 @@ pub fn position(&self) -> Option<LogicalPoint> {
             MouseEvent::Released { position, .. } => Some(*position),
             MouseEvent::Moved { position } => Some(*position),
             MouseEvent::Wheel { position, .. } => Some(*position),
+            MouseEvent::DragMove { position } => Some(*position),
+            MouseEvent::Drop { position } => Some(*position),
+            MouseEvent::Gesture { position, .. } => Some(*position),
             MouseEvent::Exit => None,
         }
     }
@@ -70,6 +95,9 @@ pub fn translate(&mut self, vec: LogicalVector) {
             MouseEvent::Released { position, .. } => Some(position),
             MouseEvent::Moved { position } => Some(position),
             MouseEvent::Wheel { position, .. } => Some(position),
+            MouseEvent::DragMove { position } => Some(position),
+            MouseEvent::Drop { position } => Some(position),
+            MouseEvent::Gesture { position, .. } => Some(position),
             MouseEvent::Exit => None,
         };
         if let Some(pos) = pos {
@@ -88,6 +116,182 @@ fn set_click_count(&mut self, count: u8) {
     }
 }
 
+/// The phase of a gesture of two fingers on a touchscreen or touchpad.
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum GesturePhase {
+    /// The gesture started. This is the first event of the gesture.
+    Started,
+    /// The fingers moved.
+    Updated,
+    /// The gesture ended, the fingers were lifted.
+    Ended,
+    /// The gesture was cancelled by the platform.
+    Cancelled,
+}
+
+/// The state of a pen or stylus, sent with the [`WindowEvent::PenPressed`](crate::platform::WindowEvent::PenPressed),
+/// [`WindowEvent::PenMoved`](crate::platform::WindowEvent::PenMoved), and
+/// [`WindowEvent::PenReleased`](crate::platform::WindowEvent::PenReleased) events.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PenState {
+    /// The pressure of the tip of the pen on the screen, from 0.0 to 1.0.
+    pub pressure: f32,
+    /// The angle in degrees between the pen and the screen in the plane of the X axis, from -90 to 90.
+    /// Positive when the top of the pen is tilted towards the right.
+    pub tilt_x: f32,
+    /// The angle in degrees between the pen and the screen in the plane of the Y axis, from -90 to 90.
+    /// Positive when the top of the pen is tilted towards the bottom.
+    pub tilt_y: f32,
+    /// True when the eraser end of the pen is used instead of its tip.
+    pub eraser: bool,
+    /// True while the barrel button on the side of the pen is pressed.
+    pub barrel_button: bool,
+}
+
+impl PenState {
+    /// The device reported in the `PointerEvent` of the `TouchArea`
+    pub(crate) fn device(&self) -> PointerDevice {
+        if self.eraser {
+            PointerDevice::Eraser
+        } else {
+            PointerDevice::Pen
+        }
+    }
+}
+
+/// Turns the touch points of a touchscreen into pointer events for the first touch point, and
+/// into [`MouseEvent::Gesture`] events while two touch points are down.
+#[derive(Default)]
+pub(crate) struct TouchState {
+    /// The id and position of the touch points that are down, in the order in which they were pressed
+    points: Vec<(u64, LogicalPoint)>,
+    mode: TouchMode,
+}
+
+#[derive(Default, Clone, Copy, PartialEq)]
+enum TouchMode {
+    /// The first touch point is delivered as the pointer
+    #[default]
+    Pointer,
+    /// The first two touch points make a gesture
+    Gesture,
+    /// The gesture ended, the touch points are ignored until they are all released
+    Ignored,
+}
+
+impl TouchState {
+    /// Returns the events for a touch point that was pressed
+    pub(crate) fn pressed(&mut self, id: u64, position: LogicalPoint) -> Vec<MouseEvent> {
+        self.points.retain(|(point_id, _)| *point_id != id);
+        self.points.push((id, position));
+        match (self.mode, self.points.len()) {
+            (TouchMode::Pointer, 1) => {
+                alloc::vec![MouseEvent::Pressed {
+                    position,
+                    button: PointerEventButton::Left,
+                    click_count: 0
+                }]
+            }
+            (TouchMode::Pointer, _) => {
+                self.mode = TouchMode::Gesture;
+                let (position, _, _) = self.gesture_geometry();
+                // The first touch point doesn't click
+                alloc::vec![
+                    MouseEvent::Exit,
+                    MouseEvent::Gesture {
+                        position,
+                        scale: 1.,
+                        rotation: 0.,
+                        delta_x: 0 as _,
+                        delta_y: 0 as _,
+                        phase: GesturePhase::Started,
+                    }
+                ]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns the events for a touch point that moved
+    pub(crate) fn moved(&mut self, id: u64, position: LogicalPoint) -> Vec<MouseEvent> {
+        let Some(index) = self.points.iter().position(|(point_id, _)| *point_id == id) else {
+            return Vec::new();
+        };
+        let (old_center, old_distance, old_angle) = self.gesture_geometry();
+        self.points[index].1 = position;
+        match self.mode {
+            TouchMode::Pointer => alloc::vec![MouseEvent::Moved { position }],
+            TouchMode::Gesture if index < 2 => {
+                let (center, distance, angle) = self.gesture_geometry();
+                let delta = center - old_center;
+                let mut rotation = angle - old_angle;
+                if rotation > 180. {
+                    rotation -= 360.;
+                } else if rotation < -180. {
+                    rotation += 360.;
+                }
+                alloc::vec![MouseEvent::Gesture {
+                    position: center,
+                    scale: if old_distance > 0. { distance / old_distance } else { 1. },
+                    rotation,
+                    delta_x: delta.x,
+                    delta_y: delta.y,
+                    phase: GesturePhase::Updated,
+                }]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns the events for a touch point that was released
+    pub(crate) fn released(&mut self, id: u64, position: LogicalPoint) -> Vec<MouseEvent> {
+        let Some(index) = self.points.iter().position(|(point_id, _)| *point_id == id) else {
+            return Vec::new();
+        };
+        let (center, _, _) = self.gesture_geometry();
+        self.points.remove(index);
+        let events = match self.mode {
+            TouchMode::Pointer => alloc::vec![MouseEvent::Released {
+                position,
+                button: PointerEventButton::Left,
+                click_count: 0
+            }],
+            TouchMode::Gesture if index < 2 => {
+                self.mode = TouchMode::Ignored;
+                alloc::vec![MouseEvent::Gesture {
+                    position: center,
+                    scale: 1.,
+                    rotation: 0.,
+                    delta_x: 0 as _,
+                    delta_y: 0 as _,
+                    phase: GesturePhase::Ended,
+                }]
+            }
+            _ => Vec::new(),
+        };
+        if self.points.is_empty() {
+            self.mode = TouchMode::Pointer;
+        }
+        events
+    }
+
+    /// Returns the center of the first two touch points, the distance between them, and the angle
+    /// in degrees of the line from the first to the second one
+    fn gesture_geometry(&self) -> (LogicalPoint, f32, f32) {
+        match self.points.as_slice() {
+            [(_, a), (_, b), ..] => {
+                let (dx, dy) = ((b.x - a.x) as f32, (b.y - a.y) as f32);
+                let center = LogicalPoint::new((a.x + b.x) / 2 as Coord, (a.y + b.y) / 2 as Coord);
+                (center, (dx * dx + dy * dy).sqrt(), dy.atan2(dx).to_degrees())
+            }
+            [(_, a)] => (*a, 0., 0.),
+            [] => (LogicalPoint::default(), 0., 0.),
+        }
+    }
+}
+
 /// This value is returned by the `input_event` function of an Item
 /// to notify the run-time about how the event was handled and
 /// what the next steps are.
@@ -542,7 +746,7 @@ pub struct MouseInputState {
     /// Offset to apply to the first item of the stack (used if there is a popup)
     pub(crate) offset: LogicalPoint,
     /// true if the top item of the stack has the mouse grab
-    grabbed: bool,
+    pub(crate) grabbed: bool,
     delayed: Option<(crate::timers::Timer, MouseEvent)>,
     delayed_exit_items: Vec<ItemWeak>,
 }
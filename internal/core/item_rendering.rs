@@ -207,6 +207,7 @@ pub fn render_item_children(renderer: &mut dyn ItemRenderer, component: &ItemTre
                // HACK, the geometry of the box shadow does not include the shadow, because when the shadow is the root for repeated elements it would translate the children
                || ItemRef::downcast_pin::<BoxShadow>(item).is_some()
             {
+                renderer.window().count_rendered_item();
                 item.as_ref().render(
                     &mut (renderer as &mut dyn ItemRenderer),
                     &item_rc,
@@ -243,6 +244,7 @@ pub fn render_component_items(
     render_item_children(renderer, component, -1);
 
     renderer.restore_state();
+    renderer.window().set_frame_rendering_metrics(renderer.metrics());
 }
 
 /// Compute the bounding rect of all children. This does /not/ include item's own bounding rect. Remember to run this
@@ -317,6 +319,18 @@ pub trait RenderText {
     fn overflow(self: Pin<&Self>) -> TextOverflow;
     fn letter_spacing(self: Pin<&Self>) -> LogicalLength;
     fn stroke(self: Pin<&Self>) -> (Brush, LogicalLength, TextStrokeStyle);
+    /// Returns the base direction of the paragraphs of the text.
+    fn text_direction(self: Pin<&Self>) -> TextDirection {
+        TextDirection::Auto
+    }
+    /// Returns true if the offset and rotation of each character is provided by [`Self::glyph_transform`].
+    fn has_glyph_transform(self: Pin<&Self>) -> bool {
+        false
+    }
+    /// Returns the offset and rotation of the character at the given index, counted in characters of [`Self::text`].
+    fn glyph_transform(self: Pin<&Self>, _index: usize) -> GlyphTransform {
+        GlyphTransform::default()
+    }
 }
 
 /// Trait used to render each items.
@@ -379,6 +393,30 @@ fn visit_layer(
         // Not supported
         RenderingResult::ContinueRenderingChildren
     }
+    fn visit_shader_effect(
+        &mut self,
+        _shader_effect_item: Pin<&ShaderEffect>,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        // Not supported: the children are rendered without the effect
+        static WARNED: portable_atomic::AtomicBool = portable_atomic::AtomicBool::new(false);
+        if !WARNED.swap(true, portable_atomic::Ordering::Relaxed) {
+            crate::debug_log!(
+                "Warning: ShaderEffect is only supported by the Skia renderer. Its children are rendered without the effect"
+            );
+        }
+        RenderingResult::ContinueRenderingChildren
+    }
+    fn visit_backdrop_blur(
+        &mut self,
+        _backdrop_blur_item: Pin<&BackdropBlur>,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        // Not supported: the content behind the element is not blurred
+        RenderingResult::ContinueRenderingChildren
+    }
 
     // Apply the bounds of the Clip element, if enabled. The default implementation calls
     // combine_clip, but the render may choose an alternate way of implementing the clip.
@@ -675,8 +713,9 @@ struct ComputeDirtyRegionState {
                             new_state.old_offset += old_geom.origin.to_vector();
                             if ItemRef::downcast_pin::<Clip>(item).is_some()
                                 || ItemRef::downcast_pin::<Opacity>(item).is_some()
+                                || ItemRef::downcast_pin::<ShaderEffect>(item).is_some()
                             {
-                                // When the opacity or the clip change, this will impact all the children, including
+                                // When the opacity, the clip, or the shader effect change, this will impact all the children, including
                                 // the ones outside the element, regardless if they are themselves dirty or not.
                                 new_state.must_refresh_children = true;
                             }
@@ -853,6 +892,8 @@ fn filter_item(&mut self, item_rc: &ItemRc) -> (bool, LogicalRect) {
 
     forward_rendering_call!(fn visit_clip(Clip) -> RenderingResult);
     forward_rendering_call!(fn visit_opacity(Opacity) -> RenderingResult);
+    forward_rendering_call!(fn visit_shader_effect(ShaderEffect) -> RenderingResult);
+    forward_rendering_call!(fn visit_backdrop_blur(BackdropBlur) -> RenderingResult);
 
     fn combine_clip(
         &mut self,
@@ -921,6 +962,10 @@ fn window(&self) -> &crate::window::WindowInner {
     fn as_any(&mut self) -> Option<&mut dyn core::any::Any> {
         self.actual_renderer.as_any()
     }
+
+    fn metrics(&self) -> crate::graphics::rendering_metrics_collector::RenderingMetrics {
+        self.actual_renderer.metrics()
+    }
 }
 
 /// This struct holds the state of the partial renderer between different frames, in particular the cache of the bounding rect
@@ -988,6 +1033,7 @@ pub fn apply_dirty_region<T: ItemRenderer>(
                 .union(&self.prev_frame_dirty.replace(partial_renderer.dirty_region.clone())),
         }
         .intersection(screen_region);
+        partial_renderer.window().set_frame_dirty_region(&partial_renderer.dirty_region);
     }
 
     /// Add the specified region to the list of regions to include in the next rendering.
@@ -20,6 +20,7 @@
 #![allow(non_upper_case_globals)]
 #![allow(missing_docs)] // because documenting each property of items is redundant
 
+use crate::api::LogicalPosition;
 use crate::graphics::{Brush, Color, Point};
 use crate::input::{
     FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEventResult,
@@ -47,10 +48,18 @@
 pub use flickable::Flickable;
 mod text;
 pub use text::*;
+mod rich_text;
+pub use rich_text::*;
 mod input_items;
 pub use input_items::*;
+mod drag_n_drop;
+pub use drag_n_drop::*;
+mod window_control;
+pub use window_control::*;
 mod image;
 pub use self::image::*;
+mod video;
+pub use self::video::*;
 #[cfg(feature = "std")]
 mod path;
 #[cfg(feature = "std")]
@@ -66,7 +75,11 @@
 type PointerEventArg = (PointerEvent,);
 type PointerScrollEventArg = (PointerScrollEvent,);
 type PointArg = (Point,);
+type StringArg = (SharedString,);
+type IntArg = (i32,);
 type MenuEntryArg = (MenuEntry,);
+type DropEventArg = (DropEvent,);
+type ImeActionArg = (ImeAction,);
 type MenuEntryModel = crate::model::ModelRc<MenuEntry>;
 
 #[cfg(all(feature = "ffi", windows))]
@@ -540,6 +553,22 @@ fn slint_get_FocusScopeVTable() -> FocusScopeVTable for FocusScope
     fn slint_get_SwipeGestureHandlerVTable() -> SwipeGestureHandlerVTable for SwipeGestureHandler
 }
 
+declare_item_vtable! {
+    fn slint_get_GestureAreaVTable() -> GestureAreaVTable for GestureArea
+}
+
+declare_item_vtable! {
+    fn slint_get_DragAreaVTable() -> DragAreaVTable for DragArea
+}
+
+declare_item_vtable! {
+    fn slint_get_DropAreaVTable() -> DropAreaVTable for DropArea
+}
+
+declare_item_vtable! {
+    fn slint_get_WindowControlAreaVTable() -> WindowControlAreaVTable for WindowControlArea
+}
+
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
 #[pin]
@@ -824,6 +853,216 @@ impl ItemConsts for Layer {
     fn slint_get_LayerVTable() -> LayerVTable for Layer
 }
 
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+/// The implementation of the `ShaderEffect` element
+pub struct ShaderEffect {
+    pub shader: Property<SharedString>,
+    /// The names of the uniforms and their number of values, such as `progress:1,tint:4`.
+    /// Their values are in the `uniform_N` properties, in the same order.
+    /// This is set by the compiler from the properties declared in the element.
+    pub uniform_names: Property<SharedString>,
+    pub uniform_0: Property<f32>,
+    pub uniform_1: Property<f32>,
+    pub uniform_2: Property<f32>,
+    pub uniform_3: Property<f32>,
+    pub uniform_4: Property<f32>,
+    pub uniform_5: Property<f32>,
+    pub uniform_6: Property<f32>,
+    pub uniform_7: Property<f32>,
+    pub uniform_8: Property<f32>,
+    pub uniform_9: Property<f32>,
+    pub uniform_10: Property<f32>,
+    pub uniform_11: Property<f32>,
+    pub uniform_12: Property<f32>,
+    pub uniform_13: Property<f32>,
+    pub uniform_14: Property<f32>,
+    pub uniform_15: Property<f32>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for ShaderEffect {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        backend: &mut ItemRendererRef,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        backend.visit_shader_effect(self, self_rc, size)
+    }
+}
+
+impl ItemConsts for ShaderEffect {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        ShaderEffect,
+        CachedRenderingData,
+    > = ShaderEffect::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl ShaderEffect {
+    /// The number of values that can be passed to the shader
+    pub const MAX_UNIFORM_VALUES: usize = 16;
+
+    /// Returns the name of each uniform with its values
+    pub fn uniforms(self: Pin<&Self>) -> alloc::vec::Vec<(SharedString, alloc::vec::Vec<f32>)> {
+        let values = [
+            self.uniform_0(),
+            self.uniform_1(),
+            self.uniform_2(),
+            self.uniform_3(),
+            self.uniform_4(),
+            self.uniform_5(),
+            self.uniform_6(),
+            self.uniform_7(),
+            self.uniform_8(),
+            self.uniform_9(),
+            self.uniform_10(),
+            self.uniform_11(),
+            self.uniform_12(),
+            self.uniform_13(),
+            self.uniform_14(),
+            self.uniform_15(),
+        ];
+        let mut values = values.into_iter();
+        self.uniform_names()
+            .split(',')
+            .filter_map(|uniform| {
+                let (name, count) = uniform.split_once(':')?;
+                Some((name.into(), values.by_ref().take(count.parse().ok()?).collect()))
+            })
+            .collect()
+    }
+}
+
+declare_item_vtable! {
+    fn slint_get_ShaderEffectVTable() -> ShaderEffectVTable for ShaderEffect
+}
+
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+/// The implementation of the `BackdropBlur` element
+pub struct BackdropBlur {
+    pub blur_radius: Property<LogicalLength>,
+    pub allow_approximation: Property<bool>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for BackdropBlur {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        backend: &mut ItemRendererRef,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        backend.visit_backdrop_blur(self, self_rc, size)
+    }
+}
+
+impl ItemConsts for BackdropBlur {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        BackdropBlur,
+        CachedRenderingData,
+    > = BackdropBlur::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+    fn slint_get_BackdropBlurVTable() -> BackdropBlurVTable for BackdropBlur
+}
+
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
 #[pin]
@@ -960,6 +1199,15 @@ pub struct WindowItem {
     pub default_font_family: Property<SharedString>,
     pub default_font_size: Property<LogicalLength>,
     pub default_font_weight: Property<i32>,
+    pub safe_area_inset_top: Property<LogicalLength>,
+    pub safe_area_inset_bottom: Property<LogicalLength>,
+    pub safe_area_inset_left: Property<LogicalLength>,
+    pub safe_area_inset_right: Property<LogicalLength>,
+    pub virtual_keyboard_height: Property<LogicalLength>,
+    pub back_progress: Property<f32>,
+    pub screen_orientation: Property<ScreenOrientation>,
+    pub device_posture: Property<DevicePosture>,
+    pub back_requested: Callback<VoidArg, EventResult>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -1233,6 +1481,10 @@ fn slint_get_ComplexTextVTable() -> ComplexTextVTable for ComplexText
     fn slint_get_SimpleTextVTable() -> SimpleTextVTable for SimpleText
 }
 
+declare_item_vtable! {
+    fn slint_get_RichTextVTable() -> RichTextVTable for RichText
+}
+
 declare_item_vtable! {
     fn slint_get_TextInputVTable() -> TextInputVTable for TextInput
 }
@@ -1245,6 +1497,14 @@ fn slint_get_ImageItemVTable() -> ImageItemVTable for ImageItem
     fn slint_get_ClippedImageVTable() -> ClippedImageVTable for ClippedImage
 }
 
+declare_item_vtable! {
+    fn slint_get_VideoItemVTable() -> VideoItemVTable for VideoItem
+}
+
+declare_item_vtable! {
+    fn slint_get_LiveFrameItemVTable() -> LiveFrameItemVTable for LiveFrameItem
+}
+
 #[cfg(feature = "std")]
 declare_item_vtable! {
     fn slint_get_PathVTable() -> PathVTable for Path
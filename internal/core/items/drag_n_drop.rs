@@ -0,0 +1,267 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use super::{
+    DropEvent, DropEventArg, Item, ItemConsts, ItemRc, ItemRendererRef, PointerEventButton,
+    RenderingResult,
+};
+use crate::input::{
+    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
+    KeyEventResult, MouseEvent,
+};
+use crate::item_rendering::CachedRenderingData;
+use crate::layout::{LayoutInfo, Orientation};
+use crate::lengths::{LogicalPoint, LogicalSize};
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::window::{WindowAdapter, WindowInner};
+use crate::{Callback, Property, SharedString};
+use alloc::rc::Rc;
+use const_field_offset::FieldOffsets;
+use core::cell::Cell;
+use core::pin::Pin;
+use i_slint_core_macros::*;
+#[cfg(not(feature = "std"))]
+#[allow(unused)]
+use num_traits::Float;
+
+/// The implementation of the `DragArea` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct DragArea {
+    pub enabled: Property<bool>,
+    pub mime_type: Property<SharedString>,
+    pub data: Property<SharedString>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+    // true when the left button is pressed and the drag hasn't started yet
+    pressed: Cell<bool>,
+    pressed_position: Cell<LogicalPoint>,
+}
+
+impl Item for DragArea {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        if !self.enabled() {
+            self.pressed.set(false);
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
+
+        match event {
+            MouseEvent::Pressed { position, button: PointerEventButton::Left, .. } => {
+                self.pressed.set(true);
+                self.pressed_position.set(position);
+                InputEventFilterResult::ForwardAndInterceptGrab
+            }
+            MouseEvent::Moved { position } => {
+                if self.pressed.get() && self.is_drag_distance(position) {
+                    InputEventFilterResult::Intercept
+                } else {
+                    InputEventFilterResult::ForwardAndInterceptGrab
+                }
+            }
+            MouseEvent::Released { button: PointerEventButton::Left, .. } | MouseEvent::Exit => {
+                self.pressed.set(false);
+                InputEventFilterResult::ForwardEvent
+            }
+            MouseEvent::Pressed { .. }
+            | MouseEvent::Released { .. }
+            | MouseEvent::Wheel { .. }
+            | MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. }
+            | MouseEvent::Gesture { .. } => InputEventFilterResult::ForwardAndIgnore,
+        }
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        match event {
+            MouseEvent::Pressed { button: PointerEventButton::Left, .. } => {
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::Moved { position } if self.pressed.get() => {
+                if !self.is_drag_distance(position) {
+                    return InputEventResult::GrabMouse;
+                }
+                self.pressed.set(false);
+                WindowInner::from_pub(window_adapter.window()).start_drag(DropEvent {
+                    mime_type: self.mime_type(),
+                    data: self.data(),
+                    position: Default::default(),
+                });
+                InputEventResult::EventIgnored
+            }
+            MouseEvent::Released { .. } | MouseEvent::Exit => {
+                self.pressed.set(false);
+                InputEventResult::EventIgnored
+            }
+            _ => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for DragArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl DragArea {
+    fn is_drag_distance(&self, position: LogicalPoint) -> bool {
+        let diff = position - self.pressed_position.get();
+        let threshold = super::flickable::DISTANCE_THRESHOLD.get();
+        diff.x.abs() > threshold || diff.y.abs() > threshold
+    }
+}
+
+/// The implementation of the `DropArea` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct DropArea {
+    pub enabled: Property<bool>,
+    pub contains_drag: Property<bool>,
+    pub can_drop: Callback<DropEventArg, bool>,
+    pub dropped: Callback<DropEventArg>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for DropArea {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        if !self.enabled() {
+            Self::FIELD_OFFSETS.contains_drag.apply_pin(self).set(false);
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
+        InputEventFilterResult::ForwardEvent
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        let window_inner = WindowInner::from_pub(window_adapter.window());
+        match event {
+            MouseEvent::DragMove { position } | MouseEvent::Drop { position } => {
+                let Some(mut drop_event) = window_inner.drag_data() else {
+                    return InputEventResult::EventIgnored;
+                };
+                drop_event.position = crate::lengths::logical_position_to_api(position);
+                let arg = (drop_event,);
+                let can_drop = !Self::FIELD_OFFSETS.can_drop.apply_pin(self).has_handler()
+                    || Self::FIELD_OFFSETS.can_drop.apply_pin(self).call(&arg);
+                if !can_drop {
+                    Self::FIELD_OFFSETS.contains_drag.apply_pin(self).set(false);
+                    return InputEventResult::EventIgnored;
+                }
+                window_inner.accept_drag();
+                if matches!(event, MouseEvent::Drop { .. }) {
+                    Self::FIELD_OFFSETS.contains_drag.apply_pin(self).set(false);
+                    Self::FIELD_OFFSETS.dropped.apply_pin(self).call(&arg);
+                } else {
+                    Self::FIELD_OFFSETS.contains_drag.apply_pin(self).set(true);
+                }
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Exit => {
+                Self::FIELD_OFFSETS.contains_drag.apply_pin(self).set(false);
+                InputEventResult::EventIgnored
+            }
+            _ => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for DropArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
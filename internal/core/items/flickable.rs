@@ -260,7 +260,10 @@ fn handle_mouse_filter(
                     InputEventFilterResult::ForwardEvent
                 }
             }
-            MouseEvent::Wheel { .. } => InputEventFilterResult::ForwardEvent,
+            MouseEvent::Wheel { .. }
+            | MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. }
+            | MouseEvent::Gesture { .. } => InputEventFilterResult::ForwardEvent,
             // Not the left button
             MouseEvent::Pressed { .. } | MouseEvent::Released { .. } => {
                 InputEventFilterResult::ForwardAndIgnore
@@ -358,6 +361,9 @@ fn handle_mouse(
                 }
                 InputEventResult::EventAccepted
             }
+            MouseEvent::DragMove { .. } | MouseEvent::Drop { .. } | MouseEvent::Gesture { .. } => {
+                InputEventResult::EventIgnored
+            }
         }
     }
 
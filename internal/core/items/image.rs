@@ -11,6 +11,8 @@
     ImageFit, ImageHorizontalAlignment, ImageRendering, ImageTiling, ImageVerticalAlignment, Item,
     ItemConsts, ItemRc, RenderingResult,
 };
+use crate::animations::Instant;
+use crate::graphics::{AnimatedImage, Image, ImageInner, OpaqueImageVTable};
 use crate::input::{
     FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
     KeyEventResult, MouseEvent,
@@ -21,11 +23,16 @@
 use crate::lengths::{LogicalLength, LogicalSize};
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
+use crate::timers::{Timer, TimerMode};
 use crate::window::WindowAdapter;
 use crate::{Brush, Coord, Property};
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
 use alloc::rc::Rc;
 use const_field_offset::FieldOffsets;
+use core::cell::{Cell, RefCell};
 use core::pin::Pin;
+use core::time::Duration;
 use i_slint_core_macros::*;
 
 #[repr(C)]
@@ -39,6 +46,13 @@ pub struct ImageItem {
     pub image_fit: Property<ImageFit>,
     pub image_rendering: Property<ImageRendering>,
     pub colorize: Property<Brush>,
+    pub playing: Property<bool>,
+    pub looping: Property<bool>,
+    pub nine_slice_top: Property<i32>,
+    pub nine_slice_right: Property<i32>,
+    pub nine_slice_bottom: Property<i32>,
+    pub nine_slice_left: Property<i32>,
+    data: ImagePlaybackDataBox,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -116,7 +130,16 @@ fn target_size(self: Pin<&Self>) -> LogicalSize {
     }
 
     fn source(self: Pin<&Self>) -> crate::graphics::Image {
-        self.source()
+        with_nine_slice_insets(
+            self.data.current_frame(self.source(), self.playing(), self.looping()),
+            self.image_fit(),
+            [
+                self.nine_slice_top(),
+                self.nine_slice_right(),
+                self.nine_slice_bottom(),
+                self.nine_slice_left(),
+            ],
+        )
     }
 
     fn source_clip(self: Pin<&Self>) -> Option<crate::graphics::IntRect> {
@@ -172,6 +195,15 @@ pub struct ClippedImage {
     pub horizontal_tiling: Property<ImageTiling>,
     pub vertical_tiling: Property<ImageTiling>,
 
+    pub nine_slice_top: Property<i32>,
+    pub nine_slice_right: Property<i32>,
+    pub nine_slice_bottom: Property<i32>,
+    pub nine_slice_left: Property<i32>,
+
+    pub playing: Property<bool>,
+    pub looping: Property<bool>,
+    data: ImagePlaybackDataBox,
+
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -253,7 +285,16 @@ fn target_size(self: Pin<&Self>) -> LogicalSize {
     }
 
     fn source(self: Pin<&Self>) -> crate::graphics::Image {
-        self.source()
+        with_nine_slice_insets(
+            self.data.current_frame(self.source(), self.playing(), self.looping()),
+            self.image_fit(),
+            [
+                self.nine_slice_top(),
+                self.nine_slice_right(),
+                self.nine_slice_bottom(),
+                self.nine_slice_left(),
+            ],
+        )
     }
 
     fn source_clip(self: Pin<&Self>) -> Option<crate::graphics::IntRect> {
@@ -292,3 +333,156 @@ impl ItemConsts for ClippedImage {
         CachedRenderingData,
     > = ClippedImage::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
 }
+
+/// Applies the nine-slice insets (top, right, bottom, left) to the source when the `image-fit` is `nine-slice`.
+/// Without insets, the nine-slice edges of the source, if any, are used.
+fn with_nine_slice_insets(mut source: Image, image_fit: ImageFit, insets: [i32; 4]) -> Image {
+    if image_fit == ImageFit::NineSlice && insets != [0; 4] {
+        let [top, right, bottom, left] = insets.map(|inset| inset.clamp(0, u16::MAX as i32) as u16);
+        source.set_nine_slice_edges(top, right, bottom, left);
+    }
+    source
+}
+
+/// The playback state of an animated image
+pub struct ImagePlaybackData {
+    /// The animated image being played
+    animation: RefCell<Option<vtable::VRc<OpaqueImageVTable, AnimatedImage>>>,
+    position: Cell<PlaybackPosition>,
+    timer: Timer,
+    /// Incremented by the timer when the next frame is due, so that the item is rendered again
+    next_frame: Pin<Rc<Property<u32>>>,
+}
+
+impl Default for ImagePlaybackData {
+    fn default() -> Self {
+        Self {
+            animation: Default::default(),
+            position: Default::default(),
+            timer: Default::default(),
+            next_frame: Rc::pin(Property::new(0)),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum PlaybackPosition {
+    /// Playing since the given instant, at which the first frame was shown
+    Playing(Instant),
+    /// Paused after playing for the given duration
+    Paused(Duration),
+}
+
+impl Default for PlaybackPosition {
+    fn default() -> Self {
+        Self::Paused(Duration::ZERO)
+    }
+}
+
+impl ImagePlaybackData {
+    /// Returns the frame of the source to show if it is animated, and schedules the timer for the next one.
+    fn current_frame(&self, source: Image, playing: bool, looping: bool) -> Image {
+        let (animation, nine_slice) = match <&ImageInner>::from(&source) {
+            ImageInner::AnimatedImage(animation) => (animation.clone(), None),
+            ImageInner::NineSlice(nine) => match &nine.0 {
+                ImageInner::AnimatedImage(animation) => (animation.clone(), Some(nine.1)),
+                _ => return self.stop(source),
+            },
+            _ => return self.stop(source),
+        };
+
+        // Read the property so that the item is rendered again when the timer fires
+        self.next_frame.as_ref().get();
+
+        let is_current =
+            self.animation.borrow().as_ref().is_some_and(|a| vtable::VRc::ptr_eq(a, &animation));
+        if !is_current {
+            *self.animation.borrow_mut() = Some(animation.clone());
+            self.position.set(PlaybackPosition::default());
+        }
+
+        let now = Instant::now();
+        let elapsed = match (self.position.get(), playing) {
+            (PlaybackPosition::Playing(start), true) => now.duration_since(start),
+            (PlaybackPosition::Playing(start), false) => {
+                let elapsed = now.duration_since(start);
+                self.position.set(PlaybackPosition::Paused(elapsed));
+                elapsed
+            }
+            (PlaybackPosition::Paused(elapsed), true) => {
+                self.position.set(PlaybackPosition::Playing(Instant(
+                    now.as_millis().saturating_sub(elapsed.as_millis() as u64),
+                )));
+                elapsed
+            }
+            (PlaybackPosition::Paused(elapsed), false) => elapsed,
+        };
+
+        let (index, next_frame_in) = animation.frame_at(elapsed, looping);
+        match next_frame_in {
+            // The time is measured from the start of the animation, so that the delays don't add up
+            Some(next_frame_in) if playing => {
+                let next_frame = self.next_frame.clone();
+                self.timer.start(TimerMode::SingleShot, next_frame_in, move || {
+                    next_frame.as_ref().set(next_frame.as_ref().get_untracked().wrapping_add(1));
+                });
+            }
+            _ => self.timer.stop(),
+        }
+
+        let mut frame = animation.frame(index);
+        if let Some([top, right, bottom, left]) = nine_slice {
+            frame.set_nine_slice_edges(top, right, bottom, left);
+        }
+        frame
+    }
+
+    fn stop(&self, source: Image) -> Image {
+        if self.animation.borrow_mut().take().is_some() {
+            self.timer.stop();
+            self.position.set(PlaybackPosition::default());
+        }
+        source
+    }
+}
+
+#[repr(C)]
+/// Wraps the playback state of the animated images
+pub struct ImagePlaybackDataBox(core::ptr::NonNull<ImagePlaybackData>);
+
+impl Default for ImagePlaybackDataBox {
+    fn default() -> Self {
+        ImagePlaybackDataBox(Box::leak(Box::<ImagePlaybackData>::default()).into())
+    }
+}
+impl Drop for ImagePlaybackDataBox {
+    fn drop(&mut self) {
+        // Safety: the self.0 was constructed from a Box::leak in ImagePlaybackDataBox::default
+        drop(unsafe { Box::from_raw(self.0.as_ptr()) });
+    }
+}
+
+impl core::ops::Deref for ImagePlaybackDataBox {
+    type Target = ImagePlaybackData;
+    fn deref(&self) -> &Self::Target {
+        // Safety: initialized in ImagePlaybackDataBox::default
+        unsafe { self.0.as_ref() }
+    }
+}
+
+/// # Safety
+/// This must be called using a non-null pointer pointing to a chunk of memory big enough to
+/// hold a ImagePlaybackDataBox
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_image_playback_data_init(data: *mut ImagePlaybackDataBox) {
+    core::ptr::write(data, ImagePlaybackDataBox::default());
+}
+
+/// # Safety
+/// This must be called using a non-null pointer pointing to an initialized ImagePlaybackDataBox
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_image_playback_data_free(data: *mut ImagePlaybackDataBox) {
+    core::ptr::drop_in_place(data);
+}
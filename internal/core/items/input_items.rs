@@ -2,13 +2,13 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 use super::{
-    EventResult, Item, ItemConsts, ItemRc, ItemRendererRef, KeyEventArg, MouseCursor, PointerEvent,
-    PointerEventArg, PointerEventButton, PointerEventKind, PointerScrollEvent,
-    PointerScrollEventArg, RenderingResult, VoidArg,
+    EventResult, Item, ItemConsts, ItemRc, ItemRendererRef, KeyEventArg, MouseCursor, PointArg,
+    PointerDevice, PointerEvent, PointerEventArg, PointerEventButton, PointerEventKind,
+    PointerScrollEvent, PointerScrollEventArg, RenderingResult, VoidArg,
 };
 use crate::api::LogicalPosition;
 use crate::input::{
-    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
+    FocusEvent, FocusEventResult, GesturePhase, InputEventFilterResult, InputEventResult, KeyEvent,
     KeyEventResult, KeyEventType, MouseEvent,
 };
 use crate::item_rendering::CachedRenderingData;
@@ -23,6 +23,9 @@
 use core::cell::Cell;
 use core::pin::Pin;
 use i_slint_core_macros::*;
+#[cfg(not(feature = "std"))]
+#[allow(unused)]
+use num_traits::Float;
 
 /// The implementation of the `TouchArea` element
 #[repr(C)]
@@ -74,11 +77,12 @@ fn input_event_filter_before_children(
             self.has_hover.set(false);
             if self.grabbed.replace(false) {
                 self.pressed.set(false);
-                Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
-                    button: PointerEventButton::Other,
-                    kind: PointerEventKind::Cancel,
-                    modifiers: window_adapter.window().0.modifiers.get().into(),
-                },));
+                let event = self.make_pointer_event(
+                    PointerEventButton::Other,
+                    PointerEventKind::Cancel,
+                    window_adapter,
+                );
+                Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(event,));
             }
             return InputEventFilterResult::ForwardAndIgnore;
         }
@@ -120,22 +124,20 @@ fn input_event(
                     Self::FIELD_OFFSETS.pressed_y.apply_pin(self).set(position.y_length());
                     Self::FIELD_OFFSETS.pressed.apply_pin(self).set(true);
                 }
-                Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
-                    button,
-                    kind: PointerEventKind::Down,
-                    modifiers: window_adapter.window().0.modifiers.get().into(),
-                },));
+                let event = self.make_pointer_event(button, PointerEventKind::Down, window_adapter);
+                Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(event,));
 
                 InputEventResult::GrabMouse
             }
             MouseEvent::Exit => {
                 Self::FIELD_OFFSETS.pressed.apply_pin(self).set(false);
                 if self.grabbed.replace(false) {
-                    Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
-                        button: PointerEventButton::Other,
-                        kind: PointerEventKind::Cancel,
-                        modifiers: window_adapter.window().0.modifiers.get().into(),
-                    },));
+                    let event = self.make_pointer_event(
+                        PointerEventButton::Other,
+                        PointerEventKind::Cancel,
+                        window_adapter,
+                    );
+                    Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(event,));
                 }
 
                 InputEventResult::EventAccepted
@@ -157,20 +159,18 @@ fn input_event(
                 if button == PointerEventButton::Left {
                     Self::FIELD_OFFSETS.pressed.apply_pin(self).set(false);
                 }
-                Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
-                    button,
-                    kind: PointerEventKind::Up,
-                    modifiers: window_adapter.window().0.modifiers.get().into(),
-                },));
+                let event = self.make_pointer_event(button, PointerEventKind::Up, window_adapter);
+                Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(event,));
 
                 InputEventResult::EventAccepted
             }
             MouseEvent::Moved { .. } => {
-                Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
-                    button: PointerEventButton::Other,
-                    kind: PointerEventKind::Move,
-                    modifiers: window_adapter.window().0.modifiers.get().into(),
-                },));
+                let event = self.make_pointer_event(
+                    PointerEventButton::Other,
+                    PointerEventKind::Move,
+                    window_adapter,
+                );
+                Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(event,));
                 return if self.grabbed.get() {
                     Self::FIELD_OFFSETS.moved.apply_pin(self).call(&());
                     InputEventResult::GrabMouse
@@ -199,6 +199,9 @@ fn input_event(
                     }
                 }
             }
+            MouseEvent::DragMove { .. } | MouseEvent::Drop { .. } | MouseEvent::Gesture { .. } => {
+                InputEventResult::EventIgnored
+            }
         }
     }
 
@@ -237,6 +240,34 @@ impl ItemConsts for TouchArea {
     > = TouchArea::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
 }
 
+impl TouchArea {
+    /// Returns the `PointerEvent` with the keyboard modifiers, and the device of the event that is dispatched
+    fn make_pointer_event(
+        self: Pin<&Self>,
+        button: PointerEventButton,
+        kind: PointerEventKind,
+        window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> PointerEvent {
+        let window = WindowInner::from_pub(window_adapter.window());
+        let (device, pen) = window.pointer_device.get();
+        let pressure = match device {
+            PointerDevice::Pen | PointerDevice::Eraser => pen.pressure,
+            _ if self.grabbed.get() => 0.5,
+            _ => 0.,
+        };
+        PointerEvent {
+            button,
+            kind,
+            modifiers: window.modifiers.get().into(),
+            device,
+            pressure,
+            tilt_x: pen.tilt_x,
+            tilt_y: pen.tilt_y,
+            barrel_button: pen.barrel_button,
+        }
+    }
+}
+
 /// A runtime item that exposes key
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
@@ -441,7 +472,10 @@ fn input_event_filter_before_children(
                     }
                 }
             }
-            MouseEvent::Wheel { .. } => InputEventFilterResult::ForwardAndIgnore,
+            MouseEvent::Wheel { .. }
+            | MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. }
+            | MouseEvent::Gesture { .. } => InputEventFilterResult::ForwardAndIgnore,
             // Not the left button
             MouseEvent::Pressed { .. } | MouseEvent::Released { .. } => {
                 InputEventFilterResult::ForwardAndIgnore
@@ -497,7 +531,10 @@ fn input_event(
                 Self::FIELD_OFFSETS.moved.apply_pin(self).call(&());
                 InputEventResult::GrabMouse
             }
-            MouseEvent::Wheel { .. } => InputEventResult::EventIgnored,
+            MouseEvent::Wheel { .. }
+            | MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. }
+            | MouseEvent::Gesture { .. } => InputEventResult::EventIgnored,
         }
     }
 
@@ -563,3 +600,264 @@ fn cancel_impl(self: Pin<&Self>) {
     let self_rc = ItemRc::new(self_component.clone(), self_index);
     s.cancel(window_adapter, &self_rc);
 }
+
+/// The implementation of the `GestureArea` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct GestureArea {
+    pub enabled: Property<bool>,
+    pub long_press_duration: Property<i64>,
+
+    pub active: Property<bool>,
+    pub scale: Property<f32>,
+    pub rotation: Property<f32>,
+    pub pan_x: Property<LogicalLength>,
+    pub pan_y: Property<LogicalLength>,
+    pub center: Property<LogicalPosition>,
+
+    pub started: Callback<VoidArg>,
+    pub updated: Callback<VoidArg>,
+    pub ended: Callback<VoidArg>,
+    pub cancelled: Callback<VoidArg>,
+    pub long_pressed: Callback<PointArg>,
+
+    // true while the left button is pressed and the long press timer is running
+    pressed: Cell<bool>,
+    pressed_position: Cell<LogicalPoint>,
+    // incremented to invalidate the running long press timer
+    long_press_generation: Cell<u32>,
+    // true when the long press was reported, until the button is released
+    long_press_triggered: Cell<bool>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for GestureArea {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {
+        Self::FIELD_OFFSETS.scale.apply_pin(self).set(1.);
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        if !self.enabled() {
+            self.cancel_long_press();
+            self.long_press_triggered.set(false);
+            self.finish_gesture(false);
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
+
+        match event {
+            MouseEvent::Pressed { position, button: PointerEventButton::Left, .. } => {
+                self.start_long_press(position, self_rc);
+                InputEventFilterResult::ForwardAndInterceptGrab
+            }
+            MouseEvent::Moved { position } => {
+                if self.pressed.get() {
+                    let distance = position - self.pressed_position.get();
+                    let threshold = super::flickable::DISTANCE_THRESHOLD.get();
+                    if distance.x.abs() > threshold || distance.y.abs() > threshold {
+                        self.cancel_long_press();
+                    }
+                }
+                InputEventFilterResult::ForwardAndInterceptGrab
+            }
+            MouseEvent::Released { button: PointerEventButton::Left, .. } => {
+                self.cancel_long_press();
+                if self.long_press_triggered.get() {
+                    // The children must not handle the release as a click
+                    InputEventFilterResult::Intercept
+                } else {
+                    InputEventFilterResult::ForwardEvent
+                }
+            }
+            MouseEvent::Exit => {
+                self.cancel_long_press();
+                self.long_press_triggered.set(false);
+                InputEventFilterResult::ForwardAndIgnore
+            }
+            MouseEvent::Gesture { .. } => {
+                self.cancel_long_press();
+                InputEventFilterResult::ForwardEvent
+            }
+            MouseEvent::Wheel { .. } | MouseEvent::DragMove { .. } | MouseEvent::Drop { .. } => {
+                InputEventFilterResult::ForwardAndIgnore
+            }
+            // Not the left button
+            MouseEvent::Pressed { .. } | MouseEvent::Released { .. } => {
+                InputEventFilterResult::ForwardAndIgnore
+            }
+        }
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        match event {
+            MouseEvent::Gesture { position, scale, rotation, delta_x, delta_y, phase } => {
+                match phase {
+                    GesturePhase::Started | GesturePhase::Updated => {
+                        let center = crate::lengths::logical_position_to_api(position);
+                        if !self.active() {
+                            Self::FIELD_OFFSETS.active.apply_pin(self).set(true);
+                            Self::FIELD_OFFSETS.scale.apply_pin(self).set(1.);
+                            Self::FIELD_OFFSETS.rotation.apply_pin(self).set(0.);
+                            Self::FIELD_OFFSETS.pan_x.apply_pin(self).set(LogicalLength::default());
+                            Self::FIELD_OFFSETS.pan_y.apply_pin(self).set(LogicalLength::default());
+                            Self::FIELD_OFFSETS.center.apply_pin(self).set(center);
+                            Self::FIELD_OFFSETS.started.apply_pin(self).call(&());
+                        }
+                        if phase == GesturePhase::Updated {
+                            Self::FIELD_OFFSETS.scale.apply_pin(self).set(self.scale() * scale);
+                            Self::FIELD_OFFSETS
+                                .rotation
+                                .apply_pin(self)
+                                .set(self.rotation() + rotation);
+                            Self::FIELD_OFFSETS
+                                .pan_x
+                                .apply_pin(self)
+                                .set(self.pan_x() + LogicalLength::new(delta_x));
+                            Self::FIELD_OFFSETS
+                                .pan_y
+                                .apply_pin(self)
+                                .set(self.pan_y() + LogicalLength::new(delta_y));
+                            Self::FIELD_OFFSETS.center.apply_pin(self).set(center);
+                            Self::FIELD_OFFSETS.updated.apply_pin(self).call(&());
+                        }
+                        InputEventResult::GrabMouse
+                    }
+                    GesturePhase::Ended | GesturePhase::Cancelled => {
+                        if self.finish_gesture(phase == GesturePhase::Ended) {
+                            InputEventResult::EventAccepted
+                        } else {
+                            InputEventResult::EventIgnored
+                        }
+                    }
+                }
+            }
+            MouseEvent::Pressed { button: PointerEventButton::Left, .. } => {
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::Moved { .. } => {
+                if self.active() || self.pressed.get() || self.long_press_triggered.get() {
+                    InputEventResult::GrabMouse
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+            MouseEvent::Released { button: PointerEventButton::Left, .. } => {
+                if self.long_press_triggered.replace(false) {
+                    InputEventResult::EventAccepted
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+            MouseEvent::Exit => {
+                self.cancel_long_press();
+                self.long_press_triggered.set(false);
+                self.finish_gesture(false);
+                InputEventResult::EventIgnored
+            }
+            MouseEvent::Pressed { .. }
+            | MouseEvent::Released { .. }
+            | MouseEvent::Wheel { .. }
+            | MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. } => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for GestureArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl GestureArea {
+    /// Starts the timer that reports a long press, unless the pointer moves or is released before
+    fn start_long_press(self: Pin<&Self>, position: LogicalPoint, self_rc: &ItemRc) {
+        let generation = self.long_press_generation.get().wrapping_add(1);
+        self.long_press_generation.set(generation);
+        self.pressed.set(true);
+        self.pressed_position.set(position);
+        self.long_press_triggered.set(false);
+        let self_weak = self_rc.downgrade();
+        crate::timers::Timer::single_shot(
+            core::time::Duration::from_millis(self.long_press_duration().max(0) as u64),
+            move || {
+                let Some(item) = self_weak.upgrade() else { return };
+                let Some(area) = item.downcast::<GestureArea>() else { return };
+                let area = area.as_pin_ref();
+                if area.long_press_generation.get() != generation || !area.pressed.replace(false) {
+                    return;
+                }
+                area.long_press_triggered.set(true);
+                Self::FIELD_OFFSETS
+                    .long_pressed
+                    .apply_pin(area)
+                    .call(&(area.pressed_position.get().to_untyped(),));
+            },
+        );
+    }
+
+    fn cancel_long_press(self: Pin<&Self>) {
+        if self.pressed.replace(false) {
+            self.long_press_generation.set(self.long_press_generation.get().wrapping_add(1));
+        }
+    }
+
+    /// Ends the gesture in progress, and returns false if there was none
+    fn finish_gesture(self: Pin<&Self>, completed: bool) -> bool {
+        if !self.active() {
+            return false;
+        }
+        Self::FIELD_OFFSETS.active.apply_pin(self).set(false);
+        if completed {
+            Self::FIELD_OFFSETS.ended.apply_pin(self).call(&());
+        } else {
+            Self::FIELD_OFFSETS.cancelled.apply_pin(self).call(&());
+        }
+        true
+    }
+}
@@ -0,0 +1,889 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+This module contains the `RichText` element, which renders a subset of markdown.
+
+The text is laid out in runs that share the same style, and each run is drawn with the
+`draw_text` function of the renderer, so that every renderer supports it.
+
+When adding an item or a property, it needs to be kept in sync with different place.
+Lookup the [`crate::items`] module documentation.
+*/
+use super::{
+    Item, ItemConsts, ItemRc, MouseCursor, PointerEventButton, RenderingResult, StringArg,
+    TextHorizontalAlignment, TextOverflow, TextStrokeStyle, TextVerticalAlignment, TextWrap,
+};
+use crate::graphics::{Brush, FontRequest, Image};
+use crate::input::{
+    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
+    KeyEventResult, MouseEvent,
+};
+use crate::item_rendering::{CachedRenderingData, ItemRenderer, RenderBorderRectangle, RenderText};
+use crate::layout::{LayoutInfo, Orientation};
+use crate::lengths::{
+    LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalRect, LogicalSize, ScaleFactor,
+    SizeLengths,
+};
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::window::{WindowAdapter, WindowInner};
+use crate::{Callback, Coord, Property, SharedString};
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::vec::Vec;
+use const_field_offset::FieldOffsets;
+use core::cell::Cell;
+use core::pin::Pin;
+#[allow(unused)]
+use euclid::num::Ceil;
+use i_slint_core_macros::*;
+
+/// The implementation of the `RichText` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct RichText {
+    pub width: Property<LogicalLength>,
+    pub height: Property<LogicalLength>,
+    pub text: Property<SharedString>,
+    pub font_size: Property<LogicalLength>,
+    pub font_weight: Property<i32>,
+    pub font_family: Property<SharedString>,
+    pub color: Property<Brush>,
+    pub link_color: Property<Brush>,
+    pub link_clicked: Callback<StringArg>,
+    /// A link was pressed, and the mouse is grabbed until it's released
+    pressed: Cell<bool>,
+    /// The mouse cursor was changed because the mouse is over a link
+    hovering_link: Cell<bool>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for RichText {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        orientation: Orientation,
+        window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        // Like a `Text` with word wrap, the rich text can be as narrow as needed, and its height
+        // depends on the width.
+        match orientation {
+            Orientation::Horizontal => {
+                let layout = self.layout(window_adapter, None);
+                LayoutInfo { preferred: layout.size.width.ceil(), ..LayoutInfo::default() }
+            }
+            Orientation::Vertical => {
+                let layout = self.layout(window_adapter, Some(self.width()));
+                let h = layout.size.height.ceil();
+                LayoutInfo { min: h, preferred: h, ..LayoutInfo::default() }
+            }
+        }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardEvent
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        let link_at = |position: LogicalPoint| {
+            self.layout(window_adapter, Some(self.width())).link_at(position)
+        };
+
+        match event {
+            MouseEvent::Pressed { position, button: PointerEventButton::Left, .. } => {
+                if link_at(position).is_some() {
+                    self.pressed.set(true);
+                    InputEventResult::GrabMouse
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+            MouseEvent::Released { position, button: PointerEventButton::Left, .. }
+                if self.pressed.replace(false) =>
+            {
+                if let Some(link) = link_at(position) {
+                    Self::FIELD_OFFSETS.link_clicked.apply_pin(self).call(&(link,));
+                }
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Moved { position } => {
+                let over_link = link_at(position).is_some();
+                if over_link != self.hovering_link.replace(over_link) {
+                    if let Some(x) = window_adapter.internal(crate::InternalToken) {
+                        x.set_mouse_cursor(if over_link {
+                            MouseCursor::Pointer
+                        } else {
+                            MouseCursor::Default
+                        });
+                    }
+                }
+                if self.pressed.get() {
+                    InputEventResult::GrabMouse
+                } else if over_link {
+                    InputEventResult::EventAccepted
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+            MouseEvent::Exit => {
+                self.pressed.set(false);
+                if self.hovering_link.replace(false) {
+                    if let Some(x) = window_adapter.internal(crate::InternalToken) {
+                        x.set_mouse_cursor(MouseCursor::Default);
+                    }
+                }
+                InputEventResult::EventAccepted
+            }
+            _ => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        backend: &mut &mut dyn ItemRenderer,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        let window_adapter = (*backend).window().window_adapter();
+        let layout = self.layout(&window_adapter, Some(size.width_length()));
+        let color = self.color();
+        let link_color = self.link_color();
+
+        for run in &layout.runs {
+            (*backend).save_state();
+            (*backend).translate(run.rect.origin.to_vector());
+            match &run.content {
+                RunContent::Text { text, font_request, link, code } => {
+                    if *code {
+                        let background = CodeBackground(color.color().with_alpha(0.15).into());
+                        (*backend).draw_border_rectangle(
+                            Pin::<&CodeBackground>::new(&background),
+                            self_rc,
+                            run.rect.size,
+                            &self.cached_rendering_data,
+                        );
+                    }
+                    let text_run = TextRun {
+                        text: text.clone(),
+                        font_request: font_request.clone(),
+                        color: if link.is_some() { link_color.clone() } else { color.clone() },
+                        item: None,
+                    };
+                    // Give the glyphs some room, the renderers clip them to the size of the run
+                    let slack = font_request.pixel_size.unwrap_or_default().get() / 2 as Coord;
+                    (*backend).draw_text(
+                        Pin::<&TextRun>::new(&text_run),
+                        self_rc,
+                        LogicalSize::new(run.rect.width() + slack, run.rect.height()),
+                        &self.cached_rendering_data,
+                    );
+                }
+                RunContent::Image(image) => (*backend).draw_image_direct(image.clone()),
+            }
+            (*backend).restore_state();
+        }
+
+        // The partial renderer records the properties read during the last draw call of an item.
+        // Draw an empty run over the whole item that reads the properties the layout depends on.
+        let tracking_run = TextRun {
+            text: SharedString::default(),
+            font_request: FontRequest::default(),
+            color: Brush::default(),
+            item: Some(self),
+        };
+        (*backend).draw_text(
+            Pin::<&TextRun>::new(&tracking_run),
+            self_rc,
+            size,
+            &self.cached_rendering_data,
+        );
+
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for RichText {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        RichText,
+        CachedRenderingData,
+    > = RichText::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl RichText {
+    fn font_request(self: Pin<&Self>, window: &WindowInner) -> FontRequest {
+        let window_item = window.window_item();
+
+        FontRequest {
+            family: {
+                let maybe_family = self.font_family();
+                if !maybe_family.is_empty() {
+                    Some(maybe_family)
+                } else {
+                    window_item.as_ref().and_then(|item| item.as_pin_ref().font_family())
+                }
+            },
+            weight: {
+                let weight = self.font_weight();
+                if weight == 0 {
+                    window_item.as_ref().and_then(|item| item.as_pin_ref().font_weight())
+                } else {
+                    Some(weight)
+                }
+            },
+            pixel_size: {
+                let font_size = self.font_size();
+                if font_size.get() == 0 as Coord {
+                    window_item.as_ref().and_then(|item| item.as_pin_ref().font_size())
+                } else {
+                    Some(font_size)
+                }
+            },
+            letter_spacing: None,
+            italic: false,
+            features: Default::default(),
+            variations: Default::default(),
+        }
+    }
+
+    fn layout(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        max_width: Option<LogicalLength>,
+    ) -> RichTextLayout {
+        let window_inner = WindowInner::from_pub(window_adapter.window());
+        let mut layouter = Layouter {
+            window_adapter,
+            scale_factor: ScaleFactor::new(window_inner.scale_factor()),
+            max_width: max_width.map(|w| w.get()),
+            runs: Vec::new(),
+            line_start: 0,
+            indent: 0 as Coord,
+            x: 0 as Coord,
+            y: 0 as Coord,
+            line_height: 0 as Coord,
+            width: 0 as Coord,
+            pending_space: false,
+            can_extend: false,
+        };
+        layouter.layout_blocks(&parse_blocks(&self.text()), self.font_request(window_inner));
+        RichTextLayout { size: LogicalSize::new(layouter.width, layouter.y), runs: layouter.runs }
+    }
+}
+
+/// Draws a run of text, or reads the properties of the item if `item` is set
+struct TextRun<'a> {
+    text: SharedString,
+    font_request: FontRequest,
+    color: Brush,
+    item: Option<Pin<&'a RichText>>,
+}
+
+impl RenderText for TextRun<'_> {
+    fn target_size(self: Pin<&Self>) -> LogicalSize {
+        LogicalSize::default()
+    }
+
+    fn text(self: Pin<&Self>) -> SharedString {
+        if let Some(item) = self.item {
+            item.text();
+            item.font_size();
+            item.font_weight();
+            item.font_family();
+            item.color();
+            item.link_color();
+        }
+        self.text.clone()
+    }
+
+    fn font_request(self: Pin<&Self>, _window: &WindowInner) -> FontRequest {
+        self.font_request.clone()
+    }
+
+    fn color(self: Pin<&Self>) -> Brush {
+        self.color.clone()
+    }
+
+    fn alignment(self: Pin<&Self>) -> (TextHorizontalAlignment, TextVerticalAlignment) {
+        (TextHorizontalAlignment::Left, TextVerticalAlignment::Top)
+    }
+
+    fn wrap(self: Pin<&Self>) -> TextWrap {
+        TextWrap::NoWrap
+    }
+
+    fn overflow(self: Pin<&Self>) -> TextOverflow {
+        TextOverflow::Clip
+    }
+
+    fn letter_spacing(self: Pin<&Self>) -> LogicalLength {
+        LogicalLength::default()
+    }
+
+    fn stroke(self: Pin<&Self>) -> (Brush, LogicalLength, TextStrokeStyle) {
+        Default::default()
+    }
+}
+
+/// The background of inline code
+struct CodeBackground(Brush);
+
+impl RenderBorderRectangle for CodeBackground {
+    fn background(self: Pin<&Self>) -> Brush {
+        self.0.clone()
+    }
+
+    fn border_width(self: Pin<&Self>) -> LogicalLength {
+        LogicalLength::default()
+    }
+
+    fn border_radius(self: Pin<&Self>) -> LogicalBorderRadius {
+        LogicalBorderRadius::new_uniform(3 as Coord)
+    }
+
+    fn border_color(self: Pin<&Self>) -> Brush {
+        Brush::default()
+    }
+}
+
+enum RunContent {
+    Text { text: SharedString, font_request: FontRequest, link: Option<SharedString>, code: bool },
+    Image(Image),
+}
+
+struct Run {
+    rect: LogicalRect,
+    content: RunContent,
+    link: Option<SharedString>,
+}
+
+struct RichTextLayout {
+    runs: Vec<Run>,
+    size: LogicalSize,
+}
+
+impl RichTextLayout {
+    fn link_at(&self, position: LogicalPoint) -> Option<SharedString> {
+        self.runs.iter().find(|run| run.rect.contains(position)).and_then(|run| run.link.clone())
+    }
+}
+
+/// Places the words of the blocks in lines, and breaks the lines at `max_width`
+struct Layouter<'a> {
+    window_adapter: &'a Rc<dyn WindowAdapter>,
+    scale_factor: ScaleFactor,
+    max_width: Option<Coord>,
+    runs: Vec<Run>,
+    /// The index of the first run of the current line
+    line_start: usize,
+    indent: Coord,
+    x: Coord,
+    y: Coord,
+    line_height: Coord,
+    width: Coord,
+    /// There was white space since the last word
+    pending_space: bool,
+    /// The next word directly follows the last run, which can be extended
+    can_extend: bool,
+}
+
+impl Layouter<'_> {
+    fn measure(&self, font_request: &FontRequest, text: &str) -> LogicalSize {
+        self.window_adapter.renderer().text_size(
+            font_request.clone(),
+            text,
+            None,
+            self.scale_factor,
+            TextWrap::NoWrap,
+        )
+    }
+
+    fn layout_blocks(&mut self, blocks: &[Block], base_font: FontRequest) {
+        let line_height = self.measure(&base_font, " ").height;
+        let em = base_font.pixel_size.map_or(line_height, |size| size.get());
+
+        for (index, block) in blocks.iter().enumerate() {
+            let is_list_item = matches!(block.kind, BlockKind::ListItem { .. });
+            if index > 0
+                && !(is_list_item && matches!(blocks[index - 1].kind, BlockKind::ListItem { .. }))
+            {
+                self.y += line_height / 2 as Coord;
+            }
+
+            let mut font = base_font.clone();
+            self.indent = 0 as Coord;
+            match &block.kind {
+                BlockKind::Paragraph => {}
+                BlockKind::Heading(level) => {
+                    let scale = match level {
+                        1 => 2.,
+                        2 => 1.5,
+                        _ => 1.25,
+                    };
+                    font.pixel_size = Some(LogicalLength::new((em as f32 * scale) as Coord));
+                    font.weight = Some(700);
+                }
+                BlockKind::ListItem { level, marker } => {
+                    let gap = em / 2 as Coord;
+                    let marker_width = self.measure(&base_font, marker).width;
+                    // The marker is right aligned in a column that is at least two em wide
+                    self.indent = *level as Coord * em * 2 as Coord
+                        + (em * 2 as Coord).max(marker_width + gap);
+                    self.x = self.indent - gap - marker_width;
+                    self.pending_space = false;
+                    self.push_text(marker, &base_font, &Style::default());
+                    self.can_extend = false;
+                }
+            }
+
+            self.x = self.indent;
+            self.pending_space = false;
+            for span in parse_inline(&block.text) {
+                match span {
+                    Span::Text(text, style) => self.add_text(&text, &font, &style),
+                    Span::Image { source, alt, link } => match load_image(&source) {
+                        Some(image) => self.add_image(image, link),
+                        None => self.add_text(
+                            &alt,
+                            &font,
+                            &Style { italic: true, link, ..Style::default() },
+                        ),
+                    },
+                }
+            }
+            if self.line_height == 0 as Coord {
+                self.line_height = line_height;
+            }
+            self.finish_line();
+        }
+    }
+
+    fn space_width(&self, font_request: &FontRequest) -> Coord {
+        self.measure(font_request, "x x").width - self.measure(font_request, "xx").width
+    }
+
+    fn add_text(&mut self, text: &str, font: &FontRequest, style: &Style) {
+        let mut font = font.clone();
+        if style.bold {
+            font.weight = Some(700);
+        }
+        font.italic = style.italic;
+        if style.code {
+            font.family = Some("monospace".into());
+            // Inline code is not broken into lines, but kept as one run
+            return self.push_text(text, &font, style);
+        }
+
+        if text.starts_with(char::is_whitespace) {
+            self.pending_space = true;
+        }
+        for (index, word) in text.split_whitespace().enumerate() {
+            if index > 0 {
+                self.pending_space = true;
+            }
+            self.push_text(word, &font, style);
+        }
+        if text.ends_with(char::is_whitespace) {
+            self.pending_space = true;
+        }
+    }
+
+    /// Returns the position of an item that is `width` wide, and starts a new line if it
+    /// doesn't fit. The returned boolean is true if there is a space before the item.
+    fn place(&mut self, width: Coord, font: &FontRequest) -> (Coord, bool) {
+        let mut space = if self.pending_space && self.x > self.indent {
+            self.space_width(font)
+        } else {
+            0 as Coord
+        };
+        self.pending_space = false;
+        if let Some(max_width) = self.max_width {
+            if self.x > self.indent && self.x + space + width > max_width {
+                self.finish_line();
+                space = 0 as Coord;
+            }
+        }
+        let x = self.x + space;
+        self.x = x + width;
+        self.width = self.width.max(self.x);
+        (x, space > 0 as Coord)
+    }
+
+    fn push_text(&mut self, text: &str, font: &FontRequest, style: &Style) {
+        if text.is_empty() {
+            return;
+        }
+        let size = self.measure(font, text);
+        let (x, space_before) = self.place(size.width, font);
+        self.line_height = self.line_height.max(size.height);
+
+        // Extend the previous run if it has the same style
+        if let (
+            true,
+            Some(Run {
+                rect,
+                content: RunContent::Text { text: previous, font_request, link, code: false },
+                ..
+            }),
+        ) = (self.can_extend, self.runs[self.line_start..].last_mut())
+        {
+            if !style.code && *font_request == *font && *link == style.link {
+                let mut extended = String::from(previous.as_str());
+                if space_before {
+                    extended.push(' ');
+                }
+                extended.push_str(text);
+                *previous = extended.into();
+                rect.size.width = x + size.width - rect.origin.x;
+                rect.size.height = rect.size.height.max(size.height);
+                return;
+            }
+        }
+        self.can_extend = true;
+
+        self.runs.push(Run {
+            rect: LogicalRect::new(LogicalPoint::new(x, self.y), size),
+            content: RunContent::Text {
+                text: text.into(),
+                font_request: font.clone(),
+                link: style.link.clone(),
+                code: style.code,
+            },
+            link: style.link.clone(),
+        });
+    }
+
+    fn add_image(&mut self, image: Image, link: Option<SharedString>) {
+        let size = image.size();
+        let size = LogicalSize::new(size.width as Coord, size.height as Coord);
+        let (x, _) = self.place(size.width, &FontRequest::default());
+        self.line_height = self.line_height.max(size.height);
+        self.runs.push(Run {
+            rect: LogicalRect::new(LogicalPoint::new(x, self.y), size),
+            content: RunContent::Image(image),
+            link,
+        });
+        self.can_extend = false;
+    }
+
+    /// Aligns the runs of the current line at the bottom, and moves to the next line
+    fn finish_line(&mut self) {
+        for run in &mut self.runs[self.line_start..] {
+            run.rect.origin.y = self.y + self.line_height - run.rect.height();
+        }
+        self.y += self.line_height;
+        self.line_height = 0 as Coord;
+        self.line_start = self.runs.len();
+        self.x = self.indent;
+    }
+}
+
+#[cfg(feature = "std")]
+fn load_image(source: &str) -> Option<Image> {
+    Image::load_from_path(std::path::Path::new(source)).ok().filter(|image| {
+        let size = image.size();
+        size.width > 0 && size.height > 0
+    })
+}
+
+#[cfg(not(feature = "std"))]
+fn load_image(_source: &str) -> Option<Image> {
+    None
+}
+
+#[derive(Debug, PartialEq)]
+enum BlockKind {
+    Paragraph,
+    Heading(u8),
+    ListItem { level: usize, marker: SharedString },
+}
+
+#[derive(Debug, PartialEq)]
+struct Block {
+    kind: BlockKind,
+    /// The inline markdown of the block
+    text: String,
+}
+
+/// Splits the markdown into paragraphs, headings, and list items.
+///
+/// Paragraphs are separated by empty lines. A line that starts with `-`, `*`, or `+`, or with a number
+/// followed by a period, starts a list item; list items are nested by indenting them by two spaces.
+fn parse_blocks(markdown: &str) -> Vec<Block> {
+    let mut blocks: Vec<Block> = Vec::new();
+    // Whether the next line continues the last block
+    let mut open = false;
+
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            open = false;
+            continue;
+        }
+        let indentation = line.len() - line.trim_start().len();
+
+        let heading_level = trimmed.bytes().take_while(|b| *b == b'#').count();
+        if (1..=3).contains(&heading_level) && trimmed[heading_level..].starts_with(' ') {
+            blocks.push(Block {
+                kind: BlockKind::Heading(heading_level as u8),
+                text: trimmed[heading_level..].trim().into(),
+            });
+            open = false;
+        } else if let Some((marker, text)) = list_marker(trimmed) {
+            blocks.push(Block {
+                kind: BlockKind::ListItem { level: indentation / 2, marker },
+                text: text.trim().into(),
+            });
+            open = true;
+        } else if let (true, Some(block)) = (open, blocks.last_mut()) {
+            block.text.push(' ');
+            block.text.push_str(trimmed);
+        } else {
+            blocks.push(Block { kind: BlockKind::Paragraph, text: trimmed.into() });
+            open = true;
+        }
+    }
+
+    blocks
+}
+
+fn list_marker(line: &str) -> Option<(SharedString, &str)> {
+    if let Some(text) = line
+        .strip_prefix("- ")
+        .or_else(|| line.strip_prefix("* "))
+        .or_else(|| line.strip_prefix("+ "))
+    {
+        return Some(("•".into(), text));
+    }
+    let digits = line.bytes().take_while(u8::is_ascii_digit).count();
+    if digits > 0 && line[digits..].starts_with(". ") {
+        return Some((line[..digits + 1].into(), &line[digits + 2..]));
+    }
+    None
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Style {
+    bold: bool,
+    italic: bool,
+    code: bool,
+    link: Option<SharedString>,
+}
+
+#[derive(Debug, PartialEq)]
+enum Span {
+    Text(String, Style),
+    Image { source: SharedString, alt: String, link: Option<SharedString> },
+}
+
+/// Parses the inline markdown of a block: `**bold**`, `*italic*`, `` `code` ``,
+/// `[links](url)`, and `![images](path)`. Delimiters without a match are kept as text.
+fn parse_inline(text: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+    parse_inline_with_style(text, &Style::default(), &mut spans);
+    spans
+}
+
+fn parse_inline_with_style(text: &str, style: &Style, spans: &mut Vec<Span>) {
+    let mut current = String::new();
+    let mut rest = text;
+    let flush = |current: &mut String, spans: &mut Vec<Span>| {
+        if !current.is_empty() {
+            spans.push(Span::Text(core::mem::take(current), style.clone()));
+        }
+    };
+
+    while let Some(c) = rest.chars().next() {
+        let after = &rest[c.len_utf8()..];
+        let previous_is_alphanumeric = current.chars().last().is_some_and(char::is_alphanumeric);
+
+        if c == '\\' {
+            if let Some(escaped) = after.chars().next().filter(char::is_ascii_punctuation) {
+                current.push(escaped);
+                rest = &after[escaped.len_utf8()..];
+                continue;
+            }
+        } else if c == '`' {
+            if let Some(end) = after.find('`') {
+                flush(&mut current, spans);
+                spans.push(Span::Text(after[..end].into(), Style { code: true, ..style.clone() }));
+                rest = &after[end + 1..];
+                continue;
+            }
+        } else if c == '!' && after.starts_with('[') {
+            if let Some((alt, url, len)) = parse_link(after) {
+                flush(&mut current, spans);
+                spans.push(Span::Image {
+                    source: url.into(),
+                    alt: alt.into(),
+                    link: style.link.clone(),
+                });
+                rest = &after[len..];
+                continue;
+            }
+        } else if c == '[' {
+            if let Some((label, url, len)) = parse_link(rest) {
+                flush(&mut current, spans);
+                let link_style = Style { link: Some(url.into()), ..style.clone() };
+                parse_inline_with_style(label, &link_style, spans);
+                rest = &rest[len..];
+                continue;
+            }
+        } else if (c == '*' || c == '_') && !(c == '_' && previous_is_alphanumeric) {
+            let double = if c == '*' { "**" } else { "__" };
+            if let Some(inner) = rest.strip_prefix(double) {
+                if let Some(end) = inner.find(double).filter(|end| *end > 0) {
+                    flush(&mut current, spans);
+                    let bold_style = Style { bold: true, ..style.clone() };
+                    parse_inline_with_style(&inner[..end], &bold_style, spans);
+                    rest = &inner[end + 2..];
+                    continue;
+                }
+            } else if let Some(end) = (!after.starts_with(char::is_whitespace))
+                .then(|| find_single_delimiter(after, c))
+                .flatten()
+                .filter(|end| *end > 0)
+            {
+                flush(&mut current, spans);
+                let italic_style = Style { italic: true, ..style.clone() };
+                parse_inline_with_style(&after[..end], &italic_style, spans);
+                rest = &after[end + 1..];
+                continue;
+            }
+        }
+
+        current.push(c);
+        rest = after;
+    }
+
+    flush(&mut current, spans);
+}
+
+/// Parses `[label](url)` at the start of `text`, and returns the label, the url, and the length
+fn parse_link(text: &str) -> Option<(&str, &str, usize)> {
+    let label_end = text.find(']')?;
+    let url_part = text[label_end + 1..].strip_prefix('(')?;
+    let url_end = url_part.find(')')?;
+    Some((&text[1..label_end], url_part[..url_end].trim(), label_end + url_end + 3))
+}
+
+/// Finds the closing delimiter of an italic span, skipping doubled delimiters that belong to a bold
+/// span, and delimiters that follow white space
+fn find_single_delimiter(text: &str, delimiter: char) -> Option<usize> {
+    let mut chars = text.char_indices().peekable();
+    let mut previous = ' ';
+    while let Some((index, c)) = chars.next() {
+        if c == '\\' {
+            chars.next();
+        } else if c == delimiter {
+            if chars.peek().is_some_and(|(_, next)| *next == delimiter) {
+                chars.next();
+            } else if !previous.is_whitespace() {
+                return Some(index);
+            }
+        }
+        previous = c;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blocks() {
+        let blocks = parse_blocks(
+            "# Title\nFirst line\nsecond line\n\n- one\n  - nested\ncontinued\n10. ten\n\nEnd",
+        );
+        assert_eq!(
+            blocks,
+            [
+                Block { kind: BlockKind::Heading(1), text: "Title".into() },
+                Block { kind: BlockKind::Paragraph, text: "First line second line".into() },
+                Block {
+                    kind: BlockKind::ListItem { level: 0, marker: "•".into() },
+                    text: "one".into()
+                },
+                Block {
+                    kind: BlockKind::ListItem { level: 1, marker: "•".into() },
+                    text: "nested continued".into()
+                },
+                Block {
+                    kind: BlockKind::ListItem { level: 0, marker: "10.".into() },
+                    text: "ten".into()
+                },
+                Block { kind: BlockKind::Paragraph, text: "End".into() },
+            ]
+        );
+    }
+
+    #[test]
+    fn inline() {
+        let bold = Style { bold: true, ..Style::default() };
+        let italic = Style { italic: true, ..Style::default() };
+        let code = Style { code: true, ..Style::default() };
+        let link = Style { link: Some("https://slint.dev".into()), ..Style::default() };
+
+        assert_eq!(
+            parse_inline("Some **bold** and *italic* `co*de`"),
+            [
+                Span::Text("Some ".into(), Style::default()),
+                Span::Text("bold".into(), bold.clone()),
+                Span::Text(" and ".into(), Style::default()),
+                Span::Text("italic".into(), italic.clone()),
+                Span::Text(" ".into(), Style::default()),
+                Span::Text("co*de".into(), code),
+            ]
+        );
+
+        assert_eq!(
+            parse_inline("A [**bold** link](https://slint.dev) ![logo](logo.png)"),
+            [
+                Span::Text("A ".into(), Style::default()),
+                Span::Text("bold".into(), Style { bold: true, ..link.clone() }),
+                Span::Text(" link".into(), link),
+                Span::Text(" ".into(), Style::default()),
+                Span::Image { source: "logo.png".into(), alt: "logo".into(), link: None },
+            ]
+        );
+
+        assert_eq!(
+            parse_inline("snake_case_name, 2 * 3 * 4, \\*not italic\\*, _italic_"),
+            [
+                Span::Text("snake_case_name, 2 * 3 * 4, *not italic*, ".into(), Style::default()),
+                Span::Text("italic".into(), italic),
+            ]
+        );
+    }
+}
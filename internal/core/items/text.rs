@@ -8,10 +8,10 @@
 Lookup the [`crate::items`] module documentation.
 */
 use super::{
-    EventResult, FontMetrics, InputType, Item, ItemConsts, ItemRc, ItemRef, KeyEventArg,
-    KeyEventResult, KeyEventType, PointArg, PointerEventButton, RenderingResult,
-    TextHorizontalAlignment, TextOverflow, TextStrokeStyle, TextVerticalAlignment, TextWrap,
-    VoidArg,
+    EventResult, FontMetrics, GlyphTransform, ImeAction, ImeActionArg, InputType, IntArg, Item,
+    ItemConsts, ItemRc, ItemRef, KeyEventArg, KeyEventResult, KeyEventType, PointArg,
+    PointerEventButton, RenderingResult, TextDirection, TextHorizontalAlignment, TextOverflow,
+    TextSpan, TextStrokeStyle, TextVerticalAlignment, TextWrap, VoidArg,
 };
 use crate::graphics::{Brush, Color, FontRequest};
 use crate::input::{
@@ -21,6 +21,7 @@
 use crate::item_rendering::{CachedRenderingData, ItemRenderer, RenderText};
 use crate::layout::{LayoutInfo, Orientation};
 use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, ScaleFactor};
+use crate::model::{Model, ModelExt, ModelRc};
 use crate::platform::Clipboard;
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
@@ -29,6 +30,8 @@
 use alloc::rc::Rc;
 #[cfg(not(feature = "std"))]
 use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 use const_field_offset::FieldOffsets;
 use core::cell::Cell;
 use core::pin::Pin;
@@ -59,6 +62,10 @@ pub struct ComplexText {
     pub stroke: Property<Brush>,
     pub stroke_width: Property<LogicalLength>,
     pub stroke_style: Property<TextStrokeStyle>,
+    pub font_features: Property<SharedString>,
+    pub font_variations: Property<SharedString>,
+    pub text_direction: Property<TextDirection>,
+    pub glyph_transform: Callback<IntArg, GlyphTransform>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -171,6 +178,8 @@ fn font_request(self: Pin<&Self>, window: &WindowInner) -> FontRequest {
             },
             letter_spacing: Some(self.letter_spacing()),
             italic: self.font_italic(),
+            features: self.font_features(),
+            variations: self.font_variations(),
         }
     }
 
@@ -199,6 +208,18 @@ fn letter_spacing(self: Pin<&Self>) -> LogicalLength {
     fn stroke(self: Pin<&Self>) -> (Brush, LogicalLength, TextStrokeStyle) {
         (self.stroke(), self.stroke_width(), self.stroke_style())
     }
+
+    fn text_direction(self: Pin<&Self>) -> TextDirection {
+        self.text_direction()
+    }
+
+    fn has_glyph_transform(self: Pin<&Self>) -> bool {
+        self.glyph_transform.has_handler()
+    }
+
+    fn glyph_transform(self: Pin<&Self>, index: usize) -> GlyphTransform {
+        Self::FIELD_OFFSETS.glyph_transform.apply_pin(self).call(&(index as i32,))
+    }
 }
 
 impl ComplexText {
@@ -329,6 +350,8 @@ fn font_request(self: Pin<&Self>, window: &WindowInner) -> FontRequest {
             },
             letter_spacing: None,
             italic: false,
+            features: Default::default(),
+            variations: Default::default(),
         }
     }
 
@@ -464,6 +487,8 @@ struct UndoItem {
     kind: UndoItemKind,
 }
 
+type TextSpanModel = ModelRc<TextSpan>;
+
 /// The implementation of the `TextInput` element
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
@@ -481,7 +506,10 @@ pub struct TextInput {
     pub vertical_alignment: Property<TextVerticalAlignment>,
     pub wrap: Property<TextWrap>,
     pub input_type: Property<InputType>,
+    pub ime_action: Property<ImeAction>,
     pub letter_spacing: Property<LogicalLength>,
+    pub font_features: Property<SharedString>,
+    pub font_variations: Property<SharedString>,
     pub width: Property<LogicalLength>,
     pub height: Property<LogicalLength>,
     pub cursor_position_byte_offset: Property<i32>,
@@ -491,6 +519,7 @@ pub struct TextInput {
     pub has_focus: Property<bool>,
     pub enabled: Property<bool>,
     pub accepted: Callback<VoidArg>,
+    pub ime_action_triggered: Callback<ImeActionArg>,
     pub cursor_position_changed: Callback<PointArg>,
     pub edited: Callback<VoidArg>,
     pub key_pressed: Callback<KeyEventArg, EventResult>,
@@ -500,6 +529,13 @@ pub struct TextInput {
     pub preedit_text: Property<SharedString>,
     /// A selection within the preedit (cursor and anchor)
     preedit_selection: Property<PreEditSelection>,
+    pub preedit_selection_start: Property<i32>,
+    pub preedit_selection_end: Property<i32>,
+    pub ime_candidate_area_x: Property<LogicalLength>,
+    pub ime_candidate_area_y: Property<LogicalLength>,
+    pub ime_candidate_area_width: Property<LogicalLength>,
+    pub ime_candidate_area_height: Property<LogicalLength>,
+    pub highlight_spans: Property<TextSpanModel>,
     pub cached_rendering_data: CachedRenderingData,
     // The x position where the cursor wants to be.
     // It is not updated when moving up and down even when the line is shorter.
@@ -752,9 +788,20 @@ fn key_event(
                 };
 
                 if let Some(keycode) = event.text.chars().next() {
-                    if keycode == key_codes::Return && !self.read_only() && self.single_line() {
-                        Self::FIELD_OFFSETS.accepted.apply_pin(self).call(&());
-                        return KeyEventResult::EventAccepted;
+                    if keycode == key_codes::Return && !self.read_only() {
+                        let ime_action = self.ime_action();
+                        if self.single_line() {
+                            Self::FIELD_OFFSETS.accepted.apply_pin(self).call(&());
+                        }
+                        if ime_action != ImeAction::Default {
+                            Self::FIELD_OFFSETS
+                                .ime_action_triggered
+                                .apply_pin(self)
+                                .call(&(ime_action,));
+                        }
+                        if self.single_line() || ime_action != ImeAction::Default {
+                            return KeyEventResult::EventAccepted;
+                        }
                     }
                 }
 
@@ -873,8 +920,7 @@ fn key_event(
             }
             KeyEventType::UpdateComposition | KeyEventType::CommitComposition => {
                 let cursor = self.cursor_position(&self.text()) as i32;
-                self.preedit_text.set(event.preedit_text.clone());
-                self.preedit_selection.set(event.preedit_selection.clone().into());
+                self.set_preedit(event.preedit_text.clone(), event.preedit_selection.clone());
 
                 if let Some(r) = &event.replacement_range {
                     // Set the selection so the call to insert erases it
@@ -941,7 +987,7 @@ fn focus_event(
                 if !self.read_only() {
                     if let Some(window_adapter) = window_adapter.internal(crate::InternalToken) {
                         window_adapter.input_method_request(InputMethodRequest::Disable);
-                        self.preedit_text.set(Default::default());
+                        self.set_preedit(Default::default(), None);
                     }
                 }
             }
@@ -956,7 +1002,10 @@ fn render(
         size: LogicalSize,
     ) -> RenderingResult {
         crate::properties::evaluate_no_tracking(|| {
-            if self.has_focus() && self.text() != *backend.window().last_ime_text.borrow() {
+            if self.has_focus()
+                && (self.text() != *backend.window().last_ime_text.borrow()
+                    || self.ime_candidate_area() != backend.window().last_ime_candidate_area.get())
+            {
                 let window_adapter = &backend.window().window_adapter();
                 if let Some(w) = window_adapter.internal(crate::InternalToken) {
                     w.input_method_request(InputMethodRequest::Update(
@@ -1077,6 +1126,9 @@ pub struct TextInputVisualRepresentation {
     pub text_color: Brush,
     /// The color of the blinking cursor
     pub cursor_color: Color,
+    /// The ranges of the text that are drawn with a different style, as byte offsets within the text.
+    /// When ranges overlap, the one that comes last applies.
+    pub highlights: Vec<TextHighlight>,
     text_without_password: Option<String>,
     password_character: char,
 }
@@ -1094,6 +1146,9 @@ fn apply_password_character_substitution(
             return;
         }
 
+        // Highlighting the hidden characters would reveal information about the password
+        self.highlights.clear();
+
         let password_character = password_character_fn.map_or('●', |f| f());
 
         let text = &mut self.text;
@@ -1129,6 +1184,86 @@ pub fn map_byte_offset_from_byte_offset_in_visual_text(&self, byte_offset: usize
     }
 }
 
+/// A range of the text of a `TextInput` that is drawn with a different style, as specified
+/// with the `highlight-spans` property.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextHighlight {
+    /// The byte range within the text used for rendering.
+    pub range: core::ops::Range<usize>,
+    /// The color of the text, if it differs from the element's color.
+    pub color: Option<Color>,
+    /// The color of the background behind the text, if any.
+    pub background: Option<Color>,
+    /// The font weight of the text, if it differs from the element's font weight.
+    pub font_weight: Option<i32>,
+}
+
+impl TextHighlight {
+    /// Returns the highlight that applies to the character at the given byte offset, if any.
+    pub fn find(highlights: &[Self], byte_offset: usize) -> Option<&Self> {
+        highlights.iter().rev().find(|h| h.range.contains(&byte_offset))
+    }
+
+    /// Splits the given byte range into consecutive runs that each have the same highlight.
+    pub fn runs(
+        highlights: &[Self],
+        range: core::ops::Range<usize>,
+    ) -> Vec<(core::ops::Range<usize>, Option<&Self>)> {
+        let mut boundaries = alloc::vec![range.start, range.end];
+        boundaries.extend(
+            highlights
+                .iter()
+                .flat_map(|h| [h.range.start, h.range.end])
+                .filter(|offset| range.contains(offset)),
+        );
+        boundaries.sort_unstable();
+        boundaries.dedup();
+        let mut runs: Vec<(core::ops::Range<usize>, Option<&Self>)> = Vec::new();
+        for w in boundaries.windows(2) {
+            let highlight = Self::find(highlights, w[0]);
+            match runs.last_mut() {
+                Some((range, last))
+                    if last.map(|h| h as *const Self) == highlight.map(|h| h as *const Self) =>
+                {
+                    range.end = w[1]
+                }
+                _ => runs.push((w[0]..w[1], highlight)),
+            }
+        }
+        runs
+    }
+
+    /// Converts the spans from the `highlight-spans` model into highlights, clamped to character
+    /// boundaries of `text`. The model is accessed in a way that tracks changes to its rows.
+    fn from_spans(spans: &ModelRc<TextSpan>, text: &str) -> Vec<Self> {
+        let clamp = |offset: i32| {
+            let mut offset = (offset.max(0) as usize).min(text.len());
+            while !text.is_char_boundary(offset) {
+                offset -= 1;
+            }
+            offset
+        };
+        spans.model_tracker().track_row_count_changes();
+        (0..spans.row_count())
+            .filter_map(|row| spans.row_data_tracked(row))
+            .filter_map(|span| {
+                let range = clamp(span.start)..clamp(span.end);
+                let highlight = Self {
+                    range,
+                    color: (span.color.alpha() > 0).then_some(span.color),
+                    background: (span.background.alpha() > 0).then_some(span.background),
+                    font_weight: (span.font_weight > 0).then_some(span.font_weight),
+                };
+                (!highlight.range.is_empty()
+                    && (highlight.color.is_some()
+                        || highlight.background.is_some()
+                        || highlight.font_weight.is_some()))
+                .then_some(highlight)
+            })
+            .collect()
+    }
+}
+
 impl TextInput {
     fn show_cursor(&self, window_adapter: &Rc<dyn WindowAdapter>) {
         WindowInner::from_pub(window_adapter.window())
@@ -1400,6 +1535,16 @@ fn ime_properties(
                 + origin
                 + cursor_relative.size,
         );
+        let candidate_area = self.ime_candidate_area();
+        WindowInner::from_pub(window_adapter.window()).last_ime_candidate_area.set(candidate_area);
+        let (candidate_rect_origin, candidate_rect_size) = if candidate_area.is_empty() {
+            (cursor_rect_origin, cursor_rect_size)
+        } else {
+            (
+                crate::api::LogicalPosition::from_euclid(candidate_area.origin + origin),
+                crate::api::LogicalSize::from_euclid(candidate_area.size),
+            )
+        };
 
         InputMethodProperties {
             text,
@@ -1410,10 +1555,36 @@ fn ime_properties(
             cursor_rect_origin,
             cursor_rect_size,
             anchor_point,
+            candidate_rect_origin,
+            candidate_rect_size,
             input_type: self.input_type(),
+            ime_action: self.ime_action(),
         }
     }
 
+    /// Returns the area set with the `ime-candidate-area-*` properties, relative to the `TextInput`.
+    /// It's empty when the candidate window is placed next to the cursor.
+    fn ime_candidate_area(self: Pin<&Self>) -> LogicalRect {
+        LogicalRect::new(
+            LogicalPoint::from_lengths(self.ime_candidate_area_x(), self.ime_candidate_area_y()),
+            LogicalSize::from_lengths(
+                self.ime_candidate_area_width(),
+                self.ime_candidate_area_height(),
+            ),
+        )
+    }
+
+    /// Sets the pre-edit text and the range selected by the input method within it, or clears the
+    /// pre-edit when `text` is empty.
+    fn set_preedit(self: Pin<&Self>, text: SharedString, selection: Option<core::ops::Range<i32>>) {
+        let (start, end) =
+            selection.as_ref().map_or((text.len() as i32, text.len() as i32), |r| (r.start, r.end));
+        self.preedit_selection_start.set(start.min(end));
+        self.preedit_selection_end.set(start.max(end));
+        self.preedit_selection.set(selection.into());
+        self.preedit_text.set(text);
+    }
+
     // Avoid accessing self.cursor_position()/self.anchor_position() directly, always
     // use this bounds-checking function.
     pub fn selection_anchor_and_cursor(self: Pin<&Self>) -> (usize, usize) {
@@ -1587,7 +1758,8 @@ fn copy_clipboard(
         WindowInner::from_pub(window_adapter.window())
             .ctx
             .platform()
-            .set_clipboard_text(&text[anchor..cursor], clipboard);
+            .set_clipboard_text(&text[anchor..cursor], clipboard.clone());
+        crate::clipboard::clipboard_changed(clipboard);
     }
 
     pub fn paste(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
@@ -1603,7 +1775,7 @@ fn paste_clipboard(
         if let Some(text) =
             WindowInner::from_pub(window_adapter.window()).ctx.platform().clipboard_text(clipboard)
         {
-            self.preedit_text.set(Default::default());
+            self.set_preedit(Default::default(), None);
             self.insert(&text, window_adapter, self_rc);
         }
     }
@@ -1638,7 +1810,18 @@ pub fn font_request(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>) ->
             },
             letter_spacing: Some(self.letter_spacing()),
             italic: self.font_italic(),
+            features: self.font_features(),
+            variations: self.font_variations(),
+        }
+    }
+
+    /// Returns the ranges of the text (without any pre-edit text) that are drawn with a different style,
+    /// as specified with the `highlight-spans` property. Password fields are never highlighted.
+    pub fn highlights(self: Pin<&Self>) -> Vec<TextHighlight> {
+        if matches!(self.input_type(), InputType::Password) {
+            return Vec::new();
         }
+        TextHighlight::from_spans(&self.highlight_spans(), &self.text())
     }
 
     /// Returns a [`TextInputVisualRepresentation`] struct that contains all the fields necessary for rendering the text input,
@@ -1650,6 +1833,8 @@ pub fn visual_representation(
     ) -> TextInputVisualRepresentation {
         let mut text: String = self.text().into();
 
+        let mut highlights = TextHighlight::from_spans(&self.highlight_spans(), &text);
+
         let preedit_text = self.preedit_text();
         let (preedit_range, selection_range, cursor_position) = if !preedit_text.is_empty() {
             let cursor_position = self.cursor_position(&text);
@@ -1657,6 +1842,16 @@ pub fn visual_representation(
             text.insert_str(cursor_position, &preedit_text);
             let preedit_range = cursor_position..cursor_position + preedit_text.len();
 
+            // The pre-edit text itself is never highlighted
+            for highlight in highlights.iter_mut() {
+                if highlight.range.start >= cursor_position {
+                    highlight.range.start += preedit_text.len();
+                }
+                if highlight.range.end > cursor_position {
+                    highlight.range.end += preedit_text.len();
+                }
+            }
+
             if let Some(preedit_sel) = self.preedit_selection().as_option() {
                 let preedit_selection = cursor_position + preedit_sel.start as usize
                     ..cursor_position + preedit_sel.end as usize;
@@ -1701,6 +1896,7 @@ pub fn visual_representation(
             password_character: Default::default(),
             text_color,
             cursor_color,
+            highlights,
         };
         repr.apply_password_character_substitution(self, password_character_fn);
         repr
@@ -2033,3 +2229,86 @@ pub fn slint_text_item_fontmetrics(
     let self_ref = self_rc.borrow();
     slint_text_item_fontmetrics(window_adapter, self_ref)
 }
+
+/// Initializes an empty model of text spans, for the C++ `TextSpanModel`
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_text_span_model_init(out: *mut TextSpanModel) {
+    core::ptr::write(out, TextSpanModel::default());
+}
+
+/// Initializes a model of text spans that holds a copy of the given spans
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_text_span_model_from_vector(
+    spans: &SharedVector<TextSpan>,
+    out: *mut TextSpanModel,
+) {
+    core::ptr::write(out, ModelRc::new(crate::model::SharedVectorModel::from(spans.clone())));
+}
+
+/// Initializes `out` with a copy of `model`
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_text_span_model_clone(
+    model: &TextSpanModel,
+    out: *mut TextSpanModel,
+) {
+    core::ptr::write(out, model.clone());
+}
+
+/// Destroys the model of text spans
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_text_span_model_drop(model: *mut TextSpanModel) {
+    core::ptr::drop_in_place(model);
+}
+
+/// Returns true if both models are the same instance
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn slint_text_span_model_eq(a: &TextSpanModel, b: &TextSpanModel) -> bool {
+    a == b
+}
+
+/// Copies the rows of the model into `out`, tracking changes of the model
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn slint_text_span_model_to_vector(
+    model: &TextSpanModel,
+    out: &mut SharedVector<TextSpan>,
+) {
+    model.model_tracker().track_row_count_changes();
+    out.clear();
+    out.extend((0..model.row_count()).filter_map(|row| model.row_data_tracked(row)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_highlights() {
+        let red = Color::from_rgb_u8(255, 0, 0);
+        let spans = ModelRc::new(crate::model::VecModel::from(alloc::vec![
+            TextSpan { start: 0, end: 3, color: red, ..Default::default() },
+            // Not on a char boundary and out of range, clamped
+            TextSpan { start: 5, end: 100, background: red, ..Default::default() },
+            // Nothing to highlight, ignored
+            TextSpan { start: 0, end: 2, ..Default::default() },
+            TextSpan { start: 2, end: 4, font_weight: 700, ..Default::default() },
+        ]));
+        let highlights = TextHighlight::from_spans(&spans, "abcdéf");
+        assert_eq!(
+            highlights.iter().map(|h| h.range.clone()).collect::<Vec<_>>(),
+            alloc::vec![0..3, 4..7, 2..4]
+        );
+
+        let runs = TextHighlight::runs(&highlights, 1..7)
+            .into_iter()
+            .map(|(range, h)| (range, h.map(|h| h.range.clone())))
+            .collect::<Vec<_>>();
+        assert_eq!(runs, alloc::vec![(1..2, Some(0..3)), (2..4, Some(2..4)), (4..7, Some(4..7))]);
+        assert_eq!(TextHighlight::find(&highlights, 7), None);
+    }
+}
@@ -0,0 +1,408 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+This module contains the builtin `Video` and `LiveFrame` items.
+
+When adding an item or a property, it needs to be kept in sync with different place.
+Lookup the [`crate::items`] module documentation.
+*/
+use super::{
+    ImageFit, ImageHorizontalAlignment, ImageRendering, ImageTiling, ImageVerticalAlignment, Item,
+    ItemConsts, ItemRc, RenderingResult,
+};
+use crate::graphics::{Image, VideoSource};
+use crate::input::{
+    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
+    KeyEventResult, MouseEvent,
+};
+use crate::item_rendering::ItemRenderer;
+use crate::item_rendering::{CachedRenderingData, RenderImage};
+use crate::item_tree::ItemTreeVTable;
+use crate::layout::{LayoutInfo, Orientation};
+use crate::lengths::{LogicalLength, LogicalSize};
+use crate::properties::ChangeTracker;
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::window::WindowAdapter;
+use crate::{Brush, Coord, Property};
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use const_field_offset::FieldOffsets;
+use core::pin::Pin;
+use core::time::Duration;
+use i_slint_core_macros::*;
+use vtable::{VRcMapped, VWeakMapped};
+
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+/// The implementation of the `Video` element
+pub struct VideoItem {
+    pub source: Property<Image>,
+    pub width: Property<LogicalLength>,
+    pub height: Property<LogicalLength>,
+    pub image_fit: Property<ImageFit>,
+    pub image_rendering: Property<ImageRendering>,
+    pub playing: Property<bool>,
+    /// The playback position in milliseconds
+    pub position: Property<i64>,
+    /// The duration of the video in milliseconds
+    pub duration: Property<i64>,
+    data: VideoPlaybackDataBox,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl VideoItem {
+    fn video_source(self: Pin<&Self>) -> Option<VideoSource> {
+        VideoSource::from_image(&self.source())
+    }
+}
+
+impl Item for VideoItem {
+    fn init(self: Pin<&Self>, self_rc: &ItemRc) {
+        let Some(item) = self_rc.downcast::<VideoItem>() else { return };
+        self.data.init(VRcMapped::downgrade(&item));
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        let natural_size = self.source().size();
+        LayoutInfo {
+            preferred: match orientation {
+                _ if natural_size.width == 0 || natural_size.height == 0 => 0 as Coord,
+                Orientation::Horizontal => natural_size.width as Coord,
+                Orientation::Vertical => {
+                    natural_size.height as Coord * self.width().get() / natural_size.width as Coord
+                }
+            },
+            ..Default::default()
+        }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        backend: &mut &mut dyn ItemRenderer,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        (*backend).draw_image(self, self_rc, size, &self.cached_rendering_data);
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl RenderImage for VideoItem {
+    fn target_size(self: Pin<&Self>) -> LogicalSize {
+        LogicalSize::from_lengths(self.width(), self.height())
+    }
+
+    fn source(self: Pin<&Self>) -> Image {
+        match self.video_source() {
+            Some(video) => video.current_frame(),
+            None => self.source(),
+        }
+    }
+
+    fn source_clip(self: Pin<&Self>) -> Option<crate::graphics::IntRect> {
+        None
+    }
+
+    fn image_fit(self: Pin<&Self>) -> ImageFit {
+        self.image_fit()
+    }
+
+    fn rendering(self: Pin<&Self>) -> ImageRendering {
+        self.image_rendering()
+    }
+
+    fn colorize(self: Pin<&Self>) -> Brush {
+        Brush::default()
+    }
+
+    fn alignment(self: Pin<&Self>) -> (ImageHorizontalAlignment, ImageVerticalAlignment) {
+        Default::default()
+    }
+
+    fn tiling(self: Pin<&Self>) -> (ImageTiling, ImageTiling) {
+        Default::default()
+    }
+}
+
+impl ItemConsts for VideoItem {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        VideoItem,
+        CachedRenderingData,
+    > = VideoItem::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+/// The implementation of the `LiveFrame` element
+pub struct LiveFrameItem {
+    pub source: Property<Image>,
+    pub width: Property<LogicalLength>,
+    pub height: Property<LogicalLength>,
+    pub image_fit: Property<ImageFit>,
+    pub image_rendering: Property<ImageRendering>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for LiveFrameItem {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        // The size of a video source only changes when a frame of a different size is pushed,
+        // so that the layout isn't invalidated by every frame.
+        let natural_size = self.source().size();
+        LayoutInfo {
+            preferred: match orientation {
+                _ if natural_size.width == 0 || natural_size.height == 0 => 0 as Coord,
+                Orientation::Horizontal => natural_size.width as Coord,
+                Orientation::Vertical => {
+                    natural_size.height as Coord * self.width().get() / natural_size.width as Coord
+                }
+            },
+            ..Default::default()
+        }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        backend: &mut &mut dyn ItemRenderer,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        (*backend).draw_image(self, self_rc, size, &self.cached_rendering_data);
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl RenderImage for LiveFrameItem {
+    fn target_size(self: Pin<&Self>) -> LogicalSize {
+        LogicalSize::from_lengths(self.width(), self.height())
+    }
+
+    fn source(self: Pin<&Self>) -> Image {
+        // Reading the frame here, while the item is rendered, makes sure that a new frame only
+        // marks this item as dirty.
+        match VideoSource::from_image(&self.source()) {
+            Some(video) => video.current_frame(),
+            None => self.source(),
+        }
+    }
+
+    fn source_clip(self: Pin<&Self>) -> Option<crate::graphics::IntRect> {
+        None
+    }
+
+    fn image_fit(self: Pin<&Self>) -> ImageFit {
+        self.image_fit()
+    }
+
+    fn rendering(self: Pin<&Self>) -> ImageRendering {
+        self.image_rendering()
+    }
+
+    fn colorize(self: Pin<&Self>) -> Brush {
+        Brush::default()
+    }
+
+    fn alignment(self: Pin<&Self>) -> (ImageHorizontalAlignment, ImageVerticalAlignment) {
+        Default::default()
+    }
+
+    fn tiling(self: Pin<&Self>) -> (ImageTiling, ImageTiling) {
+        Default::default()
+    }
+}
+
+impl ItemConsts for LiveFrameItem {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        LiveFrameItem,
+        CachedRenderingData,
+    > = LiveFrameItem::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+type VideoItemWeak = VWeakMapped<ItemTreeVTable, VideoItem>;
+
+fn duration_to_millis(duration: Duration) -> i64 {
+    duration.as_millis().try_into().unwrap_or(i64::MAX)
+}
+
+/// Keeps the properties of the `Video` element and its [`VideoSource`] in sync
+#[derive(Default)]
+pub struct VideoPlaybackData {
+    /// Plays or pauses the video when the `playing` property or the source changes
+    playing_tracker: ChangeTracker,
+    /// Seeks when the `position` property is set
+    seek_tracker: ChangeTracker,
+    /// Updates the `position` and `duration` properties from the video source
+    position_tracker: ChangeTracker,
+}
+
+impl VideoPlaybackData {
+    fn init(&self, item: VideoItemWeak) {
+        let playing_state = |item: &VideoItemWeak| {
+            let item = item.upgrade()?;
+            let item = item.as_pin_ref();
+            Some((item.video_source()?, item.playing()))
+        };
+        let apply_playing_state =
+            |_: &VideoItemWeak, state: &Option<(VideoSource, bool)>| match state {
+                Some((video, true)) => video.provider().play(),
+                Some((video, false)) => video.provider().pause(),
+                None => {}
+            };
+        apply_playing_state(&item, &playing_state(&item));
+        self.playing_tracker.init(item.clone(), playing_state, apply_playing_state);
+
+        self.seek_tracker.init(
+            item.clone(),
+            |item| item.upgrade().map(|item| item.as_pin_ref().position()),
+            |item, position| {
+                let (Some(item), Some(position)) = (item.upgrade(), *position) else { return };
+                let Some(video) = item.as_pin_ref().video_source() else { return };
+                // Changes that come from the video source itself must not seek
+                if position != duration_to_millis(video.position()) {
+                    video.provider().seek(Duration::from_millis(position.max(0) as u64));
+                }
+            },
+        );
+
+        let source_position = |item: &VideoItemWeak| {
+            let video = item.upgrade()?.as_pin_ref().video_source()?;
+            Some((duration_to_millis(video.position()), duration_to_millis(video.duration())))
+        };
+        let apply_source_position = |item: &VideoItemWeak, state: &Option<(i64, i64)>| {
+            let Some(item) = item.upgrade() else { return };
+            let (position, duration) = state.unwrap_or_default();
+            let item = item.as_pin_ref();
+            VideoItem::FIELD_OFFSETS.position.apply_pin(item).set(position);
+            VideoItem::FIELD_OFFSETS.duration.apply_pin(item).set(duration);
+        };
+        apply_source_position(&item, &source_position(&item));
+        self.position_tracker.init(item, source_position, apply_source_position);
+    }
+}
+
+#[repr(C)]
+/// Wraps the playback state of the video
+pub struct VideoPlaybackDataBox(core::ptr::NonNull<VideoPlaybackData>);
+
+impl Default for VideoPlaybackDataBox {
+    fn default() -> Self {
+        VideoPlaybackDataBox(Box::leak(Box::<VideoPlaybackData>::default()).into())
+    }
+}
+impl Drop for VideoPlaybackDataBox {
+    fn drop(&mut self) {
+        // Safety: the self.0 was constructed from a Box::leak in VideoPlaybackDataBox::default
+        drop(unsafe { Box::from_raw(self.0.as_ptr()) });
+    }
+}
+
+impl core::ops::Deref for VideoPlaybackDataBox {
+    type Target = VideoPlaybackData;
+    fn deref(&self) -> &Self::Target {
+        // Safety: initialized in VideoPlaybackDataBox::default
+        unsafe { self.0.as_ref() }
+    }
+}
+
+/// # Safety
+/// This must be called using a non-null pointer pointing to a chunk of memory big enough to
+/// hold a VideoPlaybackDataBox
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_video_playback_data_init(data: *mut VideoPlaybackDataBox) {
+    core::ptr::write(data, VideoPlaybackDataBox::default());
+}
+
+/// # Safety
+/// This must be called using a non-null pointer pointing to an initialized VideoPlaybackDataBox
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_video_playback_data_free(data: *mut VideoPlaybackDataBox) {
+    core::ptr::drop_in_place(data);
+}
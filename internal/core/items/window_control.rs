@@ -0,0 +1,201 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use super::{
+    Item, ItemConsts, ItemRc, ItemRendererRef, MouseCursor, PointerEventButton, RenderingResult,
+    WindowControl,
+};
+use crate::input::{
+    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
+    KeyEventResult, MouseEvent,
+};
+use crate::item_rendering::CachedRenderingData;
+use crate::layout::{LayoutInfo, Orientation};
+use crate::lengths::{LogicalRect, LogicalSize};
+use crate::platform::WindowEvent;
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::window::WindowAdapter;
+use crate::Property;
+use alloc::rc::Rc;
+use const_field_offset::FieldOffsets;
+use core::pin::Pin;
+use i_slint_core_macros::*;
+
+/// The implementation of the `WindowControlArea` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct WindowControlArea {
+    pub enabled: Property<bool>,
+    pub control: Property<WindowControl>,
+    pub has_hover: Property<bool>,
+    pub pressed: Property<bool>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for WindowControlArea {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        if !self.enabled() {
+            Self::FIELD_OFFSETS.has_hover.apply_pin(self).set(false);
+            Self::FIELD_OFFSETS.pressed.apply_pin(self).set(false);
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
+        InputEventFilterResult::ForwardEvent
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> InputEventResult {
+        let internal = window_adapter.internal(crate::InternalToken);
+        let control = self.control();
+        match event {
+            MouseEvent::Moved { position } => {
+                Self::FIELD_OFFSETS
+                    .has_hover
+                    .apply_pin(self)
+                    .set(LogicalRect::from_size(self_rc.geometry().size).contains(position));
+                if let Some(x) = internal {
+                    x.set_mouse_cursor(resize_cursor(control));
+                }
+                if self.pressed() {
+                    InputEventResult::GrabMouse
+                } else {
+                    InputEventResult::EventAccepted
+                }
+            }
+            MouseEvent::Exit => {
+                Self::FIELD_OFFSETS.has_hover.apply_pin(self).set(false);
+                Self::FIELD_OFFSETS.pressed.apply_pin(self).set(false);
+                if let Some(x) = internal {
+                    x.set_mouse_cursor(MouseCursor::Default);
+                }
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Pressed { position, button, click_count } => match (control, button) {
+                (WindowControl::Caption, PointerEventButton::Left) => {
+                    if click_count == 1 {
+                        let window = window_adapter.window();
+                        window.set_maximized(!window.is_maximized());
+                    } else if let Some(x) = internal {
+                        x.drag_window();
+                    }
+                    InputEventResult::EventAccepted
+                }
+                (WindowControl::Caption, PointerEventButton::Right) => {
+                    if let Some(x) = internal {
+                        x.show_window_menu(
+                            self_rc.map_to_window(self_rc.geometry().origin + position.to_vector()),
+                        );
+                    }
+                    InputEventResult::EventAccepted
+                }
+                (
+                    WindowControl::MinimizeButton
+                    | WindowControl::MaximizeButton
+                    | WindowControl::CloseButton,
+                    PointerEventButton::Left,
+                ) => {
+                    Self::FIELD_OFFSETS.pressed.apply_pin(self).set(true);
+                    InputEventResult::GrabMouse
+                }
+                (_, PointerEventButton::Left) => {
+                    if let Some(x) = internal {
+                        x.drag_resize_window(control);
+                    }
+                    InputEventResult::EventAccepted
+                }
+                _ => InputEventResult::EventIgnored,
+            },
+            MouseEvent::Released { position, button: PointerEventButton::Left, .. } => {
+                if !self.pressed() {
+                    return InputEventResult::EventIgnored;
+                }
+                Self::FIELD_OFFSETS.pressed.apply_pin(self).set(false);
+                if !LogicalRect::from_size(self_rc.geometry().size).contains(position) {
+                    return InputEventResult::EventAccepted;
+                }
+                let window = window_adapter.window();
+                match control {
+                    WindowControl::MinimizeButton => window.set_minimized(true),
+                    WindowControl::MaximizeButton => window.set_maximized(!window.is_maximized()),
+                    WindowControl::CloseButton => {
+                        window.dispatch_event(WindowEvent::CloseRequested)
+                    }
+                    _ => {}
+                }
+                InputEventResult::EventAccepted
+            }
+            _ => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for WindowControlArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+/// Returns the mouse cursor shown over the edges and corners of the window
+fn resize_cursor(control: WindowControl) -> MouseCursor {
+    match control {
+        WindowControl::ResizeTop => MouseCursor::NResize,
+        WindowControl::ResizeBottom => MouseCursor::SResize,
+        WindowControl::ResizeLeft => MouseCursor::WResize,
+        WindowControl::ResizeRight => MouseCursor::EResize,
+        WindowControl::ResizeTopLeft => MouseCursor::NwResize,
+        WindowControl::ResizeTopRight => MouseCursor::NeResize,
+        WindowControl::ResizeBottomLeft => MouseCursor::SwResize,
+        WindowControl::ResizeBottomRight => MouseCursor::SeResize,
+        WindowControl::Caption
+        | WindowControl::MinimizeButton
+        | WindowControl::MaximizeButton
+        | WindowControl::CloseButton => MouseCursor::Default,
+    }
+}
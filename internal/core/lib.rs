@@ -22,10 +22,14 @@
 pub mod animations;
 pub mod api;
 pub mod callbacks;
+pub mod charts;
+pub mod clipboard;
 pub mod component_factory;
 pub mod context;
 pub mod date_time;
+pub mod dialogs;
 pub mod future;
+pub mod global_shortcut;
 pub mod graphics;
 pub mod input;
 pub mod item_focus;
@@ -35,11 +39,15 @@
 pub mod layout;
 pub mod lengths;
 pub mod model;
+pub mod notification;
+pub mod number_format;
+pub mod offscreen;
 pub mod platform;
 pub mod properties;
 pub mod renderer;
 #[cfg(feature = "rtti")]
 pub mod rtti;
+pub mod screen;
 pub mod sharedvector;
 pub mod slice;
 #[cfg(feature = "software-renderer")]
@@ -49,6 +57,7 @@
 pub mod textlayout;
 pub mod timers;
 pub mod translations;
+pub mod tray_icon;
 pub mod window;
 
 #[doc(inline)]
@@ -752,6 +752,176 @@ enum RepeatedInstanceState {
     /// The model data is stale and needs to be refreshed
     Dirty,
 }
+
+/// Remembers the height of the rows of a ListView that were laid out at least once, so that
+/// rows of different heights can be positioned without instantiating all of them.
+///
+/// Rows that were never measured are assumed to have the average height of the measured ones.
+/// The measured heights are also kept in a Fenwick tree, so that the position of a row and the
+/// row at a position are found in O(log n). Inserting or removing rows only marks the tree as
+/// dirty; it is rebuilt once by [`Self::update_tree`] before the next layout.
+#[derive(Default)]
+struct RowHeightCache {
+    heights: Vec<Option<LogicalLength>>,
+    /// Fenwick tree (1-based) over `heights`: `tree[i]` is the sum of the measured heights and
+    /// the number of measured rows in the rows `i - lowbit(i) .. i`
+    tree: Vec<(LogicalLength, usize)>,
+    /// Sum of all the `Some` values in `heights`
+    measured_total: LogicalLength,
+    /// Number of `Some` values in `heights`
+    measured_count: usize,
+    /// Rows were inserted or removed since `tree` was last built
+    tree_dirty: bool,
+}
+
+/// The lowest set bit of `i`, which is the number of rows covered by the node `i` of the tree
+fn lowbit(i: usize) -> usize {
+    i & i.wrapping_neg()
+}
+
+impl RowHeightCache {
+    fn resize(&mut self, row_count: usize) {
+        if row_count < self.heights.len() {
+            let len = self.heights.len();
+            self.remove(row_count, len - row_count);
+        } else if row_count > self.heights.len() {
+            self.heights.resize(row_count, None);
+            self.tree_dirty = true;
+        }
+    }
+
+    fn set(&mut self, row: usize, height: LogicalLength) {
+        let Some(h) = self.heights.get_mut(row) else { return };
+        match h.replace(height) {
+            Some(old) => {
+                self.measured_total -= old;
+                self.add_to_tree(row, height - old, 0);
+            }
+            None => {
+                self.measured_count += 1;
+                self.add_to_tree(row, height, 1);
+            }
+        }
+        self.measured_total += height;
+    }
+
+    fn invalidate(&mut self, row: usize) {
+        if let Some(old) = self.heights.get_mut(row).and_then(Option::take) {
+            self.measured_total -= old;
+            self.measured_count -= 1;
+            self.add_to_tree(row, -old, -1);
+        }
+    }
+
+    fn insert(&mut self, index: usize, count: usize) {
+        let index = index.min(self.heights.len());
+        self.heights.splice(index..index, core::iter::repeat(None).take(count));
+        self.tree_dirty = true;
+    }
+
+    fn remove(&mut self, index: usize, count: usize) {
+        let end = (index + count).min(self.heights.len());
+        if index >= end {
+            return;
+        }
+        for old in self.heights.drain(index..end).flatten() {
+            self.measured_total -= old;
+            self.measured_count -= 1;
+        }
+        self.tree_dirty = true;
+    }
+
+    /// Rebuild the tree from `heights` in O(n) if rows were inserted or removed since the last call.
+    fn update_tree(&mut self) {
+        if !self.tree_dirty {
+            return;
+        }
+        self.tree_dirty = false;
+        let len = self.heights.len();
+        self.tree.clear();
+        self.tree.resize(len + 1, (LogicalLength::zero(), 0));
+        for i in 1..=len {
+            if let Some(h) = self.heights[i - 1] {
+                self.tree[i].0 += h;
+                self.tree[i].1 += 1;
+            }
+            let parent = i + lowbit(i);
+            if parent <= len {
+                let (h, c) = self.tree[i];
+                self.tree[parent].0 += h;
+                self.tree[parent].1 += c;
+            }
+        }
+    }
+
+    fn add_to_tree(&mut self, row: usize, height: LogicalLength, count: isize) {
+        if self.tree_dirty {
+            // The whole tree will be rebuilt anyway
+            return;
+        }
+        let mut i = row + 1;
+        while i < self.tree.len() {
+            self.tree[i].0 += height;
+            self.tree[i].1 = self.tree[i].1.wrapping_add_signed(count);
+            i += lowbit(i);
+        }
+    }
+
+    /// The height to use for rows that were not measured yet.
+    fn estimate(&self, fallback: LogicalLength) -> LogicalLength {
+        if self.measured_count > 0 {
+            self.measured_total / self.measured_count as Coord
+        } else {
+            fallback
+        }
+    }
+
+    fn height(&self, row: usize, estimate: LogicalLength) -> LogicalLength {
+        self.heights.get(row).copied().flatten().unwrap_or(estimate)
+    }
+
+    /// The y position of the given row, relative to the top of the first row.
+    fn row_y(&self, row: usize, estimate: LogicalLength) -> LogicalLength {
+        debug_assert!(!self.tree_dirty);
+        let row = row.min(self.heights.len());
+        let (mut total, mut count) = (LogicalLength::zero(), 0);
+        let mut i = row;
+        while i > 0 {
+            total += self.tree[i].0;
+            count += self.tree[i].1;
+            i -= lowbit(i);
+        }
+        total + estimate * (row - count) as Coord
+    }
+
+    /// The row that covers the given y position (or the last row if y is past the end).
+    fn row_at_y(&self, y: LogicalLength, estimate: LogicalLength) -> usize {
+        debug_assert!(!self.tree_dirty);
+        let len = self.heights.len();
+        // Find the largest number of rows whose total height is not greater than y
+        let mut rows = 0;
+        let mut rows_y = LogicalLength::zero();
+        let mut step = if len == 0 { 0 } else { 1 << len.ilog2() };
+        while step > 0 {
+            let next = rows + step;
+            if next <= len {
+                let (h, c) = self.tree[next];
+                let next_y = rows_y + h + estimate * (step - c) as Coord;
+                if next_y <= y {
+                    rows = next;
+                    rows_y = next_y;
+                }
+            }
+            step >>= 1;
+        }
+        rows.min(len.saturating_sub(1))
+    }
+
+    fn total_height(&self, estimate: LogicalLength) -> LogicalLength {
+        self.measured_total + estimate * (self.heights.len() - self.measured_count) as Coord
+    }
+}
+
 struct RepeaterInner<C: RepeatedItemTree> {
     instances: Vec<(RepeatedInstanceState, Option<ItemTreeRc<C>>)>,
 
@@ -760,6 +930,11 @@ struct RepeaterInner<C: RepeatedItemTree> {
     offset: usize,
     /// The average visible item height.
     cached_item_height: LogicalLength,
+    /// The height of the rows that were already laid out.
+    /// Only created by [`Repeater::ensure_updated_listview`], as other repeaters don't need it.
+    row_heights: Option<RowHeightCache>,
+    /// The row passed to [`Repeater::scroll_to_row`], applied the next time the ListView is laid out
+    scroll_to_row: Option<usize>,
     /// The viewport_y last time the layout of the ListView was done
     previous_viewport_y: LogicalLength,
     /// the position of the item in the row `offset` (which corresponds to `instances[0]`).
@@ -773,6 +948,8 @@ fn default() -> Self {
             instances: Default::default(),
             offset: 0,
             cached_item_height: Default::default(),
+            row_heights: Default::default(),
+            scroll_to_row: None,
             previous_viewport_y: Default::default(),
             anchor_y: Default::default(),
         }
@@ -798,6 +975,10 @@ impl<T: RepeatedItemTree> ModelChangeListener for RepeaterTracker<T> {
     fn row_changed(self: Pin<&Self>, row: usize) {
         let mut inner = self.inner.borrow_mut();
         let inner = &mut *inner;
+        // The new data may not have the same height
+        if let Some(row_heights) = inner.row_heights.as_mut() {
+            row_heights.invalidate(row);
+        }
         if let Some(c) = inner.instances.get_mut(row.wrapping_sub(inner.offset)) {
             if !self.model.is_dirty() {
                 if let Some(comp) = c.1.as_ref() {
@@ -815,6 +996,9 @@ fn row_changed(self: Pin<&Self>, row: usize) {
     /// Notify the peers that rows were added
     fn row_added(self: Pin<&Self>, mut index: usize, mut count: usize) {
         let mut inner = self.inner.borrow_mut();
+        if let Some(row_heights) = inner.row_heights.as_mut() {
+            row_heights.insert(index, count);
+        }
         if index < inner.offset {
             if index + count < inner.offset {
                 return;
@@ -840,6 +1024,9 @@ fn row_added(self: Pin<&Self>, mut index: usize, mut count: usize) {
     /// Notify the peers that rows were removed
     fn row_removed(self: Pin<&Self>, mut index: usize, mut count: usize) {
         let mut inner = self.inner.borrow_mut();
+        if let Some(row_heights) = inner.row_heights.as_mut() {
+            row_heights.remove(index, count);
+        }
         if index < inner.offset {
             if index + count < inner.offset {
                 return;
@@ -865,7 +1052,11 @@ fn row_removed(self: Pin<&Self>, mut index: usize, mut count: usize) {
 
     fn reset(self: Pin<&Self>) {
         self.is_dirty.set(true);
-        self.inner.borrow_mut().instances.clear();
+        let mut inner = self.inner.borrow_mut();
+        inner.instances.clear();
+        if let Some(row_heights) = inner.row_heights.as_mut() {
+            *row_heights = Default::default();
+        }
     }
 }
 
@@ -955,6 +1146,15 @@ fn ensure_updated_impl(
         any_items_created
     }
 
+    /// Scrolls the ListView so that the item of the given row is at the top, the next time
+    /// [`Self::ensure_updated_listview`] is called. This works even if the rows above it were
+    /// never instantiated.
+    pub fn scroll_to_row(self: Pin<&Self>, row: usize) {
+        self.0.inner.borrow_mut().scroll_to_row = Some(row);
+        // Lay out the items again
+        self.data().is_dirty.set(true);
+    }
+
     /// Same as `Self::ensure_updated` but for a ListView
     pub fn ensure_updated_listview(
         self: Pin<&Self>,
@@ -973,12 +1173,22 @@ pub fn ensure_updated_listview(
         let model = self.model();
         let row_count = model.row_count();
         if row_count == 0 {
-            self.0.inner.borrow_mut().instances.clear();
+            let mut inner = self.0.inner.borrow_mut();
+            inner.instances.clear();
+            inner.row_heights.get_or_insert_with(Default::default).resize(0);
+            inner.scroll_to_row = None;
+            drop(inner);
             viewport_height.set(LogicalLength::zero());
             viewport_y.set(LogicalLength::zero());
 
             return;
         }
+        self.0
+            .inner
+            .borrow_mut()
+            .row_heights
+            .get_or_insert_with(Default::default)
+            .resize(row_count);
 
         let listview_height = listview_height.get();
         let mut vp_y = viewport_y.get().min(LogicalLength::zero());
@@ -1023,14 +1233,26 @@ pub fn ensure_updated_listview(
         };
 
         let data = self.data();
-        let mut inner = data.inner.borrow_mut();
+        let mut inner_ref = data.inner.borrow_mut();
+        let inner = &mut *inner_ref;
         if inner.offset >= row_count {
             inner.offset = row_count - 1;
         }
+        let row_heights = inner.row_heights.get_or_insert_with(Default::default);
+        row_heights.update_tree();
+        // Rows that were already laid out give a better estimation than the visible ones only
+        let element_height = row_heights.estimate(element_height);
+
+        if let Some(row) = inner.scroll_to_row.take().filter(|row| *row < row_count) {
+            vp_y = -row_heights.row_y(row, element_height);
+        }
 
         let one_and_a_half_screen = listview_height * 3 as Coord / 2 as Coord;
         let first_item_y = inner.anchor_y;
-        let last_item_bottom = first_item_y + element_height * inner.instances.len() as Coord;
+        let last_item_bottom = first_item_y
+            + (inner.offset..inner.offset + inner.instances.len())
+                .map(|row| row_heights.height(row, element_height))
+                .fold(LogicalLength::zero(), |a, b| a + b);
 
         let mut indices_to_init = Vec::new();
 
@@ -1039,8 +1261,8 @@ pub fn ensure_updated_listview(
         {
             // We are jumping more than 1.5 screens, consider this as a random seek.
             inner.instances.clear();
-            inner.offset = ((-vp_y / element_height).get().floor() as usize).min(row_count - 1);
-            (inner.offset, -vp_y)
+            inner.offset = row_heights.row_at_y(-vp_y, element_height).min(row_count - 1);
+            (inner.offset, row_heights.row_y(inner.offset, element_height))
         } else if vp_y < inner.previous_viewport_y {
             // we scrolled down, try to find out the new offset.
             let mut it_y = first_item_y;
@@ -1092,7 +1314,9 @@ pub fn ensure_updated_listview(
                 if let Some(data) = model.row_data(new_offset) {
                     new_instance.update(new_offset, data);
                 }
-                new_offset_y -= new_instance.as_pin_ref().item_geometry(0).height_length();
+                let h = new_instance.as_pin_ref().item_geometry(0).height_length();
+                row_heights.set(new_offset, h);
+                new_offset_y -= h;
                 new_instances.push(new_instance);
             }
             if !new_instances.is_empty() {
@@ -1117,6 +1341,7 @@ pub fn ensure_updated_listview(
             let mut y = new_offset_y;
             let mut idx = new_offset;
             let instances_begin = new_offset - inner.offset;
+            let mut measured_heights = Vec::new();
             for c in &mut inner.instances[instances_begin..] {
                 if idx >= row_count {
                     break;
@@ -1132,7 +1357,9 @@ pub fn ensure_updated_listview(
                     c.0 = RepeatedInstanceState::Clean;
                 }
                 if let Some(x) = c.1.as_ref() {
+                    let item_y = y;
                     x.as_pin_ref().listview_layout(&mut y, viewport_width);
+                    measured_heights.push((idx, y - item_y));
                 }
                 idx += 1;
                 if y >= -vp_y + listview_height {
@@ -1140,13 +1367,19 @@ pub fn ensure_updated_listview(
                 }
             }
 
+            for (row, h) in measured_heights {
+                row_heights.set(row, h);
+            }
+
             // create more items until there is no more room.
             while y < -vp_y + listview_height && idx < row_count {
                 let new_instance = init();
                 if let Some(data) = model.row_data(idx) {
                     new_instance.update(idx, data);
                 }
+                let item_y = y;
                 new_instance.as_pin_ref().listview_layout(&mut y, viewport_width);
+                row_heights.set(idx, y - item_y);
                 indices_to_init.push(inner.instances.len());
                 inner.instances.push((RepeatedInstanceState::Clean, Some(new_instance)));
                 idx += 1;
@@ -1183,14 +1416,15 @@ pub fn ensure_updated_listview(
 
             // Now re-compute some coordinate such a way that the scrollbar are adjusted.
             inner.cached_item_height = (y - new_offset_y) / inner.instances.len() as Coord;
-            inner.anchor_y = inner.cached_item_height * inner.offset as Coord;
-            viewport_height.set(inner.cached_item_height * row_count as Coord);
+            let estimate = row_heights.estimate(inner.cached_item_height);
+            inner.anchor_y = row_heights.row_y(inner.offset, estimate);
+            viewport_height.set(row_heights.total_height(estimate));
             let new_viewport_y = -inner.anchor_y + vp_y + new_offset_y;
             viewport_y.set(new_viewport_y);
             inner.previous_viewport_y = new_viewport_y;
             break;
         }
-        drop(inner);
+        drop(inner_ref);
         let inner = self.0.inner.borrow();
         for item in indices_to_init.into_iter().filter_map(|index| inner.instances.get(index)) {
             item.1.as_ref().unwrap().init();
@@ -1534,4 +1768,52 @@ fn model_tracker(&self) -> &dyn ModelTracker {
         assert_eq!(model.iter().max().unwrap(), 9);
         assert_eq!(model.max_requested_row.get(), 9);
     }
+
+    #[test]
+    fn test_row_height_cache() {
+        let len = |v: f32| LogicalLength::new(v);
+        let mut cache = RowHeightCache::default();
+        let mut expected = Vec::<Option<f32>>::new();
+        let check = |cache: &mut RowHeightCache, expected: &[Option<f32>]| {
+            cache.update_tree();
+            let estimate = len(10.);
+            let mut y = 0.;
+            for (row, h) in expected.iter().enumerate() {
+                assert_eq!(cache.row_y(row, estimate), len(y));
+                assert_eq!(cache.row_at_y(len(y), estimate), row);
+                let h = h.unwrap_or(10.);
+                assert_eq!(cache.row_at_y(len(y + h / 2.), estimate), row);
+                y += h;
+            }
+            assert_eq!(cache.row_y(expected.len(), estimate), len(y));
+            assert_eq!(cache.total_height(estimate), len(y));
+            assert_eq!(cache.row_at_y(len(y + 100.), estimate), expected.len().saturating_sub(1));
+        };
+
+        check(&mut cache, &expected);
+        cache.resize(13);
+        expected.resize(13, None);
+        check(&mut cache, &expected);
+        for (row, h) in [(0, 5.), (3, 20.), (4, 1.), (12, 30.), (7, 8.), (3, 2.)] {
+            cache.set(row, len(h));
+            expected[row] = Some(h);
+            check(&mut cache, &expected);
+        }
+        cache.invalidate(4);
+        expected[4] = None;
+        check(&mut cache, &expected);
+        cache.insert(2, 3);
+        expected.splice(2..2, [None; 3]);
+        check(&mut cache, &expected);
+        cache.set(3, len(7.));
+        expected[3] = Some(7.);
+        check(&mut cache, &expected);
+        cache.remove(1, 4);
+        expected.drain(1..5);
+        check(&mut cache, &expected);
+        cache.resize(2);
+        expected.truncate(2);
+        check(&mut cache, &expected);
+        assert_eq!(cache.estimate(len(10.)), len(5.));
+    }
 }
@@ -0,0 +1,204 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Desktop notifications, shown by the notification service of the platform
+
+#![warn(missing_docs)]
+
+use crate::api::{PlatformError, Toast, ToastOptions, WindowManager};
+use crate::callbacks::CallbackSlot;
+use crate::graphics::Image;
+use crate::SharedString;
+use alloc::boxed::Box;
+use alloc::rc::{Rc, Weak};
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::time::Duration;
+
+/// A notification shown by the notification service of the platform, with a title, a body, an
+/// icon, and buttons for actions.
+///
+/// The notification is shown with [`Self::show()`], and closed when the `Notification` is dropped,
+/// if the platform allows it. Keep it alive for as long as its callbacks should be invoked.
+///
+/// The winit backend shows notifications with the notification portal on Linux, and with the
+/// `notifications` cargo feature on Windows and macOS, where actions are not supported. When the
+/// platform can't show the notification, it's shown as a [`Toast`] in the focused window of the
+/// application instead, with only the first action, if the window was compiled with toasts enabled.
+///
+/// # Example
+/// ```rust,no_run
+/// let notification = slint::Notification::new("Download complete");
+/// notification.set_body("The file report.pdf was downloaded");
+/// notification.add_action("Open", || println!("Open the file"));
+/// notification.on_activated(|| println!("The notification was clicked"));
+/// notification.show().unwrap();
+/// ```
+pub struct Notification {
+    inner: Rc<NotificationInner>,
+}
+
+impl Notification {
+    /// Creates a notification with the given title. It's not shown until [`Self::show()`] is called.
+    pub fn new(title: impl Into<SharedString>) -> Self {
+        Self {
+            inner: Rc::new(NotificationInner {
+                title: RefCell::new(title.into()),
+                body: Default::default(),
+                icon: Default::default(),
+                actions: Default::default(),
+                activated: Default::default(),
+                adapter: Default::default(),
+            }),
+        }
+    }
+
+    /// Returns the title of the notification.
+    pub fn title(&self) -> SharedString {
+        self.inner.title.borrow().clone()
+    }
+
+    /// Sets the title of the notification.
+    pub fn set_title(&self, title: impl Into<SharedString>) {
+        self.inner.title.replace(title.into());
+    }
+
+    /// Returns the text of the notification.
+    pub fn body(&self) -> SharedString {
+        self.inner.body.borrow().clone()
+    }
+
+    /// Sets the text of the notification, shown below the title.
+    pub fn set_body(&self, body: impl Into<SharedString>) {
+        self.inner.body.replace(body.into());
+    }
+
+    /// Returns the icon of the notification, if one was set.
+    pub fn icon(&self) -> Option<Image> {
+        self.inner.icon.borrow().clone()
+    }
+
+    /// Sets the icon of the notification. Some notification services only show icons loaded from a file.
+    pub fn set_icon(&self, icon: &Image) {
+        self.inner.icon.replace(Some(icon.clone()));
+    }
+
+    /// Returns the labels of the actions, in the order in which they were added.
+    pub fn action_labels(&self) -> Vec<SharedString> {
+        self.inner.actions.borrow().iter().map(|(label, _)| label.clone()).collect()
+    }
+
+    /// Adds a button to the notification, with the given label. The callback is invoked when the
+    /// user clicks it.
+    pub fn add_action(&self, label: impl Into<SharedString>, callback: impl FnMut() + 'static) {
+        let slot = Rc::new(CallbackSlot::<dyn FnMut()>::default());
+        slot.set(Box::new(callback));
+        self.inner.actions.borrow_mut().push((label.into(), slot));
+    }
+
+    /// Sets a callback that is invoked when the user clicks on the notification itself.
+    pub fn on_activated(&self, callback: impl FnMut() + 'static) {
+        self.inner.activated.set(Box::new(callback));
+    }
+
+    /// Shows the notification, or replaces it if it's already shown.
+    ///
+    /// Returns an error if the platform can't show the notification and no window of the application
+    /// is shown, or if the platform was not initialized yet. The platform is initialized when the first
+    /// component is created.
+    pub fn show(&self) -> Result<(), PlatformError> {
+        self.inner.adapter.take();
+        let handle = NotificationHandle(Rc::downgrade(&self.inner));
+        let adapter = crate::context::with_global_context(
+            || Err(PlatformError::NoPlatform),
+            |ctx| ctx.platform().show_notification(self, handle.clone()),
+        )?
+        .or_else(|err| self.show_toast(handle).ok_or(err))?;
+        self.inner.adapter.replace(Some(adapter));
+        Ok(())
+    }
+
+    /// Shows the notification as a toast in the focused window, or the last shown window
+    fn show_toast(&self, handle: NotificationHandle) -> Option<Box<dyn NotificationAdapter>> {
+        let id = WindowManager::focused_window().or_else(|| WindowManager::windows().pop())?;
+        let body = self.body();
+        let message = if body.is_empty() {
+            self.title()
+        } else {
+            crate::format!("{}\n{}", self.title(), body)
+        };
+        let mut options = ToastOptions::new(message).with_duration(Duration::from_secs(6));
+        if let Some(label) = self.action_labels().into_iter().next() {
+            options = options.with_action(label, move || {
+                handle.dispatch_event(NotificationEvent::ActionInvoked(0))
+            });
+        }
+        let toast = WindowManager::with_window(id, |window| Toast::show(window, options))?;
+        Some(Box::new(ToastNotificationAdapter(toast)))
+    }
+
+    /// Closes the notification, if the platform allows it.
+    pub fn close(&self) {
+        self.inner.adapter.take();
+    }
+}
+
+struct NotificationInner {
+    title: RefCell<SharedString>,
+    body: RefCell<SharedString>,
+    icon: RefCell<Option<Image>>,
+    actions: RefCell<Vec<(SharedString, Rc<CallbackSlot<dyn FnMut()>>)>>,
+    activated: CallbackSlot<dyn FnMut()>,
+    adapter: RefCell<Option<Box<dyn NotificationAdapter>>>,
+}
+
+/// This trait represents a [`Notification`] shown by the platform.
+///
+/// It is created by [`Platform::show_notification()`](crate::platform::Platform::show_notification),
+/// and dropped when the notification should be closed.
+pub trait NotificationAdapter {}
+
+/// Closes the toast that shows the notification when dropped
+struct ToastNotificationAdapter(Toast);
+
+impl NotificationAdapter for ToastNotificationAdapter {}
+
+impl Drop for ToastNotificationAdapter {
+    fn drop(&mut self) {
+        self.0.close();
+    }
+}
+
+/// The events that a [`NotificationAdapter`] reports with [`NotificationHandle::dispatch_event()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NotificationEvent {
+    /// The user clicked on the notification.
+    Activated,
+    /// The user clicked on the button of the action at the given index in
+    /// [`Notification::action_labels()`].
+    ActionInvoked(usize),
+}
+
+/// A handle given to the [`NotificationAdapter`], to report the events of the notification.
+#[derive(Clone)]
+pub struct NotificationHandle(Weak<NotificationInner>);
+
+impl NotificationHandle {
+    /// Dispatches the event to the callbacks of the [`Notification`]. Does nothing if the
+    /// notification was dropped.
+    pub fn dispatch_event(&self, event: NotificationEvent) {
+        let Some(inner) = self.0.upgrade() else { return };
+        match event {
+            NotificationEvent::Activated => {
+                inner.activated.call(|f| f());
+            }
+            NotificationEvent::ActionInvoked(index) => {
+                let callback = inner.actions.borrow().get(index).map(|(_, c)| c.clone());
+                if let Some(callback) = callback {
+                    callback.call(|f| f());
+                }
+            }
+        }
+    }
+}
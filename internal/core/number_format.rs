@@ -0,0 +1,276 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Locale aware formatting and parsing of numbers, used by the NumberInput widget.
+
+use crate::SharedString;
+use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// The characters separating the integer part from the decimals, and the groups of thousands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberSeparators {
+    pub decimal: char,
+    pub group: char,
+}
+
+impl Default for NumberSeparators {
+    fn default() -> Self {
+        Self { decimal: '.', group: ',' }
+    }
+}
+
+/// Returns the separators for the current locale
+#[cfg(feature = "std")]
+pub fn number_separators() -> NumberSeparators {
+    sys_locale::get_locale().map_or_else(Default::default, |l| number_separators_for_locale(&l))
+}
+
+/// Returns the separators for the current locale
+#[cfg(not(feature = "std"))]
+pub fn number_separators() -> NumberSeparators {
+    NumberSeparators::default()
+}
+
+/// Returns the separators for the given locale (such as `de-CH`)
+pub fn number_separators_for_locale(locale: &str) -> NumberSeparators {
+    let locale = locale.split(['.', '@']).next().unwrap_or_default();
+    let mut parts = locale.split(['-', '_']);
+    let language = parts.next().unwrap_or_default().to_ascii_lowercase();
+    let region = parts.find(|part| part.len() == 2 && part.chars().all(|c| c.is_ascii_uppercase()));
+    match (language.as_str(), region) {
+        ("de" | "it", Some("CH" | "LI")) => NumberSeparators { decimal: '.', group: '’' },
+        ("fr", Some("CH")) => NumberSeparators { decimal: ',', group: '\u{202f}' },
+        ("es", Some("MX" | "US")) | ("pt", Some("MO")) => NumberSeparators::default(),
+        (
+            "de" | "es" | "it" | "nl" | "pt" | "id" | "da" | "tr" | "el" | "ro" | "hr" | "sl"
+            | "sr" | "ca" | "vi",
+            _,
+        ) => NumberSeparators { decimal: ',', group: '.' },
+        (
+            "fr" | "nb" | "no" | "nn" | "sv" | "fi" | "pl" | "cs" | "sk" | "ru" | "uk" | "bg"
+            | "hu" | "et" | "lt" | "lv" | "be" | "kk",
+            _,
+        ) => NumberSeparators { decimal: ',', group: '\u{a0}' },
+        _ => NumberSeparators::default(),
+    }
+}
+
+/// Formats the value with the given number of decimals in the current locale, with separators between
+/// the groups of thousands if `group_separators` is true.
+pub fn format_number(value: f64, decimals: i32, group_separators: bool) -> SharedString {
+    format_number_with_separators(value, decimals, group_separators, number_separators())
+}
+
+/// Like [`format_number`], with the given separators instead of the ones of the current locale.
+pub fn format_number_with_separators(
+    value: f64,
+    decimals: i32,
+    group_separators: bool,
+    separators: NumberSeparators,
+) -> SharedString {
+    if !value.is_finite() {
+        return SharedString::default();
+    }
+    let formatted = alloc::format!("{:.*}", decimals.clamp(0, 15) as usize, value.abs());
+    let (integer, fraction) = match formatted.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (formatted.as_str(), None),
+    };
+
+    let mut result = String::new();
+    // Don't show "-0" when the value is rounded to zero
+    if value < 0. && formatted.bytes().any(|c| c.is_ascii_digit() && c != b'0') {
+        result.push('-');
+    }
+    for (i, digit) in integer.chars().enumerate() {
+        if group_separators && i > 0 && (integer.len() - i) % 3 == 0 {
+            result.push(separators.group);
+        }
+        result.push(digit);
+    }
+    if let Some(fraction) = fraction {
+        result.push(separators.decimal);
+        result.push_str(fraction);
+    }
+    result.into()
+}
+
+/// Parses a number, or a simple arithmetic expression with `+`, `-`, `*`, `/`, and parentheses such as
+/// `1+2`, written in the current locale. Returns `None` if the text is not such a number or expression.
+pub fn parse_number(text: &str) -> Option<f64> {
+    parse_number_with_separators(text, number_separators())
+}
+
+/// Like [`parse_number`], with the given separators instead of the ones of the current locale.
+pub fn parse_number_with_separators(text: &str, separators: NumberSeparators) -> Option<f64> {
+    let normalized: String = text
+        .chars()
+        .filter(|c| *c != separators.group && !c.is_whitespace())
+        .map(|c| if c == separators.decimal { '.' } else { c })
+        .collect();
+    let mut parser = ExpressionParser { text: normalized.as_bytes(), position: 0 };
+    let value = parser.expression()?;
+    (parser.position == parser.text.len() && value.is_finite()).then_some(value)
+}
+
+/// A recursive descent parser that evaluates the expression while parsing it
+struct ExpressionParser<'a> {
+    text: &'a [u8],
+    position: usize,
+}
+
+impl ExpressionParser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.text.get(self.position).copied()
+    }
+
+    // expression := term (('+' | '-') term)*
+    fn expression(&mut self) -> Option<f64> {
+        let mut value = self.term()?;
+        loop {
+            match self.peek() {
+                Some(b'+') => {
+                    self.position += 1;
+                    value += self.term()?;
+                }
+                Some(b'-') => {
+                    self.position += 1;
+                    value -= self.term()?;
+                }
+                _ => return Some(value),
+            }
+        }
+    }
+
+    // term := factor (('*' | '/') factor)*
+    fn term(&mut self) -> Option<f64> {
+        let mut value = self.factor()?;
+        loop {
+            match self.peek() {
+                Some(b'*') => {
+                    self.position += 1;
+                    value *= self.factor()?;
+                }
+                Some(b'/') => {
+                    self.position += 1;
+                    value /= self.factor()?;
+                }
+                _ => return Some(value),
+            }
+        }
+    }
+
+    // factor := ('+' | '-') factor | '(' expression ')' | number
+    fn factor(&mut self) -> Option<f64> {
+        match self.peek()? {
+            b'+' => {
+                self.position += 1;
+                self.factor()
+            }
+            b'-' => {
+                self.position += 1;
+                self.factor().map(|value| -value)
+            }
+            b'(' => {
+                self.position += 1;
+                let value = self.expression()?;
+                if self.peek()? != b')' {
+                    return None;
+                }
+                self.position += 1;
+                Some(value)
+            }
+            _ => {
+                let start = self.position;
+                while matches!(self.peek(), Some(b'0'..=b'9' | b'.')) {
+                    self.position += 1;
+                }
+                core::str::from_utf8(&self.text[start..self.position]).ok()?.parse().ok()
+            }
+        }
+    }
+}
+
+#[cfg(feature = "ffi")]
+mod ffi {
+    #![allow(unsafe_code)]
+
+    use super::*;
+
+    #[no_mangle]
+    pub extern "C" fn slint_number_format(
+        value: f64,
+        decimals: i32,
+        group_separators: bool,
+        out: &mut SharedString,
+    ) {
+        *out = format_number(value, decimals, group_separators);
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_number_parse(text: &SharedString, value: &mut f64) -> bool {
+        if let Some(x) = parse_number(text) {
+            *value = x;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[test]
+fn test_number_separators_for_locale() {
+    assert_eq!(
+        number_separators_for_locale("en-US"),
+        NumberSeparators { decimal: '.', group: ',' }
+    );
+    assert_eq!(
+        number_separators_for_locale("de_DE.UTF-8"),
+        NumberSeparators { decimal: ',', group: '.' }
+    );
+    assert_eq!(
+        number_separators_for_locale("de-CH"),
+        NumberSeparators { decimal: '.', group: '’' }
+    );
+    assert_eq!(
+        number_separators_for_locale("fr"),
+        NumberSeparators { decimal: ',', group: '\u{a0}' }
+    );
+    assert_eq!(
+        number_separators_for_locale("ja-JP"),
+        NumberSeparators { decimal: '.', group: ',' }
+    );
+}
+
+#[test]
+fn test_format_number() {
+    let en = NumberSeparators::default();
+    let de = number_separators_for_locale("de-DE");
+    assert_eq!(format_number_with_separators(1234567.891, 2, true, en), "1,234,567.89");
+    assert_eq!(format_number_with_separators(1234567.891, 2, true, de), "1.234.567,89");
+    assert_eq!(format_number_with_separators(1234567.891, 0, false, en), "1234568");
+    assert_eq!(format_number_with_separators(-1234.5, 1, true, en), "-1,234.5");
+    assert_eq!(format_number_with_separators(-0.001, 2, true, en), "0.00");
+    assert_eq!(format_number_with_separators(123., 0, true, en), "123");
+    assert_eq!(format_number_with_separators(f64::NAN, 2, true, en), "");
+}
+
+#[test]
+fn test_parse_number() {
+    let en = NumberSeparators::default();
+    let de = number_separators_for_locale("de-DE");
+    assert_eq!(parse_number_with_separators("1,234.5", en), Some(1234.5));
+    assert_eq!(parse_number_with_separators("1.234,5", de), Some(1234.5));
+    assert_eq!(parse_number_with_separators(" -42 ", en), Some(-42.));
+    assert_eq!(parse_number_with_separators("1+2", en), Some(3.));
+    assert_eq!(parse_number_with_separators("2 * (3 + 4) - 1", en), Some(13.));
+    assert_eq!(parse_number_with_separators("1,5/2", de), Some(0.75));
+    assert_eq!(parse_number_with_separators("-(2)", en), Some(-2.));
+    assert_eq!(parse_number_with_separators("1/0", en), None);
+    assert_eq!(parse_number_with_separators("1+", en), None);
+    assert_eq!(parse_number_with_separators("(1", en), None);
+    assert_eq!(parse_number_with_separators("abc", en), None);
+    assert_eq!(parse_number_with_separators("", en), None);
+}
@@ -0,0 +1,152 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+#![warn(missing_docs)]
+//! A window adapter to render components into images without showing them in a window.
+
+use crate::api::{ComponentHandle, PhysicalSize, PlatformError, Window, WindowSize};
+use crate::platform::{WindowAdapter, WindowEvent};
+use crate::renderer::Renderer;
+#[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
+use crate::thread_local;
+use crate::window::WindowProperties;
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+use alloc::rc::{Rc, Weak};
+use core::cell::{Cell, RefCell};
+use once_cell::unsync::OnceCell;
+
+thread_local! {
+    /// The window adapter that the component created in [`OffscreenWindowAdapter::create_component`] uses.
+    static PENDING_WINDOW_ADAPTER: RefCell<Option<Rc<dyn WindowAdapter>>> = const { RefCell::new(None) }
+}
+
+/// Returns the window adapter of the [`OffscreenWindowAdapter`] that's currently creating a component, if any.
+/// The code that creates the window adapter of a component calls this before asking the platform.
+#[doc(hidden)]
+pub fn take_pending_window_adapter() -> Option<Rc<dyn WindowAdapter>> {
+    PENDING_WINDOW_ADAPTER.with(|pending| pending.borrow_mut().take())
+}
+
+/// A window adapter that isn't shown on the screen, for example to generate thumbnails, to print,
+/// or to render on a server.
+///
+/// Create a component in it with [`Self::create_component()`], next to the windows of the running
+/// backend, or return it from [`Platform::create_window_adapter()`](crate::platform::Platform::create_window_adapter)
+/// when there's no windowing system. Render the window of the component with [`Window::take_snapshot()`].
+/// The window has the preferred size of the component until [`Window::set_size()`] is called, and its
+/// scale factor is set by dispatching a [`WindowEvent::ScaleFactorChanged`] event.
+///
+/// The window is rendered with the renderer returned by
+/// [`Platform::create_offscreen_renderer()`](crate::platform::Platform::create_offscreen_renderer),
+/// so that it looks like the windows of the platform. For example the winit backend renders with
+/// FemtoVG into an OpenGL pbuffer when it renders windows with FemtoVG.
+///
+/// ```rust,no_run
+/// use slint::platform::OffscreenWindowAdapter;
+///
+/// slint::slint! { export component Thumbnail inherits Window { Text { text: "Hello"; } } }
+/// let adapter = OffscreenWindowAdapter::new();
+/// let thumbnail = adapter.create_component(Thumbnail::new).unwrap();
+/// thumbnail.window().set_size(slint::PhysicalSize::new(256, 256));
+/// let image = thumbnail.window().take_snapshot().unwrap();
+/// ```
+pub struct OffscreenWindowAdapter {
+    window: Window,
+    size: Cell<PhysicalSize>,
+    /// Created when first needed, as the platform may not be initialized before.
+    renderer: OnceCell<Box<dyn Renderer>>,
+}
+
+impl OffscreenWindowAdapter {
+    /// Creates a new window adapter that renders with the offscreen renderer of the platform,
+    /// or with the software renderer if the platform doesn't provide one.
+    #[cfg(feature = "software-renderer")]
+    pub fn new() -> Rc<Self> {
+        Self::new_with_renderer_cell(OnceCell::new())
+    }
+
+    /// Creates a new window adapter that renders with the given renderer. The renderer must
+    /// implement [`Window::take_snapshot()`] without a window surface.
+    pub fn new_with_renderer(renderer: Box<dyn Renderer>) -> Rc<Self> {
+        Self::new_with_renderer_cell(OnceCell::with_value(renderer))
+    }
+
+    fn new_with_renderer_cell(renderer: OnceCell<Box<dyn Renderer>>) -> Rc<Self> {
+        Rc::new_cyclic(|w: &Weak<Self>| Self {
+            window: Window::new(w.clone()),
+            size: Default::default(),
+            renderer,
+        })
+    }
+
+    /// Calls `create` to create a component whose window is this window adapter, instead of a window
+    /// of the platform. Pass the `new` function of the component, for example.
+    ///
+    /// Returns an error if the window adapter already has a component, or if the created component
+    /// already has another window.
+    pub fn create_component<T: ComponentHandle>(
+        self: &Rc<Self>,
+        create: impl FnOnce() -> Result<T, PlatformError>,
+    ) -> Result<T, PlatformError> {
+        if crate::window::WindowInner::from_pub(&self.window).try_component().is_some() {
+            return Err("The offscreen window adapter already has a component".into());
+        }
+        let previous =
+            PENDING_WINDOW_ADAPTER.with(|pending| pending.replace(Some(self.clone() as _)));
+        let component = create().map(|component| {
+            // The window adapter of a component is created when its window is first accessed
+            let window = component.window() as *const Window;
+            (component, window)
+        });
+        PENDING_WINDOW_ADAPTER.with(|pending| *pending.borrow_mut() = previous);
+        let (component, window) = component?;
+        if !core::ptr::eq(window, &self.window) {
+            return Err(
+                "The component created for the offscreen window adapter already has a window"
+                    .into(),
+            );
+        }
+        Ok(component)
+    }
+}
+
+impl WindowAdapter for OffscreenWindowAdapter {
+    fn window(&self) -> &Window {
+        &self.window
+    }
+
+    fn size(&self) -> PhysicalSize {
+        self.size.get()
+    }
+
+    fn set_size(&self, size: WindowSize) {
+        let scale_factor = self.window.scale_factor();
+        self.size.set(size.to_physical(scale_factor));
+        self.renderer().resize(self.size.get()).ok();
+        self.window.dispatch_event(WindowEvent::Resized { size: size.to_logical(scale_factor) });
+    }
+
+    fn renderer(&self) -> &dyn Renderer {
+        &**self.renderer.get_or_init(|| {
+            crate::context::GLOBAL_CONTEXT
+                .with(|ctx| ctx.get().map(|ctx| ctx.platform().create_offscreen_renderer()))
+                .and_then(Result::ok)
+                .unwrap_or_else(|| {
+                    #[cfg(feature = "software-renderer")]
+                    return Box::new(crate::software_renderer::SoftwareRenderer::new());
+                    // Only `new_with_renderer` is available without the software renderer
+                    #[cfg(not(feature = "software-renderer"))]
+                    unreachable!()
+                })
+        })
+    }
+
+    fn update_window_properties(&self, properties: WindowProperties<'_>) {
+        if self.size.get().width == 0 {
+            let c = properties.layout_constraints();
+            self.size.set(c.preferred.to_physical(self.window.scale_factor()));
+            self.renderer().resize(self.size.get()).ok();
+        }
+    }
+}
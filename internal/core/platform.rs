@@ -9,9 +9,15 @@
 
 pub use crate::api::PlatformError;
 use crate::api::{LogicalPosition, LogicalSize};
+pub use crate::clipboard::clipboard_changed;
+pub use crate::global_shortcut::{GlobalShortcutAdapter, GlobalShortcutHandle};
+pub use crate::notification::{NotificationAdapter, NotificationEvent, NotificationHandle};
+pub use crate::offscreen::OffscreenWindowAdapter;
 pub use crate::renderer::Renderer;
+pub use crate::screen::screens_changed;
 #[cfg(feature = "software-renderer")]
 pub use crate::software_renderer;
+pub use crate::tray_icon::{TrayIconAdapter, TrayIconEvent, TrayIconHandle};
 #[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
 use crate::unsafe_single_threaded::OnceCell;
 pub use crate::window::{LayoutConstraints, WindowAdapter, WindowProperties};
@@ -21,6 +27,8 @@
 use alloc::rc::Rc;
 #[cfg(not(feature = "std"))]
 use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 #[cfg(all(feature = "std", not(target_os = "android")))]
 use once_cell::sync::OnceCell;
 #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
@@ -122,6 +130,131 @@ fn clipboard_text(&self, _clipboard: Clipboard) -> Option<String> {
         None
     }
 
+    /// Sends the given image into the system clipboard.
+    ///
+    /// If the platform doesn't support images in the specified clipboard, this function should do nothing
+    fn set_clipboard_image(
+        &self,
+        _image: &crate::graphics::SharedPixelBuffer<crate::graphics::Rgba8Pixel>,
+        _clipboard: Clipboard,
+    ) {
+    }
+
+    /// Returns a copy of the image stored in the system clipboard, if any.
+    ///
+    /// If the platform doesn't support images in the specified clipboard, the function should return None
+    fn clipboard_image(
+        &self,
+        _clipboard: Clipboard,
+    ) -> Option<crate::graphics::SharedPixelBuffer<crate::graphics::Rgba8Pixel>> {
+        None
+    }
+
+    /// Sends the given HTML into the system clipboard. `alt_text` is the plain text version of the
+    /// HTML, for the applications that can't paste HTML.
+    ///
+    /// If the platform doesn't support HTML in the specified clipboard, this function should do nothing
+    fn set_clipboard_html(&self, _html: &str, _alt_text: &str, _clipboard: Clipboard) {}
+
+    /// Returns a copy of the HTML stored in the system clipboard, if any.
+    ///
+    /// If the platform doesn't support HTML in the specified clipboard, the function should return None
+    fn clipboard_html(&self, _clipboard: Clipboard) -> Option<String> {
+        None
+    }
+
+    /// Returns the screens connected to the system, for [`Screen::all()`](crate::api::Screen::all).
+    /// Call [`screens_changed()`] when they change.
+    ///
+    /// The default implementation returns an empty list, as the screens can't be enumerated.
+    fn screens(&self) -> Vec<crate::api::Screen> {
+        Vec::new()
+    }
+
+    /// Returns the video modes of the screen, for [`Screen::video_modes()`](crate::api::Screen::video_modes).
+    /// The window adapter enters the mode returned by [`WindowProperties::exclusive_fullscreen()`].
+    ///
+    /// The default implementation returns an empty list, as exclusive fullscreen is not supported.
+    fn video_modes(&self, _screen: &crate::api::Screen) -> Vec<crate::api::VideoMode> {
+        Vec::new()
+    }
+
+    /// Creates the renderer of an [`OffscreenWindowAdapter`], which renders into images with
+    /// [`Window::take_snapshot()`](crate::api::Window::take_snapshot) without a window surface.
+    /// Implement this to render offscreen with the renderer that the windows of the platform use.
+    ///
+    /// The default implementation returns the software renderer, or an error if it's not compiled in.
+    fn create_offscreen_renderer(&self) -> Result<Box<dyn Renderer>, PlatformError> {
+        #[cfg(feature = "software-renderer")]
+        return Ok(Box::new(crate::software_renderer::SoftwareRenderer::new()));
+        #[cfg(not(feature = "software-renderer"))]
+        Err("Offscreen rendering is not supported by this platform".into())
+    }
+
+    /// Creates the platform implementation of a [`TrayIcon`](crate::api::TrayIcon). The adapter
+    /// reports the clicks on the icon with [`TrayIconHandle::dispatch_event()`].
+    ///
+    /// The default implementation returns an error, as system tray icons are not supported.
+    fn create_tray_icon_adapter(
+        &self,
+        _handle: TrayIconHandle,
+    ) -> Result<Box<dyn TrayIconAdapter>, PlatformError> {
+        Err("System tray icons are not supported by this platform".into())
+    }
+
+    /// Registers the key combination of a [`GlobalShortcut`](crate::api::GlobalShortcut) with the system, so that
+    /// [`GlobalShortcutHandle::activate()`] is called when the user presses it, even if no window of the
+    /// application has the focus. The shortcut is unregistered when the returned adapter is dropped.
+    ///
+    /// Return [`GlobalShortcutError::AlreadyRegistered`](crate::api::GlobalShortcutError::AlreadyRegistered)
+    /// if the key combination is already taken.
+    ///
+    /// The default implementation returns an error, as global shortcuts are not supported.
+    fn register_global_shortcut(
+        &self,
+        _key_combination: crate::api::KeyCombination,
+        _handle: GlobalShortcutHandle,
+    ) -> Result<Box<dyn GlobalShortcutAdapter>, crate::api::GlobalShortcutError> {
+        Err(crate::api::GlobalShortcutError::Unsupported)
+    }
+
+    /// Shows a [`Notification`](crate::api::Notification) with the notification service of the platform.
+    /// The adapter reports the clicks on the notification and its actions with
+    /// [`NotificationHandle::dispatch_event()`], and closes the notification when dropped.
+    ///
+    /// When this function returns an error, the notification is shown as a toast in a window of the
+    /// application instead. The default implementation returns an error, as notifications are not supported.
+    fn show_notification(
+        &self,
+        _notification: &crate::api::Notification,
+        _handle: NotificationHandle,
+    ) -> Result<Box<dyn NotificationAdapter>, PlatformError> {
+        Err("Notifications are not supported by this platform".into())
+    }
+
+    /// Shows a native file dialog, and returns a future that resolves to the chosen file or folder.
+    /// This is used by the functions of the [`dialogs`](crate::dialogs) module.
+    ///
+    /// The default implementation returns an error, as file dialogs are not supported.
+    #[cfg(feature = "std")]
+    fn show_file_dialog(
+        &self,
+        _kind: crate::dialogs::FileDialogKind,
+        _options: crate::dialogs::FileDialogOptions,
+    ) -> Result<crate::dialogs::FileDialogFuture, PlatformError> {
+        Err("File dialogs are not supported by this platform".into())
+    }
+
+    /// Returns the content of a resource that isn't a file of the file system, such as an asset of an
+    /// Android application. This is used when loading images and fonts from a path.
+    ///
+    /// Return `None` for the paths that should be read from the file system, which is what the default
+    /// implementation does.
+    #[cfg(feature = "std")]
+    fn read_resource(&self, _path: &str) -> Option<std::io::Result<Vec<u8>>> {
+        None
+    }
+
     /// This function is called when debug() is used in .slint files. The implementation
     /// should direct the output to some developer visible terminal. The default implementation
     /// uses stderr if available, or `console.log` when targeting wasm.
@@ -275,7 +408,8 @@ pub fn duration_until_next_timer_update() -> Option<core::time::Duration> {
 
 // reexport key enum to the public api
 pub use crate::input::key_codes::Key;
-pub use crate::input::PointerEventButton;
+pub use crate::input::{GesturePhase, PenState, PointerEventButton};
+pub use crate::items::DevicePosture;
 
 /// A event that describes user input or windowing system events.
 ///
@@ -315,6 +449,43 @@ pub enum WindowEvent {
     },
     /// The pointer exited the window.
     PointerExited,
+    /// A finger touched the touchscreen. `id` identifies the touch point until it's released.
+    ///
+    /// The first touch point is delivered like a pointer. When a second finger touches the screen,
+    /// the first two touch points make pinch, rotation, and pan gestures for the `GestureArea`
+    /// element instead. Backends that only know one touch point can send the pointer events instead.
+    TouchPressed { id: u64, position: LogicalPosition },
+    /// A touch point moved.
+    TouchMoved { id: u64, position: LogicalPosition },
+    /// A finger was lifted from the touchscreen, or the touch was cancelled.
+    TouchReleased { id: u64, position: LogicalPosition },
+    /// The tip or the eraser of a pen touched the screen. It's delivered like the left button of a
+    /// pointer, and the `TouchArea` reports the state of the pen in its `PointerEvent`.
+    PenPressed { position: LogicalPosition, state: PenState },
+    /// The pen moved, while it touches the screen or hovers over it.
+    PenMoved { position: LogicalPosition, state: PenState },
+    /// The pen was lifted from the screen.
+    PenReleased { position: LogicalPosition, state: PenState },
+    /// A pinch, rotation, or pan gesture recognized by the platform, for example on a touchpad.
+    /// Don't send this event for the touch points of a touchscreen sent with
+    /// [`WindowEvent::TouchPressed`], the gestures are recognized from those.
+    Gesture {
+        /// The center of the gesture.
+        position: LogicalPosition,
+        /// The factor by which the gesture scaled the content since the previous event, `1.0` when
+        /// the gesture doesn't scale.
+        scale: f32,
+        /// The angle in degrees by which the gesture rotated the content clockwise since the previous event.
+        rotation: f32,
+        /// The amount of logical pixels by which the gesture moved the content in the horizontal
+        /// direction since the previous event.
+        delta_x: f32,
+        /// The amount of logical pixels by which the gesture moved the content in the vertical
+        /// direction since the previous event.
+        delta_y: f32,
+        /// Whether the gesture starts, continues, or ends with this event.
+        phase: GesturePhase,
+    },
     /// A key was pressed.
     KeyPressed {
         /// The unicode representation of the key pressed.
@@ -365,6 +536,37 @@ pub enum WindowEvent {
         /// The new logical size of the window
         size: LogicalSize,
     },
+    /// The area on the edges of the window that is covered by system bars, a display cutout, or rounded
+    /// screen corners changed.
+    ///
+    /// The backend should send this event after the [`WindowEvent::Resized`] event when these insets change,
+    /// so that the `safe-area-inset-*` properties of the root Window element are set.
+    SafeAreaInsetsChanged {
+        /// The height of the obstructed area at the top of the window, in logical pixels
+        top: f32,
+        /// The height of the obstructed area at the bottom of the window, in logical pixels
+        bottom: f32,
+        /// The width of the obstructed area on the left of the window, in logical pixels
+        left: f32,
+        /// The width of the obstructed area on the right of the window, in logical pixels
+        right: f32,
+    },
+    /// The virtual keyboard was shown or hidden, or its height changed.
+    ///
+    /// The backend should send this event when the virtual keyboard covers the bottom of the window,
+    /// so that the `virtual-keyboard-height` property of the root Window element is set.
+    VirtualKeyboardHeightChanged {
+        /// The height of the part of the window covered by the virtual keyboard, in logical pixels, or 0 when it's hidden
+        height: f32,
+    },
+    /// The posture of a foldable device changed.
+    ///
+    /// The backend should send this event when the device is folded or unfolded, so that the
+    /// `device-posture` property of the root Window element is set.
+    DevicePostureChanged {
+        /// The new posture of the device
+        posture: DevicePosture,
+    },
     /// The user requested to close the window.
     ///
     /// The backend should send this event when the user tries to close the window,for example by pressing the close button.
@@ -378,6 +580,29 @@ pub enum WindowEvent {
     /// The backend should dispatch this event with true when the window gains focus
     /// and false when the window loses focus.
     WindowActiveChanged(bool),
+
+    /// Data from another application is dragged over the window.
+    ///
+    /// The backend should send this event each time the position of the drag changes, and then a
+    /// [`WindowEvent::Dropped`] or [`WindowEvent::DragExited`] event.
+    /// Files are represented with the `text/uri-list` mime type, as a list of `file://` URIs, one per line.
+    DragMoved {
+        position: LogicalPosition,
+        /// The mime type of the dragged data.
+        mime_type: SharedString,
+        /// The dragged data.
+        data: SharedString,
+    },
+    /// The data dragged over the window left it, or the drag was cancelled.
+    DragExited,
+    /// Data from another application was dropped on the window.
+    Dropped {
+        position: LogicalPosition,
+        /// The mime type of the dropped data.
+        mime_type: SharedString,
+        /// The dropped data.
+        data: SharedString,
+    },
 }
 
 impl WindowEvent {
@@ -388,6 +613,15 @@ pub fn position(&self) -> Option<LogicalPosition> {
             WindowEvent::PointerReleased { position, .. } => Some(*position),
             WindowEvent::PointerMoved { position } => Some(*position),
             WindowEvent::PointerScrolled { position, .. } => Some(*position),
+            WindowEvent::TouchPressed { position, .. } => Some(*position),
+            WindowEvent::TouchMoved { position, .. } => Some(*position),
+            WindowEvent::TouchReleased { position, .. } => Some(*position),
+            WindowEvent::PenPressed { position, .. } => Some(*position),
+            WindowEvent::PenMoved { position, .. } => Some(*position),
+            WindowEvent::PenReleased { position, .. } => Some(*position),
+            WindowEvent::Gesture { position, .. } => Some(*position),
+            WindowEvent::DragMoved { position, .. } => Some(*position),
+            WindowEvent::Dropped { position, .. } => Some(*position),
             _ => None,
         }
     }
@@ -230,6 +230,18 @@ fn c_set_animated_value<T: InterpolatedPropertyValue + Clone>(
     c_set_animated_value(handle, from.clone(), to.clone(), animation_data);
 }
 
+/// Internal function to set up a property animation to the specified target value for a path data property.
+#[cfg(feature = "std")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_property_set_animated_value_path_data(
+    handle: &PropertyHandleOpaque,
+    from: &crate::graphics::PathData,
+    to: &crate::graphics::PathData,
+    animation_data: &PropertyAnimation,
+) {
+    c_set_animated_value(handle, from.clone(), to.clone(), animation_data);
+}
+
 unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     handle: &PropertyHandleOpaque,
     binding: extern "C" fn(*mut c_void, *mut T),
@@ -371,6 +383,29 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     );
 }
 
+/// Internal function to set up a property animation between values produced by the specified binding for a path data property.
+#[cfg(feature = "std")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_property_set_animated_binding_path_data(
+    handle: &PropertyHandleOpaque,
+    binding: extern "C" fn(*mut c_void, *mut crate::graphics::PathData),
+    user_data: *mut c_void,
+    drop_user_data: Option<extern "C" fn(*mut c_void)>,
+    animation_data: Option<&PropertyAnimation>,
+    transition_data: Option<
+        extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
+    >,
+) {
+    c_set_animated_binding(
+        handle,
+        binding,
+        user_data,
+        drop_user_data,
+        animation_data,
+        transition_data,
+    );
+}
+
 /// Internal function to set up a state binding on a Property<StateInfo>.
 #[no_mangle]
 pub unsafe extern "C" fn slint_property_set_state_binding(
@@ -50,6 +50,10 @@ macro_rules! declare_ValueType_2 {
             crate::items::FontMetrics,
             crate::items::MenuEntry,
             crate::model::ModelRc<crate::items::MenuEntry>,
+            crate::items::TextSpan,
+            crate::model::ModelRc<crate::items::TextSpan>,
+            crate::items::GlyphTransform,
+            crate::items::DropEvent,
             $(crate::items::$Name,)*
         ];
     };
@@ -0,0 +1,190 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! The screens connected to the system, and the placement of windows on them
+
+#![warn(missing_docs)]
+
+use crate::api::{PhysicalPosition, PhysicalSize};
+use crate::SharedString;
+use alloc::boxed::Box;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// A screen (or monitor) connected to the system.
+///
+/// Use [`Screen::all()`] to enumerate the screens, and [`Window::move_to_screen()`](crate::api::Window::move_to_screen)
+/// to place a window on one of them.
+///
+/// # Example
+/// ```rust,no_run
+/// use slint::Screen;
+/// for screen in Screen::all() {
+///     println!("{}: {:?} at {:?}", screen.name(), screen.size(), screen.position());
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Screen {
+    name: SharedString,
+    position: PhysicalPosition,
+    size: PhysicalSize,
+    scale_factor: f32,
+    is_primary: bool,
+}
+
+impl Screen {
+    /// Creates a screen. This is used by the platform implementations of [`Platform::screens()`](crate::platform::Platform::screens).
+    ///
+    /// `position` and `size` are the geometry of the screen in the physical coordinates of the virtual desktop
+    /// that spans all the screens, like [`Window::position()`](crate::api::Window::position).
+    pub fn new(
+        name: impl Into<SharedString>,
+        position: PhysicalPosition,
+        size: PhysicalSize,
+        scale_factor: f32,
+        is_primary: bool,
+    ) -> Self {
+        Self { name: name.into(), position, size, scale_factor, is_primary }
+    }
+
+    /// Returns the screens connected to the system. The list is empty if the platform doesn't support
+    /// the enumeration of the screens.
+    pub fn all() -> Vec<Screen> {
+        crate::context::GLOBAL_CONTEXT
+            .with(|ctx| ctx.get().map(|ctx| ctx.platform().screens()))
+            .unwrap_or_default()
+    }
+
+    /// Returns the primary screen, if known.
+    pub fn primary() -> Option<Screen> {
+        let mut screens = Self::all();
+        let index = screens.iter().position(|s| s.is_primary).unwrap_or(0);
+        (index < screens.len()).then(|| screens.swap_remove(index))
+    }
+
+    /// Sets a callback that is invoked when screens are connected or disconnected, or when their
+    /// geometry or scale factor changes.
+    pub fn on_changed(callback: impl FnMut() + 'static) {
+        crate::context::GLOBAL_CONTEXT.with(|ctx| {
+            if let Some(ctx) = ctx.get() {
+                ctx.0.screens_changed.set(Box::new(callback));
+            }
+        });
+    }
+
+    /// The name of the screen, as reported by the system, for example the name of the connector or the model.
+    pub fn name(&self) -> SharedString {
+        self.name.clone()
+    }
+
+    /// The position of the top-left corner of the screen, in physical pixels.
+    pub fn position(&self) -> PhysicalPosition {
+        self.position
+    }
+
+    /// The size of the screen, in physical pixels.
+    pub fn size(&self) -> PhysicalSize {
+        self.size
+    }
+
+    /// The scale factor of the screen, to convert between logical and physical pixels.
+    pub fn scale_factor(&self) -> f32 {
+        self.scale_factor
+    }
+
+    /// Returns true if this is the primary screen of the system.
+    pub fn is_primary(&self) -> bool {
+        self.is_primary
+    }
+
+    /// Returns the video modes supported by the screen, for [`Window::set_exclusive_fullscreen()`](crate::api::Window::set_exclusive_fullscreen).
+    /// The list is empty if the platform doesn't support exclusive fullscreen.
+    pub fn video_modes(&self) -> Vec<VideoMode> {
+        crate::context::GLOBAL_CONTEXT
+            .with(|ctx| ctx.get().map(|ctx| ctx.platform().video_modes(self)))
+            .unwrap_or_default()
+    }
+
+    /// Returns true if the position, in physical pixels, is on this screen.
+    pub fn contains(&self, position: PhysicalPosition) -> bool {
+        position.x >= self.position.x
+            && position.y >= self.position.y
+            && position.x < self.position.x + self.size.width as i32
+            && position.y < self.position.y + self.size.height as i32
+    }
+
+    /// Returns the position of the top-left corner of a window of the given size, in physical pixels,
+    /// so that it's centered on the screen.
+    pub fn centered_position(&self, size: PhysicalSize) -> PhysicalPosition {
+        PhysicalPosition::new(
+            self.position.x + (self.size.width as i32 - size.width as i32) / 2,
+            self.position.y + (self.size.height as i32 - size.height as i32) / 2,
+        )
+    }
+}
+
+/// A video mode of a [`Screen`]: its resolution, refresh rate, and color depth, when a window is shown
+/// in exclusive fullscreen with [`Window::set_exclusive_fullscreen()`](crate::api::Window::set_exclusive_fullscreen).
+///
+/// # Example
+/// ```rust,no_run
+/// # fn example(window: &slint::Window) {
+/// // Use the mode with the highest refresh rate in full HD
+/// let screen = window.screen().unwrap();
+/// let mode = screen
+///     .video_modes()
+///     .into_iter()
+///     .filter(|mode| mode.size() == slint::PhysicalSize::new(1920, 1080))
+///     .max_by_key(|mode| mode.refresh_rate_millihertz());
+/// window.set_exclusive_fullscreen(mode);
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct VideoMode {
+    screen: Screen,
+    size: PhysicalSize,
+    refresh_rate_millihertz: u32,
+    bit_depth: u16,
+}
+
+impl VideoMode {
+    /// Creates a video mode of the screen. This is used by the platform implementations of
+    /// [`Platform::video_modes()`](crate::platform::Platform::video_modes).
+    pub fn new(
+        screen: Screen,
+        size: PhysicalSize,
+        refresh_rate_millihertz: u32,
+        bit_depth: u16,
+    ) -> Self {
+        Self { screen, size, refresh_rate_millihertz, bit_depth }
+    }
+
+    /// The screen of this video mode.
+    pub fn screen(&self) -> &Screen {
+        &self.screen
+    }
+
+    /// The resolution of the screen in this mode, in physical pixels.
+    pub fn size(&self) -> PhysicalSize {
+        self.size
+    }
+
+    /// The refresh rate, in millihertz. For example 60 Hz are 60000 mHz.
+    pub fn refresh_rate_millihertz(&self) -> u32 {
+        self.refresh_rate_millihertz
+    }
+
+    /// The number of bits per pixel.
+    pub fn bit_depth(&self) -> u16 {
+        self.bit_depth
+    }
+}
+
+/// Call this function from the platform when screens are connected or disconnected, or when their
+/// geometry changes, to invoke the callback set with [`Screen::on_changed()`].
+pub fn screens_changed() {
+    crate::context::GLOBAL_CONTEXT.with(|ctx| {
+        let Some(ctx) = ctx.get() else { return };
+        ctx.0.screens_changed.call(|callback| callback());
+    });
+}
@@ -24,7 +24,7 @@
 use crate::item_rendering::{
     CachedRenderingData, DirtyRegion, PartialRenderingState, RenderBorderRectangle, RenderImage,
 };
-use crate::items::{ItemRc, TextOverflow, TextWrap};
+use crate::items::{ItemRc, RenderingResult, TextHighlight, TextOverflow, TextWrap};
 use crate::lengths::{
     LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector,
     PhysicalPx, PointLengths, RectLengths, ScaleFactor, SizeLengths,
@@ -44,7 +44,10 @@
 use num_traits::Float;
 use num_traits::NumCast;
 
-pub use draw_functions::{PremultipliedRgbaColor, Rgb565Pixel, TargetPixel};
+pub use draw_functions::{
+    pack_mono_line, Dithering, Gray8Pixel, PremultipliedRgbaColor, Rgb565Pixel, SharpGray8Pixel,
+    TargetPixel,
+};
 
 type PhysicalLength = euclid::Length<i16, PhysicalPx>;
 type PhysicalRect = euclid::Rect<i16, PhysicalPx>;
@@ -841,7 +844,7 @@ fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError>
             .iter_mut()
             .zip(target_buffer.as_slice().iter())
         {
-            *target_pixel.rgb_mut() = *source_pixel;
+            *target_pixel = source_pixel.with_alpha(255);
         }
         Ok(target_buffer_with_alpha)
     }
@@ -1037,6 +1040,7 @@ trait ProcessScene {
     fn process_rounded_rectangle(&mut self, geometry: PhysicalRect, data: RoundedRectangle);
     fn process_shared_image_buffer(&mut self, geometry: PhysicalRect, buffer: SharedBufferCommand);
     fn process_gradient(&mut self, geometry: PhysicalRect, gradient: GradientCommand);
+    fn process_backdrop_blur(&mut self, geometry: PhysicalRect, radius: i16);
 }
 
 struct RenderToBuffer<'a, TargetPixel> {
@@ -1139,6 +1143,14 @@ fn process_gradient(&mut self, geometry: PhysicalRect, g: GradientCommand) {
             );
         });
     }
+
+    fn process_backdrop_blur(&mut self, geometry: PhysicalRect, radius: i16) {
+        for dirty_box in self.dirty_region.iter_box() {
+            if let Some(rect) = dirty_box.to_rect().intersection(&geometry) {
+                draw_functions::blur_rect(self.buffer, self.stride, &rect, radius);
+            }
+        }
+    }
 }
 
 #[derive(Default)]
@@ -1212,6 +1224,10 @@ fn process_gradient(&mut self, geometry: PhysicalRect, gradient: GradientCommand
             });
         }
     }
+
+    fn process_backdrop_blur(&mut self, _geometry: PhysicalRect, _radius: i16) {
+        // Not supported when rendering line by line, since the lines around are not available
+    }
 }
 
 struct SceneBuilder<'a, T> {
@@ -1437,13 +1453,47 @@ fn draw_text_paragraph<Font>(
         offset: euclid::Vector2D<f32, PhysicalPx>,
         color: Color,
         selection: Option<SelectionInfo>,
+        highlights: &[TextHighlight],
     ) where
         Font: AbstractFont + crate::textlayout::TextShaper<Length = PhysicalLength> + GlyphRenderer,
     {
+        let has_highlight_background = highlights.iter().any(|h| h.background.is_some());
         paragraph
             .layout_lines::<()>(
                 |glyphs, line_x, line_y, _, sel| {
                     let baseline_y = line_y + paragraph.layout.font.ascent();
+                    let mut collected_glyphs;
+                    let glyphs: &mut dyn Iterator<Item = _> = if has_highlight_background {
+                        // The backgrounds must be drawn before any glyph, so that they don't cover
+                        // parts of the previous glyph
+                        collected_glyphs = glyphs.collect::<Vec<_>>().into_iter();
+                        for positioned_glyph in collected_glyphs.as_slice() {
+                            let Some(background) =
+                                TextHighlight::find(highlights, positioned_glyph.text_byte_offset)
+                                    .and_then(|h| h.background)
+                            else {
+                                continue;
+                            };
+                            let geometry = euclid::rect(
+                                (line_x + positioned_glyph.x).get(),
+                                line_y.get(),
+                                positioned_glyph.advance.get(),
+                                paragraph.layout.font.height().get(),
+                            );
+                            if let Some(clipped_src) = geometry.intersection(&physical_clip.cast())
+                            {
+                                let geometry =
+                                    clipped_src.translate(offset.cast()).transformed(self.rotation);
+                                self.processor.process_rectangle(
+                                    geometry,
+                                    self.alpha_color(background).into(),
+                                );
+                            }
+                        }
+                        &mut collected_glyphs
+                    } else {
+                        glyphs
+                    };
                     if let (Some(sel), Some(selection)) = (sel, &selection) {
                         let geometry = euclid::rect(
                             line_x.get() + sel.start.get(),
@@ -1481,7 +1531,9 @@ fn draw_text_paragraph<Font>(
                             Some(s) if s.selection.contains(&positioned_glyph.text_byte_offset) => {
                                 s.selection_color
                             }
-                            _ => color,
+                            _ => TextHighlight::find(highlights, positioned_glyph.text_byte_offset)
+                                .and_then(|h| h.color)
+                                .map_or(color, |c| self.alpha_color(c)),
                         };
 
                         let Some(clipped_target) = physical_clip.intersection(&target_rect) else {
@@ -1976,7 +2028,7 @@ fn draw_text(
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None);
+                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None, &[]);
             }
             #[cfg(feature = "software-renderer-systemfonts")]
             fonts::Font::VectorFont(vf) => {
@@ -1995,7 +2047,7 @@ fn draw_text(
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None);
+                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None, &[]);
             }
         }
     }
@@ -2051,7 +2103,14 @@ fn draw_text_input(
                     single_line: text_input.single_line(),
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection);
+                self.draw_text_paragraph(
+                    &paragraph,
+                    physical_clip,
+                    offset,
+                    color,
+                    selection,
+                    &text_visual_representation.highlights,
+                );
 
                 text_visual_representation.cursor_position.map(|cursor_offset| {
                     (paragraph.cursor_pos_for_byte_offset(cursor_offset), pf.height())
@@ -2071,7 +2130,14 @@ fn draw_text_input(
                     single_line: text_input.single_line(),
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection);
+                self.draw_text_paragraph(
+                    &paragraph,
+                    physical_clip,
+                    offset,
+                    color,
+                    selection,
+                    &text_visual_representation.highlights,
+                );
 
                 text_visual_representation.cursor_position.map(|cursor_offset| {
                     (paragraph.cursor_pos_for_byte_offset(cursor_offset), vf.height())
@@ -2123,6 +2189,27 @@ fn draw_box_shadow(
         // TODO
     }
 
+    fn visit_backdrop_blur(
+        &mut self,
+        backdrop_blur_item: Pin<&crate::items::BackdropBlur>,
+        _: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        if !backdrop_blur_item.allow_approximation() {
+            return RenderingResult::ContinueRenderingChildren;
+        }
+        let radius = (backdrop_blur_item.blur_radius().cast() * self.scale_factor).get().round();
+        if let Some(clipped) = LogicalRect::from(size).intersection(&self.current_state.clip) {
+            let geometry = (clipped.translate(self.current_state.offset.to_vector()).cast()
+                * self.scale_factor)
+                .round()
+                .cast()
+                .transformed(self.rotation);
+            self.processor.process_backdrop_blur(geometry, radius as i16);
+        }
+        RenderingResult::ContinueRenderingChildren
+    }
+
     fn combine_clip(
         &mut self,
         other: LogicalRect,
@@ -2237,7 +2324,7 @@ fn draw_string(&mut self, string: &str, color: Color) {
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, clip, Default::default(), color, None);
+                self.draw_text_paragraph(&paragraph, clip, Default::default(), color, None, &[]);
             }
             #[cfg(feature = "software-renderer-systemfonts")]
             fonts::Font::VectorFont(vf) => {
@@ -2255,13 +2342,30 @@ fn draw_string(&mut self, string: &str, color: Color) {
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, clip, Default::default(), color, None);
+                self.draw_text_paragraph(&paragraph, clip, Default::default(), color, None, &[]);
             }
         }
     }
 
-    fn draw_image_direct(&mut self, _image: crate::graphics::Image) {
-        todo!()
+    fn draw_image_direct(&mut self, image: crate::graphics::Image) {
+        let source_size = image.size();
+        let geom = LogicalRect::from(LogicalSize::new(
+            source_size.width as Coord,
+            source_size.height as Coord,
+        ));
+        if self.should_draw(&geom) {
+            let phys_size = geom.size_length().cast() * self.scale_factor;
+            let fit = crate::graphics::fit(
+                crate::items::ImageFit::Fill,
+                phys_size,
+                euclid::Rect::new(Default::default(), source_size.cast()),
+                self.scale_factor,
+                Default::default(),
+                Default::default(),
+            );
+            let image_inner: &ImageInner = (&image).into();
+            self.draw_image_impl(image_inner, fit, Color::default());
+        }
     }
 
     fn window(&self) -> &crate::window::WindowInner {
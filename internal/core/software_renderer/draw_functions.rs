@@ -707,6 +707,14 @@ fn blend_slice(slice: &mut [Self], color: PremultipliedRgbaColor) {
     fn background() -> Self {
         Self::from_rgb(0, 0, 0)
     }
+
+    /// Returns the color of the pixel, or None if it can't be read back.
+    ///
+    /// This is used by effects that depend on what was already rendered, such as the approximation
+    /// of the `BackdropBlur` element. These effects are skipped if this returns None.
+    fn to_premultiplied_rgba(&self) -> Option<PremultipliedRgbaColor> {
+        None
+    }
 }
 
 impl TargetPixel for crate::graphics::image::Rgb8Pixel {
@@ -720,6 +728,10 @@ fn blend(&mut self, color: PremultipliedRgbaColor) {
     fn from_rgb(r: u8, g: u8, b: u8) -> Self {
         Self::new(r, g, b)
     }
+
+    fn to_premultiplied_rgba(&self) -> Option<PremultipliedRgbaColor> {
+        Some(PremultipliedRgbaColor { red: self.r, green: self.g, blue: self.b, alpha: 255 })
+    }
 }
 
 impl TargetPixel for PremultipliedRgbaColor {
@@ -739,6 +751,87 @@ fn from_rgb(r: u8, g: u8, b: u8) -> Self {
     fn background() -> Self {
         Self { red: 0, green: 0, blue: 0, alpha: 0 }
     }
+
+    fn to_premultiplied_rgba(&self) -> Option<PremultipliedRgbaColor> {
+        Some(*self)
+    }
+}
+
+/// Blurs the pixels of the buffer within the rectangle with a box blur of the given radius,
+/// applied horizontally and then vertically. Only the pixels within the rectangle are read.
+pub(super) fn blur_rect<T: TargetPixel>(
+    buffer: &mut [T],
+    stride: usize,
+    rect: &PhysicalRect,
+    radius: i16,
+) {
+    let (x, y) = (rect.min_x() as usize, rect.min_y() as usize);
+    let (width, height) = (rect.width() as usize, rect.height() as usize);
+    if width == 0 || height == 0 || radius <= 0 {
+        return;
+    }
+    let Some(mut pixels) = (0..height)
+        .flat_map(|line| buffer[(y + line) * stride + x..][..width].iter())
+        .map(|pixel| {
+            let c = pixel.to_premultiplied_rgba()?;
+            Some([c.red as u32, c.green as u32, c.blue as u32, c.alpha as u32])
+        })
+        .collect::<Option<alloc::vec::Vec<_>>>()
+    else {
+        return;
+    };
+
+    let mut sums = alloc::vec::Vec::new();
+    let mut blur_line = |start: usize, len: usize, step: usize| {
+        sums.clear();
+        sums.push([0u32; 4]);
+        for i in 0..len {
+            let (sum, pixel) = (sums[i], pixels[start + i * step]);
+            sums.push(core::array::from_fn(|c| sum[c] + pixel[c]));
+        }
+        for i in 0..len {
+            let begin = i.saturating_sub(radius as usize);
+            let end = (i + radius as usize + 1).min(len);
+            let count = (end - begin) as u32;
+            pixels[start + i * step] =
+                core::array::from_fn(|c| (sums[end][c] - sums[begin][c]) / count);
+        }
+    };
+    for line in 0..height {
+        blur_line(line * width, width, 1);
+    }
+    for column in 0..width {
+        blur_line(column, height, width);
+    }
+
+    for (line, pixels) in pixels.chunks(width).enumerate() {
+        for (target, [red, green, blue, alpha]) in
+            buffer[(y + line) * stride + x..][..width].iter_mut().zip(pixels)
+        {
+            *target = T::background();
+            target.blend(PremultipliedRgbaColor {
+                red: *red as u8,
+                green: *green as u8,
+                blue: *blue as u8,
+                alpha: *alpha as u8,
+            });
+        }
+    }
+}
+
+#[test]
+fn blur_rect_averages_neighbors() {
+    let white = Rgb8Pixel::new(255, 255, 255);
+    let black = Rgb8Pixel::new(0, 0, 0);
+    let mut buffer = [black, black, white, black, black, black];
+    blur_rect(&mut buffer, 6, &PhysicalRect::new((1, 0).into(), (4, 1).into()), 1);
+    // The first and last pixels are outside of the rectangle
+    assert_eq!(buffer[0], black);
+    assert_eq!(buffer[5], black);
+    assert_eq!(buffer[1], Rgb8Pixel::new(127, 127, 127));
+    assert_eq!(buffer[2], Rgb8Pixel::new(85, 85, 85));
+    assert_eq!(buffer[3], Rgb8Pixel::new(85, 85, 85));
+    assert_eq!(buffer[4], black);
 }
 
 /// A 16bit pixel that has 5 red bits, 6 green bits and  5 blue bits
@@ -796,6 +889,15 @@ fn blend(&mut self, color: PremultipliedRgbaColor) {
     fn from_rgb(r: u8, g: u8, b: u8) -> Self {
         Self(((r as u16 & 0b11111000) << 8) | ((g as u16 & 0b11111100) << 3) | (b as u16 >> 3))
     }
+
+    fn to_premultiplied_rgba(&self) -> Option<PremultipliedRgbaColor> {
+        Some(PremultipliedRgbaColor {
+            red: self.red(),
+            green: self.green(),
+            blue: self.blue(),
+            alpha: 255,
+        })
+    }
 }
 
 impl From<Rgb8Pixel> for Rgb565Pixel {
@@ -820,3 +922,165 @@ fn rgb565() {
     let pix888: Rgb8Pixel = pix565.into();
     assert_eq!(pix565, pix888.into());
 }
+
+/// Returns the luma of the color, using the BT.601 coefficients
+fn luma(red: u8, green: u8, blue: u8) -> u8 {
+    ((red as u32 * 77 + green as u32 * 150 + blue as u32 * 29 + 128) >> 8) as u8
+}
+
+/// An 8-bit greyscale pixel, for displays that can only show shades of grey, such as e-paper
+/// displays and monochrome OLEDs.
+///
+/// Colors are converted to their luma. Use [`Gray8Pixel::to_mono()`] or [`pack_mono_line()`] to
+/// convert the rendered pixels for displays with one bit per pixel.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Gray8Pixel(pub u8);
+
+impl Gray8Pixel {
+    /// Returns whether the pixel at the given position on the display is white on a display with
+    /// one bit per pixel. With [`Dithering::Ordered`], shades of grey are approximated by patterns.
+    pub fn to_mono(self, x: usize, y: usize, dithering: Dithering) -> bool {
+        let threshold = match dithering {
+            Dithering::None => 128,
+            Dithering::Ordered => {
+                const BAYER_4X4: [[u8; 4]; 4] =
+                    [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+                BAYER_4X4[y % 4][x % 4] as u16 * 16 + 8
+            }
+        };
+        self.0 as u16 >= threshold
+    }
+}
+
+impl TargetPixel for Gray8Pixel {
+    fn blend(&mut self, color: PremultipliedRgbaColor) {
+        let a = (u8::MAX - color.alpha) as u16;
+        self.0 = (self.0 as u16 * a / 255) as u8 + luma(color.red, color.green, color.blue);
+    }
+
+    fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        Self(luma(r, g, b))
+    }
+
+    fn to_premultiplied_rgba(&self) -> Option<PremultipliedRgbaColor> {
+        Some(PremultipliedRgbaColor { red: self.0, green: self.0, blue: self.0, alpha: 255 })
+    }
+}
+
+/// An 8-bit greyscale pixel like [`Gray8Pixel`], with a shorter anti-aliasing ramp.
+///
+/// Edges of text and shapes that cover less than a quarter of a pixel are dropped and edges that
+/// cover more than three quarters are drawn solid, so that the edges stay crisp on displays with
+/// one bit per pixel, where dithering would otherwise turn the grey fringes into noise.
+/// Translucent colors are affected in the same way.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct SharpGray8Pixel(pub u8);
+
+impl SharpGray8Pixel {
+    /// Returns whether the pixel at the given position is white on a display with one bit per pixel.
+    /// See [`Gray8Pixel::to_mono()`].
+    pub fn to_mono(self, x: usize, y: usize, dithering: Dithering) -> bool {
+        Gray8Pixel(self.0).to_mono(x, y, dithering)
+    }
+}
+
+impl TargetPixel for SharpGray8Pixel {
+    fn blend(&mut self, color: PremultipliedRgbaColor) {
+        if color.alpha == 0 {
+            return;
+        }
+        let sharp_alpha = ((color.alpha as i32 - 64) * 2).clamp(0, 255) as u16;
+        let luma =
+            luma(color.red, color.green, color.blue) as u16 * sharp_alpha / color.alpha as u16;
+        self.0 = (self.0 as u16 * (255 - sharp_alpha) / 255 + luma).min(255) as u8;
+    }
+
+    fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        Self(luma(r, g, b))
+    }
+
+    fn to_premultiplied_rgba(&self) -> Option<PremultipliedRgbaColor> {
+        Some(PremultipliedRgbaColor { red: self.0, green: self.0, blue: self.0, alpha: 255 })
+    }
+}
+
+/// The dithering that is applied when converting greyscale pixels to one bit per pixel.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum Dithering {
+    /// Pixels are black when they're darker than 50% grey, and white otherwise.
+    #[default]
+    None,
+    /// Shades of grey are approximated with a 4x4 Bayer matrix, which gives a regular pattern
+    /// that suits e-paper displays and doesn't flicker when only parts of the screen are updated.
+    Ordered,
+}
+
+/// Converts a line of greyscale pixels to one bit per pixel and stores them in `packed`, the most
+/// significant bit first, with 1 for white.
+///
+/// `x` and `y` are the position of the first pixel on the display, which are needed for the dithering.
+/// `packed` is the whole line of the display, of which only the bits of the given pixels are changed.
+/// This fits [`super::LineBufferProvider::process_line()`], which is passed the range of the line
+/// to render.
+pub fn pack_mono_line(
+    line: &[Gray8Pixel],
+    x: usize,
+    y: usize,
+    dithering: Dithering,
+    packed: &mut [u8],
+) {
+    for (i, pixel) in line.iter().enumerate() {
+        let x = x + i;
+        let mask = 0x80 >> (x % 8);
+        if pixel.to_mono(x, y, dithering) {
+            packed[x / 8] |= mask;
+        } else {
+            packed[x / 8] &= !mask;
+        }
+    }
+}
+
+#[test]
+fn gray8() {
+    assert_eq!(Gray8Pixel::from_rgb(255, 255, 255), Gray8Pixel(255));
+    assert_eq!(Gray8Pixel::from_rgb(0, 0, 0), Gray8Pixel(0));
+    assert_eq!(Gray8Pixel::from_rgb(0, 255, 0), Gray8Pixel(149));
+
+    let mut pixel = Gray8Pixel(255);
+    pixel.blend(PremultipliedRgbaColor { red: 0, green: 0, blue: 0, alpha: 128 });
+    assert_eq!(pixel, Gray8Pixel(127));
+
+    // Faint edges are dropped, strong ones are drawn solid
+    let mut pixel = SharpGray8Pixel(255);
+    pixel.blend(PremultipliedRgbaColor { red: 0, green: 0, blue: 0, alpha: 50 });
+    assert_eq!(pixel, SharpGray8Pixel(255));
+    pixel.blend(PremultipliedRgbaColor { red: 0, green: 0, blue: 0, alpha: 200 });
+    assert_eq!(pixel, SharpGray8Pixel(0));
+    let mut pixel = SharpGray8Pixel(0);
+    pixel.blend(PremultipliedRgbaColor { red: 128, green: 128, blue: 128, alpha: 128 });
+    assert_eq!(pixel, SharpGray8Pixel(128));
+}
+
+#[test]
+fn mono_dithering() {
+    let line = [Gray8Pixel(0), Gray8Pixel(255), Gray8Pixel(127), Gray8Pixel(128)];
+    let mut packed = [0b0000_0001, 0xff];
+    pack_mono_line(&line, 2, 0, Dithering::None, &mut packed);
+    assert_eq!(packed, [0b0001_0101, 0xff]);
+
+    // A 50% grey lights half of the pixels of each 4x4 block
+    let gray = [Gray8Pixel(128); 4];
+    let lit: usize = (0..4)
+        .map(|y| {
+            let mut packed = [0u8];
+            pack_mono_line(&gray, 0, y, Dithering::Ordered, &mut packed);
+            packed[0].count_ones() as usize
+        })
+        .sum();
+    assert_eq!(lit, 8);
+    assert!(!Gray8Pixel(0).to_mono(0, 0, Dithering::Ordered));
+    assert!(Gray8Pixel(255).to_mono(3, 3, Dithering::Ordered));
+}
@@ -53,6 +53,8 @@ pub trait GlyphRenderer {
 
 mod pixelfont;
 #[cfg(feature = "software-renderer-systemfonts")]
+mod rasterizer;
+#[cfg(feature = "software-renderer-systemfonts")]
 pub mod vectorfont;
 
 #[cfg(feature = "software-renderer-systemfonts")]
@@ -0,0 +1,230 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Rasterizes glyph outlines into alpha maps with signed area accumulation, like font-rs.
+//!
+//! fontdue only rasterizes the default instance of variable fonts, so the glyphs of variable fonts with
+//! axis values are rasterized from the outlines that ttf-parser computes for these values.
+
+use alloc::vec::Vec;
+use rustybuzz::ttf_parser;
+
+type Point = euclid::default::Point2D<f32>;
+
+/// The result of [`rasterize_glyph`]. The coverage is stored row by row from the top, and `x` and `y`
+/// are the offset of the left and bottom edge of the bitmap from the glyph origin, with y pointing up.
+#[derive(Default)]
+pub struct RasterizedGlyph {
+    pub x: i32,
+    pub y: i32,
+    pub width: usize,
+    pub height: usize,
+    pub alpha_map: Vec<u8>,
+}
+
+/// Rasterizes the outline of the glyph at the given scale (pixels per font unit), with the variations
+/// currently set on the face. Glyphs without outline, such as spaces, result in an empty bitmap.
+pub fn rasterize_glyph(
+    face: &ttf_parser::Face,
+    glyph_id: ttf_parser::GlyphId,
+    scale: f32,
+) -> RasterizedGlyph {
+    let Some(bounding_box) = face.glyph_bounding_box(glyph_id) else {
+        return RasterizedGlyph::default();
+    };
+    let left = (bounding_box.x_min as f32 * scale).floor();
+    let right = (bounding_box.x_max as f32 * scale).ceil();
+    let bottom = (bounding_box.y_min as f32 * scale).floor();
+    let top = (bounding_box.y_max as f32 * scale).ceil();
+    let width = (right - left) as usize;
+    let height = (top - bottom) as usize;
+
+    let mut builder = Builder {
+        accumulator: Accumulator::new(width, height),
+        scale,
+        left,
+        top,
+        start: Point::zero(),
+        current: Point::zero(),
+    };
+    if face.outline_glyph(glyph_id, &mut builder).is_none() {
+        return RasterizedGlyph::default();
+    }
+
+    RasterizedGlyph {
+        x: left as i32,
+        y: bottom as i32,
+        width,
+        height,
+        alpha_map: builder.accumulator.into_alpha_map(),
+    }
+}
+
+/// Collects the signed area that the edges of the outline cover in each pixel. The running sum of a
+/// row is the coverage of the pixels.
+struct Accumulator {
+    width: usize,
+    height: usize,
+    area: Vec<f32>,
+}
+
+impl Accumulator {
+    fn new(width: usize, height: usize) -> Self {
+        // The last edge of a row can add to the first pixel of the next row
+        Self { width, height, area: alloc::vec![0.; width * height + 4] }
+    }
+
+    fn line(&mut self, p0: Point, p1: Point) {
+        if (p0.y - p1.y).abs() <= f32::EPSILON {
+            return;
+        }
+        let (direction, p0, p1) = if p0.y < p1.y { (1., p0, p1) } else { (-1., p1, p0) };
+        let dxdy = (p1.x - p0.x) / (p1.y - p0.y);
+        let mut x = p0.x;
+        if p0.y < 0. {
+            x -= p0.y * dxdy;
+        }
+        let width = self.width as isize;
+        for y in (p0.y.max(0.) as usize)..(self.height.min(p1.y.ceil() as usize)) {
+            let line_start = (y * self.width) as isize;
+            let dy = ((y + 1) as f32).min(p1.y) - (y as f32).max(p0.y);
+            let x_next = x + dxdy * dy;
+            let d = dy * direction;
+            let (x0, x1) = if x < x_next { (x, x_next) } else { (x_next, x) };
+            let x0_floor = x0.floor();
+            let x0i = (x0_floor as isize).clamp(0, width);
+            let x1_ceil = x1.ceil();
+            let x1i = (x1_ceil as isize).clamp(0, width);
+            let mut add = |index: isize, value: f32| {
+                if let Some(area) = self.area.get_mut((line_start + index) as usize) {
+                    *area += value;
+                }
+            };
+            if x1i <= x0i + 1 {
+                let xmf = 0.5 * (x + x_next) - x0_floor;
+                add(x0i, d - d * xmf);
+                add(x0i + 1, d * xmf);
+            } else {
+                let s = (x1 - x0).recip();
+                let x0f = x0 - x0_floor;
+                let a0 = 0.5 * s * (1. - x0f) * (1. - x0f);
+                let x1f = x1 - x1_ceil + 1.;
+                let am = 0.5 * s * x1f * x1f;
+                add(x0i, d * a0);
+                if x1i == x0i + 2 {
+                    add(x0i + 1, d * (1. - a0 - am));
+                } else {
+                    let a1 = s * (1.5 - x0f);
+                    add(x0i + 1, d * (a1 - a0));
+                    for xi in x0i + 2..x1i - 1 {
+                        add(xi, d * s);
+                    }
+                    let a2 = a1 + (x1i - x0i - 3) as f32 * s;
+                    add(x1i - 1, d * (1. - a2 - am));
+                }
+                add(x1i, d * am);
+            }
+            x = x_next;
+        }
+    }
+
+    fn into_alpha_map(self) -> Vec<u8> {
+        let mut sum = 0.;
+        self.area[..self.width * self.height]
+            .iter()
+            .map(|area| {
+                sum += area;
+                (sum.abs().min(1.) * 255.).round() as u8
+            })
+            .collect()
+    }
+}
+
+/// Transforms the outline from font units into the pixels of the bitmap, and flattens the curves.
+struct Builder {
+    accumulator: Accumulator,
+    scale: f32,
+    left: f32,
+    top: f32,
+    start: Point,
+    current: Point,
+}
+
+impl Builder {
+    fn point(&self, x: f32, y: f32) -> Point {
+        Point::new(x * self.scale - self.left, self.top - y * self.scale)
+    }
+
+    fn line(&mut self, to: Point) {
+        self.accumulator.line(self.current, to);
+        self.current = to;
+    }
+
+    /// The number of line segments that approximate a curve with the given control polygon deviation
+    fn segments(deviation: euclid::default::Vector2D<f32>) -> usize {
+        1 + (3. * deviation.square_length()).sqrt().sqrt().floor() as usize
+    }
+}
+
+impl ttf_parser::OutlineBuilder for Builder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.start = self.point(x, y);
+        self.current = self.start;
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let to = self.point(x, y);
+        self.line(to);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (p0, p1, p2) = (self.current, self.point(x1, y1), self.point(x, y));
+        let segments = Self::segments(p0.to_vector() - p1.to_vector() * 2. + p2.to_vector());
+        for i in 1..=segments {
+            let t = i as f32 / segments as f32;
+            let to = p0.lerp(p1, t).lerp(p1.lerp(p2, t), t);
+            self.line(to);
+        }
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (p0, p1, p2, p3) =
+            (self.current, self.point(x1, y1), self.point(x2, y2), self.point(x, y));
+        let deviation = |a: Point, b: Point, c: Point| {
+            (a.to_vector() - b.to_vector() * 2. + c.to_vector()).square_length()
+        };
+        let segments = Self::segments(euclid::vec2(
+            deviation(p0, p1, p2).max(deviation(p1, p2, p3)).sqrt(),
+            0.,
+        ));
+        for i in 1..=segments {
+            let t = i as f32 / segments as f32;
+            let (a, b, c) = (p0.lerp(p1, t), p1.lerp(p2, t), p2.lerp(p3, t));
+            let to = a.lerp(b, t).lerp(b.lerp(c, t), t);
+            self.line(to);
+        }
+    }
+
+    fn close(&mut self) {
+        let start = self.start;
+        self.line(start);
+    }
+}
+
+#[test]
+fn rasterize_square() {
+    // A 2x2 pixel square in the middle of a 4x4 bitmap, with half covered pixels around it
+    let mut accumulator = Accumulator::new(4, 4);
+    let corners = [(0.5, 0.5), (3.5, 0.5), (3.5, 3.5), (0.5, 3.5), (0.5, 0.5)].map(Point::from);
+    for edge in corners.windows(2) {
+        accumulator.line(edge[0], edge[1]);
+    }
+    let alpha_map = accumulator.into_alpha_map();
+    #[rustfmt::skip]
+    assert_eq!(alpha_map, [
+        64, 128, 128, 64,
+        128, 255, 255, 128,
+        128, 255, 255, 128,
+        64, 128, 128, 64,
+    ]);
+}
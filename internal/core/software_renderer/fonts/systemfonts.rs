@@ -53,10 +53,21 @@ pub fn match_font(
 
         sharedfontdb::FONT_DB.with(|fonts| {
             let borrowed_fontdb = fonts.borrow();
-            borrowed_fontdb.query_with_family(query, Some(family_str)).map(|font_id| {
-                let fontdue_font = get_or_create_fontdue_font(&borrowed_fontdb, font_id);
-                VectorFont::new(font_id, fontdue_font.clone(), requested_pixel_size)
-            })
+            // Glyphs can't be taken from several fonts, so use the first family of the fallback chain
+            // that is available
+            core::iter::once(family_str.clone())
+                .chain(request.fallback_families())
+                .find_map(|family| borrowed_fontdb.query_with_family(query, Some(&family)))
+                .map(|font_id| {
+                    let fontdue_font = get_or_create_fontdue_font(&borrowed_fontdb, font_id);
+                    VectorFont::new(
+                        font_id,
+                        fontdue_font.clone(),
+                        requested_pixel_size,
+                        request.features(),
+                        request.variations(),
+                    )
+                })
         })
     })
 }
@@ -68,12 +79,21 @@ pub fn fallbackfont(font_request: &super::FontRequest, scale_factor: ScaleFactor
     sharedfontdb::FONT_DB.with_borrow(|fonts| {
         let query = font_request.to_fontdb_query();
 
-        let fallback_font_id = fonts
-            .query_with_family(query, None)
+        let fallback_font_id = font_request
+            .fallback_families()
+            .iter()
+            .find_map(|family| fonts.query_with_family(query, Some(family)))
+            .or_else(|| fonts.query_with_family(query, None))
             .expect("fatal: query for fallback font returned empty font list");
 
         let fontdue_font = get_or_create_fontdue_font(&fonts, fallback_font_id);
-        VectorFont::new(fallback_font_id, fontdue_font, requested_pixel_size)
+        VectorFont::new(
+            fallback_font_id,
+            fontdue_font,
+            requested_pixel_size,
+            font_request.features(),
+            font_request.variations(),
+        )
     })
 }
 
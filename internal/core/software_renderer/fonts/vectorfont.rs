@@ -3,11 +3,13 @@
 
 use alloc::rc::Rc;
 
+use crate::graphics::{FontFeature, FontVariation};
 use crate::lengths::PhysicalPx;
 use crate::software_renderer::fixed::Fixed;
 use crate::software_renderer::PhysicalLength;
 use crate::textlayout::{Glyph, TextShaper};
 use i_slint_common::sharedfontdb::{self, fontdb};
+use rustybuzz::ttf_parser;
 
 use super::RenderableGlyph;
 
@@ -16,7 +18,11 @@
 type FontLength = euclid::Length<i32, FontUnit>;
 type FontScaleFactor = euclid::Scale<f32, FontUnit, PhysicalPx>;
 
-type GlyphCacheKey = (fontdb::ID, PhysicalLength, core::num::NonZeroU16);
+/// The axis values of a variable font. The values are stored as bits, so that they can be part of the
+/// glyph cache key.
+type Variations = Rc<[(ttf_parser::Tag, u32)]>;
+
+type GlyphCacheKey = (fontdb::ID, PhysicalLength, core::num::NonZeroU16, Variations);
 
 struct RenderableGlyphWeightScale;
 
@@ -55,6 +61,21 @@ pub struct VectorFont {
     pixel_size: PhysicalLength,
     x_height: PhysicalLength,
     cap_height: PhysicalLength,
+    features: Vec<rustybuzz::Feature>,
+    /// Empty unless the font is a variable font with some of the requested axes
+    variations: Variations,
+}
+
+fn parse_face<'a>(
+    face_data: &'a [u8],
+    font_index: u32,
+    variations: &Variations,
+) -> ttf_parser::Face<'a> {
+    let mut face = ttf_parser::Face::parse(face_data, font_index).unwrap();
+    for (tag, value) in variations.iter() {
+        face.set_variation(*tag, f32::from_bits(*value));
+    }
+    face
 }
 
 impl VectorFont {
@@ -62,11 +83,30 @@ pub fn new(
         id: fontdb::ID,
         fontdue_font: Rc<fontdue::Font>,
         pixel_size: PhysicalLength,
+        features: impl Iterator<Item = FontFeature>,
+        variations: impl Iterator<Item = FontVariation>,
     ) -> Self {
+        let features = features
+            .map(|feature| {
+                rustybuzz::Feature::new(
+                    ttf_parser::Tag::from_bytes(&feature.tag),
+                    feature.value,
+                    ..,
+                )
+            })
+            .collect();
         sharedfontdb::FONT_DB.with(|db| {
             db.borrow()
                 .with_face_data(id, |face_data, font_index| {
-                    let face = rustybuzz::ttf_parser::Face::parse(face_data, font_index).unwrap();
+                    let face = ttf_parser::Face::parse(face_data, font_index).unwrap();
+                    let axes = face.variation_axes();
+                    let variations: Variations = variations
+                        .map(|variation| {
+                            (ttf_parser::Tag::from_bytes(&variation.tag), variation.value.to_bits())
+                        })
+                        .filter(|(tag, _)| axes.into_iter().any(|axis| axis.tag == *tag))
+                        .collect();
+                    let face = parse_face(face_data, font_index, &variations);
 
                     let ascender = FontLength::new(face.ascender() as _);
                     let descender = FontLength::new(face.descender() as _);
@@ -86,6 +126,8 @@ pub fn new(
                         pixel_size,
                         x_height: (x_height.cast() * scale).cast(),
                         cap_height: (cap_height.cast() * scale).cast(),
+                        features,
+                        variations,
                     }
                 })
                 .unwrap()
@@ -107,10 +149,10 @@ fn shape_text<GlyphStorage: core::iter::Extend<Glyph<PhysicalLength>>>(
         sharedfontdb::FONT_DB.with(|db| {
             db.borrow()
                 .with_face_data(self.id, |face_data, font_index| {
-                    let face = rustybuzz::ttf_parser::Face::parse(face_data, font_index).unwrap();
+                    let face = parse_face(face_data, font_index, &self.variations);
                     let rb_face = rustybuzz::Face::from_face(face);
 
-                    let glyph_buffer = rustybuzz::shape(&rb_face, &[], buffer);
+                    let glyph_buffer = rustybuzz::shape(&rb_face, &self.features, buffer);
 
                     let output_glyph_generator = glyph_buffer
                         .glyph_infos()
@@ -144,7 +186,7 @@ fn glyph_for_char(&self, ch: char) -> Option<Glyph<PhysicalLength>> {
         sharedfontdb::FONT_DB.with(|db| {
             db.borrow()
                 .with_face_data(self.id, |face_data, font_index| {
-                    let face = rustybuzz::ttf_parser::Face::parse(face_data, font_index).unwrap();
+                    let face = parse_face(face_data, font_index, &self.variations);
                     face.glyph_index(ch).map(|glyph_index| {
                         let mut out_glyph = Glyph::default();
 
@@ -196,24 +238,43 @@ fn render_glyph(&self, glyph_id: core::num::NonZeroU16) -> Option<super::Rendera
         GLYPH_CACHE.with(|cache| {
             let mut cache = cache.borrow_mut();
 
-            let cache_key = (self.id, self.pixel_size, glyph_id);
+            let cache_key = (self.id, self.pixel_size, glyph_id, self.variations.clone());
 
             if let Some(entry) = cache.get(&cache_key) {
                 Some(entry.clone())
             } else {
-                let (metrics, alpha_map) =
-                    self.fontdue_font.rasterize_indexed(glyph_id.get(), self.pixel_size.get() as _);
+                let (x, y, width, height, alpha_map) = if self.variations.is_empty() {
+                    let (metrics, alpha_map) = self
+                        .fontdue_font
+                        .rasterize_indexed(glyph_id.get(), self.pixel_size.get() as _);
+                    (metrics.xmin, metrics.ymin, metrics.width, metrics.height, alpha_map)
+                } else {
+                    // fontdue doesn't support variable fonts, so rasterize the outline for the axis values
+                    let glyph = sharedfontdb::FONT_DB.with(|db| {
+                        db.borrow()
+                            .with_face_data(self.id, |face_data, font_index| {
+                                let face = parse_face(face_data, font_index, &self.variations);
+                                super::rasterizer::rasterize_glyph(
+                                    &face,
+                                    ttf_parser::GlyphId(glyph_id.get()),
+                                    self.scale.get(),
+                                )
+                            })
+                            .unwrap()
+                    });
+                    (glyph.x, glyph.y, glyph.width, glyph.height, glyph.alpha_map)
+                };
 
                 let alpha_map: Rc<[u8]> = alpha_map.into();
 
                 let glyph = super::RenderableGlyph {
-                    x: Fixed::from_integer(metrics.xmin.try_into().unwrap()),
-                    y: Fixed::from_integer(metrics.ymin.try_into().unwrap()),
-                    width: PhysicalLength::new(metrics.width.try_into().unwrap()),
-                    height: PhysicalLength::new(metrics.height.try_into().unwrap()),
+                    x: Fixed::from_integer(x),
+                    y: Fixed::from_integer(y),
+                    width: PhysicalLength::new(width.try_into().unwrap()),
+                    height: PhysicalLength::new(height.try_into().unwrap()),
                     alpha_map: alpha_map.into(),
                     sdf: false,
-                    pixel_stride: metrics.width.try_into().unwrap(),
+                    pixel_stride: width.try_into().unwrap(),
                 };
 
                 cache.put_with_weight(cache_key, glyph.clone()).ok();
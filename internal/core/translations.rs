@@ -5,6 +5,11 @@
 use core::fmt::Display;
 pub use formatter::FormatArgs;
 
+#[cfg(feature = "fluent")]
+mod fluent;
+#[cfg(feature = "fluent")]
+pub use fluent::{load_fluent_translations, FluentSyntaxError};
+
 mod formatter {
     use core::fmt::{Display, Formatter, Result};
 
@@ -189,10 +194,18 @@ pub fn translate(
 ) -> SharedString {
     #![allow(unused)]
     let mut output = SharedString::default();
+    use core::fmt::Write;
+    #[cfg(feature = "fluent")]
+    {
+        let arguments: std::vec::Vec<_> =
+            (0..).map_while(|index| arguments.from_index(index).map(|a| a.to_string())).collect();
+        if let Some(translated) = fluent::translate(original, contextid, n, &arguments) {
+            return translated.into();
+        }
+    }
     let translated = if plural.is_empty() || n == 1 { original } else { plural };
     #[cfg(all(target_family = "unix", feature = "gettext-rs"))]
     let translated = translate_gettext(original, contextid, domain, n, plural);
-    use core::fmt::Write;
     write!(output, "{}", formatter::format(&translated, &WithPlural(arguments, n))).unwrap();
     output
 }
@@ -0,0 +1,180 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Support for translations in the [Fluent](https://projectfluent.org) format, with the
+//! [fluent-bundle](https://crates.io/crates/fluent-bundle) crate
+//!
+//! The `@tr` strings are looked up by the identifier returned by
+//! [`i_slint_common::translations::fluent_message_id`], which is also used by slint-tr-extractor.
+//!
+//! The positional arguments of the `@tr` string are available as the `$arg0`, `$arg1`, ... variables,
+//! and the argument after `%` is the `$n` variable.
+
+use crate::SharedString;
+use fluent_bundle::{FluentArgs, FluentBundle, FluentResource, FluentValue};
+use std::cell::RefCell;
+
+/// Error returned by [`load_fluent_translations`] when the Fluent resource has a syntax error
+#[derive(Debug, Clone, PartialEq)]
+pub struct FluentSyntaxError {
+    /// The line of the error, starting at 1
+    pub line: usize,
+    /// The description of the error
+    pub message: SharedString,
+}
+
+impl core::fmt::Display for FluentSyntaxError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for FluentSyntaxError {}
+
+std::thread_local! {
+    static BUNDLE: RefCell<Option<FluentBundle<FluentResource>>> = const { RefCell::new(None) };
+}
+
+/// Load the translations of the given language from the source of a Fluent (`.ftl`) resource.
+///
+/// The translations replace the previously loaded Fluent translations, and take precedence over
+/// the gettext translations. The strings that are not in the resource are not translated.
+/// Call this function with an empty `source` to go back to the untranslated strings.
+///
+/// The language (for example `"de"` or `"pt-BR"`) selects the plural rules.
+pub fn load_fluent_translations(language: &str, source: &str) -> Result<(), FluentSyntaxError> {
+    let bundle = if source.is_empty() { None } else { Some(new_bundle(language, source)?) };
+    BUNDLE.with(|b| *b.borrow_mut() = bundle);
+    super::mark_all_translations_dirty();
+    Ok(())
+}
+
+fn new_bundle(
+    language: &str,
+    source: &str,
+) -> Result<FluentBundle<FluentResource>, FluentSyntaxError> {
+    let resource = FluentResource::try_new(source.into()).map_err(|(_, errors)| {
+        let error = &errors[0];
+        let line = source[..error.pos.start.min(source.len())].matches('\n').count() + 1;
+        FluentSyntaxError { line, message: error.kind.to_string().into() }
+    })?;
+    // Locales from the environment can have an encoding or a modifier, like `de_DE.UTF-8`
+    let language = language.find(['.', '@']).map_or(language, |i| &language[..i]);
+    let mut bundle = FluentBundle::new(language.parse().ok().into_iter().collect());
+    bundle.set_use_isolating(false);
+    bundle.add_builtins().expect("the builtin functions are only added once");
+    bundle.add_resource_overriding(resource);
+    Ok(bundle)
+}
+
+/// Returns the translated and formatted string, or None if there is no Fluent translation for that string.
+pub(super) fn translate(
+    original: &str,
+    contextid: &str,
+    n: i32,
+    arguments: &[String],
+) -> Option<String> {
+    super::global_translation_property();
+    BUNDLE.with(|b| {
+        let b = b.borrow();
+        let bundle = b.as_ref()?;
+        format(
+            bundle,
+            &i_slint_common::translations::fluent_message_id(contextid, original),
+            n,
+            arguments,
+        )
+    })
+}
+
+fn format(
+    bundle: &FluentBundle<FluentResource>,
+    id: &str,
+    n: i32,
+    arguments: &[String],
+) -> Option<String> {
+    let pattern = bundle.get_message(id)?.value()?;
+    let mut args = FluentArgs::new();
+    args.set("n", n);
+    for (index, argument) in arguments.iter().enumerate() {
+        args.set(std::format!("arg{index}"), FluentValue::try_number(argument));
+    }
+    // Errors, like unknown variables, are rendered in the string, like `{$name}`
+    let mut errors = Vec::new();
+    Some(bundle.format_pattern(pattern, Some(&args), &mut errors).into_owned())
+}
+
+#[test]
+fn test_fluent() {
+    let resource = r#"
+# A comment
+-brand = Slint
+hello = Hello from { -brand }!
+    .tooltip = ignored
+multi-line =
+    First line
+    second { "{" }line{ "}" }
+
+files = { $n ->
+    [0] No file
+    [one] One file
+   *[other] { $n } files
+}
+place = { NUMBER($n, type: "ordinal") ->
+    [one] {$n}st
+    [two] {$n}nd
+    [few] {$n}rd
+   *[other] {$n}th
+}
+greet = Hello { $arg0 } and { $arg1 }
+items = { $arg0 ->
+    [one] One item
+   *[other] { $arg0 } items
+}
+"#;
+    let bundle = new_bundle("en-US", resource).unwrap();
+    let resolve = |id: &str, n: i32| format(&bundle, id, n, &["A".into(), "B".into()]).unwrap();
+    assert_eq!(resolve("hello", 1), "Hello from Slint!");
+    assert_eq!(resolve("multi-line", 1), "First line\nsecond {line}");
+    assert_eq!(resolve("files", 0), "No file");
+    assert_eq!(resolve("files", 1), "One file");
+    assert_eq!(resolve("files", 42), "42 files");
+    assert_eq!(resolve("place", 1), "1st");
+    assert_eq!(resolve("place", 12), "12th");
+    assert_eq!(resolve("place", 23), "23rd");
+    assert_eq!(resolve("greet", 1), "Hello A and B");
+    assert_eq!(format(&bundle, "items", 1, &["1".into()]).unwrap(), "One item");
+    assert_eq!(format(&bundle, "items", 1, &["3".into()]).unwrap(), "3 items");
+    assert!(format(&bundle, "missing", 1, &[]).is_none());
+
+    assert_eq!(new_bundle("en", "oops = { $n ->\n [one] x\n}\n").err().unwrap().line, 3);
+    assert_eq!(new_bundle("en", "ok = fine\n= no id").err().unwrap().line, 2);
+
+    let resource = "files = { $n ->\n [one] one\n [few] few\n [many] many\n*[other] other\n}\n";
+    let category = |language, n| format(&new_bundle(language, resource).unwrap(), "files", n, &[]);
+    assert_eq!(category("ru", 21).unwrap(), "one");
+    assert_eq!(category("ru", 22).unwrap(), "few");
+    assert_eq!(category("ru", 25).unwrap(), "many");
+    assert_eq!(category("pl", 12).unwrap(), "many");
+    assert_eq!(category("fr_FR.UTF-8", 0).unwrap(), "one");
+    assert_eq!(category("ja", 1).unwrap(), "other");
+    assert_eq!(category("ar", 5).unwrap(), "few");
+}
+
+#[test]
+fn test_fluent_translate() {
+    let resource = "Main--Hello-World = Hallo Welt\n\
+                    Main--Hello = Hallo { $arg0 }, { $arg1 }\n\
+                    ctx--n-files = { $n ->\n    [one] Eine Datei\n   *[other] { $n } Dateien\n}\n";
+    load_fluent_translations("de", resource).unwrap();
+    let tr = |original, context, args: &[&str], n, plural| {
+        super::translate(original, context, "", args, n, plural).to_string()
+    };
+    assert_eq!(tr("Hello World", "Main", &[], 1, ""), "Hallo Welt");
+    assert_eq!(tr("Hello", "Main", &["A", "B"], 1, ""), "Hallo A, B");
+    assert_eq!(tr("{n} files", "ctx", &[], 1, "{n} files"), "Eine Datei");
+    assert_eq!(tr("{n} files", "ctx", &[], 3, "{n} files"), "3 Dateien");
+    assert_eq!(tr("Not translated {}", "Main", &["x"], 1, ""), "Not translated x");
+    load_fluent_translations("", "").unwrap();
+    assert_eq!(tr("Hello World", "Main", &[], 1, ""), "Hello World");
+}
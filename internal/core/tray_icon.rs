@@ -0,0 +1,184 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! The icon of the application in the system tray (also called notification area, or status bar on macOS)
+
+#![warn(missing_docs)]
+
+use crate::api::{ComponentHandle, PlatformError};
+use crate::callbacks::CallbackSlot;
+use crate::graphics::Image;
+use crate::window::{MenuVTable, WindowInner};
+use crate::SharedString;
+use alloc::boxed::Box;
+use alloc::rc::{Rc, Weak};
+use core::cell::RefCell;
+
+/// An icon shown in the system tray: the notification area of the task bar on Windows, the status
+/// bar on macOS, or a StatusNotifierItem on Linux desktops.
+///
+/// The icon can have a tooltip, and a menu that is shown when the user right-clicks on it.
+/// The menu is declared in a `.slint` file, with a `Menu` element in a component inheriting `Window`.
+/// A `Menu` contains `MenuItem` elements, and other `Menu` elements for the sub-menus.
+/// That component's window doesn't need to be shown.
+///
+/// The tray icon is removed from the system tray when the `TrayIcon` is dropped.
+///
+/// Use [`run_event_loop_until_quit()`](crate::api::run_event_loop_until_quit) to keep the
+/// application running while no window is shown.
+///
+/// # Example
+/// ```rust,no_run
+/// use slint::ComponentHandle;
+/// slint::slint! {
+///     export component TrayMenu inherits Window {
+///         callback show-settings();
+///         callback quit();
+///         Menu {
+///             MenuItem { title: "Settings"; activated => { root.show-settings(); } }
+///             Menu {
+///                 title: "Help";
+///                 MenuItem { title: "About"; }
+///             }
+///             MenuItem { title: "Quit"; activated => { root.quit(); } }
+///         }
+///     }
+/// }
+/// let menu = TrayMenu::new().unwrap();
+/// menu.on_show_settings(|| println!("Show the settings"));
+/// menu.on_quit(|| slint::quit_event_loop().unwrap());
+///
+/// let tray_icon = slint::TrayIcon::new().unwrap();
+/// tray_icon.set_icon(&slint::Image::load_from_path("icon.png".as_ref()).unwrap());
+/// tray_icon.set_tooltip("My Application");
+/// tray_icon.set_menu(&menu);
+/// tray_icon.on_activated(|| println!("The tray icon was clicked"));
+///
+/// slint::run_event_loop_until_quit().unwrap();
+/// ```
+pub struct TrayIcon {
+    inner: Rc<TrayIconInner>,
+}
+
+impl TrayIcon {
+    /// Creates a new icon in the system tray.
+    ///
+    /// The icon is initially empty: call [`Self::set_icon()`] to show something.
+    ///
+    /// Returns an error if the platform doesn't support system tray icons, or if the platform was
+    /// not initialized yet. The platform is initialized when the first component is created.
+    pub fn new() -> Result<Self, PlatformError> {
+        let inner = Rc::new(TrayIconInner::default());
+        let handle = TrayIconHandle(Rc::downgrade(&inner));
+        let adapter = crate::context::with_global_context(
+            || Err(PlatformError::NoPlatform),
+            |ctx| ctx.platform().create_tray_icon_adapter(handle),
+        )??;
+        inner.adapter.replace(Some(adapter));
+        Ok(Self { inner })
+    }
+
+    /// Sets the image shown in the system tray.
+    pub fn set_icon(&self, icon: &Image) {
+        self.inner.with_adapter(|adapter| adapter.set_icon(icon));
+    }
+
+    /// Sets the text shown when the pointer hovers the icon.
+    pub fn set_tooltip(&self, tooltip: impl Into<SharedString>) {
+        let tooltip = tooltip.into();
+        self.inner.with_adapter(|adapter| adapter.set_tooltip(&tooltip));
+    }
+
+    /// Shows or hides the icon in the system tray. The icon is visible by default.
+    pub fn set_visible(&self, visible: bool) {
+        self.inner.with_adapter(|adapter| adapter.set_visible(visible));
+    }
+
+    /// Uses the `Menu` declared in the given component as menu of the tray icon.
+    ///
+    /// The entries and their sub-menus are queried when the menu is shown, and the `activated`
+    /// callback of a `MenuItem` is invoked when the user selects it.
+    /// If the component doesn't have a `Menu`, the entries of its `MenuBar` are used instead,
+    /// and if it has neither, the tray icon has no menu.
+    pub fn set_menu(&self, component: &impl ComponentHandle) {
+        let menu = WindowInner::from_pub(component.window()).menu();
+        self.inner.with_adapter(|adapter| adapter.set_menu(menu));
+    }
+
+    /// Removes the menu of the tray icon.
+    pub fn clear_menu(&self) {
+        self.inner.with_adapter(|adapter| adapter.set_menu(None));
+    }
+
+    /// Sets a callback that is invoked when the user clicks on the icon. On Linux, some desktops
+    /// only report a double-click.
+    pub fn on_activated(&self, callback: impl FnMut() + 'static) {
+        self.inner.activated.set(Box::new(callback));
+    }
+
+    /// Sets a callback that is invoked when the user requests the context menu of the icon,
+    /// usually with a right-click. If a menu was set with [`Self::set_menu()`], it is shown by the
+    /// platform after this callback returns, so it can be used to update the menu entries.
+    pub fn on_context_menu_requested(&self, callback: impl FnMut() + 'static) {
+        self.inner.context_menu_requested.set(Box::new(callback));
+    }
+}
+
+#[derive(Default)]
+struct TrayIconInner {
+    adapter: RefCell<Option<Box<dyn TrayIconAdapter>>>,
+    activated: CallbackSlot<dyn FnMut()>,
+    context_menu_requested: CallbackSlot<dyn FnMut()>,
+}
+
+impl TrayIconInner {
+    fn with_adapter(&self, f: impl FnOnce(&dyn TrayIconAdapter)) {
+        if let Some(adapter) = self.adapter.borrow().as_ref() {
+            f(&**adapter)
+        }
+    }
+}
+
+/// This trait represents the platform implementation of a [`TrayIcon`].
+///
+/// It is created by [`Platform::create_tray_icon_adapter()`](crate::platform::Platform::create_tray_icon_adapter),
+/// and dropped when the icon should be removed from the system tray.
+pub trait TrayIconAdapter {
+    /// Sets the image shown in the system tray.
+    fn set_icon(&self, icon: &Image);
+
+    /// Sets the text shown when the pointer hovers the icon.
+    fn set_tooltip(&self, tooltip: &str);
+
+    /// Sets the menu shown with the icon, or removes it if `menu` is `None`.
+    fn set_menu(&self, menu: Option<vtable::VBox<MenuVTable>>);
+
+    /// Shows or hides the icon.
+    fn set_visible(&self, visible: bool);
+}
+
+/// The events that a [`TrayIconAdapter`] reports with [`TrayIconHandle::dispatch_event()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TrayIconEvent {
+    /// The user clicked on the icon.
+    Activated,
+    /// The user requested the context menu of the icon.
+    ContextMenuRequested,
+}
+
+/// A handle given to the [`TrayIconAdapter`], to report the events of the tray icon.
+#[derive(Clone)]
+pub struct TrayIconHandle(Weak<TrayIconInner>);
+
+impl TrayIconHandle {
+    /// Dispatches the event to the callbacks of the [`TrayIcon`]. Does nothing if the tray icon was dropped.
+    pub fn dispatch_event(&self, event: TrayIconEvent) {
+        let Some(inner) = self.0.upgrade() else { return };
+        let callback = match event {
+            TrayIconEvent::Activated => &inner.activated,
+            TrayIconEvent::ContextMenuRequested => &inner.context_menu_requested,
+        };
+        callback.call(|f| f());
+    }
+}
@@ -7,15 +7,18 @@
 //! Exposed Window API
 
 use crate::api::{
-    CloseRequestResponse, LogicalPosition, PhysicalPosition, PhysicalSize, PlatformError, Window,
-    WindowPosition, WindowSize,
+    CloseRequestResponse, FrameStatistics, LogicalPosition, PhysicalPosition, PhysicalSize,
+    PlatformError, Window, WindowPosition, WindowSize,
 };
 use crate::input::{
     key_codes, ClickState, InternalKeyboardModifierState, KeyEvent, KeyEventType, MouseEvent,
     MouseInputState, TextCursorBlinker,
 };
 use crate::item_tree::{ItemRc, ItemTreeRc, ItemTreeRef, ItemTreeVTable, ItemTreeWeak, ItemWeak};
-use crate::items::{ColorScheme, InputType, ItemRef, MenuEntry, MouseCursor, PopupClosePolicy};
+use crate::items::{
+    ColorScheme, EventResult, ImeAction, InputType, ItemRef, MenuEntry, MouseCursor,
+    PopupClosePolicy, WindowControl,
+};
 use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, SizeLengths};
 use crate::properties::{Property, PropertyTracker};
 use crate::renderer::Renderer;
@@ -32,6 +35,8 @@
 use vtable::VRcMapped;
 
 pub mod popup;
+pub mod toast;
+pub mod window_manager;
 
 fn next_focus_item(item: ItemRc) -> ItemRc {
     item.next_focus_item()
@@ -193,6 +198,32 @@ fn set_mouse_cursor(&self, _cursor: MouseCursor) {}
     /// This method allow editable input field to communicate with the platform about input methods
     fn input_method_request(&self, _: InputMethodRequest) {}
 
+    /// Starts a drag and drop operation run by the windowing system, so that the data of a
+    /// `DragArea` can be dropped in other applications. The windowing system reports the drag
+    /// over this window with the drag and drop [`WindowEvent`](crate::platform::WindowEvent)s.
+    ///
+    /// Returns false if the platform doesn't support it (the default), in which case the data
+    /// can only be dropped in the same window.
+    fn start_drag(&self, _data: &crate::items::DropEvent) -> bool {
+        false
+    }
+
+    /// Starts moving the window with the pointer, when the title bar of a `WindowControlArea` is pressed.
+    /// Returns false if the platform doesn't support it (the default).
+    fn drag_window(&self) -> bool {
+        false
+    }
+
+    /// Starts resizing the window from the given edge or corner with the pointer, when the border of
+    /// a `WindowControlArea` is pressed. Returns false if the platform doesn't support it (the default).
+    fn drag_resize_window(&self, _edge: WindowControl) -> bool {
+        false
+    }
+
+    /// Shows the system menu of the window at the given position in window coordinates, when the
+    /// title bar of a `WindowControlArea` is right-clicked.
+    fn show_window_menu(&self, _position: LogicalPoint) {}
+
     /// Return self as any so the backend can upcast
     // TODO: consider using the as_any crate, or deriving the traint from Any to provide a better default
     fn as_any(&self) -> &dyn core::any::Any {
@@ -237,6 +268,18 @@ fn display_handle_06_rc(
     fn bring_to_front(&self) -> Result<(), PlatformError> {
         Ok(())
     }
+
+    /// Makes the window a child of the given native window when it's shown.
+    /// The caller guarantees that the handle stays valid while the window is shown.
+    #[cfg(feature = "raw-window-handle-06")]
+    fn set_parent_window_handle_06(
+        &self,
+        _parent: raw_window_handle_06::RawWindowHandle,
+    ) -> Result<(), PlatformError> {
+        Err(PlatformError::Other(
+            "Embedding a window in a native window is not supported by this platform".into(),
+        ))
+    }
 }
 
 /// This is the parameter from [`WindowAdapterInternal::input_method_request()`] which lets the editable text input field
@@ -277,8 +320,16 @@ pub struct InputMethodProperties {
     pub cursor_rect_size: crate::api::LogicalSize,
     /// The position of the anchor (bottom). Only meaningful if anchor_position is Some
     pub anchor_point: LogicalPosition,
+    /// The top-left corner, in window coordinates, of the area that the candidate window of the
+    /// input method is placed next to. This is the cursor rectangle, unless the `TextInput` sets
+    /// its `ime-candidate-area-*` properties.
+    pub candidate_rect_origin: LogicalPosition,
+    /// The size of the area that the candidate window of the input method is placed next to.
+    pub candidate_rect_size: crate::api::LogicalSize,
     /// The type of input for the text edit.
     pub input_type: InputType,
+    /// The action of the enter key of the virtual keyboard.
+    pub ime_action: ImeAction,
 }
 
 /// This struct describes layout constraints of a resizable element, such as a window.
@@ -341,6 +392,12 @@ pub fn is_fullscreen(&self) -> bool {
         self.0.is_fullscreen()
     }
 
+    /// The video mode to switch to when the window is fullscreen, or `None` for a borderless fullscreen
+    /// window that keeps the current video mode of the screen.
+    pub fn exclusive_fullscreen(&self) -> Option<crate::api::VideoMode> {
+        self.0.exclusive_fullscreen.borrow().clone()
+    }
+
     /// true if the window is in a maximized state, otherwise false
     pub fn is_maximized(&self) -> bool {
         self.0.maximized.get()
@@ -350,6 +407,47 @@ pub fn is_maximized(&self) -> bool {
     pub fn is_minimized(&self) -> bool {
         self.0.minimized.get()
     }
+
+    /// true if the windowing system should composite the window with the content behind it
+    pub fn is_transparent(&self) -> bool {
+        self.0.transparent.get()
+    }
+
+    /// The effect to apply to the content behind the transparent parts of the window
+    pub fn backdrop(&self) -> crate::api::WindowBackdrop {
+        self.0.backdrop.get()
+    }
+
+    /// The region of the window that is shown and receives pointer input, if the whole window doesn't
+    pub fn input_region(&self) -> Option<crate::api::WindowRegion> {
+        self.0.input_region.borrow().clone()
+    }
+
+    /// true if the window should be shown above the other windows
+    pub fn is_always_on_top(&self) -> bool {
+        self.0.is_always_on_top()
+    }
+
+    /// true if the window should be hidden from the task bar
+    pub fn skips_taskbar(&self) -> bool {
+        self.0.skip_taskbar.get()
+    }
+
+    /// true if the mouse pointer should be confined to the window while it has the focus
+    pub fn is_pointer_confined(&self) -> bool {
+        self.0.pointer_confined.get()
+    }
+
+    /// true if the window should grab the keyboard, so that it receives the key combinations
+    /// usually handled by the windowing system
+    pub fn is_keyboard_grabbed(&self) -> bool {
+        self.0.keyboard_grabbed.get()
+    }
+
+    /// true if the screensaver and the automatic sleep of the display should be prevented while the window is shown
+    pub fn inhibits_screensaver(&self) -> bool {
+        self.0.inhibit_screensaver.get()
+    }
 }
 
 struct WindowPropertiesTracker {
@@ -428,12 +526,23 @@ pub struct WindowInner {
     /// When the window is visible, keep a strong reference
     strong_component_ref: RefCell<Option<ItemTreeRc>>,
     mouse_input_state: Cell<MouseInputState>,
+    /// The touch points of the touchscreen, see [`crate::platform::WindowEvent::TouchPressed`]
+    pub(crate) touch_state: RefCell<crate::input::TouchState>,
+    /// The device that sent the pointer event being dispatched, and the state of the pen
+    pub(crate) pointer_device: Cell<(crate::input::PointerDevice, crate::input::PenState)>,
+    /// The safe area insets (top, bottom, left, right), see [`crate::platform::WindowEvent::SafeAreaInsetsChanged`]
+    safe_area_insets: Cell<[LogicalLength; 4]>,
+    /// The height of the virtual keyboard, see [`crate::platform::WindowEvent::VirtualKeyboardHeightChanged`]
+    virtual_keyboard_height: Cell<LogicalLength>,
+    device_posture: Cell<crate::items::DevicePosture>,
     pub(crate) modifiers: Cell<InternalKeyboardModifierState>,
 
     /// ItemRC that currently have the focus. (possibly a, instance of TextInput)
     pub focus_item: RefCell<crate::item_tree::ItemWeak>,
     /// The last text that was sent to the input method
     pub(crate) last_ime_text: RefCell<SharedString>,
+    /// The last candidate area set on the `TextInput` that was sent to the input method
+    pub(crate) last_ime_candidate_area: Cell<LogicalRect>,
     /// Don't let ComponentContainers's instantiation change the focus.
     /// This is a workaround for a recursion when instantiating ComponentContainer because the
     /// init code for the component might have code that sets the focus, but we don't want that
@@ -444,13 +553,36 @@ pub struct WindowInner {
     pinned_fields: Pin<Box<WindowPinnedFields>>,
     maximized: Cell<bool>,
     minimized: Cell<bool>,
+    transparent: Cell<bool>,
+    backdrop: Cell<crate::api::WindowBackdrop>,
+    exclusive_fullscreen: RefCell<Option<crate::api::VideoMode>>,
+    skip_taskbar: Cell<bool>,
+    pointer_confined: Cell<bool>,
+    keyboard_grabbed: Cell<bool>,
+    inhibit_screensaver: Cell<bool>,
+    input_region: RefCell<Option<crate::api::WindowRegion>>,
 
     /// Stack of currently active popups
     active_popups: RefCell<Vec<PopupWindow>>,
     next_popup_id: Cell<NonZeroU32>,
     had_popup_on_press: Cell<bool>,
+    /// Toasts shown by the toast layer of the window
+    toasts: toast::WindowToasts,
     close_requested: Callback<(), CloseRequestResponse>,
+    /// The id of the window in the window manager, allocated the first time it's needed
+    window_id: Cell<Option<window_manager::WindowId>>,
+    /// Creates an instance of the menu declared with the `Menu` or the `MenuBar` of the window, if any
+    menu_factory: RefCell<Option<Box<dyn Fn() -> vtable::VBox<MenuVTable>>>>,
+    /// The data dragged over the window, by a `DragArea` or by another application
+    drag_data: RefCell<Option<crate::items::DropEvent>>,
+    /// True when the drag was started by a `DragArea` of this window and isn't run by the windowing system
+    internal_drag: Cell<bool>,
+    /// Set when a `DropArea` accepts the data dragged over the window
+    drag_accepted: Cell<bool>,
     click_state: ClickState,
+    /// The statistics collected while rendering the current frame
+    frame_statistics: RefCell<FrameStatistics>,
+    last_frame_statistics: RefCell<Option<FrameStatistics>>,
     pub(crate) ctx: once_cell::unsync::Lazy<crate::SlintContext>,
 }
 
@@ -488,6 +620,11 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             component: Default::default(),
             strong_component_ref: Default::default(),
             mouse_input_state: Default::default(),
+            touch_state: Default::default(),
+            pointer_device: Default::default(),
+            safe_area_insets: Default::default(),
+            virtual_keyboard_height: Default::default(),
+            device_posture: Default::default(),
             modifiers: Default::default(),
             pinned_fields: Box::pin(WindowPinnedFields {
                 redraw_tracker,
@@ -501,14 +638,31 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             }),
             maximized: Cell::new(false),
             minimized: Cell::new(false),
+            transparent: Cell::new(true),
+            backdrop: Default::default(),
+            exclusive_fullscreen: Default::default(),
+            skip_taskbar: Default::default(),
+            pointer_confined: Default::default(),
+            keyboard_grabbed: Default::default(),
+            inhibit_screensaver: Default::default(),
+            input_region: Default::default(),
             focus_item: Default::default(),
             last_ime_text: Default::default(),
+            last_ime_candidate_area: Default::default(),
             cursor_blinker: Default::default(),
             active_popups: Default::default(),
             next_popup_id: Cell::new(NonZeroU32::MIN),
             had_popup_on_press: Default::default(),
+            toasts: Default::default(),
             close_requested: Default::default(),
+            window_id: Default::default(),
+            menu_factory: Default::default(),
+            drag_data: Default::default(),
+            internal_drag: Default::default(),
+            drag_accepted: Default::default(),
             click_state: ClickState::default(),
+            frame_statistics: Default::default(),
+            last_frame_statistics: Default::default(),
             prevent_focus_change: Default::default(),
             // The ctx is lazy so that a Window can be initialized before the backend.
             // (for example in test_empty_window)
@@ -543,6 +697,9 @@ pub fn set_component(&self, component: &ItemTreeRc) {
         self.set_window_item_geometry(
             window_adapter.size().to_logical(self.scale_factor()).to_euclid(),
         );
+        self.set_safe_area_insets(self.safe_area_insets.get());
+        self.set_virtual_keyboard_height(self.virtual_keyboard_height.get());
+        self.set_device_posture(self.device_posture.get());
         window_adapter.request_redraw();
         let weak = Rc::downgrade(&window_adapter);
         crate::timers::Timer::single_shot(Default::default(), move || {
@@ -578,6 +735,28 @@ pub fn active_popups(&self) -> core::cell::Ref<'_, [PopupWindow]> {
     pub fn process_mouse_input(&self, mut event: MouseEvent) {
         crate::animations::update_animations();
 
+        // The pointer is outside of the shape of the window, unless an item grabbed it
+        if let (Some(position), Some(region)) = (event.position(), &*self.input_region.borrow()) {
+            if !region.contains(crate::lengths::logical_position_to_api(position)) {
+                let mouse_input_state = self.mouse_input_state.take();
+                if !mouse_input_state.grabbed {
+                    event = MouseEvent::Exit;
+                }
+                self.mouse_input_state.set(mouse_input_state);
+            }
+        }
+
+        // While dragging the data of a `DragArea`, the pointer moves the data over the `DropArea`s
+        let internal_drag = self.internal_drag.get();
+        if internal_drag {
+            event = match event {
+                MouseEvent::Moved { position } => MouseEvent::DragMove { position },
+                MouseEvent::Released { position, .. } => MouseEvent::Drop { position },
+                event => event,
+            };
+            self.drag_accepted.set(false);
+        }
+
         // handle multiple press release
         event = self.click_state.check_repeat(event, self.ctx.platform().click_interval());
 
@@ -677,6 +856,11 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
 
         self.mouse_input_state.set(mouse_input_state);
 
+        if internal_drag && matches!(event, MouseEvent::Drop { .. } | MouseEvent::Exit) {
+            self.internal_drag.set(false);
+            self.drag_data.take();
+        }
+
         if let Some(popup_id) = popup_to_close {
             self.close_popup(popup_id);
         }
@@ -684,6 +868,29 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
         crate::properties::ChangeTracker::run_change_handlers();
     }
 
+    /// Like [`Self::process_mouse_input()`], for an event sent by a touchscreen or a pen instead of a mouse.
+    pub(crate) fn process_device_mouse_input(
+        &self,
+        event: MouseEvent,
+        device: crate::input::PointerDevice,
+        pen: crate::input::PenState,
+    ) {
+        self.pointer_device.set((device, pen));
+        self.process_mouse_input(event);
+        self.pointer_device.take();
+    }
+
+    /// Dispatches the pointer events that [`crate::input::TouchState`] made from the touch points.
+    pub(crate) fn process_touch_input(&self, events: Vec<MouseEvent>) {
+        for event in events {
+            self.process_device_mouse_input(
+                event,
+                crate::input::PointerDevice::Touch,
+                Default::default(),
+            );
+        }
+    }
+
     /// Called by the input code's internal timer to send an event that was delayed
     pub(crate) fn process_delayed_event(&self) {
         self.mouse_input_state.set(crate::input::process_delayed_event(
@@ -746,14 +953,7 @@ pub fn process_key_input(&self, mut event: KeyEvent) {
             && event.text.starts_with(key_codes::Escape)
         {
             // Closes top most popup on esc key pressed when policy is not no-auto-close
-            let close_on_escape = if let Some(popup) = self.active_popups.borrow().last() {
-                popup.close_policy == PopupClosePolicy::CloseOnClick
-                    || popup.close_policy == PopupClosePolicy::CloseOnClickOutside
-            } else {
-                false
-            };
-
-            if close_on_escape {
+            if self.top_popup_closes_automatically() {
                 self.close_top_popup();
             }
         }
@@ -904,6 +1104,7 @@ pub fn focus_previous_item(&self) {
     /// This results in WindowFocusReceived and WindowFocusLost events.
     pub fn set_active(&self, have_focus: bool) {
         self.pinned_fields.as_ref().project_ref().active.set(have_focus);
+        self.ctx.0.window_manager.window_activated(self.window_id(), have_focus);
 
         let event = if have_focus {
             crate::input::FocusEvent::WindowReceivedFocus
@@ -919,6 +1120,9 @@ pub fn set_active(&self, have_focus: bool) {
         // should not assume that the modifiers are in the same state.
         if !have_focus {
             self.modifiers.take();
+        } else {
+            // Another application may have changed the clipboard while the window was inactive
+            crate::clipboard::check_for_external_change(&self.ctx);
         }
     }
 
@@ -952,28 +1156,73 @@ pub fn draw_contents<T>(
         render_components: impl FnOnce(&[(&ItemTreeRc, LogicalPoint)]) -> T,
     ) -> Option<T> {
         let component_rc = self.try_component()?;
-        Some(self.pinned_fields.as_ref().project_ref().redraw_tracker.evaluate_as_dependency_root(
-            || {
-                if !self
-                    .active_popups
-                    .borrow()
-                    .iter()
-                    .any(|p| matches!(p.location, PopupWindowLocation::ChildWindow(..)))
-                {
-                    render_components(&[(&component_rc, LogicalPoint::default())])
-                } else {
-                    let borrow = self.active_popups.borrow();
-                    let mut cmps = Vec::with_capacity(borrow.len() + 1);
-                    cmps.push((&component_rc, LogicalPoint::default()));
-                    for popup in borrow.iter() {
-                        if let PopupWindowLocation::ChildWindow(location) = &popup.location {
-                            cmps.push((&popup.component, *location));
+        *self.frame_statistics.borrow_mut() = Default::default();
+        let start_time = crate::animations::Instant::duration_since_start();
+        let result =
+            self.pinned_fields.as_ref().project_ref().redraw_tracker.evaluate_as_dependency_root(
+                || {
+                    if !self
+                        .active_popups
+                        .borrow()
+                        .iter()
+                        .any(|p| matches!(p.location, PopupWindowLocation::ChildWindow(..)))
+                    {
+                        render_components(&[(&component_rc, LogicalPoint::default())])
+                    } else {
+                        let borrow = self.active_popups.borrow();
+                        let mut cmps = Vec::with_capacity(borrow.len() + 1);
+                        cmps.push((&component_rc, LogicalPoint::default()));
+                        for popup in borrow.iter() {
+                            if let PopupWindowLocation::ChildWindow(location) = &popup.location {
+                                cmps.push((&popup.component, *location));
+                            }
                         }
+                        render_components(&cmps)
                     }
-                    render_components(&cmps)
-                }
-            },
-        ))
+                },
+            );
+        let mut statistics = self.frame_statistics.take();
+        statistics.rendering_duration =
+            crate::animations::Instant::duration_since_start().saturating_sub(start_time);
+        *self.last_frame_statistics.borrow_mut() = Some(statistics);
+        Some(result)
+    }
+
+    /// Returns the statistics about the last frame rendered by [`Self::draw_contents`].
+    pub fn last_frame_statistics(&self) -> Option<FrameStatistics> {
+        self.last_frame_statistics.borrow().clone()
+    }
+
+    /// Counts an item drawn in the frame being rendered, for the frame statistics.
+    pub(crate) fn count_rendered_item(&self) {
+        self.frame_statistics.borrow_mut().items_rendered += 1;
+    }
+
+    /// Records the region that's redrawn in the frame being rendered, for the frame statistics.
+    pub(crate) fn set_frame_dirty_region(&self, region: &crate::item_rendering::DirtyRegion) {
+        let factor = crate::lengths::ScaleFactor::new(self.scale_factor());
+        self.frame_statistics.borrow_mut().dirty_region = Some(
+            region
+                .iter()
+                .map(|rect| {
+                    let rect = (rect.to_rect().cast::<f32>() * factor).round_out();
+                    (
+                        PhysicalPosition::new(rect.origin.x as _, rect.origin.y as _),
+                        PhysicalSize::new(rect.size.width as _, rect.size.height as _),
+                    )
+                })
+                .collect(),
+        );
+    }
+
+    /// Records the metrics of the renderer for the frame being rendered, for the frame statistics.
+    pub(crate) fn set_frame_rendering_metrics(
+        &self,
+        metrics: crate::graphics::rendering_metrics_collector::RenderingMetrics,
+    ) {
+        let mut statistics = self.frame_statistics.borrow_mut();
+        statistics.layers_created = metrics.layers_created;
+        statistics.layer_cache_hits = metrics.layer_cache_hits;
     }
 
     /// Registers the window with the windowing system, in order to render the component's items and react
@@ -983,6 +1232,10 @@ pub fn show(&self) -> Result<(), PlatformError> {
             let was_visible = self.strong_component_ref.replace(Some(component)).is_some();
             if !was_visible {
                 *(self.ctx.0.window_count.borrow_mut()) += 1;
+                self.ctx
+                    .0
+                    .window_manager
+                    .window_shown(self.window_id(), self.window_adapter_weak.clone());
             }
         }
 
@@ -1004,6 +1257,7 @@ pub fn hide(&self) -> Result<(), PlatformError> {
         let result = self.window_adapter().set_visible(false);
         let was_visible = self.strong_component_ref.borrow_mut().take().is_some();
         if was_visible {
+            self.ctx.0.window_manager.window_hidden(self.window_id());
             let mut count = self.ctx.0.window_count.borrow_mut();
             *count -= 1;
             if *count <= 0 {
@@ -1035,6 +1289,66 @@ pub fn setup_menubar(&self, menubar: vtable::VBox<MenuVTable>) {
         }
     }
 
+    /// Sets up the menu declared with the `MenuBar` of the window as native menu bar if supported.
+    /// Unless the window also has a `Menu`, it is registered to be used by a [`TrayIcon`](crate::api::TrayIcon).
+    pub fn register_menubar(&self, factory: impl Fn() -> vtable::VBox<MenuVTable> + 'static) {
+        if self.supports_native_menu_bar() {
+            self.setup_menubar(factory());
+        }
+        if self.menu_factory.borrow().is_none() {
+            self.register_menu(factory);
+        }
+    }
+
+    /// Registers the menu declared with the `Menu` of the window, to be used by a
+    /// [`TrayIcon`](crate::api::TrayIcon).
+    pub fn register_menu(&self, factory: impl Fn() -> vtable::VBox<MenuVTable> + 'static) {
+        self.menu_factory.replace(Some(Box::new(factory)));
+    }
+
+    /// Returns a new instance of the menu declared with the `Menu` or the `MenuBar` of the window, if any
+    pub fn menu(&self) -> Option<vtable::VBox<MenuVTable>> {
+        self.menu_factory.borrow().as_ref().map(|factory| factory())
+    }
+
+    /// Starts dragging the data of a `DragArea`. If the windowing system doesn't run the drag,
+    /// the following pointer events are sent as [`MouseEvent::DragMove`] and [`MouseEvent::Drop`].
+    pub(crate) fn start_drag(&self, data: crate::items::DropEvent) {
+        let window_adapter = self.window_adapter();
+        if window_adapter.internal(crate::InternalToken).is_some_and(|x| x.start_drag(&data)) {
+            return;
+        }
+        self.drag_data.replace(Some(data));
+        self.internal_drag.set(true);
+    }
+
+    /// Returns the data dragged over the window, if any.
+    pub fn drag_data(&self) -> Option<crate::items::DropEvent> {
+        self.drag_data.borrow().clone()
+    }
+
+    /// Called by the `DropArea` that accepts the data dragged over the window.
+    pub(crate) fn accept_drag(&self) {
+        self.drag_accepted.set(true);
+    }
+
+    /// Sends a drag or drop event of another application to the items, and returns true if a
+    /// `DropArea` accepted the data. `event` is the [`MouseEvent::DragMove`] or [`MouseEvent::Drop`]
+    /// for `data`, or [`MouseEvent::Exit`] when the drag left the window.
+    pub fn process_external_drag(
+        &self,
+        event: MouseEvent,
+        data: Option<crate::items::DropEvent>,
+    ) -> bool {
+        self.drag_data.replace(data);
+        self.drag_accepted.set(false);
+        self.process_mouse_input(event);
+        if !matches!(event, MouseEvent::DragMove { .. }) {
+            self.drag_data.take();
+        }
+        self.drag_accepted.get()
+    }
+
     /// Show a popup at the given position relative to the item and returns its ID.
     /// The returned ID will always be non-zero.
     pub fn show_popup(
@@ -1213,6 +1527,26 @@ pub fn close_top_popup(&self) {
         }
     }
 
+    /// Returns true if the top-most popup can be closed by the escape key or the back button,
+    /// because its close policy is not no-auto-close.
+    fn top_popup_closes_automatically(&self) -> bool {
+        self.active_popups.borrow().last().is_some_and(|popup| {
+            popup.close_policy == PopupClosePolicy::CloseOnClick
+                || popup.close_policy == PopupClosePolicy::CloseOnClickOutside
+        })
+    }
+
+    /// Returns the model of the toasts shown in the window, the oldest first.
+    /// It's the model of the toast layer that the compiler adds to the window.
+    pub fn toasts(&self) -> crate::model::ModelRc<crate::items::ToastEntry> {
+        self.toasts.entries()
+    }
+
+    /// Invokes the action of the toast with the given id and closes it, when its action is clicked.
+    pub fn activate_toast(&self, toast_id: i32) {
+        self.toasts.activate(toast_id)
+    }
+
     /// Returns the scale factor set on the window, as provided by the windowing system.
     pub fn scale_factor(&self) -> f32 {
         self.pinned_fields.as_ref().project_ref().scale_factor.get()
@@ -1238,6 +1572,30 @@ pub fn is_visible(&self) -> bool {
         self.strong_component_ref.borrow().is_some()
     }
 
+    /// Returns the control of the enabled and visible `WindowControlArea` at the given position in
+    /// window coordinates, if any. The backends use it to translate the areas to the native behavior
+    /// of the title bar and the borders of the window.
+    pub fn window_control_at(&self, position: LogicalPoint) -> Option<WindowControl> {
+        // `position` is relative to `item`. The last children are on top.
+        fn find(item: &ItemRc, position: LogicalPoint) -> Option<WindowControl> {
+            let mut child = item.last_child();
+            while let Some(c) = child {
+                let origin = c.geometry().origin;
+                if let Some(control) = find(&c, position - origin.to_vector()) {
+                    return Some(control);
+                }
+                child = c.previous_sibling();
+            }
+            let area = item.downcast::<crate::items::WindowControlArea>()?;
+            let area = area.as_pin_ref();
+            (area.enabled()
+                && LogicalRect::from_size(item.geometry().size).contains(position)
+                && item.is_visible())
+            .then(|| area.control())
+        }
+        find(&ItemRc::new(self.try_component()?, 0), position)
+    }
+
     /// Returns the window item that is the first item in the component.
     pub fn window_item(&self) -> Option<VRcMapped<ItemTreeVTable, crate::items::WindowItem>> {
         self.try_component().and_then(|component_rc| {
@@ -1255,10 +1613,48 @@ pub(crate) fn set_window_item_geometry(&self, size: crate::lengths::LogicalSize)
             {
                 window_item.width.set(size.width_length());
                 window_item.height.set(size.height_length());
+                window_item.screen_orientation.set(if size.width > size.height {
+                    crate::items::ScreenOrientation::Landscape
+                } else {
+                    crate::items::ScreenOrientation::Portrait
+                });
             }
         }
     }
 
+    /// Sets the `safe-area-inset-*` properties of the window item, in the order top, bottom, left, right
+    pub(crate) fn set_safe_area_insets(&self, insets: [LogicalLength; 4]) {
+        self.safe_area_insets.set(insets);
+        if let Some(component_rc) = self.try_component() {
+            let component = ItemTreeRc::borrow_pin(&component_rc);
+            let root_item = component.as_ref().get_item_ref(0);
+            if let Some(window_item) = ItemRef::downcast_pin::<crate::items::WindowItem>(root_item)
+            {
+                let [top, bottom, left, right] = insets;
+                window_item.safe_area_inset_top.set(top);
+                window_item.safe_area_inset_bottom.set(bottom);
+                window_item.safe_area_inset_left.set(left);
+                window_item.safe_area_inset_right.set(right);
+            }
+        }
+    }
+
+    /// Sets the `virtual-keyboard-height` property of the window item
+    pub(crate) fn set_virtual_keyboard_height(&self, height: LogicalLength) {
+        self.virtual_keyboard_height.set(height);
+        if let Some(window_item) = self.window_item() {
+            window_item.as_pin_ref().virtual_keyboard_height.set(height);
+        }
+    }
+
+    /// Sets the `device-posture` property of the window item
+    pub(crate) fn set_device_posture(&self, posture: crate::items::DevicePosture) {
+        self.device_posture.set(posture);
+        if let Some(window_item) = self.window_item() {
+            window_item.as_pin_ref().device_posture.set(posture);
+        }
+    }
+
     /// Sets the close_requested callback. The callback will be run when the user tries to close a window.
     pub fn on_close_requested(&self, mut callback: impl FnMut() -> CloseRequestResponse + 'static) {
         self.close_requested.set_handler(move |()| callback());
@@ -1268,12 +1664,55 @@ pub fn on_close_requested(&self, mut callback: impl FnMut() -> CloseRequestRespo
     /// If the callback returns KeepWindowShown, this function returns false. That should prevent the Window from closing.
     /// Otherwise it returns true, which allows the Window to hide.
     pub fn request_close(&self) -> bool {
+        if self.ctx.0.window_manager.request_close(self.window_id())
+            == CloseRequestResponse::KeepWindowShown
+        {
+            return false;
+        }
         match self.close_requested.call(&()) {
             CloseRequestResponse::HideWindow => true,
             CloseRequestResponse::KeepWindowShown => false,
         }
     }
 
+    /// Returns true if the window handles the back button or the back gesture of the platform,
+    /// because a popup can be closed or the `back-requested` callback of the window is set.
+    /// Backends use this to decide whether to intercept the back gesture before it starts.
+    pub fn handles_back_request(&self) -> bool {
+        self.top_popup_closes_automatically()
+            || self.window_item().is_some_and(|w| w.as_pin_ref().back_requested.has_handler())
+    }
+
+    /// Closes the top-most popup, or runs the `back-requested` callback of the window.
+    /// If the callback rejects the request, this function returns false, and the backend should
+    /// perform the default back navigation of the platform, such as closing the application.
+    pub fn request_back(&self) -> bool {
+        self.set_back_progress(0.);
+        if self.top_popup_closes_automatically() {
+            self.close_top_popup();
+            return true;
+        }
+        self.window_item()
+            .is_some_and(|w| w.as_pin_ref().back_requested.call(&()) == EventResult::Accept)
+    }
+
+    /// Sets the `back-progress` property of the window, while the user performs the back gesture.
+    pub fn set_back_progress(&self, progress: f32) {
+        if let Some(w) = self.window_item() {
+            w.as_pin_ref().back_progress.set(progress);
+        }
+    }
+
+    /// Returns the id of the window in the [`WindowManager`](window_manager::WindowManager)
+    pub fn window_id(&self) -> window_manager::WindowId {
+        if let Some(id) = self.window_id.get() {
+            return id;
+        }
+        let id = self.ctx.0.window_manager.allocate_id();
+        self.window_id.set(Some(id));
+        id
+    }
+
     /// Returns if the window is currently maximized
     pub fn is_fullscreen(&self) -> bool {
         if let Some(window_item) = self.window_item() {
@@ -1286,11 +1725,26 @@ pub fn is_fullscreen(&self) -> bool {
     /// Set or unset the window to display fullscreen.
     pub fn set_fullscreen(&self, enabled: bool) {
         if let Some(window_item) = self.window_item() {
+            if !enabled {
+                self.exclusive_fullscreen.replace(None);
+            }
             window_item.as_pin_ref().full_screen.set(enabled);
             self.update_window_properties()
         }
     }
 
+    /// Returns the video mode of the window in exclusive fullscreen
+    pub fn exclusive_fullscreen(&self) -> Option<crate::api::VideoMode> {
+        self.exclusive_fullscreen.borrow().clone()
+    }
+
+    /// Show the window fullscreen with the given video mode, or leave fullscreen if `mode` is `None`.
+    pub fn set_exclusive_fullscreen(&self, mode: Option<crate::api::VideoMode>) {
+        let enabled = mode.is_some();
+        self.exclusive_fullscreen.replace(mode);
+        self.set_fullscreen(enabled);
+    }
+
     /// Returns if the window is currently maximized
     pub fn is_maximized(&self) -> bool {
         self.maximized.get()
@@ -1313,6 +1767,96 @@ pub fn set_minimized(&self, minimized: bool) {
         self.update_window_properties()
     }
 
+    /// Returns if the windowing system composites the window with the content behind it
+    pub fn is_transparent(&self) -> bool {
+        self.transparent.get()
+    }
+
+    /// Set whether the windowing system composites the window with the content behind it
+    pub fn set_transparent(&self, transparent: bool) {
+        self.transparent.set(transparent);
+        self.update_window_properties()
+    }
+
+    /// Returns the effect applied to the content behind the window
+    pub fn backdrop(&self) -> crate::api::WindowBackdrop {
+        self.backdrop.get()
+    }
+
+    /// Set the effect applied to the content behind the window
+    pub fn set_backdrop(&self, backdrop: crate::api::WindowBackdrop) {
+        self.backdrop.set(backdrop);
+        self.update_window_properties()
+    }
+
+    /// Returns the region of the window that receives pointer input
+    pub fn input_region(&self) -> Option<crate::api::WindowRegion> {
+        self.input_region.borrow().clone()
+    }
+
+    /// Set the region of the window that receives pointer input, or `None` for the whole window
+    pub fn set_input_region(&self, region: Option<crate::api::WindowRegion>) {
+        self.input_region.replace(region);
+        self.update_window_properties()
+    }
+
+    /// Returns if the window is shown above the other windows
+    pub fn is_always_on_top(&self) -> bool {
+        self.window_item().is_some_and(|window_item| window_item.as_pin_ref().always_on_top())
+    }
+
+    /// Set whether the window is shown above the other windows
+    pub fn set_always_on_top(&self, always_on_top: bool) {
+        if let Some(window_item) = self.window_item() {
+            window_item.as_pin_ref().always_on_top.set(always_on_top);
+            self.update_window_properties()
+        }
+    }
+
+    /// Returns if the window is hidden from the task bar
+    pub fn skips_taskbar(&self) -> bool {
+        self.skip_taskbar.get()
+    }
+
+    /// Set whether the window is hidden from the task bar
+    pub fn set_skip_taskbar(&self, skip_taskbar: bool) {
+        self.skip_taskbar.set(skip_taskbar);
+        self.update_window_properties()
+    }
+
+    /// Returns if the mouse pointer is confined to the window
+    pub fn is_pointer_confined(&self) -> bool {
+        self.pointer_confined.get()
+    }
+
+    /// Set whether the mouse pointer is confined to the window
+    pub fn set_pointer_confined(&self, confined: bool) {
+        self.pointer_confined.set(confined);
+        self.update_window_properties()
+    }
+
+    /// Returns if the window grabs the keyboard
+    pub fn is_keyboard_grabbed(&self) -> bool {
+        self.keyboard_grabbed.get()
+    }
+
+    /// Set whether the window grabs the keyboard
+    pub fn set_keyboard_grab(&self, grab: bool) {
+        self.keyboard_grabbed.set(grab);
+        self.update_window_properties()
+    }
+
+    /// Returns if the window prevents the screensaver from starting
+    pub fn inhibits_screensaver(&self) -> bool {
+        self.inhibit_screensaver.get()
+    }
+
+    /// Set whether the window prevents the screensaver from starting
+    pub fn set_inhibit_screensaver(&self, inhibit: bool) {
+        self.inhibit_screensaver.set(inhibit);
+        self.update_window_properties()
+    }
+
     /// Returns the (context global) xdg app id for use with wayland and x11.
     pub fn xdg_app_id(&self) -> Option<SharedString> {
         self.ctx.xdg_app_id()
@@ -0,0 +1,183 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Toasts: short notifications shown on top of the content of a window
+//!
+//! The compiler adds the `ToastLayerImpl` component of the style to the windows, which shows
+//! the [`WindowInner::toasts()`] model and calls [`WindowInner::activate_toast()`].
+
+use super::{WindowAdapter, WindowInner};
+use crate::api::Window;
+use crate::items::ToastEntry;
+use crate::model::{Model, ModelRc, VecModel};
+use crate::timers::{Timer, TimerMode};
+use crate::SharedString;
+use alloc::boxed::Box;
+use alloc::rc::{Rc, Weak};
+use alloc::vec::Vec;
+use core::cell::{Cell, RefCell};
+use core::time::Duration;
+
+/// The maximum number of toasts shown at the same time in a window. Showing more closes the oldest.
+const MAX_TOASTS: usize = 3;
+
+/// The options of a [`Toast`]: the message, for how long the toast is shown, and an optional action.
+///
+/// # Example
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use slint::ComponentHandle;
+/// slint::slint! { export component MainWindow inherits Window {} }
+/// let main_window = MainWindow::new().unwrap();
+/// slint::Toast::show(
+///     main_window.window(),
+///     slint::ToastOptions::new("The file was deleted")
+///         .with_duration(std::time::Duration::from_secs(10))
+///         .with_action("Undo", || println!("Restoring the file")),
+/// );
+/// ```
+pub struct ToastOptions {
+    message: SharedString,
+    duration: Duration,
+    action: Option<(SharedString, Box<dyn FnMut()>)>,
+}
+
+impl ToastOptions {
+    /// Creates the options for a toast showing the given message for four seconds, without action.
+    pub fn new(message: impl Into<SharedString>) -> Self {
+        Self { message: message.into(), duration: Duration::from_secs(4), action: None }
+    }
+
+    /// Sets for how long the toast is shown. With a zero duration, the toast stays until it is
+    /// closed with [`Toast::close()`], or until its action is clicked.
+    #[must_use]
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Adds an action to the toast, shown as a button with the given label next to the message.
+    /// Clicking the button invokes the callback and closes the toast.
+    #[must_use]
+    pub fn with_action(
+        mut self,
+        label: impl Into<SharedString>,
+        callback: impl FnMut() + 'static,
+    ) -> Self {
+        self.action = Some((label.into(), Box::new(callback)));
+        self
+    }
+}
+
+/// A toast is a short notification shown on top of the content of a window, for a limited
+/// time. Toasts are centered at the bottom of the window, and stacked with the most recent
+/// one at the bottom. They are drawn with the colors of the `Palette` of the style.
+///
+/// Toasts are only shown in windows of components that inherit `Window`, compiled with the
+/// Rust code generator or the interpreter with toasts enabled: with `#[toasts]` at the start of
+/// the `slint!` macro, `slint_build::CompilerConfiguration::with_toasts()`, or
+/// `slint_interpreter::Compiler::set_toasts()`.
+///
+/// Show a toast with [`Toast::show()`], which returns a handle to close it early.
+#[derive(Clone)]
+pub struct Toast {
+    window_adapter: Weak<dyn WindowAdapter>,
+    id: i32,
+}
+
+impl Toast {
+    /// Shows a toast in the given window, with the given options.
+    ///
+    /// At most three toasts are shown at the same time: showing another one closes the oldest.
+    pub fn show(window: &Window, options: ToastOptions) -> Self {
+        Self {
+            window_adapter: window.0.window_adapter_weak.clone(),
+            id: window.0.toasts.show(options, window.0.window_adapter_weak.clone()),
+        }
+    }
+
+    /// Closes the toast. Does nothing if the toast was already closed.
+    pub fn close(&self) {
+        if let Some(window_adapter) = self.window_adapter.upgrade() {
+            WindowInner::from_pub(window_adapter.window()).toasts.close(self.id);
+        }
+    }
+
+    /// Returns true if the toast is still shown.
+    pub fn is_visible(&self) -> bool {
+        self.window_adapter.upgrade().is_some_and(|window_adapter| {
+            WindowInner::from_pub(window_adapter.window()).toasts.is_visible(self.id)
+        })
+    }
+}
+
+/// A toast shown in a window
+struct ActiveToast {
+    id: i32,
+    action: Option<Box<dyn FnMut()>>,
+    /// Closes the toast when its duration elapsed
+    _timer: Timer,
+}
+
+/// The toasts shown in a window, the oldest first
+#[derive(Default)]
+pub(super) struct WindowToasts {
+    /// The model shown by the toast layer, in the same order as `active`
+    entries: Rc<VecModel<ToastEntry>>,
+    active: RefCell<Vec<ActiveToast>>,
+    last_id: Cell<i32>,
+}
+
+impl WindowToasts {
+    pub fn entries(&self) -> ModelRc<ToastEntry> {
+        ModelRc::from(self.entries.clone())
+    }
+
+    fn show(&self, options: ToastOptions, window_adapter: Weak<dyn WindowAdapter>) -> i32 {
+        let id = self.last_id.get() + 1;
+        self.last_id.set(id);
+
+        let timer = Timer::default();
+        if !options.duration.is_zero() {
+            timer.start(TimerMode::SingleShot, options.duration, move || {
+                if let Some(window_adapter) = window_adapter.upgrade() {
+                    WindowInner::from_pub(window_adapter.window()).toasts.close(id);
+                }
+            });
+        }
+
+        let (label, action) = match options.action {
+            Some((label, action)) => (label, Some(action)),
+            None => (SharedString::default(), None),
+        };
+        self.active.borrow_mut().push(ActiveToast { id, action, _timer: timer });
+        self.entries.push(ToastEntry { id, message: options.message, action: label });
+
+        while self.entries.row_count() > MAX_TOASTS {
+            let oldest = self.active.borrow()[0].id;
+            self.close(oldest);
+        }
+        id
+    }
+
+    fn take(&self, id: i32) -> Option<ActiveToast> {
+        let index = self.active.borrow().iter().position(|toast| toast.id == id)?;
+        let toast = self.active.borrow_mut().remove(index);
+        self.entries.remove(index);
+        Some(toast)
+    }
+
+    fn close(&self, id: i32) {
+        drop(self.take(id));
+    }
+
+    pub fn activate(&self, id: i32) {
+        if let Some(mut action) = self.take(id).and_then(|toast| toast.action) {
+            action();
+        }
+    }
+
+    fn is_visible(&self, id: i32) -> bool {
+        self.active.borrow().iter().any(|toast| toast.id == id)
+    }
+}
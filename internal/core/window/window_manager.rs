@@ -0,0 +1,211 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! The window manager keeps track of the top-level windows of the application
+
+use super::{WindowAdapter, WindowInner};
+use crate::api::{CloseRequestResponse, ComponentHandle, PlatformError, Window};
+use crate::callbacks::CallbackSlot;
+use crate::item_tree::ItemTreeRc;
+use alloc::boxed::Box;
+use alloc::rc::Weak;
+use alloc::vec::Vec;
+use core::cell::{Cell, RefCell};
+use core::num::NonZeroU32;
+
+/// Identifies a window of the application, for use with the [`WindowManager`].
+///
+/// A window keeps the same id when it is hidden and shown again.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WindowId(NonZeroU32);
+
+/// The window manager keeps track of the windows that are shown on the screen: the windows of
+/// the components shown with [`ComponentHandle::show()`], as well as the `Window`s declared inside
+/// another component in a `.slint` file and opened with their `show()` function.
+///
+/// Use it to enumerate the open windows, to open and close them, to know which one has the
+/// keyboard focus, or to handle the requests to close any of them in one place.
+///
+/// # Example
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use slint::{ComponentHandle, WindowManager};
+/// slint::slint! {
+///     export component MainWindow inherits Window {
+///         inspector := Window { title: "Inspector"; }
+///         public function open-inspector() { inspector.show(); }
+///     }
+/// }
+/// let main_window = MainWindow::new().unwrap();
+/// let main_id = WindowManager::open(&main_window).unwrap();
+/// main_window.invoke_open_inspector();
+/// assert_eq!(WindowManager::windows().len(), 2);
+/// WindowManager::on_close_requested(move |id| {
+///     if id == main_id {
+///         // Closing the main window closes all the other windows
+///         for id in WindowManager::windows() {
+///             WindowManager::close(id).unwrap();
+///         }
+///     }
+///     slint::CloseRequestResponse::HideWindow
+/// });
+/// ```
+pub struct WindowManager {
+    _private: (),
+}
+
+impl WindowManager {
+    /// Shows the window of the component, and returns its id.
+    ///
+    /// This is the same as calling [`ComponentHandle::show()`] followed by [`Self::window_id()`].
+    pub fn open(component: &impl ComponentHandle) -> Result<WindowId, PlatformError> {
+        component.show()?;
+        Ok(Self::window_id(component.window()))
+    }
+
+    /// Hides the window with the given id. Does nothing if the window is not shown.
+    pub fn close(id: WindowId) -> Result<(), PlatformError> {
+        match find_window(id) {
+            Some(window_adapter) => window_adapter.window().hide(),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the ids of the windows currently shown, in the order in which they were shown.
+    pub fn windows() -> Vec<WindowId> {
+        with_state(|state| {
+            state
+                .windows
+                .borrow()
+                .iter()
+                .filter(|(_, w)| w.strong_count() > 0)
+                .map(|(id, _)| *id)
+                .collect()
+        })
+        .unwrap_or_default()
+    }
+
+    /// Returns the id of the given window.
+    pub fn window_id(window: &Window) -> WindowId {
+        window.0.window_id()
+    }
+
+    /// Calls the function with the window that has the given id, and returns its result.
+    /// Returns `None` if that window is not shown.
+    pub fn with_window<R>(id: WindowId, f: impl FnOnce(&Window) -> R) -> Option<R> {
+        find_window(id).map(|window_adapter| f(window_adapter.window()))
+    }
+
+    /// Returns the id of the window that has the keyboard focus, if it is one of the windows of the application.
+    pub fn focused_window() -> Option<WindowId> {
+        with_state(|state| state.focused.get()).flatten()
+    }
+
+    /// Sets a callback that is invoked when the user tries to close any of the windows of the
+    /// application, with the id of that window.
+    ///
+    /// The callback is invoked before the callback set with [`Window::on_close_requested()`], which is
+    /// only invoked when this one returns [`CloseRequestResponse::HideWindow`].
+    pub fn on_close_requested(callback: impl FnMut(WindowId) -> CloseRequestResponse + 'static) {
+        with_state(|state| state.close_requested.set(Box::new(callback)));
+    }
+
+    /// Sets a callback that is invoked when a window of the application receives or loses the
+    /// keyboard focus, with the id of the window that now has the focus.
+    pub fn on_focused_window_changed(callback: impl FnMut(Option<WindowId>) + 'static) {
+        with_state(|state| state.focused_window_changed.set(Box::new(callback)));
+    }
+}
+
+/// The state of the window manager, stored in the [`SlintContext`](crate::SlintContext)
+#[derive(Default)]
+pub(crate) struct WindowManagerState {
+    /// The windows currently shown, in the order in which they were shown
+    windows: RefCell<Vec<(WindowId, Weak<dyn WindowAdapter>)>>,
+    last_id: Cell<u32>,
+    focused: Cell<Option<WindowId>>,
+    close_requested: CallbackSlot<dyn FnMut(WindowId) -> CloseRequestResponse>,
+    focused_window_changed: CallbackSlot<dyn FnMut(Option<WindowId>)>,
+}
+
+impl WindowManagerState {
+    pub(crate) fn allocate_id(&self) -> WindowId {
+        self.last_id.set(self.last_id.get() + 1);
+        WindowId(NonZeroU32::new(self.last_id.get()).unwrap())
+    }
+
+    pub(crate) fn window_shown(&self, id: WindowId, window_adapter: Weak<dyn WindowAdapter>) {
+        let mut windows = self.windows.borrow_mut();
+        windows.retain(|(existing, w)| *existing != id && w.strong_count() > 0);
+        windows.push((id, window_adapter));
+    }
+
+    pub(crate) fn window_hidden(&self, id: WindowId) {
+        self.windows.borrow_mut().retain(|(existing, _)| *existing != id);
+        if self.focused.get() == Some(id) {
+            self.set_focused(None);
+        }
+    }
+
+    pub(crate) fn window_activated(&self, id: WindowId, active: bool) {
+        if active {
+            self.set_focused(Some(id));
+        } else if self.focused.get() == Some(id) {
+            self.set_focused(None);
+        }
+    }
+
+    fn set_focused(&self, focused: Option<WindowId>) {
+        if self.focused.replace(focused) == focused {
+            return;
+        }
+        self.focused_window_changed.call(|callback| callback(focused));
+    }
+
+    /// Runs the close requested callback, if any.
+    pub(crate) fn request_close(&self, id: WindowId) -> CloseRequestResponse {
+        self.close_requested
+            .call(|callback| callback(id))
+            .unwrap_or(CloseRequestResponse::HideWindow)
+    }
+}
+
+fn with_state<R>(f: impl FnOnce(&WindowManagerState) -> R) -> Option<R> {
+    crate::context::GLOBAL_CONTEXT.with(|ctx| ctx.get().map(|ctx| f(&ctx.0.window_manager)))
+}
+
+fn find_window(id: WindowId) -> Option<crate::window::WindowAdapterRc> {
+    with_state(|state| {
+        state
+            .windows
+            .borrow()
+            .iter()
+            .find(|(existing, _)| *existing == id)
+            .and_then(|(_, w)| w.upgrade())
+    })
+    .flatten()
+}
+
+/// Shows a `Window` declared inside another component. `id` holds the id of the window opened by a previous call:
+/// if that window is still shown, it is shown again instead of creating a new instance with `create`.
+pub fn show_secondary_window(
+    id: &Cell<Option<NonZeroU32>>,
+    create: impl FnOnce() -> Result<ItemTreeRc, PlatformError>,
+) -> Result<(), PlatformError> {
+    if let Some(window_adapter) = id.get().and_then(|id| find_window(WindowId(id))) {
+        return window_adapter.window().show();
+    }
+    let item_tree = create()?;
+    let mut window_adapter = None;
+    vtable::VRc::borrow_pin(&item_tree).as_ref().window_adapter(true, &mut window_adapter);
+    let window_adapter = window_adapter.ok_or(PlatformError::NoPlatform)?;
+    id.set(Some(WindowInner::from_pub(window_adapter.window()).window_id().0));
+    window_adapter.window().show()
+}
+
+/// Closes a `Window` declared inside another component, that was opened with [`show_secondary_window()`]
+pub fn close_secondary_window(id: &Cell<Option<NonZeroU32>>) {
+    if let Some(id) = id.take() {
+        let _ = WindowManager::close(WindowId(id));
+    }
+}
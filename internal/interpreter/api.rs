@@ -25,6 +25,7 @@
 pub use i_slint_backend_selector::api::*;
 pub use i_slint_core::graphics::{
     Brush, Color, Image, LoadImageError, Rgb8Pixel, Rgba8Pixel, RgbaColor, SharedPixelBuffer,
+    VideoFrame, VideoFrameProvider, VideoFrameSender, VideoSource,
 };
 use i_slint_core::items::*;
 
@@ -131,6 +132,10 @@ pub enum Value {
     #[doc(hidden)]
     /// Correspond to the `component-factory` type in .slint
     ComponentFactory(ComponentFactory) = 12,
+    #[doc(hidden)]
+    /// A value that is only used from Rust, like the state of the elements registered with
+    /// [`Compiler::register_element`]. Two opaque values are equal if they point to the same object.
+    Opaque(Rc<dyn core::any::Any>) = 13,
 }
 
 impl Value {
@@ -176,6 +181,7 @@ fn eq(&self, other: &Self) -> bool {
             Value::ComponentFactory(lhs) => {
                 matches!(other, Value::ComponentFactory(rhs) if lhs == rhs)
             }
+            Value::Opaque(lhs) => matches!(other, Value::Opaque(rhs) if Rc::ptr_eq(lhs, rhs)),
         }
     }
 }
@@ -200,6 +206,7 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             Value::EnumerationValue(n, v) => write!(f, "Value::EnumerationValue({:?}, {:?})", n, v),
             Value::LayoutCache(v) => write!(f, "Value::LayoutCache({:?})", v),
             Value::ComponentFactory(factory) => write!(f, "Value::ComponentFactory({:?})", factory),
+            Value::Opaque(_) => write!(f, "Value::Opaque"),
         }
     }
 }
@@ -504,6 +511,113 @@ pub(crate) fn normalize_identifier_smolstr(ident: &str) -> SmolStr {
     }
 }
 
+/// One step of a path after the global and property name (see [`parse_property_path`])
+#[derive(Debug, Clone, PartialEq)]
+enum PropertyPathSegment {
+    /// `.field`
+    Field(String),
+    /// `[index]`
+    Index(usize),
+}
+
+impl PropertyPathSegment {
+    fn get(&self, value: Value) -> Result<Value, PropertyPathError> {
+        match (self, value) {
+            (Self::Field(f), Value::Struct(s)) => {
+                s.get_field(f).cloned().ok_or_else(|| PropertyPathError::NoSuchField(f.clone()))
+            }
+            (Self::Index(index), Value::Model(m)) => m.row_data(*index).ok_or_else(|| {
+                PropertyPathError::IndexOutOfBounds { index: *index, row_count: m.row_count() }
+            }),
+            (s, _) => Err(PropertyPathError::WrongType(s.to_string())),
+        }
+    }
+
+    /// Replace the value at `segments` within `container` by `value`.
+    ///
+    /// Returns the updated container if it needs to be stored back by the caller, or None if
+    /// the value was written in place in a model.
+    fn set_in(
+        container: Value,
+        segments: &[Self],
+        value: Value,
+    ) -> Result<Option<Value>, PropertyPathError> {
+        let Some((segment, rest)) = segments.split_first() else { return Ok(Some(value)) };
+        let old = segment.get(container.clone())?;
+        let new = if rest.is_empty() {
+            if old.value_type() != value.value_type() {
+                return Err(PropertyPathError::WrongType(segment.to_string()));
+            }
+            value
+        } else {
+            match Self::set_in(old, rest, value)? {
+                Some(new) => new,
+                None => return Ok(None),
+            }
+        };
+        match (segment, container) {
+            (Self::Field(f), Value::Struct(mut s)) => {
+                s.set_field(f.clone(), new);
+                Ok(Some(Value::Struct(s)))
+            }
+            (Self::Index(index), Value::Model(m)) => {
+                m.set_row_data(*index, new);
+                Ok(None)
+            }
+            _ => unreachable!("checked by get()"),
+        }
+    }
+}
+
+impl std::fmt::Display for PropertyPathSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Field(field) => write!(f, ".{field}"),
+            Self::Index(index) => write!(f, "[{index}]"),
+        }
+    }
+}
+
+/// Parse a path such as `Global.property.field[2].other` into the normalized global name,
+/// property name, and the remaining segments.
+fn parse_property_path(
+    path: &str,
+) -> Result<(SmolStr, SmolStr, Vec<PropertyPathSegment>), PropertyPathError> {
+    let invalid = || PropertyPathError::InvalidPath(path.into());
+    let is_ident_char = |c: char| c.is_alphanumeric() || c == '_' || c == '-';
+    let ident = |s: &str| -> Result<usize, PropertyPathError> {
+        let len = s.find(|c| !is_ident_char(c)).unwrap_or(s.len());
+        if len == 0 || s.starts_with(|c: char| c.is_ascii_digit()) {
+            Err(invalid())
+        } else {
+            Ok(len)
+        }
+    };
+
+    let len = ident(path)?;
+    let global = normalize_identifier_smolstr(&path[..len]);
+    let rest = path[len..].strip_prefix('.').ok_or_else(invalid)?;
+    let len = ident(rest)?;
+    let property = normalize_identifier_smolstr(&rest[..len]);
+
+    let mut segments = Vec::new();
+    let mut rest = &rest[len..];
+    while !rest.is_empty() {
+        if let Some(r) = rest.strip_prefix('.') {
+            let len = ident(r)?;
+            segments.push(PropertyPathSegment::Field(normalize_identifier(&r[..len]).into_owned()));
+            rest = &r[len..];
+        } else if let Some(r) = rest.strip_prefix('[') {
+            let (index, r) = r.split_once(']').ok_or_else(invalid)?;
+            segments.push(PropertyPathSegment::Index(index.trim().parse().map_err(|_| invalid())?));
+            rest = r;
+        } else {
+            return Err(invalid());
+        }
+    }
+    Ok((global, property, segments))
+}
+
 /// This type represents a runtime instance of structure in `.slint`.
 ///
 /// This can either be an instance of a name structure introduced
@@ -679,7 +793,15 @@ pub async fn build_from_path<P: AsRef<Path>>(
             }
         };
 
-        let r = crate::dynamic_item_tree::load(source, path.into(), self.config.clone()).await;
+        let r = crate::dynamic_item_tree::load(
+            source,
+            path.into(),
+            self.config.clone(),
+            &Default::default(),
+            None,
+            None,
+        )
+        .await;
         self.diagnostics = r.diagnostics.into_iter().collect();
         r.components.into_values().next()
     }
@@ -705,7 +827,15 @@ pub async fn build_from_source(
         source_code: String,
         path: PathBuf,
     ) -> Option<ComponentDefinition> {
-        let r = crate::dynamic_item_tree::load(source_code, path, self.config.clone()).await;
+        let r = crate::dynamic_item_tree::load(
+            source_code,
+            path,
+            self.config.clone(),
+            &Default::default(),
+            None,
+            None,
+        )
+        .await;
         self.diagnostics = r.diagnostics.into_iter().collect();
         r.components.into_values().next()
     }
@@ -715,6 +845,12 @@ pub async fn build_from_source(
 /// compile it into a [`CompilationResult`].
 pub struct Compiler {
     config: i_slint_compiler::CompilerConfiguration,
+    native_globals: std::collections::BTreeMap<SmolStr, NativeGlobal>,
+    /// The properties of the elements registered with `register_element`
+    native_elements: std::collections::BTreeMap<SmolStr, Vec<(SmolStr, String)>>,
+    resource_loader: Option<Rc<dyn ResourceLoader>>,
+    /// The documents kept between builds when incremental compilation is enabled
+    incremental_cache: Option<std::cell::RefCell<i_slint_compiler::typeloader::IncrementalCache>>,
 }
 
 impl Default for Compiler {
@@ -722,7 +858,13 @@ fn default() -> Self {
         let config = i_slint_compiler::CompilerConfiguration::new(
             i_slint_compiler::generator::OutputFormat::Interpreter,
         );
-        Self { config }
+        Self {
+            config,
+            native_globals: Default::default(),
+            native_elements: Default::default(),
+            resource_loader: None,
+            incremental_cache: None,
+        }
     }
 }
 
@@ -732,6 +874,15 @@ pub fn new() -> Self {
         Self::default()
     }
 
+    /// Access the configuration to change it, which invalidates the documents kept for
+    /// incremental compilation.
+    fn config_mut(&mut self) -> &mut i_slint_compiler::CompilerConfiguration {
+        if let Some(cache) = &self.incremental_cache {
+            cache.take();
+        }
+        &mut self.config
+    }
+
     /// Allow access to the underlying `CompilerConfiguration`
     ///
     /// This is an internal function without and ABI or API stability guarantees.
@@ -741,12 +892,12 @@ pub fn compiler_configuration(
         &mut self,
         _: i_slint_core::InternalToken,
     ) -> &mut i_slint_compiler::CompilerConfiguration {
-        &mut self.config
+        self.config_mut()
     }
 
     /// Sets the include paths used for looking up `.slint` imports to the specified vector of paths.
     pub fn set_include_paths(&mut self, include_paths: Vec<std::path::PathBuf>) {
-        self.config.include_paths = include_paths;
+        self.config_mut().include_paths = include_paths;
     }
 
     /// Returns the include paths the component compiler is currently configured with.
@@ -756,7 +907,7 @@ pub fn include_paths(&self) -> &Vec<std::path::PathBuf> {
 
     /// Sets the library paths used for looking up `@library` imports to the specified map of library names to paths.
     pub fn set_library_paths(&mut self, library_paths: HashMap<String, PathBuf>) {
-        self.config.library_paths = library_paths;
+        self.config_mut().library_paths = library_paths;
     }
 
     /// Returns the library paths the component compiler is currently configured with.
@@ -775,7 +926,7 @@ pub fn library_paths(&self) -> &HashMap<String, PathBuf> {
     /// let result = spin_on::spin_on(compiler.build_from_path("hello.slint"));
     /// ```
     pub fn set_style(&mut self, style: String) {
-        self.config.style = Some(style);
+        self.config_mut().style = Some(style);
     }
 
     /// Returns the widget style the compiler is currently using when compiling .slint files.
@@ -785,7 +936,15 @@ pub fn style(&self) -> Option<&String> {
 
     /// The domain used for translations
     pub fn set_translation_domain(&mut self, domain: String) {
-        self.config.translation_domain = Some(domain);
+        self.config_mut().translation_domain = Some(domain);
+    }
+
+    /// Enables the toasts of [`slint::Toast`](https://docs.rs/slint/latest/slint/struct.Toast.html)
+    /// in the windows of the components compiled by this compiler.
+    ///
+    /// This is disabled by default, as it adds a layer on top of the content of every window.
+    pub fn set_toasts(&mut self, enabled: bool) {
+        self.config_mut().toasts = enabled;
     }
 
     /// Sets the callback that will be invoked when loading imported .slint files. The specified
@@ -800,10 +959,263 @@ pub fn set_file_loader(
         file_loader_fallback: impl Fn(&Path) -> core::pin::Pin<Box<dyn Future<Output = Option<std::io::Result<String>>>>>
             + 'static,
     ) {
-        self.config.open_import_fallback =
+        self.config_mut().open_import_fallback =
             Some(Rc::new(move |path| file_loader_fallback(Path::new(path.as_str()))));
     }
 
+    /// Sets the loader used to read the files referenced by the .slint code: imported .slint
+    /// files, images, and fonts.
+    ///
+    /// The loader is asked first for every file. If it returns None, the file is loaded
+    /// through the callback set with [`Self::set_file_loader`] for .slint files, or from
+    /// the file system. This allows loading .slint code and its resources from memory,
+    /// an archive, or the network on platforms that don't have a file system.
+    ///
+    /// ```
+    /// # i_slint_backend_testing::init_no_event_loop();
+    /// use slint_interpreter::{Compiler, ResourceLoader, Value};
+    /// use std::path::Path;
+    ///
+    /// struct InMemory;
+    /// impl ResourceLoader for InMemory {
+    ///     fn load(&self, path: &Path) -> Option<std::io::Result<Vec<u8>>> {
+    ///         (path == Path::new("/app/lib.slint"))
+    ///             .then(|| Ok(b"export global Lib { out property <int> answer: 42; }".to_vec()))
+    ///     }
+    /// }
+    ///
+    /// let mut compiler = Compiler::default();
+    /// compiler.set_resource_loader(InMemory);
+    /// let code = r#"
+    ///     import { Lib } from "lib.slint";
+    ///     export component MyWin inherits Window {
+    ///         out property <int> answer: Lib.answer;
+    ///     }
+    /// "#;
+    /// let result = spin_on::spin_on(compiler.build_from_source(code.into(), "/app/main.slint".into()));
+    /// assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+    /// let instance = result.component("MyWin").unwrap().create().unwrap();
+    /// assert_eq!(instance.get_property("answer").unwrap(), Value::Number(42.));
+    /// ```
+    pub fn set_resource_loader(&mut self, loader: impl ResourceLoader + 'static) {
+        self.config_mut();
+        self.resource_loader = Some(Rc::new(loader));
+    }
+
+    /// Enables or disables incremental compilation.
+    ///
+    /// When enabled, the compiler keeps the resolved and type checked .slint files between calls to
+    /// [`Self::build_from_path`] or [`Self::build_from_source`]. Only the files whose content changed
+    /// since the previous build, and the files that import them, are loaded again, which speeds up
+    /// the compilation of large projects when only a few files change. The files are still read to
+    /// detect changes, and the passes that generate the components still run on the whole project.
+    /// Warnings in files that didn't change are only reported by the build that loaded them.
+    ///
+    /// Changing the configuration of the compiler, for example the style or the include paths,
+    /// and builds with errors discard the kept files.
+    pub fn set_incremental(&mut self, enabled: bool) {
+        self.incremental_cache = enabled.then(Default::default);
+    }
+
+    /// Register a set of native callbacks as a global singleton with the given name.
+    ///
+    /// The global can then be used from any .slint file compiled by this compiler without
+    /// declaring or importing it. The handlers are installed on every component instance.
+    ///
+    /// ```
+    /// # i_slint_backend_testing::init_no_event_loop();
+    /// use slint_interpreter::{Compiler, NativeGlobal, SharedString, Value};
+    /// let mut text = NativeGlobal::default();
+    /// text.add_pure_callback("to-upper", "(string) -> string", |args| {
+    ///     let arg: SharedString = args[0].clone().try_into().unwrap();
+    ///     Value::from(SharedString::from(arg.to_uppercase()))
+    /// });
+    /// let mut compiler = Compiler::default();
+    /// compiler.register_global("Strings", text);
+    /// let code = r#"
+    ///     export component MyWin inherits Window {
+    ///         out property <string> hello: Strings.to-upper("world");
+    ///     }
+    /// "#;
+    /// let result = spin_on::spin_on(compiler.build_from_source(code.into(), Default::default()));
+    /// assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+    /// let instance = result.component("MyWin").unwrap().create().unwrap();
+    /// assert_eq!(instance.get_property("hello").unwrap(), Value::from(SharedString::from("WORLD")));
+    /// ```
+    pub fn register_global(&mut self, name: &str, global: NativeGlobal) {
+        self.native_globals.insert(normalize_identifier_smolstr(name), global);
+        self.update_native_document();
+    }
+
+    /// Register an element implemented in Rust under the given name.
+    ///
+    /// The element can then be used from any .slint file compiled by this compiler like a
+    /// builtin element, without import. Each instance of the element gets its own state,
+    /// created by the factory of the [`NativeElementType`]. The state is dropped with the
+    /// instance of the element, for example when the condition of an `if` becomes false.
+    ///
+    /// ```
+    /// # i_slint_backend_testing::init_no_event_loop();
+    /// use slint_interpreter::{Compiler, Image, LogicalSize, NativeElement, NativeElementType, Struct};
+    /// struct Checkerboard;
+    /// impl NativeElement for Checkerboard {
+    ///     fn render(&self, size: LogicalSize, properties: &Struct) -> Image {
+    ///         // draw the element into a buffer of the given size
+    ///         Image::default()
+    ///     }
+    /// }
+    /// let mut checkerboard = NativeElementType::new(|| Checkerboard);
+    /// checkerboard.add_property("cell-size", "length");
+    /// let mut compiler = Compiler::default();
+    /// compiler.register_element("Checkerboard", checkerboard);
+    /// let code = r#"
+    ///     export component MyWin inherits Window {
+    ///         Checkerboard { cell-size: 10px; }
+    ///     }
+    /// "#;
+    /// let result = spin_on::spin_on(compiler.build_from_source(code.into(), Default::default()));
+    /// assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+    /// ```
+    pub fn register_element(&mut self, name: &str, element: NativeElementType) {
+        let name = normalize_identifier_smolstr(name);
+        let callback_names = ["create", "render", "pointer-event", "scroll-event"]
+            .map(|suffix| format!("{name}-{suffix}"));
+        let helper = self.native_globals.entry(NATIVE_ELEMENTS_GLOBAL.into()).or_default();
+        helper.callbacks.retain(|c| !callback_names.iter().any(|n| c.name == n.as_str()));
+
+        let factory = element.factory;
+        helper.add_callback(
+            &callback_names[0],
+            &format!("() -> {NATIVE_ELEMENT_STATE}"),
+            move |_| Value::Opaque(Rc::new(NativeElementState(std::cell::RefCell::new(factory())))),
+        );
+
+        let property_names = element.properties.iter().map(|(n, _)| n.clone()).collect::<Vec<_>>();
+        let property_types =
+            element.properties.iter().map(|(_, ty)| format!(", {ty}")).collect::<String>();
+        helper.add_pure_callback(
+            &callback_names[1],
+            &format!("({NATIVE_ELEMENT_STATE}, length, length, int{property_types}) -> image"),
+            move |args| {
+                let Some(state) = NativeElementState::from_value(&args[0]) else {
+                    return Value::Image(Default::default());
+                };
+                let size = LogicalSize::new(
+                    f64::try_from(args[1].clone()).unwrap_or_default() as f32,
+                    f64::try_from(args[2].clone()).unwrap_or_default() as f32,
+                );
+                let properties = property_names
+                    .iter()
+                    .map(|n| n.to_string())
+                    .zip(args[4..].iter().cloned())
+                    .collect::<Struct>();
+                Value::Image(state.0.borrow().render(size, &properties))
+            },
+        );
+
+        helper.add_callback(
+            &callback_names[2],
+            &format!("({NATIVE_ELEMENT_STATE}, PointerEvent, length, length) -> bool"),
+            |args| {
+                let Ok(event) = PointerEvent::try_from(args[1].clone()) else {
+                    return Value::Bool(false);
+                };
+                let position = native_element_position(&args[2], &args[3]);
+                let button = event.button;
+                let event = match event.kind {
+                    PointerEventKind::Down => {
+                        NativeElementEvent::PointerPressed { position, button }
+                    }
+                    PointerEventKind::Up => {
+                        NativeElementEvent::PointerReleased { position, button }
+                    }
+                    PointerEventKind::Move => NativeElementEvent::PointerMoved { position },
+                    PointerEventKind::Cancel => NativeElementEvent::PointerExited,
+                };
+                Value::Bool(NativeElementState::dispatch_event(&args[0], &event))
+            },
+        );
+
+        helper.add_callback(
+            &callback_names[3],
+            &format!("({NATIVE_ELEMENT_STATE}, PointerScrollEvent, length, length) -> bool"),
+            |args| {
+                let Ok(event) = PointerScrollEvent::try_from(args[1].clone()) else {
+                    return Value::Bool(false);
+                };
+                let event = NativeElementEvent::PointerScrolled {
+                    position: native_element_position(&args[2], &args[3]),
+                    delta_x: event.delta_x,
+                    delta_y: event.delta_y,
+                };
+                Value::Bool(NativeElementState::dispatch_event(&args[0], &event))
+            },
+        );
+
+        self.native_elements.insert(name, element.properties);
+        self.update_native_document();
+    }
+
+    /// Generate the document that declares the native globals and elements
+    fn update_native_document(&mut self) {
+        let mut source = String::new();
+        if !self.native_elements.is_empty() {
+            source += &format!("struct {NATIVE_ELEMENT_STATE} {{}}\n");
+        }
+        for (name, global) in &self.native_globals {
+            let export = if name == NATIVE_ELEMENTS_GLOBAL { "" } else { "export " };
+            source += &format!("{export}global {name} {{\n");
+            for callback in &global.callbacks {
+                source += &format!(
+                    "    {}callback {}{};\n",
+                    if callback.pure { "pure " } else { "" },
+                    callback.name,
+                    callback.signature
+                );
+            }
+            source += "}\n";
+        }
+        let helper = NATIVE_ELEMENTS_GLOBAL;
+        for (name, properties) in &self.native_elements {
+            source += &format!("export component {name} {{\n");
+            for (property, ty) in properties {
+                source += &format!("    in property <{ty}> {property};\n");
+            }
+            let args = properties.iter().map(|(p, _)| format!(", root.{p}")).collect::<String>();
+            source += &format!(
+                "    // Holds a `Value::Opaque` with the NativeElementState
+    property <{NATIVE_ELEMENT_STATE}> native-state;
+    property <int> native-generation;
+    init => {{ native-state = {helper}.{name}-create(); }}
+    // The position is set so that the size of the image doesn't affect the layout
+    Image {{
+        x: 0;
+        y: 0;
+        width: 100%;
+        height: 100%;
+        source: {helper}.{name}-render(native-state, root.width, root.height, native-generation{args});
+    }}
+    TouchArea {{
+        pointer-event(event) => {{
+            if ({helper}.{name}-pointer-event(native-state, event, self.mouse-x, self.mouse-y)) {{
+                native-generation += 1;
+            }}
+        }}
+        scroll-event(event) => {{
+            if ({helper}.{name}-scroll-event(native-state, event, self.mouse-x, self.mouse-y)) {{
+                native-generation += 1;
+                return accept;
+            }}
+            reject
+        }}
+    }}
+}}
+"
+            );
+        }
+        self.config_mut().implicit_globals = Some((NATIVE_GLOBALS_PATH.into(), source));
+    }
+
     /// Compile a .slint file
     ///
     /// Returns a structure that holds the diagnostics and the compiled components.
@@ -824,23 +1236,52 @@ pub fn set_file_loader(
     /// provided by the `spin_on` crate
     pub async fn build_from_path<P: AsRef<Path>>(&self, path: P) -> CompilationResult {
         let path = path.as_ref();
-        let source = match i_slint_compiler::diagnostics::load_from_path(path) {
-            Ok(s) => s,
-            Err(d) => {
-                let mut diagnostics = i_slint_compiler::diagnostics::BuildDiagnostics::default();
-                diagnostics.push_compiler_error(d);
-                return CompilationResult {
-                    components: HashMap::new(),
-                    diagnostics: diagnostics.into_iter().collect(),
-                    #[cfg(feature = "internal")]
-                    structs_and_enums: Vec::new(),
-                    #[cfg(feature = "internal")]
-                    named_exports: Vec::new(),
-                };
+        let mut diagnostics = i_slint_compiler::diagnostics::BuildDiagnostics::default();
+        let loaded = self.resource_loader.as_ref().and_then(|loader| load_source(&**loader, path));
+        let source = match loaded {
+            Some(Ok(s)) => Some(s),
+            Some(Err(err)) => {
+                diagnostics.push_error_with_span(
+                    format!("Could not load {}: {}", path.display(), err),
+                    i_slint_compiler::diagnostics::SourceLocation {
+                        source_file: Some(
+                            i_slint_compiler::diagnostics::SourceFileInner::from_path_only(
+                                path.to_owned(),
+                            ),
+                        ),
+                        span: Default::default(),
+                    },
+                );
+                None
             }
+            None => match i_slint_compiler::diagnostics::load_from_path(path) {
+                Ok(s) => Some(s),
+                Err(d) => {
+                    diagnostics.push_compiler_error(d);
+                    None
+                }
+            },
+        };
+        let Some(source) = source else {
+            return CompilationResult {
+                components: HashMap::new(),
+                diagnostics: diagnostics.into_iter().collect(),
+                #[cfg(feature = "internal")]
+                structs_and_enums: Vec::new(),
+                #[cfg(feature = "internal")]
+                named_exports: Vec::new(),
+            };
         };
 
-        crate::dynamic_item_tree::load(source, path.into(), self.config.clone()).await
+        crate::dynamic_item_tree::load(
+            source,
+            path.into(),
+            self.config.clone(),
+            &self.native_globals,
+            self.resource_loader.clone(),
+            self.incremental_cache.as_ref(),
+        )
+        .await
     }
 
     /// Compile some .slint code
@@ -856,7 +1297,207 @@ pub async fn build_from_path<P: AsRef<Path>>(&self, path: P) -> CompilationResul
     /// If that is not used, then it is fine to use a very simple executor, such as the one
     /// provided by the `spin_on` crate
     pub async fn build_from_source(&self, source_code: String, path: PathBuf) -> CompilationResult {
-        crate::dynamic_item_tree::load(source_code, path, self.config.clone()).await
+        crate::dynamic_item_tree::load(
+            source_code,
+            path,
+            self.config.clone(),
+            &self.native_globals,
+            self.resource_loader.clone(),
+            self.incremental_cache.as_ref(),
+        )
+        .await
+    }
+}
+
+/// Provides the content of the files referenced by .slint code, such as imported .slint files,
+/// images, and fonts.
+///
+/// Implement this trait to load these files from somewhere else than the file system, and set
+/// it with [`Compiler::set_resource_loader`]. The loader is also used at run-time by the
+/// components created from the [`CompilationResult`] to load images and fonts.
+pub trait ResourceLoader {
+    /// Returns the content of the file at the given path, or None if this loader doesn't
+    /// provide that file.
+    ///
+    /// The path is the one computed by the compiler: relative paths in the .slint code are
+    /// resolved relative to the directory of the .slint file that references them.
+    fn load(&self, path: &Path) -> Option<std::io::Result<Vec<u8>>>;
+}
+
+/// Load a .slint file through the resource loader
+pub(crate) fn load_source(
+    loader: &dyn ResourceLoader,
+    path: &Path,
+) -> Option<std::io::Result<String>> {
+    Some(loader.load(path)?.and_then(|data| {
+        String::from_utf8(data)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+    }))
+}
+
+/// Path used in diagnostics for the document generated by [`Compiler::register_global`]
+pub(crate) const NATIVE_GLOBALS_PATH: &str = "<native-globals>";
+
+/// A set of native callbacks that is exposed to .slint code as a global singleton
+/// with [`Compiler::register_global`].
+#[derive(Clone, Default)]
+pub struct NativeGlobal {
+    pub(crate) callbacks: Vec<NativeCallback>,
+}
+
+#[derive(Clone)]
+pub(crate) struct NativeCallback {
+    pub name: SmolStr,
+    signature: String,
+    pure: bool,
+    pub handler: Rc<dyn Fn(&[Value]) -> Value>,
+}
+
+impl NativeGlobal {
+    /// Add a callback with the given name to this global.
+    ///
+    /// The `signature` is the list of argument types and the optional return type in
+    /// .slint syntax, for example `"(string, int) -> string"`, or `""` for a callback
+    /// without arguments nor return value.
+    pub fn add_callback(
+        &mut self,
+        name: &str,
+        signature: &str,
+        handler: impl Fn(&[Value]) -> Value + 'static,
+    ) {
+        self.callbacks.push(NativeCallback {
+            name: normalize_identifier_smolstr(name),
+            signature: signature.into(),
+            pure: false,
+            handler: Rc::new(handler),
+        });
+    }
+
+    /// Same as [`Self::add_callback`], but the callback is declared `pure` so that
+    /// it can be used in property bindings.
+    pub fn add_pure_callback(
+        &mut self,
+        name: &str,
+        signature: &str,
+        handler: impl Fn(&[Value]) -> Value + 'static,
+    ) {
+        self.add_callback(name, signature, handler);
+        self.callbacks.last_mut().unwrap().pure = true;
+    }
+}
+
+/// Name of the global holding the callbacks of the elements registered with
+/// [`Compiler::register_element`]
+const NATIVE_ELEMENTS_GLOBAL: &str = "NativeElementCallbacks";
+
+/// Name of the struct type of the property that holds the [`NativeElementState`]. Properties of
+/// struct type are stored as a [`Value`] by the interpreter.
+pub(crate) const NATIVE_ELEMENT_STATE: &str = "NativeElementState";
+
+/// The state of an instance of an element registered with [`Compiler::register_element`].
+///
+/// It is stored as a [`Value::Opaque`] in a property of the element so that it is dropped
+/// with the element.
+struct NativeElementState(std::cell::RefCell<Box<dyn NativeElement>>);
+
+impl NativeElementState {
+    fn from_value(value: &Value) -> Option<&Self> {
+        match value {
+            Value::Opaque(state) => state.downcast_ref::<Self>(),
+            _ => None,
+        }
+    }
+
+    fn dispatch_event(state: &Value, event: &NativeElementEvent) -> bool {
+        Self::from_value(state).is_some_and(|state| state.0.borrow_mut().event(event))
+    }
+}
+
+fn native_element_position(x: &Value, y: &Value) -> LogicalPosition {
+    LogicalPosition::new(
+        f64::try_from(x.clone()).unwrap_or_default() as f32,
+        f64::try_from(y.clone()).unwrap_or_default() as f32,
+    )
+}
+
+/// An event sent to a [`NativeElement`]. The positions are relative to the element.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum NativeElementEvent {
+    /// A pointer button was pressed over the element.
+    PointerPressed {
+        /// The position of the pointer.
+        position: LogicalPosition,
+        /// The button that was pressed.
+        button: PointerEventButton,
+    },
+    /// A pointer button was released, after it was pressed over the element.
+    PointerReleased {
+        /// The position of the pointer.
+        position: LogicalPosition,
+        /// The button that was released.
+        button: PointerEventButton,
+    },
+    /// The pointer moved over the element, or while a button pressed over the element is held.
+    PointerMoved {
+        /// The position of the pointer.
+        position: LogicalPosition,
+    },
+    /// The wheel of the pointer was turned, or the touchpad scrolled, over the element.
+    PointerScrolled {
+        /// The position of the pointer.
+        position: LogicalPosition,
+        /// The horizontal scroll distance.
+        delta_x: f32,
+        /// The vertical scroll distance.
+        delta_y: f32,
+    },
+    /// The pointer left the element, or the press was cancelled.
+    PointerExited,
+}
+
+/// An element implemented in Rust that can be used from .slint code.
+///
+/// The element is drawn by the renderer of the window as an image that [`Self::render`]
+/// returns, so the drawing happens on the CPU.
+///
+/// Register it with [`Compiler::register_element`].
+pub trait NativeElement {
+    /// Render the element with the given size and the current value of its properties.
+    ///
+    /// This is called again when the size or a property changes, and after
+    /// [`Self::event`] returned true. It is called while evaluating a binding, so it
+    /// must not have side effects other than caching.
+    fn render(&self, size: LogicalSize, properties: &Struct) -> Image;
+
+    /// Handle an event.
+    ///
+    /// Return true if the element handled the event and needs to be rendered again.
+    /// Scroll events that aren't handled are passed to the elements below.
+    fn event(&mut self, _event: &NativeElementEvent) -> bool {
+        false
+    }
+}
+
+/// Describes an element implemented in Rust, to be registered with [`Compiler::register_element`].
+pub struct NativeElementType {
+    properties: Vec<(SmolStr, String)>,
+    factory: Box<dyn Fn() -> Box<dyn NativeElement>>,
+}
+
+impl NativeElementType {
+    /// Creates a new element type. The `factory` is called to create the state of each
+    /// instance of the element.
+    pub fn new<T: NativeElement + 'static>(factory: impl Fn() -> T + 'static) -> Self {
+        Self { properties: Vec::new(), factory: Box::new(move || Box::new(factory())) }
+    }
+
+    /// Add an input property to the element.
+    ///
+    /// The `ty` is the type in .slint syntax, for example `"color"` or `"[string]"`.
+    /// The value of the properties is passed to [`NativeElement::render`].
+    pub fn add_property(&mut self, name: &str, ty: &str) {
+        self.properties.push((normalize_identifier_smolstr(name), ty.into()));
     }
 }
 
@@ -1013,6 +1654,16 @@ pub fn create_with_existing_window(
         })
     }
 
+    /// The type of the public property `name`, which must be normalized
+    #[cfg(feature = "json")]
+    pub(crate) fn property_type(&self, name: &str) -> Option<i_slint_compiler::langtype::Type> {
+        let guard = unsafe { generativity::Guard::new(generativity::Id::new()) };
+        self.inner
+            .unerase(guard)
+            .properties()
+            .find_map(|(n, ty)| (n == name && ty.is_property_type()).then_some(ty))
+    }
+
     /// List of publicly declared properties or callback.
     ///
     /// This is internal because it exposes the `Type` from compilerlib.
@@ -1489,6 +2140,97 @@ pub fn invoke_global(
         }
     }
 
+    /// Return the value designated by a path into an exported global singleton.
+    ///
+    /// The path starts with the exported name of the global, followed by the name of a
+    /// property, and may then address nested struct fields with `.field` and rows of
+    /// models (arrays) with `[index]`. For example `"Settings.theme.accent"` or
+    /// `"Adapter.rows[2].title"`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # i_slint_backend_testing::init_no_event_loop();
+    /// use slint_interpreter::{Compiler, Value, SharedString};
+    /// let code = r#"
+    ///     export global Adapter {
+    ///         in-out property <[{ title: string }]> rows: [{ title: "first" }, { title: "second" }];
+    ///     }
+    ///     export component MyWin inherits Window {}
+    /// "#;
+    /// let result = spin_on::spin_on(
+    ///     Compiler::default().build_from_source(code.into(), Default::default()));
+    /// let instance = result.component("MyWin").unwrap().create().unwrap();
+    /// assert_eq!(
+    ///     instance.get_global_property_by_path("Adapter.rows[1].title").unwrap(),
+    ///     Value::from(SharedString::from("second"))
+    /// );
+    /// ```
+    pub fn get_global_property_by_path(&self, path: &str) -> Result<Value, PropertyPathError> {
+        let (global, property, segments) = parse_property_path(path)?;
+        generativity::make_guard!(guard);
+        let comp = self.inner.unerase(guard);
+        let g = comp
+            .description()
+            .get_global(comp.borrow(), &global)
+            .map_err(|()| PropertyPathError::NoSuchGlobal(global.to_string()))?;
+        let value = g
+            .as_ref()
+            .get_property(&property)
+            .map_err(|()| PropertyPathError::NoSuchProperty(property.to_string()))?;
+        segments.iter().try_fold(value, |value, segment| segment.get(value))
+    }
+
+    /// Set the value designated by a path into an exported global singleton.
+    ///
+    /// See [`Self::get_global_property_by_path`] for the syntax of the path.
+    /// When the path goes through a model, the row is updated in place with
+    /// [`Model::set_row_data`], otherwise the property of the global is set to the
+    /// updated value. The new value must have the same type as the value it replaces.
+    pub fn set_global_property_by_path(
+        &self,
+        path: &str,
+        value: Value,
+    ) -> Result<(), PropertyPathError> {
+        let (global, property, segments) = parse_property_path(path)?;
+        generativity::make_guard!(guard);
+        let comp = self.inner.unerase(guard);
+        let g = comp
+            .description()
+            .get_global(comp.borrow(), &global)
+            .map_err(|()| PropertyPathError::NoSuchGlobal(global.to_string()))?;
+        let g = g.as_ref();
+        // Check the property itself before writing anything, as a path through a model
+        // writes into the model directly
+        let description = comp.description();
+        let decl = description
+            .global_property_declaration(&global, &property)
+            .filter(|decl| decl.property_type.is_property_type())
+            .ok_or_else(|| PropertyPathError::NoSuchProperty(property.to_string()))?;
+        if decl.visibility == i_slint_compiler::object_tree::PropertyVisibility::Output {
+            return Err(PropertyPathError::AccessDenied);
+        }
+        let value = if segments.is_empty() {
+            value
+        } else {
+            let current = g
+                .get_property(&property)
+                .map_err(|()| PropertyPathError::NoSuchProperty(property.to_string()))?;
+            match PropertyPathSegment::set_in(current, &segments, value)? {
+                Some(value) => value,
+                // The value was written into a model, nothing else to update
+                None => return Ok(()),
+            }
+        };
+        g.set_property(&property, value).map_err(|e| match e {
+            SetPropertyError::NoSuchProperty => {
+                PropertyPathError::NoSuchProperty(property.to_string())
+            }
+            SetPropertyError::WrongType => PropertyPathError::WrongType(path.into()),
+            SetPropertyError::AccessDenied => PropertyPathError::AccessDenied,
+        })
+    }
+
     /// Find all positions of the components which are pointed by a given source location.
     ///
     /// WARNING: this is not part of the public API
@@ -1625,6 +2367,41 @@ pub enum InvokeError {
     NoSuchCallable,
 }
 
+/// Error returned by [`ComponentInstance::get_global_property_by_path`] and
+/// [`ComponentInstance::set_global_property_by_path`]
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Error, derive_more::Display)]
+#[non_exhaustive]
+pub enum PropertyPathError {
+    /// The path could not be parsed
+    #[display("invalid property path: {_0}")]
+    InvalidPath(#[error(not(source))] String),
+    /// There is no exported global with the given name
+    #[display("no such global: {_0}")]
+    NoSuchGlobal(#[error(not(source))] String),
+    /// The global has no property with the given name
+    #[display("no such property: {_0}")]
+    NoSuchProperty(#[error(not(source))] String),
+    /// The struct has no field with the given name
+    #[display("no such field: {_0}")]
+    NoSuchField(#[error(not(source))] String),
+    /// The index is past the end of the model
+    #[display("index {index} out of bounds (row count is {row_count})")]
+    IndexOutOfBounds {
+        /// The requested row
+        index: usize,
+        /// The number of rows in the model
+        row_count: usize,
+    },
+    /// The value at the given path doesn't have the expected type: a field was
+    /// accessed on something that isn't a struct, an index on something that isn't
+    /// a model, or the value to set doesn't match the type of the destination.
+    #[display("wrong type at {_0}")]
+    WrongType(#[error(not(source))] String),
+    /// Attempt to set an output property.
+    #[display("access denied")]
+    AccessDenied,
+}
+
 /// Enters the main event loop. This is necessary in order to receive
 /// events from the windowing system in order to render to the screen
 /// and react to user input.
@@ -1908,6 +2685,409 @@ fn globals() {
     );
 }
 
+#[test]
+fn global_property_paths() {
+    i_slint_backend_testing::init_no_event_loop();
+    let mut compiler = Compiler::default();
+    compiler.set_style("fluent".into());
+    let definition = spin_on::spin_on(
+        compiler.build_from_source(
+            r#"
+    struct Theme { accent: color, font-size: length }
+    struct Row { title: string, count: int }
+    export global Settings {
+        in-out property <{ theme: Theme, name: string }> config: { theme: { accent: #ff0000, font-size: 12px }, name: "x" };
+        out property <{ name: string }> out-config: { name: "y" };
+    }
+    export global Adapter {
+        in-out property <[Row]> rows: [{ title: "a", count: 1 }, { title: "b", count: 2 }];
+        out property <[Row]> out-rows: [{ title: "o", count: 1 }];
+        private property <[Row]> private-rows: [{ title: "p", count: 1 }];
+    }
+    export component Dummy {
+        out property <string> second-title: Adapter.rows[1].title;
+    }"#
+            .into(),
+            "".into(),
+        ),
+    )
+    .component("Dummy")
+    .unwrap();
+    let instance = definition.create().unwrap();
+
+    assert_eq!(
+        instance.get_global_property_by_path("Settings.config.theme.accent"),
+        Ok(Value::Brush(Brush::SolidColor(Color::from_rgb_u8(255, 0, 0))))
+    );
+    assert_eq!(
+        instance.get_global_property_by_path("Settings.config.theme.font_size"),
+        Ok(Value::Number(12.))
+    );
+    assert_eq!(
+        instance.get_global_property_by_path("Adapter.rows[1].title"),
+        Ok(Value::String("b".into()))
+    );
+
+    instance
+        .set_global_property_by_path(
+            "Settings.config.theme.accent",
+            Value::Brush(Brush::SolidColor(Color::from_rgb_u8(0, 0, 255))),
+        )
+        .unwrap();
+    assert_eq!(
+        instance.get_global_property_by_path("Settings.config.theme.accent"),
+        Ok(Value::Brush(Brush::SolidColor(Color::from_rgb_u8(0, 0, 255))))
+    );
+    assert_eq!(
+        instance.get_global_property_by_path("Settings.config.name"),
+        Ok(Value::String("x".into()))
+    );
+
+    instance
+        .set_global_property_by_path("Adapter.rows[1].title", Value::String("c".into()))
+        .unwrap();
+    assert_eq!(instance.get_property("second-title"), Ok(Value::String("c".into())));
+    assert_eq!(
+        instance.get_global_property_by_path("Adapter.rows[0].title"),
+        Ok(Value::String("a".into()))
+    );
+
+    assert!(matches!(
+        instance.get_global_property_by_path("Settings"),
+        Err(PropertyPathError::InvalidPath(_))
+    ));
+    assert!(matches!(
+        instance.get_global_property_by_path("Adapter.rows[x]"),
+        Err(PropertyPathError::InvalidPath(_))
+    ));
+    assert!(matches!(
+        instance.get_global_property_by_path("Adapter.rows[0"),
+        Err(PropertyPathError::InvalidPath(_))
+    ));
+    assert_eq!(
+        instance.get_global_property_by_path("Nope.rows"),
+        Err(PropertyPathError::NoSuchGlobal("Nope".into()))
+    );
+    assert_eq!(
+        instance.get_global_property_by_path("Adapter.nope"),
+        Err(PropertyPathError::NoSuchProperty("nope".into()))
+    );
+    assert_eq!(
+        instance.get_global_property_by_path("Adapter.rows[0].nope"),
+        Err(PropertyPathError::NoSuchField("nope".into()))
+    );
+    assert_eq!(
+        instance.get_global_property_by_path("Adapter.rows[5]"),
+        Err(PropertyPathError::IndexOutOfBounds { index: 5, row_count: 2 })
+    );
+    assert!(matches!(
+        instance.get_global_property_by_path("Adapter.rows.title"),
+        Err(PropertyPathError::WrongType(_))
+    ));
+    assert!(matches!(
+        instance.set_global_property_by_path("Adapter.rows[0].count", Value::String("1".into())),
+        Err(PropertyPathError::WrongType(_))
+    ));
+    assert_eq!(
+        instance
+            .set_global_property_by_path("Adapter.out-rows[0].title", Value::String("z".into())),
+        Err(PropertyPathError::AccessDenied)
+    );
+    assert_eq!(
+        instance.get_global_property_by_path("Adapter.out-rows[0].title"),
+        Ok(Value::String("o".into()))
+    );
+    assert_eq!(
+        instance.set_global_property_by_path("Settings.out-config.name", Value::String("z".into())),
+        Err(PropertyPathError::AccessDenied)
+    );
+    assert_eq!(
+        instance.set_global_property_by_path(
+            "Adapter.private-rows[0].title",
+            Value::String("z".into())
+        ),
+        Err(PropertyPathError::NoSuchProperty("private-rows".into()))
+    );
+}
+
+#[test]
+fn native_globals() {
+    i_slint_backend_testing::init_no_event_loop();
+    let log = Rc::new(std::cell::RefCell::new(Vec::<SharedString>::new()));
+    let mut logger = NativeGlobal::default();
+    let log_ = log.clone();
+    logger.add_callback("log", "(string)", move |args| {
+        log_.borrow_mut().push(args[0].clone().try_into().unwrap());
+        Value::Void
+    });
+    let mut math = NativeGlobal::default();
+    math.add_pure_callback("add_one", "(int) -> int", |args| {
+        Value::Number(f64::try_from(args[0].clone()).unwrap() + 1.)
+    });
+    math.add_callback("random", "() -> int", |_| Value::Number(4.));
+
+    let mut compiler = Compiler::default();
+    compiler.set_style("fluent".into());
+    compiler.register_global("Logger", logger);
+    compiler.register_global("Math", math);
+    let result = spin_on::spin_on(
+        compiler.build_from_source(
+            r#"
+    export component Dummy {
+        in property <int> value: 41;
+        out property <int> next: Math.add-one(value);
+        public function do-log(text: string) { Logger.log(text); }
+    }"#
+            .into(),
+            "".into(),
+        ),
+    );
+    assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+    let instance = result.component("Dummy").unwrap().create().unwrap();
+    assert_eq!(instance.get_property("next"), Ok(Value::Number(42.)));
+    instance.invoke("do-log", &[Value::String("hello".into())]).unwrap();
+    assert_eq!(*log.borrow(), vec![SharedString::from("hello")]);
+
+    // Impure callbacks can't be used in bindings
+    let result = spin_on::spin_on(compiler.build_from_source(
+        "export component Dummy { out property <int> val: Math.random(); }".into(),
+        "".into(),
+    ));
+    let diagnostics = result.diagnostics().map(|d| d.message().to_string()).collect::<Vec<_>>();
+    assert!(diagnostics.iter().any(|d| d.contains("pure")), "{diagnostics:?}");
+    // The globals can't hide the builtin elements
+    compiler.register_global("Text", NativeGlobal::default());
+    let result = spin_on::spin_on(compiler.build_from_source(
+        "export component Dummy { Text { text: \"hello\"; } }".into(),
+        "".into(),
+    ));
+    let diagnostics = result.diagnostics().map(|d| d.message().to_string()).collect::<Vec<_>>();
+    assert!(diagnostics.iter().any(|d| d.contains("builtin element")), "{diagnostics:?}");
+}
+
+#[test]
+fn native_elements() {
+    use i_slint_core::graphics::{Rgba8Pixel, SharedPixelBuffer};
+    i_slint_backend_testing::init_no_event_loop();
+    use i_slint_core::platform::WindowEvent;
+    struct Element(Rc<std::cell::RefCell<Vec<NativeElementEvent>>>);
+    impl NativeElement for Element {
+        fn render(&self, size: LogicalSize, _properties: &Struct) -> Image {
+            let buffer = SharedPixelBuffer::<Rgba8Pixel>::new(size.width as _, size.height as _);
+            Image::from_rgba8(buffer)
+        }
+        fn event(&mut self, event: &NativeElementEvent) -> bool {
+            self.0.borrow_mut().push(event.clone());
+            matches!(
+                event,
+                NativeElementEvent::PointerPressed { .. }
+                    | NativeElementEvent::PointerScrolled { .. }
+            )
+        }
+    }
+
+    let events = Rc::new(std::cell::RefCell::new(Vec::new()));
+    let events_ = events.clone();
+    let mut element = NativeElementType::new(move || Element(events_.clone()));
+    element.add_property("label", "string");
+    let mut compiler = Compiler::default();
+    compiler.set_style("fluent".into());
+    compiler.register_element("Native", element);
+    let result = spin_on::spin_on(
+        compiler.build_from_source(
+            r#"
+    export component Main inherits Window {
+        in property <string> text: "hello";
+        width: 100px;
+        height: 100px;
+        VerticalLayout {
+            padding: 5px;
+            Native { label: text; }
+        }
+    }"#
+            .into(),
+            "".into(),
+        ),
+    );
+    assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+    let instance = result.component("Main").unwrap().create().unwrap();
+    instance.show().unwrap();
+    let window = instance.window();
+    window.dispatch_event(WindowEvent::PointerPressed {
+        position: LogicalPosition::new(10., 20.),
+        button: PointerEventButton::Left,
+    });
+    window.dispatch_event(WindowEvent::PointerReleased {
+        position: LogicalPosition::new(10., 20.),
+        button: PointerEventButton::Left,
+    });
+    window.dispatch_event(WindowEvent::PointerScrolled {
+        position: LogicalPosition::new(10., 20.),
+        delta_x: 0.,
+        delta_y: 3.,
+    });
+    events.borrow_mut().retain(|e| !matches!(e, NativeElementEvent::PointerMoved { .. }));
+    assert_eq!(
+        events.borrow()[..],
+        [
+            NativeElementEvent::PointerPressed {
+                position: LogicalPosition::new(5., 15.),
+                button: PointerEventButton::Left
+            },
+            NativeElementEvent::PointerReleased {
+                position: LogicalPosition::new(5., 15.),
+                button: PointerEventButton::Left
+            },
+            NativeElementEvent::PointerScrolled {
+                position: LogicalPosition::new(5., 15.),
+                delta_x: 0.,
+                delta_y: 3.
+            },
+        ]
+    );
+}
+
+#[test]
+fn native_elements_state_dropped_with_instance() {
+    i_slint_backend_testing::init_no_event_loop();
+    struct Element(Rc<core::cell::Cell<usize>>);
+    impl NativeElement for Element {
+        fn render(&self, _size: LogicalSize, _properties: &Struct) -> Image {
+            Image::default()
+        }
+    }
+    impl Drop for Element {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() - 1);
+        }
+    }
+
+    let alive = Rc::new(core::cell::Cell::new(0));
+    let alive_ = alive.clone();
+    let element = NativeElementType::new(move || {
+        alive_.set(alive_.get() + 1);
+        Element(alive_.clone())
+    });
+    let mut compiler = Compiler::default();
+    compiler.register_element("Native", element);
+    let result = spin_on::spin_on(
+        compiler.build_from_source(
+            r#"
+    export component Main inherits Window {
+        in property <int> count: 3;
+        out property <length> layout-height: layout.preferred-height;
+        layout := VerticalLayout {
+            for i in count: Native { }
+        }
+    }"#
+            .into(),
+            "".into(),
+        ),
+    );
+    assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+    let instance = result.component("Main").unwrap().create().unwrap();
+    // Evaluating the layout instantiates the repeated elements
+    instance.get_property("layout-height").unwrap();
+    assert_eq!(alive.get(), 3);
+    instance.set_property("count", Value::Number(1.)).unwrap();
+    instance.get_property("layout-height").unwrap();
+    assert_eq!(alive.get(), 1);
+    drop(instance);
+    assert_eq!(alive.get(), 0);
+}
+
+#[test]
+fn resource_loader() {
+    i_slint_backend_testing::init_no_event_loop();
+    struct InMemory(Rc<std::cell::RefCell<Vec<PathBuf>>>);
+    impl ResourceLoader for InMemory {
+        fn load(&self, path: &Path) -> Option<std::io::Result<Vec<u8>>> {
+            self.0.borrow_mut().push(path.to_owned());
+            let data: &[u8] = match path.to_str()? {
+                "/virtual/main.slint" => {
+                    b"import { Icon } from \"widgets/icon.slint\";\n\
+                      export component Main { out property <image> img: icon.source; icon := Icon {} }"
+                }
+                "/virtual/widgets/icon.slint" => {
+                    b"export component Icon { out property <image> source: @image-url(\"icon.svg\"); }"
+                }
+                "/virtual/widgets/icon.svg" => {
+                    br#"<svg width="3" height="2" xmlns="http://www.w3.org/2000/svg"></svg>"#
+                }
+                "/virtual/broken.slint" => {
+                    return Some(Err(std::io::ErrorKind::PermissionDenied.into()))
+                }
+                _ => return None,
+            };
+            Some(Ok(data.to_vec()))
+        }
+    }
+
+    let requested = Rc::new(std::cell::RefCell::new(Vec::new()));
+    let mut compiler = Compiler::default();
+    compiler.set_style("fluent".into());
+    compiler.set_resource_loader(InMemory(requested.clone()));
+    let result = spin_on::spin_on(compiler.build_from_path("/virtual/main.slint"));
+    assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+    let instance = result.component("Main").unwrap().create().unwrap();
+    let Ok(Value::Image(image)) = instance.get_property("img") else { panic!("not an image") };
+    assert_eq!(image.size(), i_slint_core::graphics::IntSize::new(3, 2));
+    assert!(requested.borrow().contains(&PathBuf::from("/virtual/widgets/icon.svg")));
+
+    let result = spin_on::spin_on(compiler.build_from_path("/virtual/broken.slint"));
+    assert!(result.has_errors());
+}
+
+#[test]
+fn incremental_compilation() {
+    i_slint_backend_testing::init_no_event_loop();
+    let temp_dir = tempfile::tempdir().unwrap();
+    let dir = temp_dir.path();
+    std::fs::write(dir.join("leaf.slint"), "export global Leaf { out property <int> value: 1; }")
+        .unwrap();
+    std::fs::write(
+        dir.join("mid.slint"),
+        "import { Leaf } from \"leaf.slint\";\n\
+         export component Mid { out property <int> value: Leaf.value * 10; }",
+    )
+    .unwrap();
+    let main_path = dir.join("main.slint");
+    std::fs::write(
+        &main_path,
+        "import { Mid } from \"mid.slint\";\n\
+         import { Button } from \"std-widgets.slint\";\n\
+         export component Main { out property <int> value: mid.value; mid := Mid {} Button {} }",
+    )
+    .unwrap();
+
+    let mut compiler = Compiler::default();
+    compiler.set_style("fluent".into());
+    compiler.set_incremental(true);
+    let value = |compiler: &Compiler| {
+        let result = spin_on::spin_on(compiler.build_from_path(&main_path));
+        assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+        result.component("Main").unwrap().create().unwrap().get_property("value").unwrap()
+    };
+    assert_eq!(value(&compiler), Value::Number(10.));
+    assert_eq!(value(&compiler), Value::Number(10.));
+
+    std::fs::write(dir.join("leaf.slint"), "export global Leaf { out property <int> value: 4; }")
+        .unwrap();
+    assert_eq!(value(&compiler), Value::Number(40.));
+
+    // Errors in imported files are reported on every build
+    std::fs::write(dir.join("leaf.slint"), "export global Leaf { out property <int> value: ; }")
+        .unwrap();
+    for _ in 0..2 {
+        let result = spin_on::spin_on(compiler.build_from_path(&main_path));
+        assert!(result.has_errors());
+    }
+
+    std::fs::write(dir.join("leaf.slint"), "export global Leaf { out property <int> value: 2; }")
+        .unwrap();
+    assert_eq!(value(&compiler), Value::Number(20.));
+}
+
 #[test]
 fn call_functions() {
     i_slint_backend_testing::init_no_event_loop();
@@ -2011,6 +3191,37 @@ fn component_definition_struct_properties() {
     );
 }
 
+#[test]
+fn opaque_values_only_for_native_element_state() {
+    i_slint_backend_testing::init_no_event_loop();
+    let mut compiler = Compiler::default();
+    compiler.set_style("fluent".into());
+    let comp_def = spin_on::spin_on(
+        compiler.build_from_source(
+            r#"
+    export struct Settings { value: string }
+    export component Dummy {
+        in-out property <string> text;
+        in-out property <Settings> settings;
+        in-out property <[int]> list;
+        in-out property <int> number;
+    }"#
+            .into(),
+            "".into(),
+        ),
+    )
+    .component("Dummy")
+    .unwrap();
+    let instance = comp_def.create().unwrap();
+    for name in ["text", "settings", "list", "number"] {
+        assert_eq!(
+            instance.set_property(name, Value::Opaque(Rc::new(42))),
+            Err(SetPropertyError::WrongType),
+            "{name}"
+        );
+    }
+}
+
 #[test]
 fn component_definition_model_properties() {
     use i_slint_core::model::*;
@@ -2067,6 +3278,36 @@ fn check_model(val: Value, r: &[f64]) {
     check_model(instance.get_property("prop").unwrap(), &[]);
 }
 
+#[test]
+fn struct_model_adapter_unmapped_fields() {
+    use i_slint_core::model::*;
+    i_slint_backend_testing::init_no_event_loop();
+    let comp_def = spin_on::spin_on(Compiler::default().build_from_source(
+        r#"
+        export struct Task { title: string, done: bool, priority: int }
+        export component Tasks {
+            in-out property <[Task]> tasks;
+            out property <int> priority: tasks[0].priority;
+            public function bump(index: int) { tasks[index].priority += 1; tasks[index].done = true; }
+        }"#
+        .into(),
+        "".into(),
+    ))
+    .component("Tasks")
+    .unwrap();
+    let instance = comp_def.create().unwrap();
+
+    let tasks = std::rc::Rc::new(VecModel::from(vec![(SharedString::from("a"), false)]));
+    let adapter = crate::StructModelAdapter::new(tasks.clone())
+        .field("title", |t: &(SharedString, bool)| t.0.clone(), |t, v| t.0 = v)
+        .field("done", |t: &(SharedString, bool)| t.1, |t, v| t.1 = v);
+    instance.set_property("tasks", adapter.into()).unwrap();
+
+    assert_eq!(instance.get_property("priority").unwrap(), Value::Number(0.));
+    instance.invoke("bump", &[Value::Number(0.)]).unwrap();
+    assert_eq!(tasks.row_data(0).unwrap(), (SharedString::from("a"), true));
+}
+
 #[test]
 fn lang_type_to_value_type() {
     use i_slint_compiler::langtype::Struct as LangStruct;
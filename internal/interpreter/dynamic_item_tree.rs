@@ -6,10 +6,11 @@
 use crate::{dynamic_type, eval};
 use core::ptr::NonNull;
 use dynamic_type::{Instance, InstanceBox};
+use i_slint_compiler::diagnostics::BuildDiagnostics;
 use i_slint_compiler::expression_tree::{Expression, NamedReference};
 use i_slint_compiler::langtype::Type;
 use i_slint_compiler::object_tree::ElementRc;
-use i_slint_compiler::{diagnostics::BuildDiagnostics, object_tree::PropertyDeclaration};
+use i_slint_compiler::object_tree::PropertyDeclaration;
 use i_slint_compiler::{generator, object_tree, parser, CompilerConfiguration};
 use i_slint_core::accessibility::{
     AccessibilityAction, AccessibleStringProperty, SupportedAccessibilityAction,
@@ -43,6 +44,7 @@
 use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::num::NonZeroU32;
+use std::path::Path;
 use std::rc::Weak;
 use std::{pin::Pin, rc::Rc};
 
@@ -469,6 +471,8 @@ pub enum WindowOptions {
     #[default]
     CreateNewWindow,
     UseExistingWindow(WindowAdapterRc),
+    /// A `Window` declared inside another component: it shares the globals of its parent, but is the root of its own window
+    SecondaryWindow(WindowAdapterRc),
     #[cfg(target_arch = "wasm32")]
     CreateWithCanvasId(String),
     Embed {
@@ -514,6 +518,23 @@ pub fn global_properties(
             .map(|global| internal_properties_to_public(global.public_properties()))
     }
 
+    /// Return the declaration of the public property `property` of the exported global `global`.
+    /// Both names must be normalized.
+    pub fn global_property_declaration(
+        &self,
+        global: &str,
+        property: &str,
+    ) -> Option<&PropertyDeclaration> {
+        let g = self.compiled_globals.as_ref().expect("Root component should have globals");
+        g.exported_globals_by_name
+            .get(global)
+            .and_then(|global_idx| g.compiled_globals.get(*global_idx))?
+            .public_properties()
+            .find(|(name, _)| name.as_str() == property)
+            .map(|(_, decl)| decl)
+            .filter(|decl| decl.expose_in_public_api)
+    }
+
     /// Instantiate a runtime ItemTree from this ItemTreeDescription
     pub fn create(
         self: Rc<Self>,
@@ -837,6 +858,9 @@ pub async fn load(
     source: String,
     path: std::path::PathBuf,
     mut compiler_config: CompilerConfiguration,
+    native_globals: &BTreeMap<SmolStr, crate::api::NativeGlobal>,
+    resource_loader: Option<Rc<dyn crate::api::ResourceLoader>>,
+    incremental_cache: Option<&std::cell::RefCell<i_slint_compiler::typeloader::IncrementalCache>>,
 ) -> CompilationResult {
     // If the native style should be Qt, resolve it here as we know that we have it
     let is_native = match &compiler_config.style {
@@ -873,9 +897,36 @@ pub async fn load(
         );
     }
 
+    if let Some(resource_loader) = resource_loader.clone() {
+        let fallback = compiler_config.open_import_fallback.take();
+        compiler_config.open_import_fallback =
+            Some(Rc::new(move |path| -> Pin<Box<dyn std::future::Future<Output = _>>> {
+                match crate::api::load_source(&*resource_loader, Path::new(&path)) {
+                    Some(result) => Box::pin(async move { Some(result) }),
+                    None => match &fallback {
+                        Some(fallback) => fallback(path),
+                        None => Box::pin(async { None }),
+                    },
+                }
+            }));
+    }
+
     let diag = BuildDiagnostics::default();
+    // Taken out of the RefCell while compiling, as another build could run concurrently
+    let mut cache = incremental_cache.map(std::cell::RefCell::take);
     #[cfg(feature = "highlight")]
-    let (path, mut diag, loader, raw_type_loader) =
+    let (path, mut diag, loader, raw_type_loader) = if let Some(cache) = &mut cache {
+        i_slint_compiler::load_root_file_incremental(
+            &path,
+            &path,
+            source,
+            diag,
+            compiler_config,
+            true,
+            cache,
+        )
+        .await
+    } else {
         i_slint_compiler::load_root_file_with_raw_type_loader(
             &path,
             &path,
@@ -883,10 +934,27 @@ pub async fn load(
             diag,
             compiler_config,
         )
-        .await;
+        .await
+    };
     #[cfg(not(feature = "highlight"))]
-    let (path, mut diag, loader) =
-        i_slint_compiler::load_root_file(&path, &path, source, diag, compiler_config).await;
+    let (path, mut diag, loader) = if let Some(cache) = &mut cache {
+        let (path, diag, loader, _) = i_slint_compiler::load_root_file_incremental(
+            &path,
+            &path,
+            source,
+            diag,
+            compiler_config,
+            false,
+            cache,
+        )
+        .await;
+        (path, diag, loader)
+    } else {
+        i_slint_compiler::load_root_file(&path, &path, source, diag, compiler_config).await
+    };
+    if let (Some(incremental_cache), Some(cache)) = (incremental_cache, cache) {
+        incremental_cache.replace(cache);
+    }
     if diag.has_errors() {
         return CompilationResult {
             components: HashMap::new(),
@@ -905,7 +973,10 @@ pub async fn load(
 
     let doc = loader.get_document(&path).unwrap();
 
-    let compiled_globals = Rc::new(CompiledGlobalCollection::compile(doc));
+    let mut compiled_globals = CompiledGlobalCollection::compile(doc, native_globals);
+    compiled_globals.loaded_resources =
+        resource_loader.map(|loader| Rc::new(crate::eval::LoadedResources::new(loader)));
+    let compiled_globals = Rc::new(compiled_globals);
     let mut components = HashMap::new();
 
     let popup_menu_description = if let Some(popup_menu_impl) = &doc.popup_menu_impl {
@@ -988,14 +1059,21 @@ fn generate_rtti() -> HashMap<&'static str, Rc<ItemRTTI>> {
             rtti_for::<Empty>(),
             rtti_for::<ImageItem>(),
             rtti_for::<ClippedImage>(),
+            rtti_for::<VideoItem>(),
+            rtti_for::<LiveFrameItem>(),
             rtti_for::<ComplexText>(),
             rtti_for::<SimpleText>(),
+            rtti_for::<RichText>(),
             rtti_for::<Rectangle>(),
             rtti_for::<BasicBorderRectangle>(),
             rtti_for::<BorderRectangle>(),
             rtti_for::<TouchArea>(),
             rtti_for::<FocusScope>(),
             rtti_for::<SwipeGestureHandler>(),
+            rtti_for::<GestureArea>(),
+            rtti_for::<DragArea>(),
+            rtti_for::<DropArea>(),
+            rtti_for::<WindowControlArea>(),
             rtti_for::<Path>(),
             rtti_for::<Flickable>(),
             rtti_for::<WindowItem>(),
@@ -1005,6 +1083,8 @@ fn generate_rtti() -> HashMap<&'static str, Rc<ItemRTTI>> {
             rtti_for::<Rotate>(),
             rtti_for::<Opacity>(),
             rtti_for::<Layer>(),
+            rtti_for::<ShaderEffect>(),
+            rtti_for::<BackdropBlur>(),
             rtti_for::<ContextMenu>(),
         ]
         .iter()
@@ -1546,27 +1626,37 @@ pub fn instantiate(
         description.root_offset.apply(instance_ref.as_ref()).set(self_weak.clone()).ok().unwrap();
     } else {
         generativity::make_guard!(guard);
+        let is_secondary_window = matches!(window_options, Some(WindowOptions::SecondaryWindow(_)));
         let root = root
             .or_else(|| {
-                instance_ref.parent_instance(guard).map(|parent| parent.root_weak().clone())
+                instance_ref
+                    .parent_instance(guard)
+                    .filter(|_| !is_secondary_window)
+                    .map(|parent| parent.root_weak().clone())
             })
             .unwrap_or_else(|| self_weak.clone());
         description.root_offset.apply(instance_ref.as_ref()).set(root).ok().unwrap();
     }
 
     if !description.original.is_global() {
-        let maybe_window_adapter =
-            if let Some(WindowOptions::UseExistingWindow(adapter)) = window_options.as_ref() {
-                Some(adapter.clone())
-            } else {
-                instance_ref.maybe_window_adapter()
-            };
+        let maybe_window_adapter = if let Some(
+            WindowOptions::UseExistingWindow(adapter) | WindowOptions::SecondaryWindow(adapter),
+        ) = window_options.as_ref()
+        {
+            Some(adapter.clone())
+        } else {
+            instance_ref.maybe_window_adapter()
+        };
 
         let component_rc = vtable::VRc::into_dyn(self_rc.clone());
         i_slint_core::item_tree::register_item_tree(&component_rc, maybe_window_adapter);
     }
 
-    if let Some(WindowOptions::UseExistingWindow(existing_adapter)) = &window_options {
+    if let Some(
+        WindowOptions::UseExistingWindow(existing_adapter)
+        | WindowOptions::SecondaryWindow(existing_adapter),
+    ) = &window_options
+    {
         description
             .window_adapter_offset
             .apply(instance_ref.as_ref())
@@ -1716,7 +1806,7 @@ pub fn instantiate(
         },
     );
 
-    for rep_in_comp in &description.repeater {
+    for (index, rep_in_comp) in description.repeater.iter().enumerate() {
         generativity::make_guard!(guard);
         let rep_in_comp = rep_in_comp.unerase(guard);
 
@@ -1727,6 +1817,31 @@ pub fn instantiate(
             let m = model_binding_closure();
             i_slint_core::model::ModelRc::new(crate::value_model::ValueModel::new(m))
         });
+
+        // The ListView is inlined in this component, so its callback is declared in the root
+        let scroll_to_row =
+            rep_in_comp.item_tree_to_repeat.original.parent_element.upgrade().and_then(|e| {
+                e.borrow().repeated.as_ref()?.is_listview.as_ref()?.scroll_to_row.clone()
+            });
+        if let Some(callback_offset) =
+            scroll_to_row.and_then(|nr| description.custom_callbacks.get(nr.name()))
+        {
+            let self_weak = self_weak.clone();
+            callback_offset.apply(instance_ref.as_ref()).set_handler(move |args: &[Value]| {
+                let row = args.first().cloned().and_then(|row| i32::try_from(row).ok());
+                if let (Some(self_rc), Some(Ok(row))) =
+                    (self_weak.upgrade(), row.map(usize::try_from))
+                {
+                    generativity::make_guard!(guard);
+                    let self_ = self_rc.unerase(guard);
+                    let instance_ref = self_.borrow_instance();
+                    generativity::make_guard!(guard);
+                    let rep_in_comp = instance_ref.description.repeater[index].unerase(guard);
+                    rep_in_comp.offset.apply_pin(instance_ref.instance).scroll_to_row(row);
+                }
+                Value::Void
+            });
+        }
     }
 
     update_timers(instance_ref);
@@ -2268,6 +2383,14 @@ pub fn root_weak(&self) -> &ErasedItemTreeBoxWeak {
         self.description.root_offset.apply(self.as_ref()).get().unwrap()
     }
 
+    /// The resources loaded through the [`crate::ResourceLoader`] of the compiler, if any
+    pub(crate) fn loaded_resources(&self) -> Option<Rc<crate::eval::LoadedResources>> {
+        let root = self.root_weak().upgrade()?;
+        generativity::make_guard!(guard);
+        let comp = root.unerase(guard);
+        comp.description.compiled_globals.as_ref()?.loaded_resources.clone()
+    }
+
     pub fn window_adapter(&self) -> WindowAdapterRc {
         let root_weak = vtable::VWeak::into_dyn(self.root_weak().clone());
         let root = self.root_weak().upgrade().unwrap();
@@ -2305,15 +2428,18 @@ pub fn get_or_init_window_adapter_ref<'b, 'id2>(
                 result.ok_or(PlatformError::NoPlatform)
             } else if do_create {
                 let extra_data = description.extra_data_offset.apply(instance);
-                let window_adapter = // We are the root: Create a window adapter
-                    i_slint_backend_selector::with_platform(|_b| {
+                // We are the root: Use the window adapter of an OffscreenWindowAdapter, or create one
+                let window_adapter = match i_slint_core::offscreen::take_pending_window_adapter() {
+                    Some(adapter) => adapter,
+                    None => i_slint_backend_selector::with_platform(|_b| {
                         #[cfg(not(target_arch = "wasm32"))]
                         return _b.create_window_adapter();
                         #[cfg(target_arch = "wasm32")]
                         i_slint_backend_winit::create_gl_window_with_canvas_id(
                             extra_data.canvas_id.get().map_or("canvas", |s| s.as_str()),
                         )
-                    })?;
+                    })?,
+                };
 
                 let comp_rc = extra_data.self_weak.get().unwrap().upgrade().unwrap();
                 WindowInner::from_pub(window_adapter.window())
@@ -2433,6 +2559,55 @@ pub fn show_popup(
     inst.run_setup_code();
 }
 
+/// Show a `Window` declared inside another component in its own window
+pub fn show_secondary_window(
+    element: ElementRc,
+    instance: InstanceRef,
+    popup: &object_tree::PopupWindow,
+    parent_comp: ErasedItemTreeBoxWeak,
+) -> Result<(), PlatformError> {
+    let window_id = std::cell::Cell::new(
+        instance.description.popup_ids.borrow().get(&element.borrow().id).copied(),
+    );
+    let result = i_slint_core::window::window_manager::show_secondary_window(&window_id, || {
+        generativity::make_guard!(guard);
+        let compiled = generate_item_tree(
+            &popup.component,
+            None,
+            parent_comp.upgrade().unwrap().0.description().popup_menu_description.clone(),
+            false,
+            guard,
+        );
+        let window_adapter =
+            i_slint_backend_selector::with_platform(|b| b.create_window_adapter())?;
+        let inst = instantiate(
+            compiled,
+            Some(parent_comp),
+            None,
+            Some(&WindowOptions::SecondaryWindow(window_adapter.clone())),
+            Default::default(),
+        );
+        WindowInner::from_pub(window_adapter.window())
+            .set_component(&vtable::VRc::into_dyn(inst.clone()));
+        inst.run_setup_code();
+        Ok(vtable::VRc::into_dyn(inst))
+    });
+    let mut popup_ids = instance.description.popup_ids.borrow_mut();
+    match window_id.get() {
+        Some(id) => popup_ids.insert(element.borrow().id.clone(), id),
+        None => popup_ids.remove(&element.borrow().id),
+    };
+    result
+}
+
+/// Close a `Window` declared inside another component, that was shown with [`show_secondary_window`]
+pub fn close_secondary_window(element: ElementRc, instance: InstanceRef) {
+    let window_id = std::cell::Cell::new(
+        instance.description.popup_ids.borrow_mut().remove(&element.borrow().id),
+    );
+    i_slint_core::window::window_manager::close_secondary_window(&window_id);
+}
+
 pub fn close_popup(
     element: ElementRc,
     instance: InstanceRef,
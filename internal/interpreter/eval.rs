@@ -4,7 +4,10 @@
 use crate::api::{SetPropertyError, Struct, Value};
 use crate::dynamic_item_tree::InstanceRef;
 use core::pin::Pin;
-use corelib::graphics::{GradientStop, LinearGradientBrush, PathElement, RadialGradientBrush};
+use corelib::graphics::{
+    ConicGradientBrush, FourCornerGradientBrush, GradientStop, LinearGradientBrush, PathElement,
+    RadialGradientBrush,
+};
 use corelib::items::{ColorScheme, ItemRef, MenuEntry, PropertyAnimation};
 use corelib::model::{Model, ModelExt, ModelRc, VecModel};
 use corelib::rtti::AnimatedBindingKind;
@@ -19,6 +22,7 @@
 use i_slint_compiler::object_tree::ElementRc;
 use i_slint_core as corelib;
 use smol_str::SmolStr;
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 
@@ -132,6 +136,58 @@ pub fn from_function_arguments(
     }
 }
 
+/// Images and fonts loaded through the [`crate::ResourceLoader`] set on the compiler.
+///
+/// They are kept so that they are only loaded once, even if several components use them.
+pub(crate) struct LoadedResources {
+    loader: Rc<dyn crate::ResourceLoader>,
+    images: RefCell<HashMap<std::path::PathBuf, corelib::graphics::Image>>,
+    /// Fonts must stay alive as long as the renderer uses them, so they are leaked
+    fonts: RefCell<HashMap<std::path::PathBuf, &'static [u8]>>,
+}
+
+impl LoadedResources {
+    pub fn new(loader: Rc<dyn crate::ResourceLoader>) -> Self {
+        Self { loader, images: Default::default(), fonts: Default::default() }
+    }
+
+    /// Returns None if the loader doesn't provide this image
+    pub fn load_image(
+        &self,
+        path: &std::path::Path,
+    ) -> Option<Result<corelib::graphics::Image, corelib::graphics::LoadImageError>> {
+        if let Some(image) = self.images.borrow().get(path) {
+            return Some(Ok(image.clone()));
+        }
+        let data = match self.loader.load(path)? {
+            Ok(data) => data,
+            Err(err) => {
+                eprintln!("Could not load image {}: {}", path.display(), err);
+                return Some(Err(Default::default()));
+            }
+        };
+        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or_default();
+        let image = corelib::graphics::load_image_from_data(&data, extension.as_bytes());
+        if let Ok(image) = &image {
+            self.images.borrow_mut().insert(path.to_owned(), image.clone());
+        }
+        Some(image)
+    }
+
+    /// Returns None if the loader doesn't provide this font
+    pub fn load_font(&self, path: &std::path::Path) -> Option<std::io::Result<&'static [u8]>> {
+        if let Some(data) = self.fonts.borrow().get(path) {
+            return Some(Ok(data));
+        }
+        let data: &'static [u8] = match self.loader.load(path)? {
+            Ok(data) => Box::leak(data.into_boxed_slice()),
+            Err(err) => return Some(Err(err)),
+        };
+        self.fonts.borrow_mut().insert(path.to_owned(), data);
+        Some(Ok(data))
+    }
+}
+
 /// Evaluate an expression and return a Value as the result of this expression
 pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalContext) -> Value {
     if let Some(r) = &local_context.return_value {
@@ -169,7 +225,8 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
         }
         Expression::StructFieldAccess { base, name } => {
             if let Value::Struct(o) = eval_expression(base, local_context) {
-                o.get_field(name).cloned().unwrap_or(Value::Void)
+                // Structs from a model (e.g. a StructModelAdapter) may not have all the fields
+                o.get_field(name).cloned().unwrap_or_else(|| default_value_for_type(&expression.ty()))
             } else {
                 Value::Void
             }
@@ -284,7 +341,14 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
                             )
                         }).ok_or_else(Default::default)
                     } else {
-                        corelib::graphics::Image::load_from_path(path)
+                        let loaded_resources = match &local_context.component_instance {
+                            ComponentInstance::InstanceRef(c) => c.loaded_resources(),
+                            ComponentInstance::GlobalComponent(_) => None,
+                        };
+                        match loaded_resources.and_then(|r| r.load_image(path)) {
+                            Some(image) => image,
+                            None => corelib::graphics::Image::load_from_path(path),
+                        }
                     }
                 }
                 i_slint_compiler::expression_tree::ImageReference::EmbeddedData { .. } => {
@@ -360,6 +424,19 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
                 GradientStop{ color, position }
             }))))
         }
+        Expression::ConicGradient{from_angle, stops} => {
+            let from_angle = eval_expression(from_angle, local_context);
+            Value::Brush(Brush::ConicGradient(ConicGradientBrush::new(from_angle.try_into().unwrap(), stops.iter().map(|(color, stop)| {
+                let color = eval_expression(color, local_context).try_into().unwrap();
+                let position = eval_expression(stop, local_context).try_into().unwrap();
+                GradientStop{ color, position }
+            }))))
+        }
+        Expression::FourCornerGradient{colors} => {
+            let mut colors = colors.iter().map(|color| eval_expression(color, local_context).try_into().unwrap());
+            let mut next = || colors.next().unwrap_or_default();
+            Value::Brush(Brush::FourCornerGradient(FourCornerGradientBrush::new(next(), next(), next(), next())))
+        }
         Expression::EnumerationValue(value) => {
             Value::EnumerationValue(value.enumeration.name.to_string(), value.to_string())
         }
@@ -599,6 +676,17 @@ fn call_builtin_function(
                 generativity::make_guard!(guard);
                 let enclosing_component =
                     enclosing_component_for_element(&popup.parent_element, component, guard);
+                if popup.is_window {
+                    if let Err(err) = crate::dynamic_item_tree::show_secondary_window(
+                        popup_window.clone(),
+                        enclosing_component,
+                        popup,
+                        enclosing_component.self_weak().get().unwrap().clone(),
+                    ) {
+                        corelib::debug_log!("Error showing a secondary window: {err}");
+                    }
+                    return Value::Void;
+                }
                 let parent_item_info = &enclosing_component.description.items
                     [popup.parent_element.borrow().id.as_str()];
                 let parent_item_comp =
@@ -666,6 +754,13 @@ fn call_builtin_function(
                 generativity::make_guard!(guard);
                 let enclosing_component =
                     enclosing_component_for_element(&popup.parent_element, component, guard);
+                if popup.is_window {
+                    crate::dynamic_item_tree::close_secondary_window(
+                        popup_window,
+                        enclosing_component,
+                    );
+                    return Value::Void;
+                }
                 crate::dynamic_item_tree::close_popup(
                     popup_window,
                     enclosing_component,
@@ -1065,6 +1160,28 @@ fn call_builtin_function(
                 panic!("First argument not an image");
             }
         }
+        BuiltinFunction::ImageWithSvgFill
+        | BuiltinFunction::ImageWithSvgStroke
+        | BuiltinFunction::ImageWithSvgVariable => {
+            if arguments.len() != 3 {
+                panic!("internal error: incorrect argument count to {f:?}")
+            }
+            let Value::Image(img) = eval_expression(&arguments[0], local_context) else {
+                panic!("First argument not an image");
+            };
+            let Value::String(name) = eval_expression(&arguments[1], local_context) else {
+                panic!("Second argument not a string");
+            };
+            let Value::Brush(brush) = eval_expression(&arguments[2], local_context) else {
+                panic!("Third argument not a color");
+            };
+            let color = brush.color();
+            Value::Image(match f {
+                BuiltinFunction::ImageWithSvgFill => img.with_svg_fill(&name, color),
+                BuiltinFunction::ImageWithSvgStroke => img.with_svg_stroke(&name, color),
+                _ => img.with_svg_variable(&name, color),
+            })
+        }
         BuiltinFunction::ArrayLength => {
             if arguments.len() != 1 {
                 panic!("internal error: incorrect argument count to ArrayLength")
@@ -1118,24 +1235,28 @@ fn call_builtin_function(
                 panic!("Cannot get the window from a global component")
             }
         },
-        BuiltinFunction::SetupNativeMenuBar => {
+        BuiltinFunction::SetupNativeMenuBar | BuiltinFunction::SetupWindowMenu => {
             let ComponentInstance::InstanceRef(component) = local_context.component_instance else {
-                panic!("SetupNativeMenuBar from a global");
+                panic!("{f:?} from a global");
             };
             let [entries, Expression::CallbackReference(sub_menu, _), Expression::CallbackReference(activated, _)] =
                 arguments
             else {
-                panic!("internal error: incorrect arguments to SetupNativeMenuBar")
+                panic!("internal error: incorrect arguments to {f:?}")
             };
-            if let Some(w) = component.window_adapter().internal(i_slint_core::InternalToken) {
-                if w.supports_native_menu_bar() {
-                    w.setup_menubar(vtable::VBox::new(MenuWrapper {
-                        entries: entries.clone(),
-                        sub_menu: sub_menu.clone(),
-                        activated: activated.clone(),
-                        item_tree: component.self_weak().get().unwrap().clone(),
-                    }));
-                }
+            let menu = MenuWrapper {
+                entries: entries.clone(),
+                sub_menu: sub_menu.clone(),
+                activated: activated.clone(),
+                item_tree: component.self_weak().get().unwrap().clone(),
+            };
+            let window_adapter = component.window_adapter();
+            let window = corelib::window::WindowInner::from_pub(window_adapter.window());
+            let factory = move || vtable::VBox::new(menu.clone());
+            if matches!(f, BuiltinFunction::SetupNativeMenuBar) {
+                window.register_menubar(factory);
+            } else {
+                window.register_menu(factory);
             }
             Value::Void
         }
@@ -1150,6 +1271,9 @@ fn call_builtin_function(
 
             Value::Number(i_slint_core::date_time::month_offset(m, y) as f64)
         }
+        BuiltinFunction::FirstDayOfWeek => {
+            Value::Number(i_slint_core::date_time::first_day_of_week() as f64)
+        }
         BuiltinFunction::FormatDate => {
             let f: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
             let d: u32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
@@ -1169,6 +1293,111 @@ fn call_builtin_function(
             let f: SharedString = eval_expression(&arguments[1], local_context).try_into().unwrap();
             Value::Bool(i_slint_core::date_time::parse_date(d.as_str(), f.as_str()).is_some())
         }
+        BuiltinFunction::ParseHexColor => {
+            let text: SharedString =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let fallback: Color = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            Value::Brush(Brush::SolidColor(
+                i_slint_core::graphics::parse_hex_color(text.as_str()).unwrap_or(fallback),
+            ))
+        }
+        BuiltinFunction::ChartLineCommands => {
+            let values = chart_values(eval_expression(&arguments[0], local_context));
+            let min: f32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let max: f32 = eval_expression(&arguments[2], local_context).try_into().unwrap();
+            let w: f32 = eval_expression(&arguments[3], local_context).try_into().unwrap();
+            let h: f32 = eval_expression(&arguments[4], local_context).try_into().unwrap();
+            let area: bool = eval_expression(&arguments[5], local_context).try_into().unwrap();
+            Value::String(i_slint_core::charts::line_chart_commands(&values, min, max, w, h, area))
+        }
+        BuiltinFunction::ChartPieSliceCommands => {
+            let values = chart_values(eval_expression(&arguments[0], local_context));
+            let index: i32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            Value::String(
+                usize::try_from(index)
+                    .map(|index| i_slint_core::charts::pie_slice_commands(&values, index))
+                    .unwrap_or_default(),
+            )
+        }
+        BuiltinFunction::ChartPieSliceAt => {
+            let values = chart_values(eval_expression(&arguments[0], local_context));
+            let angle: f32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            Value::Number(i_slint_core::charts::pie_slice_at(&values, angle) as f64)
+        }
+        BuiltinFunction::PathInterpolate => {
+            let from: SharedString =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let to: SharedString =
+                eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let t: f32 = eval_expression(&arguments[2], local_context).try_into().unwrap();
+            Value::String(
+                i_slint_core::graphics::interpolate_path_commands(from.as_str(), to.as_str(), t)
+                    .unwrap_or_default(),
+            )
+        }
+        BuiltinFunction::PathUnion
+        | BuiltinFunction::PathIntersection
+        | BuiltinFunction::PathDifference => {
+            use i_slint_core::graphics::PathBooleanOperation;
+            let a: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let b: SharedString = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let operation = match f {
+                BuiltinFunction::PathUnion => PathBooleanOperation::Union,
+                BuiltinFunction::PathIntersection => PathBooleanOperation::Intersection,
+                _ => PathBooleanOperation::Difference,
+            };
+            Value::String(
+                i_slint_core::graphics::path_boolean_operation(a.as_str(), b.as_str(), operation)
+                    .unwrap_or_default(),
+            )
+        }
+        BuiltinFunction::FormatNumber => {
+            let value: f64 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let decimals: i32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let group: bool = eval_expression(&arguments[2], local_context).try_into().unwrap();
+            Value::String(i_slint_core::number_format::format_number(value, decimals, group))
+        }
+        BuiltinFunction::ValidNumber => {
+            let text: SharedString =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::Bool(i_slint_core::number_format::parse_number(text.as_str()).is_some())
+        }
+        BuiltinFunction::ParseNumber => {
+            let text: SharedString =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::Number(
+                i_slint_core::number_format::parse_number(text.as_str()).unwrap_or_default(),
+            )
+        }
+        BuiltinFunction::ClipboardSetText => {
+            let text: SharedString =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            i_slint_core::api::SystemClipboard::set_text(text.as_str());
+            Value::Void
+        }
+        BuiltinFunction::ClipboardText => {
+            Value::String(i_slint_core::api::SystemClipboard::text().unwrap_or_default())
+        }
+        BuiltinFunction::ClipboardSetImage => {
+            let image: i_slint_core::graphics::Image =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            i_slint_core::api::SystemClipboard::set_image(&image);
+            Value::Void
+        }
+        BuiltinFunction::ClipboardImage => {
+            Value::Image(i_slint_core::api::SystemClipboard::image().unwrap_or_default())
+        }
+        BuiltinFunction::ClipboardSetHtml => {
+            let html: SharedString =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let alt_text: SharedString =
+                eval_expression(&arguments[1], local_context).try_into().unwrap();
+            i_slint_core::api::SystemClipboard::set_html(html.as_str(), alt_text.as_str());
+            Value::Void
+        }
+        BuiltinFunction::ClipboardHtml => {
+            Value::String(i_slint_core::api::SystemClipboard::html().unwrap_or_default())
+        }
         BuiltinFunction::ParseDate => {
             let d: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
             let f: SharedString = eval_expression(&arguments[1], local_context).try_into().unwrap();
@@ -1204,6 +1433,24 @@ fn call_builtin_function(
                 panic!("Cannot get the window from a global component")
             }
         },
+        BuiltinFunction::WindowToasts => match local_context.component_instance {
+            ComponentInstance::InstanceRef(component) => Value::Model(ModelRc::new(
+                component.access_window(|window| window.toasts()).map(Value::from),
+            )),
+            ComponentInstance::GlobalComponent(_) => {
+                panic!("Cannot get the window from a global component")
+            }
+        },
+        BuiltinFunction::ActivateToast => match local_context.component_instance {
+            ComponentInstance::InstanceRef(component) => {
+                let id: i32 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+                component.access_window(|window| window.activate_toast(id));
+                Value::Void
+            }
+            ComponentInstance::GlobalComponent(_) => {
+                panic!("Cannot get the window from a global component")
+            }
+        },
         BuiltinFunction::ImplicitLayoutInfo(orient) => {
             let component = match local_context.component_instance {
                 ComponentInstance::InstanceRef(c) => c,
@@ -1274,12 +1521,16 @@ fn call_builtin_function(
                 }
             };
             if let Value::String(s) = eval_expression(&arguments[0], local_context) {
-                if let Some(err) = component
-                    .window_adapter()
-                    .renderer()
-                    .register_font_from_path(&std::path::PathBuf::from(s.as_str()))
-                    .err()
-                {
+                let path = std::path::Path::new(s.as_str());
+                let window_adapter = component.window_adapter();
+                let renderer = window_adapter.renderer();
+                let result = match component.loaded_resources().and_then(|r| r.load_font(path)) {
+                    Some(data) => data
+                        .map_err(Into::into)
+                        .and_then(|data| renderer.register_font_from_memory(data)),
+                    None => renderer.register_font_from_path(path),
+                };
+                if let Some(err) = result.err() {
                     corelib::debug_log!("Error loading custom font {}: {}", s.as_str(), err);
                 }
                 Value::Void
@@ -1328,6 +1579,14 @@ fn from_index(&self, index: usize) -> Option<SharedString> {
     }
 }
 
+/// The values of a `[float]` model, for the builtin functions of the chart widgets
+fn chart_values(model: Value) -> Vec<f32> {
+    match model {
+        Value::Model(model) => model.iter().map(|v| v.try_into().unwrap_or_default()).collect(),
+        _ => Vec::new(),
+    }
+}
+
 fn eval_assignment(lhs: &Expression, op: char, rhs: Value, local_context: &mut EvalLocalContext) {
     let eval = |lhs| match (lhs, &rhs, op) {
         (Value::String(ref mut a), Value::String(b), '+') => {
@@ -1390,7 +1649,8 @@ fn eval_assignment(lhs: &Expression, op: char, rhs: Value, local_context: &mut E
         }
         Expression::StructFieldAccess { base, name } => {
             if let Value::Struct(mut o) = eval_expression(base, local_context) {
-                let mut r = o.get_field(name).unwrap().clone();
+                let mut r =
+                    o.get_field(name).cloned().unwrap_or_else(|| default_value_for_type(&lhs.ty()));
                 r = if op == '=' { rhs } else { eval(std::mem::take(&mut r)) };
                 o.set_field(name.to_string(), r);
                 eval_assignment(base, '=', Value::Struct(o), local_context)
@@ -1562,6 +1822,11 @@ pub fn store_property(
 
 /// Return true if the Value can be used for a property of the given type
 fn check_value_type(value: &Value, ty: &Type) -> bool {
+    if matches!(value, Value::Opaque(_)) {
+        // Only the state of the elements registered with `Compiler::register_element` is opaque
+        return matches!(ty, Type::Struct(s)
+            if s.name.as_deref() == Some(crate::api::NATIVE_ELEMENT_STATE));
+    }
     match ty {
         Type::Void => true,
         Type::Invalid
@@ -1867,6 +2132,7 @@ pub fn default_value_for_type(ty: &Type) -> Value {
     }
 }
 
+#[derive(Clone)]
 pub struct MenuWrapper {
     entries: Expression,
     sub_menu: NamedReference,
@@ -8,6 +8,7 @@
 use crate::SetPropertyError;
 use core::cell::RefCell;
 use core::pin::Pin;
+use i_slint_compiler::diagnostics::Spanned;
 use i_slint_compiler::langtype::ElementType;
 use i_slint_compiler::namedreference::NamedReference;
 use i_slint_compiler::object_tree::{Component, Document, PropertyDeclaration};
@@ -23,10 +24,15 @@ pub struct CompiledGlobalCollection {
     /// Map of all exported global singletons and their index in the compiled_globals vector. The key
     /// is the normalized name of the global.
     pub exported_globals_by_name: BTreeMap<SmolStr, usize>,
+    /// Images and fonts loaded through the [`crate::ResourceLoader`] of the compiler
+    pub(crate) loaded_resources: Option<Rc<crate::eval::LoadedResources>>,
 }
 
 impl CompiledGlobalCollection {
-    pub fn compile(doc: &Document) -> Self {
+    pub fn compile(
+        doc: &Document,
+        native_globals: &BTreeMap<SmolStr, crate::api::NativeGlobal>,
+    ) -> Self {
         let mut exported_globals_by_name = BTreeMap::new();
         let compiled_globals = doc
             .used_types
@@ -37,6 +43,18 @@ pub fn compile(doc: &Document) -> Self {
             .map(|(index, component)| {
                 let mut global = generate(component);
 
+                if let Some(native) = native_globals.get(&component.id).filter(|_| {
+                    component.node.as_ref().and_then(|n| n.source_file()).is_some_and(|f| {
+                        f.path() == std::path::Path::new(crate::api::NATIVE_GLOBALS_PATH)
+                    })
+                }) {
+                    if let CompiledGlobal::Component { native_callbacks, .. } = &mut global {
+                        native_callbacks.extend(
+                            native.callbacks.iter().map(|c| (c.name.clone(), c.handler.clone())),
+                        );
+                    }
+                }
+
                 if !component.exported_global_names.borrow().is_empty() {
                     global.extend_public_properties(
                         component.root_element.borrow().property_declarations.clone(),
@@ -54,7 +72,7 @@ pub fn compile(doc: &Document) -> Self {
                 global
             })
             .collect();
-        Self { compiled_globals, exported_globals_by_name }
+        Self { compiled_globals, exported_globals_by_name, loaded_resources: None }
     }
 }
 
@@ -92,6 +110,8 @@ pub enum CompiledGlobal {
     Component {
         component: ErasedItemTreeDescription,
         public_properties: BTreeMap<SmolStr, PropertyDeclaration>,
+        /// Handlers registered with `Compiler::register_global`
+        native_callbacks: Vec<(SmolStr, Rc<dyn Fn(&[Value]) -> Value>)>,
     },
 }
 
@@ -195,7 +215,7 @@ fn instantiate(name: &str) -> Pin<Rc<dyn GlobalComponent>> {
                 element.native_class.class_name.as_ref(),
             )
         }
-        CompiledGlobal::Component { component, .. } => {
+        CompiledGlobal::Component { component, native_callbacks, .. } => {
             generativity::make_guard!(guard);
             let description = component.unerase(guard);
             let inst = crate::dynamic_item_tree::instantiate(
@@ -206,7 +226,15 @@ fn instantiate(name: &str) -> Pin<Rc<dyn GlobalComponent>> {
                 GlobalStorage::Weak(Rc::downgrade(&globals)),
             );
             inst.run_setup_code();
-            Rc::pin(GlobalComponentInstance(inst))
+            let instance: Pin<Rc<dyn GlobalComponent>> = Rc::pin(GlobalComponentInstance(inst));
+            for (name, handler) in native_callbacks {
+                let handler = handler.clone();
+                // Fails if the callback isn't used and was optimized away
+                let _ = instance
+                    .as_ref()
+                    .set_callback_handler(name, Box::new(move |args| handler(args)));
+            }
+            instance
         }
     };
 
@@ -353,6 +381,7 @@ fn generate(component: &Rc<Component>) -> CompiledGlobal {
                 )
                 .into(),
                 public_properties: Default::default(),
+                native_callbacks: Vec::new(),
             }
         }
         ElementType::Builtin(b) => CompiledGlobal::Builtin {
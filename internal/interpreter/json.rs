@@ -0,0 +1,404 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Conversion between [`Value`] and [`serde_json::Value`]
+
+use crate::api::{ComponentDefinition, Struct, Value};
+use i_slint_compiler::langtype::Type as LangType;
+use i_slint_core::graphics::{
+    Brush, Color, ConicGradientBrush, FourCornerGradientBrush, GradientStop, Image,
+    LinearGradientBrush, RadialGradientBrush,
+};
+use i_slint_core::model::{Model, ModelRc, VecModel};
+use i_slint_core::SharedString;
+
+/// Error returned by [`ComponentDefinition::property_from_json`] and [`Value::to_json`]
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Error, derive_more::Display)]
+#[non_exhaustive]
+pub enum JsonError {
+    /// The JSON value doesn't have the shape required by the Slint type
+    #[display("expected {expected}, found {found}")]
+    TypeMismatch {
+        /// Description of what was expected
+        expected: String,
+        /// The JSON value that was found instead
+        found: String,
+    },
+    /// The component has no public property with that name
+    #[display("no public property `{_0}`")]
+    NoSuchProperty(#[error(not(source))] String),
+    /// A string doesn't name a value of the Slint enumeration
+    #[display("`{value}` is not a value of enum {enumeration}")]
+    UnknownEnumValue {
+        /// The name of the enumeration
+        enumeration: String,
+        /// The string found in the JSON
+        value: String,
+    },
+    /// An image could not be loaded from the path in the JSON
+    #[display("cannot load image `{_0}`")]
+    ImageLoad(#[error(not(source))] String),
+    /// The Slint type or value cannot be represented in JSON
+    #[display("cannot convert {_0} to or from JSON")]
+    Unsupported(#[error(not(source))] String),
+}
+
+/// The result of [`ComponentDefinition::property_from_json`]
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ValueFromJson {
+    /// The converted value
+    pub value: Value,
+    /// The members of JSON objects that don't exist in the Slint struct, and were ignored.
+    /// They are written as a path from the property, such as `rows[1].title`.
+    pub unknown_fields: Vec<String>,
+}
+
+impl ComponentDefinition {
+    /// Convert a JSON value to a [`Value`] of the type of the public property `name`.
+    ///
+    /// The mapping is the following:
+    ///  - numbers, strings and booleans map to the corresponding JSON type.
+    ///    Units (`length`, `duration`, ...) are expressed as plain numbers in their default unit.
+    ///  - arrays map to JSON arrays, and the resulting value holds a [`VecModel`].
+    ///  - structs map to JSON objects. Missing fields get their default value, and unknown fields
+    ///    are ignored and listed in [`ValueFromJson::unknown_fields`].
+    ///  - enums map to the string of the value name, such as `"align-center"`.
+    ///  - colors map to a string such as `"#ff0000"` or `"#ff000080"`.
+    ///  - brushes are either a color string, or an object of the form
+    ///    `{ "linear-gradient": { "angle": 90, "stops": [{ "color": "#fff", "position": 0 }, ...] } }`
+    ///    or `{ "radial-gradient": { "stops": [...] } }`.
+    ///  - images map to a string containing the path of the image.
+    pub fn property_from_json(
+        &self,
+        name: &str,
+        json: &serde_json::Value,
+    ) -> Result<ValueFromJson, JsonError> {
+        let name = crate::api::normalize_identifier(name);
+        let ty =
+            self.property_type(&name).ok_or_else(|| JsonError::NoSuchProperty(name.to_string()))?;
+        let mut unknown_fields = Vec::new();
+        let value = value_from_json(&ty, json, &mut name.to_string(), &mut unknown_fields)?;
+        Ok(ValueFromJson { value, unknown_fields })
+    }
+}
+
+/// Convert `json` to a value of type `ty`. `path` is the path of the value from the property,
+/// used to report the unknown struct fields in `unknown_fields`.
+fn value_from_json(
+    ty: &LangType,
+    json: &serde_json::Value,
+    path: &mut String,
+    unknown_fields: &mut Vec<String>,
+) -> Result<Value, JsonError> {
+    let mismatch = |expected: &str| JsonError::TypeMismatch {
+        expected: expected.into(),
+        found: json.to_string(),
+    };
+    match ty {
+        LangType::Int32 => json
+            .as_f64()
+            .filter(|n| n.fract() == 0. && (i32::MIN as f64..=i32::MAX as f64).contains(n))
+            .map(Value::Number)
+            .ok_or_else(|| mismatch("an integer")),
+        LangType::Float32
+        | LangType::Duration
+        | LangType::PhysicalLength
+        | LangType::LogicalLength
+        | LangType::Rem
+        | LangType::Angle
+        | LangType::Percent
+        | LangType::UnitProduct(_) => {
+            json.as_f64().map(Value::Number).ok_or_else(|| mismatch("a number"))
+        }
+        LangType::String => json
+            .as_str()
+            .map(|s| Value::String(SharedString::from(s)))
+            .ok_or_else(|| mismatch("a string")),
+        LangType::Bool => json.as_bool().map(Value::Bool).ok_or_else(|| mismatch("a boolean")),
+        LangType::Color => color_from_json(json).map(|c| Value::Brush(Brush::SolidColor(c))),
+        LangType::Brush => brush_from_json(json).map(Value::Brush),
+        LangType::Image => {
+            let path = json.as_str().ok_or_else(|| mismatch("an image path"))?;
+            Image::load_from_path(std::path::Path::new(path))
+                .map(Value::Image)
+                .map_err(|_| JsonError::ImageLoad(path.into()))
+        }
+        LangType::Array(ty) => {
+            let array = json.as_array().ok_or_else(|| mismatch("an array"))?;
+            let len = path.len();
+            let values = array
+                .iter()
+                .enumerate()
+                .map(|(i, v)| {
+                    path.truncate(len);
+                    path.push_str(&format!("[{i}]"));
+                    value_from_json(ty, v, path, unknown_fields)
+                })
+                .collect::<Result<Vec<_>, _>>();
+            path.truncate(len);
+            Ok(Value::Model(ModelRc::new(VecModel::from(values?))))
+        }
+        LangType::Struct(s) => {
+            let obj = json.as_object().ok_or_else(|| mismatch("an object"))?;
+            unknown_fields.extend(
+                obj.keys()
+                    .filter(|k| {
+                        !s.fields.contains_key(crate::api::normalize_identifier(k).as_ref())
+                    })
+                    .map(|k| format!("{path}.{k}")),
+            );
+            let len = path.len();
+            let mut result = Struct::default();
+            for (name, ty) in &s.fields {
+                let value =
+                    match obj.get(name.as_str()).or_else(|| obj.get(&name.replace('-', "_"))) {
+                        Some(v) => {
+                            path.truncate(len);
+                            path.push('.');
+                            path.push_str(name);
+                            value_from_json(ty, v, path, unknown_fields)
+                        }
+                        None => Ok(crate::eval::default_value_for_type(ty)),
+                    };
+                path.truncate(len);
+                result.set_field(name.to_string(), value?);
+            }
+            Ok(Value::Struct(result))
+        }
+        LangType::Enumeration(e) => {
+            let value = json.as_str().ok_or_else(|| mismatch("an enum value string"))?;
+            let normalized = crate::api::normalize_identifier_smolstr(value);
+            if e.values.contains(&normalized) {
+                Ok(Value::EnumerationValue(e.name.to_string(), normalized.to_string()))
+            } else {
+                Err(JsonError::UnknownEnumValue {
+                    enumeration: e.name.to_string(),
+                    value: value.into(),
+                })
+            }
+        }
+        _ => Err(JsonError::Unsupported(ty.to_string())),
+    }
+}
+
+impl Value {
+    /// Convert this value to JSON.
+    ///
+    /// This is the inverse of [`ComponentDefinition::property_from_json`]. Images are only supported if they
+    /// were loaded from a path.
+    pub fn to_json(&self) -> Result<serde_json::Value, JsonError> {
+        Ok(match self {
+            Value::Void => serde_json::Value::Null,
+            Value::Number(n) => serde_json::Number::from_f64(*n)
+                .map(serde_json::Value::Number)
+                .ok_or_else(|| JsonError::Unsupported(n.to_string()))?,
+            Value::String(s) => s.as_str().into(),
+            Value::Bool(b) => (*b).into(),
+            Value::Image(image) => image
+                .path()
+                .and_then(|p| p.to_str())
+                .ok_or_else(|| JsonError::Unsupported("image not loaded from a path".into()))?
+                .into(),
+            Value::Model(model) => {
+                model.iter().map(|v| v.to_json()).collect::<Result<Vec<_>, _>>()?.into()
+            }
+            Value::Struct(s) => s
+                .iter()
+                .map(|(k, v)| Ok((k.to_string(), v.to_json()?)))
+                .collect::<Result<serde_json::Map<_, _>, _>>()?
+                .into(),
+            Value::Brush(brush) => brush_to_json(brush)?,
+            Value::EnumerationValue(_, value) => value.as_str().into(),
+            _ => return Err(JsonError::Unsupported(format!("{self:?}"))),
+        })
+    }
+}
+
+fn color_from_json(json: &serde_json::Value) -> Result<Color, JsonError> {
+    json.as_str()
+        .and_then(i_slint_compiler::literals::parse_color_literal)
+        .map(Color::from_argb_encoded)
+        .ok_or_else(|| JsonError::TypeMismatch {
+            expected: "a color such as \"#rrggbb\"".into(),
+            found: json.to_string(),
+        })
+}
+
+fn color_to_json(color: Color) -> serde_json::Value {
+    let c = color.to_argb_u8();
+    if c.alpha == 0xff {
+        format!("#{:02x}{:02x}{:02x}", c.red, c.green, c.blue).into()
+    } else {
+        format!("#{:02x}{:02x}{:02x}{:02x}", c.red, c.green, c.blue, c.alpha).into()
+    }
+}
+
+fn brush_from_json(json: &serde_json::Value) -> Result<Brush, JsonError> {
+    let gradient = json.as_object().and_then(|obj| match obj.len() {
+        1 => obj.iter().next(),
+        _ => None,
+    });
+    let Some((kind, gradient)) = gradient else {
+        return color_from_json(json).map(Brush::SolidColor);
+    };
+    let mismatch = |expected: &str, found: &serde_json::Value| JsonError::TypeMismatch {
+        expected: expected.into(),
+        found: found.to_string(),
+    };
+    if kind == "four-corner-gradient" {
+        let colors = gradient
+            .get("colors")
+            .and_then(|c| c.as_array())
+            .filter(|c| c.len() == 4)
+            .ok_or_else(|| {
+                mismatch("a four-corner-gradient with an array of four colors", gradient)
+            })?
+            .iter()
+            .map(color_from_json)
+            .collect::<Result<Vec<_>, JsonError>>()?;
+        return Ok(Brush::FourCornerGradient(FourCornerGradientBrush::new(
+            colors[0], colors[1], colors[2], colors[3],
+        )));
+    }
+    let stops = gradient
+        .get("stops")
+        .and_then(|s| s.as_array())
+        .ok_or_else(|| mismatch("a gradient with an array of stops", gradient))?
+        .iter()
+        .map(|stop| {
+            Ok(GradientStop {
+                color: color_from_json(stop.get("color").unwrap_or(&serde_json::Value::Null))?,
+                position: stop
+                    .get("position")
+                    .and_then(|p| p.as_f64())
+                    .ok_or_else(|| mismatch("a gradient stop with a position", stop))?
+                    as f32,
+            })
+        })
+        .collect::<Result<Vec<_>, JsonError>>()?;
+    match kind.as_str() {
+        "linear-gradient" => {
+            let angle = gradient.get("angle").and_then(|a| a.as_f64()).unwrap_or_default();
+            Ok(Brush::LinearGradient(LinearGradientBrush::new(angle as f32, stops)))
+        }
+        "radial-gradient" => Ok(Brush::RadialGradient(RadialGradientBrush::new_circle(stops))),
+        "conic-gradient" => {
+            let from_angle =
+                gradient.get("from-angle").and_then(|a| a.as_f64()).unwrap_or_default();
+            Ok(Brush::ConicGradient(ConicGradientBrush::new(from_angle as f32, stops)))
+        }
+        _ => Err(mismatch(
+            "a color, linear-gradient, radial-gradient, conic-gradient or four-corner-gradient",
+            json,
+        )),
+    }
+}
+
+fn brush_to_json(brush: &Brush) -> Result<serde_json::Value, JsonError> {
+    let stops = |stops: &mut dyn Iterator<Item = &GradientStop>| -> serde_json::Value {
+        stops
+            .map(|s| serde_json::json!({ "color": color_to_json(s.color), "position": s.position }))
+            .collect::<Vec<_>>()
+            .into()
+    };
+    Ok(match brush {
+        Brush::SolidColor(c) => color_to_json(*c),
+        Brush::LinearGradient(g) => serde_json::json!({
+            "linear-gradient": { "angle": g.angle(), "stops": stops(&mut g.stops()) }
+        }),
+        Brush::RadialGradient(g) => {
+            serde_json::json!({ "radial-gradient": { "stops": stops(&mut g.stops()) } })
+        }
+        Brush::ConicGradient(g) => serde_json::json!({
+            "conic-gradient": { "from-angle": g.from_angle(), "stops": stops(&mut g.stops()) }
+        }),
+        Brush::FourCornerGradient(g) => serde_json::json!({
+            "four-corner-gradient": { "colors": g.colors().map(color_to_json).collect::<Vec<_>>() }
+        }),
+        _ => return Err(JsonError::Unsupported(format!("{brush:?}"))),
+    })
+}
+
+#[test]
+fn json_round_trip() {
+    i_slint_backend_testing::init_no_event_loop();
+    let result = spin_on::spin_on(crate::Compiler::default().build_from_source(
+        r#"
+    enum Alignment { start, align-center }
+    struct Row { title: string, count: int, done: bool, alignment: Alignment, color: color, background: brush }
+    export component Main {
+        in property <[Row]> rows;
+        in property <Row> item;
+        in property <int> number;
+        in property <color> tint;
+        callback clicked();
+    }"#
+        .into(),
+        "".into(),
+    ));
+    assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+    let definition = result.component("Main").unwrap();
+
+    let json = serde_json::json!([
+        {
+            "title": "first",
+            "count": 3.0,
+            "done": true,
+            "alignment": "align_center",
+            "color": "#ff000080",
+            "background": { "linear-gradient": { "angle": 90.0, "stops": [
+                { "color": "#0000ff", "position": 0.0 },
+                { "color": "#00ff00", "position": 1.0 },
+            ] } },
+        },
+        { "title": "second", "nope": 1 },
+    ]);
+    let result = definition.property_from_json("rows", &json).unwrap();
+    assert_eq!(result.unknown_fields, ["rows[1].nope"]);
+    let Value::Model(model) = &result.value else { panic!("not a model") };
+    assert_eq!(model.row_count(), 2);
+    let Value::Struct(second) = model.row_data(1).unwrap() else { panic!("not a struct") };
+    assert_eq!(second.get_field("count"), Some(&Value::Number(0.)));
+    assert_eq!(
+        second.get_field("alignment"),
+        Some(&Value::EnumerationValue("Alignment".into(), "start".into()))
+    );
+
+    let mut expected = json.clone();
+    expected[0]["alignment"] = "align-center".into();
+    expected[1] = serde_json::json!({
+        "title": "second", "count": 0.0, "done": false, "alignment": "start",
+        "color": "#00000000", "background": "#00000000",
+    });
+    assert_eq!(result.value.to_json().unwrap(), expected);
+
+    assert_eq!(
+        definition.property_from_json("item", &serde_json::json!({ "alignment": "end" })),
+        Err(JsonError::UnknownEnumValue { enumeration: "Alignment".into(), value: "end".into() })
+    );
+    assert_eq!(
+        definition.property_from_json("nope", &serde_json::json!(1)),
+        Err(JsonError::NoSuchProperty("nope".into()))
+    );
+    assert_eq!(
+        definition.property_from_json("clicked", &serde_json::json!(null)),
+        Err(JsonError::NoSuchProperty("clicked".into()))
+    );
+    assert!(matches!(
+        definition.property_from_json("number", &serde_json::json!("12")),
+        Err(JsonError::TypeMismatch { .. })
+    ));
+    assert!(matches!(
+        definition.property_from_json("number", &serde_json::json!(1.5)),
+        Err(JsonError::TypeMismatch { .. })
+    ));
+    assert!(matches!(
+        definition.property_from_json("number", &serde_json::json!(1e10)),
+        Err(JsonError::TypeMismatch { .. })
+    ));
+    assert!(matches!(
+        definition.property_from_json("tint", &serde_json::json!("red")),
+        Err(JsonError::TypeMismatch { .. })
+    ));
+}
@@ -86,10 +86,15 @@
 mod global_component;
 #[cfg(feature = "highlight")]
 pub mod highlight;
+#[cfg(feature = "json")]
+mod json;
 mod value_model;
 
 #[doc(inline)]
 pub use api::*;
+#[cfg(feature = "json")]
+pub use json::{JsonError, ValueFromJson};
+pub use value_model::StructModelAdapter;
 
 #[cfg(feature = "internal")]
 #[doc(hidden)]
@@ -1,8 +1,9 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-use crate::api::Value;
+use crate::api::{Struct, Value};
 use i_slint_core::model::{Model, ModelTracker};
+use smol_str::SmolStr;
 
 pub struct ValueModel {
     value: Value,
@@ -81,3 +82,137 @@ fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 }
+
+struct StructField<T> {
+    name: SmolStr,
+    get: Box<dyn Fn(&T) -> Value>,
+    set: Box<dyn Fn(&mut T, Value) -> Result<(), ()>>,
+}
+
+/// A model of [`Value::Struct`] that wraps a model of Rust structs, so that it can be used for
+/// a property whose type is an array of structs.
+///
+/// Each field of the .slint struct is mapped to the Rust struct with [`Self::field`].
+/// When the .slint code changes a row, for example by assigning a field of the model data in a
+/// `for` loop, the fields are written back to the Rust struct, and the row of the wrapped model is
+/// set with [`Model::set_row_data`].
+/// Fields of the .slint struct that are not mapped read as the default value of their type, and
+/// assigning them has no effect on the Rust struct.
+///
+/// ```
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use slint_interpreter::{Compiler, ComponentHandle, SharedString, StructModelAdapter, Value};
+/// use i_slint_core::model::{Model, VecModel};
+/// use std::rc::Rc;
+///
+/// #[derive(Clone)]
+/// struct Task {
+///     title: String,
+///     done: bool,
+/// }
+///
+/// let code = r#"
+///     export struct Task { title: string, done: bool }
+///     export component Tasks {
+///         in-out property <[Task]> tasks;
+///         public function complete(index: int) { tasks[index].done = true; }
+///     }
+/// "#;
+/// let result = spin_on::spin_on(Compiler::default().build_from_source(code.into(), Default::default()));
+/// let instance = result.component("Tasks").unwrap().create().unwrap();
+///
+/// let tasks = Rc::new(VecModel::from(vec![Task { title: "Write docs".into(), done: false }]));
+/// let adapter = StructModelAdapter::new(tasks.clone())
+///     .field("title", |t: &Task| SharedString::from(&t.title), |t, v: SharedString| t.title = v.into())
+///     .field("done", |t: &Task| t.done, |t, v| t.done = v);
+/// instance.set_property("tasks", adapter.into()).unwrap();
+///
+/// instance.invoke("complete", &[Value::Number(0.)]).unwrap();
+/// assert!(tasks.row_data(0).unwrap().done);
+/// ```
+pub struct StructModelAdapter<M: Model> {
+    model: M,
+    fields: Vec<StructField<M::Data>>,
+}
+
+impl<M: Model + 'static> StructModelAdapter<M> {
+    /// Creates an adapter for the given model, without any field.
+    pub fn new(model: M) -> Self {
+        Self { model, fields: Vec::new() }
+    }
+
+    /// Map the field `name` of the .slint struct to the Rust struct.
+    ///
+    /// `get` returns the value of the field from the Rust struct, and `set` writes a new value
+    /// into the Rust struct.
+    pub fn field<V: Into<Value> + TryFrom<Value> + 'static>(
+        mut self,
+        name: &str,
+        get: impl Fn(&M::Data) -> V + 'static,
+        set: impl Fn(&mut M::Data, V) + 'static,
+    ) -> Self {
+        self.fields.push(StructField {
+            name: crate::normalize_identifier_smolstr(name),
+            get: Box::new(move |data| get(data).into()),
+            set: Box::new(move |data, value| {
+                let value = V::try_from(value).map_err(|_| ())?;
+                set(data, value);
+                Ok(())
+            }),
+        });
+        self
+    }
+
+    /// Returns a reference to the wrapped model
+    pub fn source_model(&self) -> &M {
+        &self.model
+    }
+}
+
+impl<M: Model + 'static> Model for StructModelAdapter<M> {
+    type Data = Value;
+
+    fn row_count(&self) -> usize {
+        self.model.row_count()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        let data = self.model.row_data(row)?;
+        Some(Value::Struct(
+            self.fields.iter().map(|f| (f.name.to_string(), (f.get)(&data))).collect::<Struct>(),
+        ))
+    }
+
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        let Value::Struct(data) = data else {
+            eprintln!("Trying to set a row of a struct model to a value that is not a struct");
+            return;
+        };
+        let Some(mut row_data) = self.model.row_data(row) else { return };
+        for field in &self.fields {
+            if let Some(value) = data.get_field(&field.name) {
+                if (field.set)(&mut row_data, value.clone()).is_err() {
+                    eprintln!(
+                        "Invalid value {:?} for field {} of the struct model",
+                        value, field.name
+                    );
+                }
+            }
+        }
+        self.model.set_row_data(row, row_data);
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        self.model.model_tracker()
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+impl<M: Model + 'static> From<StructModelAdapter<M>> for Value {
+    fn from(adapter: StructModelAdapter<M>) -> Self {
+        Value::Model(i_slint_core::model::ModelRc::new(adapter))
+    }
+}
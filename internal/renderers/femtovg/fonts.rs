@@ -311,12 +311,16 @@ pub fn font(
         //);
 
         let fallbacks = if !matches!(coverage_result, GlyphCoverageCheckResult::Complete) {
-            self.font_fallbacks_for_request(
+            // The fallback chain configured by the application comes before the one of the system
+            let mut fallbacks = font_request.fallback_families();
+            fallbacks.retain(|family| self.is_known_family(family));
+            fallbacks.extend(self.font_fallbacks_for_request(
                 font_request.family.as_ref(),
                 pixel_size,
                 &primary_font,
                 reference_text,
-            )
+            ));
+            fallbacks
         } else {
             Vec::new()
         };
@@ -13,14 +13,20 @@
 use i_slint_core::{items::ImageRendering, ImageInner};
 
 use super::itemrenderer::CanvasRc;
+use super::texture_atlas::{AtlasRegion, TextureAtlas};
 
 pub struct Texture {
     pub id: femtovg::ImageId,
     canvas: CanvasRc,
+    /// Set if the texture is a part of a shared atlas page, in which case `id` refers to the page.
+    atlas_region: Option<AtlasRegion>,
 }
 
 impl Texture {
     pub fn size(&self) -> Option<IntSize> {
+        if let Some(region) = &self.atlas_region {
+            return Some(region.rect.size.cast());
+        }
         self.canvas
             .borrow()
             .image_info(self.id)
@@ -33,7 +39,7 @@ pub fn as_render_target(&self) -> femtovg::RenderTarget {
     }
 
     pub fn adopt(canvas: &CanvasRc, image_id: femtovg::ImageId) -> Rc<Texture> {
-        Texture { id: image_id, canvas: canvas.clone() }.into()
+        Texture { id: image_id, canvas: canvas.clone(), atlas_region: None }.into()
     }
 
     pub fn new_empty_on_gpu(canvas: &CanvasRc, width: u32, height: u32) -> Option<Rc<Texture>> {
@@ -49,7 +55,7 @@ pub fn new_empty_on_gpu(canvas: &CanvasRc, width: u32, height: u32) -> Option<Rc
                 femtovg::ImageFlags::PREMULTIPLIED | femtovg::ImageFlags::FLIP_Y,
             )
             .unwrap();
-        Some(Self { canvas: canvas.clone(), id: image_id }.into())
+        Some(Self { canvas: canvas.clone(), id: image_id, atlas_region: None }.into())
     }
 
     pub(crate) fn filter(&self, filter: femtovg::ImageFilter) -> Rc<Self> {
@@ -71,26 +77,49 @@ pub fn as_paint_with_alpha(&self, alpha_tint: f32) -> femtovg::Paint {
         let size = self
             .size()
             .expect("internal error: CachedImage::as_paint() called on zero-sized texture");
-        femtovg::Paint::image(
-            self.id,
-            0.,
-            0.,
-            size.width as f32,
-            size.height as f32,
-            0.,
-            alpha_tint,
-        )
+        self.image_paint(0., 0., size.width as f32, size.height as f32, alpha_tint)
+    }
+
+    /// Returns an image paint that maps the texture to the rectangle at (x, y) with the given size.
+    /// Use this instead of `femtovg::Paint::image` with `id`, to account for textures in an atlas page.
+    pub fn image_paint(
+        &self,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        alpha_tint: f32,
+    ) -> femtovg::Paint {
+        match &self.atlas_region {
+            None => femtovg::Paint::image(self.id, x, y, width, height, 0., alpha_tint),
+            Some(region) => {
+                let scale_x = width / region.rect.width() as f32;
+                let scale_y = height / region.rect.height() as f32;
+                let (page_width, page_height) = region.page.size();
+                femtovg::Paint::image(
+                    self.id,
+                    x - region.rect.origin.x as f32 * scale_x,
+                    y - region.rect.origin.y as f32 * scale_y,
+                    page_width * scale_x,
+                    page_height * scale_y,
+                    0.,
+                    alpha_tint,
+                )
+            }
+        }
     }
 
     // Upload the image to the GPU. This function could take just a canvas as parameter,
     // but since an upload requires a current context, this is "enforced" by taking
     // a renderer instead (which implies a current context).
+    // Small images that aren't tiled are placed into the atlas, if provided.
     pub fn new_from_image(
         image: &ImageInner,
         canvas: &CanvasRc,
         target_size_for_scalable_source: Option<euclid::Size2D<u32, PhysicalPx>>,
         scaling: ImageRendering,
         tiling: (ImageTiling, ImageTiling),
+        atlas: Option<&mut TextureAtlas>,
     ) -> Option<Rc<Self>> {
         let image_flags = base_image_flags(scaling, tiling);
 
@@ -147,6 +176,18 @@ pub fn new_from_image(
             }
             _ => {
                 let buffer = image.render_to_buffer(target_size_for_scalable_source)?;
+                if let Some(atlas) = atlas.filter(|_| tiling == Default::default()) {
+                    if let Some(region) = atlas.allocate(canvas, &buffer, image_flags) {
+                        return Some(
+                            Self {
+                                id: region.page.id,
+                                canvas: canvas.clone(),
+                                atlas_region: Some(region),
+                            }
+                            .into(),
+                        );
+                    }
+                }
                 let (image_source, flags) = image_buffer_to_image_source(&buffer);
                 canvas.borrow_mut().create_image(image_source, image_flags | flags).unwrap()
             }
@@ -158,7 +199,10 @@ pub fn new_from_image(
 
 impl Drop for Texture {
     fn drop(&mut self) {
-        self.canvas.borrow_mut().delete_image(self.id);
+        // Atlas pages are deleted when the last region is dropped.
+        if self.atlas_region.is_none() {
+            self.canvas.borrow_mut().delete_image(self.id);
+        }
     }
 }
 
@@ -189,7 +233,10 @@ pub fn new(
 // Cache used to avoid repeatedly decoding images from disk. Entries with a count
 // of 1 are drained after flushing the renderer commands to the screen.
 #[derive(Default)]
-pub struct TextureCache(HashMap<TextureCacheKey, Rc<Texture>>);
+pub struct TextureCache {
+    textures: HashMap<TextureCacheKey, Rc<Texture>>,
+    atlas: TextureAtlas,
+}
 
 impl TextureCache {
     // Look up the given image cache key in the image cache and upgrade the weak reference to a strong one if found,
@@ -197,14 +244,14 @@ impl TextureCache {
     pub(crate) fn lookup_image_in_cache_or_create(
         &mut self,
         cache_key: TextureCacheKey,
-        image_create_fn: impl Fn() -> Option<Rc<Texture>>,
+        image_create_fn: impl Fn(&mut TextureAtlas) -> Option<Rc<Texture>>,
     ) -> Option<Rc<Texture>> {
-        Some(match self.0.entry(cache_key) {
+        Some(match self.textures.entry(cache_key) {
             std::collections::hash_map::Entry::Occupied(existing_entry) => {
                 existing_entry.get().clone()
             }
             std::collections::hash_map::Entry::Vacant(vacant_entry) => {
-                let new_image = image_create_fn()?;
+                let new_image = image_create_fn(&mut self.atlas)?;
                 vacant_entry.insert(new_image.clone());
                 new_image
             }
@@ -212,7 +259,7 @@ pub(crate) fn lookup_image_in_cache_or_create(
     }
 
     pub(crate) fn drain(&mut self) {
-        self.0.retain(|_, cached_image| {
+        self.textures.retain(|_, cached_image| {
             // * Retain images that are used by elements, so that they can be effectively
             // shared (one image element refers to foo.png, another element is created
             // and refers to the same -> share).
@@ -223,10 +270,12 @@ pub(crate) fn drain(&mut self) {
             // image again, etc.
             Rc::strong_count(cached_image) > 1 || cached_image.size().is_none()
         });
+        self.atlas.drain();
     }
 
     pub(crate) fn clear(&mut self) {
-        self.0.clear();
+        self.textures.clear();
+        self.atlas.clear();
     }
 }
 
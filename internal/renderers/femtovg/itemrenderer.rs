@@ -15,8 +15,8 @@
     CachedRenderingData, ItemCache, ItemRenderer, RenderBorderRectangle, RenderImage, RenderText,
 };
 use i_slint_core::items::{
-    self, Clip, FillRule, ImageRendering, ImageTiling, ItemRc, Layer, Opacity, RenderingResult,
-    TextStrokeStyle,
+    self, BackdropBlur, Clip, FillRule, ImageRendering, ImageTiling, ItemRc, Layer, Opacity,
+    RenderingResult, TextStrokeStyle,
 };
 use i_slint_core::lengths::{
     LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector,
@@ -351,6 +351,26 @@ fn draw_text(
             None => None,
         };
 
+        let draw_text_run = |canvas: &mut Canvas, x: f32, y: f32, to_draw: &str| {
+            match (stroke_style, &stroke_paint) {
+                (TextStrokeStyle::Outside, Some(stroke_paint)) => {
+                    canvas.stroke_text(x, y, to_draw, stroke_paint).unwrap();
+                    canvas.fill_text(x, y, to_draw, &paint).unwrap();
+                }
+                (TextStrokeStyle::Center, Some(stroke_paint)) => {
+                    canvas.fill_text(x, y, to_draw, &paint).unwrap();
+                    canvas.stroke_text(x, y, to_draw, stroke_paint).unwrap();
+                }
+                _ => {
+                    canvas.fill_text(x, y, to_draw, &paint).unwrap();
+                }
+            };
+        };
+
+        let has_glyph_transform = text.has_glyph_transform();
+        let scale_factor = self.scale_factor;
+        let font_height = font.height().get();
+
         let mut canvas = self.canvas.borrow_mut();
         fonts::layout_text_lines(
             string,
@@ -362,20 +382,36 @@ fn draw_text(
             false,
             None,
             &paint,
-            |to_draw, pos, _, _| {
-                match (stroke_style, &stroke_paint) {
-                    (TextStrokeStyle::Outside, Some(stroke_paint)) => {
-                        canvas.stroke_text(pos.x, pos.y, to_draw.trim_end(), stroke_paint).unwrap();
-                        canvas.fill_text(pos.x, pos.y, to_draw.trim_end(), &paint).unwrap();
-                    }
-                    (TextStrokeStyle::Center, Some(stroke_paint)) => {
-                        canvas.fill_text(pos.x, pos.y, to_draw.trim_end(), &paint).unwrap();
-                        canvas.stroke_text(pos.x, pos.y, to_draw.trim_end(), stroke_paint).unwrap();
-                    }
-                    _ => {
-                        canvas.fill_text(pos.x, pos.y, to_draw.trim_end(), &paint).unwrap();
+            |to_draw, pos, start, _| {
+                let to_draw = to_draw.trim_end();
+                if !has_glyph_transform {
+                    draw_text_run(&mut canvas, pos.x, pos.y, to_draw);
+                    return;
+                }
+                // Draw each character on its own, rotated around its center and moved by the
+                // transform returned by the callback.
+                let first_index = string[..start].chars().count();
+                for (index, (byte_offset, ch)) in to_draw.char_indices().enumerate() {
+                    if ch.is_whitespace() {
+                        continue;
                     }
-                };
+                    let glyph = &to_draw[byte_offset..byte_offset + ch.len_utf8()];
+                    let x = pos.x
+                        + canvas
+                            .measure_text(0., 0., &to_draw[..byte_offset], &paint)
+                            .unwrap()
+                            .width();
+                    let width = canvas.measure_text(0., 0., glyph, &paint).unwrap().width();
+                    let transform = text.glyph_transform(first_index + index);
+                    canvas.save();
+                    canvas.translate(
+                        x + width / 2. + transform.x * scale_factor.get(),
+                        pos.y + font_height / 2. + transform.y * scale_factor.get(),
+                    );
+                    canvas.rotate(transform.rotation.to_radians());
+                    draw_text_run(&mut canvas, -width / 2., -font_height / 2., glyph);
+                    canvas.restore();
+                }
             },
         );
     }
@@ -429,6 +465,7 @@ fn draw_text_input(
 
         let mut canvas = self.canvas.borrow_mut();
         let font_height = font.height();
+        let highlights = visual_representation.highlights;
         let text: SharedString = visual_representation.text.into();
 
         let cursor_point = fonts::layout_text_lines(
@@ -443,7 +480,82 @@ fn draw_text_input(
             &paint,
             |to_draw: &str, pos: PhysicalPoint, start, metrics: &femtovg::TextMetrics| {
                 let range = start..(start + to_draw.len());
-                if min_select != max_select
+                if highlights.iter().any(|h| h.range.start < range.end && h.range.end > range.start)
+                {
+                    // Split the line into runs that have the same highlight and selection state, and
+                    // draw them one after the other. Like for the selection below, ligatures that
+                    // span several runs are not split.
+                    let mut boundaries = vec![range.start, range.end];
+                    boundaries.extend(
+                        highlights
+                            .iter()
+                            .flat_map(|h| [h.range.start, h.range.end])
+                            .chain([min_select, max_select])
+                            .filter(|offset| range.contains(offset)),
+                    );
+                    boundaries.sort_unstable();
+                    boundaries.dedup();
+
+                    let x_for_byte_offset = |offset: usize| {
+                        metrics
+                            .glyphs
+                            .iter()
+                            .find(|glyph| glyph.byte_index >= offset - start)
+                            .map_or(metrics.width(), |glyph| glyph.x - glyph.bearing_x)
+                    };
+
+                    for run in boundaries.windows(2).map(|w| w[0]..w[1]) {
+                        let run_start_x = x_for_byte_offset(run.start);
+                        let run_end_x = x_for_byte_offset(run.end);
+                        let selected = (min_select..max_select).contains(&run.start);
+                        let highlight = items::TextHighlight::find(&highlights, run.start);
+
+                        let background = if selected {
+                            Some(text_input.selection_background_color())
+                        } else {
+                            highlight.and_then(|h| h.background)
+                        };
+                        if let Some(background) = background {
+                            let background_rect = PhysicalRect::new(
+                                pos + PhysicalPoint::from_lengths(
+                                    PhysicalLength::new(run_start_x),
+                                    PhysicalLength::default(),
+                                )
+                                .to_vector(),
+                                PhysicalSize::from_lengths(
+                                    PhysicalLength::new(run_end_x - run_start_x),
+                                    font_height,
+                                ),
+                            );
+                            canvas.fill_path(
+                                &rect_to_path(background_rect),
+                                &femtovg::Paint::color(to_femtovg_color(&background)),
+                            );
+                        }
+
+                        let color = if selected {
+                            Some(text_input.selection_foreground_color())
+                        } else {
+                            highlight.and_then(|h| h.color)
+                        };
+                        let run_paint = color.map_or_else(
+                            || paint.clone(),
+                            |color| {
+                                let mut run_paint = paint.clone();
+                                run_paint.set_color(to_femtovg_color(&color));
+                                run_paint
+                            },
+                        );
+                        canvas
+                            .fill_text(
+                                pos.x + run_start_x,
+                                pos.y,
+                                to_draw[run.start - start..run.end - start].trim_end(),
+                                &run_paint,
+                            )
+                            .unwrap();
+                    }
+                } else if min_select != max_select
                     && (range.contains(&min_select)
                         || range.contains(&max_select)
                         || (min_select..max_select).contains(&start))
@@ -824,6 +936,70 @@ fn visit_layer(
         }
     }
 
+    fn visit_backdrop_blur(
+        &mut self,
+        backdrop_blur_item: Pin<&BackdropBlur>,
+        _self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        let blur = backdrop_blur_item.blur_radius() * self.scale_factor;
+        let geometry = PhysicalRect::from(size * self.scale_factor);
+        if blur.get() <= 0. || geometry.is_empty() {
+            return RenderingResult::ContinueRenderingChildren;
+        }
+
+        // FemtoVG can't use the render target as a source directly, so what was rendered so far is read back,
+        // and the part behind the element is uploaded into a texture that is blurred and drawn on top.
+        let (offset_x, offset_y, image_id) = {
+            let mut canvas = self.canvas.borrow_mut();
+            let (origin_x, origin_y) = canvas.transform().transform_point(0., 0.);
+            let Ok(content) = canvas.screenshot() else {
+                return RenderingResult::ContinueRenderingChildren;
+            };
+            let x = (origin_x.round().max(0.) as usize).min(content.width());
+            let y = (origin_y.round().max(0.) as usize).min(content.height());
+            let width = (geometry.width().ceil() as usize).min(content.width() - x);
+            let height = (geometry.height().ceil() as usize).min(content.height() - y);
+            if width == 0 || height == 0 {
+                return RenderingResult::ContinueRenderingChildren;
+            }
+            let Ok(image_id) = canvas.create_image(
+                content.sub_image(x, y, width, height),
+                femtovg::ImageFlags::PREMULTIPLIED,
+            ) else {
+                return RenderingResult::ContinueRenderingChildren;
+            };
+            (x as f32 - origin_x, y as f32 - origin_y, image_id)
+        };
+
+        let content = Texture::adopt(&self.canvas, image_id);
+        let blurred = content.filter(femtovg::ImageFilter::GaussianBlur { sigma: blur.get() / 2. });
+        let size = blurred.size().unwrap();
+
+        {
+            let mut canvas = self.canvas.borrow_mut();
+            canvas.set_render_target(self.current_render_target());
+            let mut path = femtovg::Path::new();
+            path.rect(offset_x, offset_y, size.width as f32, size.height as f32);
+            let paint = femtovg::Paint::image(
+                blurred.id,
+                offset_x,
+                offset_y,
+                size.width as f32,
+                size.height as f32,
+                0.,
+                1.,
+            )
+            .with_anti_alias(false);
+            canvas.fill_path(&path, &paint);
+        }
+
+        let mut textures_to_delete_after_flush = self.textures_to_delete_after_flush.borrow_mut();
+        textures_to_delete_after_flush.push(content);
+        textures_to_delete_after_flush.push(blurred);
+        RenderingResult::ContinueRenderingChildren
+    }
+
     fn visit_clip(
         &mut self,
         clip_item: Pin<&Clip>,
@@ -987,13 +1163,14 @@ fn draw_image_direct(&mut self, image: i_slint_core::graphics::Image) {
                 .and_then(|cache_key| {
                     self.texture_cache.borrow_mut().lookup_image_in_cache_or_create(
                         cache_key,
-                        || {
+                        |atlas| {
                             Texture::new_from_image(
                                 image_inner,
                                 &self.canvas,
                                 None,
                                 Default::default(),
                                 Default::default(),
+                                Some(atlas),
                             )
                         },
                     )
@@ -1005,22 +1182,22 @@ fn draw_image_direct(&mut self, image: i_slint_core::graphics::Image) {
                         None,
                         Default::default(),
                         Default::default(),
+                        None,
                     )
                 })
         else {
             return;
         };
 
-        let image_id = cached_image.id;
         let image_size = cached_image.size().unwrap_or_default().cast();
 
         let (source_width, source_height) = (image_size.width, image_size.height);
 
-        let fill_paint =
-            femtovg::Paint::image(image_id, 0., 0., image_size.width, image_size.height, 0.0, 1.0)
-                // We preserve the rectangular shape of the image, so there's no need to apply anti-aliasing
-                // at the edges
-                .with_anti_alias(false);
+        let fill_paint = cached_image
+            .image_paint(0., 0., image_size.width, image_size.height, 1.0)
+            // We preserve the rectangular shape of the image, so there's no need to apply anti-aliasing
+            // at the edges
+            .with_anti_alias(false);
 
         let mut path = femtovg::Path::new();
         path.rect(0., 0., source_width, source_height);
@@ -1108,7 +1285,7 @@ pub(super) fn new(
                 global_alpha: 1.,
                 current_render_target: femtovg::RenderTarget::Screen,
             }],
-            metrics: RenderingMetrics { layers_created: Some(0) },
+            metrics: RenderingMetrics { layers_created: Some(0), layer_cache_hits: Some(0) },
         }
     }
 
@@ -1123,7 +1300,9 @@ fn render_layer(
                 _ => None,
             });
 
+        let mut layer_rendered = false;
         let cache_entry = self.graphics_cache.get_or_update_cache_entry(item_rc, || {
+            layer_rendered = true;
             ItemGraphicsCacheEntry::Texture({
                 let size = (layer_logical_size_fn() * self.scale_factor).ceil().try_cast()?;
 
@@ -1192,6 +1371,10 @@ fn render_layer(
             .into()
         });
 
+        if !layer_rendered && cache_entry.is_some() {
+            *self.metrics.layer_cache_hits.as_mut().unwrap() += 1;
+        }
+
         cache_entry.map(|item_cache_entry| item_cache_entry.as_texture().clone())
     }
 
@@ -1244,7 +1427,6 @@ fn colorize_image(
 
         let scaling_flags = super::images::base_image_flags(scaling, tiling);
 
-        let image_id = original_image.id;
         let colorized_image = self
             .canvas
             .borrow_mut()
@@ -1276,15 +1458,7 @@ fn colorize_image(
             canvas.global_composite_operation(femtovg::CompositeOperation::Copy);
             canvas.fill_path(
                 &image_rect,
-                &femtovg::Paint::image(
-                    image_id,
-                    0.,
-                    0.,
-                    image_size.width,
-                    image_size.height,
-                    0.,
-                    1.0,
-                ),
+                &original_image.image_paint(0., 0., image_size.width, image_size.height, 1.0),
             );
 
             canvas.global_composite_operation(femtovg::CompositeOperation::SourceIn);
@@ -1348,13 +1522,14 @@ fn draw_image_impl(
                 .and_then(|cache_key| {
                     self.texture_cache.borrow_mut().lookup_image_in_cache_or_create(
                         cache_key,
-                        || {
+                        |atlas| {
                             Texture::new_from_image(
                                 image_inner,
                                 &self.canvas,
                                 target_size_for_scalable_source,
                                 image_rendering,
                                 tiling,
+                                Some(atlas),
                             )
                         },
                     )
@@ -1366,6 +1541,7 @@ fn draw_image_impl(
                         target_size_for_scalable_source,
                         image_rendering,
                         tiling,
+                        None,
                     )
                 })
                 .map(ItemGraphicsCacheEntry::Texture)
@@ -1395,7 +1571,6 @@ fn draw_image_impl(
         };
 
         let image = item.source();
-        let image_id = cached_image.id;
         let orig_size = image.size().cast::<f32>();
         let buf_size = cached_image.size().unwrap_or_default().cast::<f32>();
         let source_clip_rect = item.source_clip().unwrap_or(IntRect::from_size(orig_size.cast()));
@@ -1426,7 +1601,7 @@ fn draw_image_impl(
         let scale_h = buf_size.height / orig_size.height;
 
         for fit in fits {
-            let (image_id, origin, texture_size) =
+            let (texture, origin, texture_size) =
                 if fit.tiled.is_some() && fit.clip_rect.size.cast() != orig_size {
                     let scaling_flags = match item.rendering() {
                         ImageRendering::Smooth => femtovg::ImageFlags::empty(),
@@ -1463,36 +1638,32 @@ fn draw_image_impl(
                         canvas.global_composite_operation(femtovg::CompositeOperation::Copy);
                         canvas.fill_path(
                             &image_rect,
-                            &femtovg::Paint::image(
-                                image_id,
+                            &cached_image.image_paint(
                                 -scale_w * fit.clip_rect.origin.x as f32,
                                 -scale_h * fit.clip_rect.origin.y as f32,
                                 buf_size.cast().width,
                                 buf_size.cast().height,
-                                0.,
                                 1.0,
                             ),
                         );
                         canvas.set_render_target(self.current_render_target());
                     });
-                    self.textures_to_delete_after_flush
-                        .borrow_mut()
-                        .push(Texture::adopt(&self.canvas, clipped_image));
+                    let clipped_image = Texture::adopt(&self.canvas, clipped_image);
+                    self.textures_to_delete_after_flush.borrow_mut().push(clipped_image.clone());
                     (clipped_image, Default::default(), texture_size)
                 } else {
-                    (image_id, fit.clip_rect.origin.cast::<f32>(), buf_size)
+                    (cached_image.clone(), fit.clip_rect.origin.cast::<f32>(), buf_size)
                 };
             let tiled = fit.tiled.unwrap_or_default();
-            let fill_paint = femtovg::Paint::image(
-                image_id,
-                -origin.x - tiled.x as f32,
-                -origin.y - tiled.y as f32,
-                texture_size.width,
-                texture_size.height,
-                0.0,
-                1.0,
-            )
-            .with_anti_alias(fit.source_to_target_x != 1. || fit.source_to_target_y != 0.);
+            let fill_paint = texture
+                .image_paint(
+                    -origin.x - tiled.x as f32,
+                    -origin.y - tiled.y as f32,
+                    texture_size.width,
+                    texture_size.height,
+                    1.0,
+                )
+                .with_anti_alias(fit.source_to_target_x != 1. || fit.source_to_target_y != 0.);
 
             let mut path = femtovg::Path::new();
             path.rect(
@@ -1547,10 +1718,73 @@ fn brush_to_paint(&self, brush: Brush, path: &femtovg::Path) -> Option<femtovg::
                     stops,
                 )
             }
+            Brush::ConicGradient(gradient) => {
+                let path_bounds = path_bounding_box(&self.canvas, path);
+
+                let path_width = path_bounds.width();
+                let path_height = path_bounds.height();
+
+                return self.rasterized_gradient_paint(path_width, path_height, |x, y| {
+                    // Clockwise, starting at the top
+                    let angle = f32::atan2(x - path_width / 2., path_height / 2. - y).to_degrees();
+                    gradient.color_at((angle - gradient.from_angle()).rem_euclid(360.) / 360.)
+                });
+            }
+            Brush::FourCornerGradient(gradient) => {
+                let path_bounds = path_bounding_box(&self.canvas, path);
+
+                let path_width = path_bounds.width();
+                let path_height = path_bounds.height();
+
+                return self.rasterized_gradient_paint(path_width, path_height, |x, y| {
+                    gradient.color_at(x / path_width, y / path_height)
+                });
+            }
             _ => return None,
         })
     }
 
+    /// FemtoVG has no support for conic and four corner gradients, so they're rendered into a
+    /// texture of limited size that covers the given area, and filled with the texture.
+    fn rasterized_gradient_paint(
+        &self,
+        width: f32,
+        height: f32,
+        color_at: impl Fn(f32, f32) -> Color,
+    ) -> Option<femtovg::Paint> {
+        const MAX_TEXTURE_SIZE: f32 = 256.;
+        if width <= 0. || height <= 0. {
+            return None;
+        }
+        let texture_width = width.ceil().min(MAX_TEXTURE_SIZE) as usize;
+        let texture_height = height.ceil().min(MAX_TEXTURE_SIZE) as usize;
+        let color_at = &color_at;
+        let pixels = (0..texture_height)
+            .flat_map(|row| {
+                (0..texture_width).map(move |column| {
+                    let color = color_at(
+                        (column as f32 + 0.5) * width / texture_width as f32,
+                        (row as f32 + 0.5) * height / texture_height as f32,
+                    );
+                    rgb::RGBA8::new(color.red(), color.green(), color.blue(), color.alpha())
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let image_id = self
+            .canvas
+            .borrow_mut()
+            .create_image(
+                imgref::Img::new(pixels.as_slice(), texture_width, texture_height),
+                femtovg::ImageFlags::empty(),
+            )
+            .ok()?;
+        let texture = Texture::adopt(&self.canvas, image_id);
+        let paint = femtovg::Paint::image(texture.id, 0., 0., width, height, 0., 1.);
+        self.textures_to_delete_after_flush.borrow_mut().push(texture);
+        Some(paint)
+    }
+
     fn current_render_target(&self) -> femtovg::RenderTarget {
         self.state.last().unwrap().current_render_target
     }
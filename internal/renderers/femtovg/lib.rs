@@ -35,6 +35,7 @@
 mod fonts;
 mod images;
 mod itemrenderer;
+mod texture_atlas;
 
 /// This trait describes the interface GPU accelerated renderers in Slint require to render with OpenGL.
 ///
@@ -166,6 +167,23 @@ fn internal_render_with_post_callback(
         translation: (f32, f32),
         surface_size: i_slint_core::api::PhysicalSize,
         post_render_cb: Option<&dyn Fn(&mut dyn ItemRenderer)>,
+    ) -> Result<(), i_slint_core::platform::PlatformError> {
+        self.render_to_back_buffer(
+            rotation_angle_degrees,
+            translation,
+            surface_size,
+            post_render_cb,
+        )?;
+        self.opengl_context.borrow().swap_buffers()?;
+        Ok(())
+    }
+
+    fn render_to_back_buffer(
+        &self,
+        rotation_angle_degrees: f32,
+        translation: (f32, f32),
+        surface_size: i_slint_core::api::PhysicalSize,
+        post_render_cb: Option<&dyn Fn(&mut dyn ItemRenderer)>,
     ) -> Result<(), i_slint_core::platform::PlatformError> {
         self.opengl_context.borrow().ensure_current()?;
 
@@ -303,7 +321,6 @@ fn internal_render_with_post_callback(
             self.with_graphics_api(|api| callback.notify(RenderingState::AfterRendering, &api))?;
         }
 
-        self.opengl_context.borrow().swap_buffers()?;
         Ok(())
     }
 
@@ -520,12 +537,13 @@ fn resize(&self, size: i_slint_core::api::PhysicalSize) -> Result<(), PlatformEr
         Ok(())
     }
 
-    /// Returns an image buffer of what was rendered last by reading the previous front buffer (using glReadPixels).
+    /// Renders the scene into the back buffer without presenting it, and returns an image buffer of it
+    /// (using glReadPixels).
     fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError> {
-        self.opengl_context.borrow().ensure_current()?;
         let Some(canvas) = self.canvas.borrow().as_ref().cloned() else {
             return Err("FemtoVG renderer cannot take screenshot without a window".into());
         };
+        self.render_to_back_buffer(0., (0., 0.), self.window_adapter()?.window().size(), None)?;
         let screenshot = canvas
             .borrow_mut()
             .screenshot()
@@ -0,0 +1,210 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Packs small images into shared textures ("pages"), to reduce the number of texture binds and
+//! the fragmentation of GPU memory when rendering many small icons.
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+use i_slint_core::graphics::{IntRect, Rgba8Pixel, SharedImageBuffer};
+
+use super::itemrenderer::CanvasRc;
+
+/// Images larger than this in either dimension get their own texture.
+const MAX_ATLAS_IMAGE_SIZE: u32 = 64;
+/// The width and height of each atlas page.
+const ATLAS_PAGE_SIZE: u32 = 1024;
+/// Every image is surrounded by a border of its own edge pixels, so that bilinear filtering
+/// doesn't sample neighbouring images.
+const PADDING: u32 = 1;
+
+/// A horizontal strip of an atlas page, filled from left to right with images of similar height.
+struct Shelf {
+    y: u32,
+    height: u32,
+    used_width: u32,
+}
+
+/// A simple shelf allocator. Space isn't reclaimed when individual images are released, instead
+/// the whole page is freed once none of its images is in use anymore.
+struct ShelfAllocator {
+    shelves: Vec<Shelf>,
+    used_height: u32,
+}
+
+impl ShelfAllocator {
+    fn new() -> Self {
+        Self { shelves: Vec::new(), used_height: 0 }
+    }
+
+    fn allocate(&mut self, width: u32, height: u32) -> Option<(u32, u32)> {
+        // Pick the lowest shelf that fits, to keep the wasted space small.
+        let best_shelf = self
+            .shelves
+            .iter_mut()
+            .filter(|shelf| {
+                shelf.height >= height
+                    && shelf.height <= height * 2
+                    && shelf.used_width + width <= ATLAS_PAGE_SIZE
+            })
+            .min_by_key(|shelf| shelf.height);
+
+        if let Some(shelf) = best_shelf {
+            let x = shelf.used_width;
+            shelf.used_width += width;
+            return Some((x, shelf.y));
+        }
+
+        if self.used_height + height > ATLAS_PAGE_SIZE {
+            return None;
+        }
+        let y = self.used_height;
+        self.used_height += height;
+        self.shelves.push(Shelf { y, height, used_width: width });
+        Some((0, y))
+    }
+}
+
+/// A texture that holds several small images.
+pub struct AtlasPage {
+    pub id: femtovg::ImageId,
+    canvas: CanvasRc,
+    allocator: RefCell<ShelfAllocator>,
+}
+
+impl AtlasPage {
+    pub fn size(&self) -> (f32, f32) {
+        (ATLAS_PAGE_SIZE as f32, ATLAS_PAGE_SIZE as f32)
+    }
+}
+
+impl Drop for AtlasPage {
+    fn drop(&mut self) {
+        self.canvas.borrow_mut().delete_image(self.id);
+    }
+}
+
+/// The location of an image within an atlas page. The region keeps the page alive.
+pub struct AtlasRegion {
+    pub page: Rc<AtlasPage>,
+    pub rect: IntRect,
+}
+
+/// Keeps track of the atlas pages, one set per combination of image flags, as the flags apply
+/// to the entire texture.
+#[derive(Default)]
+pub struct TextureAtlas {
+    pages: Vec<(femtovg::ImageFlags, Weak<AtlasPage>)>,
+}
+
+impl TextureAtlas {
+    /// Copies the image into an atlas page and returns its region, or None if the image is too
+    /// large for the atlas.
+    pub fn allocate(
+        &mut self,
+        canvas: &CanvasRc,
+        buffer: &SharedImageBuffer,
+        image_flags: femtovg::ImageFlags,
+    ) -> Option<AtlasRegion> {
+        let (width, height) = (buffer.width(), buffer.height());
+        if width == 0
+            || height == 0
+            || width > MAX_ATLAS_IMAGE_SIZE
+            || height > MAX_ATLAS_IMAGE_SIZE
+        {
+            return None;
+        }
+
+        let (pixels, image_flags) = padded_rgba_pixels(buffer, image_flags);
+        let padded_width = width + 2 * PADDING;
+        let padded_height = height + 2 * PADDING;
+
+        let existing_page =
+            self.pages.iter().filter(|(flags, _)| *flags == image_flags).find_map(|(_, page)| {
+                let page = page.upgrade()?;
+                let position = page.allocator.borrow_mut().allocate(padded_width, padded_height)?;
+                Some((page, position))
+            });
+
+        let (page, (x, y)) = match existing_page {
+            Some(page_and_position) => page_and_position,
+            None => {
+                let id = canvas
+                    .borrow_mut()
+                    .create_image_empty(
+                        ATLAS_PAGE_SIZE as usize,
+                        ATLAS_PAGE_SIZE as usize,
+                        femtovg::PixelFormat::Rgba8,
+                        image_flags,
+                    )
+                    .ok()?;
+                let page = Rc::new(AtlasPage {
+                    id,
+                    canvas: canvas.clone(),
+                    allocator: RefCell::new(ShelfAllocator::new()),
+                });
+                let position = page.allocator.borrow_mut().allocate(padded_width, padded_height)?;
+                self.pages.push((image_flags, Rc::downgrade(&page)));
+                (page, position)
+            }
+        };
+
+        let source =
+            imgref::ImgRef::new(pixels.as_slice(), padded_width as usize, padded_height as usize);
+        canvas.borrow_mut().update_image(page.id, source, x as usize, y as usize).ok()?;
+
+        Some(AtlasRegion {
+            page,
+            rect: IntRect::new(
+                [(x + PADDING) as i32, (y + PADDING) as i32].into(),
+                [width as i32, height as i32].into(),
+            ),
+        })
+    }
+
+    /// Forgets about pages that were freed because none of their images is in use anymore.
+    pub fn drain(&mut self) {
+        self.pages.retain(|(_, page)| page.strong_count() > 0);
+    }
+
+    pub fn clear(&mut self) {
+        self.pages.clear();
+    }
+}
+
+/// Converts the buffer to RGBA, as all images in a page must have the same format, and extrudes
+/// the edge pixels into the padding.
+fn padded_rgba_pixels(
+    buffer: &SharedImageBuffer,
+    image_flags: femtovg::ImageFlags,
+) -> (Vec<Rgba8Pixel>, femtovg::ImageFlags) {
+    let (width, height) = (buffer.width() as usize, buffer.height() as usize);
+    let (pixel_at, image_flags): (Box<dyn Fn(usize) -> Rgba8Pixel>, _) = match buffer {
+        SharedImageBuffer::RGB8(buffer) => {
+            let pixels = buffer.as_slice();
+            (Box::new(move |i| pixels[i].with_alpha(255)), image_flags)
+        }
+        SharedImageBuffer::RGBA8(buffer) => {
+            let pixels = buffer.as_slice();
+            (Box::new(move |i| pixels[i]), image_flags)
+        }
+        SharedImageBuffer::RGBA8Premultiplied(buffer) => {
+            let pixels = buffer.as_slice();
+            (Box::new(move |i| pixels[i]), image_flags | femtovg::ImageFlags::PREMULTIPLIED)
+        }
+    };
+
+    let padding = PADDING as usize;
+    let padded_width = width + 2 * padding;
+    let padded_height = height + 2 * padding;
+    let mut pixels = Vec::with_capacity(padded_width * padded_height);
+    for y in 0..padded_height {
+        let source_y = y.saturating_sub(padding).min(height - 1);
+        for x in 0..padded_width {
+            let source_x = x.saturating_sub(padding).min(width - 1);
+            pixels.push(pixel_at(source_y * width + source_x));
+        }
+    }
+    (pixels, image_flags)
+}
@@ -132,6 +132,13 @@ pub(crate) fn as_skia_image(
         ImageInner::NineSlice(n) => {
             as_skia_image(n.image(), target_size_fn, ImageFit::Preserve, scale_factor, canvas)
         }
+        // The Image element passes the current frame, so show the first frame otherwise
+        ImageInner::AnimatedImage(animation) => {
+            as_skia_image(animation.frame(0), target_size_fn, image_fit, scale_factor, canvas)
+        }
+        ImageInner::Video(video) => {
+            as_skia_image(video.current_frame(), target_size_fn, image_fit, scale_factor, canvas)
+        }
     }
 }
 
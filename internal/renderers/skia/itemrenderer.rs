@@ -9,12 +9,14 @@
 use i_slint_core::graphics::boxshadowcache::BoxShadowCache;
 use i_slint_core::graphics::euclid::num::Zero;
 use i_slint_core::graphics::euclid::{self, Vector2D};
+use i_slint_core::graphics::rendering_metrics_collector::RenderingMetrics;
 use i_slint_core::graphics::ApproxEq;
 use i_slint_core::item_rendering::{
     CachedRenderingData, ItemCache, ItemRenderer, RenderImage, RenderText,
 };
 use i_slint_core::items::{
-    ImageFit, ImageRendering, ItemRc, Layer, Opacity, RenderingResult, TextStrokeStyle,
+    BackdropBlur, ImageFit, ImageRendering, ItemRc, Layer, Opacity, RenderingResult, ShaderEffect,
+    TextStrokeStyle,
 };
 use i_slint_core::lengths::{
     LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalPx, LogicalRect, LogicalSize,
@@ -40,7 +42,9 @@ pub struct SkiaItemRenderer<'a> {
     current_state: RenderState,
     image_cache: &'a ItemCache<Option<skia_safe::Image>>,
     path_cache: &'a ItemCache<Option<(Vector2D<f32, PhysicalPx>, skia_safe::Path)>>,
+    shader_cache: &'a ItemCache<Option<skia_safe::RuntimeEffect>>,
     box_shadow_cache: &'a mut SkiaBoxShadowCache,
+    metrics: RenderingMetrics,
 }
 
 impl<'a> SkiaItemRenderer<'a> {
@@ -49,6 +53,7 @@ pub fn new(
         window: &'a i_slint_core::api::Window,
         image_cache: &'a ItemCache<Option<skia_safe::Image>>,
         path_cache: &'a ItemCache<Option<(Vector2D<f32, PhysicalPx>, skia_safe::Path)>>,
+        shader_cache: &'a ItemCache<Option<skia_safe::RuntimeEffect>>,
         box_shadow_cache: &'a mut SkiaBoxShadowCache,
     ) -> Self {
         Self {
@@ -59,7 +64,9 @@ pub fn new(
             current_state: RenderState { alpha: 1.0, translation: Default::default() },
             image_cache,
             path_cache,
+            shader_cache,
             box_shadow_cache,
+            metrics: RenderingMetrics { layers_created: Some(0), layer_cache_hits: Some(0) },
         }
     }
 
@@ -137,6 +144,63 @@ fn brush_to_shader(
                         as &skia_safe::Matrix,
                 )
             }
+            Brush::ConicGradient(g) => {
+                let (colors, pos): (Vec<_>, Vec<_>) =
+                    g.stops().map(|s| (to_skia_color(&s.color), s.position)).unzip();
+                let center = skia_safe::Point::new(width.get() / 2., height.get() / 2.);
+
+                paint.set_dither(true);
+
+                // Skia's sweep gradient starts at 3 o'clock, while ours starts at the top
+                skia_safe::gradient_shader::sweep(
+                    center,
+                    skia_safe::gradient_shader::GradientShaderColors::Colors(&colors),
+                    Some(&*pos),
+                    TileMode::Clamp,
+                    None,
+                    skia_safe::gradient_shader::Flags::INTERPOLATE_COLORS_IN_PREMUL,
+                    &skia_safe::Matrix::rotate_deg_pivot(g.from_angle() - 90., center),
+                )
+            }
+            Brush::FourCornerGradient(g) => {
+                // Let the bilinear sampling of a 2x2 image interpolate between the corners,
+                // mapping the pixel centers to the corners of the shape.
+                let pixels = [g.top_left, g.top_right, g.bottom_left, g.bottom_right]
+                    .iter()
+                    .flat_map(|c| {
+                        let premultiply =
+                            |channel: u8| (channel as u16 * c.alpha() as u16 / 255) as u8;
+                        [
+                            premultiply(c.red()),
+                            premultiply(c.green()),
+                            premultiply(c.blue()),
+                            c.alpha(),
+                        ]
+                    })
+                    .collect::<Vec<_>>();
+                let image_info = skia_safe::ImageInfo::new(
+                    skia_safe::ISize::new(2, 2),
+                    skia_safe::ColorType::RGBA8888,
+                    skia_safe::AlphaType::Premul,
+                    None,
+                );
+                let image = skia_safe::images::raster_from_data(
+                    &image_info,
+                    skia_safe::Data::new_copy(&pixels),
+                    2 * 4,
+                )?;
+
+                paint.set_dither(true);
+
+                image.to_shader(
+                    (TileMode::Clamp, TileMode::Clamp),
+                    skia_safe::SamplingOptions::from(
+                        skia_safe::sampling_options::FilterMode::Linear,
+                    ),
+                    skia_safe::Matrix::scale((width.get(), height.get()))
+                        .pre_translate((-0.5, -0.5)) as &skia_safe::Matrix,
+                )
+            }
             _ => None,
         }
         .map(|shader| (paint, shader))
@@ -289,8 +353,22 @@ fn draw_image_impl(
     }
 
     fn render_and_blend_layer(&mut self, item_rc: &ItemRc) -> RenderingResult {
+        if let Some(layer_image) = self.render_children_layer(item_rc) {
+            let _saved_canvas = self.pixel_align_origin();
+            self.canvas.draw_image_with_sampling_options(
+                layer_image,
+                skia_safe::Point::default(),
+                skia_safe::sampling_options::FilterMode::Linear,
+                self.default_paint().as_ref(),
+            );
+        }
+        RenderingResult::ContinueRenderingWithoutChildren
+    }
+
+    /// Renders the children of the item into a layer that covers them all.
+    fn render_children_layer(&mut self, item_rc: &ItemRc) -> Option<skia_safe::Image> {
         let current_clip = self.get_current_clip();
-        if let Some(layer_image) = self.render_layer(item_rc, &|| {
+        self.render_layer(item_rc, &|| {
             // We don't need to include the size of the "layer" item itself, since it has no content.
             let children_rect = i_slint_core::properties::evaluate_no_tracking(|| {
                 item_rc.geometry().union(
@@ -302,16 +380,7 @@ fn render_and_blend_layer(&mut self, item_rc: &ItemRc) -> RenderingResult {
                 )
             });
             children_rect.size_length()
-        }) {
-            let _saved_canvas = self.pixel_align_origin();
-            self.canvas.draw_image_with_sampling_options(
-                layer_image,
-                skia_safe::Point::default(),
-                skia_safe::sampling_options::FilterMode::Linear,
-                self.default_paint().as_ref(),
-            );
-        }
-        RenderingResult::ContinueRenderingWithoutChildren
+        })
     }
 
     fn render_layer(
@@ -319,7 +388,9 @@ fn render_layer(
         item_rc: &ItemRc,
         layer_logical_size_fn: &dyn Fn() -> LogicalSize,
     ) -> Option<skia_safe::Image> {
-        self.image_cache.get_or_update_cache_entry(item_rc, || {
+        let mut layer_rendered = false;
+        let layer = self.image_cache.get_or_update_cache_entry(item_rc, || {
+            layer_rendered = true;
             let layer_size = layer_logical_size_fn() * self.scale_factor;
 
             let image_info = skia_safe::ImageInfo::new(
@@ -337,6 +408,7 @@ fn render_layer(
                 &self.window,
                 self.image_cache,
                 self.path_cache,
+                self.shader_cache,
                 self.box_shadow_cache,
             );
 
@@ -346,8 +418,21 @@ fn render_layer(
                 item_rc.index() as isize,
             );
 
+            // Include the layers of the children
+            let sub_metrics = sub_renderer.metrics;
+            *self.metrics.layers_created.as_mut().unwrap() +=
+                1 + sub_metrics.layers_created.unwrap_or_default();
+            *self.metrics.layer_cache_hits.as_mut().unwrap() +=
+                sub_metrics.layer_cache_hits.unwrap_or_default();
+
             Some(surface.image_snapshot())
-        })
+        });
+
+        if !layer_rendered && layer.is_some() {
+            *self.metrics.layer_cache_hits.as_mut().unwrap() += 1;
+        }
+
+        layer
     }
 
     /// Draws a `Rectangle` using the `GLItemRenderer`.
@@ -547,7 +632,9 @@ fn draw_text(
                         vertical_alignment,
                         text.wrap(),
                         text.overflow(),
+                        text.text_direction(),
                         None,
+                        &[],
                     ))
                 }
             }
@@ -565,22 +652,65 @@ fn draw_text(
             vertical_alignment,
             text.wrap(),
             text.overflow(),
+            text.text_direction(),
             None,
+            &[],
         );
 
-        match (stroke_style, stroke_layout) {
-            (TextStrokeStyle::Outside, Some((stroke_layout, stroke_layout_top_left))) => {
-                stroke_layout.paint(&mut self.canvas, to_skia_point(stroke_layout_top_left));
-                layout.paint(&mut self.canvas, to_skia_point(layout_top_left));
-            }
-            (TextStrokeStyle::Center, Some((stroke_layout, stroke_layout_top_left))) => {
-                layout.paint(&mut self.canvas, to_skia_point(layout_top_left));
-                stroke_layout.paint(&mut self.canvas, to_skia_point(stroke_layout_top_left));
+        let paint_layouts = |canvas: &skia_safe::Canvas| {
+            match (stroke_style, &stroke_layout) {
+                (TextStrokeStyle::Outside, Some((stroke_layout, stroke_layout_top_left))) => {
+                    stroke_layout.paint(canvas, to_skia_point(*stroke_layout_top_left));
+                    layout.paint(canvas, to_skia_point(layout_top_left));
+                }
+                (TextStrokeStyle::Center, Some((stroke_layout, stroke_layout_top_left))) => {
+                    layout.paint(canvas, to_skia_point(layout_top_left));
+                    stroke_layout.paint(canvas, to_skia_point(*stroke_layout_top_left));
+                }
+                _ => {
+                    layout.paint(canvas, to_skia_point(layout_top_left));
+                }
+            };
+        };
+
+        if !text.has_glyph_transform() {
+            paint_layouts(self.canvas);
+            return;
+        }
+
+        // Paint the layout once per character, clipped to the character's box, rotated around its
+        // center and moved by the transform returned by the callback.
+        let mut utf16_index = 0;
+        for (index, ch) in string.chars().enumerate() {
+            let utf16_range = utf16_index..utf16_index + ch.len_utf16();
+            utf16_index = utf16_range.end;
+            if ch.is_whitespace() {
+                continue;
             }
-            _ => {
-                layout.paint(&mut self.canvas, to_skia_point(layout_top_left));
+            let transform = text.glyph_transform(index);
+            for text_box in layout.get_rects_for_range(
+                utf16_range,
+                skia_safe::textlayout::RectHeightStyle::Max,
+                skia_safe::textlayout::RectWidthStyle::Tight,
+            ) {
+                let rect = text_box.rect.with_offset(to_skia_point(layout_top_left));
+                let clip_rect = skia_safe::Rect::from_ltrb(
+                    rect.left - stroke_width,
+                    rect.top - stroke_width,
+                    rect.right + stroke_width,
+                    rect.bottom + stroke_width,
+                );
+                self.canvas.save();
+                self.canvas.translate((
+                    transform.x * self.scale_factor.get(),
+                    transform.y * self.scale_factor.get(),
+                ));
+                self.canvas.rotate(transform.rotation, Some(rect.center()));
+                self.canvas.clip_rect(clip_rect, None, true);
+                paint_layouts(self.canvas);
+                self.canvas.restore();
             }
-        };
+        }
     }
 
     fn draw_text_input(
@@ -638,7 +768,9 @@ fn draw_text_input(
             text_input.vertical_alignment(),
             text_input.wrap(),
             i_slint_core::items::TextOverflow::Clip,
+            Default::default(),
             selection.as_ref(),
+            &visual_representation.highlights,
         );
 
         layout.paint(&mut self.canvas, to_skia_point(layout_top_left));
@@ -946,6 +1078,10 @@ fn as_any(&mut self) -> Option<&mut dyn core::any::Any> {
         None
     }
 
+    fn metrics(&self) -> RenderingMetrics {
+        self.metrics.clone()
+    }
+
     fn visit_opacity(
         &mut self,
         opacity_item: Pin<&Opacity>,
@@ -986,6 +1122,105 @@ fn visit_layer(
             RenderingResult::ContinueRenderingChildren
         }
     }
+
+    fn visit_backdrop_blur(
+        &mut self,
+        backdrop_blur_item: Pin<&BackdropBlur>,
+        _self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        let blur = backdrop_blur_item.blur_radius() * self.scale_factor;
+        let geometry = PhysicalRect::from(size * self.scale_factor);
+        if blur.get() <= 0. || geometry.is_empty() {
+            return RenderingResult::ContinueRenderingChildren;
+        }
+        let Some(filter) = skia_safe::image_filters::blur(
+            (blur.get() / 2., blur.get() / 2.),
+            TileMode::Clamp,
+            None,
+            None,
+        ) else {
+            return RenderingResult::ContinueRenderingChildren;
+        };
+
+        // Saving a layer with a backdrop filter initializes it with the filtered content behind it,
+        // which is then drawn back within the clip when restoring.
+        self.canvas.save();
+        self.canvas.clip_rect(to_skia_rect(&geometry), None, true);
+        self.canvas.save_layer(&skia_safe::canvas::SaveLayerRec::default().backdrop(&filter));
+        self.canvas.restore();
+        self.canvas.restore();
+        RenderingResult::ContinueRenderingChildren
+    }
+
+    fn visit_shader_effect(
+        &mut self,
+        shader_effect_item: Pin<&ShaderEffect>,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        let Some(effect) = self.shader_cache.get_or_update_cache_entry(self_rc, || {
+            skia_safe::RuntimeEffect::make_for_shader(shader_effect_item.shader().as_str(), None)
+                .map_err(|error| eprintln!("Error compiling the shader of a ShaderEffect: {error}"))
+                .ok()
+        }) else {
+            // Render the children without the effect
+            self.image_cache.release(self_rc);
+            return RenderingResult::ContinueRenderingChildren;
+        };
+
+        let Some(layer_image) = self.render_children_layer(self_rc) else {
+            return RenderingResult::ContinueRenderingWithoutChildren;
+        };
+
+        let values = shader_effect_item.uniforms();
+        let mut uniform_data = vec![0u8; effect.uniform_size()];
+        for uniform in effect.uniforms() {
+            let floats = match uniform.name() {
+                "size" => vec![size.width, size.height],
+                name => match values.iter().find(|(value_name, _)| value_name.as_str() == name) {
+                    Some((_, floats)) => floats.clone(),
+                    None => continue,
+                },
+            };
+            let bytes = floats.iter().flat_map(|value| value.to_ne_bytes());
+            let range = uniform.offset()..uniform.offset() + uniform.size_in_bytes();
+            for (dst, src) in uniform_data[range].iter_mut().zip(bytes) {
+                *dst = src;
+            }
+        }
+
+        // The shader works in logical coordinates, while the layer is in physical pixels
+        let scale_factor = self.scale_factor.get();
+        let Some(content) = layer_image.to_shader(
+            (TileMode::Decal, TileMode::Decal),
+            skia_safe::SamplingOptions::from(skia_safe::sampling_options::FilterMode::Linear),
+            &Matrix::scale((1. / scale_factor, 1. / scale_factor)),
+        ) else {
+            return RenderingResult::ContinueRenderingWithoutChildren;
+        };
+        let children = effect
+            .children()
+            .iter()
+            .map(|_| skia_safe::runtime_effect::ChildPtr::from(content.clone()))
+            .collect::<Vec<_>>();
+        let Some(shader) = effect.make_shader(
+            skia_safe::Data::new_copy(&uniform_data),
+            &children,
+            Some(&Matrix::scale((scale_factor, scale_factor))),
+        ) else {
+            return RenderingResult::ContinueRenderingWithoutChildren;
+        };
+
+        let mut paint = self.default_paint().unwrap_or_default();
+        paint.set_shader(shader);
+        let _saved_canvas = self.pixel_align_origin();
+        self.canvas.draw_rect(
+            skia_safe::Rect::from_wh(layer_image.width() as _, layer_image.height() as _),
+            &paint,
+        );
+        RenderingResult::ContinueRenderingWithoutChildren
+    }
 }
 
 pub fn from_skia_rect(rect: &skia_safe::Rect) -> PhysicalRect {
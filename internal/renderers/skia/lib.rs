@@ -108,6 +108,7 @@ pub struct SkiaRenderer {
     rendering_notifier: RefCell<Option<Box<dyn RenderingNotifier>>>,
     image_cache: ItemCache<Option<skia_safe::Image>>,
     path_cache: ItemCache<Option<(Vector2D<f32, PhysicalPx>, skia_safe::Path)>>,
+    shader_cache: ItemCache<Option<skia_safe::RuntimeEffect>>,
     rendering_metrics_collector: RefCell<Option<Rc<RenderingMetricsCollector>>>,
     rendering_first_time: Cell<bool>,
     surface: RefCell<Option<Box<dyn Surface>>>,
@@ -130,6 +131,7 @@ fn default() -> Self {
             rendering_notifier: Default::default(),
             image_cache: Default::default(),
             path_cache: Default::default(),
+            shader_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
             rendering_first_time: Default::default(),
             surface: Default::default(),
@@ -151,6 +153,7 @@ pub fn default_software() -> Self {
             rendering_notifier: Default::default(),
             image_cache: Default::default(),
             path_cache: Default::default(),
+            shader_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
             rendering_first_time: Default::default(),
             surface: Default::default(),
@@ -178,6 +181,7 @@ pub fn default_opengl() -> Self {
             rendering_notifier: Default::default(),
             image_cache: Default::default(),
             path_cache: Default::default(),
+            shader_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
             rendering_first_time: Default::default(),
             surface: Default::default(),
@@ -205,6 +209,7 @@ pub fn default_metal() -> Self {
             rendering_notifier: Default::default(),
             image_cache: Default::default(),
             path_cache: Default::default(),
+            shader_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
             rendering_first_time: Default::default(),
             surface: Default::default(),
@@ -232,6 +237,7 @@ pub fn default_vulkan() -> Self {
             rendering_notifier: Default::default(),
             image_cache: Default::default(),
             path_cache: Default::default(),
+            shader_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
             rendering_first_time: Default::default(),
             surface: Default::default(),
@@ -259,6 +265,7 @@ pub fn default_direct3d() -> Self {
             rendering_notifier: Default::default(),
             image_cache: Default::default(),
             path_cache: Default::default(),
+            shader_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
             rendering_first_time: Default::default(),
             surface: Default::default(),
@@ -299,6 +306,7 @@ pub fn new_with_surface(surface: Box<dyn Surface + 'static>) -> Self {
             rendering_notifier: Default::default(),
             image_cache: Default::default(),
             path_cache: Default::default(),
+            shader_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
             rendering_first_time: Cell::new(true),
             surface: RefCell::new(Some(surface)),
@@ -315,6 +323,7 @@ pub fn new_with_surface(surface: Box<dyn Surface + 'static>) -> Self {
     pub fn set_surface(&self, surface: Box<dyn Surface + 'static>) {
         self.image_cache.clear_all();
         self.path_cache.clear_all();
+        self.shader_cache.clear_all();
         self.rendering_first_time.set(true);
         *self.surface.borrow_mut() = Some(surface);
     }
@@ -346,6 +355,7 @@ fn clear_surface(&self) {
     pub fn suspend(&self) -> Result<(), PlatformError> {
         self.image_cache.clear_all();
         self.path_cache.clear_all();
+        self.shader_cache.clear_all();
         // Destroy the old surface before allocating the new one, to work around
         // the vivante drivers using zwp_linux_explicit_synchronization_v1 and
         // trying to create a second synchronization object and that's not allowed.
@@ -479,12 +489,14 @@ fn render_components_to_canvas(
 
         self.image_cache.clear_cache_if_scale_factor_changed(window);
         self.path_cache.clear_cache_if_scale_factor_changed(window);
+        self.shader_cache.clear_cache_if_scale_factor_changed(window);
 
         let mut skia_item_renderer = itemrenderer::SkiaItemRenderer::new(
             skia_canvas,
             window,
             &self.image_cache,
             &self.path_cache,
+            &self.shader_cache,
             &mut box_shadow_cache,
         );
 
@@ -640,7 +652,9 @@ fn text_size(
             Default::default(),
             Default::default(),
             Default::default(),
+            Default::default(),
             None,
+            &[],
         );
 
         PhysicalSize::new(layout.max_intrinsic_width().ceil(), layout.height().ceil())
@@ -683,7 +697,9 @@ fn text_input_byte_offset_for_position(
             text_input.vertical_alignment(),
             text_input.wrap(),
             i_slint_core::items::TextOverflow::Clip,
+            Default::default(),
             None,
+            &visual_representation.highlights,
         );
 
         let utf16_index =
@@ -731,7 +747,9 @@ fn text_input_cursor_rect_for_byte_offset(
             text_input.vertical_alignment(),
             text_input.wrap(),
             i_slint_core::items::TextOverflow::Clip,
+            Default::default(),
             None,
+            &text_input.highlights(),
         );
 
         let physical_cursor_rect = textlayout::cursor_rect(
@@ -790,6 +808,7 @@ fn free_graphics_resources(
     ) -> Result<(), i_slint_core::platform::PlatformError> {
         self.image_cache.component_destroyed(component);
         self.path_cache.component_destroyed(component);
+        self.shader_cache.component_destroyed(component);
 
         if let Some(partial_rendering_state) = self.partial_rendering_state.as_ref() {
             partial_rendering_state.free_graphics_resources(items);
@@ -802,6 +821,7 @@ fn set_window_adapter(&self, window_adapter: &Rc<dyn WindowAdapter>) {
         *self.maybe_window_adapter.borrow_mut() = Some(Rc::downgrade(window_adapter));
         self.image_cache.clear_all();
         self.path_cache.clear_all();
+        self.shader_cache.clear_all();
 
         if let Some(partial_rendering_state) = self.partial_rendering_state.as_ref() {
             partial_rendering_state.clear_cache();
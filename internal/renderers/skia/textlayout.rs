@@ -81,12 +81,46 @@ pub fn create_layout(
     v_align: TextVerticalAlignment,
     wrap: items::TextWrap,
     overflow: items::TextOverflow,
+    direction: items::TextDirection,
     selection: Option<&Selection>,
+    highlights: &[items::TextHighlight],
 ) -> (skia_safe::textlayout::Paragraph, PhysicalPoint) {
     let mut text_style = text_style.unwrap_or_default();
 
+    let fallback_families = font_request.fallback_families();
     if let Some(family_name) = font_request.family.as_ref() {
-        text_style.set_font_families(&[family_name.as_str()]);
+        let families = core::iter::once(family_name.as_str())
+            .chain(fallback_families.iter().map(|family| family.as_str()))
+            .collect::<Vec<_>>();
+        text_style.set_font_families(&families);
+    } else if !fallback_families.is_empty() {
+        let families = text_style
+            .font_families()
+            .iter()
+            .map(ToString::to_string)
+            .chain(fallback_families.iter().map(|family| family.to_string()))
+            .collect::<Vec<_>>();
+        text_style.set_font_families(&families);
+    }
+
+    for feature in font_request.features() {
+        if let Ok(name) = core::str::from_utf8(&feature.tag) {
+            text_style.add_font_feature(name, feature.value as i32);
+        }
+    }
+
+    let coordinates = font_request
+        .variations()
+        .map(|variation| skia_safe::font_arguments::variation_position::Coordinate {
+            axis: skia_safe::FourByteTag::new(u32::from_be_bytes(variation.tag)),
+            value: variation.value,
+        })
+        .collect::<Vec<_>>();
+    if !coordinates.is_empty() {
+        let arguments = skia_safe::FontArguments::new().set_variation_design_position(
+            skia_safe::font_arguments::VariationPosition { coordinates: &coordinates },
+        );
+        text_style.set_font_arguments(&arguments);
     }
 
     let pixel_size = font_request.pixel_size.unwrap_or(DEFAULT_FONT_SIZE) * scale_factor;
@@ -114,6 +148,16 @@ pub fn create_layout(
         items::TextHorizontalAlignment::Right => skia_safe::textlayout::TextAlign::Right,
     });
 
+    match direction {
+        items::TextDirection::Auto => {}
+        items::TextDirection::LeftToRight => {
+            style.set_text_direction(skia_safe::textlayout::TextDirection::LTR);
+        }
+        items::TextDirection::RightToLeft => {
+            style.set_text_direction(skia_safe::textlayout::TextDirection::RTL);
+        }
+    }
+
     style.set_text_style(&text_style);
 
     let mut builder = FONT_CACHE.with(|font_cache| {
@@ -123,40 +167,72 @@ pub fn create_layout(
         )
     });
 
-    if let Some(selection) = selection {
-        let before_selection = &text[..selection.range.start];
-        builder.add_text(before_selection);
-
-        let mut selection_style = text_style.clone();
-
-        if let Some(selection_background) = selection.background {
-            let mut selection_background_paint = skia_safe::Paint::default();
-            selection_background_paint.set_color(to_skia_color(&selection_background));
-            selection_style.set_background_paint(&selection_background_paint);
-        }
-
-        if let Some(selection_foreground) = selection.foreground {
-            let mut selection_foreground_paint = skia_safe::Paint::default();
-            selection_foreground_paint.set_color(to_skia_color(&selection_foreground));
-            selection_style.set_foreground_paint(&selection_foreground_paint);
+    if selection.is_none() && highlights.is_empty() {
+        builder.add_text(text);
+    } else {
+        let mut boundaries = vec![0, text.len()];
+        if let Some(selection) = selection {
+            boundaries.extend([selection.range.start, selection.range.end]);
         }
-
-        if selection.underline {
-            let mut decoration = skia_safe::textlayout::Decoration::default();
-            decoration.ty = skia_safe::textlayout::TextDecoration::UNDERLINE;
-            decoration.color = text_style.foreground().color();
-            selection_style.set_decoration(&decoration);
+        boundaries.extend(highlights.iter().flat_map(|h| [h.range.start, h.range.end]));
+        boundaries.retain(|offset| *offset <= text.len());
+        boundaries.sort_unstable();
+        boundaries.dedup();
+
+        for run in boundaries.windows(2).map(|w| w[0]..w[1]) {
+            let highlight = items::TextHighlight::find(highlights, run.start);
+            let selection = selection.filter(|selection| selection.range.contains(&run.start));
+            if highlight.is_none() && selection.is_none() {
+                builder.add_text(&text[run]);
+                continue;
+            }
+
+            let mut run_style = text_style.clone();
+
+            if let Some(highlight) = highlight {
+                if let Some(color) = highlight.color {
+                    let mut foreground_paint = skia_safe::Paint::default();
+                    foreground_paint.set_color(to_skia_color(&color));
+                    run_style.set_foreground_paint(&foreground_paint);
+                }
+                if let Some(background) = highlight.background {
+                    let mut background_paint = skia_safe::Paint::default();
+                    background_paint.set_color(to_skia_color(&background));
+                    run_style.set_background_paint(&background_paint);
+                }
+                if let Some(weight) = highlight.font_weight {
+                    run_style.set_font_style(font_style_for_request(&FontRequest {
+                        weight: Some(weight),
+                        ..font_request.clone()
+                    }));
+                }
+            }
+
+            if let Some(selection) = selection {
+                if let Some(selection_background) = selection.background {
+                    let mut selection_background_paint = skia_safe::Paint::default();
+                    selection_background_paint.set_color(to_skia_color(&selection_background));
+                    run_style.set_background_paint(&selection_background_paint);
+                }
+
+                if let Some(selection_foreground) = selection.foreground {
+                    let mut selection_foreground_paint = skia_safe::Paint::default();
+                    selection_foreground_paint.set_color(to_skia_color(&selection_foreground));
+                    run_style.set_foreground_paint(&selection_foreground_paint);
+                }
+
+                if selection.underline {
+                    let mut decoration = skia_safe::textlayout::Decoration::default();
+                    decoration.ty = skia_safe::textlayout::TextDecoration::UNDERLINE;
+                    decoration.color = text_style.foreground().color();
+                    run_style.set_decoration(&decoration);
+                }
+            }
+
+            builder.push_style(&run_style);
+            builder.add_text(&text[run]);
+            builder.pop();
         }
-
-        builder.push_style(&selection_style);
-        let selected_text = &text[selection.range.clone()];
-        builder.add_text(selected_text);
-        builder.pop();
-
-        let after_selection = &text[selection.range.end..];
-        builder.add_text(after_selection);
-    } else {
-        builder.add_text(text);
     }
 
     let mut paragraph = builder.build();
@@ -188,7 +264,9 @@ pub fn font_metrics(
         Default::default(),
         Default::default(),
         Default::default(),
+        Default::default(),
         None,
+        &[],
     );
 
     let fonts = layout.get_fonts();
@@ -221,7 +299,9 @@ fn register_font(source: CustomFontSource) -> Result<(), Box<dyn std::error::Err
 
         let data: std::borrow::Cow<[u8]> = match &source {
             CustomFontSource::ByData(data) => std::borrow::Cow::Borrowed(data),
-            CustomFontSource::ByPath(path) => std::borrow::Cow::Owned(std::fs::read(path)?),
+            CustomFontSource::ByPath(path) => std::borrow::Cow::Owned(
+                i_slint_core::graphics::read_resource(&path.to_string_lossy())?,
+            ),
         };
 
         let type_face =
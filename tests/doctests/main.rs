@@ -10,9 +10,11 @@ fn do_test(snippet: &str, path: &str) -> Result<(), Box<dyn std::error::Error>>
     let code = if must_wrap {
         format!(
             "import {{
-                Button, CheckBox, ComboBox, DatePickerPopup, LineEdit, ProgressIndicator, ScrollView,
-                Slider, SpinBox, Spinner, StandardButton, StandardListView, StandardTableView,
-                Switch, TabWidget, TextEdit, TimePickerPopup}} from\"std-widgets.slint\";
+                Accordion, Avatar, Badge, Button, CheckBox, Chip, ChipGroup, ChipItem, ColorPicker,
+                ComboBox, DataGrid, DataGridCellKind, DatePickerPopup, Expander, LineEdit, NumberInput,
+                ProgressIndicator, ScrollView, Slider, SpinBox, Spinner, SplitView, StandardButton,
+                StandardListView, StandardTableView, StandardTreeView, SwipeView, Switch, TabWidget,
+                TextEdit, TimePickerPopup, ToolBar, Wizard}} from\"std-widgets.slint\";
             component Example {{\n{}\n}}",
             snippet
         )
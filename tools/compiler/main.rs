@@ -80,6 +80,24 @@ struct Cli {
     /// C++ files to generate (0 for header-only output)
     #[arg(long = "cpp-file", name = "C++ file to generate", number_of_values = 1, action)]
     cpp_files: Vec<std::path::PathBuf>,
+
+    /// Report the exported components that are never used, the properties that are never read,
+    /// and the callbacks that are never invoked
+    #[arg(long = "report-unused", action)]
+    report_unused: bool,
+
+    /// Share the code of the identical property bindings to generate smaller code (Only for the Rust output)
+    #[arg(long = "optimize-for-size", action)]
+    optimize_for_size: bool,
+
+    /// Replace the images that show a simple SVG with a Path element
+    #[arg(long = "svg-to-path", action)]
+    svg_to_path: bool,
+
+    /// Define a name, or a name and a value in the format `<name>=<value>`, that is matched
+    /// by the `@cfg(...)` conditions of the elements
+    #[arg(short = 'D', long = "define", name = "define", number_of_values = 1, action)]
+    defines: Vec<String>,
 }
 
 fn main() -> std::io::Result<()> {
@@ -125,6 +143,21 @@ fn main() -> std::io::Result<()> {
 
     let mut compiler_config = CompilerConfiguration::new(format.clone());
     compiler_config.translation_domain = args.translation_domain;
+    compiler_config.report_unused_items = args.report_unused;
+    if args.optimize_for_size {
+        compiler_config.optimize_for_size = true;
+    }
+    if args.svg_to_path {
+        compiler_config.svg_to_path = true;
+    }
+    compiler_config.defines = args
+        .defines
+        .iter()
+        .map(|define| match define.split_once('=') {
+            Some((name, value)) => (name.into(), Some(value.into())),
+            None => (define.clone(), None),
+        })
+        .collect();
 
     // Override defaults from command line:
     if let Some(embed) = args.embed_resources {
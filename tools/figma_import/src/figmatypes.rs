@@ -20,7 +20,7 @@ pub struct File {
     pub document: Node,
     pub components: HashMap<String, Component>,
     //schemaVersion: 0,
-    styles: HashMap<String, Style>,
+    pub styles: HashMap<String, Style>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -46,6 +46,39 @@ pub struct NodeCommon {
     pub visible: bool,
     #[serde(default)]
     pub children: Vec<Node>,
+    /// "INHERIT" or "STRETCH" along the counter axis of the parent auto-layout
+    pub layoutAlign: Option<String>,
+    /// Greater than 0 if the node grows along the primary axis of the parent auto-layout
+    #[serde(default)]
+    pub layoutGrow: f32,
+    /// "AUTO" or "ABSOLUTE" when the parent is an auto-layout
+    pub layoutPositioning: Option<String>,
+    /// "FIXED", "HUG", or "FILL"
+    pub layoutSizingHorizontal: Option<String>,
+    /// "FIXED", "HUG", or "FILL"
+    pub layoutSizingVertical: Option<String>,
+}
+
+impl NodeCommon {
+    /// Whether the size of the node along the `horizontal` or vertical axis follows its parent auto-layout.
+    /// `primary` tells if that axis is the direction of the auto-layout or its counter axis.
+    pub fn fills(&self, horizontal: bool, primary: bool) -> bool {
+        let sizing =
+            if horizontal { &self.layoutSizingHorizontal } else { &self.layoutSizingVertical };
+        sizing.as_deref() == Some("FILL")
+            || if primary {
+                self.layoutGrow > 0.
+            } else {
+                self.layoutAlign.as_deref() == Some("STRETCH")
+            }
+    }
+
+    /// Whether the size along the axis is the size of the children
+    pub fn hugs(&self, horizontal: bool) -> bool {
+        let sizing =
+            if horizontal { &self.layoutSizingHorizontal } else { &self.layoutSizingVertical };
+        sizing.as_deref() == Some("HUG")
+    }
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -121,6 +154,8 @@ pub struct Paint {
     pub scaleMode: Option<String>,
     pub imageTransform: Option<Transform>,
     pub imageRef: Option<String>,
+    #[serde(default)]
+    pub boundVariables: HashMap<String, BoundVariable>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -222,6 +257,26 @@ pub struct Frame {
     pub isMask: bool,
     #[serde(default)]
     pub isMaskOutline: bool,
+    #[serde(default)]
+    pub styles: HashMap<String, String>,
+    #[serde(default)]
+    pub boundVariables: HashMap<String, BoundVariable>,
+    /// "NONE", "HORIZONTAL", or "VERTICAL"
+    pub layoutMode: Option<String>,
+    #[serde(default)]
+    pub itemSpacing: f32,
+    #[serde(default)]
+    pub paddingLeft: f32,
+    #[serde(default)]
+    pub paddingRight: f32,
+    #[serde(default)]
+    pub paddingTop: f32,
+    #[serde(default)]
+    pub paddingBottom: f32,
+    /// "MIN", "CENTER", "MAX", or "SPACE_BETWEEN"
+    pub primaryAxisAlignItems: Option<String>,
+    /// "MIN", "CENTER", "MAX", or "BASELINE"
+    pub counterAxisAlignItems: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -274,6 +329,7 @@ pub struct VectorNode {
     pub strokeGeometry: Vec<Path>,
     pub strokeAlign: String,
     pub styles: HashMap<String, String>,
+    pub boundVariables: HashMap<String, BoundVariable>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -363,3 +419,58 @@ pub struct Style {
     pub description: String,
     pub styleType: String,
 }
+
+/// The response of the `variables/local` endpoint
+#[derive(Debug, Deserialize, Default)]
+pub struct LocalVariables {
+    pub meta: VariablesMeta,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(default)]
+pub struct VariablesMeta {
+    pub variables: HashMap<String, Variable>,
+    pub variableCollections: HashMap<String, VariableCollection>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Variable {
+    pub id: String,
+    pub name: String,
+    pub variableCollectionId: String,
+    /// One of "BOOLEAN", "FLOAT", "STRING", or "COLOR"
+    pub resolvedType: String,
+    /// The value for each mode, or a `VariableAlias` to another variable
+    pub valuesByMode: HashMap<String, serde_json::Value>,
+    #[serde(default)]
+    pub remote: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VariableCollection {
+    pub id: String,
+    pub name: String,
+    pub defaultModeId: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct VariableAlias {
+    pub id: String,
+}
+
+/// A variable bound to a property, or one variable per item for properties that are lists (like fills)
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum BoundVariable {
+    Alias(VariableAlias),
+    List(Vec<VariableAlias>),
+}
+
+impl BoundVariable {
+    pub fn first(&self) -> Option<&VariableAlias> {
+        match self {
+            BoundVariable::Alias(alias) => Some(alias),
+            BoundVariable::List(list) => list.first(),
+        }
+    }
+}
@@ -32,6 +32,7 @@ struct Opt {
 
 mod figmatypes;
 mod rendered;
+mod theme;
 
 fn fill_hash<'x>(hash: &mut HashMap<&'x str, &'x figmatypes::Node>, node: &'x figmatypes::Node) {
     let n = node.common();
@@ -98,9 +99,32 @@ struct ImageResultMeta {
         x?
     }
 
+    println!("Fetch variables...");
+    let variables = reqwest::Client::new()
+        .get(format!("https://api.figma.com/v1/files/{}/variables/local", opt.file))
+        .header("X-Figma-Token", &opt.token)
+        .send()
+        .await?;
+    if variables.status().is_success() {
+        std::fs::write("figma_output/variables.json", variables.bytes().await?)?;
+    } else {
+        // The variables API is only available on some Figma plans
+        eprintln!("Warning: could not fetch the variables: {}", variables.status());
+        let _ = std::fs::remove_file("figma_output/variables.json");
+    }
+
     Ok(r)
 }
 
+/// The variables fetched by `load_from_network`, if any
+fn load_variables() -> Result<Option<figmatypes::LocalVariables>, Box<dyn std::error::Error>> {
+    match std::fs::read("figma_output/variables.json") {
+        Ok(data) => Ok(Some(serde_json::from_slice(&data)?)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let opt = Opt::parse();
@@ -112,9 +136,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         serde_json::from_slice(&full_doc)?
     };
 
+    let theme = theme::Theme::new(&r, load_variables()?.as_ref());
+    std::fs::write("figma_output/theme.slint", theme.render()?)?;
+
     let mut nodeHash = HashMap::new();
     fill_hash(&mut nodeHash, &r.document);
-    let doc = rendered::Document { nodeHash };
+    let doc = rendered::Document { nodeHash, theme: std::rc::Rc::new(theme) };
 
     if let figmatypes::Node::DOCUMENT(document) = &r.document {
         if let figmatypes::Node::CANVAS { node, prototypeStartNodeID, backgroundColor, .. } =
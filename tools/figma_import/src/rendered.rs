@@ -2,13 +2,16 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 use crate::figmatypes::{self, *};
+use crate::theme::Theme;
 use std::collections::HashMap;
 use std::fmt::Display;
 use std::fmt::Write;
+use std::rc::Rc;
 
 pub struct Document<'doc> {
     pub nodeHash: HashMap<&'doc str, &'doc figmatypes::Node>,
     //pub images: HashMap<String, Vec<u8>>,
+    pub theme: Rc<Theme>,
 }
 
 #[derive(Default, Clone, Copy, PartialEq, Eq)]
@@ -35,11 +38,23 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     }
 }
 
+/// What positions the element being rendered
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ParentLayout {
+    /// The root element of a responsive design, which fills the window
+    Window,
+    /// A `HorizontalLayout` or `VerticalLayout` created from an auto-layout
+    Box { horizontal: bool },
+}
+
 #[derive(Default)]
 struct Ctx {
     out: String,
     indent: Indent,
     offset: Vector,
+    theme: Rc<Theme>,
+    /// None when the element is placed at its absolute position
+    layout: Option<ParentLayout>,
 }
 
 impl Ctx {
@@ -57,15 +72,51 @@ fn begin_element(
             node.name
         )?;
         self.indent += 1;
-        if let Some(bb) = absoluteBoundingBox {
-            writeln!(self, "width: {}px;", bb.width)?;
-            writeln!(self, "height: {}px;", bb.height)?;
-            writeln!(self, "x: {}px;", bb.x - self.offset.x)?;
-            writeln!(self, "y: {}px;", bb.y - self.offset.y)?;
+        let Some(bb) = absoluteBoundingBox else { return Ok(()) };
+        match self.layout {
+            None => {
+                writeln!(self, "width: {}px;", bb.width)?;
+                writeln!(self, "height: {}px;", bb.height)?;
+                writeln!(self, "x: {}px;", bb.x - self.offset.x)?;
+                writeln!(self, "y: {}px;", bb.y - self.offset.y)?;
+            }
+            Some(ParentLayout::Window) => (),
+            Some(ParentLayout::Box { horizontal }) => {
+                for (axis_horizontal, property, size) in
+                    [(true, "width", bb.width), (false, "height", bb.height)]
+                {
+                    let primary = axis_horizontal == horizontal;
+                    let fills = node.fills(axis_horizontal, primary);
+                    if !fills && !node.hugs(axis_horizontal) {
+                        writeln!(self, "{property}: {size}px;")?;
+                    }
+                    if primary {
+                        let direction = if horizontal { "horizontal" } else { "vertical" };
+                        writeln!(self, "{direction}-stretch: {};", fills as u8)?;
+                    }
+                }
+            }
         }
         Ok(())
     }
 
+    /// A length in pixels, or the theme property of the variable bound to `key`
+    fn length(
+        &self,
+        boundVariables: &HashMap<String, BoundVariable>,
+        key: &str,
+        px: f32,
+    ) -> String {
+        match boundVariables
+            .get(key)
+            .and_then(BoundVariable::first)
+            .and_then(|alias| self.theme.variable(&alias.id))
+        {
+            Some(name) => format!("Theme.{name}"),
+            None => format!("{px}px"),
+        }
+    }
+
     fn end_element(&mut self) -> std::fmt::Result {
         self.indent -= 1;
         writeln!(self, "}}")
@@ -119,12 +170,21 @@ pub fn render(
         _ => return Err(super::Error("Rendering not a frame".into()).into()),
     };
 
-    let mut ctx = Ctx::default();
+    let mut ctx = Ctx { theme: doc.theme.clone(), ..Default::default() };
+    writeln!(ctx, "import {{ Theme }} from \"theme.slint\";")?;
+    writeln!(ctx)?;
     writeln!(ctx, "App := Window {{")?;
     ctx.indent += 1;
     writeln!(ctx, "background: {};", background)?;
-    writeln!(ctx, "width: {}px;", frame.absoluteBoundingBox.width)?;
-    writeln!(ctx, "height: {}px;", frame.absoluteBoundingBox.height)?;
+    // A design with an auto-layout can be resized, so it only gives the initial size of the window
+    let size_prefix = if auto_layout_direction(frame).is_some() {
+        ctx.layout = Some(ParentLayout::Window);
+        "preferred-"
+    } else {
+        ""
+    };
+    writeln!(ctx, "{size_prefix}width: {}px;", frame.absoluteBoundingBox.width)?;
+    writeln!(ctx, "{size_prefix}height: {}px;", frame.absoluteBoundingBox.height)?;
     ctx.offset = frame.absoluteBoundingBox.origin();
     render_node(node, &mut ctx, doc)?;
     ctx.end_element()?;
@@ -135,10 +195,8 @@ pub fn render(
 fn render_frame(frame: &Frame, rc: &mut Ctx) -> Result<bool, Box<dyn std::error::Error>> {
     rc.begin_element("Rectangle", &frame.node, Some(&frame.absoluteBoundingBox))?;
     rc.offset = frame.absoluteBoundingBox.origin();
-    let mut has_background = false;
-    for p in frame.background.iter() {
-        has_background |= handle_paint(p, rc, "background")?;
-    }
+    let has_background =
+        handle_paints(&frame.background, frame.styles.get("fill"), rc, "background")?;
     if !has_background && !frame.backgroundColor.is_transparent() {
         writeln!(rc, "background: {};", frame.backgroundColor)?;
     }
@@ -148,6 +206,46 @@ fn render_frame(frame: &Frame, rc: &mut Ctx) -> Result<bool, Box<dyn std::error:
     Ok(frame.isMask)
 }
 
+/// Whether the frame's auto-layout is horizontal, or None if it has no auto-layout
+fn auto_layout_direction(frame: &Frame) -> Option<bool> {
+    match frame.layoutMode.as_deref() {
+        Some("HORIZONTAL") => Some(true),
+        Some("VERTICAL") => Some(false),
+        _ => None,
+    }
+}
+
+/// Open the layout element for the auto-layout of the frame, that will contain its children
+fn begin_auto_layout(frame: &Frame, horizontal: bool, rc: &mut Ctx) -> std::fmt::Result {
+    writeln!(rc, "{} {{", if horizontal { "HorizontalLayout" } else { "VerticalLayout" })?;
+    rc.indent += 1;
+    for (property, key, value) in [
+        ("padding-left", "paddingLeft", frame.paddingLeft),
+        ("padding-right", "paddingRight", frame.paddingRight),
+        ("padding-top", "paddingTop", frame.paddingTop),
+        ("padding-bottom", "paddingBottom", frame.paddingBottom),
+        ("spacing", "itemSpacing", frame.itemSpacing),
+    ] {
+        if value != 0. || frame.boundVariables.contains_key(key) {
+            let value = rc.length(&frame.boundVariables, key, value);
+            writeln!(rc, "{property}: {value};")?;
+        }
+    }
+    // The children that fill the layout need the default `stretch` alignment
+    let children = &frame.node.children;
+    if !children.iter().any(|c| c.common().fills(horizontal, true)) {
+        let alignment = match frame.primaryAxisAlignItems.as_deref() {
+            Some("CENTER") => "center",
+            Some("MAX") => "end",
+            Some("SPACE_BETWEEN") => "space-between",
+            _ => "start",
+        };
+        writeln!(rc, "alignment: {alignment};")?;
+    }
+    rc.layout = Some(ParentLayout::Box { horizontal });
+    Ok(())
+}
+
 fn render_vector(
     vector: &VectorNode,
     rc: &mut Ctx,
@@ -159,15 +257,11 @@ fn render_vector(
             writeln!(rc, "commands: \"{}\";", p.path)?;
             writeln!(rc, "fill-rule: {};", p.windingRule.to_ascii_lowercase())?;
             if vector.strokeWeight > 0. {
-                writeln!(rc, "stroke-width: {}px;", vector.strokeWeight)?;
-            }
-            for p in vector.strokes.iter() {
-                handle_paint(p, rc, "stroke")?;
-            }
-            for p in vector.fills.iter() {
-                handle_paint(p, rc, "fill")?;
-                if let Some(_imr) = &p.imageRef { /* */ }
+                let width = rc.length(&vector.boundVariables, "strokeWeight", vector.strokeWeight);
+                writeln!(rc, "stroke-width: {width};")?;
             }
+            handle_paints(&vector.strokes, vector.styles.get("stroke"), rc, "stroke")?;
+            handle_paints(&vector.fills, vector.styles.get("fill"), rc, "fill")?;
             rc.end_element()?;
         }
         return Ok(false);
@@ -198,15 +292,22 @@ fn render_text(
 ) -> Result<(), Box<dyn std::error::Error>> {
     rc.begin_element("Text", &vector.node, Some(&vector.absoluteBoundingBox))?;
     writeln!(rc, "text: \"{}\";", text.escape_debug())?;
-    writeln!(rc, "font-family: \"{}\";", font.fontFamily)?;
-    writeln!(rc, "font-size: {}px;", font.fontSize)?;
-    writeln!(rc, "font-weight: {};", font.fontWeight)?;
+    let theme = rc.theme.clone();
+    if let Some(prefix) = vector.styles.get("text").and_then(|id| theme.text_style(id)) {
+        for property in ["font-family", "font-size", "font-weight", "letter-spacing"] {
+            writeln!(rc, "{property}: Theme.{prefix}-{property};")?;
+        }
+    } else {
+        writeln!(rc, "font-family: \"{}\";", font.fontFamily)?;
+        let font_size = rc.length(&vector.boundVariables, "fontSize", font.fontSize);
+        writeln!(rc, "font-size: {font_size};")?;
+        writeln!(rc, "font-weight: {};", font.fontWeight)?;
+        let letter_spacing = rc.length(&vector.boundVariables, "letterSpacing", font.letterSpacing);
+        writeln!(rc, "letter-spacing: {letter_spacing};")?;
+    }
     writeln!(rc, "horizontal-alignment: {};", font.textAlignHorizontal.to_ascii_lowercase())?;
     writeln!(rc, "vertical-alignment: {};", font.textAlignVertical.to_ascii_lowercase())?;
-    writeln!(rc, "letter-spacing: {}px;", font.letterSpacing)?;
-    for p in vector.fills.iter() {
-        handle_paint(p, rc, "color")?;
-    }
+    handle_paints(&vector.fills, vector.styles.get("fill"), rc, "color")?;
     rc.end_element()?;
     Ok(())
 }
@@ -223,17 +324,18 @@ fn render_rectangle(
         // Note that figma rendering when the cornerRadius > min(height,width)/2 is different
         // than ours, so we adjust it there
         let min_edge = vector.absoluteBoundingBox.width.min(vector.absoluteBoundingBox.height);
-        writeln!(rc, "border-radius: {}px;", cornerRadius.min(min_edge / 2.))?;
-    }
-    let mut has_border = false;
-    for p in vector.strokes.iter() {
-        has_border |= handle_paint(p, rc, "border-color")?;
+        let radius =
+            rc.length(&vector.boundVariables, "topLeftRadius", cornerRadius.min(min_edge / 2.));
+        writeln!(rc, "border-radius: {radius};")?;
     }
+    let has_border =
+        handle_paints(&vector.strokes, vector.styles.get("stroke"), rc, "border-color")?;
     if vector.strokeWeight > 0. && has_border {
-        writeln!(rc, "border-width: {}px;", vector.strokeWeight)?;
+        let width = rc.length(&vector.boundVariables, "strokeWeight", vector.strokeWeight);
+        writeln!(rc, "border-width: {width};")?;
     }
+    handle_paints(&vector.fills, vector.styles.get("fill"), rc, "background")?;
     for p in vector.fills.iter() {
-        handle_paint(p, rc, "background")?;
         if let Some(imr) = &p.imageRef {
             writeln!(rc, "Image {{")?;
             writeln!(rc, "    width: 100%; height: 100%;")?;
@@ -268,9 +370,7 @@ fn render_line(
     }
 
     rc.begin_element("Rectangle", &vector.node, Some(&bb))?;
-    for p in vector.strokes.iter() {
-        handle_paint(p, rc, "background")?;
-    }
+    handle_paints(&vector.strokes, vector.styles.get("stroke"), rc, "background")?;
     rc.end_element()?;
     Ok(())
 }
@@ -280,7 +380,7 @@ fn render_node(
     rc: &mut Ctx,
     doc: &Document,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let prev_ctx = (rc.indent, rc.offset);
+    let prev_ctx = (rc.indent, rc.offset, rc.layout);
     let is_mask = match node {
         Node::FRAME(f) => render_frame(f, rc)?,
         Node::GROUP(f) => render_frame(f, rc)?,
@@ -305,11 +405,38 @@ fn render_node(
         _ => false,
     };
 
-    for x in node.common().children.iter() {
-        render_node(x, rc, doc)?;
+    rc.layout = None;
+    let children = &node.common().children;
+    let auto_layout = match node {
+        Node::FRAME(f) | Node::GROUP(f) | Node::COMPONENT(f) => {
+            auto_layout_direction(f).map(|horizontal| (f, horizontal))
+        }
+        _ => None,
+    };
+    if let Some((frame, horizontal)) = auto_layout {
+        let layout_indent = rc.indent;
+        begin_auto_layout(frame, horizontal, rc)?;
+        let is_absolute = |c: &Node| c.common().layoutPositioning.as_deref() == Some("ABSOLUTE");
+        for x in children.iter().filter(|c| !is_absolute(c)) {
+            render_node(x, rc, doc)?;
+        }
+        while rc.indent != layout_indent {
+            rc.indent -= 1;
+            writeln!(rc, "}}")?;
+        }
+        // Children with an absolute position are not part of the layout
+        rc.layout = None;
+        for x in children.iter().filter(|c| is_absolute(c)) {
+            render_node(x, rc, doc)?;
+        }
+    } else {
+        for x in children.iter() {
+            render_node(x, rc, doc)?;
+        }
     }
 
     if is_mask {
+        rc.layout = prev_ctx.2;
         return Ok(());
     }
 
@@ -318,19 +445,59 @@ fn render_node(
         writeln!(rc, "}}")?;
     }
     rc.offset = prev_ctx.1;
+    rc.layout = prev_ctx.2;
 
     Ok(())
 }
 
+/// Set the `arg` property to the theme property of the Figma `style`, if the paints have one,
+/// or else to each of the paints
+fn handle_paints(
+    paints: &[Paint],
+    style: Option<&String>,
+    rc: &mut Ctx,
+    arg: &str,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    let theme = rc.theme.clone();
+    if let Some(name) = style.and_then(|id| theme.paint_style(id)) {
+        if paints.iter().any(|p| p.visible) {
+            writeln!(rc, "{}: Theme.{};", arg, name)?;
+            return Ok(true);
+        }
+    }
+    let mut has_something = false;
+    for p in paints {
+        has_something |= handle_paint(p, rc, arg)?;
+    }
+    Ok(has_something)
+}
+
 fn handle_paint(p: &Paint, rc: &mut Ctx, arg: &str) -> Result<bool, Box<dyn std::error::Error>> {
     if !p.visible {
         return Ok(false);
     }
-    let mut has_something = false;
+    let color_variable = p
+        .boundVariables
+        .get("color")
+        .and_then(BoundVariable::first)
+        .and_then(|alias| rc.theme.variable(&alias.id))
+        .filter(|_| p.gradientStops.is_empty())
+        .map(|name| format!("Theme.{name}"));
+    match color_variable.map_or_else(|| paint_expression(p), |v| Ok(Some(v)))? {
+        Some(expression) => {
+            writeln!(rc, "{}: {};", arg, expression)?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// The expression of a solid color or a linear gradient paint
+pub fn paint_expression(p: &Paint) -> Result<Option<String>, Box<dyn std::error::Error>> {
     if !p.gradientStops.is_empty() {
         if p.r#type != "GRADIENT_LINEAR" {
             eprintln!("Warning: unsupported paint type {:?}", p.r#type);
-            return Ok(false);
+            return Ok(None);
         }
         let p1 = *p
             .gradientHandlePositions
@@ -341,17 +508,16 @@ fn handle_paint(p: &Paint, rc: &mut Ctx, arg: &str) -> Result<bool, Box<dyn std:
             .get(1)
             .ok_or_else(|| "Gradient with missing 'gradientHandlePositions'".to_string())?;
         let sub = p1 - p2;
-        write!(rc, "{}: @linear-gradient({}deg", arg, -f32::atan2(sub.x, sub.y).to_degrees())?;
+        let mut expression =
+            format!("@linear-gradient({}deg", -f32::atan2(sub.x, sub.y).to_degrees());
         for stop in &p.gradientStops {
-            write!(rc, ", {} {}", stop.color, stop.position)?;
-        }
-        writeln!(rc, ");")?;
-        has_something = true;
-    } else if let Some(color) = &p.color {
-        if !color.is_transparent() {
-            writeln!(rc, "{}: {};", arg, color)?;
-            has_something = true;
+            write!(expression, ", {} {}", stop.color, stop.position)?;
         }
+        expression.push(')');
+        Ok(Some(expression))
+    } else if let Some(color) = p.color.filter(|color| !color.is_transparent()) {
+        Ok(Some(color.to_string()))
+    } else {
+        Ok(None)
     }
-    Ok(has_something)
 }
@@ -0,0 +1,216 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! The styles and variables of the Figma file, exported as properties of a `Theme` global
+//! that the generated elements refer to.
+
+use crate::figmatypes::*;
+use crate::rendered::paint_expression;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+struct ThemeProperty {
+    name: String,
+    ty: &'static str,
+    value: String,
+}
+
+#[derive(Default)]
+pub struct Theme {
+    properties: Vec<ThemeProperty>,
+    used_names: HashSet<String>,
+    /// Property names by variable id
+    variables: HashMap<String, String>,
+    /// Property names by fill or stroke style id
+    paint_styles: HashMap<String, String>,
+    /// Property name prefixes by text style id
+    text_styles: HashMap<String, String>,
+}
+
+impl Theme {
+    pub fn new(file: &File, variables: Option<&LocalVariables>) -> Self {
+        let mut theme = Theme::default();
+        if let Some(variables) = variables {
+            theme.add_variables(&variables.meta);
+        }
+        theme.add_styles(&file.document, &file.styles);
+        theme
+    }
+
+    /// The name of the property for the variable with the given `id`
+    pub fn variable(&self, id: &str) -> Option<&str> {
+        self.variables.get(id).map(String::as_str)
+    }
+
+    /// The name of the brush property for the fill or stroke style with the given `id`
+    pub fn paint_style(&self, id: &str) -> Option<&str> {
+        self.paint_styles.get(id).map(String::as_str)
+    }
+
+    /// The prefix of the font properties for the text style with the given `id`
+    pub fn text_style(&self, id: &str) -> Option<&str> {
+        self.text_styles.get(id).map(String::as_str)
+    }
+
+    /// The content of the .slint file that declares the `Theme` global
+    pub fn render(&self) -> Result<String, std::fmt::Error> {
+        let mut out = String::new();
+        writeln!(out, "// Generated from the styles and variables of the Figma file")?;
+        writeln!(out)?;
+        writeln!(out, "export global Theme {{")?;
+        for p in &self.properties {
+            writeln!(out, "    out property <{}> {}: {};", p.ty, p.name, p.value)?;
+        }
+        writeln!(out, "}}")?;
+        Ok(out)
+    }
+
+    fn add_variables(&mut self, meta: &VariablesMeta) {
+        let mut variables = meta.variables.values().filter(|v| !v.remote).collect::<Vec<_>>();
+        variables.sort_by(|a, b| a.name.cmp(&b.name));
+        // Name all variables first, since their values may refer to each other
+        for v in &variables {
+            let name = self.unique_name(&v.name);
+            self.variables.insert(v.id.clone(), name);
+        }
+        for v in variables {
+            let ty = match v.resolvedType.as_str() {
+                "COLOR" => "color",
+                "FLOAT" => "length",
+                "STRING" => "string",
+                "BOOLEAN" => "bool",
+                _ => continue,
+            };
+            let Some(json) = meta
+                .variableCollections
+                .get(&v.variableCollectionId)
+                .and_then(|c| v.valuesByMode.get(&c.defaultModeId))
+            else {
+                continue;
+            };
+            let value = if let Ok(alias) = serde_json::from_value::<VariableAlias>(json.clone()) {
+                match self.variables.get(&alias.id) {
+                    Some(name) => name.clone(),
+                    None => continue,
+                }
+            } else {
+                match ty {
+                    "color" => match serde_json::from_value::<Color>(json.clone()) {
+                        Ok(color) => color.to_string(),
+                        Err(_) => continue,
+                    },
+                    "length" => match json.as_f64() {
+                        Some(value) => format!("{value}px"),
+                        None => continue,
+                    },
+                    "string" => match json.as_str() {
+                        Some(value) => format!("\"{}\"", value.escape_debug()),
+                        None => continue,
+                    },
+                    _ => match json.as_bool() {
+                        Some(value) => value.to_string(),
+                        None => continue,
+                    },
+                }
+            };
+            let name = self.variables[&v.id].clone();
+            self.properties.push(ThemeProperty { name, ty, value });
+        }
+    }
+
+    /// The styles of the file only have a name, so take their value from the first node that uses them
+    fn add_styles(&mut self, node: &Node, styles: &HashMap<String, Style>) {
+        match node {
+            Node::FRAME(f) | Node::GROUP(f) | Node::COMPONENT(f) => {
+                self.add_paint_style(f.styles.get("fill"), &f.background, styles)
+            }
+            Node::TEXT { vector, style, .. } => {
+                self.add_paint_style(vector.styles.get("fill"), &vector.fills, styles);
+                self.add_text_style(vector.styles.get("text"), style, styles);
+            }
+            Node::VECTOR(vector)
+            | Node::BOOLEAN_OPERATION { vector, .. }
+            | Node::STAR(vector)
+            | Node::LINE(vector)
+            | Node::ELLIPSE(vector)
+            | Node::REGULAR_POLYGON(vector)
+            | Node::RECTANGLE { vector, .. } => {
+                self.add_paint_style(vector.styles.get("fill"), &vector.fills, styles);
+                self.add_paint_style(vector.styles.get("stroke"), &vector.strokes, styles);
+            }
+            _ => (),
+        }
+        for child in node.common().children.iter() {
+            self.add_styles(child, styles);
+        }
+    }
+
+    fn add_paint_style(
+        &mut self,
+        id: Option<&String>,
+        paints: &[Paint],
+        styles: &HashMap<String, Style>,
+    ) {
+        let Some((id, style)) = id.and_then(|id| Some((id, styles.get(id)?))) else { return };
+        if self.paint_styles.contains_key(id) {
+            return;
+        }
+        let Some(value) =
+            paints.iter().filter(|p| p.visible).find_map(|p| paint_expression(p).ok().flatten())
+        else {
+            return;
+        };
+        let name = self.unique_name(&style.name);
+        self.properties.push(ThemeProperty { name: name.clone(), ty: "brush", value });
+        self.paint_styles.insert(id.clone(), name);
+    }
+
+    fn add_text_style(
+        &mut self,
+        id: Option<&String>,
+        font: &TypeStyle,
+        styles: &HashMap<String, Style>,
+    ) {
+        let Some((id, style)) = id.and_then(|id| Some((id, styles.get(id)?))) else { return };
+        if self.text_styles.contains_key(id) {
+            return;
+        }
+        let prefix = self.unique_name(&style.name);
+        for (suffix, ty, value) in [
+            ("font-family", "string", format!("\"{}\"", font.fontFamily.escape_debug())),
+            ("font-size", "length", format!("{}px", font.fontSize)),
+            ("font-weight", "int", font.fontWeight.to_string()),
+            ("letter-spacing", "length", format!("{}px", font.letterSpacing)),
+        ] {
+            let name = format!("{prefix}-{suffix}");
+            self.used_names.insert(name.clone());
+            self.properties.push(ThemeProperty { name, ty, value });
+        }
+        self.text_styles.insert(id.clone(), prefix);
+    }
+
+    /// Turn a Figma name like "Colors/Primary 500" into a unique identifier like `colors-primary-500`
+    fn unique_name(&mut self, figma_name: &str) -> String {
+        let mut name = String::new();
+        for c in figma_name.chars() {
+            if c.is_ascii_alphanumeric() {
+                name.push(c.to_ascii_lowercase());
+            } else if !name.is_empty() && !name.ends_with('-') {
+                name.push('-');
+            }
+        }
+        let name = name.trim_end_matches('-');
+        let name = match name.chars().next() {
+            None => "style".to_string(),
+            Some(c) if c.is_ascii_digit() => format!("style-{name}"),
+            Some(_) => name.to_string(),
+        };
+        let mut unique = name.clone();
+        let mut index = 1;
+        while !self.used_names.insert(unique.clone()) {
+            index += 1;
+            unique = format!("{name}-{index}");
+        }
+        unique
+    }
+}
@@ -526,6 +526,15 @@ fn change_local_data_type(
             &mut Vec<common::SingleTextEdit>,
         ),
     > = match identifier.parent().map(|p| p.kind()).unwrap_or(SyntaxKind::Error) {
+        // Globals are used by name in expressions, like the structs and enums
+        SyntaxKind::Component
+            if identifier
+                .parent()
+                .and_then(|p| p.child_text(SyntaxKind::Identifier))
+                .is_some_and(|t| t == "global") =>
+        {
+            Some(&change_local_data_type)
+        }
         SyntaxKind::Component => Some(&change_local_element_type),
         SyntaxKind::EnumDeclaration | SyntaxKind::StructDeclaration => {
             Some(&change_local_data_type)
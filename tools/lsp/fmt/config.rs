@@ -0,0 +1,145 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! The configuration of the formatter, read from a `.slint-fmt.json` file
+
+use serde::Deserialize;
+use std::path::Path;
+
+/// The name of the file that contains the configuration of the formatter.
+/// It applies to all the files in the directory that contains it and its sub-directories.
+pub const CONFIG_FILE_NAME: &str = ".slint-fmt.json";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum IndentStyle {
+    #[default]
+    Spaces,
+    Tabs,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BraceStyle {
+    /// The opening brace of an element is on the same line as its type
+    #[default]
+    SameLine,
+    /// The opening brace of an element is on its own line
+    NextLine,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PropertyOrdering {
+    /// Keep the order of the source
+    #[default]
+    Preserve,
+    /// Move the declarations of properties, callbacks, and functions before
+    /// the bindings and the child elements
+    DeclarationsFirst,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct FormatConfig {
+    /// Arrays and object literals longer than this are split over several lines
+    pub line_width: usize,
+    pub indent_style: IndentStyle,
+    /// The number of spaces per indentation level, when indenting with spaces
+    pub indent_width: usize,
+    pub brace_style: BraceStyle,
+    pub property_ordering: PropertyOrdering,
+    /// The maximum number of consecutive empty lines that are kept in elements and code blocks
+    pub max_empty_lines: usize,
+}
+
+impl Default for FormatConfig {
+    fn default() -> Self {
+        Self {
+            line_width: 80,
+            indent_style: IndentStyle::default(),
+            indent_width: 4,
+            brace_style: BraceStyle::default(),
+            property_ordering: PropertyOrdering::default(),
+            max_empty_lines: 1,
+        }
+    }
+}
+
+impl FormatConfig {
+    /// The whitespace for one level of indentation
+    pub fn indentation(&self) -> String {
+        match self.indent_style {
+            IndentStyle::Spaces => " ".repeat(self.indent_width),
+            IndentStyle::Tabs => "\t".into(),
+        }
+    }
+
+    /// Read the configuration from a file
+    pub fn from_file(path: &Path) -> std::io::Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        serde_json::from_str(&contents).map_err(|e| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, format!("{}: {e}", path.display()))
+        })
+    }
+
+    /// Find the configuration file that applies to the file at `path` by looking into its
+    /// directory and all its parent directories, and read it.
+    /// Returns the default configuration if there is no such file.
+    pub fn for_path(path: &Path) -> std::io::Result<Self> {
+        let path = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_owned());
+        match path.parent() {
+            Some(dir) => Self::for_directory(dir),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Find the configuration file that applies to the files in `dir`, and read it.
+    /// Returns the default configuration if there is no such file.
+    pub fn for_directory(dir: &Path) -> std::io::Result<Self> {
+        for dir in dir.ancestors() {
+            let config_file = dir.join(CONFIG_FILE_NAME);
+            if config_file.is_file() {
+                return Self::from_file(&config_file);
+            }
+        }
+        Ok(Self::default())
+    }
+}
+
+#[test]
+fn test_parse_config() {
+    let config: FormatConfig = serde_json::from_str(
+        r#"{ "indent-style": "tabs", "brace-style": "next-line", "max-empty-lines": 0 }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        config,
+        FormatConfig {
+            indent_style: IndentStyle::Tabs,
+            brace_style: BraceStyle::NextLine,
+            max_empty_lines: 0,
+            ..Default::default()
+        }
+    );
+    assert_eq!(config.indentation(), "\t");
+    assert_eq!(FormatConfig::default().indentation(), "    ");
+    assert!(serde_json::from_str::<FormatConfig>(r#"{ "indent": 2 }"#).is_err());
+    assert!(serde_json::from_str::<FormatConfig>(r#"{ "brace-style": "k&r" }"#).is_err());
+}
+
+#[test]
+fn test_config_for_path() {
+    let dir = std::env::temp_dir().join(format!("slint-fmt-config-test-{}", std::process::id()));
+    let sub_dir = dir.join("ui").join("widgets");
+    std::fs::create_dir_all(&sub_dir).unwrap();
+    std::fs::write(dir.join(CONFIG_FILE_NAME), r#"{ "indent-width": 2 }"#).unwrap();
+
+    let config = FormatConfig::for_path(&sub_dir.join("button.slint")).unwrap();
+    assert_eq!(config, FormatConfig { indent_width: 2, ..Default::default() });
+
+    std::fs::write(dir.join("ui").join(CONFIG_FILE_NAME), "{ invalid").unwrap();
+    assert!(FormatConfig::for_path(&sub_dir.join("button.slint")).is_err());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
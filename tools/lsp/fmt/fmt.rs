@@ -1,14 +1,20 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
+use super::config::{BraceStyle, FormatConfig, PropertyOrdering};
 use super::writer::TokenWriter;
 use i_slint_compiler::parser::{syntax_nodes, NodeOrToken, SyntaxKind, SyntaxNode};
 
 pub fn format_document(
     doc: syntax_nodes::Document,
     writer: &mut impl TokenWriter,
+    config: &FormatConfig,
 ) -> Result<(), std::io::Error> {
-    let mut state = FormatState::default();
+    let mut state = FormatState {
+        indentation: config.indentation(),
+        config: config.clone(),
+        ..FormatState::default()
+    };
     format_node(&doc, writer, &mut state)
 }
 
@@ -32,6 +38,10 @@ struct FormatState {
 
     /// a comment has been written followed maybe by some spacing
     after_comment: bool,
+
+    config: FormatConfig,
+    /// The whitespace for one level of indentation, computed from the config
+    indentation: String,
 }
 
 impl FormatState {
@@ -46,11 +56,22 @@ fn new_line(&mut self) {
         }
         let mut new_line = String::from("\n");
         for _ in 0..self.indentation_level {
-            new_line += "    ";
+            new_line += &self.indentation;
         }
         self.whitespace_to_add = Some(new_line);
     }
 
+    /// Keep up to `max-empty-lines` of the empty lines of the whitespace `token`
+    fn keep_empty_lines(&mut self, token: &NodeOrToken) {
+        let empty_lines = token
+            .as_token()
+            .map_or(0, |t| t.text().matches('\n').count().saturating_sub(1))
+            .min(self.config.max_empty_lines);
+        for _ in 0..empty_lines {
+            self.new_line();
+        }
+    }
+
     fn insert_whitespace(&mut self, arg: &str) {
         if self.after_comment {
             return;
@@ -319,9 +340,9 @@ fn format_element(
 
     let ok = if node.child_node(SyntaxKind::QualifiedName).is_some() {
         whitespace_to(&mut sub, SyntaxKind::QualifiedName, writer, state, "")?
-            && whitespace_to(&mut sub, SyntaxKind::LBrace, writer, state, " ")?
+            && whitespace_to_element_brace(&mut sub, writer, state, " ")?
     } else {
-        whitespace_to(&mut sub, SyntaxKind::LBrace, writer, state, "")?
+        whitespace_to_element_brace(&mut sub, writer, state, "")?
     };
 
     if !ok {
@@ -334,12 +355,14 @@ fn format_element(
     let ins_ctn = state.insertion_count;
     let mut inserted_newline = false;
 
-    for n in sub {
-        if n.kind() == SyntaxKind::Whitespace {
-            let is_empty_line = n.as_token().map(|n| n.text().contains("\n\n")).unwrap_or(false);
-            if is_empty_line && !inserted_newline {
-                state.new_line();
-            }
+    let children = match state.config.property_ordering {
+        PropertyOrdering::Preserve => sub.collect(),
+        PropertyOrdering::DeclarationsFirst => declarations_first(sub),
+    };
+
+    for n in children {
+        if n.kind() == SyntaxKind::Whitespace && !inserted_newline {
+            state.keep_empty_lines(&n);
         }
         inserted_newline = false;
 
@@ -367,6 +390,83 @@ fn format_element(
     Ok(())
 }
 
+/// Like `whitespace_to(sub, SyntaxKind::LBrace, ...)` for the opening brace of an element,
+/// which is put on the next line depending on the brace style
+fn whitespace_to_element_brace(
+    sub: &mut impl Iterator<Item = NodeOrToken>,
+    writer: &mut impl TokenWriter,
+    state: &mut FormatState,
+    prefix_whitespace: &str,
+) -> Result<bool, std::io::Error> {
+    match state.config.brace_style {
+        BraceStyle::SameLine => {
+            whitespace_to(sub, SyntaxKind::LBrace, writer, state, prefix_whitespace)
+        }
+        BraceStyle::NextLine => {
+            state.whitespace_to_add = None;
+            state.new_line();
+            whitespace_to(sub, SyntaxKind::LBrace, writer, state, "")
+        }
+    }
+}
+
+/// Reorder the content of an element so that the property, callback, and function
+/// declarations come first. The comments and whitespace before a node, as well as a
+/// comment that follows it on the same line, are moved together with it.
+fn declarations_first(sub: impl Iterator<Item = NodeOrToken>) -> Vec<NodeOrToken> {
+    let is_declaration = |n: &NodeOrToken| {
+        matches!(
+            n.kind(),
+            SyntaxKind::PropertyDeclaration
+                | SyntaxKind::CallbackDeclaration
+                | SyntaxKind::Function
+        )
+    };
+
+    // Each chunk is a node with the trivia around it, and whether it is a declaration
+    let mut chunks: Vec<(bool, Vec<NodeOrToken>)> = Vec::new();
+    let mut pending = Vec::new();
+    for n in sub {
+        match n.kind() {
+            SyntaxKind::Whitespace | SyntaxKind::RBrace => pending.push(n),
+            SyntaxKind::Comment => {
+                let on_same_line = pending
+                    .iter()
+                    .all(|p: &NodeOrToken| p.as_token().is_some_and(|t| !t.text().contains('\n')));
+                match chunks.last_mut() {
+                    Some((_, last)) if on_same_line => {
+                        last.append(&mut pending);
+                        last.push(n);
+                    }
+                    _ => pending.push(n),
+                }
+            }
+            _ => {
+                let declaration = is_declaration(&n);
+                pending.push(n);
+                chunks.push((declaration, std::mem::take(&mut pending)));
+            }
+        }
+    }
+    // The whitespace after the opening brace stays there
+    let split_leading_whitespace = |chunk: &mut Vec<NodeOrToken>| {
+        let len = chunk.iter().take_while(|n| n.kind() == SyntaxKind::Whitespace).count();
+        chunk.drain(..len).collect::<Vec<_>>()
+    };
+    let leading_whitespace =
+        chunks.first_mut().map(|(_, chunk)| split_leading_whitespace(chunk)).unwrap_or_default();
+    // The sort is stable, so the order within the declarations and within the rest is kept
+    chunks.sort_by_key(|(declaration, _)| !declaration);
+    if let Some((_, first)) = chunks.first_mut() {
+        split_leading_whitespace(first);
+    }
+    leading_whitespace
+        .into_iter()
+        .chain(chunks.into_iter().flat_map(|(_, chunk)| chunk))
+        .chain(pending)
+        .collect()
+}
+
 fn format_sub_element(
     node: &SyntaxNode,
     writer: &mut impl TokenWriter,
@@ -764,10 +864,7 @@ fn format_codeblock(
     for n in sub {
         state.skip_all_whitespace = true;
         if n.kind() == SyntaxKind::Whitespace {
-            let is_empty_line = n.as_token().map(|n| n.text().contains("\n\n")).unwrap_or(false);
-            if is_empty_line {
-                state.new_line();
-            }
+            state.keep_empty_lines(&n);
         } else if n.kind() == SyntaxKind::RBrace {
             state.indentation_level -= 1;
             state.whitespace_to_add = None;
@@ -904,7 +1001,7 @@ fn format_array(
     // not really accurate, e.g., [1] should have len 1, but due to this
     // it will be 3, but it doesn't matter
     let len = len + (2 * node.children().count());
-    let is_large_array = len >= 80;
+    let is_large_array = len >= state.config.line_width;
     let mut sub = node.children_with_tokens().peekable();
     whitespace_to(&mut sub, SyntaxKind::LBracket, writer, state, "")?;
 
@@ -1195,7 +1292,7 @@ fn format_object_literal(
         e.text().for_each_chunk(|s| len += s.trim().len());
         acc + len
     });
-    let is_large_literal = len >= 80;
+    let is_large_literal = len >= state.config.line_width;
 
     let mut sub = node.children_with_tokens().peekable();
     whitespace_to(&mut sub, SyntaxKind::LBrace, writer, state, "")?;
@@ -1297,12 +1394,18 @@ fn format_member_access(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fmt::config::IndentStyle;
     use crate::fmt::writer::FileWriter;
     use i_slint_compiler::diagnostics::BuildDiagnostics;
 
     // FIXME more descriptive errors when an assertion fails
     #[track_caller]
     fn assert_formatting(unformatted: &str, formatted: &str) {
+        assert_formatting_with_config(unformatted, formatted, &FormatConfig::default());
+    }
+
+    #[track_caller]
+    fn assert_formatting_with_config(unformatted: &str, formatted: &str, config: &FormatConfig) {
         // Parse the unformatted string
         let syntax_node = i_slint_compiler::parser::parse(
             String::from(unformatted),
@@ -1312,7 +1415,7 @@ fn assert_formatting(unformatted: &str, formatted: &str) {
         // Turn the syntax node into a document
         let doc = syntax_nodes::Document::new(syntax_node).unwrap();
         let mut file = Vec::new();
-        format_document(doc, &mut FileWriter { file: &mut file }).unwrap();
+        format_document(doc, &mut FileWriter { file: &mut file }, config).unwrap();
         assert_eq!(String::from_utf8(file).unwrap(), formatted);
     }
 
@@ -2140,4 +2243,125 @@ fn access_member() {
 "#,
         );
     }
+
+    #[test]
+    fn config_indentation() {
+        let source = "component X { Rectangle { x: 1px; } callback foo(); foo => { debug(1); } }";
+        let config = FormatConfig { indent_style: IndentStyle::Tabs, ..Default::default() };
+        assert_formatting_with_config(
+            source,
+            "component X {\n\tRectangle {\n\t\tx: 1px;\n\t}\n\n\tcallback foo();\n\tfoo => {\n\t\tdebug(1);\n\t}\n}\n",
+            &config,
+        );
+        let config = FormatConfig { indent_width: 2, ..Default::default() };
+        assert_formatting_with_config(
+            source,
+            r#"component X {
+  Rectangle {
+    x: 1px;
+  }
+
+  callback foo();
+  foo => {
+    debug(1);
+  }
+}
+"#,
+            &config,
+        );
+    }
+
+    #[test]
+    fn config_brace_style() {
+        let config = FormatConfig { brace_style: BraceStyle::NextLine, ..Default::default() };
+        assert_formatting_with_config(
+            "export component X inherits Window { r := Rectangle { for x in 2: Text {} } }",
+            r#"export component X inherits Window
+{
+    r := Rectangle
+    {
+        for x in 2: Text
+        { }
+    }
+}
+"#,
+            &config,
+        );
+        assert_formatting_with_config(
+            "component X { Text{} }",
+            "component X\n{\n    Text\n    { }\n}\n",
+            &config,
+        );
+    }
+
+    #[test]
+    fn config_property_ordering() {
+        let source = r#"component X {
+    width: 10px; // the width
+    Text {
+        text: "a";
+    }
+
+    // The height
+    property <length> h: 5px;
+
+    /* before */ callback clicked;
+    height: h;
+    function f() {
+    }
+}"#;
+        assert_formatting(source, &format!("{source}\n"));
+        let config = FormatConfig {
+            property_ordering: PropertyOrdering::DeclarationsFirst,
+            ..Default::default()
+        };
+        assert_formatting_with_config(
+            source,
+            r#"component X {
+    // The height
+    property <length> h: 5px;
+
+    /* before */ callback clicked;
+    function f() {
+    }
+    width: 10px; // the width
+    Text {
+        text: "a";
+    }
+
+    height: h;
+}
+"#,
+            &config,
+        );
+    }
+
+    #[test]
+    fn config_max_empty_lines() {
+        let source = "component X {\n    x: 1px;\n\n\n\n    y: 2px;\n    f => {\n        a();\n\n\n        b();\n    }\n}\n";
+        assert_formatting(
+            source,
+            "component X {\n    x: 1px;\n\n    y: 2px;\n    f => {\n        a();\n\n        b();\n    }\n}\n",
+        );
+        let config = FormatConfig { max_empty_lines: 0, ..Default::default() };
+        assert_formatting_with_config(
+            source,
+            "component X {\n    x: 1px;\n    y: 2px;\n    f => {\n        a();\n        b();\n    }\n}\n",
+            &config,
+        );
+        let config = FormatConfig { max_empty_lines: 3, ..Default::default() };
+        assert_formatting_with_config(source, source, &config);
+    }
+
+    #[test]
+    fn config_line_width() {
+        let source = "component X { x: [1, 2, 3]; y: { a: 1, b: 2 }; }";
+        assert_formatting(source, "component X {\n    x: [1, 2, 3];\n    y: { a: 1, b: 2 };\n}\n");
+        let config = FormatConfig { line_width: 5, ..Default::default() };
+        assert_formatting_with_config(
+            source,
+            "component X {\n    x: [\n        1,\n        2,\n        3\n    ];\n    y: {\n        a: 1,\n        b: 2\n    };\n}\n",
+            &config,
+        );
+    }
 }
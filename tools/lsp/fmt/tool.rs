@@ -20,24 +20,43 @@
 use std::io::{BufWriter, Write};
 use std::path::Path;
 
+use super::config::FormatConfig;
 use super::{fmt, writer};
 
-pub fn run(files: Vec<std::path::PathBuf>, inplace: bool) -> std::io::Result<()> {
+/// Format the files. The configuration is read from `config_file` if specified, otherwise
+/// from the `.slint-fmt.json` file that applies to each file.
+pub fn run(
+    files: Vec<std::path::PathBuf>,
+    inplace: bool,
+    config_file: Option<std::path::PathBuf>,
+) -> std::io::Result<()> {
+    let config = config_file.map(|f| FormatConfig::from_file(&f)).transpose()?;
     for path in files {
         let source = std::fs::read_to_string(&path)?;
+        let config = match &config {
+            Some(config) => config.clone(),
+            None if path.as_path() == Path::new("/dev/stdin") => {
+                FormatConfig::for_directory(&std::env::current_dir()?)?
+            }
+            None => FormatConfig::for_path(&path)?,
+        };
 
         if inplace {
             let file = BufWriter::new(std::fs::File::create(&path)?);
-            process_file(source, path, file)?
+            process_file(source, path, file, &config)?
         } else {
-            process_file(source, path, std::io::stdout())?
+            process_file(source, path, std::io::stdout(), &config)?
         }
     }
     Ok(())
 }
 
 /// FIXME! this is duplicated with the updater
-fn process_rust_file(source: String, mut file: impl Write) -> std::io::Result<()> {
+fn process_rust_file(
+    source: String,
+    mut file: impl Write,
+    config: &FormatConfig,
+) -> std::io::Result<()> {
     let mut last = 0;
     for range in i_slint_compiler::lexer::locate_slint_macro(&source) {
         file.write_all(source[last..=range.start].as_bytes())?;
@@ -47,7 +66,7 @@ fn process_rust_file(source: String, mut file: impl Write) -> std::io::Result<()
         let mut diag = BuildDiagnostics::default();
         let syntax_node = i_slint_compiler::parser::parse(code.to_owned(), None, &mut diag);
         let len = syntax_node.text_range().end().into();
-        visit_node(syntax_node, &mut file)?;
+        visit_node(syntax_node, &mut file, config)?;
         if diag.has_errors() {
             file.write_all(&code.as_bytes()[len..])?;
             diag.print();
@@ -57,7 +76,11 @@ fn process_rust_file(source: String, mut file: impl Write) -> std::io::Result<()
 }
 
 /// FIXME! this is duplicated with the updater
-fn process_markdown_file(source: String, mut file: impl Write) -> std::io::Result<()> {
+fn process_markdown_file(
+    source: String,
+    mut file: impl Write,
+    config: &FormatConfig,
+) -> std::io::Result<()> {
     let mut source_slice = &source[..];
     const CODE_FENCE_START: &str = "```slint\n";
     const CODE_FENCE_END: &str = "```\n";
@@ -77,7 +100,7 @@ fn process_markdown_file(source: String, mut file: impl Write) -> std::io::Resul
         let mut diag = BuildDiagnostics::default();
         let syntax_node = i_slint_compiler::parser::parse(code.to_owned(), None, &mut diag);
         let len = syntax_node.text_range().end().into();
-        visit_node(syntax_node, &mut file)?;
+        visit_node(syntax_node, &mut file, config)?;
         if diag.has_errors() {
             file.write_all(&code.as_bytes()[len..])?;
             diag.print();
@@ -90,11 +113,12 @@ fn process_slint_file(
     source: String,
     path: std::path::PathBuf,
     mut file: impl Write,
+    config: &FormatConfig,
 ) -> std::io::Result<()> {
     let mut diag = BuildDiagnostics::default();
     let syntax_node = i_slint_compiler::parser::parse(source.clone(), Some(&path), &mut diag);
     let len = syntax_node.node.text_range().end().into();
-    visit_node(syntax_node, &mut file)?;
+    visit_node(syntax_node, &mut file, config)?;
     if diag.has_errors() {
         file.write_all(&source.as_bytes()[len..])?;
         diag.print();
@@ -106,16 +130,19 @@ fn process_file(
     source: String,
     path: std::path::PathBuf,
     mut file: impl Write,
+    config: &FormatConfig,
 ) -> std::io::Result<()> {
     match path.extension() {
-        Some(ext) if ext == "rs" => process_rust_file(source, file),
-        Some(ext) if ext == "md" => process_markdown_file(source, file),
+        Some(ext) if ext == "rs" => process_rust_file(source, file, config),
+        Some(ext) if ext == "md" => process_markdown_file(source, file, config),
         // Formatting .60 files because of backwards compatibility (project was recently renamed)
-        Some(ext) if ext == "slint" || ext == ".60" => process_slint_file(source, path, file),
+        Some(ext) if ext == "slint" || ext == ".60" => {
+            process_slint_file(source, path, file, config)
+        }
         _ => {
             // This allows usage like `cat x.slint | slint-lsp format /dev/stdin`
             if path.as_path() == Path::new("/dev/stdin") {
-                return process_slint_file(source, path, file);
+                return process_slint_file(source, path, file, config);
             }
             // With other file types, we just output them in their original form.
             return file.write_all(source.as_bytes());
@@ -123,10 +150,14 @@ fn process_file(
     }
 }
 
-fn visit_node(node: SyntaxNode, file: &mut impl Write) -> std::io::Result<()> {
+fn visit_node(
+    node: SyntaxNode,
+    file: &mut impl Write,
+    config: &FormatConfig,
+) -> std::io::Result<()> {
     if let Some(doc) = syntax_nodes::Document::new(node) {
         let mut writer = writer::FileWriter { file };
-        fmt::format_document(doc, &mut writer)
+        fmt::format_document(doc, &mut writer, config)
     } else {
         Err(std::io::Error::new(std::io::ErrorKind::Other, "Not a Document"))
     }
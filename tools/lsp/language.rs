@@ -3,10 +3,16 @@
 
 // cSpell: ignore descr rfind unindented
 
+mod call_hierarchy;
 pub mod completion;
+mod document_color;
+mod extract_component;
 mod formatting;
 mod goto;
 mod hover;
+pub mod inlay_hints;
+mod references;
+mod rename;
 mod semantic_tokens;
 mod signature_help;
 #[cfg(test)]
@@ -15,6 +21,7 @@
 
 use crate::common;
 use crate::util;
+use document_color::get_document_color;
 
 #[cfg(target_arch = "wasm32")]
 use crate::wasm_prelude::*;
@@ -24,13 +31,15 @@
 };
 use i_slint_compiler::{diagnostics::BuildDiagnostics, langtype::Type};
 use lsp_types::request::{
+    CallHierarchyIncomingCalls, CallHierarchyOutgoingCalls, CallHierarchyPrepare,
     CodeActionRequest, CodeLensRequest, ColorPresentationRequest, Completion, DocumentColor,
     DocumentHighlightRequest, DocumentSymbolRequest, ExecuteCommand, Formatting, GotoDefinition,
-    HoverRequest, PrepareRenameRequest, Rename, SemanticTokensFullRequest, SignatureHelpRequest,
+    HoverRequest, InlayHintRequest, PrepareRenameRequest, References, Rename,
+    SemanticTokensFullRequest, SignatureHelpRequest,
 };
 use lsp_types::{
-    ClientCapabilities, CodeActionOrCommand, CodeActionProviderCapability, CodeLens,
-    CodeLensOptions, Color, ColorInformation, ColorPresentation, Command, CompletionOptions,
+    CallHierarchyServerCapability, ClientCapabilities, CodeActionOrCommand,
+    CodeActionProviderCapability, CodeLens, CodeLensOptions, Command, CompletionOptions,
     DocumentSymbol, DocumentSymbolResponse, InitializeParams, InitializeResult, OneOf, Position,
     PrepareRenameResponse, PublishDiagnosticsParams, RenameOptions, SemanticTokensFullOptions,
     SemanticTokensLegend, SemanticTokensOptions, ServerCapabilities, ServerInfo,
@@ -149,6 +158,7 @@ pub struct Context {
     pub to_show: RefCell<Option<common::PreviewComponent>>,
     /// File currently open in the editor
     pub open_urls: RefCell<HashSet<lsp_types::Url>>,
+    pub inlay_hints_config: RefCell<inlay_hints::InlayHintsConfig>,
 }
 
 /// An error from a LSP request
@@ -286,6 +296,9 @@ pub fn server_initialize_result(client_cap: &ClientCapabilities) -> InitializeRe
                 },
             ),
             document_formatting_provider: Some(OneOf::Left(true)),
+            references_provider: Some(OneOf::Left(true)),
+            inlay_hint_provider: Some(OneOf::Left(true)),
+            call_hierarchy_provider: Some(CallHierarchyServerCapability::Simple(true)),
             ..ServerCapabilities::default()
         },
         server_info: Some(ServerInfo {
@@ -354,10 +367,21 @@ pub fn register_request_handlers(rh: &mut RequestHandler) {
     rh.register::<CodeActionRequest, _>(|params, ctx| async move {
         let document_cache = &mut ctx.document_cache.borrow_mut();
 
-        let result = token_descr(document_cache, &params.text_document.uri, &params.range.start)
-            .and_then(|(token, _)| {
-                get_code_actions(document_cache, token, &ctx.init_param.capabilities)
-            });
+        let mut result =
+            token_descr(document_cache, &params.text_document.uri, &params.range.start).and_then(
+                |(token, _)| get_code_actions(document_cache, token, &ctx.init_param.capabilities),
+            );
+        if let Some(action) = extract_component::extract_component(
+            document_cache,
+            &params.text_document.uri,
+            params.range,
+        ) {
+            result.get_or_insert_with(Vec::new).push(CodeActionOrCommand::CodeAction(action));
+        }
+        let fix_its = get_fix_it_code_actions(document_cache, &params);
+        if !fix_its.is_empty() {
+            result.get_or_insert_with(Vec::new).extend(fix_its);
+        }
         Ok(result)
     });
     rh.register::<ExecuteCommand, _>(|params, ctx| async move {
@@ -376,29 +400,14 @@ pub fn register_request_handlers(rh: &mut RequestHandler) {
         let document_cache = &mut ctx.document_cache.borrow_mut();
         Ok(get_document_color(document_cache, &params.text_document).unwrap_or_default())
     });
-    rh.register::<ColorPresentationRequest, _>(|params, _ctx| async move {
-        // Convert the color from the color picker to a string representation. This could try to produce a minimal
-        // representation.
-        let requested_color = params.color;
-
-        let color_literal = if requested_color.alpha < 1. {
-            format!(
-                "#{:0>2x}{:0>2x}{:0>2x}{:0>2x}",
-                (requested_color.red * 255.) as u8,
-                (requested_color.green * 255.) as u8,
-                (requested_color.blue * 255.) as u8,
-                (requested_color.alpha * 255.) as u8
-            )
-        } else {
-            format!(
-                "#{:0>2x}{:0>2x}{:0>2x}",
-                (requested_color.red * 255.) as u8,
-                (requested_color.green * 255.) as u8,
-                (requested_color.blue * 255.) as u8,
-            )
-        };
-
-        Ok(vec![ColorPresentation { label: color_literal, ..Default::default() }])
+    rh.register::<ColorPresentationRequest, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        Ok(document_color::get_color_presentations(
+            document_cache,
+            &params.text_document.uri,
+            params.range,
+            &params.color,
+        ))
     });
     rh.register::<DocumentSymbolRequest, _>(|params, ctx| async move {
         let document_cache = &mut ctx.document_cache.borrow_mut();
@@ -497,24 +506,9 @@ pub fn register_request_handlers(rh: &mut RequestHandler) {
                     .collect();
                 return Ok(Some(common::create_workspace_edit(uri, version, edits)));
             }
-            match p.kind() {
-                SyntaxKind::DeclaredIdentifier => {
-                    common::rename_component::rename_identifier_from_declaration(
-                        &document_cache,
-                        &p.into(),
-                        &params.new_name,
-                    )
-                    .map(Some)
-                    .map_err(|e| LspError {
-                        code: LspErrorCode::RequestFailed,
-                        message: e.to_string(),
-                    })
-                }
-                _ => Err(LspError {
-                    code: LspErrorCode::RequestFailed,
-                    message: "This symbol cannot be renamed.".into(),
-                }),
-            }
+            rename::rename_symbol(&mut document_cache, &tk, &params.new_name)
+                .map(Some)
+                .map_err(|e| LspError { code: LspErrorCode::RequestFailed, message: e.to_string() })
         } else {
             Err(LspError {
                 code: LspErrorCode::RequestFailed,
@@ -543,9 +537,64 @@ pub fn register_request_handlers(rh: &mut RequestHandler) {
                     }
                 }
             }
+            if rename::can_rename(&mut document_cache, &tk) {
+                return Ok(Some(PrepareRenameResponse::Range(util::token_to_lsp_range(&tk))));
+            }
         }
         Ok(None)
     });
+    rh.register::<References, _>(|params, ctx| async move {
+        let mut document_cache = ctx.document_cache.borrow_mut();
+        let uri = params.text_document_position.text_document.uri;
+        let Some((tk, _)) =
+            token_descr(&mut document_cache, &uri, &params.text_document_position.position)
+        else {
+            return Ok(None);
+        };
+        if let Some(value) = find_element_id_for_highlight(&tk, &tk.parent()) {
+            return Ok(Some(
+                value
+                    .into_iter()
+                    .map(|r| lsp_types::Location {
+                        uri: uri.clone(),
+                        range: util::text_range_to_lsp_range(&tk.source_file, r),
+                    })
+                    .collect(),
+            ));
+        }
+        Ok(references::find_references_at(
+            &mut document_cache,
+            &tk,
+            params.context.include_declaration,
+        ))
+    });
+    rh.register::<CallHierarchyPrepare, _>(|params, ctx| async move {
+        let mut document_cache = ctx.document_cache.borrow_mut();
+        let uri = params.text_document_position_params.text_document.uri;
+        let Some((tk, _)) =
+            token_descr(&mut document_cache, &uri, &params.text_document_position_params.position)
+        else {
+            return Ok(None);
+        };
+        Ok(call_hierarchy::prepare(&mut document_cache, &tk))
+    });
+    rh.register::<CallHierarchyIncomingCalls, _>(|params, ctx| async move {
+        let mut document_cache = ctx.document_cache.borrow_mut();
+        Ok(call_hierarchy::incoming_calls(&mut document_cache, &params.item))
+    });
+    rh.register::<CallHierarchyOutgoingCalls, _>(|params, ctx| async move {
+        let mut document_cache = ctx.document_cache.borrow_mut();
+        Ok(call_hierarchy::outgoing_calls(&mut document_cache, &params.item))
+    });
+    rh.register::<InlayHintRequest, _>(|params, ctx| async move {
+        let document_cache = ctx.document_cache.borrow();
+        Ok(inlay_hints::get_inlay_hints(
+            &document_cache,
+            &params.text_document.uri,
+            &params.range,
+            &ctx.inlay_hints_config.borrow(),
+        ))
+    });
     rh.register::<Formatting, _>(|params, ctx| async move {
         let document_cache = ctx.document_cache.borrow_mut();
         Ok(formatting::format_document(params, &document_cache))
@@ -922,6 +971,40 @@ fn has_experimental_client_capability(capabilities: &ClientCapabilities, name: &
         .unwrap_or(false)
 }
 
+/// The code actions for the fix-its of the compiler diagnostics in the request context
+fn get_fix_it_code_actions(
+    document_cache: &common::DocumentCache,
+    params: &lsp_types::CodeActionParams,
+) -> Vec<CodeActionOrCommand> {
+    let Some(path) = common::uri_to_file(&params.text_document.uri) else { return vec![] };
+    params
+        .context
+        .diagnostics
+        .iter()
+        .flat_map(|diagnostic| {
+            let fix_its = diagnostic
+                .data
+                .clone()
+                .and_then(|data| serde_json::from_value::<Vec<util::LspFixIt>>(data).ok())
+                .unwrap_or_default();
+            fix_its.into_iter().map(|fix_it| {
+                CodeActionOrCommand::CodeAction(lsp_types::CodeAction {
+                    title: fix_it.title,
+                    kind: Some(lsp_types::CodeActionKind::QUICKFIX),
+                    diagnostics: Some(vec![diagnostic.clone()]),
+                    edit: common::create_workspace_edit_from_path(
+                        document_cache,
+                        &path,
+                        vec![fix_it.edit],
+                    ),
+                    is_preferred: Some(true),
+                    ..Default::default()
+                })
+            })
+        })
+        .collect()
+}
+
 fn get_code_actions(
     document_cache: &mut common::DocumentCache,
     token: SyntaxToken,
@@ -1161,39 +1244,6 @@ fn is_sub_element(kind: SyntaxKind) -> bool {
     (!result.is_empty()).then_some(result)
 }
 
-fn get_document_color(
-    document_cache: &mut common::DocumentCache,
-    text_document: &lsp_types::TextDocumentIdentifier,
-) -> Option<Vec<ColorInformation>> {
-    let mut result = Vec::new();
-    let doc = document_cache.get_document(&text_document.uri)?;
-    let root_node = doc.node.as_ref()?;
-    let mut token = root_node.first_token()?;
-    loop {
-        if token.kind() == SyntaxKind::ColorLiteral {
-            (|| -> Option<()> {
-                let range = util::token_to_lsp_range(&token);
-                let col = i_slint_compiler::literals::parse_color_literal(token.text())?;
-                let shift = |s: u32| -> f32 { ((col >> s) & 0xff) as f32 / 255. };
-                result.push(ColorInformation {
-                    range,
-                    color: Color {
-                        alpha: shift(24),
-                        red: shift(16),
-                        green: shift(8),
-                        blue: shift(0),
-                    },
-                });
-                Some(())
-            })();
-        }
-        token = match token.next_token() {
-            Some(token) => token,
-            None => break Some(result),
-        }
-    }
-}
-
 /// Retrieve the document outline
 fn get_document_symbols(
     document_cache: &mut common::DocumentCache,
@@ -1237,28 +1287,124 @@ fn get_document_symbols(
         })
         .collect::<Vec<_>>();
 
-    r.extend(inner_types.iter().filter_map(|c| match c {
-        Type::Struct(s) if s.name.is_some() && s.node.is_some() => Some(DocumentSymbol {
-            range: util::node_to_lsp_range(s.node.as_ref().unwrap().parent().as_ref()?),
-            selection_range: util::node_to_lsp_range(
-                &s.node.as_ref().unwrap().parent()?.child_node(SyntaxKind::DeclaredIdentifier)?,
-            ),
-            name: s.name.as_ref().unwrap().to_string(),
-            kind: lsp_types::SymbolKind::STRUCT,
-            ..ds.clone()
-        }),
-        Type::Enumeration(enumeration) => enumeration.node.as_ref().map(|node| DocumentSymbol {
-            range: util::node_to_lsp_range(node),
-            selection_range: util::node_to_lsp_range(&node.DeclaredIdentifier()),
-            name: enumeration.name.to_string(),
-            kind: lsp_types::SymbolKind::ENUM,
-            ..ds.clone()
-        }),
-        _ => None,
+    r.extend(inner_types.iter().filter_map(|c| {
+        match c {
+            Type::Struct(s) if s.name.is_some() && s.node.is_some() => Some(DocumentSymbol {
+                range: util::node_to_lsp_range(s.node.as_ref().unwrap().parent().as_ref()?),
+                selection_range: util::node_to_lsp_range(
+                    &s.node
+                        .as_ref()
+                        .unwrap()
+                        .parent()?
+                        .child_node(SyntaxKind::DeclaredIdentifier)?,
+                ),
+                name: s.name.as_ref().unwrap().to_string(),
+                kind: lsp_types::SymbolKind::STRUCT,
+                children: symbol_list(
+                    s.node
+                        .as_ref()
+                        .unwrap()
+                        .ObjectTypeMember()
+                        .filter_map(|m| {
+                            let name = i_slint_compiler::parser::identifier_text(&m)?;
+                            let selection_range =
+                                util::token_to_lsp_range(&m.child_token(SyntaxKind::Identifier)?);
+                            Some(DocumentSymbol {
+                                range: util::node_to_lsp_range(&m),
+                                selection_range,
+                                name: name.to_string(),
+                                detail: Some(m.Type().text().to_string().trim().into()),
+                                kind: lsp_types::SymbolKind::FIELD,
+                                ..ds.clone()
+                            })
+                        })
+                        .collect(),
+                ),
+                ..ds.clone()
+            }),
+            Type::Enumeration(enumeration) => {
+                enumeration.node.as_ref().map(|node| DocumentSymbol {
+                    range: util::node_to_lsp_range(node),
+                    selection_range: util::node_to_lsp_range(&node.DeclaredIdentifier()),
+                    name: enumeration.name.to_string(),
+                    kind: lsp_types::SymbolKind::ENUM,
+                    children: symbol_list(
+                        node.EnumValue()
+                            .filter_map(|v| {
+                                Some(DocumentSymbol {
+                                    range: util::node_to_lsp_range(&v),
+                                    selection_range: util::node_to_lsp_range(&v),
+                                    name: i_slint_compiler::parser::identifier_text(&v)?
+                                        .to_string(),
+                                    kind: lsp_types::SymbolKind::ENUM_MEMBER,
+                                    ..ds.clone()
+                                })
+                            })
+                            .collect(),
+                    ),
+                    ..ds.clone()
+                })
+            }
+            _ => None,
+        }
     }));
 
+    /// The symbols sorted by position, or None if there are none
+    fn symbol_list(mut r: Vec<DocumentSymbol>) -> Option<Vec<DocumentSymbol>> {
+        r.sort_by_key(|s| s.range.start);
+        (!r.is_empty()).then_some(r)
+    }
+
+    /// The properties, callbacks, functions, and states declared in the element
+    fn declarations(
+        element_node: &syntax_nodes::Element,
+        ds: &DocumentSymbol,
+    ) -> Vec<DocumentSymbol> {
+        let declaration = |node: &SyntaxNode,
+                           identifier: syntax_nodes::DeclaredIdentifier,
+                           detail: Option<String>,
+                           kind| {
+            let name = i_slint_compiler::parser::identifier_text(&identifier)?;
+            Some(DocumentSymbol {
+                range: util::node_to_lsp_range(node),
+                selection_range: util::node_to_lsp_range(&identifier),
+                name: name.to_string(),
+                detail,
+                kind,
+                ..ds.clone()
+            })
+        };
+        let type_text =
+            |ty: Option<syntax_nodes::Type>| ty.map(|t| t.text().to_string().trim().into());
+        element_node
+            .PropertyDeclaration()
+            .filter_map(|p| {
+                declaration(
+                    &p,
+                    p.DeclaredIdentifier(),
+                    type_text(p.Type()),
+                    lsp_types::SymbolKind::PROPERTY,
+                )
+            })
+            .chain(element_node.CallbackDeclaration().filter_map(|c| {
+                declaration(&c, c.DeclaredIdentifier(), None, lsp_types::SymbolKind::EVENT)
+            }))
+            .chain(element_node.Function().filter_map(|f| {
+                declaration(&f, f.DeclaredIdentifier(), None, lsp_types::SymbolKind::FUNCTION)
+            }))
+            .chain(element_node.States().flat_map(|s| s.State()).filter_map(|s| {
+                declaration(
+                    &s,
+                    s.DeclaredIdentifier(),
+                    Some("state".into()),
+                    lsp_types::SymbolKind::ENUM_MEMBER,
+                )
+            }))
+            .collect()
+    }
+
     fn gen_children(elem: &ElementRc, ds: &DocumentSymbol) -> Option<Vec<DocumentSymbol>> {
-        let r = elem
+        let mut r = elem
             .borrow()
             .children
             .iter()
@@ -1280,7 +1426,10 @@ fn gen_children(elem: &ElementRc, ds: &DocumentSymbol) -> Option<Vec<DocumentSym
                 })
             })
             .collect::<Vec<_>>();
-        (!r.is_empty()).then_some(r)
+        if let Some(debug) = elem.borrow().debug.first() {
+            r.extend(declarations(&debug.node, ds));
+        }
+        symbol_list(r)
     }
 
     r.sort_by(|a, b| a.range.start.cmp(&b.range.start));
@@ -1457,8 +1606,9 @@ pub async fn load_configuration(ctx: &Context) -> common::Result<()> {
         )?
         .await?;
 
-    let (hide_ui, include_paths, library_paths, style) = {
+    let (hide_ui, include_paths, library_paths, style, inlay_hints_config) = {
         let mut hide_ui = None;
+        let mut inlay_hints_config = inlay_hints::InlayHintsConfig::default();
         let mut include_paths = None;
         let mut library_paths = None;
         let mut style = None;
@@ -1490,10 +1640,14 @@ pub async fn load_configuration(ctx: &Context) -> common::Result<()> {
                     }
                 }
                 hide_ui = o.get("preview").and_then(|v| v.as_object()?.get("hide_ui")?.as_bool());
+                if let Some(ih) = o.get("inlayHints") {
+                    inlay_hints_config = inlay_hints::InlayHintsConfig::from_json(ih);
+                }
             }
         }
-        (hide_ui, include_paths, library_paths, style)
+        (hide_ui, include_paths, library_paths, style, inlay_hints_config)
     };
+    *ctx.inlay_hints_config.borrow_mut() = inlay_hints_config;
 
     let document_cache = &mut ctx.document_cache.borrow_mut();
     let cc = document_cache.reconfigure(style, include_paths, library_paths).await?;
@@ -1507,6 +1661,20 @@ pub async fn load_configuration(ctx: &Context) -> common::Result<()> {
     *ctx.preview_config.borrow_mut() = config.clone();
     ctx.server_notifier
         .send_message_to_preview(common::LspToPreviewMessage::SetConfiguration { config });
+
+    if ctx
+        .init_param
+        .capabilities
+        .workspace
+        .as_ref()
+        .and_then(|w| w.inlay_hint.as_ref())
+        .and_then(|ih| ih.refresh_support)
+        .unwrap_or(false)
+    {
+        ctx.server_notifier
+            .send_request::<lsp_types::request::InlayHintRefreshRequest>(())?
+            .await?;
+    }
     Ok(())
 }
 
@@ -1542,21 +1710,21 @@ fn test_reload_document_valid_contents() {
 
     #[test]
     fn test_text_document_color_no_color_set() {
-        let (mut dc, uri, _) = loaded_document_cache(
+        let (dc, uri, _) = loaded_document_cache(
             r#"
             component Main inherits Rectangle { }
             "#
             .into(),
         );
 
-        let result = get_document_color(&mut dc, &lsp_types::TextDocumentIdentifier { uri })
+        let result = get_document_color(&dc, &lsp_types::TextDocumentIdentifier { uri })
             .expect("Color Vec was returned");
         assert!(result.is_empty());
     }
 
     #[test]
     fn test_text_document_color_rgba_color() {
-        let (mut dc, uri, _) = loaded_document_cache(
+        let (dc, uri, _) = loaded_document_cache(
             r#"
             component Main inherits Rectangle {
                 background: #1200FF80;
@@ -1565,7 +1733,7 @@ fn test_text_document_color_rgba_color() {
             .into(),
         );
 
-        let result = get_document_color(&mut dc, &lsp_types::TextDocumentIdentifier { uri })
+        let result = get_document_color(&dc, &lsp_types::TextDocumentIdentifier { uri })
             .expect("Color Vec was returned");
 
         assert_eq!(result.len(), 1);
@@ -1742,16 +1910,72 @@ macro_rules! tree {
                     result[$root] $(.children.as_ref().unwrap()[$more])*
                 };
             }
-        assert_eq!(tree!(1 0).name, "HorizontalLayout");
-        check_start_with(tree!(1 0).range.start, "HorizontalLayout {");
-        check_start_with(tree!(1 0).range.end, "/*HorizontalLayout*/");
-        assert_eq!(tree!(1 0 0).name, "Button");
-        assert_eq!(tree!(1 0 0).detail, Some("btn".into()));
-        check_start_with(tree!(1 0 0).range.start, "btn := Button");
-
-        assert_eq!(tree!(1 0 1 0).name, "TouchArea");
-        assert_eq!(tree!(1 0 1 0).detail, Some("ta".into()));
-        check_start_with(tree!(1 0 1 0).range.start, "ta := TouchArea");
+        assert_eq!(tree!(0 1).name, "Def");
+        assert_eq!(tree!(0 1).kind, lsp_types::SymbolKind::ENUM_MEMBER);
+        assert_eq!(tree!(1 0).name, "the-enum");
+        assert_eq!(tree!(1 0).kind, lsp_types::SymbolKind::PROPERTY);
+        assert_eq!(tree!(1 0).detail, Some("TheEnum".into()));
+        check_start_with(tree!(1 0).range.start, "in property <TheEnum> the-enum;");
+        check_start_with(tree!(1 0).selection_range.start, "the-enum;");
+        assert_eq!(tree!(1 1).name, "HorizontalLayout");
+        check_start_with(tree!(1 1).range.start, "HorizontalLayout {");
+        check_start_with(tree!(1 1).range.end, "/*HorizontalLayout*/");
+        assert_eq!(tree!(1 1 0).name, "Button");
+        assert_eq!(tree!(1 1 0).detail, Some("btn".into()));
+        check_start_with(tree!(1 1 0).range.start, "btn := Button");
+
+        assert_eq!(tree!(1 1 1 0).name, "TouchArea");
+        assert_eq!(tree!(1 1 1 0).detail, Some("ta".into()));
+        check_start_with(tree!(1 1 1 0).range.start, "ta := TouchArea");
+        assert_eq!(tree!(2 0).name, "abc");
+        assert_eq!(tree!(2 0).kind, lsp_types::SymbolKind::FIELD);
+        assert_eq!(tree!(2 0).detail, Some("string".into()));
+        assert_eq!(tree!(3 0).name, "prop");
+    }
+
+    #[test]
+    fn test_document_symbols_declarations() {
+        let (mut dc, uri, _) = loaded_document_cache(
+            r#"
+export component Test {
+    callback clicked(int);
+    property <bool> active;
+    function toggle() { active = !active; }
+    Rectangle {
+        out property <color> color: active ? red : blue;
+    }
+    states [
+        active when active: {}
+        inactive when !active: {}
+    ]
+}"#
+            .into(),
+        );
+        let result =
+            get_document_symbols(&mut dc, &lsp_types::TextDocumentIdentifier { uri }).unwrap();
+        let DocumentSymbolResponse::Nested(result) = result else {
+            panic!("not nested {result:?}")
+        };
+        let outline = |symbols: &[DocumentSymbol]| {
+            symbols.iter().map(|s| (s.name.clone(), s.kind)).collect::<Vec<_>>()
+        };
+        use lsp_types::SymbolKind;
+        let children = result[0].children.as_ref().unwrap();
+        assert_eq!(
+            outline(children),
+            [
+                ("clicked".to_string(), SymbolKind::EVENT),
+                ("active".to_string(), SymbolKind::PROPERTY),
+                ("toggle".to_string(), SymbolKind::FUNCTION),
+                ("Rectangle".to_string(), SymbolKind::VARIABLE),
+                ("active".to_string(), SymbolKind::ENUM_MEMBER),
+                ("inactive".to_string(), SymbolKind::ENUM_MEMBER),
+            ]
+        );
+        assert_eq!(
+            outline(children[3].children.as_ref().unwrap()),
+            [("color".to_string(), SymbolKind::PROPERTY)]
+        );
     }
 
     #[test]
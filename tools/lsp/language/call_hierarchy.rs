@@ -0,0 +1,302 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Call hierarchy of the callbacks and functions.
+//!
+//! The incoming calls of a callback or function are the places where it is invoked. The outgoing
+//! calls of a callback are its handlers, and the outgoing calls of a handler, function, or
+//! binding are the callbacks and functions that it invokes.
+
+use super::references::{find_references, find_symbol, same_node, Symbol};
+use crate::common::DocumentCache;
+use crate::util;
+use i_slint_compiler::parser::{syntax_nodes, SyntaxKind, SyntaxNode, SyntaxToken};
+use lsp_types::{
+    CallHierarchyIncomingCall, CallHierarchyItem, CallHierarchyOutgoingCall, Range, SymbolKind,
+};
+
+/// The kinds of nodes that are shown as items in the call hierarchy
+const ITEM_KINDS: [SyntaxKind; 6] = [
+    SyntaxKind::CallbackDeclaration,
+    SyntaxKind::Function,
+    SyntaxKind::CallbackConnection,
+    SyntaxKind::Binding,
+    SyntaxKind::PropertyDeclaration,
+    SyntaxKind::PropertyChangedCallback,
+];
+
+fn item_node(token: &SyntaxToken) -> Option<SyntaxNode> {
+    token.parent_ancestors().find(|n| ITEM_KINDS.contains(&n.kind()))
+}
+
+/// The name of the element that contains `node`: its id, or `root`, or its type
+fn element_name(node: &SyntaxNode) -> Option<String> {
+    let mut element = node.parent()?;
+    while element.kind() != SyntaxKind::Element {
+        element = element.parent()?;
+    }
+    let parent = element.parent()?;
+    if parent.kind() == SyntaxKind::Component {
+        return Some("root".into());
+    }
+    if let Some(id) = parent.child_text(SyntaxKind::Identifier) {
+        return Some(id.to_string());
+    }
+    Some(syntax_nodes::Element::from(element).QualifiedName()?.text().to_string().trim().into())
+}
+
+fn component_name(node: &SyntaxNode) -> Option<String> {
+    let mut component = node.parent()?;
+    while component.kind() != SyntaxKind::Component {
+        component = component.parent()?;
+    }
+    Some(component.child_node(SyntaxKind::DeclaredIdentifier)?.text().to_string().trim().into())
+}
+
+fn call_hierarchy_item(node: &SyntaxNode) -> Option<CallHierarchyItem> {
+    let declared_name =
+        || node.child_node(SyntaxKind::DeclaredIdentifier)?.child_token(SyntaxKind::Identifier);
+    let (name_token, name, kind) = match node.kind() {
+        SyntaxKind::CallbackDeclaration => {
+            let token = declared_name()?;
+            let name = token.text().to_string();
+            (token, name, SymbolKind::EVENT)
+        }
+        SyntaxKind::Function => {
+            let token = declared_name()?;
+            let name = token.text().to_string();
+            (token, name, SymbolKind::FUNCTION)
+        }
+        SyntaxKind::PropertyDeclaration => {
+            let token = declared_name()?;
+            let name = token.text().to_string();
+            (token, name, SymbolKind::PROPERTY)
+        }
+        SyntaxKind::PropertyChangedCallback => {
+            let token = declared_name()?;
+            let name = format!("{}.changed {}", element_name(node)?, token.text());
+            (token, name, SymbolKind::EVENT)
+        }
+        SyntaxKind::CallbackConnection => {
+            let token = node.child_token(SyntaxKind::Identifier)?;
+            let name = format!("{}.{}", element_name(node)?, token.text());
+            (token, name, SymbolKind::EVENT)
+        }
+        SyntaxKind::Binding => {
+            let token = node.child_token(SyntaxKind::Identifier)?;
+            let name = format!("{}.{}", element_name(node)?, token.text());
+            (token, name, SymbolKind::PROPERTY)
+        }
+        _ => return None,
+    };
+    let (uri, range) = util::node_to_url_and_lsp_range(node)?;
+    Some(CallHierarchyItem {
+        name,
+        kind,
+        tags: None,
+        detail: component_name(node),
+        uri,
+        range,
+        selection_range: util::token_to_lsp_range(&name_token),
+        data: None,
+    })
+}
+
+/// Returns true if `token` is the name of the callback or function called in a function call
+fn is_call(token: &SyntaxToken) -> bool {
+    let qualified_name = token.parent();
+    if qualified_name.kind() != SyntaxKind::QualifiedName {
+        return false;
+    }
+    let Some(expression) = qualified_name.parent() else { return false };
+    expression
+        .parent()
+        .filter(|call| call.kind() == SyntaxKind::FunctionCallExpression)
+        .and_then(|call| call.child_node(SyntaxKind::Expression))
+        .is_some_and(|callee| same_node(&callee, &expression))
+}
+
+/// Groups the ranges by node, in the order of their first occurrence
+fn group_by_node(
+    calls: impl Iterator<Item = (SyntaxNode, Range)>,
+) -> Vec<(SyntaxNode, Vec<Range>)> {
+    let mut result: Vec<(SyntaxNode, Vec<Range>)> = Vec::new();
+    for (node, range) in calls {
+        match result.iter_mut().find(|(n, _)| same_node(n, &node)) {
+            Some((_, ranges)) => ranges.push(range),
+            None => result.push((node, vec![range])),
+        }
+    }
+    result
+}
+
+/// The token and the node of an item returned by [`prepare`]
+fn item_from_lsp(
+    document_cache: &DocumentCache,
+    item: &CallHierarchyItem,
+) -> Option<(SyntaxToken, SyntaxNode)> {
+    let doc = document_cache.get_document(&item.uri)?.node.as_ref()?;
+    let offset = util::lsp_position_to_text_size(&doc.source_file, item.selection_range.start);
+    let token = crate::language::token_at_offset(doc, offset)?;
+    let node = item_node(&token)?;
+    Some((token, node))
+}
+
+/// Handles the `textDocument/prepareCallHierarchy` request
+pub fn prepare(
+    document_cache: &mut DocumentCache,
+    token: &SyntaxToken,
+) -> Option<Vec<CallHierarchyItem>> {
+    // The name of a handler is the handler itself, not the callback that it handles
+    if token.parent().kind() == SyntaxKind::CallbackConnection {
+        return Some(vec![call_hierarchy_item(&token.parent())?]);
+    }
+    let node = match find_symbol(document_cache, token) {
+        Some(Symbol::Property(declaration))
+            if matches!(
+                declaration.kind(),
+                SyntaxKind::CallbackDeclaration | SyntaxKind::Function
+            ) =>
+        {
+            declaration
+        }
+        _ => item_node(token)?,
+    };
+    Some(vec![call_hierarchy_item(&node)?])
+}
+
+/// Handles the `callHierarchy/incomingCalls` request
+pub fn incoming_calls(
+    document_cache: &mut DocumentCache,
+    item: &CallHierarchyItem,
+) -> Option<Vec<CallHierarchyIncomingCall>> {
+    let (token, node) = item_from_lsp(document_cache, item)?;
+    let symbol = match node.kind() {
+        SyntaxKind::CallbackDeclaration | SyntaxKind::Function => Symbol::Property(node),
+        // A handler is called when its callback is
+        SyntaxKind::CallbackConnection => find_symbol(document_cache, &token)?,
+        _ => return Some(Vec::new()),
+    };
+    let calls = find_references(document_cache, &symbol)
+        .into_iter()
+        .filter(is_call)
+        .filter_map(|token| Some((item_node(&token)?, util::token_to_lsp_range(&token))));
+    Some(
+        group_by_node(calls)
+            .into_iter()
+            .filter_map(|(caller, from_ranges)| {
+                Some(CallHierarchyIncomingCall { from: call_hierarchy_item(&caller)?, from_ranges })
+            })
+            .collect(),
+    )
+}
+
+/// Handles the `callHierarchy/outgoingCalls` request
+pub fn outgoing_calls(
+    document_cache: &mut DocumentCache,
+    item: &CallHierarchyItem,
+) -> Option<Vec<CallHierarchyOutgoingCall>> {
+    let (_, node) = item_from_lsp(document_cache, item)?;
+    let calls: Vec<(SyntaxNode, Range)> = if node.kind() == SyntaxKind::CallbackDeclaration {
+        // The handlers of the callback
+        find_references(document_cache, &Symbol::Property(node))
+            .into_iter()
+            .filter(|token| token.parent().kind() == SyntaxKind::CallbackConnection)
+            .map(|token| (token.parent(), item.selection_range))
+            .collect()
+    } else {
+        let tokens = node
+            .descendants_with_tokens()
+            .filter_map(|element| element.into_token())
+            .filter(|token| token.kind() == SyntaxKind::Identifier)
+            .map(|token| SyntaxToken { token, source_file: node.source_file.clone() })
+            .filter(is_call)
+            .collect::<Vec<_>>();
+        tokens
+            .into_iter()
+            .filter_map(|token| match find_symbol(document_cache, &token)? {
+                Symbol::Property(declaration)
+                    if matches!(
+                        declaration.kind(),
+                        SyntaxKind::CallbackDeclaration | SyntaxKind::Function
+                    ) =>
+                {
+                    Some((declaration, util::token_to_lsp_range(&token)))
+                }
+                _ => None,
+            })
+            .collect()
+    };
+    Some(
+        group_by_node(calls.into_iter())
+            .into_iter()
+            .filter_map(|(callee, from_ranges)| {
+                Some(CallHierarchyOutgoingCall { to: call_hierarchy_item(&callee)?, from_ranges })
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prepare_in_test_document(source: &str, needle: &str) -> (DocumentCache, CallHierarchyItem) {
+        let (mut dc, uri, _) = crate::language::test::loaded_document_cache(source.into());
+        let doc = dc.get_document(&uri).unwrap().node.clone().unwrap();
+        let offset = source.find(needle).unwrap() as u32;
+        let token = crate::language::token_at_offset(&doc, offset.into()).unwrap();
+        let mut items = prepare(&mut dc, &token).unwrap();
+        assert_eq!(items.len(), 1);
+        (dc, items.remove(0))
+    }
+
+    const SOURCE: &str = r#"
+component Button {
+    callback clicked();
+    function press() { clicked(); }
+    TouchArea { clicked => { root.press(); } }
+}
+export component Test {
+    callback save(string);
+    function do-save() { save("a"); }
+    ok := Button { clicked => { root.save("b"); } }
+    Button { clicked => { do-save(); } }
+}"#;
+
+    #[test]
+    fn test_incoming_calls() {
+        let (mut dc, item) = prepare_in_test_document(SOURCE, "save(\"a\")");
+        assert_eq!(item.name, "save");
+        assert_eq!(item.kind, SymbolKind::EVENT);
+        assert_eq!(item.detail.as_deref(), Some("Test"));
+
+        let calls = incoming_calls(&mut dc, &item).unwrap();
+        let names = calls.iter().map(|c| c.from.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["do-save", "ok.clicked"]);
+        assert!(calls.iter().all(|c| c.from_ranges.len() == 1));
+
+        let (mut dc, item) = prepare_in_test_document(SOURCE, "clicked();");
+        let calls = incoming_calls(&mut dc, &item).unwrap();
+        let names = calls.iter().map(|c| c.from.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["press"]);
+    }
+
+    #[test]
+    fn test_outgoing_calls() {
+        // The handlers of the callback
+        let (mut dc, item) = prepare_in_test_document(SOURCE, "clicked();\n");
+        assert_eq!(item.name, "clicked");
+        let calls = outgoing_calls(&mut dc, &item).unwrap();
+        let names = calls.iter().map(|c| c.to.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["ok.clicked", "Button.clicked"]);
+
+        // The calls from a handler
+        let (mut dc, item) = prepare_in_test_document(SOURCE, "clicked => { do-save");
+        assert_eq!(item.name, "Button.clicked");
+        let calls = outgoing_calls(&mut dc, &item).unwrap();
+        let names = calls.iter().map(|c| c.to.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["do-save"]);
+        assert_eq!(calls[0].to.kind, SymbolKind::FUNCTION);
+    }
+}
@@ -0,0 +1,394 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! The colors shown in the editor: the color literals, including the stops of gradients,
+//! the named colors, and the color properties of globals used as palettes.
+
+use crate::common::DocumentCache;
+use crate::util;
+use i_slint_compiler::expression_tree::{BuiltinFunction, Expression, Unit};
+use i_slint_compiler::langtype::{ElementType, Type};
+use i_slint_compiler::lookup::named_colors::named_colors;
+use i_slint_compiler::parser::{
+    syntax_nodes, SyntaxKind, SyntaxNode, SyntaxToken, TextRange, TextSize,
+};
+use lsp_types::{Color, ColorInformation, ColorPresentation, TextEdit};
+
+/// How many properties of globals are followed to find the value of a color
+const MAX_INDIRECTIONS: u8 = 8;
+
+/// A color in the document
+struct DocumentColor {
+    range: TextRange,
+    /// The color as 0xAARRGGBB
+    argb: u32,
+    /// The color literal that defines the color when the color comes from a global property
+    definition: Option<SyntaxToken>,
+}
+
+fn color_to_lsp(argb: u32) -> Color {
+    let shift = |s: u32| -> f32 { ((argb >> s) & 0xff) as f32 / 255. };
+    Color { alpha: shift(24), red: shift(16), green: shift(8), blue: shift(0) }
+}
+
+fn color_from_lsp(color: &Color) -> [u8; 4] {
+    let to_u8 = |c: f32| (c.clamp(0., 1.) * 255.).round() as u8;
+    [to_u8(color.alpha), to_u8(color.red), to_u8(color.green), to_u8(color.blue)]
+}
+
+/// The value of `expression` if it is a constant number
+fn constant_number(expression: &Expression) -> Option<f64> {
+    match expression {
+        Expression::NumberLiteral(value, _) => Some(*value),
+        Expression::Cast { from, to: Type::Float32 | Type::Int32 } => constant_number(from),
+        Expression::UnaryOp { sub, op: '-' } => Some(-constant_number(sub)?),
+        Expression::BinaryExpression { lhs, rhs, op } => {
+            let (lhs, rhs) = (constant_number(lhs)?, constant_number(rhs)?);
+            match op {
+                '+' => Some(lhs + rhs),
+                '-' => Some(lhs - rhs),
+                '*' => Some(lhs * rhs),
+                '/' => Some(lhs / rhs),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// The value of `expression` if it is a constant color, following the properties of globals,
+/// and the color literal that defines it in a global
+fn constant_color(
+    document_cache: &DocumentCache,
+    expression: &Expression,
+    depth: u8,
+) -> Option<(u32, Option<SyntaxToken>)> {
+    match expression {
+        Expression::Cast { from, to: Type::Color | Type::Brush } => match &**from {
+            Expression::NumberLiteral(value, Unit::None) => Some((*value as u32, None)),
+            from => constant_color(document_cache, from, depth),
+        },
+        Expression::FunctionCall { function, arguments, .. }
+            if matches!(
+                **function,
+                Expression::BuiltinFunctionReference(BuiltinFunction::Rgb, _)
+            ) =>
+        {
+            let [r, g, b, a] = arguments.as_slice() else { return None };
+            let channel = |e| Some(constant_number(e)?.round().clamp(0., 255.) as u32);
+            let alpha = (constant_number(a)?.clamp(0., 1.) * 255.).round() as u32;
+            Some(((alpha << 24) | (channel(r)? << 16) | (channel(g)? << 8) | channel(b)?, None))
+        }
+        Expression::PropertyReference(nr) if depth > 0 => {
+            let element = nr.element();
+            let is_global =
+                element.borrow().enclosing_component.upgrade().is_some_and(|c| c.is_global());
+            if !is_global {
+                return None;
+            }
+            let expression = element.borrow().bindings.get(nr.name())?.borrow().expression.clone();
+            let expression = match expression {
+                Expression::Uncompiled(node) if node.kind() == SyntaxKind::BindingExpression => {
+                    util::with_lookup_ctx(document_cache, node.clone(), |ctx| {
+                        Expression::from_binding_expression_node(node.clone(), ctx)
+                    })?
+                }
+                expression => expression,
+            };
+            let (argb, definition) = constant_color(document_cache, &expression, depth - 1)?;
+            let literal = element
+                .borrow()
+                .debug
+                .first()
+                .and_then(|d| {
+                    d.node.PropertyDeclaration().find(|p| {
+                        i_slint_compiler::parser::identifier_text(&p.DeclaredIdentifier())
+                            .is_some_and(|n| &n == nr.name())
+                    })
+                })
+                .and_then(|p| p.BindingExpression()?.Expression())
+                .filter(|e| e.children().next().is_none())
+                .and_then(|e| e.child_token(SyntaxKind::ColorLiteral));
+            Some((argb, literal.or(definition)))
+        }
+        _ => None,
+    }
+}
+
+/// Returns true if the qualified name may be a named color or a property of a global
+fn may_be_color_name(document_cache: &DocumentCache, name: &syntax_nodes::QualifiedName) -> bool {
+    let mut identifiers =
+        name.children_with_tokens().filter(|t| t.kind() == SyntaxKind::Identifier);
+    let Some(first) = identifiers.next().and_then(|t| t.into_token()) else { return false };
+    let first = i_slint_compiler::parser::normalize_identifier(first.text());
+    if identifiers.next().is_none() {
+        return named_colors().contains_key(first.as_str());
+    }
+    if first == "Colors" {
+        return true;
+    }
+    let global_tr = document_cache.global_type_registry();
+    let tr = document_cache
+        .get_document_for_source_file(&name.source_file)
+        .map(|doc| &doc.local_registry)
+        .unwrap_or(&global_tr);
+    matches!(tr.lookup_element(&first), Ok(ElementType::Component(c)) if c.is_global())
+}
+
+fn document_colors(
+    document_cache: &DocumentCache,
+    uri: &lsp_types::Url,
+) -> Option<Vec<DocumentColor>> {
+    let doc = document_cache.get_document(uri)?;
+    let root_node: &SyntaxNode = doc.node.as_ref()?;
+    let mut result = Vec::new();
+    for token in root_node.descendants_with_tokens().filter_map(|n| n.into_token()) {
+        if token.kind() == SyntaxKind::ColorLiteral {
+            if let Some(argb) = i_slint_compiler::literals::parse_color_literal(token.text()) {
+                result.push(DocumentColor { range: token.text_range(), argb, definition: None });
+            }
+        }
+    }
+    let mut push_expression = |expression: syntax_nodes::Expression, node: &SyntaxNode| {
+        let color = util::with_lookup_ctx(document_cache, expression.clone().into(), |ctx| {
+            let expression = Expression::from_expression_node(expression, ctx);
+            constant_color(document_cache, &expression, MAX_INDIRECTIONS)
+        });
+        if let Some(Some((argb, definition))) = color {
+            let text = node.text().to_string();
+            let range = TextRange::at(node.text_range().start(), TextSize::of(text.trim_end()));
+            result.push(DocumentColor { range, argb, definition });
+        }
+    };
+    for node in root_node.descendants() {
+        let Some(expression) = node.parent().and_then(syntax_nodes::Expression::new) else {
+            continue;
+        };
+        match node.kind() {
+            SyntaxKind::QualifiedName
+                if may_be_color_name(document_cache, &node.clone().into()) =>
+            {
+                push_expression(expression, &node);
+            }
+            SyntaxKind::FunctionCallExpression => {
+                let function = syntax_nodes::FunctionCallExpression::from(node.clone())
+                    .Expression()
+                    .next()
+                    .and_then(|e| e.QualifiedName())
+                    .map(|n| n.text().to_string());
+                let is_rgb = function.is_some_and(|f| {
+                    let f = f.trim();
+                    ["rgb", "rgba", "Colors.rgb", "Colors.rgba"].contains(&f)
+                });
+                if is_rgb {
+                    push_expression(expression, &node);
+                }
+            }
+            _ => (),
+        }
+    }
+    result.sort_by_key(|c| c.range.start());
+    Some(result)
+}
+
+/// The colors of the document, for the `textDocument/documentColor` request
+pub fn get_document_color(
+    document_cache: &DocumentCache,
+    text_document: &lsp_types::TextDocumentIdentifier,
+) -> Option<Vec<ColorInformation>> {
+    let doc = document_cache.get_document(&text_document.uri)?;
+    let source_file = doc.node.as_ref()?.source_file.clone();
+    Some(
+        document_colors(document_cache, &text_document.uri)?
+            .into_iter()
+            .map(|c| ColorInformation {
+                range: util::text_range_to_lsp_range(&source_file, c.range),
+                color: color_to_lsp(c.argb),
+            })
+            .collect(),
+    )
+}
+
+fn hex_presentation([a, r, g, b]: [u8; 4]) -> String {
+    if a < 255 {
+        format!("#{r:0>2x}{g:0>2x}{b:0>2x}{a:0>2x}")
+    } else {
+        format!("#{r:0>2x}{g:0>2x}{b:0>2x}")
+    }
+}
+
+fn rgb_presentation([a, r, g, b]: [u8; 4]) -> String {
+    if a < 255 {
+        let alpha = format!("{:.2}", a as f32 / 255.);
+        let alpha = alpha.trim_end_matches('0').trim_end_matches('.');
+        format!("rgba({r}, {g}, {b}, {alpha})")
+    } else {
+        format!("rgb({r}, {g}, {b})")
+    }
+}
+
+fn named_presentation([a, r, g, b]: [u8; 4]) -> Option<&'static str> {
+    let argb = u32::from_be_bytes([a, r, g, b]);
+    // Some colors have several names (eg, `aqua` and `cyan`): pick one deterministically
+    named_colors().iter().filter(|(_, c)| **c == argb).map(|(n, _)| *n).min()
+}
+
+/// The ways to write the color picked in the editor, for the `textDocument/colorPresentation`
+/// request. The first presentation is in the same style as the color that was there before.
+pub fn get_color_presentations(
+    document_cache: &DocumentCache,
+    uri: &lsp_types::Url,
+    range: lsp_types::Range,
+    color: &Color,
+) -> Vec<ColorPresentation> {
+    let color = color_from_lsp(color);
+    let presentation = |label: String| ColorPresentation {
+        text_edit: Some(TextEdit::new(range, label.clone())),
+        label,
+        additional_text_edits: None,
+    };
+
+    let current = document_cache.get_document(uri).and_then(|doc| {
+        let node = doc.node.as_ref()?;
+        let text_range = util::lsp_range_to_text_range(&node.source_file, range);
+        let current =
+            document_colors(document_cache, uri)?.into_iter().find(|c| c.range == text_range)?;
+        let text = node.text().slice(text_range).to_string();
+        Some((current, text, node.source_file.clone()))
+    });
+    let Some((current, text, source_file)) = current else {
+        return vec![presentation(hex_presentation(color)), presentation(rgb_presentation(color))];
+    };
+
+    let mut result = Vec::new();
+    // The text edits can only be in the current document
+    if let Some(definition) =
+        current.definition.as_ref().filter(|d| d.source_file.path() == source_file.path())
+    {
+        // Keep the reference to the global and change the color where it is defined
+        result.push(ColorPresentation {
+            label: text.clone(),
+            text_edit: None,
+            additional_text_edits: Some(vec![TextEdit::new(
+                util::token_to_lsp_range(definition),
+                hex_presentation(color),
+            )]),
+        });
+    }
+    if let Some(name) = named_presentation(color) {
+        if text.starts_with("Colors.") {
+            result.push(presentation(format!("Colors.{name}")));
+        } else if text.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_') {
+            result.push(presentation(name.to_string()));
+        }
+    }
+    let hex = presentation(hex_presentation(color));
+    let rgb = presentation(rgb_presentation(color));
+    if text.starts_with("rgb") {
+        result.extend([rgb, hex]);
+    } else {
+        result.extend([hex, rgb]);
+    }
+    result
+}
+
+#[cfg(test)]
+fn colors_in_test_document(source: &str) -> (DocumentCache, lsp_types::Url, Vec<(String, String)>) {
+    let (dc, uri, _) = crate::language::test::loaded_document_cache(source.into());
+    let colors = document_colors(&dc, &uri)
+        .unwrap()
+        .into_iter()
+        .map(|c| {
+            (source[usize::from(c.range.start())..usize::from(c.range.end())].to_string(), {
+                let [a, r, g, b] = c.argb.to_be_bytes();
+                hex_presentation([a, r, g, b])
+            })
+        })
+        .collect();
+    (dc, uri, colors)
+}
+
+#[test]
+fn test_document_colors() {
+    let source = r#"
+global Palette {
+    out property <color> primary: #336699;
+    out property <brush> secondary: Colors.red;
+    out property <color> alias: primary;
+    out property <color> computed: primary.darker(20%);
+}
+export component Test inherits Rectangle {
+    in property <color> c: blue;
+    background: @linear-gradient(90deg, #3f87a6 0%, Palette.alias 50%, orange 100%);
+    Rectangle {
+        background: rgba(255, 128, 0, 50%);
+        border-color: Colors.rgb(10, 20, 30);
+        drop-shadow-color: Palette.computed;
+    }
+    Text { text: "red"; color: Palette.secondary; }
+}"#;
+    let (_, _, colors) = colors_in_test_document(source);
+    let expected = [
+        ("#336699", "#336699"),
+        ("Colors.red", "#ff0000"),
+        ("blue", "#0000ff"),
+        ("#3f87a6", "#3f87a6"),
+        ("Palette.alias", "#336699"),
+        ("orange", "#ffa500"),
+        ("rgba(255, 128, 0, 50%)", "#ff800080"),
+        ("Colors.rgb(10, 20, 30)", "#0a141e"),
+        ("Palette.secondary", "#ff0000"),
+    ];
+    assert_eq!(
+        colors,
+        expected.iter().map(|(a, b)| (a.to_string(), b.to_string())).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_color_presentations() {
+    let source = r#"
+global Palette {
+    out property <color> primary: #336699;
+}
+export component Test inherits Rectangle {
+    background: Palette.primary;
+    border-color: Colors.red;
+    Rectangle { background: rgb(1, 2, 3); border-color: #123; }
+}"#;
+    let (dc, uri, _) = colors_in_test_document(source);
+    let doc = dc.get_document(&uri).unwrap().node.clone().unwrap();
+    let range = |needle: &str| {
+        let offset = source.find(needle).unwrap() as u32;
+        util::text_range_to_lsp_range(
+            &doc.source_file,
+            TextRange::at(offset.into(), TextSize::of(needle)),
+        )
+    };
+    let presentations = |needle: &str, color: Color| {
+        get_color_presentations(&dc, &uri, range(needle), &color)
+            .into_iter()
+            .map(|p| p.label)
+            .collect::<Vec<_>>()
+    };
+    let lime = Color { red: 0., green: 1., blue: 0., alpha: 1. };
+    let transparent_red = Color { red: 1., green: 0., blue: 0., alpha: 0.5 };
+
+    let result = get_color_presentations(&dc, &uri, range("Palette.primary"), &lime);
+    assert_eq!(result[0].label, "Palette.primary");
+    assert_eq!(result[0].text_edit, None);
+    assert_eq!(
+        result[0].additional_text_edits,
+        Some(vec![TextEdit::new(range("#336699"), "#00ff00".into())])
+    );
+    assert_eq!(result[1].label, "#00ff00");
+    assert_eq!(
+        result[1].text_edit,
+        Some(TextEdit::new(range("Palette.primary"), "#00ff00".into()))
+    );
+
+    assert_eq!(presentations("Colors.red", lime), ["Colors.lime", "#00ff00", "rgb(0, 255, 0)"]);
+    assert_eq!(presentations("Colors.red", transparent_red), ["#ff000080", "rgba(255, 0, 0, 0.5)"]);
+    assert_eq!(presentations("rgb(1, 2, 3)", lime), ["rgb(0, 255, 0)", "#00ff00"]);
+    assert_eq!(presentations("#123", lime), ["#00ff00", "rgb(0, 255, 0)"]);
+}
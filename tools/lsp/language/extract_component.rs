@@ -0,0 +1,507 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! The "Extract component" code action.
+//!
+//! The selected element and its children are moved into a new component that inherits the type
+//! of the element. The properties and callbacks of the enclosing component that are used in the
+//! extracted elements become `in` properties and callbacks of the new component, and the
+//! properties of the extracted elements that are used outside become `out` properties.
+
+use super::references::same_node;
+use crate::common::{self, DocumentCache};
+use crate::util;
+use i_slint_compiler::expression_tree::Expression;
+use i_slint_compiler::langtype::{Function, Type};
+use i_slint_compiler::lookup::{LookupObject, LookupResult};
+use i_slint_compiler::object_tree::ElementRc;
+use i_slint_compiler::parser::{
+    normalize_identifier, syntax_nodes, SyntaxKind, SyntaxNode, TextRange, TextSize,
+};
+use lsp_types::{CodeAction, CodeActionKind, TextEdit};
+use smol_str::SmolStr;
+use std::rc::Rc;
+
+/// How a property or callback of one side of the extraction is used on the other side
+enum LiftedKind {
+    Property { write: bool },
+    Callback(Function),
+}
+
+struct Lifted {
+    /// The element and the name of the property, or of the model data or index
+    key: (*const (), SmolStr),
+    /// The name of the property or callback in the new component
+    name: String,
+    ty: Type,
+    kind: LiftedKind,
+    /// How the property is accessed from the side where it is declared
+    source: String,
+}
+
+/// A reference to a property or callback in an expression: the range of the qualified name
+/// that designates it, and what it refers to
+struct Reference {
+    range: TextRange,
+    element: ElementRc,
+    name: SmolStr,
+    ty: Type,
+    is_model: bool,
+    write: bool,
+}
+
+/// Returns true if the element is declared in `node`. If `strict` is true, `node` itself doesn't count.
+fn is_inside(element: &ElementRc, node: &SyntaxNode, strict: bool) -> bool {
+    element.borrow().debug.iter().any(|d| {
+        d.node.source_file.path() == node.source_file.path()
+            && node.text_range().contains_range(d.node.text_range())
+            && (!strict || d.node.text_range() != node.text_range())
+    })
+}
+
+/// Resolve the property, callback, or model data designated by the qualified name of an expression
+fn resolve(document_cache: &DocumentCache, qualified_name: &SyntaxNode) -> Option<Reference> {
+    let expression = qualified_name.parent().filter(|n| n.kind() == SyntaxKind::Expression)?;
+    let write = expression.parent().is_some_and(|p| match p.kind() {
+        SyntaxKind::TwoWayBinding => true,
+        SyntaxKind::SelfAssignment => {
+            p.child_node(SyntaxKind::Expression).is_some_and(|lhs| same_node(&lhs, &expression))
+        }
+        _ => false,
+    });
+    util::with_lookup_ctx(document_cache, qualified_name.clone(), |ctx| {
+        let mut result = None;
+        for token in qualified_name
+            .children_with_tokens()
+            .filter_map(|t| t.into_token())
+            .filter(|t| t.kind() == SyntaxKind::Identifier)
+        {
+            let name = normalize_identifier(token.text());
+            result = match result {
+                None => i_slint_compiler::lookup::global_lookup().lookup(ctx, &name),
+                Some(r) => LookupObject::lookup(&r, ctx, &name),
+            };
+            let range =
+                TextRange::new(qualified_name.text_range().start(), token.text_range().end());
+            match &result {
+                Some(LookupResult::Expression {
+                    expression: Expression::ElementReference(_),
+                    ..
+                }) => continue,
+                Some(LookupResult::Expression {
+                    expression:
+                        e @ (Expression::PropertyReference(nr)
+                        | Expression::CallbackReference(nr, _)
+                        | Expression::FunctionReference(nr, _)),
+                    ..
+                }) => {
+                    return Some(Reference {
+                        range,
+                        element: nr.element(),
+                        name: nr.name().clone(),
+                        ty: e.ty(),
+                        is_model: false,
+                        write,
+                    })
+                }
+                Some(LookupResult::Expression {
+                    expression:
+                        e @ (Expression::RepeaterModelReference { element }
+                        | Expression::RepeaterIndexReference { element }),
+                    ..
+                }) => {
+                    return Some(Reference {
+                        range,
+                        element: element.upgrade()?,
+                        name,
+                        ty: e.ty(),
+                        is_model: true,
+                        write: false,
+                    })
+                }
+                _ => return None,
+            }
+        }
+        None
+    })?
+}
+
+/// The references of the expressions in `node`
+fn references(document_cache: &DocumentCache, node: &SyntaxNode) -> Vec<Reference> {
+    node.descendants()
+        .filter(|n| n.kind() == SyntaxKind::QualifiedName)
+        .filter_map(|n| resolve(document_cache, &n))
+        .collect()
+}
+
+/// The element to extract: the innermost sub-element that contains the selection, or whose
+/// type name is under the cursor
+fn element_to_extract(document: &syntax_nodes::Document, range: TextRange) -> Option<SyntaxNode> {
+    let token = crate::language::token_at_offset(document, range.start())?;
+    if range.is_empty() {
+        let element = token.parent().parent()?;
+        return (token.parent().kind() == SyntaxKind::QualifiedName
+            && element.kind() == SyntaxKind::Element
+            && element.parent()?.kind() == SyntaxKind::SubElement)
+            .then_some(element);
+    }
+    let element = token.parent_ancestors().find(|n| {
+        n.kind() == SyntaxKind::Element
+            && n.text_range().contains_range(range)
+            && n.parent().is_some_and(|p| p.kind() == SyntaxKind::SubElement)
+    });
+    element
+}
+
+/// A name that is not in `used`, based on `name`
+fn unique_name(name: &str, used: &mut Vec<String>) -> String {
+    let mut candidate = name.to_string();
+    let mut counter = 1;
+    while used.contains(&candidate) {
+        counter += 1;
+        candidate = format!("{name}-{counter}");
+    }
+    used.push(candidate.clone());
+    candidate
+}
+
+/// The whitespace at the start of the line that contains `offset`
+fn line_indent(text: &str, offset: usize) -> &str {
+    let line_start = text[..offset].rfind('\n').map_or(0, |p| p + 1);
+    let line = &text[line_start..];
+    &line[..line.len() - line.trim_start().len()]
+}
+
+/// Where to insert the new component: before the top-level item that contains `node`,
+/// and the comments attached to it
+fn insertion_offset(node: &SyntaxNode) -> Option<TextSize> {
+    let mut top_level = node.clone();
+    while top_level.parent()?.kind() != SyntaxKind::Document {
+        top_level = top_level.parent()?;
+    }
+    let mut offset = top_level.text_range().start();
+    let mut previous = top_level.first_token()?.prev_token();
+    while let Some(token) = previous {
+        match token.kind() {
+            SyntaxKind::Comment => offset = token.text_range().start(),
+            SyntaxKind::Whitespace if token.text().matches('\n').count() <= 1 => {}
+            _ => break,
+        }
+        previous = token.prev_token();
+    }
+    Some(offset)
+}
+
+fn callback_signature(function: &Function) -> String {
+    let args = function.args.iter().map(|a| a.to_string()).collect::<Vec<_>>().join(", ");
+    match function.return_type {
+        Type::Void => format!("({args})"),
+        ref ty => format!("({args}) -> {ty}"),
+    }
+}
+
+/// Returns the "Extract component" code action for the element at the selection `range`
+pub fn extract_component(
+    document_cache: &mut DocumentCache,
+    uri: &lsp_types::Url,
+    range: lsp_types::Range,
+) -> Option<CodeAction> {
+    let doc = document_cache.get_document(uri)?;
+    let document = doc.node.clone()?;
+    let source_file = document.source_file.clone();
+    let range = TextRange::new(
+        util::lsp_position_to_text_size(&source_file, range.start),
+        util::lsp_position_to_text_size(&source_file, range.end),
+    );
+    let element = element_to_extract(&document, range)?;
+    let sub_element = element.parent()?;
+    let mut component = sub_element.clone();
+    while component.kind() != SyntaxKind::Component {
+        component = component.parent()?;
+    }
+    let source = source_file.source()?;
+
+    let mut component_name = "NewComponent".to_string();
+    let mut counter = 1;
+    while doc.local_registry.lookup_element(&component_name).is_ok() {
+        counter += 1;
+        component_name = format!("NewComponent{counter}");
+    }
+
+    // The names that can't be used for the properties of the new component
+    let mut used_names = {
+        let ty = util::lookup_current_element_type(element.clone(), &doc.local_registry);
+        let mut used: Vec<String> = ty
+            .map(|ty| ty.property_list().into_iter().map(|(n, _)| n.to_string()).collect())
+            .unwrap_or_default();
+        used.extend(
+            element
+                .children()
+                .filter(|n| {
+                    matches!(
+                        n.kind(),
+                        SyntaxKind::PropertyDeclaration
+                            | SyntaxKind::CallbackDeclaration
+                            | SyntaxKind::Function
+                    )
+                })
+                .filter_map(|n| n.child_node(SyntaxKind::DeclaredIdentifier))
+                .map(|n| normalize_identifier(n.text().to_string().trim()).to_string()),
+        );
+        used.extend(["root", "parent", "self"].map(String::from));
+        used
+    };
+
+    let mut lifted: Vec<Lifted> = Vec::new();
+    let mut lift = |reference: &Reference, source: String, lifted: &mut Vec<Lifted>| {
+        let key = (Rc::as_ptr(&reference.element) as *const (), reference.name.clone());
+        if let Some(l) = lifted.iter_mut().find(|l| l.key == key) {
+            if let LiftedKind::Property { write } = &mut l.kind {
+                *write |= reference.write;
+            }
+            return l.name.clone();
+        }
+        let kind = match &reference.ty {
+            Type::Callback(f) | Type::Function(f) => LiftedKind::Callback((**f).clone()),
+            _ => LiftedKind::Property { write: reference.write },
+        };
+        let name = unique_name(&reference.name, &mut used_names);
+        lifted.push(Lifted { key, name: name.clone(), ty: reference.ty.clone(), kind, source });
+        name
+    };
+
+    // The properties of the enclosing component that are used in the extracted elements
+    let mut inner_edits = Vec::new();
+    for reference in references(document_cache, &element) {
+        let is_outside = if reference.is_model {
+            !is_inside(&reference.element, &element, true)
+        } else {
+            !is_inside(&reference.element, &element, false)
+        };
+        if is_outside {
+            let source = source[reference.range].to_string();
+            let name = lift(&reference, source, &mut lifted);
+            inner_edits.push((reference.range, name));
+        }
+    }
+    let inputs = lifted.len();
+
+    // The properties of the extracted elements that are used in the rest of the component
+    let element_id = sub_element
+        .child_token(SyntaxKind::Identifier)
+        .map(|t| t.text().to_string())
+        .unwrap_or_else(|| {
+            let mut id = String::new();
+            for c in component_name.chars() {
+                if c.is_uppercase() && !id.is_empty() {
+                    id.push('-');
+                }
+                id.push(c.to_ascii_lowercase());
+            }
+            id
+        });
+    let mut outer_edits = Vec::new();
+    for reference in references(document_cache, &component) {
+        if !element.text_range().contains_range(reference.range)
+            && !reference.is_model
+            && is_inside(&reference.element, &element, true)
+        {
+            let source = source[reference.range].to_string();
+            let name = lift(&reference, source, &mut lifted);
+            outer_edits.push((reference.range, format!("{element_id}.{name}")));
+        }
+    }
+
+    // The new component
+    let indent = line_indent(source, usize::from(element.text_range().start()));
+    let mut declarations = String::new();
+    for (i, l) in lifted.iter().enumerate() {
+        let declaration = match &l.kind {
+            LiftedKind::Callback(f) if i < inputs => {
+                format!("callback {}{};", l.name, callback_signature(f))
+            }
+            LiftedKind::Callback(_) => format!("callback {} <=> {};", l.name, l.source),
+            LiftedKind::Property { write } if i < inputs => {
+                format!("{} property <{}> {};", if *write { "in-out" } else { "in" }, l.ty, l.name)
+            }
+            LiftedKind::Property { write } => format!(
+                "{} property <{}> {} <=> {};",
+                if *write { "in-out" } else { "out" },
+                l.ty,
+                l.name,
+                l.source
+            ),
+        };
+        declarations.push_str(&format!("\n{indent}    {declaration}"));
+    }
+    let lbrace = element.child_token(SyntaxKind::LBrace)?;
+    let mut declarations_range = TextRange::empty(lbrace.text_range().end());
+    match lbrace.next_token() {
+        Some(t) if t.kind() == SyntaxKind::Whitespace && t.text().contains('\n') => {}
+        // Go to the next line if the element is written on one line
+        next if !declarations.is_empty() => {
+            if let Some(t) = next.filter(|t| t.kind() == SyntaxKind::Whitespace) {
+                declarations_range = declarations_range.cover(t.text_range());
+            }
+            declarations.push_str(&format!("\n{indent}    "));
+        }
+        _ => {}
+    }
+    inner_edits.push((declarations_range, declarations));
+    inner_edits.sort_by_key(|(r, _)| r.start());
+    let mut element_text = String::new();
+    let mut position = element.text_range().start();
+    for (range, text) in &inner_edits {
+        element_text.push_str(&source[TextRange::new(position, range.start())]);
+        element_text.push_str(text);
+        position = range.end();
+    }
+    element_text.push_str(&source[TextRange::new(position, element.text_range().end())]);
+    let element_text = element_text
+        .lines()
+        .map(|line| line.strip_prefix(indent).unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let new_component = format!("component {component_name} inherits {element_text}\n\n");
+
+    // The instantiation of the new component, with the bindings of its `in` properties
+    let mut bindings = String::new();
+    for l in &lifted[..inputs] {
+        let binding = match &l.kind {
+            LiftedKind::Callback(f) => {
+                let args = (0..f.args.len()).map(|i| format!("arg{i}")).collect::<Vec<_>>();
+                let args = args.join(", ");
+                format!("{}({args}) => {{ {}({args}) }}", l.name, l.source)
+            }
+            LiftedKind::Property { write: true } => format!("{} <=> {};", l.name, l.source),
+            LiftedKind::Property { write: false } => format!("{}: {};", l.name, l.source),
+        };
+        bindings.push_str(&format!("\n{indent}    {binding}"));
+    }
+    let id = if sub_element.child_token(SyntaxKind::Identifier).is_none() && !outer_edits.is_empty()
+    {
+        format!("{element_id} := ")
+    } else {
+        String::new()
+    };
+    let instantiation = if bindings.is_empty() {
+        format!("{id}{component_name} {{}}")
+    } else {
+        format!("{id}{component_name} {{{bindings}\n{indent}}}")
+    };
+
+    let insert_at = util::text_range_to_lsp_range(
+        &source_file,
+        TextRange::empty(insertion_offset(&component)?),
+    );
+    let mut edits = vec![
+        TextEdit::new(insert_at, new_component),
+        TextEdit::new(
+            util::text_range_to_lsp_range(&source_file, element.text_range()),
+            instantiation,
+        ),
+    ];
+    edits.extend(outer_edits.into_iter().map(|(range, text)| {
+        TextEdit::new(util::text_range_to_lsp_range(&source_file, range), text)
+    }));
+
+    Some(CodeAction {
+        title: "Extract component".into(),
+        kind: Some(CodeActionKind::REFACTOR_EXTRACT),
+        edit: common::create_workspace_edit_from_path(document_cache, source_file.path(), edits),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+fn extract_in_test_document(source: &str, needle: &str) -> Option<String> {
+    let (mut dc, uri, _) = crate::language::test::loaded_document_cache(source.into());
+    let offset = TextSize::new(source.find(needle).unwrap() as u32);
+    let doc = dc.get_document(&uri).unwrap().node.clone().unwrap();
+    let position = util::text_range_to_lsp_range(&doc.source_file, TextRange::empty(offset)).start;
+    let action = extract_component(&mut dc, &uri, lsp_types::Range::new(position, position))?;
+    let edited = common::text_edit::apply_workspace_edit(&dc, &action.edit.unwrap()).unwrap();
+    assert_eq!(edited.len(), 1);
+    let result = edited[0].contents.clone();
+
+    // The result must compile without errors
+    let (_, _, diagnostics) = crate::language::test::loaded_document_cache(result.clone());
+    for diagnostic in diagnostics.values().flatten() {
+        assert_ne!(
+            diagnostic.severity,
+            Some(lsp_types::DiagnosticSeverity::ERROR),
+            "{}\n{result}",
+            diagnostic.message
+        );
+    }
+    Some(result)
+}
+
+#[test]
+fn test_extract_component() {
+    let source = r#"
+/// The main window
+export component Test {
+    in property <string> title;
+    in-out property <int> count;
+    callback clicked(int);
+    HorizontalLayout {
+        spacing: 4px;
+        Text { text: root.title; }
+        touch := TouchArea {
+            clicked => {
+                count += 1;
+                root.clicked(count);
+            }
+        }
+    }
+    Text { text: touch.pressed ? "pressed" : ""; }
+    for item[index] in [1, 2] : Text { text: item + index; }
+}"#;
+
+    assert_eq!(
+        extract_in_test_document(source, "HorizontalLayout").unwrap(),
+        r#"
+component NewComponent inherits HorizontalLayout {
+    in property <string> title;
+    in-out property <int> count;
+    callback clicked(int);
+    out property <bool> pressed <=> touch.pressed;
+    spacing: 4px;
+    Text { text: title; }
+    touch := TouchArea {
+        clicked => {
+            count += 1;
+            clicked(count);
+        }
+    }
+}
+
+/// The main window
+export component Test {
+    in property <string> title;
+    in-out property <int> count;
+    callback clicked(int);
+    new-component := NewComponent {
+        title: root.title;
+        count <=> count;
+        clicked(arg0) => { root.clicked(arg0) }
+    }
+    Text { text: new-component.pressed ? "pressed" : ""; }
+    for item[index] in [1, 2] : Text { text: item + index; }
+}"#
+    );
+
+    // The model data and index of a repeated element
+    let result = extract_in_test_document(source, "Text { text: item").unwrap();
+    assert!(result.contains(
+        "component NewComponent inherits Text {\n    in property <float> item;\n    in property <int> index;\n    text: item + index; }\n"
+    ), "{result}");
+    assert!(
+        result.contains("for item[index] in [1, 2] : NewComponent {\n        item: item;\n        index: index;\n    }\n"),
+        "{result}"
+    );
+
+    // Not on an element
+    assert!(extract_in_test_document(source, "spacing").is_none());
+}
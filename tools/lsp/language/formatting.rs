@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 use crate::common::DocumentCache;
+use crate::fmt::config::FormatConfig;
 use crate::fmt::{fmt, writer};
 use crate::util::text_range_to_lsp_range;
 use dissimilar::Chunk;
@@ -37,8 +38,11 @@ pub fn format_document(
     let doc = document_cache.get_document(&params.text_document.uri)?;
     let doc = doc.node.as_ref()?;
 
+    // An invalid configuration file is ignored, the formatting request has no way to report it
+    let config = FormatConfig::for_path(doc.source_file.path()).unwrap_or_default();
+
     let mut writer = StringWriter { text: String::new() };
-    fmt::format_document(doc.clone(), &mut writer).ok()?;
+    fmt::format_document(doc.clone(), &mut writer, &config).ok()?;
 
     let original: String = doc.text().into();
     let diff = dissimilar::diff(&original, &writer.text);
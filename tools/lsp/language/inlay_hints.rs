@@ -0,0 +1,321 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Inlay hints: the types of the properties and model data that are inferred, the default
+//! values that bindings override, and the size constraints that the children of layouts
+//! inherit from their component.
+
+use crate::common::DocumentCache;
+use crate::util;
+use i_slint_compiler::diagnostics::SourceFile;
+use i_slint_compiler::expression_tree::Expression;
+use i_slint_compiler::langtype::{ElementType, Type};
+use i_slint_compiler::object_tree::{recurse_elem, ElementRc};
+use i_slint_compiler::parser::{identifier_text, syntax_nodes, SyntaxKind, TextRange, TextSize};
+use lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, TextEdit};
+
+/// Which kinds of inlay hints are shown, from the `slint.inlayHints` configuration
+#[derive(Clone, Debug)]
+pub struct InlayHintsConfig {
+    /// The type of the properties declared without a type, and of the model data of repeaters
+    pub types: bool,
+    /// The default value of the properties that have a binding
+    pub default_values: bool,
+    /// The size constraints that the children of layouts get from their base component
+    pub layout_sizes: bool,
+}
+
+impl Default for InlayHintsConfig {
+    fn default() -> Self {
+        Self { types: true, default_values: true, layout_sizes: true }
+    }
+}
+
+impl InlayHintsConfig {
+    /// Read the configuration from the `inlayHints` object of the `slint` section
+    pub fn from_json(value: &serde_json::Value) -> Self {
+        let get = |name: &str| value.get(name).and_then(|v| v.as_bool()).unwrap_or(true);
+        Self {
+            types: get("types"),
+            default_values: get("defaultValues"),
+            layout_sizes: get("layoutSizes"),
+        }
+    }
+}
+
+/// The properties that constrain the size of an element in a layout
+const LAYOUT_SIZE_PROPERTIES: [&str; 8] = [
+    "width",
+    "height",
+    "min-width",
+    "min-height",
+    "preferred-width",
+    "preferred-height",
+    "max-width",
+    "max-height",
+];
+
+const MAX_VALUE_LENGTH: usize = 30;
+
+/// Format the source code of an expression to be shown on one line
+fn shorten(text: &str) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let text = text.strip_suffix(';').unwrap_or(&text);
+    if text.chars().count() > MAX_VALUE_LENGTH {
+        format!("{}…", text.chars().take(MAX_VALUE_LENGTH - 1).collect::<String>())
+    } else {
+        text.to_string()
+    }
+}
+
+/// The source of the literals that are used as default values of builtin properties
+fn literal_text(expression: &Expression) -> Option<String> {
+    match expression {
+        Expression::NumberLiteral(value, unit) => Some(format!("{value}{unit}")),
+        Expression::BoolLiteral(value) => Some(value.to_string()),
+        Expression::StringLiteral(value) => Some(format!("{value:?}")),
+        Expression::EnumerationValue(value) => Some(value.to_string()),
+        Expression::Cast { from, .. } => literal_text(from),
+        _ => None,
+    }
+}
+
+/// The text of the binding of `name` in the element, if it has one
+fn binding_text(element: &ElementRc, name: &str) -> Option<String> {
+    let element = element.borrow();
+    let node = &element.debug.first()?.node;
+    let expression = node
+        .Binding()
+        .find(|b| identifier_text(b).as_deref() == Some(name))
+        .map(|b| b.BindingExpression())
+        .or_else(|| {
+            node.PropertyDeclaration()
+                .find(|p| identifier_text(&p.DeclaredIdentifier()).as_deref() == Some(name))
+                .and_then(|p| p.BindingExpression())
+        })?;
+    Some(shorten(&expression.text().to_string()))
+}
+
+/// The value that a property of `element` has when it has no binding
+fn default_value(base_type: &ElementType, element: &ElementRc, name: &str) -> Option<String> {
+    match base_type {
+        ElementType::Component(c) => {
+            let root = &c.root_element;
+            binding_text(root, name).or_else(|| default_value(&root.borrow().base_type, root, name))
+        }
+        ElementType::Builtin(b) => {
+            literal_text(&b.properties.get(name)?.default_value.expr(element)?)
+        }
+        _ => None,
+    }
+}
+
+fn is_layout(element_type: &ElementType) -> bool {
+    match element_type {
+        ElementType::Component(c) => is_layout(&c.root_element.borrow().base_type),
+        ElementType::Builtin(b) => {
+            matches!(b.name.as_str(), "HorizontalLayout" | "VerticalLayout" | "GridLayout")
+        }
+        _ => false,
+    }
+}
+
+fn hint(source_file: &SourceFile, offset: TextSize, label: String) -> InlayHint {
+    InlayHint {
+        position: util::text_range_to_lsp_range(source_file, TextRange::empty(offset)).start,
+        label: InlayHintLabel::String(label),
+        kind: None,
+        text_edits: None,
+        tooltip: None,
+        padding_left: Some(true),
+        padding_right: None,
+        data: None,
+    }
+}
+
+fn element_hints(
+    document_cache: &DocumentCache,
+    element: &ElementRc,
+    parent: Option<&ElementRc>,
+    config: &InlayHintsConfig,
+    result: &mut Vec<InlayHint>,
+) {
+    let e = element.borrow();
+    let Some(node) = e.debug.first().map(|d| d.node.clone()) else { return };
+    let source_file = &node.source_file;
+
+    if config.types {
+        for declaration in node.PropertyDeclaration().filter(|p| p.Type().is_none()) {
+            let ty = match (declaration.BindingExpression(), declaration.TwoWayBinding()) {
+                (Some(binding), _) => {
+                    util::with_lookup_ctx(document_cache, binding.clone().into(), |ctx| {
+                        Expression::from_binding_expression_node(binding.into(), ctx).ty()
+                    })
+                }
+                (None, Some(binding)) => {
+                    util::with_lookup_ctx(document_cache, binding.clone().into(), |ctx| {
+                        Expression::from_expression_node(binding.Expression(), ctx).ty()
+                    })
+                }
+                (None, None) => None,
+            };
+            let Some(ty) = ty.filter(|ty| ty.is_property_type()) else { continue };
+            let offset = declaration.DeclaredIdentifier().text_range().start();
+            let label = format!("<{ty}>");
+            result.push(InlayHint {
+                kind: Some(InlayHintKind::TYPE),
+                text_edits: Some(vec![TextEdit::new(
+                    util::text_range_to_lsp_range(source_file, TextRange::empty(offset)),
+                    format!("{label} "),
+                )]),
+                padding_left: None,
+                padding_right: Some(true),
+                ..hint(source_file, offset, label)
+            });
+        }
+        let repeated =
+            node.parent().and_then(|n| n.parent()).and_then(syntax_nodes::RepeatedElement::new);
+        if let Some(repeated) = repeated {
+            let model_data =
+                repeated.DeclaredIdentifier().and_then(|d| d.child_token(SyntaxKind::Identifier));
+            let model = repeated.Expression();
+            let ty = util::with_lookup_ctx(document_cache, model.clone().into(), |ctx| {
+                Expression::from_expression_node(model, ctx).ty()
+            });
+            let ty = match ty {
+                Some(Type::Array(ty)) => Some((*ty).clone()),
+                Some(Type::Int32 | Type::Float32) => Some(Type::Int32),
+                _ => None,
+            };
+            if let (Some(model_data), Some(ty)) = (model_data, ty) {
+                result.push(InlayHint {
+                    kind: Some(InlayHintKind::TYPE),
+                    padding_left: None,
+                    ..hint(source_file, model_data.text_range().end(), format!(": {ty}"))
+                });
+            }
+        }
+    }
+
+    if config.default_values {
+        for binding in node.Binding() {
+            let Some(name) = identifier_text(&binding) else { continue };
+            if e.property_declarations.contains_key(&name) {
+                continue;
+            }
+            if let Some(value) = default_value(&e.base_type, element, &name) {
+                result.push(hint(
+                    source_file,
+                    binding.text_range().end(),
+                    format!("default: {value}"),
+                ));
+            }
+        }
+    }
+
+    if config.layout_sizes && parent.is_some_and(|p| is_layout(&p.borrow().base_type)) {
+        let constraints = LAYOUT_SIZE_PROPERTIES
+            .iter()
+            .filter(|name| !node.Binding().any(|b| identifier_text(&b).as_deref() == Some(**name)))
+            .filter_map(|name| {
+                Some(format!("{name}: {}", default_value(&e.base_type, element, name)?))
+            })
+            .collect::<Vec<_>>();
+        if let (false, Some(type_name)) = (constraints.is_empty(), node.QualifiedName()) {
+            let end = type_name.text_range().start()
+                + TextSize::of(type_name.text().to_string().trim_end());
+            result.push(hint(source_file, end, constraints.join(", ")));
+        }
+    }
+}
+
+/// Returns the inlay hints of the document in the given range
+pub fn get_inlay_hints(
+    document_cache: &DocumentCache,
+    uri: &lsp_types::Url,
+    range: &lsp_types::Range,
+    config: &InlayHintsConfig,
+) -> Option<Vec<InlayHint>> {
+    let doc = document_cache.get_document(uri)?;
+    let source_file = doc.node.as_ref()?.source_file.clone();
+    let mut result = Vec::new();
+    for component in &doc.inner_components {
+        recurse_elem(&component.root_element, &None, &mut |element, parent: &Option<ElementRc>| {
+            let is_in_document = element
+                .borrow()
+                .debug
+                .first()
+                .is_some_and(|d| d.node.source_file.path() == source_file.path());
+            if is_in_document {
+                element_hints(document_cache, element, parent.as_ref(), config, &mut result);
+            }
+            Some(element.clone())
+        });
+    }
+    result.retain(|h| range.start <= h.position && h.position <= range.end);
+    result.sort_by_key(|h| h.position);
+    Some(result)
+}
+
+#[test]
+fn test_inlay_hints() {
+    let source = r#"
+component Button inherits Rectangle {
+    in property <string> text;
+    in property <length> radius: 4px;
+    min-width: 64px;
+    preferred-height: max(32px, 2rem);
+    background: gray;
+}
+export component Test {
+    property inferred: 42;
+    property <[string]> items;
+    VerticalLayout {
+        Button { text: "ok"; radius: 2px; }
+        for item in items: Button { text: item; min-width: 10px; }
+    }
+    TouchArea { enabled: false; }
+}"#;
+    let (dc, uri, _) = crate::language::test::loaded_document_cache(source.into());
+    let doc = dc.get_document(&uri).unwrap().node.clone().unwrap();
+    let hints = |config: &InlayHintsConfig| {
+        let range = lsp_types::Range::new(
+            lsp_types::Position::new(0, 0),
+            lsp_types::Position::new(u32::MAX, 0),
+        );
+        get_inlay_hints(&dc, &uri, &range, config)
+            .unwrap()
+            .into_iter()
+            .map(|h| {
+                let InlayHintLabel::String(label) = h.label else { unreachable!() };
+                let offset = util::lsp_position_to_text_size(&doc.source_file, h.position);
+                (label, source[usize::from(offset)..].lines().next().unwrap().to_string())
+            })
+            .collect::<Vec<_>>()
+    };
+    let expected = |v: &[(&str, &str)]| {
+        v.iter().map(|(a, b)| (a.to_string(), b.to_string())).collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        hints(&InlayHintsConfig::default()),
+        expected(&[
+            ("<float>", "inferred: 42;"),
+            (
+                "min-width: 64px, preferred-height: max(32px, 2rem)",
+                " { text: \"ok\"; radius: 2px; }"
+            ),
+            ("default: 4px", " }"),
+            (": string", " in items: Button { text: item; min-width: 10px; }"),
+            ("preferred-height: max(32px, 2rem)", " { text: item; min-width: 10px; }"),
+            ("default: 64px", " }"),
+            ("default: true", " }"),
+        ])
+    );
+
+    let config = InlayHintsConfig { types: false, default_values: true, layout_sizes: false };
+    assert_eq!(
+        hints(&config),
+        expected(&[("default: 4px", " }"), ("default: 64px", " }"), ("default: true", " }")])
+    );
+}
@@ -0,0 +1,358 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Find the declaration of the symbol at a token, and all the places where it is used in the
+//! loaded documents.
+
+use crate::common::DocumentCache;
+use crate::language::token_info::{token_info, TokenInfo};
+use crate::util;
+use i_slint_compiler::langtype::{ElementType, Struct, Type};
+use i_slint_compiler::lookup::{LookupObject, LookupResult};
+use i_slint_compiler::parser::{
+    identifier_text, normalize_identifier, syntax_nodes, SyntaxKind, SyntaxNode, SyntaxToken,
+};
+use smol_str::SmolStr;
+use std::rc::Rc;
+
+#[cfg(target_arch = "wasm32")]
+use crate::wasm_prelude::*;
+
+/// A symbol that can be referenced from other places
+pub enum Symbol {
+    /// The identifier of the declaration of a component, global, struct, or enum
+    Type(syntax_nodes::DeclaredIdentifier),
+    /// A `PropertyDeclaration`, `CallbackDeclaration`, or `Function`
+    Property(SyntaxNode),
+    /// A field of the struct declared with this `ObjectType`
+    StructField(syntax_nodes::ObjectType, SmolStr),
+}
+
+impl Symbol {
+    pub fn declaration(&self) -> &SyntaxNode {
+        match self {
+            Symbol::Type(identifier) => identifier,
+            Symbol::Property(node) => node,
+            Symbol::StructField(object_type, _) => object_type,
+        }
+    }
+
+    pub fn name(&self) -> Option<SmolStr> {
+        match self {
+            Symbol::Type(identifier) => identifier_text(identifier),
+            Symbol::Property(node) => {
+                identifier_text(&node.child_node(SyntaxKind::DeclaredIdentifier)?)
+            }
+            Symbol::StructField(_, name) => Some(name.clone()),
+        }
+    }
+
+    fn is_same(&self, other: &Symbol) -> bool {
+        match (self, other) {
+            (Symbol::Type(a), Symbol::Type(b)) => same_node(a, b),
+            (Symbol::Property(a), Symbol::Property(b)) => same_node(a, b),
+            (Symbol::StructField(a, a_name), Symbol::StructField(b, b_name)) => {
+                a_name == b_name && same_node(a, b)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns true if `token` is the name of the symbol in its declaration
+    pub fn is_declared_at(&self, token: &SyntaxToken) -> bool {
+        let parent = token.parent();
+        match self {
+            Symbol::Type(identifier) => same_node(&parent, identifier),
+            Symbol::Property(node) => {
+                parent.kind() == SyntaxKind::DeclaredIdentifier
+                    && parent.parent().is_some_and(|p| same_node(&p, node))
+            }
+            Symbol::StructField(object_type, _) => {
+                parent.kind() == SyntaxKind::ObjectTypeMember
+                    && parent.parent().is_some_and(|p| same_node(&p, object_type))
+            }
+        }
+    }
+}
+
+pub fn same_node(a: &SyntaxNode, b: &SyntaxNode) -> bool {
+    a.text_range() == b.text_range() && a.source_file.path() == b.source_file.path()
+}
+
+pub fn is_builtin(node: &SyntaxNode) -> bool {
+    node.source_file.path().to_string_lossy().starts_with("builtin:")
+}
+
+/// Returns the node that declares the property `name` in `element_type` or its base types
+pub fn declaration_in_type(mut element_type: ElementType, name: &str) -> Option<SyntaxNode> {
+    while let ElementType::Component(c) = element_type {
+        if let Some(p) = c.root_element.borrow().property_declarations.get(name) {
+            return p.node.clone();
+        }
+        element_type = c.root_element.borrow().base_type.clone();
+    }
+    None
+}
+
+fn property_symbol(token_info: TokenInfo) -> Option<Symbol> {
+    let declaration = match token_info {
+        TokenInfo::NamedReference(nr) => {
+            let element = nr.element();
+            let declaration =
+                element.borrow().property_declarations.get(nr.name()).and_then(|d| d.node.clone());
+            declaration
+                .or_else(|| declaration_in_type(element.borrow().base_type.clone(), nr.name()))
+        }
+        TokenInfo::LocalProperty(p) => Some(p.into()),
+        TokenInfo::LocalCallback(c) => Some(c.into()),
+        TokenInfo::IncompleteNamedReference(element_type, name) => {
+            declaration_in_type(element_type, &name)
+        }
+        _ => None,
+    }?;
+    Some(Symbol::Property(declaration))
+}
+
+fn component_symbol(component: &i_slint_compiler::object_tree::Component) -> Option<Symbol> {
+    let identifier = component.node.as_ref()?.child_node(SyntaxKind::DeclaredIdentifier)?;
+    Some(Symbol::Type(identifier.into()))
+}
+
+fn struct_field(s: &Struct, name: SmolStr) -> Option<Symbol> {
+    let object_type = s.node.clone()?;
+    // The fields of anonymous structs are not renamed
+    let is_declared = object_type.parent()?.kind() == SyntaxKind::StructDeclaration;
+    (is_declared && s.fields.contains_key(&name)).then_some(Symbol::StructField(object_type, name))
+}
+
+/// The type that is expected for the `ObjectLiteral`, when it is the value of a binding, or of
+/// a field of another object literal
+fn object_literal_type(document_cache: &DocumentCache, literal: &SyntaxNode) -> Option<Type> {
+    let mut node = literal.parent()?;
+    loop {
+        match node.kind() {
+            SyntaxKind::Expression => node = node.parent()?,
+            SyntaxKind::CodeBlock if node.children().count() == 1 => node = node.parent()?,
+            _ => break,
+        }
+    }
+    match node.kind() {
+        SyntaxKind::BindingExpression => {
+            util::with_lookup_ctx(document_cache, node, |ctx| ctx.property_type.clone())
+        }
+        SyntaxKind::ObjectMember => {
+            let Type::Struct(s) = object_literal_type(document_cache, &node.parent()?)? else {
+                return None;
+            };
+            s.fields.get(&identifier_text(&node)?).cloned()
+        }
+        _ => None,
+    }
+}
+
+/// If `token` is a member of a struct in the `QualifiedName` of an expression (like `title` in
+/// `root.item.title`), return that struct field
+fn struct_field_access(
+    document_cache: &DocumentCache,
+    qualified_name: &SyntaxNode,
+    token: &SyntaxToken,
+) -> Option<Symbol> {
+    let identifiers = qualified_name
+        .children_with_tokens()
+        .filter_map(|t| t.into_token())
+        .filter(|t| t.kind() == SyntaxKind::Identifier)
+        .collect::<Vec<_>>();
+    let index = identifiers.iter().position(|t| t.token == token.token)?;
+    if index == 0 {
+        return None;
+    }
+    let ty = util::with_lookup_ctx(document_cache, qualified_name.clone(), |ctx| {
+        let global = i_slint_compiler::lookup::global_lookup();
+        let mut result = global.lookup(ctx, &normalize_identifier(identifiers[0].text()))?;
+        for identifier in &identifiers[1..index] {
+            result = result.lookup(ctx, &normalize_identifier(identifier.text()))?;
+        }
+        match result {
+            LookupResult::Expression { expression, .. } => Some(expression.ty()),
+            _ => None,
+        }
+    })??;
+    let Type::Struct(s) = ty else { return None };
+    struct_field(&s, normalize_identifier(token.text()))
+}
+
+/// Returns the symbol at `token`
+pub fn find_symbol(document_cache: &mut DocumentCache, token: &SyntaxToken) -> Option<Symbol> {
+    if token.kind() != SyntaxKind::Identifier {
+        return None;
+    }
+    let parent = token.parent();
+    match parent.kind() {
+        SyntaxKind::DeclaredIdentifier => {
+            let declaration = parent.parent()?;
+            return match declaration.kind() {
+                SyntaxKind::Component
+                | SyntaxKind::StructDeclaration
+                | SyntaxKind::EnumDeclaration => Some(Symbol::Type(parent.into())),
+                SyntaxKind::PropertyDeclaration
+                | SyntaxKind::CallbackDeclaration
+                | SyntaxKind::Function => Some(Symbol::Property(declaration)),
+                SyntaxKind::PropertyChangedCallback => {
+                    property_symbol(token_info(document_cache, token.clone())?)
+                }
+                _ => None,
+            };
+        }
+        SyntaxKind::ObjectTypeMember => {
+            let object_type = syntax_nodes::ObjectType::new(parent.parent()?)?;
+            let is_declared = object_type.parent()?.kind() == SyntaxKind::StructDeclaration;
+            return is_declared
+                .then(|| Symbol::StructField(object_type, normalize_identifier(token.text())));
+        }
+        SyntaxKind::ObjectMember => {
+            let Type::Struct(s) = object_literal_type(document_cache, &parent.parent()?)? else {
+                return None;
+            };
+            return struct_field(&s, normalize_identifier(token.text()));
+        }
+        SyntaxKind::QualifiedName
+            if parent.parent().is_some_and(|p| p.kind() == SyntaxKind::Expression) =>
+        {
+            if let Some(field) = struct_field_access(document_cache, &parent, token) {
+                return Some(field);
+            }
+        }
+        _ => {}
+    }
+
+    match token_info(document_cache, token.clone())? {
+        TokenInfo::ElementType(ElementType::Component(c)) => component_symbol(&c),
+        TokenInfo::ElementRc(e) => {
+            // The name of a global used in an expression
+            let component = e.borrow().enclosing_component.upgrade()?;
+            if component.is_global() && Rc::ptr_eq(&component.root_element, &e) {
+                component_symbol(&component)
+            } else {
+                None
+            }
+        }
+        TokenInfo::Type(Type::Struct(s)) => {
+            let identifier =
+                s.node.as_ref()?.parent()?.child_node(SyntaxKind::DeclaredIdentifier)?;
+            Some(Symbol::Type(identifier.into()))
+        }
+        TokenInfo::Type(Type::Enumeration(e)) => {
+            Some(Symbol::Type(e.node.as_ref()?.DeclaredIdentifier()))
+        }
+        token_info => property_symbol(token_info),
+    }
+}
+
+/// All the identifiers in the loaded documents that are `name`
+fn identifier_tokens(document_cache: &DocumentCache, name: &str) -> Vec<SyntaxToken> {
+    let mut result = Vec::new();
+    for (url, doc) in document_cache.all_url_documents() {
+        if url.scheme() == "builtin" {
+            continue;
+        }
+        result.extend(
+            doc.descendants_with_tokens()
+                .filter_map(|element| element.into_token())
+                .filter(|t| {
+                    t.kind() == SyntaxKind::Identifier && normalize_identifier(t.text()) == name
+                })
+                .map(|token| SyntaxToken { token, source_file: doc.source_file.clone() }),
+        );
+    }
+    result
+}
+
+/// All the places where `symbol` is used in the loaded documents, including its declaration
+pub fn find_references(document_cache: &mut DocumentCache, symbol: &Symbol) -> Vec<SyntaxToken> {
+    let Some(name) = symbol.name() else { return Vec::new() };
+    identifier_tokens(document_cache, &name)
+        .into_iter()
+        .filter(|token| find_symbol(document_cache, token).is_some_and(|s| s.is_same(symbol)))
+        .collect()
+}
+
+/// The locations of the references of the symbol at `token`, for the `textDocument/references`
+/// request
+pub fn find_references_at(
+    document_cache: &mut DocumentCache,
+    token: &SyntaxToken,
+    include_declaration: bool,
+) -> Option<Vec<lsp_types::Location>> {
+    let symbol = find_symbol(document_cache, token)?;
+    let references = find_references(document_cache, &symbol);
+    Some(
+        references
+            .iter()
+            .filter(|token| include_declaration || !symbol.is_declared_at(token))
+            .filter_map(|token| {
+                Some(lsp_types::Location {
+                    uri: lsp_types::Url::from_file_path(token.source_file.path()).ok()?,
+                    range: util::token_to_lsp_range(token),
+                })
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+fn references_in_test_document(source: &str, needle: &str, include_declaration: bool) -> Vec<u32> {
+    let (mut dc, uri, _) = crate::language::test::loaded_document_cache(source.into());
+    let doc = dc.get_document(&uri).unwrap().node.clone().unwrap();
+    let offset = source.find(needle).unwrap() as u32;
+    let token = crate::language::token_at_offset(&doc, offset.into()).unwrap();
+    let locations = find_references_at(&mut dc, &token, include_declaration).unwrap();
+    let mut offsets = locations
+        .iter()
+        .map(|l| {
+            assert_eq!(l.uri, uri);
+            u32::from(util::lsp_position_to_text_size(&doc.source_file, l.range.start))
+        })
+        .collect::<Vec<_>>();
+    offsets.sort();
+    offsets
+}
+
+#[test]
+fn test_find_references() {
+    let source = r#"
+component Abc {
+    in-out property <string> hello;
+    callback clicked(string);
+    Text { text: root.hello; }
+    TouchArea { clicked => { root.clicked(hello); } }
+}
+export component Test {
+    property <string> other <=> abc.hello;
+    abc := Abc {
+        hello <=> other;
+        clicked(x) => { other = x; }
+    }
+    Text { text: abc.hello; }
+}"#;
+    let offsets_of = |needles: &[&str]| {
+        let mut offsets =
+            needles.iter().map(|n| source.find(n).unwrap() as u32).collect::<Vec<_>>();
+        offsets.sort();
+        offsets
+    };
+
+    let hello = offsets_of(&[
+        "hello;\n    callback",
+        "hello; }",
+        "hello); }",
+        "hello;\n    abc :=",
+        "hello <=> other",
+        "hello; }\n}",
+    ]);
+    assert_eq!(references_in_test_document(source, "hello <=> other", true), hello);
+    assert_eq!(references_in_test_document(source, "hello;\n    callback", false), hello[1..]);
+
+    // The `clicked` of the TouchArea is a different callback
+    let clicked = offsets_of(&["clicked(string)", "clicked(hello)", "clicked(x)"]);
+    assert_eq!(references_in_test_document(source, "clicked(x)", true), clicked);
+}
@@ -0,0 +1,239 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Rename of properties, callbacks, functions, and struct fields in all the loaded documents,
+//! and of components, globals, structs, and enums from any place where they are used.
+
+use super::references::{
+    declaration_in_type, find_references, find_symbol, is_builtin, same_node, Symbol,
+};
+use crate::common::{self, DocumentCache};
+use crate::util;
+use i_slint_compiler::diagnostics::Spanned;
+use i_slint_compiler::langtype::ElementType;
+use i_slint_compiler::parser::{
+    identifier_text, normalize_identifier, syntax_nodes, SyntaxNode, SyntaxToken,
+};
+
+/// Returns true if the symbol at `token` can be renamed with [`rename_symbol`]
+pub fn can_rename(document_cache: &mut DocumentCache, token: &SyntaxToken) -> bool {
+    find_symbol(document_cache, token).is_some_and(|symbol| !is_builtin(symbol.declaration()))
+}
+
+fn check_identifier(name: &str) -> crate::Result<()> {
+    let mut chars = name.chars();
+    if chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+    {
+        Ok(())
+    } else {
+        Err(format!("'{name}' is not a valid identifier").into())
+    }
+}
+
+fn declares(element: &syntax_nodes::Element, name: &str) -> bool {
+    element
+        .PropertyDeclaration()
+        .any(|p| identifier_text(&p.DeclaredIdentifier()).as_deref() == Some(name))
+        || element
+            .CallbackDeclaration()
+            .any(|c| identifier_text(&c.DeclaredIdentifier()).as_deref() == Some(name))
+        || element
+            .Function()
+            .any(|f| identifier_text(&f.DeclaredIdentifier()).as_deref() == Some(name))
+}
+
+/// Rename the symbol at `token` everywhere it is used in the loaded documents
+pub fn rename_symbol(
+    document_cache: &mut DocumentCache,
+    token: &SyntaxToken,
+    new_name: &str,
+) -> crate::Result<lsp_types::WorkspaceEdit> {
+    let Some(symbol) = find_symbol(document_cache, token) else {
+        return Err("This symbol cannot be renamed.".into());
+    };
+    if is_builtin(symbol.declaration()) {
+        return Err("Symbols from the builtin files cannot be renamed.".into());
+    }
+    check_identifier(new_name)?;
+    let old_name = symbol.name().ok_or("The symbol has no name")?;
+    if old_name == normalize_identifier(new_name) {
+        return Ok(lsp_types::WorkspaceEdit::default());
+    }
+    match &symbol {
+        Symbol::Type(identifier) => {
+            return common::rename_component::rename_identifier_from_declaration(
+                document_cache,
+                identifier,
+                new_name,
+            )
+        }
+        Symbol::Property(declaration) => {
+            check_property_conflicts(document_cache, declaration, &old_name, new_name)?
+        }
+        Symbol::StructField(object_type, _) => {
+            if object_type.ObjectTypeMember().any(|member| {
+                identifier_text(&member).is_some_and(|n| n == normalize_identifier(new_name))
+            }) {
+                return Err(format!("The struct already has a field named '{new_name}'").into());
+            }
+        }
+    }
+
+    let edits = find_references(document_cache, &symbol)
+        .iter()
+        .map(|token| text_edit(document_cache, token, new_name))
+        .collect::<crate::Result<Vec<_>>>()?;
+    Ok(common::create_workspace_edit_from_single_text_edits(edits))
+}
+
+fn check_property_conflicts(
+    document_cache: &DocumentCache,
+    declaration: &SyntaxNode,
+    old_name: &str,
+    new_name: &str,
+) -> crate::Result<()> {
+    let normalized_new_name = normalize_identifier(new_name);
+
+    // Check for conflicts in the element that declares the property and in its base type ...
+    let element = declaration
+        .parent()
+        .and_then(syntax_nodes::Element::new)
+        .ok_or("The declaration is not in an element")?;
+    let declares_new_name = |element: &syntax_nodes::Element| {
+        let global_tr = document_cache.global_type_registry();
+        let tr = element
+            .source_file()
+            .and_then(|sf| document_cache.get_document_for_source_file(sf))
+            .map(|doc| &doc.local_registry)
+            .unwrap_or(&global_tr);
+        let element_type = util::lookup_current_element_type((**element).clone(), tr);
+        (declares(element, &normalized_new_name), element_type)
+    };
+    let (declared, element_type) = declares_new_name(&element);
+    let is_global = matches!(element_type, Some(ElementType::Global));
+    if declared
+        || (!is_global
+            && i_slint_compiler::typeregister::reserved_property(&normalized_new_name).is_valid())
+        || element_type.is_some_and(|ty| ty.lookup_property(&normalized_new_name).is_valid())
+    {
+        return Err(format!("'{new_name}' is already declared in this element").into());
+    }
+    // ... and in the elements that use the component of the property
+    for (url, doc) in document_cache.all_url_documents() {
+        if url.scheme() == "builtin" {
+            continue;
+        }
+        for element in doc.descendants().filter_map(syntax_nodes::Element::new) {
+            let (declared, element_type) = declares_new_name(&element);
+            if declared
+                && element_type
+                    .and_then(|ty| declaration_in_type(ty, old_name))
+                    .is_some_and(|d| same_node(&d, declaration))
+            {
+                return Err(format!(
+                    "'{new_name}' is already declared in an element that uses this property"
+                )
+                .into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn text_edit(
+    document_cache: &DocumentCache,
+    token: &SyntaxToken,
+    new_name: &str,
+) -> crate::Result<common::SingleTextEdit> {
+    common::SingleTextEdit::from_path(
+        document_cache,
+        token.source_file.path(),
+        lsp_types::TextEdit {
+            range: util::token_to_lsp_range(token),
+            new_text: new_name.to_string(),
+        },
+    )
+    .ok_or_else(|| format!("Cannot edit {}", token.source_file.path().display()).into())
+}
+
+#[cfg(test)]
+fn rename_in_test_document(source: &str, needle: &str, new_name: &str) -> crate::Result<String> {
+    let (mut dc, uri, _) = crate::language::test::loaded_document_cache(source.into());
+    let doc = dc.get_document(&uri).unwrap().node.clone().unwrap();
+    let offset = source.find(needle).unwrap() as u32;
+    let token = crate::language::token_at_offset(&doc, offset.into()).unwrap();
+    let edit = rename_symbol(&mut dc, &token, new_name)?;
+    let edited = common::text_edit::apply_workspace_edit(&dc, &edit)?;
+    assert_eq!(edited.len(), 1);
+    Ok(edited[0].contents.clone())
+}
+
+#[test]
+fn test_rename_property_and_callback() {
+    let source = r#"
+component Abc {
+    in property <string> hello;
+    property <int> count;
+    callback clicked(string);
+    Text { text: root.hello; }
+    TouchArea { clicked => { root.clicked(hello); } }
+}
+export component Test {
+    property <string> other;
+    abc := Abc {
+        hello: "foo";
+        clicked(x) => { other = x; }
+        changed hello => {}
+    }
+    Text { text: abc.hello; }
+}"#;
+
+    let expected = source.replace("hello", "greeting");
+    assert_eq!(rename_in_test_document(source, "hello;", "greeting").unwrap(), expected);
+    assert_eq!(rename_in_test_document(source, "hello: \"foo\"", "greeting").unwrap(), expected);
+    assert_eq!(rename_in_test_document(source, "hello => {}", "greeting").unwrap(), expected);
+
+    // The `clicked` callback of the TouchArea is not the one of Abc
+    let expected = source
+        .replace("callback clicked", "callback activated")
+        .replace("root.clicked", "root.activated")
+        .replace("clicked(x)", "activated(x)");
+    assert_eq!(rename_in_test_document(source, "clicked(string)", "activated").unwrap(), expected);
+
+    // Conflicts
+    assert!(rename_in_test_document(source, "hello;", "count").is_err());
+    assert!(rename_in_test_document(source, "hello;", "width").is_err());
+    assert!(rename_in_test_document(source, "hello;", "not valid").is_err());
+    // Builtin properties can't be renamed
+    assert!(rename_in_test_document(source, "text: abc", "label").is_err());
+}
+
+#[test]
+fn test_rename_struct_field_and_global() {
+    let source = r#"
+export struct Item { title: string, count: int }
+export global Logic {
+    in-out property <Item> current: { title: "a", count: 1 };
+}
+export component Test {
+    in property <Item> item: { title: "b", count: 2 };
+    property <string> t: Logic.current.title + item.title;
+    property <{ title: string }> anonymous: { title: "c" };
+    out property <string> anonymous-title: anonymous.title;
+}"#;
+
+    let expected = source
+        .replace("{ title: string, count", "{ name: string, count")
+        .replace("{ title: \"a\"", "{ name: \"a\"")
+        .replace("{ title: \"b\"", "{ name: \"b\"")
+        .replace("current.title + item.title", "current.name + item.name");
+    assert_eq!(rename_in_test_document(source, "title: string,", "name").unwrap(), expected);
+    assert_eq!(rename_in_test_document(source, "title: \"b\"", "name").unwrap(), expected);
+    assert!(rename_in_test_document(source, "title: string,", "count").is_err());
+
+    // Rename the global from where it is used
+    let expected = source.replace("Logic", "Model");
+    assert_eq!(rename_in_test_document(source, "Logic.current", "Model").unwrap(), expected);
+}
@@ -159,6 +159,7 @@ fn accurate_diagnostics_in_dependencies() {
         #[cfg(any(feature = "preview-external", feature = "preview-engine"))]
         to_show: Default::default(),
         open_urls: RefCell::new(HashSet::from_iter([foo_url.clone(), bar_url.clone()])),
+        inlay_hints_config: Default::default(),
     }));
 
     let bar_ctn = r#" export component Bar { in property <int> hello; } "#;
@@ -86,6 +86,11 @@ struct Format {
     /// modify the file inline instead of printing to stdout
     #[arg(short, long, action)]
     inline: bool,
+
+    /// The configuration file to use instead of the `.slint-fmt.json` found in the
+    /// directory of the files or in its parents
+    #[arg(long, action)]
+    config: Option<std::path::PathBuf>,
 }
 
 enum OutgoingRequest {
@@ -220,7 +225,7 @@ fn main() {
     }
 
     if let Some(Commands::Format(args)) = args.command {
-        let _ = fmt::tool::run(args.paths, args.inline).map_err(|e| {
+        let _ = fmt::tool::run(args.paths, args.inline, args.config).map_err(|e| {
             eprintln!("{e}");
             std::process::exit(1);
         });
@@ -368,6 +373,7 @@ fn main_loop(connection: Connection, init_param: InitializeParams, cli_args: Cli
         #[cfg(any(feature = "preview-external", feature = "preview-engine"))]
         to_show: Default::default(),
         open_urls: Default::default(),
+        inlay_hints_config: Default::default(),
     });
 
     let mut futures = Vec::<Pin<Box<dyn Future<Output = Result<()>>>>>::new();
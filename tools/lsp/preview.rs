@@ -12,7 +12,7 @@
 use i_slint_compiler::parser::{syntax_nodes, TextSize};
 use i_slint_compiler::{diagnostics, EmbedResourcesKind};
 use i_slint_core::component_factory::FactoryContext;
-use i_slint_core::lengths::{LogicalPoint, LogicalRect, LogicalSize};
+use i_slint_core::lengths::{LogicalPoint, LogicalRect, LogicalSize, LogicalVector};
 use lsp_types::Url;
 use slint::PlatformError;
 use slint_interpreter::{ComponentDefinition, ComponentHandle, ComponentInstance};
@@ -32,6 +32,8 @@
 mod ext;
 use ext::ElementRcNodeExt;
 mod properties;
+mod sample_data;
+mod snapping;
 pub mod ui;
 #[cfg(all(target_arch = "wasm32", feature = "preview-external"))]
 mod wasm;
@@ -106,6 +108,7 @@ struct PreviewState {
     ui: Option<ui::PreviewUi>,
     property_range_declarations: Option<ui::PropertyDeclarations>,
     handle: Rc<RefCell<Option<slint_interpreter::ComponentInstance>>>,
+    sample_data: Rc<RefCell<sample_data::SampleData>>,
     document_cache: Rc<RefCell<Option<Rc<common::DocumentCache>>>>,
     selected: Option<element_selection::ElementSelection>,
     notify_editor_about_selection_after_update: bool,
@@ -835,6 +838,62 @@ fn resize_selected_element_impl(
     .map(|edit| (edit, format!("{op} element")))
 }
 
+// triggered from the UI, running in UI thread
+fn sample_data(property_name: slint::SharedString) -> slint::SharedString {
+    let Some(component_instance) = component_instance() else {
+        return Default::default();
+    };
+    PREVIEW_STATE.with(|preview_state| {
+        preview_state
+            .borrow()
+            .sample_data
+            .borrow()
+            .get(component_instance.definition().name(), &property_name)
+            .unwrap_or_default()
+            .into()
+    })
+}
+
+// triggered from the UI, running in UI thread
+fn set_sample_data(
+    property_name: slint::SharedString,
+    json: slint::SharedString,
+) -> slint::SharedString {
+    match set_sample_data_impl(&property_name, &json) {
+        Ok(()) => Default::default(),
+        Err(e) => e.into(),
+    }
+}
+
+fn set_sample_data_impl(property_name: &str, json: &str) -> Result<(), String> {
+    let component_instance = component_instance().ok_or("No component is previewed")?;
+
+    // Only the properties of the root element can be set from the outside
+    let root_element = element_selection::root_element(&component_instance);
+    if !selected_element()
+        .and_then(|s| s.as_element_node())
+        .is_some_and(|node| Rc::ptr_eq(&node.element, &root_element))
+    {
+        return Err(
+            "Sample data can only be used for the properties of the previewed component".into()
+        );
+    }
+
+    let sample_data =
+        PREVIEW_STATE.with(|preview_state| preview_state.borrow().sample_data.clone());
+    let mut sample_data = RefCell::borrow_mut(&sample_data);
+    if json.trim().is_empty() {
+        // Go back to the value from the source code
+        if sample_data.remove(component_instance.definition().name(), property_name) {
+            drop(sample_data);
+            reload_preview();
+        }
+        Ok(())
+    } else {
+        sample_data.set(&component_instance, property_name, json)
+    }
+}
+
 // triggered from the UI, running in UI thread
 fn can_move_selected_element(x: f32, y: f32, mouse_x: f32, mouse_y: f32) -> bool {
     let position = LogicalPoint::new(x, y);
@@ -890,6 +949,80 @@ fn move_selected_element(x: f32, y: f32, mouse_x: f32, mouse_y: f32) {
     } else {
         element_selection::reselect_element();
     }
+    set_guides(&[]);
+}
+
+// triggered from the UI, running in UI thread
+fn snap_selected_element(x: f32, y: f32) -> ui::SnappedPosition {
+    let unsnapped = ui::SnappedPosition { x, y };
+    let Some(result) = snap_selected_element_impl(LogicalPoint::new(x, y)) else {
+        set_guides(&[]);
+        return unsnapped;
+    };
+    set_guides(&result.guides);
+    ui::SnappedPosition { x: result.position.x, y: result.position.y }
+}
+
+fn snap_selected_element_impl(position: LogicalPoint) -> Option<snapping::SnapResult> {
+    let selected = selected_element()?;
+    let element_node = selected.as_element_node()?;
+    // Elements in layouts get placed by their layout
+    if element_selection::parent_layout_kind(&element_node) != ui::LayoutKind::None {
+        return None;
+    }
+
+    let component_instance = component_instance()?;
+    let geometry =
+        element_node.geometries(&component_instance).get(selected.instance_index).cloned()?;
+    let root_element = element_selection::root_element(&component_instance);
+    let parent = search_for_parent_element(&root_element, &element_node.element)?;
+
+    let container = component_instance
+        .element_positions(&parent)
+        .into_iter()
+        .find(|g| g.contains(geometry.center()))?;
+    let siblings = parent
+        .borrow()
+        .children
+        .iter()
+        .filter(|c| !Rc::ptr_eq(c, &element_node.element))
+        .flat_map(|c| component_instance.element_positions(c))
+        .filter(|g| container.contains(g.center()))
+        .collect::<Vec<_>>();
+
+    Some(snapping::snap(LogicalRect::new(position, geometry.size), &siblings, container))
+}
+
+// triggered from the UI, running in UI thread
+fn nudge_selected_element(dx: f32, dy: f32) {
+    let Some(element_selection) = &selected_element() else {
+        return;
+    };
+    let Some(element_node) = element_selection.as_element_node() else {
+        return;
+    };
+    // Elements in layouts can not be moved freely
+    if element_selection::parent_layout_kind(&element_node) != ui::LayoutKind::None {
+        return;
+    }
+    let Some(component_instance) = component_instance() else {
+        return;
+    };
+    let Some(geometry) =
+        element_node.geometries(&component_instance).get(element_selection.instance_index).cloned()
+    else {
+        return;
+    };
+
+    let Some((edit, label)) = resize_selected_element_impl(
+        &element_node,
+        element_selection.instance_index,
+        geometry.translate(LogicalVector::new(dx, dy)),
+    ) else {
+        return;
+    };
+
+    send_workspace_edit(label, edit, true);
 }
 
 fn test_workspace_edit(edit: &lsp_types::WorkspaceEdit, test_edit: bool) -> bool {
@@ -1564,6 +1697,23 @@ fn set_drop_mark(mark: &Option<drop_location::DropMark>) {
     })
 }
 
+fn set_guides(guides: &[snapping::Guide]) {
+    PREVIEW_STATE.with(move |preview_state| {
+        let preview_state = preview_state.borrow();
+
+        let Some(ui) = &preview_state.ui else {
+            return;
+        };
+
+        let values = guides
+            .iter()
+            .map(|g| ui::Guide { x1: g.start.x, y1: g.start.y, x2: g.end.x, y2: g.end.y })
+            .collect::<Vec<_>>();
+        let api = ui.global::<ui::Api>();
+        api.set_guides(slint::ModelRc::from(Rc::new(slint::VecModel::from(values))));
+    })
+}
+
 #[derive(Debug, PartialEq)]
 pub enum SelectionNotification {
     Never,
@@ -1790,6 +1940,7 @@ fn update_preview_area(
         let ui = preview_state.ui.as_ref().unwrap();
 
         let shared_handle = preview_state.handle.clone();
+        let shared_sample_data = preview_state.sample_data.clone();
         let shared_document_cache = preview_state.document_cache.clone();
 
         if let Some(compiled) = compiled {
@@ -1806,6 +1957,7 @@ fn update_preview_area(
                             ),
                         )));
                     }
+                    shared_sample_data.borrow().apply(&instance);
                     shared_handle.replace(Some(instance));
                 }),
                 behavior,
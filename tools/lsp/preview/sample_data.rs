@@ -0,0 +1,134 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! JSON sample data for the properties of the previewed component.
+//!
+//! The sample data is only used in the preview and never written into the source code.
+
+use std::collections::HashMap;
+
+use slint_interpreter::{ComponentDefinition, ComponentInstance, JsonError, Value};
+
+/// The sample data of the properties, by component name and property name
+#[derive(Default)]
+pub struct SampleData(HashMap<(String, String), serde_json::Value>);
+
+impl SampleData {
+    /// The sample data of `property` of the `component`, as pretty printed JSON
+    pub fn get(&self, component: &str, property: &str) -> Option<String> {
+        self.0
+            .get(&(component.to_string(), property.to_string()))
+            .and_then(|json| serde_json::to_string_pretty(json).ok())
+    }
+
+    /// Parse `json` and set it as the value of the `property` of the `instance`.
+    pub fn set(
+        &mut self,
+        instance: &ComponentInstance,
+        property: &str,
+        json: &str,
+    ) -> Result<(), String> {
+        let definition = instance.definition();
+        let (json, value) = convert(&definition, property, json)?;
+        instance.set_property(property, value).map_err(|e| e.to_string())?;
+        self.0.insert((definition.name().to_string(), property.to_string()), json);
+        Ok(())
+    }
+
+    /// Forget the sample data of the `property` of the `component`.
+    /// Returns whether there was any.
+    pub fn remove(&mut self, component: &str, property: &str) -> bool {
+        self.0.remove(&(component.to_string(), property.to_string())).is_some()
+    }
+
+    /// Set the sample data of the properties of a newly created `instance`.
+    /// Sample data that does not match the type of the property anymore is ignored.
+    pub fn apply(&self, instance: &ComponentInstance) {
+        let definition = instance.definition();
+        for ((component, property), json) in &self.0 {
+            if component != definition.name() {
+                continue;
+            }
+            let Ok(result) = definition.property_from_json(property, json) else {
+                continue;
+            };
+            if result.unknown_fields.is_empty() {
+                let _ = instance.set_property(property, result.value);
+            }
+        }
+    }
+}
+
+/// Parse `json` into a value for the `property` of the component
+fn convert(
+    definition: &ComponentDefinition,
+    property: &str,
+    json: &str,
+) -> Result<(serde_json::Value, Value), String> {
+    let json = serde_json::from_str::<serde_json::Value>(json).map_err(|e| e.to_string())?;
+    let result = definition.property_from_json(property, &json).map_err(|e| match e {
+        JsonError::NoSuchProperty(_) => {
+            format!("{} has no public property {property}", definition.name())
+        }
+        e => e.to_string(),
+    })?;
+    if let Some(field) = result.unknown_fields.first() {
+        return Err(format!("unknown field `{field}`"));
+    }
+    Ok((json, result.value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::preview::test::interpret_test;
+    use slint::Model;
+
+    #[test]
+    fn test_sample_data() {
+        let instance = interpret_test(
+            "fluent",
+            r#"
+struct Item { name: string, count: int }
+export component Test {
+    in property <[Item]> items: [{ name: "source" }];
+    in property <[string]> names;
+    property <[int]> private-numbers;
+}"#,
+        );
+
+        let mut sample_data = SampleData::default();
+        sample_data
+            .set(&instance, "items", r#"[{ "name": "a", "count": 1 }, { "name": "b" }]"#)
+            .unwrap();
+        let Ok(Value::Model(model)) = instance.get_property("items") else { panic!("No model") };
+        assert_eq!(model.row_count(), 2);
+        let Some(Value::Struct(item)) = model.row_data(1) else { panic!("Not a struct") };
+        assert_eq!(item.get_field("name"), Some(&Value::String("b".into())));
+        assert_eq!(item.get_field("count"), Some(&Value::Number(0.0)));
+
+        assert!(sample_data.set(&instance, "names", "[1, 2]").is_err());
+        assert!(sample_data.set(&instance, "names", "[").is_err());
+        assert!(sample_data.set(&instance, "items", r#"[{ "title": "a" }]"#).is_err());
+        assert!(sample_data.set(&instance, "private-numbers", "[1]").is_err());
+        assert!(sample_data.set(&instance, "unknown", "[1]").is_err());
+        assert_eq!(sample_data.get("Test", "names"), None);
+
+        sample_data.set(&instance, "names", r#"["x", "y"]"#).unwrap();
+        assert_eq!(sample_data.get("Test", "names").unwrap(), "[\n  \"x\",\n  \"y\"\n]");
+
+        // A new instance gets the same sample data
+        let instance = instance.definition().create().unwrap();
+        let Ok(Value::Model(model)) = instance.get_property("items") else { panic!("No model") };
+        assert_eq!(model.row_count(), 1);
+        sample_data.apply(&instance);
+        let Ok(Value::Model(model)) = instance.get_property("items") else { panic!("No model") };
+        assert_eq!(model.row_count(), 2);
+        let Ok(Value::Model(model)) = instance.get_property("names") else { panic!("No model") };
+        assert_eq!(model.row_data(0), Some(Value::String("x".into())));
+
+        assert!(sample_data.remove("Test", "items"));
+        assert!(!sample_data.remove("Test", "items"));
+        assert_eq!(sample_data.get("Test", "items"), None);
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Snapping of the elements moved in design mode to the edges and centers of their siblings
+//! and of their parent, or to a grid.
+
+use i_slint_core::lengths::{LogicalPoint, LogicalRect};
+
+/// How close an edge or center must be to a guide to snap to it
+pub const SNAP_DISTANCE: f32 = 6.0;
+/// The size of the grid used when no guide is close enough
+pub const GRID_SIZE: f32 = 4.0;
+
+/// A line between two points, shown while moving an element
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Guide {
+    pub start: LogicalPoint,
+    pub end: LogicalPoint,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SnapResult {
+    pub position: LogicalPoint,
+    pub guides: Vec<Guide>,
+}
+
+/// The left (top), center, and right (bottom) of a rectangle along one axis
+fn anchors(start: f32, size: f32) -> [f32; 3] {
+    [start, start + size / 2.0, start + size]
+}
+
+/// Returns the offset to apply to the `moving` range so that one of its anchors is on
+/// one of the anchors of the `targets`, if one is close enough
+fn snap_axis(moving: (f32, f32), targets: &[(f32, f32)]) -> Option<f32> {
+    let moving = anchors(moving.0, moving.1);
+    targets
+        .iter()
+        .flat_map(|t| anchors(t.0, t.1))
+        .flat_map(|target| moving.iter().map(move |m| target - m))
+        .filter(|delta| delta.abs() <= SNAP_DISTANCE)
+        .min_by(|a, b| a.abs().total_cmp(&b.abs()))
+}
+
+fn snap_to_grid(value: f32, origin: f32) -> f32 {
+    ((value - origin) / GRID_SIZE).round() * GRID_SIZE + origin
+}
+
+/// Snap the `rect` that is being moved to the edges and centers of the `siblings` and the
+/// `container`, or to the grid that starts at the origin of the container if none is close.
+/// Returns the new position and the guides to show.
+pub fn snap(rect: LogicalRect, siblings: &[LogicalRect], container: LogicalRect) -> SnapResult {
+    let targets = siblings.iter().chain(std::iter::once(&container)).collect::<Vec<_>>();
+
+    let x_targets = targets.iter().map(|r| (r.origin.x, r.size.width)).collect::<Vec<_>>();
+    let y_targets = targets.iter().map(|r| (r.origin.y, r.size.height)).collect::<Vec<_>>();
+    let dx = snap_axis((rect.origin.x, rect.size.width), &x_targets);
+    let dy = snap_axis((rect.origin.y, rect.size.height), &y_targets);
+
+    let position = LogicalPoint::new(
+        dx.map_or_else(|| snap_to_grid(rect.origin.x, container.origin.x), |dx| rect.origin.x + dx),
+        dy.map_or_else(|| snap_to_grid(rect.origin.y, container.origin.y), |dy| rect.origin.y + dy),
+    );
+    let rect = LogicalRect::new(position, rect.size);
+
+    // Show a guide for every anchor that is aligned now, spanning both rectangles
+    let is_aligned = |a: f32, b: f32| (a - b).abs() < 0.5;
+    let mut guides = Vec::new();
+    for target in &targets {
+        if dx.is_some() {
+            for x in anchors(target.origin.x, target.size.width) {
+                if anchors(rect.origin.x, rect.size.width).iter().any(|m| is_aligned(*m, x)) {
+                    guides.push(Guide {
+                        start: LogicalPoint::new(x, rect.min_y().min(target.min_y())),
+                        end: LogicalPoint::new(x, rect.max_y().max(target.max_y())),
+                    });
+                }
+            }
+        }
+        if dy.is_some() {
+            for y in anchors(target.origin.y, target.size.height) {
+                if anchors(rect.origin.y, rect.size.height).iter().any(|m| is_aligned(*m, y)) {
+                    guides.push(Guide {
+                        start: LogicalPoint::new(rect.min_x().min(target.min_x()), y),
+                        end: LogicalPoint::new(rect.max_x().max(target.max_x()), y),
+                    });
+                }
+            }
+        }
+    }
+
+    SnapResult { position, guides }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use i_slint_core::lengths::LogicalSize;
+
+    fn rect(x: f32, y: f32, width: f32, height: f32) -> LogicalRect {
+        LogicalRect::new(LogicalPoint::new(x, y), LogicalSize::new(width, height))
+    }
+
+    fn guide(x1: f32, y1: f32, x2: f32, y2: f32) -> Guide {
+        Guide { start: LogicalPoint::new(x1, y1), end: LogicalPoint::new(x2, y2) }
+    }
+
+    #[test]
+    fn test_snap_to_sibling_edges() {
+        let container = rect(0.0, 0.0, 400.0, 300.0);
+        let sibling = rect(100.0, 50.0, 50.0, 20.0);
+
+        // The left edge snaps to the right edge of the sibling, the top to its top
+        let result = snap(rect(153.0, 48.0, 30.0, 30.0), &[sibling], container);
+        assert_eq!(result.position, LogicalPoint::new(150.0, 50.0));
+        assert_eq!(
+            result.guides,
+            vec![guide(150.0, 50.0, 150.0, 80.0), guide(100.0, 50.0, 180.0, 50.0)]
+        );
+
+        // The center snaps to the center of the sibling, the top to the grid
+        let result = snap(rect(108.0, 201.0, 30.0, 30.0), &[sibling], container);
+        assert_eq!(result.position, LogicalPoint::new(110.0, 200.0));
+        assert_eq!(result.guides, vec![guide(125.0, 50.0, 125.0, 230.0)]);
+    }
+
+    #[test]
+    fn test_snap_to_container_and_grid() {
+        let container = rect(10.0, 10.0, 200.0, 100.0);
+
+        // The center snaps to the center of the container
+        let result = snap(rect(97.0, 31.0, 20.0, 20.0), &[], container);
+        assert_eq!(result.position, LogicalPoint::new(100.0, 30.0));
+        assert_eq!(result.guides, vec![guide(110.0, 10.0, 110.0, 110.0)]);
+
+        // Nothing close: the position snaps to the grid that starts at the container origin
+        let result = snap(rect(51.0, 71.0, 20.0, 20.0), &[], container);
+        assert_eq!(result.position, LogicalPoint::new(50.0, 70.0));
+        assert!(result.guides.is_empty());
+    }
+}
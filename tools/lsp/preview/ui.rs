@@ -90,6 +90,8 @@ pub fn create_ui(style: String, experimental: bool) -> Result<PreviewUi, Platfor
     api.on_selected_element_resize(super::resize_selected_element);
     api.on_selected_element_can_move_to(super::can_move_selected_element);
     api.on_selected_element_move(super::move_selected_element);
+    api.on_selected_element_snap(super::snap_selected_element);
+    api.on_selected_element_nudge(super::nudge_selected_element);
     api.on_selected_element_delete(super::delete_selected_element);
 
     api.on_test_code_binding(super::test_code_binding);
@@ -114,11 +116,7 @@ pub fn create_ui(style: String, experimental: bool) -> Result<PreviewUi, Platfor
             r: r as i32,
             g: g as i32,
             b: b as i32,
-            text: format!(
-                "#{:08x}",
-                ((r as u32) << 24) + ((g as u32) << 16) + ((b as u32) << 8) + (a as u32)
-            )
-            .into(),
+            text: color_to_string(c).into(),
         }
     });
     api.on_rgba_to_color(|r, g, b, a| {
@@ -129,6 +127,34 @@ pub fn create_ui(style: String, experimental: bool) -> Result<PreviewUi, Platfor
         }
     });
 
+    api.on_gradient_code(|kind, angle, stops| {
+        gradient_code(&kind, angle, &stops.iter().collect::<Vec<_>>()).into()
+    });
+    api.on_add_color_stop(|stops, index| {
+        let mut stops = stops.iter().collect::<Vec<_>>();
+        add_color_stop(&mut stops, usize::try_from(index).unwrap_or_default());
+        Rc::new(VecModel::from(stops)).into()
+    });
+    api.on_remove_color_stop(|stops, index| {
+        let mut stops = stops.iter().collect::<Vec<_>>();
+        if let Ok(index) = usize::try_from(index) {
+            if index < stops.len() {
+                stops.remove(index);
+            }
+        }
+        Rc::new(VecModel::from(stops)).into()
+    });
+    api.on_set_color_stop(|stops, index, stop| {
+        let mut stops = stops.iter().collect::<Vec<_>>();
+        if let Some(s) = usize::try_from(index).ok().and_then(|index| stops.get_mut(index)) {
+            *s = stop;
+        }
+        Rc::new(VecModel::from(stops)).into()
+    });
+
+    api.on_sample_data(super::sample_data);
+    api.on_set_sample_data(super::set_sample_data);
+
     #[cfg(target_vendor = "apple")]
     api.set_control_key_name("command".into());
 
@@ -428,6 +454,128 @@ fn extract_color(
     false
 }
 
+/// The color as `#rrggbbaa`
+fn color_to_string(color: slint::Color) -> String {
+    let rgba: slint::RgbaColor<u8> = color.into();
+    format!("#{:02x}{:02x}{:02x}{:02x}", rgba.red, rgba.green, rgba.blue, rgba.alpha)
+}
+
+fn gradient_code(kind: &str, angle: f32, stops: &[ColorStop]) -> String {
+    let stops = stops
+        .iter()
+        .map(|s| format!("{} {}%", color_to_string(s.color), (s.position * 1000.0).round() / 10.0))
+        .join(", ");
+    if kind == "radial-gradient" {
+        format!("@radial-gradient(circle, {stops})")
+    } else {
+        format!("@linear-gradient({angle}deg, {stops})")
+    }
+}
+
+/// Insert a stop after the one at `index`, halfway between it and the next stop
+fn add_color_stop(stops: &mut Vec<ColorStop>, index: usize) {
+    let new_stop = match (stops.get(index), stops.get(index + 1)) {
+        (Some(s), Some(n)) => ColorStop {
+            color: s.color.mix(&n.color, 0.5),
+            position: (s.position + n.position) / 2.0,
+        },
+        (Some(s), None) => ColorStop { color: s.color, position: (s.position + 1.0) / 2.0 },
+        (None, _) => ColorStop { color: slint::Color::from_rgb_u8(0, 0, 0), position: 0.0 },
+    };
+    stops.insert((index + 1).min(stops.len()), new_stop);
+}
+
+/// Extract `@linear-gradient(...)` and `@radial-gradient(circle, ...)` with literal colors,
+/// angles, and positions
+fn extract_gradient(gradient: &syntax_nodes::AtGradient, value: &mut PropertyValue) {
+    use i_slint_compiler::expression_tree::Unit;
+    use i_slint_core::graphics::{GradientStop, LinearGradientBrush, RadialGradientBrush};
+
+    let Some(kind) = gradient.child_text(SyntaxKind::Identifier) else {
+        return;
+    };
+
+    // The arguments, split at the commas
+    let mut arguments = vec![vec![]];
+    for n in gradient.children_with_tokens() {
+        match n.kind() {
+            SyntaxKind::Comma => arguments.push(vec![]),
+            SyntaxKind::Expression => arguments
+                .last_mut()
+                .unwrap()
+                .push(syntax_nodes::Expression::from(n.into_node().unwrap())),
+            _ => {}
+        }
+    }
+    let mut arguments = arguments.into_iter();
+
+    let (kind, angle) = match (kind.starts_with("linear"), arguments.next().as_deref()) {
+        (true, Some([angle])) => match convert_number_literal(angle) {
+            Some((angle, unit @ (Unit::Deg | Unit::Grad | Unit::Turn | Unit::Rad))) => {
+                ("linear-gradient", unit.normalize(angle) as f32)
+            }
+            _ => return,
+        },
+        (false, Some([circle])) if circle.text().to_string().trim() == "circle" => {
+            ("radial-gradient", 0.0)
+        }
+        _ => return,
+    };
+
+    let mut stops = Vec::new();
+    for argument in arguments {
+        let color = argument.first().and_then(|c| c.child_text(SyntaxKind::ColorLiteral));
+        let Some(color) = color.and_then(|c| string_to_color(&c)) else {
+            return;
+        };
+        let position = match argument.get(1).map(convert_number_literal) {
+            None => None,
+            Some(Some((position, Unit::Percent))) => Some(position as f32 / 100.0),
+            Some(_) => return,
+        };
+        if argument.len() > 2 {
+            return;
+        }
+        stops.push((color, position));
+    }
+    if stops.is_empty() {
+        return;
+    }
+
+    // Stops without position are spread evenly between their neighbors, like the compiler does
+    let last = stops.len() - 1;
+    let mut positions = stops.iter().map(|s| s.1).collect::<Vec<_>>();
+    positions[0].get_or_insert(0.0);
+    positions[last].get_or_insert(1.0);
+    let mut start = 0;
+    for i in 1..=last {
+        if let Some(end_position) = positions[i] {
+            let start_position = positions[start].unwrap();
+            let step = (end_position - start_position) / (i - start) as f32;
+            for (n, position) in positions[start + 1..i].iter_mut().enumerate() {
+                *position = Some(start_position + step * (n + 1) as f32);
+            }
+            start = i;
+        }
+    }
+
+    let stops = std::iter::zip(stops, positions)
+        .map(|((color, _), position)| ColorStop { color, position: position.unwrap() })
+        .collect::<Vec<_>>();
+    let gradient_stops =
+        stops.iter().map(|s| GradientStop { color: s.color, position: s.position });
+
+    value.kind = PropertyValueKind::Gradient;
+    value.value_string = kind.into();
+    value.value_float = angle;
+    value.value_brush = if kind == "radial-gradient" {
+        slint::Brush::RadialGradient(RadialGradientBrush::new_circle(gradient_stops))
+    } else {
+        slint::Brush::LinearGradient(LinearGradientBrush::new(angle, gradient_stops))
+    };
+    value.value_stops = Rc::new(VecModel::from(stops)).into();
+}
+
 fn set_default_brush(
     kind: PropertyValueKind,
     def_val: Option<&expression_tree::Expression>,
@@ -514,8 +662,15 @@ fn simplify_value(prop_info: &super::properties::PropertyInformation) -> Propert
         }
         Type::Brush => {
             if let Some(expression) = expression {
-                extract_color(&expression, PropertyValueKind::Brush, &mut value);
-                // TODO: Handle gradients...
+                // Bindings with syntax errors are edited as code
+                let is_complete = expression
+                    .parent()
+                    .is_some_and(|binding| binding.child_token(SyntaxKind::Semicolon).is_some());
+                if let Some(gradient) = expression.AtGradient().filter(|_| is_complete) {
+                    extract_gradient(&gradient, &mut value);
+                } else {
+                    extract_color(&expression, PropertyValueKind::Brush, &mut value);
+                }
             } else if value.code.is_empty() {
                 set_default_brush(PropertyValueKind::Brush, def_val, &mut value);
             }
@@ -567,7 +722,7 @@ fn simplify_value(prop_info: &super::properties::PropertyInformation) -> Propert
             .into();
 
             if let Some(expression) = expression {
-                if let Some(text) = expression
+                let index = expression
                     .child_node(SyntaxKind::QualifiedName)
                     .map(|n| i_slint_compiler::object_tree::QualifiedTypeName::from_node(n.into()))
                     .map(|n| {
@@ -577,19 +732,20 @@ fn simplify_value(prop_info: &super::properties::PropertyInformation) -> Propert
                             .map(|s| s.to_string())
                             .unwrap_or(n_str)
                     })
-                    .map(|s| s.to_string())
-                {
-                    value.value_int = enumeration
-                        .values
-                        .iter()
-                        .position(|v| v == &text)
-                        .and_then(|v| i32::try_from(v).ok())
-                        .unwrap_or_default();
+                    .and_then(|text| enumeration.values.iter().position(|v| v == &text))
+                    .and_then(|v| i32::try_from(v).ok());
+                match index {
+                    Some(index) => value.value_int = index,
+                    // Not a value of the enum: Edit as code
+                    None => value.kind = PropertyValueKind::Code,
                 }
             } else if let Some(expression_tree::Expression::EnumerationValue(v)) = def_val {
                 value.value_int = v.value as i32
             }
         }
+        Type::Array(_) => {
+            value.kind = PropertyValueKind::Model;
+        }
         _ => {}
     }
 
@@ -1112,6 +1268,13 @@ fn test_property_enum() {
         assert_eq!(result.visual_items.row_count(), 2);
         assert_eq!(result.visual_items.row_data(0), Some(slint::SharedString::from("foo")));
         assert_eq!(result.visual_items.row_data(1), Some(slint::SharedString::from("bar")));
+
+        let result = property_conversion_test(
+            r#"enum Foobar { foo, bar }
+export component Test { in property <bool> cond; in property <Foobar> test1: cond ? Foobar.foo : Foobar.bar; }"#,
+            1,
+        );
+        assert_eq!(result.kind, PropertyValueKind::Code);
     }
 
     #[test]
@@ -1259,7 +1422,7 @@ fn test_property_brush() {
             r#"export component Test { in property <brush> test1: @linear-gradient(90deg, #3f87a6 0%, #ebf8e1 50%, #f69d3c 100%); }"#,
             1,
         );
-        assert_eq!(result.kind, PropertyValueKind::Code);
+        assert_eq!(result.kind, PropertyValueKind::Gradient);
 
         let result = property_conversion_test(
             r#"export component Test { in property <brush> test1: @radial-gradient(circle, #f00 0%, #0f0 50%, #00f 100%)
@@ -1269,6 +1432,121 @@ fn test_property_brush() {
         assert_eq!(result.kind, PropertyValueKind::Code);
     }
 
+    #[test]
+    fn test_property_gradient() {
+        let stops = |result: &PropertyValue| {
+            result
+                .value_stops
+                .iter()
+                .map(|s| (super::color_to_string(s.color), s.position))
+                .collect::<Vec<_>>()
+        };
+
+        let result = property_conversion_test(
+            r#"export component Test { in property <brush> test1: @linear-gradient(0.25turn, #3f87a6 0%, #ebf8e1, #f69d3c 80%); }"#,
+            0,
+        );
+        assert_eq!(result.kind, PropertyValueKind::Gradient);
+        assert_eq!(result.value_string, "linear-gradient");
+        assert_eq!(result.value_float, 90.0);
+        assert_eq!(
+            stops(&result),
+            vec![
+                ("#3f87a6ff".to_string(), 0.0),
+                ("#ebf8e1ff".to_string(), 0.4),
+                ("#f69d3cff".to_string(), 0.8)
+            ]
+        );
+        assert!(matches!(result.value_brush, slint::Brush::LinearGradient(_)));
+        assert_eq!(
+            super::gradient_code(
+                &result.value_string,
+                result.value_float,
+                &result.value_stops.iter().collect::<Vec<_>>()
+            ),
+            "@linear-gradient(90deg, #3f87a6ff 0%, #ebf8e1ff 40%, #f69d3cff 80%)"
+        );
+
+        let result = property_conversion_test(
+            r#"export component Test { in property <brush> test1: @radial-gradient(circle, #f00, #00f); }"#,
+            0,
+        );
+        assert_eq!(result.kind, PropertyValueKind::Gradient);
+        assert_eq!(result.value_string, "radial-gradient");
+        assert_eq!(
+            stops(&result),
+            vec![("#ff0000ff".to_string(), 0.0), ("#0000ffff".to_string(), 1.0)]
+        );
+        assert!(matches!(result.value_brush, slint::Brush::RadialGradient(_)));
+        assert_eq!(
+            super::gradient_code(
+                &result.value_string,
+                result.value_float,
+                &result.value_stops.iter().collect::<Vec<_>>()
+            ),
+            "@radial-gradient(circle, #ff0000ff 0%, #0000ffff 100%)"
+        );
+
+        // Colors that are not literals, and computed angles or positions are edited as code
+        let result = property_conversion_test(
+            r#"export component Test { in property <brush> test1: @linear-gradient(90deg, Colors.red 0%, #00f 100%); }"#,
+            0,
+        );
+        assert_eq!(result.kind, PropertyValueKind::Code);
+        let result = property_conversion_test(
+            r#"export component Test { in property <brush> test1: @linear-gradient(45deg * 2, #f00 0%, #00f 100%); }"#,
+            0,
+        );
+        assert_eq!(result.kind, PropertyValueKind::Code);
+        let result = property_conversion_test(
+            r#"export component Test { in property <brush> test1: @linear-gradient(90deg, #f00 0%, #00f 50% + 50%); }"#,
+            0,
+        );
+        assert_eq!(result.kind, PropertyValueKind::Code);
+    }
+
+    #[test]
+    fn test_color_stops() {
+        let red = slint::Color::from_rgb_u8(255, 0, 0);
+        let blue = slint::Color::from_rgb_u8(0, 0, 255);
+        let mut stops = vec![
+            super::ColorStop { color: red, position: 0.0 },
+            super::ColorStop { color: blue, position: 0.5 },
+        ];
+
+        super::add_color_stop(&mut stops, 0);
+        assert_eq!(stops.len(), 3);
+        assert_eq!(stops[1].position, 0.25);
+        assert_eq!(stops[1].color, red.mix(&blue, 0.5));
+
+        super::add_color_stop(&mut stops, 2);
+        assert_eq!(stops.len(), 4);
+        assert_eq!(stops[3].position, 0.75);
+        assert_eq!(stops[3].color, blue);
+
+        let mut stops = vec![];
+        super::add_color_stop(&mut stops, 0);
+        assert_eq!(stops.len(), 1);
+        assert_eq!(stops[0].position, 0.0);
+    }
+
+    #[test]
+    fn test_property_model() {
+        let result = property_conversion_test(
+            r#"export component Test { in property <[string]> test1: ["a", "b"]; }"#,
+            0,
+        );
+        assert_eq!(result.kind, PropertyValueKind::Model);
+        assert!(!result.code.is_empty());
+
+        let result = property_conversion_test(
+            r#"export component Test { in property <[{ a: int }]> test1; }"#,
+            0,
+        );
+        assert_eq!(result.kind, PropertyValueKind::Model);
+        assert!(result.code.is_empty());
+    }
+
     #[test]
     fn test_property_units() {
         let result =
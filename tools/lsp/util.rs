@@ -305,8 +305,16 @@ fn lookup_expression_context(mut n: SyntaxNode) -> Option<ExpressionContextInfo>
     Some(ExpressionContextInfo::new(element, prop_name, is_animate))
 }
 
+/// The fix-its of a diagnostic, stored in the `data` of the LSP diagnostic so that they
+/// come back in the context of the code action request
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct LspFixIt {
+    pub title: String,
+    pub edit: lsp_types::TextEdit,
+}
+
 pub fn to_lsp_diag(d: &i_slint_compiler::diagnostics::Diagnostic) -> lsp_types::Diagnostic {
-    lsp_types::Diagnostic::new(
+    let mut diag = lsp_types::Diagnostic::new(
         to_range(d.line_column()),
         Some(to_lsp_diag_level(d.level())),
         None,
@@ -314,7 +322,26 @@ pub fn to_lsp_diag(d: &i_slint_compiler::diagnostics::Diagnostic) -> lsp_types::
         d.message().to_owned(),
         None,
         None,
-    )
+    );
+    if !d.fix_its().is_empty() {
+        let fix_its = d
+            .fix_its()
+            .iter()
+            .map(|fix_it| {
+                let start = to_range(d.offset_to_line_column(fix_it.range.start)).start;
+                let end = to_range(d.offset_to_line_column(fix_it.range.end)).start;
+                LspFixIt {
+                    title: fix_it.title.clone(),
+                    edit: lsp_types::TextEdit::new(
+                        lsp_types::Range::new(start, end),
+                        fix_it.replacement.clone(),
+                    ),
+                }
+            })
+            .collect::<Vec<_>>();
+        diag.data = serde_json::to_value(fix_its).ok();
+    }
+    diag
 }
 
 fn to_range(span: (usize, usize)) -> lsp_types::Range {
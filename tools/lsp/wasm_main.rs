@@ -223,6 +223,7 @@ pub fn create(
             server_notifier,
             to_show: Default::default(),
             open_urls: Default::default(),
+            inlay_hints_config: Default::default(),
         }),
         reentry_guard,
         rh: Rc::new(rh),
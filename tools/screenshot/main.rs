@@ -0,0 +1,250 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use clap::Parser;
+use i_slint_backend_testing::screenshot;
+use i_slint_compiler::ComponentSelection;
+use i_slint_core::api::LogicalSize;
+use itertools::Itertools;
+use slint_interpreter::ComponentHandle;
+use std::path::{Path, PathBuf};
+
+struct Error(Box<dyn std::error::Error>);
+impl std::fmt::Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Use the Display impl of the error instead of the error
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<T> From<T> for Error
+where
+    T: Into<Box<dyn std::error::Error>> + 'static,
+{
+    fn from(value: T) -> Self {
+        Self(value.into())
+    }
+}
+
+type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Clone, clap::Parser)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    /// Include path for other .slint files or images
+    #[arg(short = 'I', value_name = "include path", number_of_values = 1, action)]
+    include_paths: Vec<std::path::PathBuf>,
+
+    /// Specify Library location of the '@library' in the form 'library=/path/to/library'
+    #[arg(short = 'L', value_name = "library=path", number_of_values = 1, action)]
+    library_paths: Vec<String>,
+
+    /// The .slint file to render
+    #[arg(name = "path", action)]
+    path: std::path::PathBuf,
+
+    /// The name of the component to render. If unset, the last exported component of the file is used.
+    #[arg(long, value_name = "component name", action)]
+    component: Option<String>,
+
+    /// The styles to render the component with. If unset, the default style is used.
+    #[arg(long = "style", value_name = "style name", number_of_values = 1, action)]
+    styles: Vec<String>,
+
+    /// The sizes to render the component at, in logical pixels, like '320x240'.
+    /// If unset, the preferred size of the component is used.
+    #[arg(long = "size", value_name = "WIDTHxHEIGHT", number_of_values = 1, value_parser = parse_size, action)]
+    sizes: Vec<(f32, f32)>,
+
+    /// The scale factors to render the component with. Defaults to 1.
+    #[arg(long = "scale-factor", value_name = "factor", number_of_values = 1, action)]
+    scale_factors: Vec<f32>,
+
+    /// The folder of the reference images. Defaults to a 'screenshots' folder next to the .slint file.
+    #[arg(long, value_name = "folder", action)]
+    references: Option<PathBuf>,
+
+    /// The folder where the renderings and diff images of the failed comparisons are written.
+    /// Defaults to the folder of the reference images.
+    #[arg(long, value_name = "folder", action)]
+    output: Option<PathBuf>,
+
+    /// Write the renderings as the new reference images instead of comparing them
+    #[arg(long, action)]
+    update: bool,
+
+    /// How different two colors may look, between 0 and 1, before a pixel counts as different
+    #[arg(long, value_name = "difference", default_value_t = screenshot::Tolerance::default().pixel_threshold, action)]
+    pixel_threshold: f32,
+
+    /// The percentage of pixels that may be different from the reference image
+    #[arg(long, value_name = "percentage", default_value_t = screenshot::Tolerance::default().max_different_pixels, action)]
+    max_different_pixels: f32,
+}
+
+fn parse_size(size: &str) -> std::result::Result<(f32, f32), String> {
+    size.split_once('x')
+        .and_then(|(w, h)| Some((w.trim().parse().ok()?, h.trim().parse().ok()?)))
+        .ok_or_else(|| format!("'{size}' is not a size like '320x240'"))
+}
+
+/// One rendering of the component to compare with a reference image
+struct Variant<'a> {
+    style: Option<&'a str>,
+    size: Option<(f32, f32)>,
+    scale_factor: f32,
+}
+
+impl Variant<'_> {
+    /// The file name of the reference image, like 'App-320x240@2x-fluent.png'
+    fn file_name(&self, component: &str) -> String {
+        let size = match self.size {
+            Some((width, height)) => format!("{width}x{height}"),
+            None => "preferred".into(),
+        };
+        let style = self.style.map(|style| format!("-{style}")).unwrap_or_default();
+        format!("{component}-{size}@{}x{style}.png", self.scale_factor)
+    }
+}
+
+fn main() -> Result<()> {
+    let args = Cli::parse();
+    let tolerance = screenshot::Tolerance {
+        pixel_threshold: args.pixel_threshold,
+        max_different_pixels: args.max_different_pixels,
+    };
+    let references = args
+        .references
+        .clone()
+        .unwrap_or_else(|| args.path.parent().unwrap_or(Path::new(".")).join("screenshots"));
+    let output = args.output.clone().unwrap_or_else(|| references.clone());
+    std::fs::create_dir_all(&references)?;
+    std::fs::create_dir_all(&output)?;
+
+    screenshot::init();
+
+    let styles = if args.styles.is_empty() {
+        vec![None]
+    } else {
+        args.styles.iter().map(|s| Some(s.as_str())).collect()
+    };
+    let sizes = if args.sizes.is_empty() {
+        vec![None]
+    } else {
+        args.sizes.iter().copied().map(Some).collect()
+    };
+    let scale_factors =
+        if args.scale_factors.is_empty() { vec![1.] } else { args.scale_factors.clone() };
+
+    let mut failures = 0;
+    for style in styles {
+        let c = compile(&args, style)?;
+        for (size, scale_factor) in sizes.iter().cartesian_product(&scale_factors) {
+            let variant = Variant { style, size: *size, scale_factor: *scale_factor };
+            let file_name = variant.file_name(c.name());
+            match check(&c, &variant, &references.join(&file_name), &output, &args, &tolerance) {
+                Ok(message) => println!("ok    {file_name}{message}"),
+                Err(e) => {
+                    println!("FAIL  {file_name}: {e:?}");
+                    failures += 1;
+                }
+            }
+        }
+    }
+
+    if failures > 0 {
+        eprintln!("{failures} screenshot(s) do not match the reference images");
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn compile(args: &Cli, style: Option<&str>) -> Result<slint_interpreter::ComponentDefinition> {
+    let mut compiler = slint_interpreter::Compiler::new();
+    compiler.set_include_paths(args.include_paths.clone());
+    compiler.set_library_paths(
+        args.library_paths
+            .iter()
+            .filter_map(|entry| entry.split('=').collect_tuple().map(|(k, v)| (k.into(), v.into())))
+            .collect(),
+    );
+    if let Some(style) = style {
+        compiler.set_style(style.into());
+    }
+    compiler.compiler_configuration(i_slint_core::InternalToken).components_to_generate =
+        match &args.component {
+            Some(component) => ComponentSelection::Named(component.clone()),
+            None => ComponentSelection::LastExported,
+        };
+
+    let r = spin_on::spin_on(compiler.build_from_path(&args.path));
+    r.print_diagnostics();
+    if r.has_errors() {
+        return Err(format!("Could not compile {}", args.path.display()).into());
+    }
+    let component = r.components().next();
+    component.ok_or_else(|| match &args.component {
+        Some(name) => {
+            format!("Component '{name}' not found in file '{}'", args.path.display()).into()
+        }
+        None => format!("No component found in file '{}'", args.path.display()).into(),
+    })
+}
+
+/// Render the `variant` of the component and compare it with the `reference` image, or
+/// write it as the reference when updating.
+fn check(
+    c: &slint_interpreter::ComponentDefinition,
+    variant: &Variant,
+    reference: &Path,
+    output: &Path,
+    args: &Cli,
+    tolerance: &screenshot::Tolerance,
+) -> Result<String> {
+    let instance = c.create()?;
+    let window = instance.window();
+    window.dispatch_event(i_slint_core::platform::WindowEvent::ScaleFactorChanged {
+        scale_factor: variant.scale_factor,
+    });
+    if let Some((width, height)) = variant.size {
+        window.set_size(LogicalSize::new(width, height));
+    }
+    instance.show()?;
+    let rendering = screenshot::render(window)?;
+    instance.hide()?;
+
+    if args.update {
+        screenshot::save_image(reference, &rendering)?;
+        return Ok(" (updated)".into());
+    }
+    if !reference.exists() {
+        return Err("The reference image does not exist, use --update to create it".into());
+    }
+    let comparison =
+        screenshot::compare(&screenshot::load_image(reference)?, &rendering, tolerance)
+            .inspect_err(|_| {
+                let _ =
+                    screenshot::save_image(&with_suffix(output, reference, "actual"), &rendering);
+            })?;
+    if comparison.matches {
+        return Ok(String::new());
+    }
+    let actual = with_suffix(output, reference, "actual");
+    let diff = with_suffix(output, reference, "diff");
+    screenshot::save_image(&actual, &rendering)?;
+    screenshot::save_image(&diff, &comparison.diff)?;
+    Err(format!(
+        "{:.2}% of the pixels are different (maximum difference: {:.2}), see {} and {}",
+        comparison.different_percentage,
+        comparison.max_difference,
+        actual.display(),
+        diff.display()
+    )
+    .into())
+}
+
+/// The path in the `output` folder for the `reference` file name with a suffix, like 'App-actual.png'
+fn with_suffix(output: &Path, reference: &Path, suffix: &str) -> PathBuf {
+    let stem = reference.file_stem().unwrap_or_default().to_string_lossy();
+    output.join(format!("{stem}-{suffix}.png"))
+}
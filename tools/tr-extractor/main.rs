@@ -45,15 +45,50 @@ struct Cli {
     #[arg(long = "join-existing", short = 'j')]
     /// Join messages with existing file
     join_existing: bool,
+
+    /// The format of the output file
+    #[arg(long, value_enum, default_value = "gettext")]
+    format: Format,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum Format {
+    /// A gettext .po file
+    Gettext,
+    /// A Fluent .ftl file, with the message identifiers expected by `slint::load_fluent_translations`
+    Fluent,
 }
 
 fn main() -> std::io::Result<()> {
     let args = Cli::parse();
 
+    let extension = if args.format == Format::Fluent { "ftl" } else { "po" };
     let output = args.output.unwrap_or_else(|| {
-        format!("{}.po", args.domain.as_ref().map(String::as_str).unwrap_or("messages")).into()
+        let domain = args.domain.as_ref().map(String::as_str).unwrap_or("messages");
+        format!("{domain}.{extension}").into()
     });
 
+    if args.format == Format::Fluent {
+        let mut messages = Messages::new(Default::default());
+        for path in args.paths {
+            process_file(path, &mut messages)?
+        }
+        let existing = if args.join_existing {
+            std::fs::read_to_string(&output).or_else(|e| {
+                if e.kind() == std::io::ErrorKind::NotFound {
+                    Ok(String::new())
+                } else {
+                    Err(e)
+                }
+            })?
+        } else {
+            String::new()
+        };
+        let fluent = write_fluent(&messages, &existing)
+            .map_err(|x| std::io::Error::new(std::io::ErrorKind::Other, x))?;
+        return std::fs::write(&output, fluent);
+    }
+
     let mut messages = if args.join_existing {
         polib::po_file::parse(&output)
             .map_err(|x| std::io::Error::new(std::io::ErrorKind::Other, x))?
@@ -175,6 +210,99 @@ fn visit_node(node: SyntaxNode, results: &mut Messages, current_context: Option<
     }
 }
 
+/// Convert a `@tr` format string to a Fluent pattern
+fn fluent_pattern(string: &str) -> String {
+    let mut result = String::new();
+    let mut arg_idx = 0;
+    let mut chars = string.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                result += "{ \"{\" }";
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                result += "{ \"}\" }";
+            }
+            '{' => {
+                let mut argument = String::new();
+                for c in chars.by_ref().take_while(|c| *c != '}') {
+                    argument.push(c);
+                }
+                let argument = argument.trim();
+                if argument.is_empty() {
+                    write!(result, "{{ $arg{arg_idx} }}").unwrap();
+                    arg_idx += 1;
+                } else if argument == "n" {
+                    result += "{ $n }";
+                } else {
+                    write!(result, "{{ $arg{argument} }}").unwrap();
+                }
+            }
+            _ => result.push(c),
+        }
+    }
+    // Lines that start with a special character would not be part of the pattern
+    let lines = result
+        .split('\n')
+        .map(|line| match line.strip_prefix(['[', '*', '.', '}']) {
+            Some(rest) => format!("{{ \"{}\" }}{rest}", &line[..1]),
+            None => line.into(),
+        })
+        .collect::<Vec<_>>();
+    lines.join("\n    ")
+}
+
+/// Generate the content of a Fluent file with the messages that are not already in `existing`
+fn write_fluent(messages: &Messages, existing: &str) -> Result<String, String> {
+    let existing_ids = existing
+        .lines()
+        .filter_map(|l| l.split_once('=').map(|(id, _)| id.trim()))
+        .filter(|id| id.starts_with(|c: char| c.is_ascii_alphabetic()))
+        .collect::<std::collections::HashSet<_>>();
+    let mut ids = std::collections::HashMap::new();
+    let mut result = existing.to_string();
+    if !result.is_empty() && !result.ends_with('\n') {
+        result.push('\n');
+    }
+    for msg in messages.messages() {
+        let id = i_slint_common::translations::fluent_message_id(msg.msgctxt(), msg.msgid());
+        if let Some((ctx, msgid)) = ids.insert(id.clone(), (msg.msgctxt(), msg.msgid())) {
+            return Err(format!(
+                "'{msgid}' (context '{ctx}') and '{}' (context '{}') have the same Fluent identifier '{id}'",
+                msg.msgid(),
+                msg.msgctxt()
+            ));
+        }
+        if existing_ids.contains(id.as_str()) {
+            continue;
+        }
+        if !result.is_empty() {
+            result.push('\n');
+        }
+        for comment in msg.comments().lines() {
+            writeln!(result, "# {comment}").unwrap();
+        }
+        if !msg.source().is_empty() {
+            writeln!(result, "# {}", msg.source()).unwrap();
+        }
+        let singular = fluent_pattern(msg.msgid());
+        let plural = msg.msgid_plural().unwrap_or_default();
+        if !plural.is_empty() {
+            writeln!(result, "{id} = {{ $n ->").unwrap();
+            writeln!(result, "    [one] {singular}").unwrap();
+            writeln!(result, "   *[other] {}", fluent_pattern(plural)).unwrap();
+            result += "}\n";
+        } else if msg.msgid().contains('\n') {
+            writeln!(result, "{id} =\n    {singular}").unwrap();
+        } else {
+            writeln!(result, "{id} = {singular}").unwrap();
+        }
+    }
+    Ok(result)
+}
+
 fn get_comments_before_line(token: i_slint_compiler::parser::SyntaxToken) -> Option<String> {
     let mut token = token.prev_token()?;
     loop {
@@ -305,3 +433,48 @@ pub fn new(
     }
     assert_eq!(r.len(), messages.count());
 }
+
+#[test]
+fn extract_fluent() {
+    let source = r##"export component Foo {
+        // greeting
+        x: @tr("Hello, {}!", "World");
+        y: @tr("{n} file" | "{n} files" % 2);
+        z: @tr("ctx" => "a {{literal}}\n[b]");
+    }"##;
+
+    let mut diag = BuildDiagnostics::default();
+    let syntax_node = i_slint_compiler::parser::parse(
+        source.into(),
+        Some(std::path::Path::new("test.slint")),
+        &mut diag,
+    );
+    let mut messages = polib::catalog::Catalog::new(Default::default());
+    visit_node(syntax_node, &mut messages, None);
+
+    assert_eq!(
+        write_fluent(&messages, "").unwrap(),
+        r#"# greeting
+# test.slint:3
+Foo--Hello = Hello, { $arg0 }!
+
+# test.slint:4
+Foo--n-file = { $n ->
+    [one] { $n } file
+   *[other] { $n } files
+}
+
+# test.slint:5
+ctx--a-literal-b =
+    a { "{" }literal{ "}" }
+    { "[" }b]
+"#
+    );
+
+    let existing = "Foo--Hello = Hallo, { $arg0 }!\nctx--a-literal-b = x\n";
+    assert_eq!(
+        write_fluent(&messages, existing).unwrap(),
+        "Foo--Hello = Hallo, { $arg0 }!\nctx--a-literal-b = x\n\n# test.slint:4\n\
+         Foo--n-file = { $n ->\n    [one] { $n } file\n   *[other] { $n } files\n}\n"
+    );
+}
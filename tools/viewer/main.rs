@@ -4,14 +4,10 @@
 #![doc = include_str!("README.md")]
 
 use clap::Parser;
+use i_slint_compiler::langtype::Type as LangType;
 use i_slint_compiler::ComponentSelection;
-use i_slint_core::model::{Model, ModelRc};
-use i_slint_core::SharedVector;
 use itertools::Itertools;
-use slint_interpreter::{
-    ComponentDefinition, ComponentHandle, ComponentInstance, SharedString, Value,
-};
-use smol_str::ToSmolStr;
+use slint_interpreter::{ComponentHandle, ComponentInstance, JsonError, Value};
 use std::collections::HashMap;
 use std::io::{BufReader, BufWriter};
 use std::path::PathBuf;
@@ -77,6 +73,12 @@ struct Cli {
     #[arg(long, value_name = "json file", action)]
     save_data: Option<std::path::PathBuf>,
 
+    /// Load properties from a .json or .csv file, and load them again when the file changes.
+    /// A JSON object sets the properties with the same name as its fields. A JSON array, or the rows
+    /// of a CSV file, set the model property with the same name as the file.
+    #[arg(long, value_name = "data file", number_of_values = 1, action)]
+    data: Vec<std::path::PathBuf>,
+
     /// Specify callbacks handler.
     /// The first argument is the callback name, and the second argument is a string that is going
     /// to be passed to the shell to be executed. Occurrences of `$1` will be replaced by the first argument,
@@ -95,34 +97,9 @@ struct Cli {
     translation_dir: Option<std::path::PathBuf>,
 }
 
-fn to_json(val: slint_interpreter::Value) -> Option<serde_json::Value> {
-    match val {
-        slint_interpreter::Value::Number(x) => Some(x.into()),
-        slint_interpreter::Value::String(x) => Some(x.as_str().into()),
-        slint_interpreter::Value::Bool(x) => Some(x.into()),
-        slint_interpreter::Value::Model(model) => {
-            let mut res = Vec::with_capacity(model.row_count());
-            for i in 0..model.row_count() {
-                res.push(to_json(model.row_data(i).unwrap())?);
-            }
-            Some(serde_json::Value::Array(res))
-        }
-        slint_interpreter::Value::Struct(st) => {
-            let mut obj = serde_json::Map::new();
-            for (k, v) in st.iter() {
-                obj.insert(k.into(), to_json(v.clone())?);
-            }
-            Some(obj.into())
-        }
-        slint_interpreter::Value::EnumerationValue(_class, value) => Some(value.as_str().into()),
-        slint_interpreter::Value::Image(image) => {
-            image.path().and_then(|path| path.to_str()).map(|path| path.into())
-        }
-        _ => None,
-    }
-}
-
 thread_local! {static CURRENT_INSTANCE: std::cell::RefCell<Option<ComponentInstance>> = Default::default();}
+// The values of the public properties of CURRENT_INSTANCE after it was created, to find out which ones changed since
+thread_local! {static INITIAL_PROPERTY_VALUES: std::cell::RefCell<serde_json::Map<String, serde_json::Value>> = Default::default();}
 static EXIT_CODE: std::sync::atomic::AtomicI32 = std::sync::atomic::AtomicI32::new(0);
 
 fn main() -> Result<()> {
@@ -147,6 +124,8 @@ fn main() -> Result<()> {
     };
 
     let fswatcher = if args.auto_reload { Some(start_fswatch_thread(args.clone())?) } else { None };
+    let _data_watcher =
+        if !args.data.is_empty() { Some(start_data_watch_thread(args.clone())?) } else { None };
     let compiler = init_compiler(&args, fswatcher);
     let r = spin_on::spin_on(compiler.build_from_path(&args.path));
     r.print_diagnostics();
@@ -169,23 +148,22 @@ fn main() -> Result<()> {
     init_dialog(&component);
 
     if let Some(data_path) = args.load_data {
-        load_data(&c, &component, &data_path)?;
+        load_data(&component, &data_path)?;
+    }
+    for data_path in &args.data {
+        load_data_file(&component, data_path)?;
     }
     install_callbacks(&component, &args.on);
 
-    if args.auto_reload {
+    if args.auto_reload || !args.data.is_empty() {
+        INITIAL_PROPERTY_VALUES.with(|initial| initial.replace(property_values(&component)));
         CURRENT_INSTANCE.with(|current| current.replace(Some(component.clone_strong())));
     }
 
     component.run()?;
 
     if let Some(data_path) = args.save_data {
-        let mut obj = serde_json::Map::new();
-        for (name, _) in c.properties() {
-            if let Some(v) = to_json(component.get_property(&name).unwrap()) {
-                obj.insert(name.into(), v);
-            }
-        }
+        let obj = property_values(&component);
         if data_path == std::path::Path::new("-") {
             serde_json::to_writer_pretty(std::io::stdout(), &obj)?;
         } else {
@@ -314,6 +292,44 @@ fn start_fswatch_thread(args: Cli) -> Result<Arc<Mutex<notify::RecommendedWatche
     Ok(w)
 }
 
+fn start_data_watch_thread(args: Cli) -> Result<Arc<Mutex<notify::RecommendedWatcher>>> {
+    let (tx, rx) = std::sync::mpsc::channel();
+    let w = Arc::new(Mutex::new(notify::recommended_watcher(tx)?));
+    for data_path in &args.data {
+        watch_with_retry(data_path, &w);
+    }
+    let w2 = w.clone();
+    std::thread::spawn(move || {
+        while let Ok(event) = rx.recv() {
+            use notify::EventKind::*;
+            if let Ok(event) = event {
+                if matches!(event.kind, Modify(_) | Remove(_) | Create(_)) {
+                    let args = args.clone();
+                    let w2 = w2.clone();
+                    i_slint_core::api::invoke_from_event_loop(move || reload_data(&args, &w2))
+                        .unwrap();
+                }
+            }
+        }
+    });
+    Ok(w)
+}
+
+/// Load the `--data` files again into the current instance
+fn reload_data(args: &Cli, watcher: &Arc<Mutex<notify::RecommendedWatcher>>) {
+    CURRENT_INSTANCE.with(|current| {
+        let Some(instance) = &*current.borrow() else { return };
+        for data_path in &args.data {
+            // Editors may replace the file instead of modifying it, so watch the new one
+            watch_with_retry(data_path, watcher);
+            match load_data_file(instance, data_path) {
+                Ok(()) => eprintln!("Successful reload of {}", data_path.display()),
+                Err(e) => eprintln!("Warning: cannot load {}: {e:?}", data_path.display()),
+            }
+        }
+    });
+}
+
 async fn reload(args: Cli, fswatcher: Arc<Mutex<notify::RecommendedWatcher>>) {
     let compiler = init_compiler(&args, Some(fswatcher));
     let r = compiler.build_from_path(&args.path).await;
@@ -321,20 +337,31 @@ async fn reload(args: Cli, fswatcher: Arc<Mutex<notify::RecommendedWatcher>>) {
     if let Some(c) = r.components().next() {
         CURRENT_INSTANCE.with(|current| {
             let mut current = current.borrow_mut();
-            if let Some(handle) = current.take() {
-                let window = handle.window();
-                let new_handle = c.create_with_existing_window(window).unwrap();
-                init_dialog(&new_handle);
-                current.replace(new_handle);
+            let old_handle = current.take();
+            let new_handle = if let Some(handle) = &old_handle {
+                c.create_with_existing_window(handle.window()).unwrap()
             } else {
                 let handle = c.create().unwrap();
-                init_dialog(&handle);
                 handle.show().unwrap();
-                current.replace(handle);
+                handle
+            };
+            init_dialog(&new_handle);
+            if let Some(data_path) = &args.load_data {
+                let _ = load_data(&new_handle, data_path);
+            }
+            for data_path in &args.data {
+                if let Err(e) = load_data_file(&new_handle, data_path) {
+                    eprintln!("Warning: cannot load {}: {e:?}", data_path.display());
+                }
             }
-            if let Some(data_path) = args.load_data {
-                let _ = load_data(&c, current.as_ref().unwrap(), &data_path);
+            let initial_values = property_values(&new_handle);
+            if let Some(old_handle) = &old_handle {
+                INITIAL_PROPERTY_VALUES.with(|initial| {
+                    restore_changed_properties(&initial.borrow(), old_handle, &new_handle)
+                });
             }
+            INITIAL_PROPERTY_VALUES.with(|initial| initial.replace(initial_values));
+            current.replace(new_handle);
             eprintln!("Successful reload of {}", args.path.display());
         });
     } else if !r.has_errors() {
@@ -347,97 +374,140 @@ async fn reload(args: Cli, fswatcher: Arc<Mutex<notify::RecommendedWatcher>>) {
     PENDING_EVENTS.fetch_sub(1, Ordering::SeqCst);
 }
 
-fn load_data(
-    c: &ComponentDefinition,
-    instance: &ComponentInstance,
-    data_path: &std::path::Path,
-) -> Result<()> {
+fn load_data(instance: &ComponentInstance, data_path: &std::path::Path) -> Result<()> {
     let json: serde_json::Value = if data_path == std::path::Path::new("-") {
         serde_json::from_reader(std::io::stdin())?
     } else {
         serde_json::from_reader(BufReader::new(std::fs::File::open(data_path)?))?
     };
 
-    let types = c.properties_and_callbacks().collect::<HashMap<_, _>>();
     let obj = json.as_object().ok_or("The data is not a JSON object")?;
     for (name, v) in obj {
-        fn from_json(
-            t: &i_slint_compiler::langtype::Type,
-            v: &serde_json::Value,
-        ) -> slint_interpreter::Value {
-            match v {
-                serde_json::Value::Null => slint_interpreter::Value::Void,
-                serde_json::Value::Bool(b) => (*b).into(),
-                serde_json::Value::Number(n) => {
-                    slint_interpreter::Value::Number(n.as_f64().unwrap_or(f64::NAN))
-                }
-                serde_json::Value::String(s) => match t {
-                    i_slint_compiler::langtype::Type::Enumeration(e) => {
-                        let s = s.to_smolstr();
-                        if e.values.contains(&s) {
-                            slint_interpreter::Value::EnumerationValue(e.name.to_string(), s.into())
-                        } else {
-                            eprintln!("Warning: Unexpected value for enum '{}': {}", e.name, s);
-                            slint_interpreter::Value::Void
-                        }
-                    }
-                    i_slint_compiler::langtype::Type::String => {
-                        SharedString::from(s.as_str()).into()
-                    }
-                    i_slint_compiler::langtype::Type::Image => {
-                        match slint_interpreter::Image::load_from_path(std::path::Path::new(s)) {
-                            Ok(image) => image.into(),
-                            Err(_) => {
-                                eprintln!("Warning: Failed to load image from path: {}", s);
-                                slint_interpreter::Value::Void
-                            }
-                        }
-                    }
-                    _ => slint_interpreter::Value::Void,
-                },
-                serde_json::Value::Array(array) => match t {
-                    i_slint_compiler::langtype::Type::Array(it) => slint_interpreter::Value::Model(
-                        ModelRc::new(i_slint_core::model::SharedVectorModel::from(
-                            array.iter().map(|v| from_json(it, v)).collect::<SharedVector<Value>>(),
-                        )),
-                    ),
-                    _ => slint_interpreter::Value::Void,
-                },
-                serde_json::Value::Object(obj) => match t {
-                    i_slint_compiler::langtype::Type::Struct(s) => obj
-                        .iter()
-                        .filter_map(|(k, v)| {
-                            let k = k.to_smolstr();
-                            match s.fields.get(&k) {
-                                Some(t) => Some((k.to_string(), from_json(t, v))),
-                                None => {
-                                    eprintln!("Warning: ignoring unknown property: {}", k);
-                                    None
-                                }
-                            }
-                        })
-                        .collect::<slint_interpreter::Struct>()
-                        .into(),
-                    _ => slint_interpreter::Value::Void,
-                },
+        set_property_from_json(instance, name, v);
+    }
+    Ok(())
+}
+
+fn set_property_from_json(instance: &ComponentInstance, name: &str, v: &serde_json::Value) {
+    match instance.definition().property_from_json(name, v) {
+        Ok(result) => {
+            for field in &result.unknown_fields {
+                eprintln!("Warning: ignoring unknown field: {}", field);
             }
+            if let Err(e) = instance.set_property(name, result.value) {
+                eprintln!("Warning: cannot set property '{}' from data file: {:?}", name, e)
+            }
+        }
+        Err(JsonError::NoSuchProperty(_)) => {
+            eprintln!("Warning: ignoring unknown property: {}", name)
         }
+        Err(e) => eprintln!("Warning: cannot read property '{}' from data file: {}", name, e),
+    }
+}
 
-        match types.get(name.as_str()) {
-            Some(t) => {
-                match instance.set_property(name, from_json(t, v)) {
-                    Ok(()) => (),
-                    Err(e) => {
-                        eprintln!("Warning: cannot set property '{}' from data file: {:?}", name, e)
-                    }
-                };
+/// Load a file passed with `--data`: a JSON object sets the properties named like its fields,
+/// while a JSON array or a CSV file sets the property named like the file.
+fn load_data_file(instance: &ComponentInstance, data_path: &std::path::Path) -> Result<()> {
+    let types = instance.definition().properties_and_callbacks().collect::<HashMap<_, _>>();
+    let property_name = data_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().replace('_', "-"))
+        .unwrap_or_default();
+
+    if data_path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("csv")) {
+        let Some(LangType::Array(row_type)) = types.get(&property_name) else {
+            return Err(format!("There is no model property named '{property_name}'").into());
+        };
+        let json = csv_to_json(csv::Reader::from_path(data_path)?, row_type)?;
+        set_property_from_json(instance, &property_name, &json);
+        return Ok(());
+    }
+
+    let json: serde_json::Value =
+        serde_json::from_reader(BufReader::new(std::fs::File::open(data_path)?))?;
+    match json {
+        serde_json::Value::Object(obj) => {
+            for (name, v) in &obj {
+                set_property_from_json(instance, name, v);
             }
-            None => eprintln!("Warning: ignoring unknown property: {}", name),
         }
+        serde_json::Value::Array(_) => set_property_from_json(instance, &property_name, &json),
+        _ => return Err("The data is not a JSON object or array".into()),
     }
     Ok(())
 }
 
+/// Convert the rows of a CSV file to a JSON array, where the header gives the field names.
+/// Cells are converted to numbers or booleans if the field of the `row_type` struct has that type.
+fn csv_to_json<R: std::io::Read>(
+    mut reader: csv::Reader<R>,
+    row_type: &LangType,
+) -> Result<serde_json::Value> {
+    let LangType::Struct(row_struct) = row_type else {
+        return Err(format!("The rows of the model must be a struct, not {row_type}").into());
+    };
+    let headers = reader.headers()?.clone();
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = record?;
+        let mut row = serde_json::Map::new();
+        for (name, cell) in headers.iter().zip(record.iter()) {
+            let field_type = row_struct
+                .fields
+                .get(name)
+                .or_else(|| row_struct.fields.get(name.replace('_', "-").as_str()));
+            let value = match field_type {
+                Some(LangType::Bool) => cell
+                    .trim()
+                    .parse::<bool>()
+                    .map_err(|_| format!("'{cell}' is not a boolean for field '{name}'"))?
+                    .into(),
+                Some(t) if t.as_unit_product().is_some() => cell
+                    .trim()
+                    .parse::<f64>()
+                    .map_err(|_| format!("'{cell}' is not a number for field '{name}'"))?
+                    .into(),
+                _ => cell.into(),
+            };
+            row.insert(name.into(), value);
+        }
+        rows.push(serde_json::Value::Object(row));
+    }
+    Ok(serde_json::Value::Array(rows))
+}
+
+/// The values of the public properties of `instance` that can be converted to JSON
+fn property_values(instance: &ComponentInstance) -> serde_json::Map<String, serde_json::Value> {
+    instance
+        .definition()
+        .properties()
+        .filter_map(|(name, _)| {
+            let value = instance.get_property(&name).ok()?.to_json().ok()?;
+            Some((name, value))
+        })
+        .collect()
+}
+
+/// Copy the public properties that changed in `old` since it was created to `new`, when
+/// the values still fit the type of the property in `new`
+fn restore_changed_properties(
+    initial_values: &serde_json::Map<String, serde_json::Value>,
+    old: &ComponentInstance,
+    new: &ComponentInstance,
+) {
+    let definition = new.definition();
+    for (name, value) in property_values(old) {
+        if initial_values.get(&name) == Some(&value) {
+            continue;
+        }
+        let Ok(result) = definition.property_from_json(&name, &value) else {
+            continue;
+        };
+        // Output properties can't be set, they are computed again in the new instance
+        let _ = new.set_property(&name, result.value);
+    }
+}
+
 fn install_callbacks(instance: &ComponentInstance, callbacks: &[String]) {
     assert!(callbacks.len() % 2 == 0);
     for chunk in callbacks.chunks(2) {
@@ -473,13 +543,7 @@ fn execute_cmd(cmd: &str, callback_args: &[Value]) -> Result<()> {
                 Value::Image(img) => {
                     img.path().map(|p| p.to_string_lossy()).unwrap_or_default().into()
                 }
-                Value::Struct(st) => {
-                    let mut obj = serde_json::Map::new();
-                    for (k, v) in st.iter() {
-                        obj.insert(k.into(), to_json(v.clone()).unwrap());
-                    }
-                    serde_json::to_string_pretty(&obj)?
-                }
+                Value::Struct(_) => serde_json::to_string_pretty(&v.to_json()?)?,
                 _ => return Err(format!("Cannot convert argument to string: {:?}", v).into()),
             })
         })
@@ -169,6 +169,9 @@ macro_rules! map_type {
         (Image) => {
             "image"
         };
+        (Color) => {
+            "color"
+        };
         ($pub_type:ident) => {
             stringify!($pub_type)
         };